@@ -34,7 +34,20 @@ fn ints_json(bench: &mut Bencher) {
         let validator = build_schema_validator(py, c"{'type': 'int'}");
 
         let result = validator
-            .validate_json(py, &json(py, "123"), None, None, None, false.into(), None, None)
+            .validate_json(
+                py,
+                &json(py, "123"),
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+            )
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
@@ -42,7 +55,20 @@ fn ints_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, "123"), None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &json(py, "123"),
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -56,7 +82,23 @@ fn ints_python(bench: &mut Bencher) {
 
         let Ok(input) = 123_i64.into_pyobject(py);
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+                false,
+                false,
+            )
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
@@ -65,7 +107,23 @@ fn ints_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -84,7 +142,20 @@ fn list_int_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &json(py, &code),
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -110,7 +181,23 @@ fn list_int_python(bench: &mut Bencher) {
         let input = black_box(input.bind(py));
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    None,
+                    None,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                )
                 .unwrap();
             black_box(v)
         })
@@ -148,7 +235,20 @@ fn list_error_json(bench: &mut Bencher) {
                 .join(", ")
         );
 
-        match validator.validate_json(py, &json(py, &code), None, None, None, false.into(), None, None) {
+        match validator.validate_json(
+            py,
+            &json(py, &code),
+            None,
+            None,
+            None,
+            None,
+            false.into(),
+            None,
+            None,
+            false,
+            false,
+            false,
+        ) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -159,12 +259,25 @@ fn list_error_json(bench: &mut Bencher) {
             }
         };
 
-        bench.iter(
-            || match validator.validate_json(py, &json(py, &code), None, None, None, false.into(), None, None) {
+        bench.iter(|| {
+            match validator.validate_json(
+                py,
+                &json(py, &code),
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+            ) {
                 Ok(_) => panic!("unexpectedly valid"),
                 Err(e) => black_box(e),
-            },
-        )
+            }
+        })
     })
 }
 
@@ -181,7 +294,23 @@ fn list_error_python_input(py: Python<'_>) -> (SchemaValidator, PyObject) {
 
     let input = py.eval(&code, None, None).unwrap().extract().unwrap();
 
-    match validator.validate_python(py, &input, None, None, None, None, false.into(), None, None) {
+    match validator.validate_python(
+        py,
+        &input,
+        None,
+        None,
+        None,
+        None,
+        None,
+        false.into(),
+        None,
+        None,
+        false,
+        false,
+        false,
+        false,
+        false,
+    ) {
         Ok(_) => panic!("unexpectedly valid"),
         Err(e) => {
             let v = e.value(py);
@@ -201,7 +330,23 @@ fn list_error_python(bench: &mut Bencher) {
 
         let input = black_box(input.bind(py));
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None, false.into(), None, None);
+            let result = validator.validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+                false,
+                false,
+            );
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -243,7 +388,20 @@ fn list_any_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &json(py, &code),
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -263,7 +421,23 @@ fn list_any_python(bench: &mut Bencher) {
         let input = black_box(input);
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    None,
+                    None,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                )
                 .unwrap();
             black_box(v)
         })
@@ -297,7 +471,20 @@ fn dict_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, &code), None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &json(py, &code),
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -324,7 +511,23 @@ fn dict_python(bench: &mut Bencher) {
         let input = black_box(input);
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    None,
+                    None,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                )
                 .unwrap();
             black_box(v)
         })
@@ -354,7 +557,23 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = py.eval(&code, None, None).unwrap();
 
-        match validator.validate_python(py, &input, None, None, None, None, false.into(), None, None) {
+        match validator.validate_python(
+            py,
+            &input,
+            None,
+            None,
+            None,
+            None,
+            None,
+            false.into(),
+            None,
+            None,
+            false,
+            false,
+            false,
+            false,
+            false,
+        ) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -367,7 +586,23 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = black_box(input);
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None, false.into(), None, None);
+            let result = validator.validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+                false,
+                false,
+            );
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -405,7 +640,20 @@ fn typed_dict_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &json(py, code), None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &json(py, code),
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -440,7 +688,23 @@ fn typed_dict_python(bench: &mut Bencher) {
         let input = black_box(input);
         bench.iter(|| {
             let v = validator
-                .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    None,
+                    None,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                )
                 .unwrap();
             black_box(v)
         })
@@ -481,7 +745,23 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         let input = py.eval(code, None, None).unwrap();
         let input = black_box(input);
 
-        match validator.validate_python(py, &input, None, None, None, None, false.into(), None, None) {
+        match validator.validate_python(
+            py,
+            &input,
+            None,
+            None,
+            None,
+            None,
+            None,
+            false.into(),
+            None,
+            None,
+            false,
+            false,
+            false,
+            false,
+            false,
+        ) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -493,7 +773,23 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         };
 
         bench.iter(|| {
-            let result = validator.validate_python(py, &input, None, None, None, None, false.into(), None, None);
+            let result = validator.validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+                false,
+                false,
+            );
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -520,7 +816,23 @@ fn complete_model(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             );
         })
@@ -542,13 +854,45 @@ fn nested_model_using_definitions(bench: &mut Bencher) {
         let input = black_box(input);
 
         validator
-            .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+                false,
+                false,
+            )
             .unwrap();
 
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             );
         })
@@ -570,13 +914,45 @@ fn nested_model_inlined(bench: &mut Bencher) {
         let input = black_box(input);
 
         validator
-            .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+                false,
+                false,
+            )
             .unwrap();
 
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             );
         })
@@ -590,7 +966,23 @@ fn literal_ints_few_python(bench: &mut Bencher) {
 
         let Ok(input) = 4_i64.into_pyobject(py);
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+                false,
+                false,
+            )
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 4);
@@ -599,7 +991,23 @@ fn literal_ints_few_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -614,7 +1022,23 @@ fn literal_strings_few_small_python(bench: &mut Bencher) {
         let input = py.eval(c"'4'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+                false,
+                false,
+            )
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -623,7 +1047,23 @@ fn literal_strings_few_small_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -641,7 +1081,23 @@ fn literal_strings_few_large_python(bench: &mut Bencher) {
         let input = py.eval(c"'a' * 25 + '4'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+                false,
+                false,
+            )
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -650,7 +1106,23 @@ fn literal_strings_few_large_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -684,7 +1156,23 @@ class Foo(Enum):
 
         let input = py.eval(c"Foo.v4", Some(&globals), None).unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+                false,
+                false,
+            )
             .unwrap();
         assert!(input.eq(result).unwrap());
 
@@ -692,7 +1180,23 @@ class Foo(Enum):
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -706,7 +1210,23 @@ fn literal_ints_many_python(bench: &mut Bencher) {
 
         let Ok(input) = 99_i64.into_pyobject(py);
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+                false,
+                false,
+            )
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 99);
@@ -715,7 +1235,23 @@ fn literal_ints_many_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -731,7 +1267,23 @@ fn literal_strings_many_small_python(bench: &mut Bencher) {
         let input = py.eval(c"'99'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+                false,
+                false,
+            )
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -740,7 +1292,23 @@ fn literal_strings_many_small_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -758,7 +1326,23 @@ fn literal_strings_many_large_python(bench: &mut Bencher) {
         let input = py.eval(c"'a' * 25 + '99'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+            .validate_python(
+                py,
+                &input,
+                None,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+                false,
+                false,
+            )
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -767,7 +1351,23 @@ fn literal_strings_many_large_python(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                    .validate_python(
+                        py,
+                        &input,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -781,7 +1381,20 @@ fn literal_ints_many_json(bench: &mut Bencher) {
 
         let input_json = py.eval(c"'99'", None, None).unwrap();
         let result = validator
-            .validate_json(py, &input_json, None, None, None, false.into(), None, None)
+            .validate_json(
+                py,
+                &input_json,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+            )
             .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 99);
@@ -790,7 +1403,20 @@ fn literal_ints_many_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &input_json, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &input_json,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -809,7 +1435,20 @@ fn literal_strings_many_large_json(bench: &mut Bencher) {
         let input_json = py.eval(c"'\"' + 'a' * 25 + '99' + '\"'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
         let result = validator
-            .validate_json(py, &input_json, None, None, None, false.into(), None, None)
+            .validate_json(
+                py,
+                &input_json,
+                None,
+                None,
+                None,
+                None,
+                false.into(),
+                None,
+                None,
+                false,
+                false,
+                false,
+            )
             .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
@@ -818,7 +1457,20 @@ fn literal_strings_many_large_json(bench: &mut Bencher) {
         bench.iter(|| {
             black_box(
                 validator
-                    .validate_json(py, &input_json, None, None, None, false.into(), None, None)
+                    .validate_json(
+                        py,
+                        &input_json,
+                        None,
+                        None,
+                        None,
+                        None,
+                        false.into(),
+                        None,
+                        None,
+                        false,
+                        false,
+                        false,
+                    )
                     .unwrap(),
             )
         })
@@ -854,7 +1506,23 @@ class Foo(Enum):
             let input = py.eval(c"'null'", None, None).unwrap();
             let input_str: String = input.extract().unwrap();
             let result = validator
-                .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    None,
+                    None,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                )
                 .unwrap();
             let result_str: String = result.extract(py).unwrap();
             assert_eq!(result_str, input_str);
@@ -863,7 +1531,23 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                        .validate_python(
+                            py,
+                            &input,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            false.into(),
+                            None,
+                            None,
+                            false,
+                            false,
+                            false,
+                            false,
+                            false,
+                        )
                         .unwrap(),
                 )
             })
@@ -874,7 +1558,23 @@ class Foo(Enum):
             let input = py.eval(c"-1", None, None).unwrap();
             let input_int: i64 = input.extract().unwrap();
             let result = validator
-                .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    None,
+                    None,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                )
                 .unwrap();
             let result_int: i64 = result.extract(py).unwrap();
             assert_eq!(result_int, input_int);
@@ -883,7 +1583,23 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                        .validate_python(
+                            py,
+                            &input,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            false.into(),
+                            None,
+                            None,
+                            false,
+                            false,
+                            false,
+                            false,
+                            false,
+                        )
                         .unwrap(),
                 )
             })
@@ -893,7 +1609,23 @@ class Foo(Enum):
         {
             let input = py.eval(c"None", None, None).unwrap();
             let result = validator
-                .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    None,
+                    None,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                )
                 .unwrap();
             assert!(input.eq(result).unwrap());
 
@@ -901,7 +1633,23 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                        .validate_python(
+                            py,
+                            &input,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            false.into(),
+                            None,
+                            None,
+                            false,
+                            false,
+                            false,
+                            false,
+                            false,
+                        )
                         .unwrap(),
                 )
             })
@@ -911,7 +1659,23 @@ class Foo(Enum):
         {
             let input = py.eval(c"Foo.v4", Some(&globals), None).unwrap();
             let result = validator
-                .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                .validate_python(
+                    py,
+                    &input,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false.into(),
+                    None,
+                    None,
+                    false,
+                    false,
+                    false,
+                    false,
+                    false,
+                )
                 .unwrap();
             assert!(input.eq(result).unwrap());
 
@@ -919,7 +1683,23 @@ class Foo(Enum):
             bench.iter(|| {
                 black_box(
                     validator
-                        .validate_python(py, &input, None, None, None, None, false.into(), None, None)
+                        .validate_python(
+                            py,
+                            &input,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            false.into(),
+                            None,
+                            None,
+                            false,
+                            false,
+                            false,
+                            false,
+                            false,
+                        )
                         .unwrap(),
                 )
             })
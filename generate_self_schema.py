@@ -13,6 +13,7 @@ import re
 import sys
 from collections.abc import Callable
 from datetime import date, datetime, time, timedelta
+from fractions import Fraction
 from pathlib import Path
 from re import Pattern
 from typing import TYPE_CHECKING, Any, ForwardRef, Union
@@ -51,12 +52,14 @@ schema_ref_validator = {'type': 'definition-ref', 'schema_ref': 'root-schema'}
 def get_schema(obj: Any, definitions: dict[str, core_schema.CoreSchema]) -> core_schema.CoreSchema:  # noqa: C901
     if isinstance(obj, str):
         return {'type': obj}
-    elif obj in (datetime, timedelta, date, time, bool, int, float, str, decimal.Decimal, complex):
+    elif obj in (datetime, timedelta, date, time, bool, int, float, str, decimal.Decimal, Fraction, complex):
         return {'type': obj.__name__.lower()}
     elif is_typeddict(obj):
         return type_dict_schema(obj, definitions)
     elif obj == Any or obj == type:
         return {'type': 'any'}
+    elif obj is type(Ellipsis):
+        return {'type': 'literal', 'expected': [Ellipsis]}
     if isinstance(obj, type) and issubclass(obj, core_schema.Protocol):
         return {'type': 'callable'}
 
@@ -74,6 +77,11 @@ def get_schema(obj: Any, definitions: dict[str, core_schema.CoreSchema]) -> core
         return {'type': 'list', 'items_schema': get_schema(obj.__args__[0], definitions)}
     elif issubclass(origin, set):
         return {'type': 'set', 'items_schema': get_schema(obj.__args__[0], definitions)}
+    elif issubclass(origin, tuple):
+        if len(obj.__args__) == 2 and obj.__args__[1] is Ellipsis:
+            items_schema = [get_schema(obj.__args__[0], definitions)]
+            return {'type': 'tuple', 'items_schema': items_schema, 'variadic_item_index': 0}
+        return {'type': 'tuple', 'items_schema': [get_schema(arg, definitions) for arg in obj.__args__]}
     elif issubclass(origin, dict):
         return {
             'type': 'dict',
@@ -2,9 +2,13 @@ from __future__ import annotations
 
 import sys as _sys
 from typing import Any as _Any
+from typing import Literal as _Literal
 
 from ._pydantic_core import (
     ArgsKwargs,
+    ArrowArray,
+    EmailAddress,
+    ModelFieldGetter,
     MultiHostUrl,
     PydanticCustomError,
     PydanticKnownError,
@@ -17,12 +21,17 @@ from ._pydantic_core import (
     SchemaError,
     SchemaSerializer,
     SchemaValidator,
+    Secret,
     Some,
     TzInfo,
     Url,
     ValidationError,
+    ValidationReport,
+    ValidationResult,
     __version__,
+    copy_validated,
     from_json,
+    register_ob_type,
     to_json,
     to_jsonable_python,
     validate_core_schema,
@@ -49,13 +58,19 @@ __all__ = [
     'Some',
     'Url',
     'MultiHostUrl',
+    'EmailAddress',
+    'Secret',
     'ArgsKwargs',
+    'ArrowArray',
+    'ModelFieldGetter',
     'PydanticUndefined',
     'PydanticUndefinedType',
     'SchemaError',
     'ErrorDetails',
     'InitErrorDetails',
     'ValidationError',
+    'ValidationReport',
+    'ValidationResult',
     'PydanticCustomError',
     'PydanticKnownError',
     'PydanticOmit',
@@ -66,6 +81,8 @@ __all__ = [
     'to_json',
     'from_json',
     'to_jsonable_python',
+    'copy_validated',
+    'register_ob_type',
     'validate_core_schema',
 ]
 
@@ -94,6 +111,11 @@ class ErrorDetails(_TypedDict):
     The documentation URL giving information about the error. No URL is available if
     a [`PydanticCustomError`][pydantic_core.PydanticCustomError] is used.
     """
+    severity: _NotRequired[_Literal['info', 'warning', 'error']]
+    """
+    How serious the error is, only set if a [`PydanticCustomError`][pydantic_core.PydanticCustomError]
+    was raised with a severity other than `'error'`.
+    """
 
 
 class InitErrorDetails(_TypedDict):
@@ -1,8 +1,9 @@
 import datetime
-from collections.abc import Mapping
+import os
+from collections.abc import Iterator, Mapping, Sequence
 from typing import Any, Callable, Generic, Literal, TypeVar, final
 
-from _typeshed import SupportsAllComparisons
+from _typeshed import SupportsAllComparisons, SupportsWrite
 from typing_extensions import LiteralString, Self, TypeAlias
 
 from pydantic_core import ErrorDetails, ErrorTypeInfo, InitErrorDetails, MultiHostHost
@@ -18,8 +19,13 @@ __all__ = [
     'SchemaSerializer',
     'Url',
     'MultiHostUrl',
+    'EmailAddress',
+    'Secret',
     'SchemaError',
     'ValidationError',
+    'ValidationReport',
+    'ValidationResult',
+    'ArrowArray',
     'PydanticCustomError',
     'PydanticKnownError',
     'PydanticOmit',
@@ -32,6 +38,7 @@ __all__ = [
     'to_json',
     'from_json',
     'to_jsonable_python',
+    'register_ob_type',
     'list_all_errors',
     'TzInfo',
     'validate_core_schema',
@@ -42,6 +49,7 @@ build_info: str
 _recursion_limit: int
 
 _T = TypeVar('_T', default=Any, covariant=True)
+_SecretT = TypeVar('_SecretT', str, bytes)
 
 _StringInput: TypeAlias = 'dict[str, _StringInput]'
 
@@ -88,17 +96,30 @@ class SchemaValidator:
         """
         The title of the schema, as used in the heading of [`ValidationError.__str__()`][pydantic_core.ValidationError].
         """
+    @property
+    def schema_hash(self) -> int:
+        """
+        A hash of the schema and config, the same across processes so it can be used to identify the schema
+        in logs and metrics. Classes and functions in the schema are identified by their module and qualified name,
+        `metadata` is ignored.
+        """
     def validate_python(
         self,
         input: Any,
         *,
         strict: bool | None = None,
+        strict_overrides: dict[str | tuple[str, ...], bool] | None = None,
         from_attributes: bool | None = None,
         context: Any | None = None,
         self_instance: Any | None = None,
         allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
         by_alias: bool | None = None,
         by_name: bool | None = None,
+        trace: bool = False,
+        memoize: bool = False,
+        coercions: bool = False,
+        suppressed_errors: bool = False,
+        alias_hits: bool = False,
     ) -> Any:
         """
         Validate a Python object against the schema and return the validated object.
@@ -107,6 +128,9 @@ class SchemaValidator:
             input: The Python object to validate.
             strict: Whether to validate the object in strict mode.
                 If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            strict_overrides: Strictness of individual fields of models, dataclasses and typed dicts, keyed by the
+                field name or a tuple of field names for nested fields, e.g. `{('address', 'postcode'): False}`.
+                The override applies to the field and everything within it, taking precedence over `strict`.
             from_attributes: Whether to validate objects as inputs to models by extracting attributes.
                 If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
             context: The context to use for validation, this is passed to functional validators as
@@ -118,13 +142,130 @@ class SchemaValidator:
                 `'trailing-strings'` means any final unfinished JSON string is included in the result.
             by_alias: Whether to use the field's alias when validating against the provided input data.
             by_name: Whether to use the field's name when validating against the provided input data.
+            trace: Whether to record the steps taken during validation, if `True` a `(value, report)` tuple is
+                returned where [`report.trace`][pydantic_core.ValidationReport.trace] is a list of dicts describing,
+                in order, which validators were entered, which union branches were attempted and why they were
+                rejected. If validation fails the steps taken are available as
+                [`ValidationError.trace`][pydantic_core.ValidationError.trace].
+            memoize: Whether to validate each python object used as a model, dataclass or typed dict input only once,
+                so inputs which contain many references to the same object are validated once per object. Only
+                outputs which can't be mutated through another reference are reused: instances of frozen models and
+                dataclasses are shared, typed dicts are copied, and only if all their values are immutable (scalars,
+                tuples or frozen sets of them, or such instances). Outputs depending on more than the input, e.g. of
+                functional validators taking `info` or default factories, are never reused.
+            coercions: Whether to record every lax conversion performed, e.g. `'123'` to an int or a float to a
+                datetime, if `True` a `(value, report)` tuple is returned where
+                [`report.coercions`][pydantic_core.ValidationReport.coercions] is a list of dicts with `loc`,
+                `input`, `input_type` and `target` keys.
+            suppressed_errors: Whether to record the errors recovered from by `on_error='default'` or `'omit'`,
+                if `True` a `(value, report)` tuple is returned where
+                [`report.suppressed_errors`][pydantic_core.ValidationReport.suppressed_errors] is a list of error
+                dicts, as from [`ValidationError.errors()`][pydantic_core.ValidationError.errors].
+            alias_hits: Whether to record the alias each field of a model, dataclass or typed dict was found under,
+                if `True` a `(value, report)` tuple is returned where
+                [`report.alias_hits`][pydantic_core.ValidationReport.alias_hits] is a list of dicts with `loc` and
+                `alias` keys, `alias` being a `str`, or a list for an alias path.
 
         Raises:
             ValidationError: If validation fails.
             Exception: Other error types maybe raised if internal errors occur.
 
         Returns:
-            The validated object.
+            The validated object, or a `(value, report)` tuple if `trace`, `coercions`, `suppressed_errors` or
+            `alias_hits` is set.
+        """
+    def try_validate_python(
+        self,
+        input: Any,
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+        self_instance: Any | None = None,
+        allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> ValidationResult:
+        """
+        Validate a Python object against the schema like
+        [`validate_python`][pydantic_core.SchemaValidator.validate_python], but return the outcome as a
+        [`ValidationResult`][pydantic_core.ValidationResult] instead of raising a `ValidationError` if validation fails.
+
+        Arguments are the same as for [`validate_python`][pydantic_core.SchemaValidator.validate_python].
+
+        Raises:
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            A `ValidationResult` holding either the validated object or the validation errors.
+        """
+    def validate_python_arrow(
+        self,
+        input: Any,
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> ArrowArray:
+        """
+        Validate a Python object against a list schema and return the result as an
+        [`ArrowArray`][pydantic_core.ArrowArray], so DataFrame libraries can ingest it via the Arrow PyCapsule
+        interface without converting each item from a Python object again.
+
+        Only `list` schemas with `int`, `float`, `bool` or `str` items, optionally wrapped in a `nullable` schema,
+        are supported. Arguments match `validate_python()`.
+
+        Raises:
+            ValidationError: If validation fails.
+            TypeError: If the schema can't be exported to arrow.
+            OverflowError: If an integer doesn't fit in an int64.
+
+        Returns:
+            The validated data as an `ArrowArray`.
+        """
+    def validate_json_arrow(
+        self,
+        input: str | bytes | bytearray | memoryview,
+        *,
+        strict: bool | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> ArrowArray:
+        """
+        Like [`validate_python_arrow()`][pydantic_core.SchemaValidator.validate_python_arrow] but validates
+        JSON data, arguments match `validate_json()`.
+        """
+    def matches(
+        self,
+        input: Any,
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> str | None:
+        """
+        Validate `input` against a top-level union schema and return which choice it matched, rather than the
+        validated object.
+
+        For a `union` schema this is the label of the choice selected by validation (the choice's name if it has
+        no label), for a `tagged-union` schema it's the string form of the tag.
+
+        This is a full validation of `input`: every choice tried is validated in full, collecting all of its
+        errors and building its output, which is then discarded. It costs the same as `validate_python()`.
+
+        Arguments match `validate_python()`. This method will not raise `ValidationError`s but will raise internal
+        errors.
+
+        Raises:
+            TypeError: If the schema is not a `union` or `tagged-union` schema.
+
+        Returns:
+            The name of the matching choice, or `None` if no choice matches.
         """
     def isinstance_python(
         self,
@@ -148,14 +289,18 @@ class SchemaValidator:
         """
     def validate_json(
         self,
-        input: str | bytes | bytearray,
+        input: str | bytes | bytearray | memoryview | Iterator[bytes | bytearray | memoryview],
         *,
         strict: bool | None = None,
+        strict_overrides: dict[str | tuple[str, ...], bool] | None = None,
         context: Any | None = None,
         self_instance: Any | None = None,
         allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
         by_alias: bool | None = None,
         by_name: bool | None = None,
+        coercions: bool = False,
+        suppressed_errors: bool = False,
+        alias_hits: bool = False,
     ) -> Any:
         """
         Validate JSON data directly against the schema and return the validated Python object.
@@ -167,9 +312,14 @@ class SchemaValidator:
         `validate_python(json.loads(json_data))` would fail validation.
 
         Arguments:
-            input: The JSON data to validate.
+            input: The JSON data to validate, any object supporting the buffer protocol with a byte format
+                (e.g. `memoryview`) is read without copying where possible. An iterable of byte chunks (e.g. a list
+                or a generator over an HTTP body) is also accepted, each item of a top level JSON array or object is
+                parsed as soon as it's been read and its data dropped, so the whole document isn't held in memory.
             strict: Whether to validate the object in strict mode.
                 If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            strict_overrides: Strictness of individual fields, see
+                [`validate_python`][pydantic_core.SchemaValidator.validate_python].
             context: The context to use for validation, this is passed to functional validators as
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
             self_instance: An instance of a model set attributes on from validation.
@@ -178,19 +328,27 @@ class SchemaValidator:
                 `'trailing-strings'` means any final unfinished JSON string is included in the result.
             by_alias: Whether to use the field's alias when validating against the provided input data.
             by_name: Whether to use the field's name when validating against the provided input data.
+            coercions: Whether to record every lax conversion performed, see
+                [`validate_python`][pydantic_core.SchemaValidator.validate_python].
+            suppressed_errors: Whether to record the errors recovered from by `on_error='default'` or `'omit'`, see
+                [`validate_python`][pydantic_core.SchemaValidator.validate_python].
+            alias_hits: Whether to record the alias each field was found under, see
+                [`validate_python`][pydantic_core.SchemaValidator.validate_python].
 
         Raises:
             ValidationError: If validation fails or if the JSON data is invalid.
             Exception: Other error types maybe raised if internal errors occur.
 
         Returns:
-            The validated Python object.
+            The validated Python object, or a `(value, report)` tuple if `coercions`, `suppressed_errors` or
+            `alias_hits` is set.
         """
     def validate_strings(
         self,
         input: _StringInput,
         *,
         strict: bool | None = None,
+        strict_overrides: dict[str | tuple[str, ...], bool] | None = None,
         context: Any | None = None,
         allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
         by_alias: bool | None = None,
@@ -206,6 +364,8 @@ class SchemaValidator:
             input: The input as a string, or bytes/bytearray if `strict=False`.
             strict: Whether to validate the object in strict mode.
                 If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            strict_overrides: Strictness of individual fields, see
+                [`validate_python`][pydantic_core.SchemaValidator.validate_python].
             context: The context to use for validation, this is passed to functional validators as
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
             allow_partial: Whether to allow partial validation; if `True` errors in the last element of sequences
@@ -256,6 +416,105 @@ class SchemaValidator:
         Returns:
             Either the model dict or a tuple of `(model_data, model_extra, fields_set)`
         """
+    def validate_assignments(
+        self,
+        obj: Any,
+        assignments: dict[str, Any],
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> Any:
+        """
+        Validate assignments to several fields of a model at once.
+
+        Unlike calling [`validate_assignment`][pydantic_core.SchemaValidator.validate_assignment] for each field,
+        model validators run once, `__pydantic_fields_set__` is updated once, and `obj` is only updated if every
+        value is valid.
+
+        Arguments:
+            obj: The model instance being assigned to.
+            assignments: The values to assign, keyed by field name.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes.
+                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            by_alias: Whether to use the field's alias when validating against the provided input data.
+            by_name: Whether to use the field's name when validating against the provided input data.
+
+        Raises:
+            ValidationError: If validation fails, with an error for each invalid value.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            The model instance.
+        """
+    def apply_merge_patch(
+        self,
+        instance: Any,
+        patch: str | bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        context: Any | None = None,
+    ) -> Any:
+        """
+        Apply a [JSON merge patch](https://www.rfc-editor.org/rfc/rfc7386) to `instance` and validate the result.
+
+        Objects in the patch are merged recursively into the current state of `instance`, where dicts, models and
+        dataclasses are treated as objects. `null` removes a key, so the field takes its default on validation or
+        is reported as missing. Any other value replaces the current one. `instance` isn't modified.
+
+        Fields are matched by name, as well as by alias. Models keep the fields set on `instance`, plus those set
+        by the patch, less those it removed.
+
+        Arguments:
+            instance: The current value, e.g. a model instance.
+            patch: The JSON merge patch document.
+            strict: Whether to validate the patched state in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            ValidationError: If the patched state fails validation.
+            ValueError: If `patch` isn't valid JSON.
+
+        Returns:
+            The validated patched value, e.g. a new model instance.
+        """
+    def construct_python(
+        self,
+        data: Any,
+        *,
+        fields_set: set[str] | None = None,
+        context: Any | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
+    ) -> Any:
+        """
+        Create a model instance from trusted data without validating it, as `BaseModel.model_construct()` does.
+
+        Values are used as they are, including for nested models. Fields which are missing take their default if
+        they have one, without validating it, otherwise they're left unset. Functional validators aren't called,
+        `post_init` is.
+
+        Arguments:
+            data: The field values for a model, or the root value for a root model.
+            fields_set: The value of `__pydantic_fields_set__`, by default the fields found in `data`.
+            context: The context passed to `post_init`.
+            by_alias: Whether to look up fields in `data` by their aliases.
+            by_name: Whether to look up fields in `data` by their names.
+
+        Raises:
+            TypeError: If the schema isn't for a model, or `data` isn't a mapping.
+
+        Returns:
+            The model instance.
+        """
     def get_default_value(self, *, strict: bool | None = None, context: Any = None) -> Some | None:
         """
         Get the default value for the schema, including running default value validation.
@@ -273,11 +532,72 @@ class SchemaValidator:
         Returns:
             `None` if the schema has no default value, otherwise a [`Some`][pydantic_core.Some] containing the default.
         """
+    def dump_schema(self) -> CoreSchema:
+        """
+        Get the core schema this validator was built from, in a normalized form.
+
+        Any nested `definitions` schemas are replaced by their inner schema and all definitions are collected
+        into a single `definitions` schema at the root, so tooling can introspect the validator without keeping
+        the original schema around.
+
+        Returns:
+            A new schema dict, parts of the schema which didn't need normalizing are shared with the original.
+        """
 
 # In reality, `bool` should be replaced by `Literal[True]` but mypy fails to correctly apply bidirectional type inference
 # (e.g. when using `{'a': {'b': True}}`).
 _IncEx: TypeAlias = set[int] | set[str] | Mapping[int, _IncEx | bool] | Mapping[str, _IncEx | bool]
 
+@final
+class ValidationResult:
+    """
+    The outcome of [`SchemaValidator.try_validate_python`][pydantic_core.SchemaValidator.try_validate_python].
+    """
+
+    @property
+    def ok(self) -> bool:
+        """
+        Whether validation succeeded.
+        """
+    @property
+    def value(self) -> Any:
+        """
+        The validated value, or `None` if validation failed.
+        """
+    @property
+    def error(self) -> ValidationError | None:
+        """
+        The validation error, or `None` if validation succeeded.
+        """
+    @property
+    def errors(self) -> list[ErrorDetails]:
+        """
+        Details about each error if validation failed, otherwise an empty list. Errors are only rendered
+        when this is accessed.
+        """
+
+@final
+class ArrowArray:
+    """
+    The output of [`SchemaValidator.validate_python_arrow()`][pydantic_core.SchemaValidator.validate_python_arrow],
+    implementing the [Arrow PyCapsule interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html),
+    e.g. `pyarrow.array(arrow_array)`.
+    """
+
+    @property
+    def type(self) -> Literal['int64', 'float64', 'bool', 'utf8']:
+        """
+        The arrow type of the array.
+        """
+    @property
+    def null_count(self) -> int:
+        """
+        The number of null items in the array.
+        """
+    def __len__(self) -> int: ...
+    def __arrow_c_schema__(self) -> Any: ...
+    def __arrow_c_array__(self, requested_schema: Any | None = None) -> tuple[Any, Any]: ...
+
 @final
 class SchemaSerializer:
     """
@@ -310,6 +630,8 @@ class SchemaSerializer:
         fallback: Callable[[Any], Any] | None = None,
         serialize_as_any: bool = False,
         context: Any | None = None,
+        serialize_secrets: bool = False,
+        missing_value: Any | None = None,
     ) -> Any:
         """
         Serialize/marshal a Python object to a Python object including transforming and filtering data.
@@ -333,6 +655,11 @@ class SchemaSerializer:
             serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
             context: The context to use for serialization, this is passed to functional serializers as
                 [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+            serialize_secrets: Whether to serialize [`Secret`][pydantic_core.Secret] values as their value,
+                rather than leaving them as `Secret`s.
+            missing_value: If set, model fields which are not in `__pydantic_fields_set__` are serialized as this
+                value instead of their current value, unless `exclude_unset` is set. The value is serialized by
+                inference rather than with the field's serializer.
 
         Raises:
             PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -356,6 +683,14 @@ class SchemaSerializer:
         fallback: Callable[[Any], Any] | None = None,
         serialize_as_any: bool = False,
         context: Any | None = None,
+        serialize_secrets: bool = False,
+        missing_value: Any | None = None,
+        sort_keys: bool = False,
+        set_order: Literal['insertion', 'sorted'] = 'insertion',
+        canonical: bool = False,
+        separators: tuple[str, str] | None = None,
+        newline: str | None = None,
+        ensure_ascii: bool = False,
     ) -> bytes:
         """
         Serialize a Python object to JSON including transforming and filtering data.
@@ -378,6 +713,22 @@ class SchemaSerializer:
             serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
             context: The context to use for serialization, this is passed to functional serializers as
                 [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+            serialize_secrets: Whether to serialize [`Secret`][pydantic_core.Secret] values as their value,
+                rather than the masked placeholder `'**********'`.
+            missing_value: If set, model fields which are not in `__pydantic_fields_set__` are serialized as this
+                value instead of their current value, unless `exclude_unset` is set. The value is serialized by
+                inference rather than with the field's serializer.
+            sort_keys: Whether to sort the keys of all JSON objects, including those from models and dataclasses.
+            set_order: How to order the elements of sets and frozensets, `'insertion'` uses iteration order,
+                `'sorted'` sorts elements by value, or by type name then `repr()` if the values can't be compared.
+            canonical: Whether to write [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785) canonical JSON,
+                with no whitespace, keys sorted by UTF-16 code units and numbers formatted as in JavaScript.
+                Integers which can't be represented exactly as a float and `inf`/`nan` (unless `ser_json_inf_nan`
+                is `'null'` or `'strings'`) raise an error. Can't be combined with the layout arguments below.
+            separators: An `(item_separator, key_separator)` tuple as in `json.dumps()`, if `None` the separators
+                are `(',', ':')`, or `(',', ': ')` when `indent` is set.
+            newline: The line break written before each indented line when `indent` is set, defaults to `'\\n'`.
+            ensure_ascii: Whether to escape all non-ASCII characters in strings as `\\uXXXX` sequences.
 
         Raises:
             PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -385,6 +736,58 @@ class SchemaSerializer:
         Returns:
            JSON bytes.
         """
+    def to_json_into(
+        self,
+        value: Any,
+        file: str | os.PathLike[str] | SupportsWrite[bytes],
+        *,
+        buffer_size: int = 65536,
+        indent: int | None = None,
+        include: _IncEx | None = None,
+        exclude: _IncEx | None = None,
+        by_alias: bool | None = None,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] = True,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+        serialize_secrets: bool = False,
+        missing_value: Any | None = None,
+        sort_keys: bool = False,
+        set_order: Literal['insertion', 'sorted'] = 'insertion',
+        canonical: bool = False,
+        separators: tuple[str, str] | None = None,
+        newline: str | None = None,
+        ensure_ascii: bool = False,
+    ) -> None:
+        """
+        Serialize a Python object to JSON, writing it to a file as it's serialized rather than
+        building a single `bytes` object as [`to_json`][pydantic_core.SchemaSerializer.to_json] does.
+
+        If serialization fails part of the JSON may already have been written to a file-like object,
+        a path is only replaced once the whole value has been written, so it's left unchanged.
+
+        Arguments:
+            value: The Python object to serialize.
+            file: A path to write to, JSON is written to a temporary file in the same directory which then
+                replaces it, or a binary file-like object with a `write()` method.
+            buffer_size: How many bytes are collected before they're written to the file.
+            **kwargs: The remaining arguments are the same as for
+                [`to_json`][pydantic_core.SchemaSerializer.to_json].
+
+        Raises:
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+            OSError: If the file can't be opened or written to, errors raised by `write()` are raised unchanged.
+        """
+    def dump_schema(self) -> CoreSchema:
+        """
+        Get the core schema this serializer was built from, in a normalized form.
+
+        See [`SchemaValidator.dump_schema()`][pydantic_core.SchemaValidator.dump_schema] for details.
+        """
 
 def to_json(
     value: Any,
@@ -398,13 +801,21 @@ def to_json(
     by_alias: bool = True,
     exclude_none: bool = False,
     round_trip: bool = False,
-    timedelta_mode: Literal['iso8601', 'float'] = 'iso8601',
-    bytes_mode: Literal['utf8', 'base64', 'hex'] = 'utf8',
-    inf_nan_mode: Literal['null', 'constants', 'strings'] = 'constants',
+    timedelta_mode: Literal['iso8601', 'float', 'milliseconds_int', 'hh:mm:ss', 'human'] = 'iso8601',
+    bytes_mode: Literal['utf8', 'base64', 'base64url', 'base32', 'hex'] = 'utf8',
+    inf_nan_mode: Literal['null', 'constants', 'strings', 'error'] = 'constants',
+    temporal_format: Literal['iso8601', 'iso8601_basic', 'rfc2822', 'epoch_millis'] | str = 'iso8601',
     serialize_unknown: bool = False,
     fallback: Callable[[Any], Any] | None = None,
     serialize_as_any: bool = False,
     context: Any | None = None,
+    serialize_secrets: bool = False,
+    sort_keys: bool = False,
+    set_order: Literal['insertion', 'sorted'] = 'insertion',
+    canonical: bool = False,
+    separators: tuple[str, str] | None = None,
+    newline: str | None = None,
+    ensure_ascii: bool = False,
 ) -> bytes:
     """
     Serialize a Python object to JSON including transforming and filtering data.
@@ -419,9 +830,13 @@ def to_json(
         by_alias: Whether to use the alias names of fields.
         exclude_none: Whether to exclude fields that have a value of `None`.
         round_trip: Whether to enable serialization and validation round-trip support.
-        timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'` or `'float'`.
-        bytes_mode: How to serialize `bytes` objects, either `'utf8'`, `'base64'`, or `'hex'`.
-        inf_nan_mode: How to serialize `Infinity`, `-Infinity` and `NaN` values, either `'null'`, `'constants'`, or `'strings'`.
+        timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'`, `'float'`, `'milliseconds_int'`,
+            `'hh:mm:ss'` or `'human'`, see `ser_json_timedelta` in `CoreConfig`.
+        bytes_mode: How to serialize `bytes` objects, either `'utf8'`, `'base64'`, `'base64url'`, `'base32'` or `'hex'`.
+        inf_nan_mode: How to serialize `Infinity`, `-Infinity` and `NaN` values, either `'null'`, `'constants'`, `'strings'`,
+            or `'error'` to raise a `PydanticSerializationError`.
+        temporal_format: How to serialize `datetime`, `date` and `time` objects, see `ser_json_temporal_format`
+            in `CoreConfig`.
         serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
             `"<Unserializable {value_type} object>"` will be used.
         fallback: A function to call when an unknown value is encountered,
@@ -429,6 +844,17 @@ def to_json(
         serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
         context: The context to use for serialization, this is passed to functional serializers as
             [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+        serialize_secrets: Whether to serialize [`Secret`][pydantic_core.Secret] values as their value,
+            rather than the masked placeholder `'**********'`.
+        sort_keys: Whether to sort the keys of all JSON objects.
+        set_order: How to order the elements of sets and frozensets, `'insertion'` uses iteration order,
+            `'sorted'` sorts elements by value, or by type name then `repr()` if the values can't be compared.
+        canonical: Whether to write [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785) canonical JSON,
+            see [`SchemaSerializer.to_json`][pydantic_core.SchemaSerializer.to_json].
+        separators: An `(item_separator, key_separator)` tuple as in `json.dumps()`, if `None` the separators
+            are `(',', ':')`, or `(',', ': ')` when `indent` is set.
+        newline: The line break written before each indented line when `indent` is set, defaults to `'\\n'`.
+        ensure_ascii: Whether to escape all non-ASCII characters in strings as `\\uXXXX` sequences.
 
     Raises:
         PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -438,7 +864,7 @@ def to_json(
     """
 
 def from_json(
-    data: str | bytes | bytearray,
+    data: str | bytes | bytearray | memoryview,
     *,
     allow_inf_nan: bool = True,
     cache_strings: bool | Literal['all', 'keys', 'none'] = True,
@@ -477,13 +903,17 @@ def to_jsonable_python(
     by_alias: bool = True,
     exclude_none: bool = False,
     round_trip: bool = False,
-    timedelta_mode: Literal['iso8601', 'float'] = 'iso8601',
-    bytes_mode: Literal['utf8', 'base64', 'hex'] = 'utf8',
-    inf_nan_mode: Literal['null', 'constants', 'strings'] = 'constants',
+    timedelta_mode: Literal['iso8601', 'float', 'milliseconds_int', 'hh:mm:ss', 'human'] = 'iso8601',
+    bytes_mode: Literal['utf8', 'base64', 'base64url', 'base32', 'hex'] = 'utf8',
+    inf_nan_mode: Literal['null', 'constants', 'strings', 'error'] = 'constants',
+    temporal_format: Literal['iso8601', 'iso8601_basic', 'rfc2822', 'epoch_millis'] | str = 'iso8601',
     serialize_unknown: bool = False,
     fallback: Callable[[Any], Any] | None = None,
     serialize_as_any: bool = False,
     context: Any | None = None,
+    serialize_secrets: bool = False,
+    sort_keys: bool = False,
+    set_order: Literal['insertion', 'sorted'] = 'insertion',
 ) -> Any:
     """
     Serialize/marshal a Python object to a JSON-serializable Python object including transforming and filtering data.
@@ -498,9 +928,13 @@ def to_jsonable_python(
         by_alias: Whether to use the alias names of fields.
         exclude_none: Whether to exclude fields that have a value of `None`.
         round_trip: Whether to enable serialization and validation round-trip support.
-        timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'` or `'float'`.
-        bytes_mode: How to serialize `bytes` objects, either `'utf8'`, `'base64'`, or `'hex'`.
-        inf_nan_mode: How to serialize `Infinity`, `-Infinity` and `NaN` values, either `'null'`, `'constants'`, or `'strings'`.
+        timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'`, `'float'`, `'milliseconds_int'`,
+            `'hh:mm:ss'` or `'human'`, see `ser_json_timedelta` in `CoreConfig`.
+        bytes_mode: How to serialize `bytes` objects, either `'utf8'`, `'base64'`, `'base64url'`, `'base32'` or `'hex'`.
+        inf_nan_mode: How to serialize `Infinity`, `-Infinity` and `NaN` values, either `'null'`, `'constants'`, `'strings'`,
+            or `'error'` to raise a `PydanticSerializationError`.
+        temporal_format: How to serialize `datetime`, `date` and `time` objects, see `ser_json_temporal_format`
+            in `CoreConfig`.
         serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
             `"<Unserializable {value_type} object>"` will be used.
         fallback: A function to call when an unknown value is encountered,
@@ -508,6 +942,11 @@ def to_jsonable_python(
         serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
         context: The context to use for serialization, this is passed to functional serializers as
             [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+        serialize_secrets: Whether to serialize [`Secret`][pydantic_core.Secret] values as their value,
+            rather than the masked placeholder `'**********'`.
+        sort_keys: Whether to sort the keys of all JSON objects.
+        set_order: How to order the elements of sets and frozensets, `'insertion'` uses iteration order,
+            `'sorted'` sorts elements by value, or by type name then `repr()` if the values can't be compared.
 
     Raises:
         PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -516,6 +955,65 @@ def to_jsonable_python(
         The serialized Python object.
     """
 
+def copy_validated(value: Any, *, deep: bool = True) -> Any:
+    """
+    Copy a validated value, as `copy.deepcopy()` or `copy.copy()` would but without the overhead
+    of `__deepcopy__` calls for each nested model.
+
+    Models are copied as `BaseModel.__copy__` and `__deepcopy__` do, including `__pydantic_fields_set__`,
+    `__pydantic_extra__` and `__pydantic_private__`, without calling `__init__` or validating. Builtin containers
+    are rebuilt, immutable builtin values are shared, and any other value is passed to `copy.deepcopy()`,
+    as are models whose class overrides `__deepcopy__` (or `__copy__` with `deep=False`).
+
+    Arguments:
+        value: The value to copy.
+        deep: Whether to copy nested values, with `False` only a model's own attributes are copied
+            and any other value is passed to `copy.copy()`.
+
+    Raises:
+        RecursionError: If `value` is nested more than 1000 levels deep.
+
+    Returns:
+        The copy of `value`.
+    """
+
+def register_ob_type(
+    cls: type[Any],
+    ob_type: Literal['int', 'float', 'decimal', 'path', 'str', 'bytes', 'datetime', 'date', 'time', 'timedelta'],
+) -> None:
+    """
+    Register a type to be treated like a builtin type when serializing by inference (e.g. with `any_schema`,
+    [`to_json`][pydantic_core.to_json] and [`to_jsonable_python`][pydantic_core.to_jsonable_python]) and when
+    checking values in serializers, rather than falling back to slower checks or warning.
+
+    `'int'` and `'decimal'` registrations also apply to validation in lax mode. Registrations are global and
+    apply to subclasses of `cls`.
+
+    ```py
+    from pydantic_core import register_ob_type, to_json
+
+    class MyInt:
+        def __init__(self, value):
+            self.value = value
+
+        def __index__(self):
+            return self.value
+
+    register_ob_type(MyInt, 'int')
+    assert to_json([MyInt(1), MyInt(2)]) == b'[1,2]'
+    ```
+
+    Args:
+        cls: The type to register.
+        ob_type: The builtin type `cls` should be treated as, values are converted with `__index__` for `'int'`,
+            `__float__` for `'float'` and `str()` for `'decimal'` and `'path'`, for other types `cls` must be a
+            subclass of the builtin type.
+
+    Raises:
+        ValueError: If `ob_type` isn't supported.
+        TypeError: If `cls` must be, but isn't, a subclass of the builtin type.
+    """
+
 class Url(SupportsAllComparisons):
     """
     A URL type, internal logic uses the [url rust crate](https://docs.rs/url/latest/url/) originally developed
@@ -540,8 +1038,14 @@ class Url(SupportsAllComparisons):
     @property
     def query(self) -> str | None: ...
     def query_params(self) -> list[tuple[str, str]]: ...
+    def with_query(self, query: str | Mapping[str, str] | Sequence[tuple[str, str]] | None) -> Self: ...
+    def join(self, url: str) -> Self: ...
     @property
     def fragment(self) -> str | None: ...
+    @property
+    def original(self) -> str | None: ...
+    @property
+    def is_relative(self) -> bool: ...
     def unicode_string(self) -> str: ...
     def __repr__(self) -> str: ...
     def __str__(self) -> str: ...
@@ -577,8 +1081,11 @@ class MultiHostUrl(SupportsAllComparisons):
     @property
     def query(self) -> str | None: ...
     def query_params(self) -> list[tuple[str, str]]: ...
+    def with_query(self, query: str | Mapping[str, str] | Sequence[tuple[str, str]] | None) -> Self: ...
     @property
     def fragment(self) -> str | None: ...
+    @property
+    def original(self) -> str | None: ...
     def hosts(self) -> list[MultiHostHost]: ...
     def unicode_string(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -599,6 +1106,54 @@ class MultiHostUrl(SupportsAllComparisons):
         fragment: str | None = None,
     ) -> Self: ...
 
+class EmailAddress(SupportsAllComparisons):
+    """
+    An email address validated per RFC 5321, and RFC 6531 for internationalized addresses.
+
+    The domain is normalized to lowercase, internationalized domain names are handled via IDNA.
+    """
+
+    def __init__(self, email: str) -> None: ...
+    def __new__(cls, email: str) -> Self: ...
+    @property
+    def local_part(self) -> str:
+        """The part before the @-sign, as given."""
+    @property
+    def domain(self) -> str:
+        """The part after the @-sign, lowercase with internationalized labels decoded."""
+    @property
+    def ascii_domain(self) -> str:
+        """The part after the @-sign, lowercase with internationalized labels punycode encoded."""
+    def ascii_email(self) -> str | None:
+        """
+        Returns:
+            The email address with the domain punycode encoded, or `None` if the local part
+                contains internationalized characters.
+        """
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __hash__(self) -> int: ...
+    def __deepcopy__(self, memo: dict) -> Self: ...
+
+@final
+class Secret(Generic[_SecretT]):
+    """
+    A secret `str` or `bytes` value, masked in its `repr` and `str`, validation errors and serialized output.
+    """
+
+    def __new__(cls, value: _SecretT) -> Self: ...
+    def get_secret_value(self) -> _SecretT:
+        """
+        Returns:
+            The unmasked value of the secret.
+        """
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def __len__(self) -> int: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+    def __deepcopy__(self, memo: dict) -> Self: ...
+
 @final
 class SchemaError(Exception):
     """
@@ -617,6 +1172,37 @@ class SchemaError(Exception):
             A list of [`ErrorDetails`][pydantic_core.ErrorDetails] for each error in the schema.
         """
 
+@final
+class ValidationReport:
+    """
+    Returned alongside the validated value by
+    [`SchemaValidator.validate_python`][pydantic_core.SchemaValidator.validate_python] and
+    [`SchemaValidator.validate_json`][pydantic_core.SchemaValidator.validate_json] when `trace`, `coercions`,
+    `suppressed_errors` or `alias_hits` is set.
+    """
+
+    @property
+    def trace(self) -> list[dict[str, Any]] | None:
+        """
+        The steps taken during validation, or `None` unless validating with `trace=True`.
+        """
+    @property
+    def coercions(self) -> list[dict[str, Any]] | None:
+        """
+        The lax conversions performed during validation, or `None` unless validating with `coercions=True`.
+        """
+    @property
+    def suppressed_errors(self) -> list[ErrorDetails] | None:
+        """
+        The errors recovered from by `on_error='default'` or `'omit'`, or `None` unless validating with
+        `suppressed_errors=True`.
+        """
+    @property
+    def alias_hits(self) -> list[dict[str, Any]] | None:
+        """
+        The alias each field was found under, or `None` unless validating with `alias_hits=True`.
+        """
+
 class ValidationError(ValueError):
     """
     `ValidationError` is the exception raised by `pydantic-core` when validation fails, it contains a list of errors
@@ -648,13 +1234,36 @@ class ValidationError(ValueError):
         """
         The title of the error, as used in the heading of `str(validation_error)`.
         """
+    @property
+    def schema_title(self) -> str | None:
+        """
+        The [`title`][pydantic_core.SchemaValidator.title] of the `SchemaValidator` which raised the error,
+        `None` if the error wasn't raised by a `SchemaValidator`, e.g. it was created with `from_exception_data()`.
+        """
+    @property
+    def schema_hash(self) -> int | None:
+        """
+        The [`schema_hash`][pydantic_core.SchemaValidator.schema_hash] of the `SchemaValidator` which raised
+        the error, `None` if the error wasn't raised by a `SchemaValidator`.
+        """
+    @property
+    def trace(self) -> list[dict[str, Any]] | None:
+        """
+        The steps taken before validation failed, `None` unless the error was raised by
+        [`SchemaValidator.validate_python`][pydantic_core.SchemaValidator.validate_python] with `trace=True`.
+        """
     def error_count(self) -> int:
         """
         Returns:
             The number of errors in the validation error.
         """
     def errors(
-        self, *, include_url: bool = True, include_context: bool = True, include_input: bool = True
+        self,
+        *,
+        include_url: bool = True,
+        include_context: bool = True,
+        include_input: bool = True,
+        min_severity: Literal['info', 'warning', 'error'] | None = None,
     ) -> list[ErrorDetails]:
         """
         Details about each error in the validation error.
@@ -663,6 +1272,9 @@ class ValidationError(ValueError):
             include_url: Whether to include a URL to documentation on the error each error.
             include_context: Whether to include the context of each error.
             include_input: Whether to include the input value of each error.
+            min_severity: Only include errors at least this severe, e.g. `'error'` to leave out the warnings
+                raised with [`PydanticCustomError`][pydantic_core.PydanticCustomError], by default all errors
+                are included.
 
         Returns:
             A list of [`ErrorDetails`][pydantic_core.ErrorDetails] for each error in the validation error.
@@ -719,15 +1331,26 @@ class PydanticCustomError(ValueError):
         error_type: The error type.
         message_template: The message template.
         context: The data to inject into the message template.
+        severity: How serious the error is, errors with a severity other than `'error'` still fail validation but
+            can be filtered out with [`ValidationError.errors(min_severity=...)`][pydantic_core.ValidationError.errors].
     """
 
     def __init__(
-        self, error_type: LiteralString, message_template: LiteralString, context: dict[str, Any] | None = None, /
+        self,
+        error_type: LiteralString,
+        message_template: LiteralString,
+        context: dict[str, Any] | None = None,
+        severity: Literal['info', 'warning', 'error'] = 'error',
+        /,
     ) -> None: ...
     @property
     def context(self) -> dict[str, Any] | None:
         """Values which are required to render the error message, and could hence be useful in passing error data forward."""
 
+    @property
+    def severity(self) -> Literal['info', 'warning', 'error']:
+        """How serious the error is."""
+
     @property
     def type(self) -> str:
         """The error type associated with the error. For consistency with Pydantic, this is typically a snake_case string."""
@@ -963,6 +1586,22 @@ class ArgsKwargs:
     def kwargs(self) -> dict[str, Any] | None:
         """The keyword arguments for a function call."""
 
+@final
+class ModelFieldGetter:
+    """
+    Reads a field of a model instance straight from the instance storage, without going through `__getattr__`.
+
+    Like [`operator.attrgetter`][], `ModelFieldGetter('a')(instance)` returns the validated value of field `a`
+    (or extra `a`) of `instance`.
+    """
+
+    def __new__(cls, name: str, /) -> Self: ...
+    def __call__(self, instance: Any, /) -> Any:
+        """
+        Raises:
+            AttributeError: If `instance` has no field or extra called `name`.
+        """
+
 @final
 class PydanticUndefinedType:
     """A type used as a sentinel for undefined values."""
@@ -7,9 +7,10 @@ from __future__ import annotations as _annotations
 
 import sys
 import warnings
-from collections.abc import Hashable, Mapping
+from collections.abc import Hashable, Iterable, Mapping
 from datetime import date, datetime, time, timedelta
 from decimal import Decimal
+from fractions import Fraction
 from re import Pattern
 from typing import TYPE_CHECKING, Any, Callable, Literal, Union
 
@@ -25,6 +26,11 @@ if sys.version_info < (3, 11):
 else:
     from typing import Protocol, Required, TypeAlias
 
+if sys.version_info < (3, 10):
+    EllipsisType = type(Ellipsis)
+else:
+    from types import EllipsisType
+
 if TYPE_CHECKING:
     from pydantic_core import PydanticUndefined
 else:
@@ -59,12 +65,48 @@ class CoreConfig(TypedDict, total=False):
         str_strip_whitespace: Whether to strip whitespace from string fields.
         str_to_lower: Whether to convert string fields to lowercase.
         str_to_upper: Whether to convert string fields to uppercase.
+        str_to_title: Whether to convert string fields to title case, like `str.title()`.
+        str_to_snake: Whether to convert string fields to snake_case.
+        str_to_camel: Whether to convert string fields to camelCase.
+        str_normalize: The unicode normalization form to apply to string fields, before length constraints are checked.
+        str_casefold: Whether to casefold string fields, like `str.casefold()`, before length constraints are checked.
         allow_inf_nan: Whether to allow infinity and NaN values for float fields. Default is `True`.
         ser_json_timedelta: The serialization option for `timedelta` values. Default is 'iso8601'.
+            'milliseconds_int' gives whole milliseconds (rounded down) as an integer, 'hh:mm:ss' a clock style
+            string like `26:03:04` with hours not wrapped at a day, and 'human' a string like `1d 2h 3m 4.5s`.
         ser_json_bytes: The serialization option for `bytes` values. Default is 'utf8'.
+            'base64' uses the URL-safe alphabet with padding, 'base64url' the URL-safe alphabet without padding
+            and 'base32' the RFC 4648 base32 alphabet with padding.
         ser_json_inf_nan: The serialization option for infinity and NaN values
-            in float fields. Default is 'null'.
+            in float fields. Default is 'null', 'error' raises a `PydanticSerializationError`, including when
+            serializing to python with `mode='json'`.
+            Can be overridden for a single field with `ser_json_inf_nan` on `float_schema`.
+        ser_json_temporal_format: The serialization option for `datetime`, `date` and `time` values. Default is
+            'iso8601', the presets 'iso8601_basic' (e.g. `20240102T030405Z`), 'rfc2822' (e.g.
+            `Tue, 02 Jan 2024 03:04:05 +0000`) and 'epoch_millis' (an integer, naive values are taken to be UTC)
+            are also supported, otherwise a strftime style pattern using the directives supported by `formats`
+            on `datetime_schema`.
+        ser_json_uuid: The serialization option for `UUID` values. Default is 'str', 'int' gives the 128-bit
+            integer form, `UUID.int`.
+        ser_json_float: The serialization option for finite `float` values. Default is 'repr', the shortest
+            representation which round trips, 'scientific' always uses an exponent (e.g. `1.5e3`), and an integer
+            rounds to that many decimal places, also when serializing to python with `mode='json'`.
+            Can be overridden for a single field with `ser_json_float` on `float_schema`.
+        ser_json_invalid_utf8: How strings containing lone surrogates, which can't be encoded as UTF-8, are
+            serialized to JSON. Default is 'error', 'replace' replaces each surrogate with U+FFFD (also when
+            serializing to python with `mode='json'`) and 'surrogatepass' writes them as `\\uXXXX` escapes as
+            `json.dumps()` does. Dict keys containing lone surrogates are always replaced, and are an error
+            with 'surrogatepass'.
+        ser_enum: How enum members are serialized. Default is 'object', which keeps members when serializing
+            to python and uses their `value` in JSON, 'value' uses `value` in both and 'name' uses `name` in both.
+            Can be overridden for a single enum with `ser_enum` on `enum_schema`.
+        ser_json_bigint: The serialization option for integers outside the range JavaScript can represent exactly,
+            ±(2**53 - 1). Default is 'int', which leaves them unchanged, 'string' serializes them as strings and
+            'error' raises a `PydanticSerializationError`. Also applies when serializing to python with `mode='json'`.
         val_json_bytes: The validation option for `bytes` values, complementing ser_json_bytes. Default is 'utf8'.
+            Padding is optional when decoding any of the base64 or base32 modes.
+        ser_warn_lax_retry: Whether to add a serializer warning, with a count and location, each time a union only
+            matches a value after retrying its choices with a lax check. Default is `False`.
         hide_input_in_errors: Whether to hide input data from `ValidationError` representation.
         validation_error_cause: Whether to add user-python excs to the __cause__ of a ValidationError.
             Requires exceptiongroup backport pre Python 3.11.
@@ -75,6 +117,42 @@ class CoreConfig(TypedDict, total=False):
         validate_by_alias: Whether to use the field's alias when validating against the provided input data. Default is `True`.
         validate_by_name: Whether to use the field's name when validating against the provided input data. Default is `False`. Replacement for `populate_by_name`.
         serialize_by_alias: Whether to serialize by alias. Default is `False`, expected to change to `True` in V3.
+            When set on the config of a `SchemaSerializer` it's also the default for fields whose own config doesn't
+            set it, `by_alias` passed to `to_python` or `to_json` overrides both.
+        alias_generator: The case to generate aliases in from field names, for model, dataclass and typed dict fields
+            without an alias in the schema. Applies to validation and serialization (including computed fields),
+            leading underscores are kept.
+        serialization_alias_generator: Like `alias_generator` but for serialization only, takes precedence over
+            `alias_generator` when serializing.
+        max_total_items: The maximum number of items, summed over all lists, tuples, sets and dicts in the input to a
+            validation call. Default is no limit.
+        max_string_length_bytes: The maximum length in bytes (UTF-8 encoded for `str`) of any string or bytes value
+            in the input to a validation call. Default is no limit.
+        max_nesting_depth: The maximum depth lists, tuples, sets and dicts may be nested in the input to a validation
+            call. Default is no limit.
+
+            These limits are checked while JSON is parsed, and as validators reach each part of Python input, so
+            the contents of values which aren't validated (e.g. by an `any` schema) aren't checked.
+        config_merge: How the `config` of nested model, dataclass and typed dict schemas combines with this config.
+            `'replace'` uses the nested config on its own, ignoring this config, `'inherit'` uses keys from the nested
+            config first and falls back to this config, `'override'` uses keys from this config first and falls back
+            to the nested config. Default is `'replace'`.
+        list_strict_exact_type: Whether strict mode should reject subclasses of `list`. Default is `False`.
+        dict_strict_exact_type: Whether strict mode should reject subclasses of `dict`. Default is `False`.
+        str_strict_exact_type: Whether strict mode should reject subclasses of `str`. Default is `False`.
+        list_allow_array_like: Whether NumPy arrays and buffer protocol objects are accepted for lists, converting
+            them in a single call. Default is `False`.
+        field_order: The order fields are emitted in when serializing models, dataclasses and typed dicts.
+            `'insertion'` keeps the order of the value being serialized (e.g. `__dict__`), `'schema'` uses the order
+            of the fields in the schema (extra keys follow in insertion order) and `'alphabetical'` sorts by key.
+            Computed fields are always emitted last, sorted by name with `'alphabetical'` and otherwise in the order
+            they're defined. Default is `'insertion'`.
+        union_error_mode: Which errors unions report when no choice matches, see `UnionSchema.error_mode`.
+            Default is `'all'`.
+        coerce_int: Called in lax mode with inputs int fields can't convert, e.g. `'1,000'`, the returned value is
+            validated instead. Raise `ValueError` to reject the input.
+        coerce_float: Like `coerce_int`, for float fields.
+        coerce_decimal: Like `coerce_int`, for decimal fields.
     """
 
     title: str
@@ -98,13 +176,25 @@ class CoreConfig(TypedDict, total=False):
     str_strip_whitespace: bool
     str_to_lower: bool
     str_to_upper: bool
+    str_to_title: bool
+    str_to_snake: bool
+    str_to_camel: bool
+    str_normalize: Literal['NFC', 'NFKC', 'NFD', 'NFKD']
+    str_casefold: bool
     # fields related to float fields only
     allow_inf_nan: bool  # default: True
     # the config options are used to customise serialization to JSON
-    ser_json_timedelta: Literal['iso8601', 'float']  # default: 'iso8601'
-    ser_json_bytes: Literal['utf8', 'base64', 'hex']  # default: 'utf8'
-    ser_json_inf_nan: Literal['null', 'constants', 'strings']  # default: 'null'
-    val_json_bytes: Literal['utf8', 'base64', 'hex']  # default: 'utf8'
+    ser_json_timedelta: Literal['iso8601', 'float', 'milliseconds_int', 'hh:mm:ss', 'human']  # default: 'iso8601'
+    ser_json_bytes: Literal['utf8', 'base64', 'base64url', 'base32', 'hex']  # default: 'utf8'
+    ser_json_inf_nan: Literal['null', 'constants', 'strings', 'error']  # default: 'null'
+    ser_json_temporal_format: Union[Literal['iso8601', 'iso8601_basic', 'rfc2822', 'epoch_millis'], str]
+    ser_json_uuid: Literal['str', 'int']  # default: 'str'
+    ser_json_float: Union[Literal['repr', 'scientific'], int]  # default: 'repr'
+    ser_json_bigint: Literal['int', 'string', 'error']  # default: 'int'
+    ser_json_invalid_utf8: Literal['error', 'replace', 'surrogatepass']  # default: 'error'
+    ser_enum: Literal['object', 'value', 'name']  # default: 'object'
+    val_json_bytes: Literal['utf8', 'base64', 'base64url', 'base32', 'hex']  # default: 'utf8'
+    ser_warn_lax_retry: bool  # default: False
     # used to hide input data from ValidationError repr
     hide_input_in_errors: bool
     validation_error_cause: bool  # default: False
@@ -114,6 +204,24 @@ class CoreConfig(TypedDict, total=False):
     validate_by_alias: bool  # default: True
     validate_by_name: bool  # default: False
     serialize_by_alias: bool  # default: False
+    alias_generator: Literal['camel', 'pascal', 'snake', 'kebab']
+    serialization_alias_generator: Literal['camel', 'pascal', 'snake', 'kebab']
+    # limits on the whole input to a validation call, checked while parsing JSON and while validating python input
+    max_total_items: int
+    max_string_length_bytes: int
+    max_nesting_depth: int
+    # how nested schema configs combine with the parent config
+    config_merge: Literal['replace', 'inherit', 'override']  # default: 'replace'
+    # whether strict mode should reject subclasses, checking the exact type
+    list_strict_exact_type: bool  # default: False
+    dict_strict_exact_type: bool  # default: False
+    str_strict_exact_type: bool  # default: False
+    list_allow_array_like: bool  # default: False
+    field_order: Literal['schema', 'alphabetical', 'insertion']  # default: 'insertion'
+    union_error_mode: Literal['all', 'best-match', 'summarize']  # default: 'all'
+    coerce_int: Callable[[Any], Any]
+    coerce_float: Callable[[Any], Any]
+    coerce_decimal: Callable[[Any], Any]
 
 
 IncExCall: TypeAlias = 'set[int | str] | dict[int | str, IncExCall] | None'
@@ -196,12 +304,22 @@ class ValidationInfo(Protocol):
         """
         ...
 
+    @property
+    def field_path(self) -> tuple[Union[str, int], ...]:
+        """
+        The names of the model, dataclass and typed dict fields, and the indices of list, tuple and set items,
+        entered from the root of validation to the value being validated, e.g. `('addresses', 0, 'postcode')`.
+        Keys and values of mappings aren't included.
+        """
+        ...
+
 
 ExpectedSerializationTypes = Literal[
     'none',
     'int',
     'bool',
     'float',
+    'fraction',
     'str',
     'bytes',
     'bytearray',
@@ -211,14 +329,24 @@ ExpectedSerializationTypes = Literal[
     'frozenset',
     'generator',
     'dict',
+    'deque',
+    'counter',
+    'defaultdict',
     'datetime',
     'date',
     'time',
     'timedelta',
     'url',
     'multi-host-url',
+    'email',
     'json',
     'uuid',
+    'path',
+    'ip-address',
+    'ip-network',
+    'ip-interface',
+    'secret_str',
+    'secret_bytes',
     'any',
 ]
 
@@ -305,7 +433,16 @@ def plain_serializer_function_ser_schema(
 
 
 class SerializerFunctionWrapHandler(Protocol):  # pragma: no cover
-    def __call__(self, input_value: Any, index_key: int | str | None = None, /) -> Any: ...
+    def __call__(
+        self,
+        input_value: Any,
+        index_key: int | str | None = None,
+        /,
+        *,
+        include: IncExCall = None,
+        exclude: IncExCall = None,
+        only_fields: Iterable[str] | None = None,
+    ) -> Any: ...
 
 
 # (input_value: Any, serializer: SerializerFunctionWrapHandler, /) -> Any
@@ -346,6 +483,38 @@ def wrap_serializer_function_ser_schema(
     """
     Returns a schema for serialization with a wrap function, can be either a "general" or "field" function.
 
+    The `handler` passed to the function accepts `include` and `exclude` keyword arguments which replace
+    those of the current call, and `only_fields` to serialize just the given fields of a model, e.g.:
+
+    ```py
+    from pydantic_core import SchemaSerializer, core_schema
+
+    class User:
+        __pydantic_fields_set__ = {'name', 'password'}
+        __pydantic_extra__ = None
+        __pydantic_private__ = None
+
+        def __init__(self, name: str, password: str):
+            self.name = name
+            self.password = password
+
+    def public_fields(value, handler):
+        return handler(value, only_fields=['name'])
+
+    schema = core_schema.model_schema(
+        User,
+        core_schema.model_fields_schema(
+            {
+                'name': core_schema.model_field(core_schema.str_schema()),
+                'password': core_schema.model_field(core_schema.str_schema()),
+            }
+        ),
+        serialization=core_schema.wrap_serializer_function_ser_schema(public_fields),
+    )
+    s = SchemaSerializer(schema)
+    assert s.to_python(User('alice', 'hunter2')) == {'name': 'alice'}
+    ```
+
     Args:
         function: The function to use for serialization
         is_field_serializer: Whether the serializer is for a field, e.g. takes `model` as the first argument,
@@ -425,6 +594,37 @@ def model_ser_schema(cls: type[Any], schema: CoreSchema) -> ModelSerSchema:
     return ModelSerSchema(type='model', cls=cls, schema=schema)
 
 
+class IterDictSerSchema(TypedDict, total=False):
+    type: Required[Literal['iter-dict']]
+    keys_schema: CoreSchema
+    values_schema: CoreSchema
+
+
+def iter_dict_ser_schema(
+    keys_schema: CoreSchema | None = None, values_schema: CoreSchema | None = None
+) -> IterDictSerSchema:
+    """
+    Returns a schema for serializing an iterable of `(key, value)` pairs, e.g. a generator or `dict.items()`,
+    as a dict. When serializing to JSON the pairs are written as they're consumed, without first collecting
+    them into a dict, e.g.:
+
+    ```py
+    from pydantic_core import SchemaSerializer, core_schema
+
+    schema = core_schema.any_schema(
+        serialization=core_schema.iter_dict_ser_schema(values_schema=core_schema.int_schema())
+    )
+    s = SchemaSerializer(schema)
+    assert s.to_json((str(i), i) for i in range(3)) == b'{"0":0,"1":1,"2":2}'
+    ```
+
+    Args:
+        keys_schema: The schema to use to serialize the keys, defaults to inferring the type
+        values_schema: The schema to use to serialize the values, defaults to inferring the type
+    """
+    return _dict_not_none(type='iter-dict', keys_schema=keys_schema, values_schema=values_schema)
+
+
 SerSchema = Union[
     SimpleSerSchema,
     PlainSerializerFunctionSerSchema,
@@ -432,6 +632,7 @@ SerSchema = Union[
     FormatSerSchema,
     ToStringSerSchema,
     ModelSerSchema,
+    IterDictSerSchema,
 ]
 
 
@@ -583,6 +784,8 @@ class IntSchema(TypedDict, total=False):
     lt: int
     gt: int
     strict: bool
+    allow_underscores: bool  # default: True
+    allow_radix_prefixes: bool  # default: False
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -596,6 +799,8 @@ def int_schema(
     lt: int | None = None,
     gt: int | None = None,
     strict: bool | None = None,
+    allow_underscores: bool | None = None,
+    allow_radix_prefixes: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -618,6 +823,10 @@ def int_schema(
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
         strict: Whether the value should be a int or a value that can be converted to a int
+        allow_underscores: Whether strings with underscores separating digits, e.g. `'1_000'`, are accepted in lax
+            mode, defaults to `True`
+        allow_radix_prefixes: Whether strings with a `0x`, `0o` or `0b` prefix, e.g. `'0x1F'`, are accepted in lax
+            mode, defaults to `False`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -630,6 +839,8 @@ def int_schema(
         lt=lt,
         gt=gt,
         strict=strict,
+        allow_underscores=allow_underscores,
+        allow_radix_prefixes=allow_radix_prefixes,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -640,11 +851,15 @@ class FloatSchema(TypedDict, total=False):
     type: Required[Literal['float']]
     allow_inf_nan: bool  # whether 'NaN', '+inf', '-inf' should be forbidden. default: True
     multiple_of: float
+    multiple_of_tolerance: float
+    multiple_of_tolerance_mode: Literal['absolute', 'relative']
     le: float
     ge: float
     lt: float
     gt: float
     strict: bool
+    ser_json_float: Union[Literal['repr', 'scientific'], int]
+    ser_json_inf_nan: Literal['null', 'constants', 'strings', 'error']
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -654,11 +869,15 @@ def float_schema(
     *,
     allow_inf_nan: bool | None = None,
     multiple_of: float | None = None,
+    multiple_of_tolerance: float | None = None,
+    multiple_of_tolerance_mode: Literal['absolute', 'relative'] | None = None,
     le: float | None = None,
     ge: float | None = None,
     lt: float | None = None,
     gt: float | None = None,
     strict: bool | None = None,
+    ser_json_float: Literal['repr', 'scientific'] | int | None = None,
+    ser_json_inf_nan: Literal['null', 'constants', 'strings', 'error'] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -677,11 +896,18 @@ def float_schema(
     Args:
         allow_inf_nan: Whether to allow inf and nan values
         multiple_of: The value must be a multiple of this number
+        multiple_of_tolerance: How far the value may be from a multiple of `multiple_of` and still be accepted,
+            defaults to `1e-9`
+        multiple_of_tolerance_mode: Whether `multiple_of_tolerance` is an absolute difference (the default), or
+            relative to the magnitude of the value or `multiple_of`, whichever is larger
         le: The value must be less than or equal to this number
         ge: The value must be greater than or equal to this number
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
         strict: Whether the value should be a float or a value that can be converted to a float
+        ser_json_float: How the value is serialized to JSON, overriding `ser_json_float` from the config,
+            see [`CoreConfig`][pydantic_core.core_schema.CoreConfig]
+        ser_json_inf_nan: How infinity and NaN are serialized to JSON, overriding `ser_json_inf_nan` from the config
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -690,17 +916,33 @@ def float_schema(
         type='float',
         allow_inf_nan=allow_inf_nan,
         multiple_of=multiple_of,
+        multiple_of_tolerance=multiple_of_tolerance,
+        multiple_of_tolerance_mode=multiple_of_tolerance_mode,
         le=le,
         ge=ge,
         lt=lt,
         gt=gt,
         strict=strict,
+        ser_json_float=ser_json_float,
+        ser_json_inf_nan=ser_json_inf_nan,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
     )
 
 
+DecimalRounding = Literal[
+    'ROUND_UP',
+    'ROUND_DOWN',
+    'ROUND_CEILING',
+    'ROUND_FLOOR',
+    'ROUND_HALF_UP',
+    'ROUND_HALF_DOWN',
+    'ROUND_HALF_EVEN',
+    'ROUND_05UP',
+]
+
+
 class DecimalSchema(TypedDict, total=False):
     type: Required[Literal['decimal']]
     allow_inf_nan: bool  # whether 'NaN', '+inf', '-inf' should be forbidden. default: False
@@ -711,6 +953,11 @@ class DecimalSchema(TypedDict, total=False):
     gt: Decimal
     max_digits: int
     decimal_places: int
+    rounding: DecimalRounding
+    quantize: bool
+    normalize: bool
+    ser_decimal_places: int
+    ser_normalize: bool
     strict: bool
     ref: str
     metadata: dict[str, Any]
@@ -727,6 +974,11 @@ def decimal_schema(
     gt: Decimal | None = None,
     max_digits: int | None = None,
     decimal_places: int | None = None,
+    rounding: DecimalRounding | None = None,
+    quantize: bool | None = None,
+    normalize: bool | None = None,
+    ser_decimal_places: int | None = None,
+    ser_normalize: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
@@ -744,6 +996,21 @@ def decimal_schema(
     assert v.validate_python('0.5') == Decimal('0.5')
     ```
 
+    Values can be rounded to `decimal_places` instead of rejected, and the number of places used when
+    serializing can be fixed, e.g. for money:
+
+    ```py
+    from decimal import Decimal
+    from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+    schema = core_schema.decimal_schema(
+        decimal_places=2, quantize=True, rounding='ROUND_HALF_UP', ser_decimal_places=2
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python('1.005') == Decimal('1.01')
+    assert SchemaSerializer(schema).to_json(Decimal('1.5')) == b'"1.50"'
+    ```
+
     Args:
         allow_inf_nan: Whether to allow inf and nan values
         multiple_of: The value must be a multiple of this number
@@ -753,6 +1020,12 @@ def decimal_schema(
         gt: The value must be strictly greater than this number
         max_digits: The maximum number of decimal digits allowed
         decimal_places: The maximum number of decimal places allowed
+        rounding: The rounding mode used by `quantize` and `ser_decimal_places`, one of the `decimal.ROUND_*`
+            constants, defaults to `'ROUND_HALF_EVEN'`
+        quantize: Whether to round values to `decimal_places` rather than rejecting values with more places
+        normalize: Whether to strip trailing zeros from validated values, e.g. `1.50` becomes `1.5`
+        ser_decimal_places: When set, values are serialized with exactly this many decimal places
+        ser_normalize: Whether to strip trailing zeros from values when serializing
         strict: Whether the value should be a float or a value that can be converted to a float
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -766,6 +1039,11 @@ def decimal_schema(
         le=le,
         max_digits=max_digits,
         decimal_places=decimal_places,
+        rounding=rounding,
+        quantize=quantize,
+        normalize=normalize,
+        ser_decimal_places=ser_decimal_places,
+        ser_normalize=ser_normalize,
         multiple_of=multiple_of,
         allow_inf_nan=allow_inf_nan,
         strict=strict,
@@ -775,6 +1053,69 @@ def decimal_schema(
     )
 
 
+class FractionSchema(TypedDict, total=False):
+    type: Required[Literal['fraction']]
+    le: Fraction
+    ge: Fraction
+    lt: Fraction
+    gt: Fraction
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    serialization: SerSchema
+
+
+def fraction_schema(
+    *,
+    le: Fraction | None = None,
+    ge: Fraction | None = None,
+    lt: Fraction | None = None,
+    gt: Fraction | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> FractionSchema:
+    """
+    Returns a schema that matches a fraction value, e.g.:
+
+    ```py
+    from fractions import Fraction
+
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.fraction_schema(ge=Fraction(0), lt=Fraction(1))
+    v = SchemaValidator(schema)
+    assert v.validate_python('3/4') == Fraction(3, 4)
+    ```
+
+    In lax mode ints, strings like `'3/4'` or `'0.75'`, floats and `Decimal`s are accepted, in strict mode only
+    `Fraction` instances (or strings and numbers from JSON) are accepted. In JSON mode fractions are serialized
+    to strings like `'3/4'`.
+
+    Args:
+        le: The value must be less than or equal to this number
+        ge: The value must be greater than or equal to this number
+        lt: The value must be strictly less than this number
+        gt: The value must be strictly greater than this number
+        strict: Whether to only accept `Fraction` instances from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='fraction',
+        le=le,
+        ge=ge,
+        lt=lt,
+        gt=gt,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
 class ComplexSchema(TypedDict, total=False):
     type: Required[Literal['complex']]
     strict: bool
@@ -822,12 +1163,21 @@ class StringSchema(TypedDict, total=False):
     pattern: Union[str, Pattern[str]]
     max_length: int
     min_length: int
+    max_length_bytes: int
+    min_length_bytes: int
     strip_whitespace: bool
     to_lower: bool
     to_upper: bool
+    to_title: bool
+    to_snake: bool
+    to_camel: bool
+    normalize: Literal['NFC', 'NFKC', 'NFD', 'NFKD']
+    casefold: bool
+    pattern_mode: Literal['search', 'fullmatch']  # default: 'search'
     regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
     strict: bool
     coerce_numbers_to_str: bool
+    strict_exact_type: bool
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -838,12 +1188,21 @@ def str_schema(
     pattern: str | Pattern[str] | None = None,
     max_length: int | None = None,
     min_length: int | None = None,
+    max_length_bytes: int | None = None,
+    min_length_bytes: int | None = None,
     strip_whitespace: bool | None = None,
     to_lower: bool | None = None,
     to_upper: bool | None = None,
+    to_title: bool | None = None,
+    to_snake: bool | None = None,
+    to_camel: bool | None = None,
+    normalize: Literal['NFC', 'NFKC', 'NFD', 'NFKD'] | None = None,
+    casefold: bool | None = None,
+    pattern_mode: Literal['search', 'fullmatch'] | None = None,
     regex_engine: Literal['rust-regex', 'python-re'] | None = None,
     strict: bool | None = None,
     coerce_numbers_to_str: bool | None = None,
+    strict_exact_type: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -863,10 +1222,23 @@ def str_schema(
         pattern: A regex pattern that the value must match
         max_length: The value must be at most this length
         min_length: The value must be at least this length
+        max_length_bytes: The value must be at most this many bytes when UTF-8 encoded
+        min_length_bytes: The value must be at least this many bytes when UTF-8 encoded
         strip_whitespace: Whether to strip whitespace from the value
         to_lower: Whether to convert the value to lowercase
         to_upper: Whether to convert the value to uppercase
-        regex_engine: The regex engine to use for pattern validation. Default is 'rust-regex'.
+        to_title: Whether to convert the value to title case, like `str.title()`
+        to_snake: Whether to convert the value to snake_case, e.g. `'HTTPResponseCode'` becomes `'http_response_code'`
+        to_camel: Whether to convert the value to camelCase, e.g. `'http_response_code'` becomes `'httpResponseCode'`
+        normalize: The unicode normalization form to convert the value to, like `unicodedata.normalize()`,
+            unlike the other conversions this is applied before length constraints and `pattern` are checked
+        casefold: Whether to casefold the value, like `str.casefold()`, this is also applied before constraints
+            are checked and before `normalize`
+        pattern_mode: How `pattern` is matched against the value. Default is 'search'.
+            - `search` accepts the value if the pattern matches anywhere in it, like `re.search()`.
+            - `fullmatch` requires the pattern to match the whole value, like `re.fullmatch()`.
+        regex_engine: The regex engine to use for pattern validation, this overrides the `regex_engine` config
+            for this schema. Default is 'rust-regex'.
             - `rust-regex` uses the [`regex`](https://docs.rs/regex) Rust
               crate, which is non-backtracking and therefore more DDoS
               resistant, but does not support all regex features.
@@ -874,6 +1246,7 @@ def str_schema(
               which supports all regex features, but may be slower.
         strict: Whether the value should be a string or a value that can be converted to a string
         coerce_numbers_to_str: Whether to enable coercion of any `Number` type to `str` (not applicable in `strict` mode).
+        strict_exact_type: Whether strict mode should reject subclasses of `str`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -883,12 +1256,21 @@ def str_schema(
         pattern=pattern,
         max_length=max_length,
         min_length=min_length,
+        max_length_bytes=max_length_bytes,
+        min_length_bytes=min_length_bytes,
         strip_whitespace=strip_whitespace,
         to_lower=to_lower,
         to_upper=to_upper,
+        to_title=to_title,
+        to_snake=to_snake,
+        to_camel=to_camel,
+        normalize=normalize,
+        casefold=casefold,
+        pattern_mode=pattern_mode,
         regex_engine=regex_engine,
         strict=strict,
         coerce_numbers_to_str=coerce_numbers_to_str,
+        strict_exact_type=strict_exact_type,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -955,6 +1337,7 @@ class DateSchema(TypedDict, total=False):
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
+    formats: list[str]
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -969,6 +1352,7 @@ def date_schema(
     gt: date | None = None,
     now_op: Literal['past', 'future'] | None = None,
     now_utc_offset: int | None = None,
+    formats: list[str] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -993,6 +1377,9 @@ def date_schema(
         gt: The value must be strictly greater than this date
         now_op: The value must be in the past or future relative to the current date
         now_utc_offset: The value must be in the past or future relative to the current date with this utc offset
+        formats: `strptime` style formats, e.g. `'%d/%m/%Y'`, tried in order on strings in lax mode before the
+            default date parsing, supported directives are
+            `%Y %y %m %d %j %H %I %p %M %S %f %z %b %h %B %a %A %%`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1006,6 +1393,7 @@ def date_schema(
         gt=gt,
         now_op=now_op,
         now_utc_offset=now_utc_offset,
+        formats=formats,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1021,6 +1409,7 @@ class TimeSchema(TypedDict, total=False):
     gt: time
     tz_constraint: Union[Literal['aware', 'naive'], int]
     microseconds_precision: Literal['truncate', 'error']
+    formats: list[str]
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -1035,6 +1424,7 @@ def time_schema(
     gt: time | None = None,
     tz_constraint: Literal['aware', 'naive'] | int | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    formats: list[str] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -1059,6 +1449,9 @@ def time_schema(
         gt: The value must be strictly greater than this time
         tz_constraint: The value must be timezone aware or naive, or an int to indicate required tz offset
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        formats: `strptime` style formats, e.g. `'%d/%m/%Y'`, tried in order on strings in lax mode before the
+            default time parsing, supported directives are
+            `%Y %y %m %d %j %H %I %p %M %S %f %z %b %h %B %a %A %%`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1072,6 +1465,7 @@ def time_schema(
         gt=gt,
         tz_constraint=tz_constraint,
         microseconds_precision=microseconds_precision,
+        formats=formats,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1089,8 +1483,12 @@ class DatetimeSchema(TypedDict, total=False):
     tz_constraint: Union[Literal['aware', 'naive'], int]
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
+    tz_allowed: list[Union[str, int, tuple[int, int]]]
     now_utc_offset: int
     microseconds_precision: Literal['truncate', 'error']  # default: 'truncate'
+    formats: list[str]
+    epoch_unit: Literal['seconds', 'milliseconds', 'microseconds', 'infer']  # default: 'infer'
+    preserve_zone_key: bool  # default: False
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -1105,8 +1503,12 @@ def datetime_schema(
     gt: datetime | None = None,
     now_op: Literal['past', 'future'] | None = None,
     tz_constraint: Literal['aware', 'naive'] | int | None = None,
+    tz_allowed: list[str | int | tuple[int, int]] | None = None,
     now_utc_offset: int | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    formats: list[str] | None = None,
+    epoch_unit: Literal['seconds', 'milliseconds', 'microseconds', 'infer'] | None = None,
+    preserve_zone_key: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -1133,8 +1535,19 @@ def datetime_schema(
         now_op: The value must be in the past or future relative to the current datetime
         tz_constraint: The value must be timezone aware or naive, or an int to indicate required tz offset
             TODO: use of a tzinfo where offset changes based on the datetime is not yet supported
+        tz_allowed: The value must be timezone aware and in one of these zones: IANA zone names like
+            `'Europe/London'` (matched against the `key` of a `ZoneInfo`, a zero offset without a zone name matches
+            `'UTC'`), offsets in seconds, or `(min, max)` tuples giving an inclusive range of offsets
         now_utc_offset: The value must be in the past or future relative to the current datetime with this utc offset
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        formats: `strptime` style formats, e.g. `'%d/%m/%Y'`, tried in order on strings in lax mode before the
+            default datetime parsing, supported directives are
+            `%Y %y %m %d %j %H %I %p %M %S %f %z %b %h %B %a %A %%`
+        epoch_unit: The unit of numeric timestamps (and strings holding a number) in lax mode, by default `'infer'`
+            treats values above 2e10 as milliseconds and smaller ones as seconds
+        preserve_zone_key: Whether to accept strings with an RFC 9557 zone key suffix, e.g.
+            `'2024-01-02T03:04:05+01:00[Europe/Paris]'`, attaching the zone as a `zoneinfo.ZoneInfo`, and to add
+            the key of `ZoneInfo` zones back as a suffix when serializing to JSON with `round_trip=True`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1148,8 +1561,12 @@ def datetime_schema(
         gt=gt,
         now_op=now_op,
         tz_constraint=tz_constraint,
+        tz_allowed=tz_allowed,
         now_utc_offset=now_utc_offset,
         microseconds_precision=microseconds_precision,
+        formats=formats,
+        epoch_unit=epoch_unit,
+        preserve_zone_key=preserve_zone_key,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1245,7 +1662,8 @@ def literal_schema(
     ```
 
     Args:
-        expected: The value must be one of these values
+        expected: The value must be one of these values, strings from JSON are compared with `bytes` values
+            after decoding them according to `val_json_bytes` in [`CoreConfig`][pydantic_core.core_schema.CoreConfig]
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1259,7 +1677,10 @@ class EnumSchema(TypedDict, total=False):
     members: Required[list[Any]]
     sub_type: Literal['str', 'int', 'float']
     missing: Callable[[Any], Any]
+    by: Literal['value', 'name', 'either']
+    aliases: dict[Any, Any]
     strict: bool
+    ser_enum: Literal['object', 'value', 'name']
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -1271,7 +1692,10 @@ def enum_schema(
     *,
     sub_type: Literal['str', 'int', 'float'] | None = None,
     missing: Callable[[Any], Any] | None = None,
+    by: Literal['value', 'name', 'either'] | None = None,
+    aliases: dict[Any, Any] | None = None,
     strict: bool | None = None,
+    ser_enum: Literal['object', 'value', 'name'] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -1298,7 +1722,12 @@ def enum_schema(
         members: The members of the enum, generally `list(MyEnum.__members__.values())`
         sub_type: The type of the enum, either 'str' or 'int' or None for plain enums
         missing: A function to use when the value is not found in the enum, from `_missing_`
+        by: Whether members are looked up by `'value'` (the default), by `'name'`, or by `'either'`,
+            `missing` isn't called when looking up by name
+        aliases: Historical values mapped to the member which replaced them, e.g. `{'crimson': Color.RED}`
         strict: Whether to use strict mode, defaults to False
+        ser_enum: How members are serialized, overriding `ser_enum` from the config,
+            see [`CoreConfig`][pydantic_core.core_schema.CoreConfig]
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1309,7 +1738,10 @@ def enum_schema(
         members=members,
         sub_type=sub_type,
         missing=missing,
+        by=by,
+        aliases=aliases,
         strict=strict,
+        ser_enum=ser_enum,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1324,6 +1756,8 @@ class IsInstanceSchema(TypedDict, total=False):
     type: Required[Literal['is-instance']]
     cls: Required[Any]
     cls_repr: str
+    fallback_schema: CoreSchema
+    protocol_check: Literal['attributes', 'isinstance']
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
@@ -1333,6 +1767,8 @@ def is_instance_schema(
     cls: Any,
     *,
     cls_repr: str | None = None,
+    fallback_schema: CoreSchema | None = None,
+    protocol_check: Literal['attributes', 'isinstance'] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -1351,15 +1787,43 @@ def is_instance_schema(
     v.validate_python(A())
     ```
 
+    If `fallback_schema` is provided, inputs which aren't instances of `cls` (including all JSON inputs)
+    are validated with it instead, e.g.:
+
+    ```py
+    from decimal import Decimal
+
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.is_instance_schema(
+        cls=Decimal,
+        fallback_schema=core_schema.no_info_after_validator_function(Decimal, core_schema.str_schema()),
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python('1.5') == Decimal('1.5')
+    ```
+
     Args:
         cls: The value must be an instance of this class
         cls_repr: If provided this string is used in the validator name instead of `repr(cls)`
+        fallback_schema: Schema used to validate the value when it isn't an instance of `cls`
+        protocol_check: How instances of a `runtime_checkable` protocol are checked, by default (`'isinstance'`)
+            `isinstance` is called. With `'attributes'` the protocol's members are found once and looked up in rust
+            as `inspect.getattr_static` does, `isinstance` is only called for objects missing a member, so classes
+            registered with `register()` are still accepted
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
     return _dict_not_none(
-        type='is-instance', cls=cls, cls_repr=cls_repr, ref=ref, metadata=metadata, serialization=serialization
+        type='is-instance',
+        cls=cls,
+        cls_repr=cls_repr,
+        fallback_schema=fallback_schema,
+        protocol_check=protocol_check,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
     )
 
 
@@ -1411,13 +1875,24 @@ def is_subclass_schema(
 
 class CallableSchema(TypedDict, total=False):
     type: Required[Literal['callable']]
+    arity: int
+    callable_type: Any
+    required_keyword_params: list[str]
+    must_be_coroutine: bool
     ref: str
     metadata: dict[str, Any]
     serialization: SerSchema
 
 
 def callable_schema(
-    *, ref: str | None = None, metadata: dict[str, Any] | None = None, serialization: SerSchema | None = None
+    *,
+    arity: int | None = None,
+    callable_type: Any = None,
+    required_keyword_params: list[str] | None = None,
+    must_be_coroutine: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
 ) -> CallableSchema:
     """
     Returns a schema that checks if a value is callable, equivalent to python's `callable` method, e.g.:
@@ -1430,17 +1905,109 @@ def callable_schema(
     v.validate_python(min)
     ```
 
+    If `arity` or `callable_type` is provided, the callable's signature is also checked (using `inspect.signature`)
+    to make sure it can be called with that many positional arguments, e.g.:
+
+    ```py
+    from typing import Callable
+
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.callable_schema(callable_type=Callable[[int, str], bool])
+    v = SchemaValidator(schema)
+    v.validate_python(lambda a, b: True)
+    ```
+
+    Callables without an inspectable signature, such as some builtins, are always accepted.
+    The outcome of these checks is cached for each callable (holding only a weak reference to it), so a callable's
+    signature is only inspected the first time it's validated.
+
     Args:
+        arity: The number of positional arguments the callable must accept
+        callable_type: A `Callable[[...], ...]` type whose parameter count the callable must accept
+        required_keyword_params: Names of parameters the callable must accept as keyword arguments,
+            combined with `arity` or `callable_type` if either is set
+        must_be_coroutine: Whether the callable must be a coroutine function, as checked by `inspect.iscoroutinefunction`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='callable', ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='callable',
+        arity=arity,
+        callable_type=callable_type,
+        required_keyword_params=required_keyword_params,
+        must_be_coroutine=must_be_coroutine,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class ProtocolCustomSchema(TypedDict, total=False):
+    type: Required[Literal['protocol-custom']]
+    cls: Required[type[Any]]
+    cls_repr: str
+    ref: str
+    metadata: dict[str, Any]
+    serialization: SerSchema
+
+
+def protocol_custom_schema(
+    cls: type[Any],
+    *,
+    cls_repr: str | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> ProtocolCustomSchema:
+    """
+    Returns a schema for a type which implements the pydantic-core protocol methods, these are looked up once
+    when the schema is built and called directly on validation and serialization, e.g.:
+
+    ```py
+    from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+    class Money:
+        def __init__(self, cents: int):
+            self.cents = cents
+
+        @classmethod
+        def __validate_core__(cls, value):
+            if isinstance(value, cls):
+                return value
+            return cls(int(value))
+
+        def __serialize_core__(self):
+            return self.cents
+
+    schema = core_schema.protocol_custom_schema(cls=Money)
+    v = SchemaValidator(schema)
+    assert v.validate_python('150').cents == 150
+    assert SchemaSerializer(schema).to_python(Money(150)) == 150
+    ```
+
+    `__validate_core__` is required and is called with the raw input, errors it raises are converted the same
+    way as for plain validator functions. `__serialize_core__` is optional, when it's omitted values are
+    serialized by type inference.
+
+    Args:
+        cls: The class defining `__validate_core__` and optionally `__serialize_core__`
+        cls_repr: If provided this string is used in the validator name instead of `repr(cls)`
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='protocol-custom', cls=cls, cls_repr=cls_repr, ref=ref, metadata=metadata, serialization=serialization
+    )
 
 
 class UuidSchema(TypedDict, total=False):
     type: Required[Literal['uuid']]
     version: Literal[1, 3, 4, 5, 7]
+    allow_int: bool  # default: False
+    bytes_order: Literal['big', 'little']  # default: 'big'
     strict: bool
     ref: str
     metadata: dict[str, Any]
@@ -1450,13 +2017,342 @@ class UuidSchema(TypedDict, total=False):
 def uuid_schema(
     *,
     version: Literal[1, 3, 4, 5, 6, 7, 8] | None = None,
+    allow_int: bool | None = None,
+    bytes_order: Literal['big', 'little'] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
 ) -> UuidSchema:
+    """
+    Returns a schema that matches a UUID value, e.g.:
+
+    ```py
+    from uuid import UUID
+
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.uuid_schema(allow_int=True)
+    v = SchemaValidator(schema)
+    assert v.validate_python(1) == UUID(int=1)
+    ```
+
+    Args:
+        version: The UUID version the value must have
+        allow_int: Whether to accept ints, as the 128-bit integer form of the UUID (`UUID.int`), in lax mode
+        bytes_order: The byte order of 16 byte inputs, `'little'` for the order of `UUID.bytes_le`
+        strict: Whether the value should be a UUID or a value that can be converted to a UUID
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='uuid',
+        version=version,
+        allow_int=allow_int,
+        bytes_order=bytes_order,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class PathSchema(TypedDict, total=False):
+    type: Required[Literal['path']]
+    path_type: Literal['path', 'posix', 'windows']  # default: 'path'
+    must_be_absolute: bool  # default: False
+    allowed_suffixes: list[str]
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    serialization: SerSchema
+
+
+def path_schema(
+    *,
+    path_type: Literal['path', 'posix', 'windows'] | None = None,
+    must_be_absolute: bool | None = None,
+    allowed_suffixes: list[str] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> PathSchema:
+    """
+    Returns a schema that matches a filesystem path, e.g.:
+
+    ```py
+    from pathlib import Path
+
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.path_schema(must_be_absolute=True, allowed_suffixes=['.toml'])
+    v = SchemaValidator(schema)
+    assert v.validate_python('/etc/app/config.toml') == Path('/etc/app/config.toml')
+    ```
+
+    In lax mode strings, bytes (decoded with `os.fsdecode`) and `os.PathLike` objects are accepted,
+    in strict mode only instances of the path type (or strings from JSON) are accepted.
+
+    Args:
+        path_type: The type to produce, `'path'` for `pathlib.Path`, `'posix'` for `pathlib.PurePosixPath`
+            or `'windows'` for `pathlib.PureWindowsPath`
+        must_be_absolute: Whether the path must be absolute
+        allowed_suffixes: The allowed suffixes of the final path component, e.g. `['.json', '']`
+        strict: Whether to only accept instances of the path type from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='path',
+        path_type=path_type,
+        must_be_absolute=must_be_absolute,
+        allowed_suffixes=allowed_suffixes,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class IpAddressSchema(TypedDict, total=False):
+    type: Required[Literal['ip-address']]
+    version: Literal[4, 6]
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    serialization: SerSchema
+
+
+def ip_address_schema(
+    *,
+    version: Literal[4, 6] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> IpAddressSchema:
+    """
+    Returns a schema that matches an IP address, producing an `ipaddress.IPv4Address` or `ipaddress.IPv6Address`, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ip_address_schema(version=4)
+    v = SchemaValidator(schema)
+    print(repr(v.validate_python('192.168.0.1')))
+    #> IPv4Address('192.168.0.1')
+    ```
+
+    In lax mode strings, packed bytes and integers are accepted, in strict mode only `ipaddress` objects
+    (or strings from JSON) are accepted.
+
+    Args:
+        version: The IP version to accept, `4` or `6`, defaults to both
+        strict: Whether to only accept `ipaddress` objects from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='ip-address', version=version, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+    )
+
+
+class IpNetworkSchema(TypedDict, total=False):
+    type: Required[Literal['ip-network']]
+    version: Literal[4, 6]
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    serialization: SerSchema
+
+
+def ip_network_schema(
+    *,
+    version: Literal[4, 6] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> IpNetworkSchema:
+    """
+    Returns a schema that matches an IP network, producing an `ipaddress.IPv4Network` or `ipaddress.IPv6Network`, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ip_network_schema()
+    v = SchemaValidator(schema)
+    print(repr(v.validate_python('2001:db8::/32')))
+    #> IPv6Network('2001:db8::/32')
+    ```
+
+    The prefix may be given as a length or, for IPv4, as a netmask or hostmask. As with `ipaddress.ip_network`,
+    networks with host bits set are rejected.
+
+    Args:
+        version: The IP version to accept, `4` or `6`, defaults to both
+        strict: Whether to only accept `ipaddress` objects from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='ip-network', version=version, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+    )
+
+
+class IpInterfaceSchema(TypedDict, total=False):
+    type: Required[Literal['ip-interface']]
+    version: Literal[4, 6]
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    serialization: SerSchema
+
+
+def ip_interface_schema(
+    *,
+    version: Literal[4, 6] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> IpInterfaceSchema:
+    """
+    Returns a schema that matches an IP interface, producing an `ipaddress.IPv4Interface` or
+    `ipaddress.IPv6Interface`, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ip_interface_schema(version=4)
+    v = SchemaValidator(schema)
+    print(repr(v.validate_python('192.168.0.1/24')))
+    #> IPv4Interface('192.168.0.1/24')
+    ```
+
+    Args:
+        version: The IP version to accept, `4` or `6`, defaults to both
+        strict: Whether to only accept `ipaddress` objects from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='ip-interface', version=version, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+    )
+
+
+class SecretStrSchema(TypedDict, total=False):
+    type: Required[Literal['secret_str']]
+    min_length: int
+    max_length: int
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    serialization: SerSchema
+
+
+def secret_str_schema(
+    *,
+    min_length: int | None = None,
+    max_length: int | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> SecretStrSchema:
+    """
+    Returns a schema that matches a secret string, producing a `Secret` whose `repr` is masked, e.g.:
+
+    ```py
+    from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+    schema = core_schema.secret_str_schema(min_length=3)
+    v = SchemaValidator(schema)
+    secret = v.validate_python('hunter2')
+    print(repr(secret))
+    #> Secret('**********')
+    assert secret.get_secret_value() == 'hunter2'
+
+    s = SchemaSerializer(schema)
+    assert s.to_json(secret) == b'"**********"'
+    assert s.to_json(secret, serialize_secrets=True) == b'"hunter2"'
+    ```
+
+    The value never appears as the `input` of validation errors, the masked placeholder is used instead.
+
+    Args:
+        min_length: The value must be at least this length
+        max_length: The value must be at most this length
+        strict: Whether to only accept `Secret` instances from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='secret_str',
+        min_length=min_length,
+        max_length=max_length,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class SecretBytesSchema(TypedDict, total=False):
+    type: Required[Literal['secret_bytes']]
+    min_length: int
+    max_length: int
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    serialization: SerSchema
+
+
+def secret_bytes_schema(
+    *,
+    min_length: int | None = None,
+    max_length: int | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> SecretBytesSchema:
+    """
+    Returns a schema that matches secret bytes, producing a `Secret` whose `repr` is masked, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    v = SchemaValidator(core_schema.secret_bytes_schema())
+    secret = v.validate_python(b'hunter2')
+    print(repr(secret))
+    #> Secret(b'**********')
+    assert secret.get_secret_value() == b'hunter2'
+    ```
+
+    Args:
+        min_length: The value must be at least this length
+        max_length: The value must be at most this length
+        strict: Whether to only accept `Secret` instances from python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
     return _dict_not_none(
-        type='uuid', version=version, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+        type='secret_bytes',
+        min_length=min_length,
+        max_length=max_length,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
     )
 
 
@@ -1480,6 +2376,10 @@ class ListSchema(TypedDict, total=False):
     max_length: int
     fail_fast: bool
     strict: bool
+    strict_exact_type: bool
+    allow_array_like: bool
+    unique_items: bool
+    unique_items_key: Callable[[Any], Hashable]
     ref: str
     metadata: dict[str, Any]
     serialization: IncExSeqOrElseSerSchema
@@ -1492,6 +2392,10 @@ def list_schema(
     max_length: int | None = None,
     fail_fast: bool | None = None,
     strict: bool | None = None,
+    strict_exact_type: bool | None = None,
+    allow_array_like: bool | None = None,
+    unique_items: bool | None = None,
+    unique_items_key: Callable[[Any], Hashable] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
@@ -1513,6 +2417,12 @@ def list_schema(
         max_length: The value must be a list with at most this many items
         fail_fast: Stop validation on the first error
         strict: The value must be a list with exactly this many items
+        strict_exact_type: Whether strict mode should reject subclasses of `list`
+        allow_array_like: Whether NumPy arrays (or other objects implementing `__array__`) and buffer protocol
+            objects like `array.array` are accepted, converting them to a list in a single call
+        unique_items: The validated items must all be different, the first duplicate is reported at its index
+        unique_items_key: Called with each validated item to get the value compared for `unique_items`,
+            e.g. `lambda user: user.id`, a `ValueError` or `AssertionError` it raises is reported at the item's index
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1524,6 +2434,10 @@ def list_schema(
         max_length=max_length,
         fail_fast=fail_fast,
         strict=strict,
+        strict_exact_type=strict_exact_type,
+        allow_array_like=allow_array_like,
+        unique_items=unique_items,
+        unique_items_key=unique_items_key,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1586,6 +2500,8 @@ def tuple_variable_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     strict: bool | None = None,
+    unique_items: bool | None = None,
+    unique_items_key: Callable[[Any], Hashable] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
@@ -1608,6 +2524,8 @@ def tuple_variable_schema(
         min_length: The value must be a tuple with at least this many items
         max_length: The value must be a tuple with at most this many items
         strict: The value must be a tuple with exactly this many items
+        unique_items: The validated items must all be different, the first duplicate is reported at its index
+        unique_items_key: Called with each validated item to get the value compared for `unique_items`
         ref: Optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1618,6 +2536,8 @@ def tuple_variable_schema(
         min_length=min_length,
         max_length=max_length,
         strict=strict,
+        unique_items=unique_items,
+        unique_items_key=unique_items_key,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1630,8 +2550,12 @@ class TupleSchema(TypedDict, total=False):
     variadic_item_index: int
     min_length: int
     max_length: int
+    variadic_min_length: int
+    variadic_max_length: int
     fail_fast: bool
     strict: bool
+    unique_items: bool
+    unique_items_key: Callable[[Any], Hashable]
     ref: str
     metadata: dict[str, Any]
     serialization: IncExSeqOrElseSerSchema
@@ -1643,8 +2567,12 @@ def tuple_schema(
     variadic_item_index: int | None = None,
     min_length: int | None = None,
     max_length: int | None = None,
+    variadic_min_length: int | None = None,
+    variadic_max_length: int | None = None,
     fail_fast: bool | None = None,
     strict: bool | None = None,
+    unique_items: bool | None = None,
+    unique_items_key: Callable[[Any], Hashable] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
@@ -1663,13 +2591,38 @@ def tuple_schema(
     assert v.validate_python((1, 'hello', 'world', 1.5)) == (1, 'hello', 'world', 1.5)
     ```
 
+    Trailing items (before any variadic item) can have defaults, shorter tuples are padded with them:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.tuple_schema(
+        [
+            core_schema.int_schema(),
+            core_schema.with_default_schema(core_schema.int_schema(), default=0),
+            core_schema.with_default_schema(core_schema.int_schema(), default=0),
+        ],
+        min_length=2,
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python((1, 2)) == (1, 2, 0)
+    ```
+
     Args:
         items_schema: The value must be a tuple with items that match these schemas
         variadic_item_index: The index of the schema in `items_schema` to be treated as variadic (following PEP 646)
-        min_length: The value must be a tuple with at least this many items
+        min_length: The value must be a tuple with at least this many items, items within this length must be
+            in the input and aren't padded with their default
         max_length: The value must be a tuple with at most this many items
+        variadic_min_length: The variadic item must match at least this many items, not counting the items before
+            and after it, errors are reported at the index the variadic items start from
+        variadic_max_length: The variadic item must match at most this many items, not counting the items before
+            and after it
         fail_fast: Stop validation on the first error
         strict: The value must be a tuple with exactly this many items
+        unique_items: The validated items must all be different, including the fixed items around a variadic
+            item, the first duplicate is reported at its index
+        unique_items_key: Called with each validated item to get the value compared for `unique_items`
         ref: Optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1680,8 +2633,12 @@ def tuple_schema(
         variadic_item_index=variadic_item_index,
         min_length=min_length,
         max_length=max_length,
+        variadic_min_length=variadic_min_length,
+        variadic_max_length=variadic_max_length,
         fail_fast=fail_fast,
         strict=strict,
+        unique_items=unique_items,
+        unique_items_key=unique_items_key,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1886,6 +2843,10 @@ class DictSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     strict: bool
+    strict_exact_type: bool
+    key_pattern: Union[str, Pattern[str]]
+    required_keys: list[Any]
+    forbidden_keys: list[Any]
     ref: str
     metadata: dict[str, Any]
     serialization: IncExDictOrElseSerSchema
@@ -1898,6 +2859,10 @@ def dict_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     strict: bool | None = None,
+    strict_exact_type: bool | None = None,
+    key_pattern: str | Pattern[str] | None = None,
+    required_keys: list[Hashable] | None = None,
+    forbidden_keys: list[Hashable] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -1921,6 +2886,10 @@ def dict_schema(
         min_length: The value must be a dict with at least this many items
         max_length: The value must be a dict with at most this many items
         strict: Whether the keys and values should be validated with strict mode
+        strict_exact_type: Whether strict mode should reject subclasses of `dict`
+        key_pattern: A regex pattern which each validated key must be a string matching
+        required_keys: Keys which must be present after validating the keys, each missing key is reported at its loc
+        forbidden_keys: Keys which must not be present after validating the keys
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1932,6 +2901,235 @@ def dict_schema(
         min_length=min_length,
         max_length=max_length,
         strict=strict,
+        strict_exact_type=strict_exact_type,
+        key_pattern=key_pattern,
+        required_keys=required_keys,
+        forbidden_keys=forbidden_keys,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class DequeSchema(TypedDict, total=False):
+    type: Required[Literal['deque']]
+    items_schema: CoreSchema
+    maxlen: int
+    fail_fast: bool
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    serialization: IncExSeqOrElseSerSchema
+
+
+def deque_schema(
+    items_schema: CoreSchema | None = None,
+    *,
+    maxlen: int | None = None,
+    fail_fast: bool | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    serialization: IncExSeqOrElseSerSchema | None = None,
+) -> DequeSchema:
+    """
+    Returns a schema that matches a `collections.deque` value, e.g.:
+
+    ```py
+    from collections import deque
+
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.deque_schema(core_schema.int_schema(), maxlen=2)
+    v = SchemaValidator(schema)
+    assert v.validate_python(['1', 2, 3]) == deque([2, 3], maxlen=2)
+    ```
+
+    Args:
+        items_schema: The value must be a deque of items that match this schema
+        maxlen: The `maxlen` of the deque, as with `deque` itself extra items are dropped from the start
+        fail_fast: Stop validation on the first error
+        strict: Whether the value should be a deque, rather than any sequence that can be converted to a deque
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='deque',
+        items_schema=items_schema,
+        maxlen=maxlen,
+        fail_fast=fail_fast,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class CounterSchema(TypedDict, total=False):
+    type: Required[Literal['counter']]
+    keys_schema: CoreSchema  # default: AnySchema
+    values_schema: CoreSchema  # default: IntSchema
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    serialization: IncExDictOrElseSerSchema
+
+
+def counter_schema(
+    keys_schema: CoreSchema | None = None,
+    values_schema: CoreSchema | None = None,
+    *,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    serialization: IncExDictOrElseSerSchema | None = None,
+) -> CounterSchema:
+    """
+    Returns a schema that matches a `collections.Counter` value, e.g.:
+
+    ```py
+    from collections import Counter
+
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.counter_schema(keys_schema=core_schema.str_schema())
+    v = SchemaValidator(schema)
+    assert v.validate_python({'a': '1', 'b': 2}) == Counter({'a': 1, 'b': 2})
+    ```
+
+    Args:
+        keys_schema: The value must be a counter with keys that match this schema
+        values_schema: The value must be a counter with counts that match this schema, defaults to an int schema
+        strict: Whether the value should be a Counter, rather than any mapping that can be converted to a Counter
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='counter',
+        keys_schema=keys_schema,
+        values_schema=values_schema,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class DefaultDictSchema(TypedDict, total=False):
+    type: Required[Literal['defaultdict']]
+    keys_schema: CoreSchema  # default: AnySchema
+    values_schema: CoreSchema  # default: AnySchema
+    default_factory: Callable[[], Any]
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    serialization: IncExDictOrElseSerSchema
+
+
+def defaultdict_schema(
+    keys_schema: CoreSchema | None = None,
+    values_schema: CoreSchema | None = None,
+    *,
+    default_factory: Callable[[], Any] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    serialization: IncExDictOrElseSerSchema | None = None,
+) -> DefaultDictSchema:
+    """
+    Returns a schema that matches a `collections.defaultdict` value, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.defaultdict_schema(
+        keys_schema=core_schema.str_schema(),
+        values_schema=core_schema.list_schema(core_schema.int_schema()),
+        default_factory=list,
+    )
+    v = SchemaValidator(schema)
+    d = v.validate_python({'a': ['1']})
+    assert d['a'] == [1]
+    assert d['b'] == []
+    ```
+
+    Args:
+        keys_schema: The value must be a defaultdict with keys that match this schema
+        values_schema: The value must be a defaultdict with values that match this schema
+        default_factory: The `default_factory` of the defaultdict, if omitted the factory of a defaultdict input
+            is kept, otherwise the default factory is `None`
+        strict: Whether the value should be a defaultdict, rather than any mapping that can be converted
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='defaultdict',
+        keys_schema=keys_schema,
+        values_schema=values_schema,
+        default_factory=default_factory,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class NamedTupleSchema(TypedDict, total=False):
+    type: Required[Literal['namedtuple']]
+    cls: Required[type[Any]]
+    items_schema: list[CoreSchema]
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    serialization: SerSchema
+
+
+def namedtuple_schema(
+    cls: type[Any],
+    items_schema: list[CoreSchema] | None = None,
+    *,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> NamedTupleSchema:
+    """
+    Returns a schema that matches a `NamedTuple` (or `collections.namedtuple`) class, e.g.:
+
+    ```py
+    from typing import NamedTuple
+
+    from pydantic_core import SchemaValidator, core_schema
+
+    class Point(NamedTuple):
+        x: int
+        y: int = 0
+
+    schema = core_schema.namedtuple_schema(Point, [core_schema.int_schema(), core_schema.int_schema()])
+    v = SchemaValidator(schema)
+    assert v.validate_python((1, '2')) == Point(1, 2)
+    assert v.validate_python({'x': 1}) == Point(1, 0)
+    ```
+
+    Sequences are validated positionally and mappings by field name, using the field names and defaults of
+    the class, so renamed fields are matched by their final names.
+
+    Args:
+        cls: The NamedTuple class to validate into
+        items_schema: One schema per field of the class, if omitted the fields are not validated
+        strict: Whether the value must be an instance of the class, rather than any sequence or mapping
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='namedtuple',
+        cls=cls,
+        items_schema=items_schema,
+        strict=strict,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2427,8 +3625,9 @@ class WithDefaultSchema(TypedDict, total=False):
     type: Required[Literal['default']]
     schema: Required[CoreSchema]
     default: Any
-    default_factory: Union[Callable[[], Any], Callable[[dict[str, Any]], Any]]
+    default_factory: Union[Callable[[], Any], Callable[[dict[str, Any]], Any], Callable[..., Any]]
     default_factory_takes_data: bool
+    default_factory_takes_context: bool
     on_error: Literal['raise', 'omit', 'default']  # default: 'raise'
     validate_default: bool  # default: False
     strict: bool
@@ -2441,8 +3640,9 @@ def with_default_schema(
     schema: CoreSchema,
     *,
     default: Any = PydanticUndefined,
-    default_factory: Union[Callable[[], Any], Callable[[dict[str, Any]], Any], None] = None,
+    default_factory: Union[Callable[[], Any], Callable[[dict[str, Any]], Any], Callable[..., Any], None] = None,
     default_factory_takes_data: bool | None = None,
+    default_factory_takes_context: bool | None = None,
     on_error: Literal['raise', 'omit', 'default'] | None = None,
     validate_default: bool | None = None,
     strict: bool | None = None,
@@ -2469,6 +3669,8 @@ def with_default_schema(
         default: The default value to use
         default_factory: A callable that returns the default value to use
         default_factory_takes_data: Whether the default factory takes a validated data argument
+        default_factory_takes_context: Whether the default factory takes the validation context as an argument,
+            after the validated data if it takes that too
         on_error: What to do if the schema validation fails. One of 'raise', 'omit', 'default'
         validate_default: Whether the default value should be validated
         strict: Whether the underlying schema should be validated with strict mode
@@ -2481,6 +3683,7 @@ def with_default_schema(
         schema=schema,
         default_factory=default_factory,
         default_factory_takes_data=default_factory_takes_data,
+        default_factory_takes_context=default_factory_takes_context,
         on_error=on_error,
         validate_default=validate_default,
         strict=strict,
@@ -2496,6 +3699,7 @@ def with_default_schema(
 class NullableSchema(TypedDict, total=False):
     type: Required[Literal['nullable']]
     schema: Required[CoreSchema]
+    none_as: Any
     strict: bool
     ref: str
     metadata: dict[str, Any]
@@ -2505,6 +3709,7 @@ class NullableSchema(TypedDict, total=False):
 def nullable_schema(
     schema: CoreSchema,
     *,
+    none_as: Any = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
@@ -2521,15 +3726,37 @@ def nullable_schema(
     assert v.validate_python(None) is None
     ```
 
+    `none_as` replaces `None` when serializing to JSON, for wire formats which can't represent `null`,
+    if it's `PydanticOmit` the key is left out when the value is a field of a model, typed dict or dataclass:
+
+    ```py
+    from pydantic_core import PydanticOmit, SchemaSerializer, core_schema
+
+    schema = core_schema.nullable_schema(core_schema.str_schema(), none_as='')
+    assert SchemaSerializer(schema).to_json(None) == b'""'
+
+    schema = core_schema.typed_dict_schema(
+        {'a': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.int_schema(), none_as=PydanticOmit))}
+    )
+    assert SchemaSerializer(schema).to_json({'a': None}) == b'{}'
+    ```
+
     Args:
         schema: The schema to wrap
+        none_as: The value `None` is serialized as in JSON, or `PydanticOmit` to omit fields which are `None`
         strict: Whether the underlying schema should be validated with strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
     return _dict_not_none(
-        type='nullable', schema=schema, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+        type='nullable',
+        schema=schema,
+        none_as=none_as,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
     )
 
 
@@ -2542,6 +3769,8 @@ class UnionSchema(TypedDict, total=False):
     custom_error_message: str
     custom_error_context: dict[str, Union[str, int, float]]
     mode: Literal['smart', 'left_to_right']  # default: 'smart'
+    priorities: list[int]
+    error_mode: Literal['all', 'best-match', 'summarize']  # default: 'all'
     strict: bool
     ref: str
     metadata: dict[str, Any]
@@ -2556,6 +3785,8 @@ def union_schema(
     custom_error_message: str | None = None,
     custom_error_context: dict[str, str | int] | None = None,
     mode: Literal['smart', 'left_to_right'] | None = None,
+    priorities: list[int] | None = None,
+    error_mode: Literal['all', 'best-match', 'summarize'] | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -2581,6 +3812,15 @@ def union_schema(
         mode: How to select which choice to return
             * `smart` (default) will try to return the choice which is the closest match to the input value
             * `left_to_right` will return the first choice in `choices` which succeeds validation
+        priorities: One integer per choice, used in `smart` mode to break ties between choices which match the
+            input equally well, the choice with the highest priority wins rather than the first, e.g.
+            `priorities=[0, 1]` with `choices=[float_schema(), int_schema()]` prefers `int` for the input `'1'`
+        error_mode: Which errors are reported when no choice matches, overrides the `union_error_mode` config
+            * `all` (default) will report the errors of every choice
+            * `best-match` will only report the errors of the closest matching choice, the choice with the fewest
+              errors, preferring choices which didn't fail at the top level
+            * `summarize` will report the errors of the closest matching choice and a single `union_member_failed`
+              error for each other choice
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -2593,6 +3833,8 @@ def union_schema(
         custom_error_message=custom_error_message,
         custom_error_context=custom_error_context,
         mode=mode,
+        priorities=priorities,
+        error_mode=error_mode,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2602,7 +3844,15 @@ def union_schema(
 class TaggedUnionSchema(TypedDict, total=False):
     type: Required[Literal['tagged-union']]
     choices: Required[dict[Hashable, CoreSchema]]
-    discriminator: Required[Union[str, list[Union[str, int]], list[list[Union[str, int]]], Callable[[Any], Hashable]]]
+    discriminator: Required[
+        Union[
+            str,
+            list[Union[str, int]],
+            list[list[Union[str, int]]],
+            tuple[Union[str, list[Union[str, int]]], ...],
+            Callable[[Any], Hashable],
+        ]
+    ]
     custom_error_type: str
     custom_error_message: str
     custom_error_context: dict[str, Union[str, int, float]]
@@ -2615,7 +3865,11 @@ class TaggedUnionSchema(TypedDict, total=False):
 
 def tagged_union_schema(
     choices: dict[Any, CoreSchema],
-    discriminator: str | list[str | int] | list[list[str | int]] | Callable[[Any], Any],
+    discriminator: str
+    | list[str | int]
+    | list[list[str | int]]
+    | tuple[str | list[str | int], ...]
+    | Callable[[Any], Any],
     *,
     custom_error_type: str | None = None,
     custom_error_message: str | None = None,
@@ -2672,6 +3926,9 @@ def tagged_union_schema(
             * If `discriminator` is a list of lists, each inner list is a path, and the first path that exists is used
             * If `discriminator` is a callable, it should return the discriminator when called on the value to validate;
               the callable can return `None` to indicate that there is no matching discriminator present on the input
+            * If `discriminator` is a tuple, each item is a str or path as above, and the discriminator value is the
+              tuple of the values found, e.g. `('type', 'version')` with `choices` keys like `('cat', 1)`; if no
+              choice matches, the error reports the first key whose value doesn't match
         custom_error_type: The custom error type to use if the validation fails
         custom_error_message: The custom error message to use if the validation fails
         custom_error_context: The custom error context to use if the validation fails
@@ -2859,13 +4116,70 @@ def json_or_python_schema(
     )
 
 
+class ContextSwitchSchema(TypedDict, total=False):
+    type: Required[Literal['context-switch']]
+    context_key: Required[str]
+    choices: Required[dict[Hashable, CoreSchema]]
+    default_schema: CoreSchema
+    ref: str
+    metadata: dict[str, Any]
+    serialization: SerSchema
+
+
+def context_switch_schema(
+    context_key: str,
+    choices: dict[Any, CoreSchema],
+    *,
+    default_schema: CoreSchema | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> ContextSwitchSchema:
+    """
+    Returns a schema that selects one of `choices` based on the value of `context_key` in the
+    validation context, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.context_switch_schema(
+        'api_version',
+        {'v1': core_schema.int_schema(), 'v2': core_schema.str_schema()},
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python('123', context={'api_version': 'v1'}) == 123
+    assert v.validate_python('123', context={'api_version': 'v2'}) == '123'
+    ```
+
+    Args:
+        context_key: The key to look up in the validation context
+        choices: The schemas to choose from, keyed by the expected context value
+        default_schema: The schema to use when the context doesn't contain `context_key`, or its value
+            doesn't match any of `choices`; if omitted, validation fails in those cases
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='context-switch',
+        context_key=context_key,
+        choices=choices,
+        default_schema=default_schema,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
 class TypedDictField(TypedDict, total=False):
     type: Required[Literal['typed-dict-field']]
     schema: Required[CoreSchema]
     required: bool
-    validation_alias: Union[str, list[Union[str, int]], list[list[Union[str, int]]]]
+    validation_alias: Union[str, list[Union[str, int, EllipsisType]], list[list[Union[str, int, EllipsisType]]]]
     serialization_alias: str
     serialization_exclude: bool  # default: False
+    serialization_exclude_none: bool  # default: False
+    serialization_exclude_if: Callable[[Any], bool]
     metadata: dict[str, Any]
 
 
@@ -2873,9 +4187,11 @@ def typed_dict_field(
     schema: CoreSchema,
     *,
     required: bool | None = None,
-    validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
+    validation_alias: str | list[str | int | EllipsisType] | list[list[str | int | EllipsisType]] | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
+    serialization_exclude_none: bool | None = None,
+    serialization_exclude_if: Callable[[Any], bool] | None = None,
     metadata: dict[str, Any] | None = None,
 ) -> TypedDictField:
     """
@@ -2890,9 +4206,13 @@ def typed_dict_field(
     Args:
         schema: The schema to use for the field
         required: Whether the field is required, otherwise uses the value from `total` on the typed dict
-        validation_alias: The alias(es) to use to find the field in the validation data
+        validation_alias: The alias(es) to use to find the field in the validation data, `...` in an alias path
+            matches every item of a list or value of a dict
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
+        serialization_exclude_none: Whether to exclude the field when serializing if its value is `None`
+        serialization_exclude_if: A callable called with the field's value when serializing, the field is excluded
+            if it returns a truthy value
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
     return _dict_not_none(
@@ -2902,6 +4222,8 @@ def typed_dict_field(
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
+        serialization_exclude_none=serialization_exclude_none,
+        serialization_exclude_if=serialization_exclude_if,
         metadata=metadata,
     )
 
@@ -2914,6 +4236,9 @@ class TypedDictSchema(TypedDict, total=False):
     computed_fields: list[ComputedField]
     strict: bool
     extras_schema: CoreSchema
+    extras_pattern_schemas: dict[str, CoreSchema]
+    forbidden_extra_keys: list[str]
+    combine_extra_errors: bool  # default: False
     # all these values can be set via config, equivalent fields have `typed_dict_` prefix
     extra_behavior: ExtraBehavior
     total: bool  # default: True
@@ -2931,6 +4256,9 @@ def typed_dict_schema(
     computed_fields: list[ComputedField] | None = None,
     strict: bool | None = None,
     extras_schema: CoreSchema | None = None,
+    extras_pattern_schemas: dict[str, CoreSchema] | None = None,
+    forbidden_extra_keys: list[str] | None = None,
+    combine_extra_errors: bool | None = None,
     extra_behavior: ExtraBehavior | None = None,
     total: bool | None = None,
     ref: str | None = None,
@@ -2964,6 +4292,11 @@ def typed_dict_schema(
         computed_fields: Computed fields to use when serializing the model, only applies when directly inside a model
         strict: Whether the typed dict is strict
         extras_schema: The extra validator to use for the typed dict
+        extras_pattern_schemas: Schemas for extra keys matching regex patterns, the first pattern found in the key is
+            used, keys matching no pattern use `extras_schema` or are forbidden if it isn't set
+        forbidden_extra_keys: Extra keys which are forbidden whatever `extra_behavior` is
+        combine_extra_errors: Whether to report all forbidden extra keys in a single `extra_keys_forbidden` error
+            on the whole input, rather than an `extra_forbidden` error for each key
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         extra_behavior: The extra behavior to use for the typed dict
@@ -2978,6 +4311,9 @@ def typed_dict_schema(
         computed_fields=computed_fields,
         strict=strict,
         extras_schema=extras_schema,
+        extras_pattern_schemas=extras_pattern_schemas,
+        forbidden_extra_keys=forbidden_extra_keys,
+        combine_extra_errors=combine_extra_errors,
         extra_behavior=extra_behavior,
         total=total,
         ref=ref,
@@ -2990,20 +4326,26 @@ def typed_dict_schema(
 class ModelField(TypedDict, total=False):
     type: Required[Literal['model-field']]
     schema: Required[CoreSchema]
-    validation_alias: Union[str, list[Union[str, int]], list[list[Union[str, int]]]]
+    validation_alias: Union[str, list[Union[str, int, EllipsisType]], list[list[Union[str, int, EllipsisType]]]]
     serialization_alias: str
     serialization_exclude: bool  # default: False
+    serialization_exclude_none: bool  # default: False
+    serialization_exclude_if: Callable[[Any], bool]
     frozen: bool
+    repr: Union[bool, Literal['masked']]  # default: True
     metadata: dict[str, Any]
 
 
 def model_field(
     schema: CoreSchema,
     *,
-    validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
+    validation_alias: str | list[str | int | EllipsisType] | list[list[str | int | EllipsisType]] | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
+    serialization_exclude_none: bool | None = None,
+    serialization_exclude_if: Callable[[Any], bool] | None = None,
     frozen: bool | None = None,
+    repr: bool | Literal['masked'] | None = None,
     metadata: dict[str, Any] | None = None,
 ) -> ModelField:
     """
@@ -3017,10 +4359,16 @@ def model_field(
 
     Args:
         schema: The schema to use for the field
-        validation_alias: The alias(es) to use to find the field in the validation data
+        validation_alias: The alias(es) to use to find the field in the validation data, `...` in an alias path
+            matches every item of a list or value of a dict
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
+        serialization_exclude_none: Whether to exclude the field when serializing if its value is `None`
+        serialization_exclude_if: A callable called with the field's value when serializing, the field is excluded
+            if it returns a truthy value
         frozen: Whether the field is frozen
+        repr: Whether to include the field in a repr generated with the model's `generate_repr`,
+            `'masked'` includes the field with its value replaced by `'**********'`
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
     return _dict_not_none(
@@ -3029,7 +4377,10 @@ def model_field(
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
+        serialization_exclude_none=serialization_exclude_none,
+        serialization_exclude_if=serialization_exclude_if,
         frozen=frozen,
+        repr=repr,
         metadata=metadata,
     )
 
@@ -3041,6 +4392,7 @@ class ModelFieldsSchema(TypedDict, total=False):
     computed_fields: list[ComputedField]
     strict: bool
     extras_schema: CoreSchema
+    extras_pattern_schemas: dict[str, CoreSchema]
     extras_keys_schema: CoreSchema
     extra_behavior: ExtraBehavior
     from_attributes: bool
@@ -3056,6 +4408,7 @@ def model_fields_schema(
     computed_fields: list[ComputedField] | None = None,
     strict: bool | None = None,
     extras_schema: CoreSchema | None = None,
+    extras_pattern_schemas: dict[str, CoreSchema] | None = None,
     extras_keys_schema: CoreSchema | None = None,
     extra_behavior: ExtraBehavior | None = None,
     from_attributes: bool | None = None,
@@ -3083,6 +4436,8 @@ def model_fields_schema(
         computed_fields: Computed fields to use when serializing the model, only applies when directly inside a model
         strict: Whether the model is strict
         extras_schema: The schema to use when validating extra input data
+        extras_pattern_schemas: Schemas for extra keys matching regex patterns, the first pattern found in the key is
+            used, keys matching no pattern use `extras_schema` or are forbidden if it isn't set
         extras_keys_schema: The schema to use when validating the keys of extra input data
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -3097,6 +4452,7 @@ def model_fields_schema(
         computed_fields=computed_fields,
         strict=strict,
         extras_schema=extras_schema,
+        extras_pattern_schemas=extras_pattern_schemas,
         extras_keys_schema=extras_keys_schema,
         extra_behavior=extra_behavior,
         from_attributes=from_attributes,
@@ -3118,6 +4474,9 @@ class ModelSchema(TypedDict, total=False):
     strict: bool
     frozen: bool
     extra_behavior: ExtraBehavior
+    generate_repr: bool  # default: False
+    generate_eq: bool  # default: False
+    context: dict[str, Any]
     config: CoreConfig
     ref: str
     metadata: dict[str, Any]
@@ -3136,6 +4495,9 @@ def model_schema(
     strict: bool | None = None,
     frozen: bool | None = None,
     extra_behavior: ExtraBehavior | None = None,
+    generate_repr: bool | None = None,
+    generate_eq: bool | None = None,
+    context: dict[str, Any] | None = None,
     config: CoreConfig | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
@@ -3185,6 +4547,13 @@ def model_schema(
         strict: Whether the model is strict
         frozen: Whether the model is frozen
         extra_behavior: The extra behavior to use for the model, used in serialization
+        generate_repr: Whether to set `__repr__` and `__rich_repr__` on the class, built from the fields and extras
+            of the instance, honoring each field's `repr` setting
+        generate_eq: Whether to set `__eq__` on the class, comparing the fields, extras and private attributes of
+            instances of the same class (or generic origin), `__hash__` is set too if the model is frozen,
+            otherwise it's set to `None`
+        context: Entries added to the validation context while the model's fields are validated, the context must
+            be a dict (or `None`), the entries and any changes made to the context are discarded afterwards
         config: The config to use for the model
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -3202,6 +4571,9 @@ def model_schema(
         strict=strict,
         frozen=frozen,
         extra_behavior=extra_behavior,
+        generate_repr=generate_repr,
+        generate_eq=generate_eq,
+        context=context,
         config=config,
         ref=ref,
         metadata=metadata,
@@ -3217,9 +4589,11 @@ class DataclassField(TypedDict, total=False):
     init: bool  # default: True
     init_only: bool  # default: False
     frozen: bool  # default: False
-    validation_alias: Union[str, list[Union[str, int]], list[list[Union[str, int]]]]
+    validation_alias: Union[str, list[Union[str, int, EllipsisType]], list[list[Union[str, int, EllipsisType]]]]
     serialization_alias: str
     serialization_exclude: bool  # default: False
+    serialization_exclude_none: bool  # default: False
+    serialization_exclude_if: Callable[[Any], bool]
     metadata: dict[str, Any]
 
 
@@ -3230,9 +4604,11 @@ def dataclass_field(
     kw_only: bool | None = None,
     init: bool | None = None,
     init_only: bool | None = None,
-    validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
+    validation_alias: str | list[str | int | EllipsisType] | list[list[str | int | EllipsisType]] | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
+    serialization_exclude_none: bool | None = None,
+    serialization_exclude_if: Callable[[Any], bool] | None = None,
     metadata: dict[str, Any] | None = None,
     frozen: bool | None = None,
 ) -> DataclassField:
@@ -3256,9 +4632,13 @@ def dataclass_field(
         kw_only: Whether the field can be set with a positional argument as well as a keyword argument
         init: Whether the field should be validated during initialization
         init_only: Whether the field should be omitted  from `__dict__` and passed to `__post_init__`
-        validation_alias: The alias(es) to use to find the field in the validation data
+        validation_alias: The alias(es) to use to find the field in the validation data, `...` in an alias path
+            matches every item of a list or value of a dict
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
+        serialization_exclude_none: Whether to exclude the field when serializing if its value is `None`
+        serialization_exclude_if: A callable called with the field's value when serializing, the field is excluded
+            if it returns a truthy value
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         frozen: Whether the field is frozen
     """
@@ -3272,6 +4652,8 @@ def dataclass_field(
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
+        serialization_exclude_none=serialization_exclude_none,
+        serialization_exclude_if=serialization_exclude_if,
         metadata=metadata,
         frozen=frozen,
     )
@@ -3348,6 +4730,7 @@ class DataclassSchema(TypedDict, total=False):
     fields: Required[list[str]]
     cls_name: str
     post_init: bool  # default: False
+    validate_after_post_init: bool  # default: False
     revalidate_instances: Literal['always', 'never', 'subclass-instances']  # default: 'never'
     strict: bool  # default: False
     frozen: bool  # default False
@@ -3355,6 +4738,7 @@ class DataclassSchema(TypedDict, total=False):
     metadata: dict[str, Any]
     serialization: SerSchema
     slots: bool
+    context: dict[str, Any]
     config: CoreConfig
 
 
@@ -3366,6 +4750,7 @@ def dataclass_schema(
     generic_origin: type[Any] | None = None,
     cls_name: str | None = None,
     post_init: bool | None = None,
+    validate_after_post_init: bool | None = None,
     revalidate_instances: Literal['always', 'never', 'subclass-instances'] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
@@ -3373,6 +4758,7 @@ def dataclass_schema(
     serialization: SerSchema | None = None,
     frozen: bool | None = None,
     slots: bool | None = None,
+    context: dict[str, Any] | None = None,
     config: CoreConfig | None = None,
 ) -> DataclassSchema:
     """
@@ -3388,6 +4774,8 @@ def dataclass_schema(
             if this model schema represents `SomeDataclass[int]`, generic_origin is `SomeDataclass`
         cls_name: The name to use in error locs, etc; this is useful for generics (default: `cls.__name__`)
         post_init: Whether to call `__post_init__` after validation
+        validate_after_post_init: Whether to validate fields which `__post_init__` sets or replaces, as if they'd
+            been assigned to, has no effect unless `post_init` is `True`
         revalidate_instances: whether instances of models and dataclasses (including subclass instances)
             should re-validate defaults to config.revalidate_instances, else 'never'
         strict: Whether to require an exact instance of `cls`
@@ -3397,6 +4785,8 @@ def dataclass_schema(
         frozen: Whether the dataclass is frozen
         slots: Whether `slots=True` on the dataclass, means each field is assigned independently, rather than
             simply setting `__dict__`, default false
+        context: Entries added to the validation context while the dataclass's fields are validated, see
+            [`model_schema`][pydantic_core.core_schema.model_schema]
     """
     return _dict_not_none(
         type='dataclass',
@@ -3406,6 +4796,7 @@ def dataclass_schema(
         cls_name=cls_name,
         schema=schema,
         post_init=post_init,
+        validate_after_post_init=validate_after_post_init,
         revalidate_instances=revalidate_instances,
         strict=strict,
         ref=ref,
@@ -3413,6 +4804,7 @@ def dataclass_schema(
         serialization=serialization,
         frozen=frozen,
         slots=slots,
+        context=context,
         config=config,
     )
 
@@ -3421,7 +4813,7 @@ class ArgumentsParameter(TypedDict, total=False):
     name: Required[str]
     schema: Required[CoreSchema]
     mode: Literal['positional_only', 'positional_or_keyword', 'keyword_only']  # default positional_or_keyword
-    alias: Union[str, list[Union[str, int]], list[list[Union[str, int]]]]
+    alias: Union[str, list[Union[str, int, EllipsisType]], list[list[Union[str, int, EllipsisType]]]]
 
 
 def arguments_parameter(
@@ -3429,7 +4821,7 @@ def arguments_parameter(
     schema: CoreSchema,
     *,
     mode: Literal['positional_only', 'positional_or_keyword', 'keyword_only'] | None = None,
-    alias: str | list[str | int] | list[list[str | int]] | None = None,
+    alias: str | list[str | int | EllipsisType] | list[list[str | int | EllipsisType]] | None = None,
 ) -> ArgumentsParameter:
     """
     Returns a schema that matches an argument parameter, e.g.:
@@ -3537,7 +4929,7 @@ class ArgumentsV3Parameter(TypedDict, total=False):
         'var_kwargs_uniform',
         'var_kwargs_unpacked_typed_dict',
     ]  # default positional_or_keyword
-    alias: Union[str, list[Union[str, int]], list[list[Union[str, int]]]]
+    alias: Union[str, list[Union[str, int, EllipsisType]], list[list[Union[str, int, EllipsisType]]]]
 
 
 def arguments_v3_parameter(
@@ -3553,7 +4945,7 @@ def arguments_v3_parameter(
         'var_kwargs_unpacked_typed_dict',
     ]
     | None = None,
-    alias: str | list[str | int] | list[list[str | int]] | None = None,
+    alias: str | list[str | int | EllipsisType] | list[list[str | int | EllipsisType]] | None = None,
 ) -> ArgumentsV3Parameter:
     """
     Returns a schema that matches an argument parameter, e.g.:
@@ -3824,6 +5216,8 @@ class UrlSchema(TypedDict, total=False):
     default_host: str
     default_port: int
     default_path: str
+    preserve_original: bool  # default False
+    allow_relative: bool  # default False
     strict: bool
     ref: str
     metadata: dict[str, Any]
@@ -3838,6 +5232,8 @@ def url_schema(
     default_host: str | None = None,
     default_port: int | None = None,
     default_path: str | None = None,
+    preserve_original: bool | None = None,
+    allow_relative: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
@@ -3862,6 +5258,10 @@ def url_schema(
         default_host: The default host to use if the URL does not have a host
         default_port: The default port to use if the URL does not have a port
         default_path: The default path to use if the URL does not have a path
+        preserve_original: Whether to keep the input string on the URL as `original`, it's used instead of the
+            normalized URL when serializing with `round_trip=True`
+        allow_relative: Whether to accept path-absolute (`/path`) and scheme-relative (`//host/path`) references,
+            these have an empty `scheme` and `is_relative` set, they're rejected when `allowed_schemes` is set
         strict: Whether to use strict URL parsing
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -3875,6 +5275,8 @@ def url_schema(
         default_host=default_host,
         default_port=default_port,
         default_path=default_path,
+        preserve_original=preserve_original,
+        allow_relative=allow_relative,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -3890,6 +5292,11 @@ class MultiHostUrlSchema(TypedDict, total=False):
     default_host: str
     default_port: int
     default_path: str
+    preserve_original: bool  # default False
+    min_hosts: int
+    max_hosts: int
+    port_required: bool  # default False
+    userinfo_required: bool  # default False
     strict: bool
     ref: str
     metadata: dict[str, Any]
@@ -3904,6 +5311,11 @@ def multi_host_url_schema(
     default_host: str | None = None,
     default_port: int | None = None,
     default_path: str | None = None,
+    preserve_original: bool | None = None,
+    min_hosts: int | None = None,
+    max_hosts: int | None = None,
+    port_required: bool | None = None,
+    userinfo_required: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: dict[str, Any] | None = None,
@@ -3928,6 +5340,12 @@ def multi_host_url_schema(
         default_host: The default host to use if the URL does not have a host
         default_port: The default port to use if the URL does not have a port
         default_path: The default path to use if the URL does not have a path
+        preserve_original: Whether to keep the input string on the URL as `original`, it's used instead of the
+            normalized URL when serializing with `round_trip=True`
+        min_hosts: The minimum number of hosts
+        max_hosts: The maximum number of hosts
+        port_required: Whether every host must have a port, either explicit or the scheme's default
+        userinfo_required: Whether every host must have a username
         strict: Whether to use strict URL parsing
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -3941,6 +5359,64 @@ def multi_host_url_schema(
         default_host=default_host,
         default_port=default_port,
         default_path=default_path,
+        preserve_original=preserve_original,
+        min_hosts=min_hosts,
+        max_hosts=max_hosts,
+        port_required=port_required,
+        userinfo_required=userinfo_required,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class EmailSchema(TypedDict, total=False):
+    type: Required[Literal['email']]
+    allow_smtputf8: bool  # default True
+    strict: bool
+    ref: str
+    metadata: dict[str, Any]
+    serialization: SerSchema
+
+
+def email_schema(
+    *,
+    allow_smtputf8: bool | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> EmailSchema:
+    """
+    Returns a schema that matches an email address, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.email_schema()
+    v = SchemaValidator(schema)
+    email = v.validate_python('John.Doe@Example.COM')
+    print(email)
+    #> John.Doe@example.com
+    print(email.local_part, email.domain)
+    #> John.Doe example.com
+    ```
+
+    Addresses are validated per RFC 5321, and RFC 6531 for internationalized addresses, the domain is
+    normalized to lowercase and internationalized domain names are handled via IDNA.
+    Quoted local parts and domain literals are not supported.
+
+    Args:
+        allow_smtputf8: Whether to allow internationalized characters in the local part, defaults to `True`
+        strict: Whether to use strict input validation, e.g. not accepting bytes
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='email',
+        allow_smtputf8=allow_smtputf8,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -4033,6 +5509,7 @@ if not MYPY:
         IntSchema,
         FloatSchema,
         DecimalSchema,
+        FractionSchema,
         StringSchema,
         BytesSchema,
         DateSchema,
@@ -4044,12 +5521,17 @@ if not MYPY:
         IsInstanceSchema,
         IsSubclassSchema,
         CallableSchema,
+        ProtocolCustomSchema,
         ListSchema,
         TupleSchema,
         SetSchema,
         FrozenSetSchema,
         GeneratorSchema,
         DictSchema,
+        DequeSchema,
+        CounterSchema,
+        DefaultDictSchema,
+        NamedTupleSchema,
         AfterValidatorFunctionSchema,
         BeforeValidatorFunctionSchema,
         WrapValidatorFunctionSchema,
@@ -4061,6 +5543,7 @@ if not MYPY:
         ChainSchema,
         LaxOrStrictSchema,
         JsonOrPythonSchema,
+        ContextSwitchSchema,
         TypedDictSchema,
         ModelFieldsSchema,
         ModelSchema,
@@ -4073,9 +5556,16 @@ if not MYPY:
         JsonSchema,
         UrlSchema,
         MultiHostUrlSchema,
+        EmailSchema,
         DefinitionsSchema,
         DefinitionReferenceSchema,
         UuidSchema,
+        PathSchema,
+        IpAddressSchema,
+        IpNetworkSchema,
+        IpInterfaceSchema,
+        SecretStrSchema,
+        SecretBytesSchema,
         ComplexSchema,
     ]
 elif False:
@@ -4091,6 +5581,7 @@ CoreSchemaType = Literal[
     'int',
     'float',
     'decimal',
+    'fraction',
     'str',
     'bytes',
     'date',
@@ -4102,12 +5593,17 @@ CoreSchemaType = Literal[
     'is-instance',
     'is-subclass',
     'callable',
+    'protocol-custom',
     'list',
     'tuple',
     'set',
     'frozenset',
     'generator',
     'dict',
+    'deque',
+    'counter',
+    'defaultdict',
+    'namedtuple',
     'function-after',
     'function-before',
     'function-wrap',
@@ -4119,6 +5615,7 @@ CoreSchemaType = Literal[
     'chain',
     'lax-or-strict',
     'json-or-python',
+    'context-switch',
     'typed-dict',
     'model-fields',
     'model',
@@ -4131,9 +5628,16 @@ CoreSchemaType = Literal[
     'json',
     'url',
     'multi-host-url',
+    'email',
     'definitions',
     'definition-ref',
     'uuid',
+    'path',
+    'ip-address',
+    'ip-network',
+    'ip-interface',
+    'secret_str',
+    'secret_bytes',
     'complex',
 ]
 
@@ -4148,10 +5652,12 @@ ErrorType = Literal[
     'json_type',
     'needs_python_object',
     'recursion_loop',
+    'resource_limit_exceeded',
     'missing',
     'frozen_field',
     'frozen_instance',
     'extra_forbidden',
+    'extra_keys_forbidden',
     'invalid_key',
     'get_attribute_error',
     'model_type',
@@ -4167,6 +5673,7 @@ ErrorType = Literal[
     'finite_number',
     'too_short',
     'too_long',
+    'duplicate_item',
     'iterable_type',
     'iteration_error',
     'string_type',
@@ -4174,10 +5681,13 @@ ErrorType = Literal[
     'string_unicode',
     'string_too_short',
     'string_too_long',
+    'string_too_short_bytes',
+    'string_too_long_bytes',
     'string_pattern_mismatch',
     'enum',
     'dict_type',
     'mapping_type',
+    'dict_key_forbidden',
     'list_type',
     'tuple_type',
     'set_type',
@@ -4214,14 +5724,21 @@ ErrorType = Literal[
     'timezone_naive',
     'timezone_aware',
     'timezone_offset',
+    'timezone_not_allowed',
     'time_delta_type',
     'time_delta_parsing',
     'frozen_set_type',
     'is_instance_of',
     'is_subclass_of',
     'callable_type',
+    'callable_arity',
+    'callable_keywords',
+    'callable_coroutine',
     'union_tag_invalid',
     'union_tag_not_found',
+    'union_member_failed',
+    'context_switch_invalid',
+    'context_switch_not_found',
     'arguments_type',
     'missing_argument',
     'unexpected_keyword_argument',
@@ -4234,14 +5751,27 @@ ErrorType = Literal[
     'url_syntax_violation',
     'url_too_long',
     'url_scheme',
+    'url_too_few_hosts',
+    'url_too_many_hosts',
+    'url_host_port_required',
+    'url_host_userinfo_required',
+    'email_type',
+    'email_parsing',
     'uuid_type',
     'uuid_parsing',
     'uuid_version',
+    'path_type',
+    'path_not_absolute',
+    'path_suffix',
+    'ip_type',
+    'ip_parsing',
     'decimal_type',
     'decimal_parsing',
     'decimal_max_digits',
     'decimal_max_places',
     'decimal_whole_digits',
+    'fraction_type',
+    'fraction_parsing',
     'complex_type',
     'complex_str_parsing',
 ]
@@ -0,0 +1,329 @@
+//! Export of validated lists of scalars via the Arrow PyCapsule interface, see
+//! https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html
+//!
+//! The C data interface structs are defined here rather than pulling in an arrow crate since we only
+//! ever export flat arrays of a handful of primitive types.
+
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::fmt;
+use std::sync::Arc;
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::ffi::c_str;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyCapsule, PyDict, PyList, PyString};
+
+use crate::tools::SchemaDict;
+
+const ARROW_FLAG_NULLABLE: i64 = 2;
+
+#[repr(C)]
+struct FfiArrowSchema {
+    format: *const c_char,
+    name: *const c_char,
+    metadata: *const c_char,
+    flags: i64,
+    n_children: i64,
+    children: *mut *mut FfiArrowSchema,
+    dictionary: *mut FfiArrowSchema,
+    release: Option<unsafe extern "C" fn(*mut FfiArrowSchema)>,
+    private_data: *mut c_void,
+}
+
+// Safety: the schema only points to static strings
+unsafe impl Send for FfiArrowSchema {}
+
+#[repr(C)]
+struct FfiArrowArray {
+    length: i64,
+    null_count: i64,
+    offset: i64,
+    n_buffers: i64,
+    n_children: i64,
+    buffers: *mut *const c_void,
+    children: *mut *mut FfiArrowArray,
+    dictionary: *mut FfiArrowArray,
+    release: Option<unsafe extern "C" fn(*mut FfiArrowArray)>,
+    private_data: *mut c_void,
+}
+
+// Safety: the buffers are owned by `private_data` which is only freed by `release`
+unsafe impl Send for FfiArrowArray {}
+
+/// Keeps the data alive until the consumer of the array calls `release`
+struct ArrayPrivateData {
+    _data: Arc<ArrowData>,
+    buffers: Vec<*const c_void>,
+}
+
+unsafe extern "C" fn release_schema(schema: *mut FfiArrowSchema) {
+    if let Some(schema) = schema.as_mut() {
+        // all the pointers in the schema are static, so there's nothing to free
+        schema.release = None;
+    }
+}
+
+unsafe extern "C" fn release_array(array: *mut FfiArrowArray) {
+    if let Some(array) = array.as_mut() {
+        drop(Box::from_raw(array.private_data.cast::<ArrayPrivateData>()));
+        array.private_data = std::ptr::null_mut();
+        array.release = None;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrowType {
+    Int64,
+    Float64,
+    Boolean,
+    Utf8,
+}
+
+impl ArrowType {
+    /// The arrow type to use for the output of a core schema, `None` if the output can't be exported,
+    /// currently only lists of `int`, `float`, `bool` and `str`, optionally `nullable`, are supported
+    pub fn from_schema(schema: &Bound<'_, PyAny>) -> PyResult<Option<(Self, bool)>> {
+        let py = schema.py();
+        let Ok(schema) = schema.downcast::<PyDict>() else {
+            return Ok(None);
+        };
+        let schema_type: Bound<'_, PyString> = schema.get_as_req(intern!(py, "type"))?;
+        if schema_type.to_str()? != "list" {
+            return Ok(None);
+        }
+        let Some(mut items_schema) = schema.get_as::<Bound<'_, PyDict>>(intern!(py, "items_schema"))? else {
+            return Ok(None);
+        };
+        let mut items_type: Bound<'_, PyString> = items_schema.get_as_req(intern!(py, "type"))?;
+        let nullable = items_type.to_str()? == "nullable";
+        if nullable {
+            items_schema = items_schema.get_as_req(intern!(py, "schema"))?;
+            items_type = items_schema.get_as_req(intern!(py, "type"))?;
+        }
+        let arrow_type = match items_type.to_str()? {
+            "int" => Self::Int64,
+            "float" => Self::Float64,
+            "bool" => Self::Boolean,
+            "str" => Self::Utf8,
+            _ => return Ok(None),
+        };
+        Ok(Some((arrow_type, nullable)))
+    }
+
+    fn format(self) -> &'static CStr {
+        match self {
+            Self::Int64 => c_str!("l"),
+            Self::Float64 => c_str!("g"),
+            Self::Boolean => c_str!("b"),
+            Self::Utf8 => c_str!("u"),
+        }
+    }
+}
+
+impl fmt::Display for ArrowType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int64 => write!(f, "int64"),
+            Self::Float64 => write!(f, "float64"),
+            Self::Boolean => write!(f, "bool"),
+            Self::Utf8 => write!(f, "utf8"),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum ArrowValues {
+    Int64(Vec<i64>),
+    Float64(Vec<f64>),
+    /// bit packed, least significant bit first
+    Boolean(Vec<u8>),
+    Utf8 {
+        offsets: Vec<i32>,
+        data: Vec<u8>,
+    },
+}
+
+#[derive(Debug)]
+struct ArrowData {
+    length: usize,
+    null_count: usize,
+    /// bit packed, `None` if there are no nulls
+    validity: Option<Vec<u8>>,
+    values: ArrowValues,
+}
+
+fn set_bit(bits: &mut [u8], index: usize) {
+    bits[index / 8] |= 1 << (index % 8);
+}
+
+impl ArrowData {
+    fn from_list(arrow_type: ArrowType, list: &Bound<'_, PyList>) -> PyResult<Self> {
+        let length = list.len();
+        let bitmap_len = length.div_ceil(8);
+        let mut validity = vec![0u8; bitmap_len];
+        let mut null_count = 0;
+        let mut values = match arrow_type {
+            ArrowType::Int64 => ArrowValues::Int64(Vec::with_capacity(length)),
+            ArrowType::Float64 => ArrowValues::Float64(Vec::with_capacity(length)),
+            ArrowType::Boolean => ArrowValues::Boolean(vec![0u8; bitmap_len]),
+            ArrowType::Utf8 => ArrowValues::Utf8 {
+                offsets: vec![0],
+                data: Vec::new(),
+            },
+        };
+
+        for (index, item) in list.iter().enumerate() {
+            let is_null = item.is_none();
+            if is_null {
+                null_count += 1;
+            } else {
+                set_bit(&mut validity, index);
+            }
+            match &mut values {
+                ArrowValues::Int64(v) => v.push(if is_null { 0 } else { item.extract()? }),
+                ArrowValues::Float64(v) => v.push(if is_null { 0.0 } else { item.extract()? }),
+                ArrowValues::Boolean(bits) => {
+                    if !is_null && item.extract::<bool>()? {
+                        set_bit(bits, index);
+                    }
+                }
+                ArrowValues::Utf8 { offsets, data } => {
+                    if !is_null {
+                        data.extend_from_slice(item.downcast::<PyString>()?.to_str()?.as_bytes());
+                    }
+                    let offset = i32::try_from(data.len())
+                        .map_err(|_| PyTypeError::new_err("String data too large to export as an arrow utf8 array"))?;
+                    offsets.push(offset);
+                }
+            }
+        }
+
+        Ok(Self {
+            length,
+            null_count,
+            validity: (null_count > 0).then_some(validity),
+            values,
+        })
+    }
+
+    fn buffers(&self) -> Vec<*const c_void> {
+        let validity = match &self.validity {
+            Some(validity) => validity.as_ptr().cast(),
+            None => std::ptr::null(),
+        };
+        match &self.values {
+            ArrowValues::Int64(v) => vec![validity, v.as_ptr().cast()],
+            ArrowValues::Float64(v) => vec![validity, v.as_ptr().cast()],
+            ArrowValues::Boolean(v) => vec![validity, v.as_ptr().cast()],
+            ArrowValues::Utf8 { offsets, data } => vec![validity, offsets.as_ptr().cast(), data.as_ptr().cast()],
+        }
+    }
+}
+
+/// The validated output of a schema, exported via the Arrow PyCapsule interface
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+pub struct ArrowArray {
+    arrow_type: ArrowType,
+    nullable: bool,
+    data: Arc<ArrowData>,
+}
+
+impl ArrowArray {
+    pub fn new(arrow_type: ArrowType, nullable: bool, list: &Bound<'_, PyList>) -> PyResult<Self> {
+        Ok(Self {
+            arrow_type,
+            nullable,
+            data: Arc::new(ArrowData::from_list(arrow_type, list)?),
+        })
+    }
+
+    fn schema_capsule<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyCapsule>> {
+        let schema = FfiArrowSchema {
+            format: self.arrow_type.format().as_ptr(),
+            name: c_str!("").as_ptr(),
+            metadata: std::ptr::null(),
+            flags: if self.nullable { ARROW_FLAG_NULLABLE } else { 0 },
+            n_children: 0,
+            children: std::ptr::null_mut(),
+            dictionary: std::ptr::null_mut(),
+            release: Some(release_schema),
+            private_data: std::ptr::null_mut(),
+        };
+        PyCapsule::new_with_destructor(
+            py,
+            schema,
+            Some(CString::from(c_str!("arrow_schema"))),
+            |mut schema, _| {
+                // the consumer sets `release` to null if it has taken ownership
+                if let Some(release) = schema.release {
+                    unsafe { release(&mut schema) };
+                }
+            },
+        )
+    }
+
+    fn array_capsule<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyCapsule>> {
+        let mut private_data = Box::new(ArrayPrivateData {
+            _data: self.data.clone(),
+            buffers: self.data.buffers(),
+        });
+        let array = FfiArrowArray {
+            length: self.data.length as i64,
+            null_count: self.data.null_count as i64,
+            offset: 0,
+            n_buffers: private_data.buffers.len() as i64,
+            n_children: 0,
+            buffers: private_data.buffers.as_mut_ptr(),
+            children: std::ptr::null_mut(),
+            dictionary: std::ptr::null_mut(),
+            release: Some(release_array),
+            private_data: Box::into_raw(private_data).cast(),
+        };
+        PyCapsule::new_with_destructor(py, array, Some(CString::from(c_str!("arrow_array"))), |mut array, _| {
+            if let Some(release) = array.release {
+                unsafe { release(&mut array) };
+            }
+        })
+    }
+}
+
+#[pymethods]
+impl ArrowArray {
+    #[getter]
+    fn r#type(&self) -> String {
+        self.arrow_type.to_string()
+    }
+
+    #[getter]
+    fn null_count(&self) -> usize {
+        self.data.null_count
+    }
+
+    fn __len__(&self) -> usize {
+        self.data.length
+    }
+
+    fn __arrow_c_schema__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyCapsule>> {
+        self.schema_capsule(py)
+    }
+
+    /// `requested_schema` is ignored, as allowed by the interface, consumers are expected to cast if needed
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_array__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<&Bound<'py, PyAny>>,
+    ) -> PyResult<(Bound<'py, PyCapsule>, Bound<'py, PyCapsule>)> {
+        let _ = requested_schema;
+        Ok((self.schema_capsule(py)?, self.array_capsule(py)?))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ArrowArray(type={}, length={}, null_count={})",
+            self.arrow_type, self.data.length, self.data.null_count
+        )
+    }
+}
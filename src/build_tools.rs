@@ -3,12 +3,12 @@ use std::fmt;
 
 use pyo3::exceptions::PyException;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyString};
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyFrozenSet, PyInt, PyList, PySet, PyString, PyTuple};
 use pyo3::{intern, FromPyObject, PyErrArguments};
 
 use crate::errors::{PyLineError, ValError};
 use crate::input::InputType;
-use crate::tools::SchemaDict;
+use crate::tools::{to_camel_case, to_snake_case, SchemaDict};
 use crate::ValidationError;
 
 pub fn schema_or_config<'py, T>(
@@ -85,14 +85,7 @@ impl SchemaError {
     pub fn from_val_error(py: Python, error: ValError) -> PyErr {
         match error {
             ValError::LineErrors(raw_errors) => {
-                let line_errors = match raw_errors
-                    .into_iter()
-                    .map(|e| PyLineError::from_val_line_error(py, e))
-                    .collect::<PyResult<_>>()
-                {
-                    Ok(errors) => errors,
-                    Err(err) => return err,
-                };
+                let line_errors = raw_errors.into_iter().map(PyLineError::from_val_line_error).collect();
                 let validation_error = ValidationError::new(
                     line_errors,
                     PyString::new(py, "Schema").into(),
@@ -136,7 +129,7 @@ impl SchemaError {
     fn errors(&self, py: Python) -> PyResult<Py<PyList>> {
         match &self.0 {
             SchemaErrorEnum::Message(_) => Ok(PyList::empty(py).unbind()),
-            SchemaErrorEnum::ValidationError(error) => error.errors(py, false, false, true),
+            SchemaErrorEnum::ValidationError(error) => error.errors(py, false, false, true, None),
         }
     }
 
@@ -206,3 +199,329 @@ impl ExtraBehavior {
         Ok(res)
     }
 }
+
+/// Case conversion used to derive aliases from field names which don't have an alias set in the schema
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum AliasGenerator {
+    Camel,
+    Pascal,
+    Snake,
+    Kebab,
+}
+
+impl AliasGenerator {
+    /// generator for validation aliases, from the `alias_generator` config key
+    pub fn validation(config: Option<&Bound<'_, PyDict>>) -> PyResult<Option<Self>> {
+        let py = match config {
+            Some(config) => config.py(),
+            None => return Ok(None),
+        };
+        Self::from_config(config, intern!(py, "alias_generator"))
+    }
+
+    /// generator for serialization aliases, `serialization_alias_generator` falling back to `alias_generator`
+    pub fn serialization(config: Option<&Bound<'_, PyDict>>) -> PyResult<Option<Self>> {
+        let py = match config {
+            Some(config) => config.py(),
+            None => return Ok(None),
+        };
+        match Self::from_config(config, intern!(py, "serialization_alias_generator"))? {
+            Some(generator) => Ok(Some(generator)),
+            None => Self::from_config(config, intern!(py, "alias_generator")),
+        }
+    }
+
+    fn from_config(config: Option<&Bound<'_, PyDict>>, key: &Bound<'_, PyString>) -> PyResult<Option<Self>> {
+        let value: Option<Bound<'_, PyString>> = config.get_as(key)?;
+        let res = match value.as_ref().map(|s| s.to_str()).transpose()? {
+            Some("camel") => Self::Camel,
+            Some("pascal") => Self::Pascal,
+            Some("snake") => Self::Snake,
+            Some("kebab") => Self::Kebab,
+            Some(v) => return py_schema_err!("Invalid {}: `{}`", key, v),
+            None => return Ok(None),
+        };
+        Ok(Some(res))
+    }
+
+    /// Convert `name` to this generator's case, leading underscores are kept as they are
+    pub fn generate(self, name: &str) -> String {
+        let stripped = name.trim_start_matches('_');
+        let prefix = &name[..name.len() - stripped.len()];
+        let converted = match self {
+            Self::Camel => to_camel_case(stripped),
+            Self::Snake => to_snake_case(stripped),
+            Self::Kebab => to_snake_case(stripped).replace('_', "-"),
+            Self::Pascal => {
+                let camel = to_camel_case(stripped);
+                let mut chars = camel.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => camel,
+                }
+            }
+        };
+        format!("{prefix}{converted}")
+    }
+}
+
+/// The alias from the schema if it's set, otherwise the alias generated from `name` if it differs from `name`
+pub(crate) fn alias_or_generated<'py>(
+    py: Python<'py>,
+    alias: Option<Bound<'py, PyAny>>,
+    generator: Option<AliasGenerator>,
+    name: &str,
+) -> Option<Bound<'py, PyAny>> {
+    alias.or_else(|| {
+        generator
+            .map(|generator| generator.generate(name))
+            .filter(|generated| generated != name)
+            .map(|generated| PyString::new(py, &generated).into_any())
+    })
+}
+
+/// Build a normalized copy of a core schema, as returned by `dump_schema()`.
+///
+/// Nested `definitions` schemas are replaced by their inner schema and all definitions are hoisted into a
+/// single `definitions` schema at the root, so every definition lives in one place and is only referenced via
+/// `definition-ref`. Parts of the schema which don't change are shared with the original rather than copied.
+pub fn normalize_schema<'py>(schema: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let py = schema.py();
+    let mut definitions = Vec::new();
+    let normalized = match normalize_value(schema, &mut definitions)? {
+        Some(normalized) => normalized,
+        None => match schema.downcast::<PyDict>() {
+            Ok(dict) => dict.copy()?.into_any(),
+            Err(_) => schema.clone(),
+        },
+    };
+    if definitions.is_empty() {
+        return Ok(normalized);
+    }
+    let dict = PyDict::new(py);
+    dict.set_item(intern!(py, "type"), intern!(py, "definitions"))?;
+    dict.set_item(intern!(py, "schema"), normalized)?;
+    dict.set_item(intern!(py, "definitions"), PyList::new(py, definitions)?)?;
+    Ok(dict.into_any())
+}
+
+/// Returns `None` if `value` is unchanged by normalization
+fn normalize_value<'py>(
+    value: &Bound<'py, PyAny>,
+    definitions: &mut Vec<Bound<'py, PyAny>>,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let py = value.py();
+    if let Ok(dict) = value.downcast::<PyDict>() {
+        let schema_type = schema_type(dict)?;
+        if schema_type.as_ref().is_some_and(|t| t == "definitions") {
+            let schema_definitions: Bound<'_, PyList> = dict.get_as_req(intern!(py, "definitions"))?;
+            for definition in schema_definitions {
+                let definition = normalize_value(&definition, definitions)?.unwrap_or(definition);
+                definitions.push(definition);
+            }
+            let inner_schema: Bound<'_, PyAny> = dict.get_as_req(intern!(py, "schema"))?;
+            return Ok(Some(
+                normalize_value(&inner_schema, definitions)?.unwrap_or(inner_schema),
+            ));
+        }
+
+        let mut new_dict: Option<Bound<'_, PyDict>> = None;
+        for (key, item) in dict {
+            // a schema's metadata and default are arbitrary user data, not schemas, but elsewhere (e.g. in a
+            // typed dict's `fields`) these are just names
+            if schema_type.is_some() && (key.eq(intern!(py, "metadata"))? || key.eq(intern!(py, "default"))?) {
+                continue;
+            }
+            if let Some(new_item) = normalize_value(&item, definitions)? {
+                let new_dict = match new_dict {
+                    Some(ref new_dict) => new_dict,
+                    None => new_dict.insert(dict.copy()?),
+                };
+                new_dict.set_item(key, new_item)?;
+            }
+        }
+        Ok(new_dict.map(Bound::into_any))
+    } else if let Ok(list) = value.downcast::<PyList>() {
+        normalize_items(list.iter(), definitions)?
+            .map(|items| PyList::new(py, items).map(Bound::into_any))
+            .transpose()
+    } else if let Ok(tuple) = value.downcast::<PyTuple>() {
+        normalize_items(tuple.iter(), definitions)?
+            .map(|items| PyTuple::new(py, items).map(Bound::into_any))
+            .transpose()
+    } else {
+        Ok(None)
+    }
+}
+
+/// The `type` of `dict` if it's a schema, `None` if it's some other dict, e.g. a typed dict's `fields`
+fn schema_type(dict: &Bound<'_, PyDict>) -> PyResult<Option<String>> {
+    match dict.get_item(intern!(dict.py(), "type"))? {
+        Some(type_) => match type_.downcast::<PyString>() {
+            Ok(type_) => Ok(Some(type_.to_str()?.to_string())),
+            Err(_) => Ok(None),
+        },
+        None => Ok(None),
+    }
+}
+
+fn normalize_items<'py>(
+    items: impl Iterator<Item = Bound<'py, PyAny>>,
+    definitions: &mut Vec<Bound<'py, PyAny>>,
+) -> PyResult<Option<Vec<Bound<'py, PyAny>>>> {
+    let mut changed = false;
+    let mut new_items = Vec::new();
+    for item in items {
+        match normalize_value(&item, definitions)? {
+            Some(new_item) => {
+                changed = true;
+                new_items.push(new_item);
+            }
+            None => new_items.push(item),
+        }
+    }
+    Ok(changed.then_some(new_items))
+}
+
+/// A hash of a core schema and its config which, unlike python's `hash()`, is the same across processes.
+///
+/// Classes and functions are identified by their module and qualified name, schemas' `metadata` is ignored as it
+/// doesn't affect validation. Other objects (e.g. defaults) are identified by their type and `repr()`, unless the repr
+/// includes the object's address.
+pub fn schema_hash(schema: &Bound<'_, PyAny>, config: Option<&Bound<'_, PyDict>>) -> PyResult<u64> {
+    let mut hasher = SchemaHasher::default();
+    hasher.write_value(schema, &mut Vec::new())?;
+    if let Some(config) = config {
+        hasher.write_value(config, &mut Vec::new())?;
+    }
+    Ok(hasher.0)
+}
+
+/// 64-bit FNV-1a, chosen because it's simple and its output never changes
+struct SchemaHasher(u64);
+
+impl Default for SchemaHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl SchemaHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_tagged(&mut self, tag: u8, bytes: &[u8]) {
+        self.write(&[tag]);
+        self.write(&(bytes.len() as u64).to_le_bytes());
+        self.write(bytes);
+    }
+
+    /// `parents` holds the addresses of the containers `value` is within, so cyclic references are only visited once
+    fn write_value(&mut self, value: &Bound<'_, PyAny>, parents: &mut Vec<usize>) -> PyResult<()> {
+        let py = value.py();
+        if value.is_none() {
+            self.write(b"n");
+        } else if let Ok(py_str) = value.downcast::<PyString>() {
+            self.write_tagged(b's', py_str.to_str()?.as_bytes());
+        } else if let Ok(py_bytes) = value.downcast::<PyBytes>() {
+            self.write_tagged(b'y', py_bytes.as_bytes());
+        } else if value.is_instance_of::<PyBool>()
+            || value.is_instance_of::<PyInt>()
+            || value.is_instance_of::<PyFloat>()
+        {
+            self.write_tagged(b'i', value.repr()?.to_str()?.as_bytes());
+        } else if value.is_instance_of::<PyDict>()
+            || value.is_instance_of::<PyList>()
+            || value.is_instance_of::<PyTuple>()
+        {
+            let address = value.as_ptr() as usize;
+            if parents.contains(&address) {
+                self.write(b"c");
+                return Ok(());
+            }
+            parents.push(address);
+            if let Ok(dict) = value.downcast::<PyDict>() {
+                self.write(b"d");
+                // only a schema's metadata is skipped, elsewhere (e.g. in a typed dict's `fields`) it's just a name
+                let is_schema = schema_type(dict)?.is_some();
+                for (key, item) in dict {
+                    if is_schema && key.eq(intern!(py, "metadata"))? {
+                        continue;
+                    }
+                    self.write_value(&key, parents)?;
+                    self.write_value(&item, parents)?;
+                }
+                self.write(b"D");
+            } else {
+                self.write_tagged(b'l', &(value.len()? as u64).to_le_bytes());
+                for item in value.try_iter()? {
+                    self.write_value(&item?, parents)?;
+                }
+            }
+            parents.pop();
+        } else if value.is_instance_of::<PySet>() || value.is_instance_of::<PyFrozenSet>() {
+            // the iteration order of sets varies between processes, so items are combined regardless of order
+            let mut combined: u64 = 0;
+            for item in value.try_iter()? {
+                let mut item_hasher = Self::default();
+                item_hasher.write_value(&item?, parents)?;
+                combined = combined.wrapping_add(item_hasher.0);
+            }
+            self.write_tagged(b'e', &combined.to_le_bytes());
+        } else if let Ok(qualname) = value.getattr(intern!(py, "__qualname__")) {
+            let module = value.getattr(intern!(py, "__module__"))?;
+            self.write_tagged(b'q', format!("{module}.{qualname}").as_bytes());
+        } else {
+            let py_type = value.get_type();
+            self.write_tagged(
+                b'o',
+                format!("{}.{}", py_type.module()?, py_type.qualname()?).as_bytes(),
+            );
+            let repr = value.repr()?;
+            let repr = repr.to_str()?;
+            if !repr.contains(" at 0x") {
+                self.write_tagged(b'r', repr.as_bytes());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The config to use for a schema which carries its own `config`, i.e. models, dataclasses and typed dicts.
+///
+/// How the schema's config combines with the parent config depends on `config_merge` in the parent config:
+/// * `'replace'` (the default) - the schema's config is used on its own, the parent config is ignored
+/// * `'inherit'` - keys set in the schema's config take precedence, other keys are inherited from the parent
+/// * `'override'` - keys set in the parent config take precedence over the schema's config
+pub fn nested_config<'py>(
+    schema: &Bound<'py, PyDict>,
+    parent_config: Option<&Bound<'py, PyDict>>,
+) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let py = schema.py();
+    let config: Option<Bound<'py, PyDict>> = schema.get_as(intern!(py, "config"))?;
+    let Some(parent_config) = parent_config else {
+        return Ok(config);
+    };
+    let merge_mode: Option<Bound<'py, PyString>> = parent_config.get_as(intern!(py, "config_merge"))?;
+    let inherit = match merge_mode.as_ref().map(|m| m.to_str()).transpose()? {
+        None | Some("replace") => return Ok(config),
+        Some("inherit") => true,
+        Some("override") => false,
+        Some(s) => return py_schema_err!("Invalid config_merge value: {}", s),
+    };
+    let Some(config) = config else {
+        return Ok(Some(parent_config.clone()));
+    };
+    let (base, overrides) = if inherit {
+        (parent_config, &config)
+    } else {
+        (&config, parent_config)
+    };
+    let merged = base.copy()?;
+    merged.update(overrides.as_mapping())?;
+    Ok(Some(merged))
+}
@@ -1,6 +1,9 @@
 use pyo3::prelude::*;
+use pyo3::types::PyTuple;
 use pyo3::{PyTraverseError, PyVisit};
 
+use crate::build_tools::py_schema_err;
+
 use crate::lookup_key::LookupKey;
 use crate::py_gc::PyGcTraverse;
 
@@ -10,6 +13,8 @@ pub enum Discriminator {
     LookupKey(LookupKey),
     /// call a function to find the tag to use
     Function(PyObject),
+    /// use several `LookupKey`s, the tag is the tuple of the values found
+    Composite(Vec<LookupKey>),
 }
 
 impl Discriminator {
@@ -17,6 +22,16 @@ impl Discriminator {
         if raw.is_callable() {
             return Ok(Self::Function(raw.clone().unbind()));
         }
+        if let Ok(keys) = raw.downcast::<PyTuple>() {
+            if keys.is_empty() {
+                return py_schema_err!("A composite discriminator should have at least one key");
+            }
+            let lookup_keys = keys
+                .iter()
+                .map(|key| LookupKey::from_py(py, &key, None))
+                .collect::<PyResult<_>>()?;
+            return Ok(Self::Composite(lookup_keys));
+        }
 
         let lookup_key = LookupKey::from_py(py, raw, None)?;
         Ok(Self::LookupKey(lookup_key))
@@ -26,6 +41,14 @@ impl Discriminator {
         match self {
             Self::Function(f) => Ok(format!("{}()", f.getattr(py, "__name__")?)),
             Self::LookupKey(lookup_key) => Ok(lookup_key.to_string()),
+            Self::Composite(lookup_keys) => Ok(format!(
+                "({})",
+                lookup_keys
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
         }
     }
 }
@@ -34,7 +57,7 @@ impl PyGcTraverse for Discriminator {
     fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
         match self {
             Self::Function(obj) => visit.call(obj)?,
-            Self::LookupKey(_) => {}
+            Self::LookupKey(_) | Self::Composite(_) => {}
         }
         Ok(())
     }
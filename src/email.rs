@@ -0,0 +1,200 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use pyo3::pyclass::CompareOp;
+use pyo3::sync::GILOnceCell;
+use pyo3::{prelude::*, IntoPyObjectExt};
+
+use crate::url::build_schema_validator;
+use crate::SchemaValidator;
+
+static SCHEMA_DEFINITION_EMAIL: GILOnceCell<SchemaValidator> = GILOnceCell::new();
+
+/// Longest local part allowed by RFC 5321, in octets
+const MAX_LOCAL_PART_LENGTH: usize = 64;
+/// Longest domain allowed by RFC 5321, in octets, after conversion to ASCII
+const MAX_DOMAIN_LENGTH: usize = 253;
+const MAX_DOMAIN_LABEL_LENGTH: usize = 63;
+
+#[pyclass(name = "EmailAddress", module = "pydantic_core._pydantic_core", subclass, frozen)]
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct PyEmailAddress {
+    local_part: String,
+    // domain with internationalized labels decoded, lowercase
+    domain: String,
+    // domain with internationalized labels punycode encoded, lowercase
+    ascii_domain: String,
+}
+
+impl PyEmailAddress {
+    /// Parse and normalize an email address per RFC 5321, and RFC 6531 for internationalized addresses,
+    /// the error is used as the `error` context of the `email_parsing` error.
+    ///
+    /// Quoted local parts and domain literals (e.g. `user@[127.0.0.1]`) are not supported.
+    pub fn parse(value: &str, allow_smtputf8: bool) -> Result<Self, String> {
+        let Some((local_part, domain)) = value.rsplit_once('@') else {
+            return Err("an email address must have an @-sign".to_string());
+        };
+        if local_part.is_empty() {
+            return Err("there must be something before the @-sign".to_string());
+        }
+        if domain.is_empty() {
+            return Err("there must be something after the @-sign".to_string());
+        }
+        check_local_part(local_part, allow_smtputf8)?;
+        let ascii_domain = domain_to_ascii(domain)?;
+        let (domain, _) = idna::domain_to_unicode(&ascii_domain);
+        Ok(Self {
+            local_part: local_part.to_string(),
+            domain,
+            ascii_domain,
+        })
+    }
+}
+
+fn check_local_part(local_part: &str, allow_smtputf8: bool) -> Result<(), String> {
+    if local_part.len() > MAX_LOCAL_PART_LENGTH {
+        return Err(format!(
+            "the part before the @-sign is too long, it should have at most {MAX_LOCAL_PART_LENGTH} bytes"
+        ));
+    }
+    if local_part.starts_with('.') {
+        return Err("the part before the @-sign cannot start with a period".to_string());
+    }
+    if local_part.ends_with('.') {
+        return Err("the part before the @-sign cannot end with a period".to_string());
+    }
+    if local_part.contains("..") {
+        return Err("the part before the @-sign cannot contain two periods in a row".to_string());
+    }
+    for c in local_part.chars() {
+        if c.is_ascii() {
+            if !is_atext(c) && c != '.' {
+                return Err(format!(
+                    "the part before the @-sign contains an invalid character: {c:?}"
+                ));
+            }
+        } else if !allow_smtputf8 {
+            return Err("internationalized characters before the @-sign are not supported".to_string());
+        } else if c.is_control() || c.is_whitespace() {
+            return Err(format!(
+                "the part before the @-sign contains an invalid character: {c:?}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// `atext` from RFC 5322, the characters allowed in a dot-atom
+fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c)
+}
+
+fn domain_to_ascii(domain: &str) -> Result<String, String> {
+    if domain.starts_with('[') {
+        return Err("domain literals are not supported".to_string());
+    }
+    if domain.starts_with('.') {
+        return Err("the part after the @-sign cannot start with a period".to_string());
+    }
+    if domain.ends_with('.') {
+        return Err("the part after the @-sign cannot end with a period".to_string());
+    }
+    if domain.contains("..") {
+        return Err("the part after the @-sign cannot contain two periods in a row".to_string());
+    }
+    let ascii_domain = idna::domain_to_ascii(domain)
+        .map_err(|_| "the part after the @-sign is not a valid domain name".to_string())?;
+    if ascii_domain.len() > MAX_DOMAIN_LENGTH {
+        return Err(format!(
+            "the part after the @-sign is too long, it should have at most {MAX_DOMAIN_LENGTH} bytes"
+        ));
+    }
+    let labels: Vec<&str> = ascii_domain.split('.').collect();
+    if labels.len() < 2 {
+        return Err("the part after the @-sign should contain a period".to_string());
+    }
+    for label in &labels {
+        if label.len() > MAX_DOMAIN_LABEL_LENGTH {
+            return Err(format!(
+                "the part after the @-sign has a label longer than {MAX_DOMAIN_LABEL_LENGTH} bytes"
+            ));
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err("the part after the @-sign cannot have a label starting or ending with a hyphen".to_string());
+        }
+        if let Some(c) = label.chars().find(|c| !c.is_ascii_alphanumeric() && *c != '-') {
+            return Err(format!(
+                "the part after the @-sign contains an invalid character: {c:?}"
+            ));
+        }
+    }
+    if labels.last().is_some_and(|tld| tld.chars().all(|c| c.is_ascii_digit())) {
+        return Err("the part after the @-sign cannot have a numeric top level domain".to_string());
+    }
+    Ok(ascii_domain)
+}
+
+#[pymethods]
+impl PyEmailAddress {
+    #[new]
+    pub fn py_new(py: Python, email: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let schema_obj = SCHEMA_DEFINITION_EMAIL
+            .get_or_init(py, || build_schema_validator(py, "email"))
+            .validate_python_default(py, email)?;
+        schema_obj.extract(py)
+    }
+
+    #[getter]
+    pub fn local_part(&self) -> &str {
+        &self.local_part
+    }
+
+    #[getter]
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    #[getter]
+    pub fn ascii_domain(&self) -> &str {
+        &self.ascii_domain
+    }
+
+    // string representation of the email with the domain punycode encoded,
+    // `None` if the local part contains internationalized characters so can't be represented in ASCII
+    pub fn ascii_email(&self) -> Option<String> {
+        if self.local_part.is_ascii() {
+            Some(format!("{}@{}", self.local_part, self.ascii_domain))
+        } else {
+            None
+        }
+    }
+
+    pub fn __str__(&self) -> String {
+        format!("{}@{}", self.local_part, self.domain)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("EmailAddress('{}')", self.__str__())
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(self.cmp(other))
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.hash(&mut s);
+        s.finish()
+    }
+
+    #[pyo3(signature = (_memo, /))]
+    pub fn __deepcopy__(&self, py: Python, _memo: Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        self.clone().into_py_any(py)
+    }
+
+    fn __getnewargs__(&self) -> (String,) {
+        (self.__str__(),)
+    }
+}
@@ -169,3 +169,13 @@ pub enum InputValue {
     Python(PyObject),
     Json(JsonValue<'static>),
 }
+
+impl InputValue {
+    /// The input as a python object, JSON inputs are converted each time this is called
+    pub fn to_python<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match self {
+            Self::Python(value) => Ok(value.bind(py).clone()),
+            Self::Json(value) => value.clone().into_pyobject(py),
+        }
+    }
+}
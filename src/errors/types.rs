@@ -2,10 +2,10 @@ use std::any::type_name;
 use std::borrow::Cow;
 use std::fmt;
 
-use pyo3::exceptions::{PyKeyError, PyTypeError};
+use pyo3::exceptions::{PyKeyError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyDict, PyList, PyString};
 
 use ahash::AHashMap;
 use num_bigint::BigInt;
@@ -172,11 +172,20 @@ error_types! {
     // recursion error
     RecursionLoop {},
     // ---------------------
+    // resource limit errors
+    ResourceLimitExceeded {
+        limit: {ctx_type: String, ctx_fn: field_from_context},
+        max_value: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    // ---------------------
     // typed dict specific errors
     Missing {},
     FrozenField {},
     FrozenInstance {},
     ExtraForbidden {},
+    ExtraKeysForbidden {
+        extra_keys: {ctx_type: String, ctx_fn: field_from_context},
+    },
     InvalidKey {},
     GetAttributeError {
         error: {ctx_type: String, ctx_fn: field_from_context},
@@ -228,6 +237,9 @@ error_types! {
         max_length: {ctx_type: usize, ctx_fn: field_from_context},
         actual_length: {ctx_type: Option<usize>, ctx_fn: field_from_context},
     },
+    DuplicateItem {
+        first_index: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     // ---------------------
     // generic collection and iteration errors
     IterableType {},
@@ -245,6 +257,14 @@ error_types! {
     StringTooLong {
         max_length: {ctx_type: usize, ctx_fn: field_from_context},
     },
+    StringTooShortBytes {
+        min_length_bytes: {ctx_type: usize, ctx_fn: field_from_context},
+        actual_length_bytes: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    StringTooLongBytes {
+        max_length_bytes: {ctx_type: usize, ctx_fn: field_from_context},
+        actual_length_bytes: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     StringPatternMismatch {
         pattern: {ctx_type: String, ctx_fn: field_from_context},
     },
@@ -259,6 +279,7 @@ error_types! {
     MappingType {
         error: {ctx_type: Cow<'static, str>, ctx_fn: cow_field_from_context<String, _>},
     },
+    DictKeyForbidden {},
     // ---------------------
     // list errors
     ListType {},
@@ -309,6 +330,7 @@ error_types! {
         // context is a common field in all enums
         error_type: {ctx_type: String, ctx_fn: field_from_context},
         message_template: {ctx_type: String, ctx_fn: field_from_context},
+        severity: {ctx_type: ErrorSeverity, ctx_fn: field_from_context},
     },
     // ---------------------
     // literals
@@ -355,6 +377,10 @@ error_types! {
         tz_expected: {ctx_type: i32, ctx_fn: field_from_context},
         tz_actual: {ctx_type: i32, ctx_fn: field_from_context},
     },
+    TimezoneNotAllowed {
+        tz_allowed: {ctx_type: String, ctx_fn: field_from_context},
+        tz_actual: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // timedelta errors
     TimeDeltaType {},
@@ -373,6 +399,13 @@ error_types! {
         class: {ctx_type: String, ctx_fn: field_from_context},
     },
     CallableType {},
+    CallableArity {
+        arity: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    CallableKeywords {
+        keywords: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    CallableCoroutine {},
     // ---------------------
     // union errors
     UnionTagInvalid {
@@ -383,6 +416,19 @@ error_types! {
     UnionTagNotFound {
         discriminator: {ctx_type: String, ctx_fn: field_from_context},
     },
+    UnionMemberFailed {
+        error_count: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    // ---------------------
+    // context switch errors
+    ContextSwitchInvalid {
+        context_key: {ctx_type: String, ctx_fn: field_from_context},
+        value: {ctx_type: String, ctx_fn: field_from_context},
+        expected_values: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    ContextSwitchNotFound {
+        context_key: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // argument errors
     ArgumentsType {},
@@ -408,6 +454,24 @@ error_types! {
     UrlScheme {
         expected_schemes: {ctx_type: String, ctx_fn: field_from_context},
     },
+    UrlTooFewHosts {
+        min_hosts: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    UrlTooManyHosts {
+        max_hosts: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    UrlHostPortRequired {
+        host_index: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    UrlHostUserinfoRequired {
+        host_index: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    // ---------------------
+    // email errors
+    EmailType {},
+    EmailParsing {
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // UUID errors,
     UuidType {},
     UuidParsing {
@@ -416,6 +480,20 @@ error_types! {
     UuidVersion {
         expected_version: {ctx_type: usize, ctx_fn: field_from_context},
     },
+    // path errors
+    PathType {},
+    PathNotAbsolute {},
+    PathSuffix {
+        expected_suffixes: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    // IP address, network and interface errors
+    IpType {
+        kind: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    IpParsing {
+        kind: {ctx_type: String, ctx_fn: field_from_context},
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // Decimal errors
     DecimalType {},
     DecimalParsing {},
@@ -428,6 +506,9 @@ error_types! {
     DecimalWholeDigits {
         whole_digits: {ctx_type: u64, ctx_fn: field_from_context},
     },
+    // Fraction errors
+    FractionType {},
+    FractionParsing {},
     // Complex errors
     ComplexType {},
     ComplexStrParsing {},
@@ -470,10 +551,18 @@ impl ErrorType {
         Self::CustomError {
             error_type: custom_error.error_type().to_owned(),
             message_template: custom_error.message_template().to_owned(),
+            severity: custom_error.severity(),
             context: custom_error.context(py),
         }
     }
 
+    pub fn severity(&self) -> ErrorSeverity {
+        match self {
+            Self::CustomError { severity, .. } => *severity,
+            _ => ErrorSeverity::Error,
+        }
+    }
+
     pub fn message_template_python(&self) -> &'static str {
         match self {
             Self::NoSuchAttribute {..} => "Object has no attribute '{attribute}'",
@@ -481,10 +570,12 @@ impl ErrorType {
             Self::JsonType {..} => "JSON input should be string, bytes or bytearray",
             Self::NeedsPythonObject {..} => "Cannot check `{method_name}` when validating from json, use a JsonOrPython validator instead",
             Self::RecursionLoop {..} => "Recursion error - cyclic reference detected",
+            Self::ResourceLimitExceeded {..} => "Input exceeds the {limit} limit of {max_value}",
             Self::Missing {..} => "Field required",
             Self::FrozenField {..} => "Field is frozen",
             Self::FrozenInstance {..} => "Instance is frozen",
             Self::ExtraForbidden {..} => "Extra inputs are not permitted",
+            Self::ExtraKeysForbidden {..} => "Extra inputs are not permitted: {extra_keys}",
             Self::InvalidKey {..} => "Keys should be strings",
             Self::GetAttributeError {..} => "Error extracting attribute: {error}",
             Self::ModelType {..} => "Input should be a valid dictionary or instance of {class_name}",
@@ -500,6 +591,7 @@ impl ErrorType {
             Self::FiniteNumber {..} => "Input should be a finite number",
             Self::TooShort {..} => "{field_type} should have at least {min_length} item{expected_plural} after validation, not {actual_length}",
             Self::TooLong {..} => "{field_type} should have at most {max_length} item{expected_plural} after validation, not {actual_length}",
+            Self::DuplicateItem {..} => "Items should be unique, this is a duplicate of the item at index {first_index}",
             Self::IterableType {..} => "Input should be iterable",
             Self::IterationError {..} => "Error iterating over object, error: {error}",
             Self::StringType {..} => "Input should be a valid string",
@@ -507,10 +599,13 @@ impl ErrorType {
             Self::StringUnicode {..} => "Input should be a valid string, unable to parse raw data as a unicode string",
             Self::StringTooShort {..} => "String should have at least {min_length} character{expected_plural}",
             Self::StringTooLong {..} => "String should have at most {max_length} character{expected_plural}",
+            Self::StringTooShortBytes {..} => "String should have at least {min_length_bytes} byte{expected_plural} when UTF-8 encoded, not {actual_length_bytes}",
+            Self::StringTooLongBytes {..} => "String should have at most {max_length_bytes} byte{expected_plural} when UTF-8 encoded, not {actual_length_bytes}",
             Self::StringPatternMismatch {..} => "String should match pattern '{pattern}'",
             Self::Enum {..} => "Input should be {expected}",
             Self::DictType {..} => "Input should be a valid dictionary",
             Self::MappingType {..} => "Input should be a valid mapping, error: {error}",
+            Self::DictKeyForbidden {..} => "Key is not permitted",
             Self::ListType {..} => "Input should be a valid list",
             Self::TupleType {..} => "Input should be a valid tuple",
             Self::SetType {..} => "Input should be a valid set",
@@ -548,14 +643,21 @@ impl ErrorType {
             Self::TimezoneNaive {..} => "Input should not have timezone info",
             Self::TimezoneAware {..} => "Input should have timezone info",
             Self::TimezoneOffset {..} => "Timezone offset of {tz_expected} required, got {tz_actual}",
+            Self::TimezoneNotAllowed {..} => "Timezone should be {tz_allowed}, got {tz_actual}",
             Self::TimeDeltaType {..} => "Input should be a valid timedelta",
             Self::TimeDeltaParsing {..} => "Input should be a valid timedelta, {error}",
             Self::FrozenSetType {..} => "Input should be a valid frozenset",
             Self::IsInstanceOf {..} => "Input should be an instance of {class}",
             Self::IsSubclassOf {..} => "Input should be a subclass of {class}",
             Self::CallableType {..} => "Input should be callable",
+            Self::CallableArity {..} => "Input should be callable with {arity} positional argument{expected_plural}",
+            Self::CallableKeywords {..} => "Input should be callable with keyword arguments {keywords}",
+            Self::CallableCoroutine {..} => "Input should be a coroutine function",
             Self::UnionTagInvalid {..} => "Input tag '{tag}' found using {discriminator} does not match any of the expected tags: {expected_tags}",
             Self::UnionTagNotFound {..} => "Unable to extract tag using discriminator {discriminator}",
+            Self::UnionMemberFailed {..} => "Input failed validation against this union member with {error_count} error{expected_plural}",
+            Self::ContextSwitchInvalid {..} => "Context value {value} for '{context_key}' does not match any of the expected values: {expected_values}",
+            Self::ContextSwitchNotFound {..} => "Validation context should include '{context_key}'",
             Self::ArgumentsType {..} => "Arguments must be a tuple, list or a dictionary",
             Self::MissingArgument {..} => "Missing required argument",
             Self::UnexpectedKeywordArgument {..} => "Unexpected keyword argument",
@@ -568,14 +670,27 @@ impl ErrorType {
             Self::UrlSyntaxViolation {..} => "Input violated strict URL syntax rules, {error}",
             Self::UrlTooLong {..} => "URL should have at most {max_length} character{expected_plural}",
             Self::UrlScheme {..} => "URL scheme should be {expected_schemes}",
+            Self::UrlTooFewHosts {..} => "URL should have at least {min_hosts} host{expected_plural}",
+            Self::UrlTooManyHosts {..} => "URL should have at most {max_hosts} host{expected_plural}",
+            Self::UrlHostPortRequired {..} => "URL host {host_index} should have a port",
+            Self::UrlHostUserinfoRequired {..} => "URL host {host_index} should have a username",
+            Self::EmailType {..} => "Email address input should be a string or EmailAddress",
+            Self::EmailParsing {..} => "Input should be a valid email address, {error}",
             Self::UuidType {..} => "UUID input should be a string, bytes or UUID object",
             Self::UuidParsing {..} => "Input should be a valid UUID, {error}",
             Self::UuidVersion {..} => "UUID version {expected_version} expected",
+            Self::PathType {..} => "Path input should be a string, bytes or os.PathLike",
+            Self::PathNotAbsolute {..} => "Path should be absolute",
+            Self::PathSuffix {..} => "Path suffix should be {expected_suffixes}",
+            Self::IpType {..} => "{kind} input should be a string, bytes or integer",
+            Self::IpParsing {..} => "Input should be a valid {kind}, {error}",
             Self::DecimalType {..} => "Decimal input should be an integer, float, string or Decimal object",
             Self::DecimalParsing {..} => "Input should be a valid decimal",
             Self::DecimalMaxDigits {..} => "Decimal input should have no more than {max_digits} digit{expected_plural} in total",
             Self::DecimalMaxPlaces {..} => "Decimal input should have no more than {decimal_places} decimal place{expected_plural}",
             Self::DecimalWholeDigits {..} => "Decimal input should have no more than {whole_digits} digit{expected_plural} before the decimal point",
+            Self::FractionType {..} => "Fraction input should be an integer, float, string or Fraction object",
+            Self::FractionParsing {..} => "Input should be a valid fraction",
             Self::ComplexType {..} => "Input should be a valid python complex object, a number, or a valid complex string following the rules at https://docs.python.org/3/library/functions.html#complex",
             Self::ComplexStrParsing {..} => "Input should be a valid complex string following the rules at https://docs.python.org/3/library/functions.html#complex",
         }
@@ -631,6 +746,7 @@ impl ErrorType {
             Self::NoSuchAttribute { attribute, .. } => render!(tmpl, attribute),
             Self::JsonInvalid { error, .. } => render!(tmpl, error),
             Self::NeedsPythonObject { method_name, .. } => render!(tmpl, method_name),
+            Self::ResourceLimitExceeded { limit, max_value, .. } => to_string_render!(tmpl, limit, max_value),
             Self::GetAttributeError { error, .. } => render!(tmpl, error),
             Self::ModelType { class_name, .. } => render!(tmpl, class_name),
             Self::DataclassType { class_name, .. } => render!(tmpl, class_name),
@@ -659,6 +775,7 @@ impl ErrorType {
                 let actual_length = actual_length.map_or(Cow::Borrowed("more"), |v| Cow::Owned(v.to_string()));
                 to_string_render!(tmpl, field_type, max_length, actual_length, expected_plural,)
             }
+            Self::DuplicateItem { first_index, .. } => to_string_render!(tmpl, first_index),
             Self::IterationError { error, .. } => render!(tmpl, error),
             Self::StringTooShort { min_length, .. } => {
                 let expected_plural = plural_s(*min_length);
@@ -668,6 +785,22 @@ impl ErrorType {
                 let expected_plural = plural_s(*max_length);
                 to_string_render!(tmpl, max_length, expected_plural)
             }
+            Self::StringTooShortBytes {
+                min_length_bytes,
+                actual_length_bytes,
+                ..
+            } => {
+                let expected_plural = plural_s(*min_length_bytes);
+                to_string_render!(tmpl, min_length_bytes, actual_length_bytes, expected_plural)
+            }
+            Self::StringTooLongBytes {
+                max_length_bytes,
+                actual_length_bytes,
+                ..
+            } => {
+                let expected_plural = plural_s(*max_length_bytes);
+                to_string_render!(tmpl, max_length_bytes, actual_length_bytes, expected_plural)
+            }
             Self::StringPatternMismatch { pattern, .. } => render!(tmpl, pattern),
             Self::Enum { expected, .. } => to_string_render!(tmpl, expected),
             Self::MappingType { error, .. } => render!(tmpl, error),
@@ -711,9 +844,17 @@ impl ErrorType {
             Self::TimezoneOffset {
                 tz_expected, tz_actual, ..
             } => to_string_render!(tmpl, tz_expected, tz_actual),
+            Self::TimezoneNotAllowed {
+                tz_allowed, tz_actual, ..
+            } => render!(tmpl, tz_allowed, tz_actual),
             Self::TimeDeltaParsing { error, .. } => render!(tmpl, error),
             Self::IsInstanceOf { class, .. } => render!(tmpl, class),
             Self::IsSubclassOf { class, .. } => render!(tmpl, class),
+            Self::CallableArity { arity, .. } => {
+                let expected_plural = plural_s(*arity);
+                to_string_render!(tmpl, arity, expected_plural)
+            }
+            Self::CallableKeywords { keywords, .. } => render!(tmpl, keywords),
             Self::UnionTagInvalid {
                 discriminator,
                 tag,
@@ -721,15 +862,41 @@ impl ErrorType {
                 ..
             } => render!(tmpl, discriminator, tag, expected_tags),
             Self::UnionTagNotFound { discriminator, .. } => render!(tmpl, discriminator),
+            Self::UnionMemberFailed { error_count, .. } => {
+                let expected_plural = plural_s(*error_count);
+                to_string_render!(tmpl, error_count, expected_plural)
+            }
+            Self::ContextSwitchInvalid {
+                context_key,
+                value,
+                expected_values,
+                ..
+            } => render!(tmpl, context_key, value, expected_values),
+            Self::ContextSwitchNotFound { context_key, .. } => render!(tmpl, context_key),
+            Self::ExtraKeysForbidden { extra_keys, .. } => render!(tmpl, extra_keys),
             Self::UrlParsing { error, .. } => render!(tmpl, error),
             Self::UrlSyntaxViolation { error, .. } => render!(tmpl, error),
+            Self::EmailParsing { error, .. } => render!(tmpl, error),
             Self::UrlTooLong { max_length, .. } => {
                 let expected_plural = plural_s(*max_length);
                 to_string_render!(tmpl, max_length, expected_plural)
             }
             Self::UrlScheme { expected_schemes, .. } => render!(tmpl, expected_schemes),
+            Self::UrlTooFewHosts { min_hosts, .. } => {
+                let expected_plural = plural_s(*min_hosts);
+                to_string_render!(tmpl, min_hosts, expected_plural)
+            }
+            Self::UrlTooManyHosts { max_hosts, .. } => {
+                let expected_plural = plural_s(*max_hosts);
+                to_string_render!(tmpl, max_hosts, expected_plural)
+            }
+            Self::UrlHostPortRequired { host_index, .. } => to_string_render!(tmpl, host_index),
+            Self::UrlHostUserinfoRequired { host_index, .. } => to_string_render!(tmpl, host_index),
             Self::UuidParsing { error, .. } => render!(tmpl, error),
             Self::UuidVersion { expected_version, .. } => to_string_render!(tmpl, expected_version),
+            Self::PathSuffix { expected_suffixes, .. } => render!(tmpl, expected_suffixes),
+            Self::IpType { kind, .. } => render!(tmpl, kind),
+            Self::IpParsing { kind, error, .. } => render!(tmpl, kind, error),
             Self::DecimalMaxDigits { max_digits, .. } => {
                 let expected_plural = plural_s(*max_digits);
                 to_string_render!(tmpl, max_digits, expected_plural)
@@ -752,10 +919,11 @@ impl ErrorType {
 
         if let Self::CustomError { .. } = self {
             if custom_ctx_used {
-                // Custom error type and message are handled separately by the caller.
+                // Custom error type, message and severity are handled separately by the caller.
                 // They are added to the root of the ErrorDetails.
                 dict.del_item("error_type")?;
                 dict.del_item("message_template")?;
+                dict.del_item("severity")?;
                 Ok(Some(dict.into()))
             } else {
                 Ok(None)
@@ -768,6 +936,47 @@ impl ErrorType {
     }
 }
 
+/// How serious an error is, errors raised by pydantic-core are always `Error`, custom errors can be less severe
+/// so soft failures can be reported alongside hard ones and filtered with `ValidationError.errors(min_severity=...)`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ErrorSeverity {
+    Info,
+    Warning,
+    #[default]
+    Error,
+}
+
+impl ErrorSeverity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl FromPyObject<'_> for ErrorSeverity {
+    fn extract_bound(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        match obj.extract::<&str>()? {
+            "info" => Ok(Self::Info),
+            "warning" => Ok(Self::Warning),
+            "error" => Ok(Self::Error),
+            s => py_err!(PyValueError; "Invalid severity: `{}`, expected `info`, `warning` or `error`", s),
+        }
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &ErrorSeverity {
+    type Target = PyString;
+    type Output = Bound<'py, PyString>;
+    type Error = std::convert::Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        Ok(PyString::new(py, self.as_str()))
+    }
+}
+
 #[derive(Clone, Debug, IntoPyObject, IntoPyObjectRef)]
 pub enum Number {
     Int(i64),
@@ -23,7 +23,7 @@ use crate::tools::{safe_repr, write_truncated_to_limited_bytes, SchemaDict};
 
 use super::line_error::ValLineError;
 use super::location::Location;
-use super::types::ErrorType;
+use super::types::{ErrorSeverity, ErrorType};
 use super::value_exception::PydanticCustomError;
 use super::{InputValue, ValError};
 
@@ -35,6 +35,11 @@ pub struct ValidationError {
     title: PyObject,
     input_type: InputType,
     hide_input: bool,
+    // set when the error is raised by a `SchemaValidator`, only the title and hash are held so the error
+    // doesn't keep the validator alive
+    schema_origin: Option<(PyObject, u64)>,
+    // the steps taken before validation failed, only set when validating with `trace=True`
+    trace: Option<Py<PyList>>,
 }
 
 impl ValidationError {
@@ -44,9 +49,19 @@ impl ValidationError {
             title,
             input_type,
             hide_input,
+            schema_origin: None,
+            trace: None,
         }
     }
 
+    pub fn set_schema_origin(&mut self, schema_title: PyObject, schema_hash: u64) {
+        self.schema_origin = Some((schema_title, schema_hash));
+    }
+
+    pub fn set_trace(&mut self, trace: Py<PyList>) {
+        self.trace = Some(trace);
+    }
+
     pub fn from_val_error(
         py: Python,
         title: PyObject,
@@ -58,31 +73,16 @@ impl ValidationError {
     ) -> PyErr {
         match error {
             ValError::LineErrors(raw_errors) => {
-                let line_errors = match outer_location {
-                    Some(outer_location) => raw_errors
-                        .into_iter()
-                        .map(|e| PyLineError::from_val_line_error(py, e.with_outer_location(outer_location.clone())))
-                        .collect(),
-                    None => raw_errors
-                        .into_iter()
-                        .map(|e| PyLineError::from_val_line_error(py, e))
-                        .collect(),
-                };
-                let line_errors = match line_errors {
-                    Ok(errors) => errors,
-                    Err(err) => return err,
-                };
-                let validation_error = Self::new(line_errors, title, input_type, hide_input);
-                match Bound::new(py, validation_error) {
-                    Ok(err) => {
-                        if validation_error_cause {
-                            // Will return an import error if the backport was needed and not installed:
-                            if let Some(cause_problem) = ValidationError::maybe_add_cause(err.borrow(), py) {
-                                return cause_problem;
-                            }
-                        }
-                        PyErr::from_value(err.into_any())
-                    }
+                match Self::from_line_errors(
+                    py,
+                    title,
+                    input_type,
+                    raw_errors,
+                    outer_location,
+                    hide_input,
+                    validation_error_cause,
+                ) {
+                    Ok(err) => PyErr::from_value(err.into_any()),
                     Err(err) => err,
                 }
             }
@@ -92,6 +92,33 @@ impl ValidationError {
         }
     }
 
+    /// Build a `ValidationError` from line errors without raising it.
+    pub fn from_line_errors(
+        py: Python<'_>,
+        title: PyObject,
+        input_type: InputType,
+        raw_errors: Vec<ValLineError>,
+        outer_location: Option<LocItem>,
+        hide_input: bool,
+        validation_error_cause: bool,
+    ) -> PyResult<Bound<'_, ValidationError>> {
+        let line_errors = match outer_location {
+            Some(outer_location) => raw_errors
+                .into_iter()
+                .map(|e| PyLineError::from_val_line_error(e.with_outer_location(outer_location.clone())))
+                .collect(),
+            None => raw_errors.into_iter().map(PyLineError::from_val_line_error).collect(),
+        };
+        let err = Bound::new(py, Self::new(line_errors, title, input_type, hide_input))?;
+        if validation_error_cause {
+            // Will return an import error if the backport was needed and not installed:
+            if let Some(cause_problem) = ValidationError::maybe_add_cause(err.borrow(), py) {
+                return Err(cause_problem);
+            }
+        }
+        Ok(err)
+    }
+
     pub fn display(&self, py: Python, prefix_override: Option<&'static str>, hide_input: bool) -> String {
         let url_prefix = get_url_prefix(py, include_url_env(py));
         let line_errors = pretty_py_line_errors(py, self.input_type, self.line_errors.iter(), url_prefix, hide_input);
@@ -263,6 +290,8 @@ impl ValidationError {
             title,
             input_type: InputType::try_from(input_type)?,
             hide_input,
+            schema_origin: None,
+            trace: None,
         })
     }
 
@@ -291,26 +320,50 @@ impl ValidationError {
         self.title.clone_ref(py)
     }
 
+    #[getter]
+    fn schema_title(&self, py: Python) -> Option<PyObject> {
+        self.schema_origin.as_ref().map(|(title, _)| title.clone_ref(py))
+    }
+
+    #[getter]
+    fn schema_hash(&self) -> Option<u64> {
+        self.schema_origin.as_ref().map(|(_, hash)| *hash)
+    }
+
+    #[getter]
+    fn trace(&self, py: Python) -> Option<Py<PyList>> {
+        self.trace.as_ref().map(|trace| trace.clone_ref(py))
+    }
+
     pub fn error_count(&self) -> usize {
         self.line_errors.len()
     }
 
-    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true))]
+    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true, min_severity = None))]
     pub fn errors(
         &self,
         py: Python,
         include_url: bool,
         include_context: bool,
         include_input: bool,
+        min_severity: Option<ErrorSeverity>,
     ) -> PyResult<Py<PyList>> {
         let url_prefix = get_url_prefix(py, include_url);
+        let line_errors: Vec<&PyLineError> = match min_severity {
+            Some(min_severity) => self
+                .line_errors
+                .iter()
+                .filter(|e| e.error_type.severity() >= min_severity)
+                .collect(),
+            None => self.line_errors.iter().collect(),
+        };
         let mut iteration_error = None;
         let list = PyList::new(
             py,
             // PyList::new takes ExactSizeIterator, so if an error occurs during iteration we
             // fill the list with None before returning the error; the list will then be thrown
             // away safely.
-            self.line_errors.iter().map(|e| -> PyObject {
+            line_errors.into_iter().map(|e| -> PyObject {
                 if iteration_error.is_some() {
                     return py.None();
                 }
@@ -340,7 +393,7 @@ impl ValidationError {
         include_context: bool,
         include_input: bool,
     ) -> PyResult<Bound<'py, PyString>> {
-        let state = SerializationState::new("iso8601", "utf8", "constants")?;
+        let state = SerializationState::new("iso8601", "utf8", "constants", "iso8601")?;
         let extra = state.extra(
             py,
             &SerMode::Json,
@@ -395,7 +448,7 @@ impl ValidationError {
         let borrow = slf.try_borrow()?;
         let args = (
             &borrow.title,
-            borrow.errors(py, include_url_env(py), true, true)?,
+            borrow.errors(py, include_url_env(py), true, true, None)?,
             borrow.input_type,
             borrow.hide_input,
         )
@@ -425,7 +478,9 @@ pub fn pretty_py_line_errors<'a>(
 pub struct PyLineError {
     error_type: ErrorType,
     location: Location,
-    input_value: PyObject,
+    // JSON inputs are only converted to python objects when the error is rendered, so errors which are
+    // caught and discarded don't pay for the conversion
+    input_value: InputValue,
 }
 
 impl From<PyLineError> for ValLineError {
@@ -434,7 +489,7 @@ impl From<PyLineError> for ValLineError {
         ValLineError {
             error_type: other.error_type,
             location: other.location,
-            input_value: InputValue::Python(other.input_value),
+            input_value: other.input_value,
         }
     }
 }
@@ -464,8 +519,8 @@ impl TryFrom<&Bound<'_, PyAny>> for PyLineError {
         let location = Location::try_from(dict.get_item("loc")?.as_ref())?;
 
         let input_value = match dict.get_item("input")? {
-            Some(i) => i.unbind(),
-            None => py.None(),
+            Some(i) => InputValue::Python(i.unbind()),
+            None => InputValue::Python(py.None()),
         };
 
         Ok(Self {
@@ -477,12 +532,12 @@ impl TryFrom<&Bound<'_, PyAny>> for PyLineError {
 }
 
 impl PyLineError {
-    pub fn from_val_line_error(py: Python, error: ValLineError) -> PyResult<Self> {
-        Ok(Self {
+    pub fn from_val_line_error(error: ValLineError) -> Self {
+        Self {
             error_type: error.error_type,
             location: error.location,
-            input_value: error.input_value.into_pyobject(py)?.unbind(),
-        })
+            input_value: error.input_value,
+        }
     }
 
     fn get_error_url(&self, url_prefix: &str) -> String {
@@ -502,13 +557,17 @@ impl PyLineError {
         dict.set_item("loc", &self.location)?;
         dict.set_item("msg", self.error_type.render_message(py, input_type)?)?;
         if include_input {
-            dict.set_item("input", &self.input_value)?;
+            dict.set_item("input", self.input_value.to_python(py)?)?;
         }
         if include_context {
             if let Some(context) = self.error_type.py_dict(py)? {
                 dict.set_item("ctx", context)?;
             }
         }
+        let severity = self.error_type.severity();
+        if severity != ErrorSeverity::Error {
+            dict.set_item("severity", severity.as_str())?;
+        }
         if let Some(url_prefix) = url_prefix {
             match self.error_type {
                 ErrorType::CustomError { .. } => {
@@ -539,8 +598,8 @@ impl PyLineError {
         write!(output, "  {message} [type={}", self.error_type.type_string())?;
 
         if !hide_input {
-            let input_value = self.input_value.bind(py);
-            let input_str = safe_repr(input_value);
+            let input_value = self.input_value.to_python(py).map_err(|_| fmt::Error)?;
+            let input_str = safe_repr(&input_value);
             write!(output, ", input_value=")?;
             write_truncated_to_limited_bytes(&mut output, &input_str.to_string(), 50)?;
 
@@ -628,10 +687,16 @@ impl Serialize for PyLineErrorSerializer<'_> {
         S: Serializer,
     {
         let py = self.py;
-        let size = 3 + [self.url_prefix.is_some(), self.include_context, self.include_input]
-            .into_iter()
-            .filter(|b| *b)
-            .count();
+        let severity = self.line_error.error_type.severity();
+        let size = 3 + [
+            self.url_prefix.is_some(),
+            self.include_context,
+            self.include_input,
+            severity != ErrorSeverity::Error,
+        ]
+        .into_iter()
+        .filter(|b| *b)
+        .count();
         let mut map = serializer.serialize_map(Some(size))?;
 
         map.serialize_entry("type", &self.line_error.error_type.type_string())?;
@@ -646,10 +711,8 @@ impl Serialize for PyLineErrorSerializer<'_> {
         map.serialize_entry("msg", &msg)?;
 
         if self.include_input {
-            map.serialize_entry(
-                "input",
-                &self.extra.serialize_infer(self.line_error.input_value.bind(py)),
-            )?;
+            let input_value = self.line_error.input_value.to_python(py).map_err(py_err_json::<S>)?;
+            map.serialize_entry("input", &self.extra.serialize_infer(&input_value))?;
         }
 
         if self.include_context {
@@ -657,6 +720,9 @@ impl Serialize for PyLineErrorSerializer<'_> {
                 map.serialize_entry("ctx", &self.extra.serialize_infer(context.bind(py)))?;
             }
         }
+        if severity != ErrorSeverity::Error {
+            map.serialize_entry("severity", severity.as_str())?;
+        }
         if let Some(url_prefix) = self.url_prefix {
             map.serialize_entry("url", &self.line_error.get_error_url(url_prefix))?;
         }
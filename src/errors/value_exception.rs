@@ -6,7 +6,7 @@ use crate::input::InputType;
 use crate::tools::extract_i64;
 
 use super::line_error::ToErrorValue;
-use super::{ErrorType, ValError};
+use super::{ErrorSeverity, ErrorType, ValError};
 
 #[pyclass(extends=PyException, module="pydantic_core._pydantic_core")]
 #[derive(Debug, Clone)]
@@ -60,17 +60,24 @@ pub struct PydanticCustomError {
     error_type: String,
     message_template: String,
     context: Option<Py<PyDict>>,
+    severity: ErrorSeverity,
 }
 
 #[pymethods]
 impl PydanticCustomError {
     #[new]
-    #[pyo3(signature = (error_type, message_template, context = None, /))]
-    pub fn py_new(error_type: String, message_template: String, context: Option<Bound<'_, PyDict>>) -> Self {
+    #[pyo3(signature = (error_type, message_template, context = None, severity = ErrorSeverity::Error, /))]
+    pub fn py_new(
+        error_type: String,
+        message_template: String,
+        context: Option<Bound<'_, PyDict>>,
+        severity: ErrorSeverity,
+    ) -> Self {
         Self {
             error_type,
             message_template,
             context: context.map(Bound::unbind),
+            severity,
         }
     }
 
@@ -89,6 +96,11 @@ impl PydanticCustomError {
         self.context.as_ref().map(|c| c.clone_ref(py))
     }
 
+    #[getter(severity)]
+    pub fn py_severity(&self) -> &'static str {
+        self.severity.as_str()
+    }
+
     pub fn message(&self, py: Python) -> PyResult<String> {
         Self::format_message(&self.message_template, self.context.as_ref().map(|c| c.bind(py)))
     }
@@ -107,10 +119,15 @@ impl PydanticCustomError {
 }
 
 impl PydanticCustomError {
+    pub fn severity(&self) -> ErrorSeverity {
+        self.severity
+    }
+
     pub fn into_val_error(self, input: impl ToErrorValue) -> ValError {
         let error_type = ErrorType::CustomError {
             error_type: self.error_type,
             message_template: self.message_template,
+            severity: self.severity,
             context: self.context,
         };
         ValError::new(error_type, input)
@@ -17,6 +17,7 @@ use std::hash::Hasher;
 use strum::EnumMessage;
 
 use super::Input;
+use crate::build_tools::py_schema_err;
 use crate::errors::ToErrorValue;
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::tools::py_err;
@@ -428,6 +429,102 @@ pub fn float_as_datetime<'py>(input: &(impl Input<'py> + ?Sized), timestamp: f64
     int_as_datetime(input, timestamp.floor() as i64, microseconds.round() as u32)
 }
 
+/// The unit of numeric timestamps given to datetimes, by default it's inferred from the magnitude of the value
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EpochUnit {
+    #[default]
+    Infer,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+}
+
+impl EpochUnit {
+    pub fn from_str(s: &str) -> PyResult<Self> {
+        match s {
+            "infer" => Ok(Self::Infer),
+            "seconds" => Ok(Self::Seconds),
+            "milliseconds" => Ok(Self::Milliseconds),
+            "microseconds" => Ok(Self::Microseconds),
+            _ => py_schema_err!("Invalid epoch_unit {:?}", s),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Infer => "infer",
+            Self::Seconds => "seconds",
+            Self::Milliseconds => "milliseconds",
+            Self::Microseconds => "microseconds",
+        }
+    }
+
+    fn per_second(self) -> i64 {
+        match self {
+            Self::Infer | Self::Seconds => 1,
+            Self::Milliseconds => 1_000,
+            Self::Microseconds => 1_000_000,
+        }
+    }
+}
+
+/// Largest timestamp in seconds speedate accepts without inferring that it's in milliseconds
+const EPOCH_SECONDS_MAX: i64 = 20_000_000_000;
+
+/// Interpret a numeric input (or a string holding a number) as a timestamp in `unit`,
+/// `Ok(None)` if the input isn't a number.
+pub fn epoch_as_datetime<'py>(
+    py: Python<'py>,
+    input: &(impl Input<'py> + ?Sized),
+    unit: EpochUnit,
+) -> ValResult<Option<EitherDateTime<'py>>> {
+    let out_of_range = || {
+        ValError::new(
+            ErrorType::DatetimeParsing {
+                error: Cow::Owned(format!("timestamp is out of range for epoch_unit '{}'", unit.as_str())),
+                context: None,
+            },
+            input,
+        )
+    };
+    let per_second = unit.per_second();
+    let from_int = |timestamp: i64| {
+        let seconds = timestamp.div_euclid(per_second);
+        if seconds.abs() > EPOCH_SECONDS_MAX {
+            return Err(out_of_range());
+        }
+        let microseconds = timestamp.rem_euclid(per_second) * (1_000_000 / per_second);
+        int_as_datetime(input, seconds, u32::try_from(microseconds).map_err(|_| out_of_range())?)
+    };
+    let from_float = |timestamp: f64| {
+        let seconds = timestamp / per_second as f64;
+        if seconds.abs() > EPOCH_SECONDS_MAX as f64 {
+            return Err(out_of_range());
+        }
+        float_as_datetime(input, seconds)
+    };
+
+    if let Ok(int) = input.validate_int(true) {
+        let timestamp = int.into_inner().into_i64(py).map_err(|_| out_of_range())?;
+        from_int(timestamp).map(Some)
+    } else if let Ok(float) = input.validate_float(true) {
+        from_float(float.into_inner().as_f64()).map(Some)
+    } else if let Ok(either_str) = input.validate_str(true, false) {
+        let either_str = either_str.into_inner();
+        let s = either_str.as_cow()?;
+        let s = s.trim();
+        if let Ok(timestamp) = s.parse::<i64>() {
+            from_int(timestamp).map(Some)
+        } else if s.bytes().all(|b| b.is_ascii_digit() || b == b'.' || b == b'-') {
+            s.parse::<f64>().ok().map(from_float).transpose()
+        } else {
+            Ok(None)
+        }
+    } else {
+        Ok(None)
+    }
+}
+
 pub fn date_as_datetime<'py>(date: &Bound<'py, PyDate>) -> PyResult<EitherDateTime<'py>> {
     let py = date.py();
     let dt = PyDateTime::new(
@@ -73,6 +73,11 @@ pub trait Input<'py>: fmt::Debug {
         None
     }
 
+    /// The python object checked against the input limits, JSON is checked while it's parsed instead
+    fn as_limits_input(&self) -> Option<&Bound<'py, PyAny>> {
+        self.as_python()
+    }
+
     fn as_kwargs(&self, py: Python<'py>) -> Option<Bound<'py, PyDict>>;
 
     type Arguments<'a>: Arguments<'py>
@@ -1,12 +1,14 @@
+use std::ffi::CStr;
 use std::str::from_utf8;
 
 use pyo3::intern;
 use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
 
 use pyo3::types::PyType;
 use pyo3::types::{
     PyBool, PyByteArray, PyBytes, PyComplex, PyDate, PyDateTime, PyDict, PyFloat, PyFrozenSet, PyInt, PyIterator,
-    PyList, PyMapping, PySet, PyString, PyTime, PyTuple,
+    PyList, PyMapping, PyMemoryView, PySet, PyString, PyTime, PyTuple,
 };
 
 use pyo3::PyTypeCheck;
@@ -14,6 +16,7 @@ use pyo3::PyTypeInfo;
 use speedate::MicrosecondsPrecisionOverflowBehavior;
 
 use crate::errors::{ErrorType, ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
+use crate::serializers::{ObType, ObTypeLookup};
 use crate::tools::{extract_i64, safe_repr};
 use crate::validators::complex::string_to_complex;
 use crate::validators::decimal::{create_decimal, get_decimal_type};
@@ -56,6 +59,79 @@ pub(crate) fn input_as_python_instance<'a, 'py>(
     input.as_python().filter(|any| any.is_instance(class).unwrap_or(false))
 }
 
+/// Converts NumPy arrays (or anything else implementing `__array__`) and buffer protocol objects like
+/// `array.array` to a list in a single call, rather than iterating over them element by element.
+///
+/// `str`, `bytes`, `bytearray` and lists are never converted.
+pub(crate) fn array_like_as_list<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyList>>> {
+    if obj.is_instance_of::<PyList>()
+        || obj.is_instance_of::<PyString>()
+        || obj.is_instance_of::<PyBytes>()
+        || obj.is_instance_of::<PyByteArray>()
+    {
+        return Ok(None);
+    }
+    let py = obj.py();
+    let items = if obj.hasattr(intern!(py, "__array__"))? {
+        obj.call_method0(intern!(py, "__array__"))?
+            .call_method0(intern!(py, "tolist"))?
+    } else if let Ok(memory_view) = PyMemoryView::from(obj) {
+        memory_view.call_method0(intern!(py, "tolist"))?
+    } else {
+        return Ok(None);
+    };
+    // zero dimensional arrays convert to a scalar
+    Ok(items.downcast_into::<PyList>().ok())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NumpyScalar {
+    Integer,
+    Floating,
+}
+
+static NUMPY_SCALAR_TYPES: GILOnceCell<[Py<PyType>; 2]> = GILOnceCell::new();
+
+/// Identifies NumPy integer and floating point scalars. NumPy's types are named e.g. `numpy.int64`, so other
+/// inputs are ruled out by the name of their type without looking NumPy up, NumPy is never imported for them.
+fn numpy_scalar(obj: &Bound<'_, PyAny>) -> Option<NumpyScalar> {
+    // SAFETY: `tp_name` of a live type object is a valid nul-terminated string
+    let type_name = unsafe { CStr::from_ptr((*obj.get_type_ptr()).tp_name) };
+    if !type_name.to_bytes().starts_with(b"numpy.") {
+        return None;
+    }
+    let py = obj.py();
+    let types = NUMPY_SCALAR_TYPES
+        .get_or_try_init(py, || -> PyResult<[Py<PyType>; 2]> {
+            let numpy = py.import(intern!(py, "numpy"))?;
+            Ok([
+                numpy.getattr(intern!(py, "integer"))?.extract()?,
+                numpy.getattr(intern!(py, "floating"))?.extract()?,
+            ])
+        })
+        .ok()?;
+    if obj.is_instance(types[0].bind(py)).unwrap_or(false) {
+        Some(NumpyScalar::Integer)
+    } else if obj.is_instance(types[1].bind(py)).unwrap_or(false) {
+        Some(NumpyScalar::Floating)
+    } else {
+        None
+    }
+}
+
+/// Whether the type of `obj` was registered as `ob_type` with `register_ob_type`
+fn registered_as(obj: &Bound<'_, PyAny>, ob_type: ObType) -> bool {
+    ObTypeLookup::cached(obj.py()).registered(&obj.get_type()) == Some(ob_type)
+}
+
+/// NumPy integers implement `__index__`, which unlike `__float__` is lossless for 64 bit values
+fn numpy_int_as_int<'py>(obj: &Bound<'py, PyAny>) -> ValResult<EitherInt<'py>> {
+    match obj.extract::<i64>() {
+        Ok(int) => Ok(EitherInt::I64(int)),
+        Err(_) => EitherInt::upcast(&obj.call_method0(intern!(obj.py(), "__index__"))?),
+    }
+}
+
 impl From<&Bound<'_, PyAny>> for LocItem {
     fn from(py_any: &Bound<'_, PyAny>) -> Self {
         if let Ok(py_str) = py_any.downcast::<PyString>() {
@@ -179,6 +255,11 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
                     Ok(self.str()?.into())
                 } else if let Some(enum_val) = maybe_as_enum(self) {
                     Ok(enum_val.str()?.into())
+                } else if let Some(bytes) = EitherBytes::from_buffer(self) {
+                    match from_utf8(bytes.as_slice()) {
+                        Ok(str) => Ok(str.to_string().into()),
+                        Err(_) => Err(ValError::new(ErrorTypeDefaults::StringUnicode, self)),
+                    }
                 } else {
                     break 'lax;
                 }
@@ -210,6 +291,8 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
                     }
                 } else if let Ok(py_byte_array) = self.downcast::<PyByteArray>() {
                     Ok(py_byte_array.to_vec().into())
+                } else if let Some(bytes) = EitherBytes::from_buffer(self) {
+                    Ok(bytes)
                 } else {
                     break 'lax;
                 }
@@ -267,6 +350,8 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
                     str_as_int(self, s)
                 } else if self.is_exact_instance_of::<PyFloat>() {
                     float_as_int(self, self.extract::<f64>()?)
+                } else if numpy_scalar(self) == Some(NumpyScalar::Integer) || registered_as(self, ObType::Int) {
+                    numpy_int_as_int(self)
                 } else if let Ok(decimal) = self.validate_decimal(true, self.py()) {
                     decimal_as_int(self, &decimal.into_inner())
                 } else if let Ok(float) = self.extract::<f64>() {
@@ -311,6 +396,11 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
             }
         }
 
+        // NumPy scalars skip the bool check below, the exactness matches other objects implementing `__float__`
+        if numpy_scalar(self).is_some() {
+            return Ok(ValidationMatch::strict(EitherFloat::F64(self.extract::<f64>()?)));
+        }
+
         if let Ok(float) = self.extract::<f64>() {
             let exactness = if self.is_instance_of::<PyBool>() {
                 if strict {
@@ -341,7 +431,7 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
                 return create_decimal(self, self).map(ValidationMatch::lax);
             }
 
-            if self.is_instance_of::<PyFloat>() {
+            if self.is_instance_of::<PyFloat>() || registered_as(self, ObType::Decimal) {
                 return create_decimal(self.str()?.as_any(), self).map(ValidationMatch::lax);
             }
         }
@@ -75,6 +75,13 @@ impl<'py> Input<'py> for StringMapping<'py> {
         }
     }
 
+    fn as_limits_input(&self) -> Option<&Bound<'py, PyAny>> {
+        match self {
+            Self::String(s) => Some(s.as_any()),
+            Self::Mapping(d) => Some(d.as_any()),
+        }
+    }
+
     fn as_kwargs(&self, _py: Python<'py>) -> Option<Bound<'py, PyDict>> {
         None
     }
@@ -0,0 +1,272 @@
+use std::sync::Arc;
+
+use pyo3::prelude::*;
+use pyo3::types::PyIterator;
+
+use jiter::{Jiter, JsonValue, LinePosition, PartialMode};
+
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::validators::json::validate_json_bytes;
+
+use super::limits::{JsonLimitsError, JsonLimitsParser, LimitsCheck};
+use super::InputLimits;
+
+/// JSON data read from an iterator of chunks, e.g. an HTTP body iterator, without joining the chunks.
+///
+/// jiter needs contiguous data, so each item of a top level array or object is parsed as soon as it's been read
+/// in full, then its data is dropped. Only the data of the item being read is held at once, rather than the whole
+/// document. Other top level values can't be split, so they're read in full before they're parsed.
+pub struct JsonChunks<'a, 'py> {
+    input: &'a Bound<'py, PyAny>,
+    chunks: Bound<'py, PyIterator>,
+    buffer: Vec<u8>,
+    // how far parsing has reached in `buffer`
+    position: usize,
+    // the number of bytes dropped from the start of `buffer`, the number of lines they contained
+    // and where the last of those lines started, so errors are located as if no data was dropped
+    dropped: usize,
+    dropped_lines: usize,
+    dropped_line_start: usize,
+    exhausted: bool,
+}
+
+impl<'a, 'py> JsonChunks<'a, 'py> {
+    pub fn new(input: &'a Bound<'py, PyAny>, chunks: &Bound<'py, PyIterator>) -> Self {
+        Self {
+            input,
+            chunks: chunks.clone(),
+            buffer: Vec::new(),
+            position: 0,
+            dropped: 0,
+            dropped_lines: 0,
+            dropped_line_start: 0,
+            exhausted: false,
+        }
+    }
+
+    pub fn parse(mut self, limits: &'a InputLimits, allow_partial: PartialMode) -> ValResult<JsonValue<'static>> {
+        let first = loop {
+            self.skip_whitespace();
+            if let Some(&first) = self.buffer.get(self.position) {
+                break Some(first);
+            }
+            if !self.read_chunk()? {
+                break None;
+            }
+        };
+        match first {
+            Some(b'[') => self.parse_array(LimitsCheck::new(limits), allow_partial),
+            Some(b'{') => self.parse_object(LimitsCheck::new(limits), allow_partial),
+            _ => {
+                while self.read_chunk()? {}
+                limits
+                    .parse_json(self.input, &self.buffer[self.position..], allow_partial)
+                    .map_err(|e| self.map_err(e))
+            }
+        }
+    }
+
+    fn parse_array(&mut self, mut check: LimitsCheck<'a>, allow_partial: PartialMode) -> ValResult<JsonValue<'static>> {
+        let input = self.input;
+        let mut items = Vec::new();
+        loop {
+            let first = items.is_empty();
+            self.read_item(first)?;
+            let mut parser = JsonLimitsParser::new(&self.buffer[self.position..], check, allow_partial, input);
+            let item = match first {
+                true => parser.enter_container(1).and_then(|()| parser.array_item(true, 0, 1)),
+                false => parser.array_item(false, items.len(), 1),
+            };
+            let parsed = parser.jiter.current_index();
+            check = parser.check;
+            let item = item.map_err(|e| self.map_err(e))?;
+            // with partial strings, jiter's index can end up past the end of the data
+            self.position = (self.position + parsed).min(self.buffer.len());
+            match item {
+                Some(item) => items.push(item),
+                None => break,
+            }
+        }
+        self.finish(allow_partial)?;
+        Ok(JsonValue::Array(Arc::new(items)))
+    }
+
+    fn parse_object(
+        &mut self,
+        mut check: LimitsCheck<'a>,
+        allow_partial: PartialMode,
+    ) -> ValResult<JsonValue<'static>> {
+        let input = self.input;
+        let mut fields = Vec::new();
+        loop {
+            let first = fields.is_empty();
+            self.read_item(first)?;
+            let mut parser = JsonLimitsParser::new(&self.buffer[self.position..], check, allow_partial, input);
+            let field = match first {
+                true => parser.enter_container(1).and_then(|()| parser.object_field(true, 1)),
+                false => parser.object_field(false, 1),
+            };
+            let parsed = parser.jiter.current_index();
+            check = parser.check;
+            let field = field.map_err(|e| self.map_err(e))?;
+            // with partial strings, jiter's index can end up past the end of the data
+            self.position = (self.position + parsed).min(self.buffer.len());
+            match field {
+                Some(field) => fields.push(field),
+                None => break,
+            }
+        }
+        self.finish(allow_partial)?;
+        Ok(JsonValue::Object(Arc::new(fields)))
+    }
+
+    /// Read chunks until the next item of the top level array or object, or its end, has been read in full.
+    /// The first item follows the opening `[` or `{`.
+    fn read_item(&mut self, first: bool) -> ValResult<()> {
+        let mut scan = ItemScan::new(usize::from(first));
+        // the scan is relative to `position`, so isn't affected by parsed data being dropped
+        while !scan.scan(&self.buffer[self.position..]) {
+            if !self.read_chunk()? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Check only whitespace follows the top level value, as jiter does
+    fn finish(&mut self, allow_partial: PartialMode) -> ValResult<()> {
+        if allow_partial.is_active() {
+            return Ok(());
+        }
+        loop {
+            self.skip_whitespace();
+            if self.position < self.buffer.len() || !self.read_chunk()? {
+                break;
+            }
+        }
+        Jiter::new(&self.buffer[self.position..])
+            .finish()
+            .map_err(|e| self.map_err(e.into()))
+    }
+
+    /// Read the next chunk into `buffer`, dropping the data which has been parsed, `false` once no chunks are left
+    fn read_chunk(&mut self) -> ValResult<bool> {
+        if self.exhausted {
+            return Ok(false);
+        }
+        let Some(chunk) = self.chunks.next() else {
+            self.exhausted = true;
+            return Ok(false);
+        };
+        let chunk = chunk?;
+        self.drop_parsed();
+        self.buffer
+            .extend_from_slice(validate_json_bytes(&chunk)?.into_inner().as_slice());
+        Ok(true)
+    }
+
+    fn drop_parsed(&mut self) {
+        for (index, byte) in self.buffer[..self.position].iter().enumerate() {
+            if *byte == b'\n' {
+                self.dropped_lines += 1;
+                self.dropped_line_start = self.dropped + index + 1;
+            }
+        }
+        self.dropped += self.position;
+        self.buffer.drain(..self.position);
+        self.position = 0;
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\r' | b'\n') = self.buffer.get(self.position) {
+            self.position += 1;
+        }
+    }
+
+    /// Errors are relative to `position` in `buffer`, report them with the same message as if the JSON data
+    /// had been parsed in one piece
+    fn map_err(&self, error: JsonLimitsError) -> ValError {
+        match error {
+            JsonLimitsError::Limit(error) => error,
+            JsonLimitsError::Json(error) => ValError::new(
+                ErrorType::JsonInvalid {
+                    error: format!(
+                        "{} at {}",
+                        error.error_type,
+                        self.line_position(self.position + error.index)
+                    ),
+                    context: None,
+                },
+                self.input,
+            ),
+        }
+    }
+
+    /// The position of `index` in `buffer` within the whole JSON document, matching `LinePosition::find`
+    fn line_position(&self, index: usize) -> LinePosition {
+        let mut line = self.dropped_lines + 1;
+        let mut line_start = self.dropped_line_start;
+        for (i, byte) in self.buffer.iter().enumerate() {
+            if *byte == b'\n' {
+                line += 1;
+                line_start = self.dropped + i + 1;
+            }
+            if i == index {
+                return LinePosition::new(line, self.dropped + i + 1 - line_start);
+            }
+        }
+        LinePosition::new(line, (self.dropped + self.buffer.len()).saturating_sub(line_start))
+    }
+}
+
+/// Finds the end of the next item of a top level array or object, the `,`, `]` or `}` after it, tracking strings
+/// and nesting so it can resume when more data has been read. Invalid JSON may mean the end isn't found until all
+/// chunks have been read, jiter then reports the error as it would for the whole document.
+struct ItemScan {
+    index: usize,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    // whether the item has started, i.e. the `,` separating it from the previous item has been passed
+    started: bool,
+}
+
+impl ItemScan {
+    fn new(index: usize) -> Self {
+        Self {
+            index,
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            started: false,
+        }
+    }
+
+    /// Scan `data` from where the last scan stopped, `true` once the end of the item has been found
+    fn scan(&mut self, data: &[u8]) -> bool {
+        while let Some(&byte) = data.get(self.index) {
+            if self.in_string {
+                match byte {
+                    _ if self.escaped => self.escaped = false,
+                    b'\\' => self.escaped = true,
+                    b'"' => self.in_string = false,
+                    _ => (),
+                }
+            } else {
+                match byte {
+                    b' ' | b'\t' | b'\r' | b'\n' => (),
+                    b']' | b'}' if self.depth == 0 => return true,
+                    b',' if self.depth == 0 && self.started => return true,
+                    b',' if self.depth == 0 => self.started = true,
+                    b'[' | b'{' => self.depth += 1,
+                    b']' | b'}' => self.depth -= 1,
+                    b'"' => self.in_string = true,
+                    _ => (),
+                }
+                self.started |= byte != b',' && !byte.is_ascii_whitespace();
+            }
+            self.index += 1;
+        }
+        false
+    }
+}
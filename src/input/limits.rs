@@ -0,0 +1,460 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use ahash::AHashMap;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyFrozenSet, PyList, PySet, PyString, PyTuple};
+
+use jiter::{
+    Jiter, JiterError, JiterErrorType, JsonError, JsonErrorType, JsonValue, NumberAny, NumberInt, PartialMode, Peek,
+};
+
+use crate::errors::{ErrorType, LocItem, ToErrorValue, ValError, ValResult};
+use crate::tools::SchemaDict;
+
+use super::EitherBytes;
+
+/// the depth JSON is always limited to when `max_nesting_depth` isn't set, the same as jiter's own limit
+const JSON_RECURSION_LIMIT: usize = 200;
+
+/// Limits on the size of the whole input to a single validation call, set via config.
+///
+/// JSON is checked while it's parsed, so hostile documents are rejected before they're held in memory in full.
+/// Python input is checked as validators reach each part of it, see `LimitsState`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InputLimits {
+    total_items: Option<usize>,
+    string_length_bytes: Option<usize>,
+    nesting_depth: Option<usize>,
+}
+
+impl InputLimits {
+    /// `None` if no limits are set, so there's nothing to check
+    pub fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Option<Self>> {
+        let Some(config) = config else {
+            return Ok(None);
+        };
+        let py = config.py();
+        let limits = Self {
+            total_items: config.get_as(intern!(py, "max_total_items"))?,
+            string_length_bytes: config.get_as(intern!(py, "max_string_length_bytes"))?,
+            nesting_depth: config.get_as(intern!(py, "max_nesting_depth"))?,
+        };
+        if limits.total_items.is_none() && limits.string_length_bytes.is_none() && limits.nesting_depth.is_none() {
+            Ok(None)
+        } else {
+            Ok(Some(limits))
+        }
+    }
+
+    /// Parse `json_data`, checking the limits as each value is read. Limit errors are reported against `input`,
+    /// the whole JSON document, located at the container or string which exceeded the limit.
+    pub fn parse_json(
+        &self,
+        input: &Bound<'_, PyAny>,
+        json_data: &[u8],
+        allow_partial: PartialMode,
+    ) -> Result<JsonValue<'static>, JsonLimitsError> {
+        let mut parser = JsonLimitsParser::new(json_data, LimitsCheck::new(self), allow_partial, input);
+        let peek = parser.jiter.peek()?;
+        let value = parser.value(peek, 0)?;
+        if !parser.allow_partial {
+            parser.jiter.finish()?;
+        }
+        Ok(value)
+    }
+}
+
+pub enum JsonLimitsError {
+    Json(JsonError),
+    Limit(ValError),
+}
+
+impl JsonLimitsError {
+    pub(super) fn with_outer_location(self, loc_item: impl Into<LocItem>) -> Self {
+        match self {
+            Self::Limit(error) => Self::Limit(error.with_outer_location(loc_item)),
+            Self::Json(error) => Self::Json(error),
+        }
+    }
+}
+
+impl From<JiterError> for JsonLimitsError {
+    fn from(error: JiterError) -> Self {
+        let error_type = match error.error_type {
+            JiterErrorType::JsonError(error_type) => error_type,
+            JiterErrorType::WrongType { .. } => JsonErrorType::ExpectedSomeValue,
+        };
+        Self::Json(JsonError {
+            error_type,
+            index: error.index,
+        })
+    }
+}
+
+impl From<ValError> for JsonLimitsError {
+    fn from(error: ValError) -> Self {
+        Self::Limit(error)
+    }
+}
+
+pub(super) struct LimitsCheck<'a> {
+    limits: &'a InputLimits,
+    total_items: usize,
+}
+
+impl<'a> LimitsCheck<'a> {
+    pub(super) fn new(limits: &'a InputLimits) -> Self {
+        Self { limits, total_items: 0 }
+    }
+
+    /// called when entering a container at `depth`, the outermost container has depth 1
+    fn container(&self, depth: usize, input: impl ToErrorValue) -> ValResult<()> {
+        match self.limits.nesting_depth {
+            Some(max_value) if depth > max_value => Err(exceeded("max_nesting_depth", max_value, input)),
+            _ => Ok(()),
+        }
+    }
+
+    pub(super) fn item(&mut self, input: impl ToErrorValue) -> ValResult<()> {
+        self.total_items += 1;
+        match self.limits.total_items {
+            Some(max_value) if self.total_items > max_value => Err(exceeded("max_total_items", max_value, input)),
+            _ => Ok(()),
+        }
+    }
+
+    pub(super) fn string(&self, length_bytes: impl FnOnce() -> usize, input: impl ToErrorValue) -> ValResult<()> {
+        match self.limits.string_length_bytes {
+            Some(max_value) if length_bytes() > max_value => Err(exceeded("max_string_length_bytes", max_value, input)),
+            _ => Ok(()),
+        }
+    }
+}
+
+pub(super) struct JsonLimitsParser<'j, 'a, 'py> {
+    pub(super) jiter: Jiter<'j>,
+    pub(super) check: LimitsCheck<'a>,
+    allow_partial: bool,
+    input: &'a Bound<'py, PyAny>,
+}
+
+impl<'j, 'a, 'py> JsonLimitsParser<'j, 'a, 'py> {
+    pub(super) fn new(
+        json_data: &'j [u8],
+        check: LimitsCheck<'a>,
+        allow_partial: PartialMode,
+        input: &'a Bound<'py, PyAny>,
+    ) -> Self {
+        let mut jiter = Jiter::new(json_data).with_allow_inf_nan();
+        if allow_partial.allow_trailing_str() {
+            jiter = jiter.with_allow_partial_strings();
+        }
+        Self {
+            jiter,
+            check,
+            allow_partial: allow_partial.is_active(),
+            input,
+        }
+    }
+
+    pub(super) fn value(&mut self, peek: Peek, depth: usize) -> Result<JsonValue<'static>, JsonLimitsError> {
+        match peek {
+            Peek::Null => {
+                self.jiter.known_null()?;
+                Ok(JsonValue::Null)
+            }
+            Peek::True | Peek::False => Ok(JsonValue::Bool(self.jiter.known_bool(peek)?)),
+            Peek::String => {
+                let s = self.jiter.known_str()?;
+                self.check.string(|| s.len(), self.input)?;
+                Ok(JsonValue::Str(s.to_owned().into()))
+            }
+            Peek::Array => self.array(depth + 1),
+            Peek::Object => self.object(depth + 1),
+            _ if peek.is_num() => match self.jiter.known_number(peek)? {
+                NumberAny::Int(NumberInt::Int(int)) => Ok(JsonValue::Int(int)),
+                NumberAny::Int(NumberInt::BigInt(big_int)) => Ok(JsonValue::BigInt(big_int)),
+                NumberAny::Float(float) => Ok(JsonValue::Float(float)),
+            },
+            _ => Err(JsonLimitsError::Json(JsonError {
+                error_type: JsonErrorType::ExpectedSomeValue,
+                index: self.jiter.current_index(),
+            })),
+        }
+    }
+
+    fn array(&mut self, depth: usize) -> Result<JsonValue<'static>, JsonLimitsError> {
+        self.enter_container(depth)?;
+        let mut items = Vec::new();
+        while let Some(item) = self.array_item(items.is_empty(), items.len(), depth)? {
+            items.push(item);
+        }
+        Ok(JsonValue::Array(Arc::new(items)))
+    }
+
+    fn object(&mut self, depth: usize) -> Result<JsonValue<'static>, JsonLimitsError> {
+        self.enter_container(depth)?;
+        let mut fields = Vec::new();
+        while let Some(field) = self.object_field(fields.is_empty(), depth)? {
+            fields.push(field);
+        }
+        Ok(JsonValue::Object(Arc::new(fields)))
+    }
+
+    /// The next item of the array being parsed, `None` at the end of the array
+    pub(super) fn array_item(
+        &mut self,
+        first: bool,
+        index: usize,
+        depth: usize,
+    ) -> Result<Option<JsonValue<'static>>, JsonLimitsError> {
+        let step = if first {
+            self.jiter.known_array()
+        } else {
+            self.jiter.array_step()
+        };
+        let Some(peek) = self.partial(step)? else {
+            return Ok(None);
+        };
+        self.check.item(self.input)?;
+        match self.value(peek, depth) {
+            Ok(item) => Ok(Some(item)),
+            Err(JsonLimitsError::Json(e)) if self.allowed_if_partial(&e) => Ok(None),
+            Err(e) => Err(e.with_outer_location(index)),
+        }
+    }
+
+    /// The next key and value of the object being parsed, `None` at the end of the object
+    pub(super) fn object_field(
+        &mut self,
+        first: bool,
+        depth: usize,
+    ) -> Result<Option<(Cow<'static, str>, JsonValue<'static>)>, JsonLimitsError> {
+        let step = if first {
+            self.jiter.known_object()
+        } else {
+            self.jiter.next_key()
+        }
+        .map(|key| key.map(str::to_owned));
+        let Some(key) = self.partial(step)? else {
+            return Ok(None);
+        };
+        self.check.item(self.input)?;
+        self.check
+            .string(|| key.len(), self.input)
+            .map_err(|e| e.with_outer_location(key.as_str()))?;
+        let peek = self.jiter.peek().map(Some);
+        let Some(peek) = self.partial(peek)? else {
+            return Ok(None);
+        };
+        match self.value(peek, depth) {
+            Ok(value) => Ok(Some((key.into(), value))),
+            Err(JsonLimitsError::Json(e)) if self.allowed_if_partial(&e) => Ok(None),
+            Err(e) => Err(e.with_outer_location(key.as_str())),
+        }
+    }
+
+    pub(super) fn enter_container(&self, depth: usize) -> Result<(), JsonLimitsError> {
+        self.check.container(depth, self.input)?;
+        if depth > JSON_RECURSION_LIMIT {
+            return Err(JsonLimitsError::Json(JsonError {
+                error_type: JsonErrorType::RecursionLimitExceeded,
+                index: self.jiter.current_index(),
+            }));
+        }
+        Ok(())
+    }
+
+    /// With `allow_partial`, treat data ending part way through a container as the end of the container
+    pub(super) fn partial<T>(&self, result: Result<Option<T>, JiterError>) -> Result<Option<T>, JsonLimitsError> {
+        match result {
+            Ok(value) => Ok(value),
+            Err(e) => match JsonLimitsError::from(e) {
+                JsonLimitsError::Json(e) if self.allowed_if_partial(&e) => Ok(None),
+                e => Err(e),
+            },
+        }
+    }
+
+    pub(super) fn allowed_if_partial(&self, error: &JsonError) -> bool {
+        self.allow_partial
+            && matches!(
+                error.error_type,
+                JsonErrorType::EofWhileParsingList
+                    | JsonErrorType::EofWhileParsingObject
+                    | JsonErrorType::EofWhileParsingString
+                    | JsonErrorType::EofWhileParsingValue
+                    | JsonErrorType::ExpectedListCommaOrEnd
+                    | JsonErrorType::ExpectedObjectCommaOrEnd
+            )
+    }
+}
+
+/// Checks python input against `InputLimits` as validators are entered with each part of it, rather than
+/// scanning the input up front. Each container is counted once however many validators see it, and only
+/// containers which are validated are entered, so cyclic inputs are caught by the recursion guard like any
+/// other validation. The contents of values which aren't validated, e.g. by `any_schema`, aren't checked.
+pub struct LimitsState {
+    limits: InputLimits,
+    total_items: usize,
+    // containers counted towards `total_items` so far, held so their addresses can't be reused
+    seen: AHashMap<usize, PyObject>,
+    // the containers being validated, outermost first
+    containers: Vec<PyObject>,
+    // the first limit exceeded, reported however the validators handled the error
+    exceeded: Option<Exceeded>,
+}
+
+struct Exceeded {
+    limit: &'static str,
+    max_value: usize,
+    input: PyObject,
+    location: Vec<LocItem>,
+}
+
+impl Exceeded {
+    fn to_error(&self, py: Python) -> ValError {
+        self.location.iter().rev().fold(
+            exceeded(self.limit, self.max_value, self.input.bind(py)),
+            |error, loc_item| error.with_outer_location(loc_item.clone()),
+        )
+    }
+}
+
+impl LimitsState {
+    pub fn new(limits: InputLimits) -> Self {
+        Self {
+            limits,
+            total_items: 0,
+            seen: AHashMap::new(),
+            containers: Vec::new(),
+            exceeded: None,
+        }
+    }
+
+    /// Called as a validator is entered with `input`, returns whether `input` is a container which was entered,
+    /// in which case `exit` must be called once the validator returns.
+    pub fn enter(&mut self, input: &Bound<'_, PyAny>) -> ValResult<bool> {
+        if let Some(exceeded) = &self.exceeded {
+            // validation is only continuing because the error was suppressed, e.g. by a union, so stop early
+            return Err(exceeded.to_error(input.py()));
+        }
+        match self.check(input) {
+            Ok(entered) => Ok(entered),
+            Err((limit, max_value)) => {
+                let exceeded = Exceeded {
+                    limit,
+                    max_value,
+                    input: input.clone().unbind(),
+                    location: self.location(input),
+                };
+                let error = exceeded.to_error(input.py());
+                self.exceeded = Some(exceeded);
+                Err(error)
+            }
+        }
+    }
+
+    pub fn exit(&mut self) {
+        self.containers.pop();
+    }
+
+    /// The error for the first limit exceeded, it's raised even if the validator which saw it recovered,
+    /// e.g. a union trying its next choice or a default used `on_error`
+    pub fn exceeded_error(&self, py: Python) -> Option<ValError> {
+        self.exceeded.as_ref().map(|exceeded| exceeded.to_error(py))
+    }
+
+    fn check(&mut self, input: &Bound<'_, PyAny>) -> Result<bool, (&'static str, usize)> {
+        let len = if let Ok(py_str) = input.downcast::<PyString>() {
+            return self.check_string(|| match py_str.to_str() {
+                Ok(s) => s.len(),
+                // strings containing surrogates can't be encoded as utf-8
+                Err(_) => py_str.to_string_lossy().len(),
+            });
+        } else if let Ok(py_bytes) = input.downcast::<PyBytes>() {
+            return self.check_string(|| py_bytes.as_bytes().len());
+        } else if let Ok(py_byte_array) = input.downcast::<PyByteArray>() {
+            return self.check_string(|| py_byte_array.len());
+        } else if let Ok(dict) = input.downcast::<PyDict>() {
+            dict.len()
+        } else if let Ok(list) = input.downcast::<PyList>() {
+            list.len()
+        } else if let Ok(tuple) = input.downcast::<PyTuple>() {
+            tuple.len()
+        } else if let Ok(set) = input.downcast::<PySet>() {
+            set.len()
+        } else if let Ok(frozenset) = input.downcast::<PyFrozenSet>() {
+            frozenset.len()
+        } else {
+            if self.limits.string_length_bytes.is_some() {
+                if let Some(bytes) = EitherBytes::from_buffer(input) {
+                    return self.check_string(|| bytes.as_slice().len());
+                }
+            }
+            return Ok(false);
+        };
+        // a container is entered by every validator between it and its items, e.g. a model and its fields
+        if self.containers.last().is_some_and(|container| container.is(input)) {
+            return Ok(false);
+        }
+        match self.limits.nesting_depth {
+            Some(max_value) if self.containers.len() >= max_value => return Err(("max_nesting_depth", max_value)),
+            _ => (),
+        }
+        let key = input.as_ptr() as usize;
+        if !self.seen.contains_key(&key) {
+            self.total_items += len;
+            match self.limits.total_items {
+                Some(max_value) if self.total_items > max_value => return Err(("max_total_items", max_value)),
+                _ => (),
+            }
+            self.seen.insert(key, input.clone().unbind());
+        }
+        self.containers.push(input.clone().unbind());
+        Ok(true)
+    }
+
+    fn check_string(&self, length_bytes: impl FnOnce() -> usize) -> Result<bool, (&'static str, usize)> {
+        match self.limits.string_length_bytes {
+            Some(max_value) if length_bytes() > max_value => Err(("max_string_length_bytes", max_value)),
+            _ => Ok(false),
+        }
+    }
+
+    /// Where `input` is within the containers being validated, found by identity so it's the location in
+    /// the input, stopping at the first container it's not found in, e.g. if a function validator replaced it
+    fn location(&self, input: &Bound<'_, PyAny>) -> Vec<LocItem> {
+        let py = input.py();
+        let parents = self.containers.iter().map(|c| c.bind(py));
+        let children = parents.clone().skip(1).chain(std::iter::once(input));
+        parents
+            .zip(children)
+            .map_while(|(parent, child)| location_in(parent, child))
+            .collect()
+    }
+}
+
+fn location_in(parent: &Bound<'_, PyAny>, child: &Bound<'_, PyAny>) -> Option<LocItem> {
+    if let Ok(dict) = parent.downcast::<PyDict>() {
+        dict.iter()
+            .find(|(key, value)| value.is(child) || key.is(child))
+            .map(|(key, _)| LocItem::from(&key))
+    } else {
+        let iter = parent.try_iter().ok()?;
+        iter.flatten().position(|item| item.is(child)).map(LocItem::from)
+    }
+}
+
+fn exceeded(limit: &str, max_value: usize, input: impl ToErrorValue) -> ValError {
+    ValError::new(
+        ErrorType::ResourceLimitExceeded {
+            limit: limit.to_string(),
+            max_value,
+            context: None,
+        },
+        input,
+    )
+}
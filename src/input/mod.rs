@@ -7,24 +7,30 @@ mod input_abstract;
 mod input_json;
 mod input_python;
 mod input_string;
+mod json_chunks;
+mod limits;
 mod return_enums;
 mod shared;
+mod strptime;
 
 pub use datetime::TzInfo;
 pub(crate) use datetime::{
-    duration_as_pytimedelta, pydate_as_date, pydatetime_as_datetime, pytime_as_time, EitherDate, EitherDateTime,
-    EitherTime, EitherTimedelta,
+    bytes_as_datetime, duration_as_pytimedelta, epoch_as_datetime, pydate_as_date, pydatetime_as_datetime,
+    pytime_as_time, EitherDate, EitherDateTime, EitherTime, EitherTimedelta, EpochUnit,
 };
 pub(crate) use input_abstract::{
     Arguments, BorrowInput, ConsumeIterator, Input, InputType, KeywordArgs, PositionalArgs, ValidatedDict,
     ValidatedList, ValidatedSet, ValidatedTuple,
 };
-pub(crate) use input_python::{downcast_python_input, input_as_python_instance};
+pub(crate) use input_python::{array_like_as_list, downcast_python_input, input_as_python_instance};
 pub(crate) use input_string::StringMapping;
+pub(crate) use json_chunks::JsonChunks;
+pub(crate) use limits::{InputLimits, JsonLimitsError, LimitsState};
 pub(crate) use return_enums::{
     no_validator_iter_to_vec, py_string_str, validate_iter_to_set, validate_iter_to_vec, EitherBytes, EitherFloat,
     EitherInt, EitherString, GenericIterator, Int, MaxLengthCheck, ValidationMatch,
 };
+pub(crate) use strptime::{check_format, days_from_civil, format_offset, strftime, DateTimeFormats};
 
 // Defined here as it's not exported by pyo3
 pub fn py_error_on_minusone(py: Python<'_>, result: c_int) -> PyResult<()> {
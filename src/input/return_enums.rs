@@ -1,12 +1,12 @@
 use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::convert::Infallible;
-use std::ops::Rem;
 use std::str::FromStr;
 
 use jiter::{JsonArray, JsonValue, PartialMode, StringCacheMode};
-use num_bigint::BigInt;
+use num_bigint::{BigInt, Sign};
 
+use pyo3::buffer::PyBuffer;
 use pyo3::exceptions::PyTypeError;
 use pyo3::ffi;
 use pyo3::intern;
@@ -23,7 +23,7 @@ use crate::errors::{
 };
 use crate::py_gc::PyGcTraverse;
 use crate::tools::{extract_i64, extract_int, new_py_string, py_err};
-use crate::validators::{CombinedValidator, Exactness, ValidationState, Validator};
+use crate::validators::{CombinedValidator, Exactness, ValidationState};
 
 use super::{py_error_on_minusone, BorrowInput, Input};
 
@@ -55,6 +55,19 @@ impl<T> ValidationMatch<T> {
         self.0
     }
 
+    /// Like `unpack`, a lax match is also recorded as a coercion of `input` to `target`, see `record_coercion`
+    pub fn unpack_coerced<'py>(
+        self,
+        state: &mut ValidationState<'_, 'py>,
+        input: &(impl Input<'py> + ?Sized),
+        target: &'static str,
+    ) -> T {
+        if self.1 == Exactness::Lax {
+            state.record_coercion(input, target);
+        }
+        self.unpack(state)
+    }
+
     pub fn into_inner(self) -> T {
         self.0
     }
@@ -138,12 +151,18 @@ pub(crate) fn validate_iter_to_vec<'py>(
             false => PartialMode::Off,
         };
         let item = item_result.map_err(|e| any_next_error!(py, e, max_length_check.input, index))?;
-        match validator.validate(py, item.borrow_input(), state) {
+        let report_mark = state.report_mark();
+        state.field_path.push_index(index);
+        let result = validator.validate(py, item.borrow_input(), state);
+        state.field_path.pop();
+        match result {
             Ok(item) => {
+                state.reports_with_outer_location(report_mark, index);
                 max_length_check.incr()?;
                 output.push(item);
             }
             Err(ValError::LineErrors(line_errors)) => {
+                state.take_reports(report_mark);
                 max_length_check.incr()?;
                 if !is_last_partial {
                     errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
@@ -152,7 +171,9 @@ pub(crate) fn validate_iter_to_vec<'py>(
                     }
                 }
             }
-            Err(ValError::Omit) => (),
+            Err(ValError::Omit) => {
+                state.omitted_with_outer_location(report_mark, index);
+            }
             Err(err) => return Err(err),
         }
     }
@@ -235,8 +256,13 @@ pub(crate) fn validate_iter_to_set<'py>(
             false => PartialMode::Off,
         };
         let item = item_result.map_err(|e| any_next_error!(py, e, input, index))?;
-        match validate_add(py, set, item, state, validator) {
+        let report_mark = state.report_mark();
+        state.field_path.push_index(index);
+        let result = validate_add(py, set, item, state, validator);
+        state.field_path.pop();
+        match result {
             Ok(()) => {
+                state.reports_with_outer_location(report_mark, index);
                 if let Some(max_length) = max_length {
                     if set.build_len() > max_length {
                         return Err(ValError::new(
@@ -255,11 +281,12 @@ pub(crate) fn validate_iter_to_set<'py>(
                 }
             }
             Err(ValError::LineErrors(line_errors)) => {
+                state.take_reports(report_mark);
                 if !is_last_partial {
                     errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
                 }
             }
-            Err(ValError::Omit) => (),
+            Err(ValError::Omit) => state.omitted_with_outer_location(report_mark, index),
             Err(err) => return Err(err),
         }
         if fail_fast && !errors.is_empty() {
@@ -526,10 +553,25 @@ pub fn py_string_str<'a>(py_str: &'a Bound<'_, PyString>) -> ValResult<&'a str>
 }
 
 #[cfg_attr(debug_assertions, derive(Debug))]
-#[derive(IntoPyObject)]
 pub enum EitherBytes<'a, 'py> {
     Cow(Cow<'a, [u8]>),
     Py(Bound<'py, PyBytes>),
+    /// A C-contiguous byte buffer from an object implementing the buffer protocol, e.g. `memoryview`,
+    /// read in place without copying
+    Buffer(PyBuffer<u8>),
+}
+
+impl<'py> IntoPyObject<'py> for EitherBytes<'_, 'py> {
+    type Target = PyBytes;
+    type Output = Bound<'py, PyBytes>;
+    type Error = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        match self {
+            EitherBytes::Py(py_bytes) => Ok(py_bytes),
+            other => Ok(PyBytes::new(py, other.as_slice())),
+        }
+    }
 }
 
 impl From<Vec<u8>> for EitherBytes<'_, '_> {
@@ -550,11 +592,30 @@ impl<'a, 'py> From<&'a Bound<'py, PyBytes>> for EitherBytes<'a, 'py> {
     }
 }
 
-impl EitherBytes<'_, '_> {
+impl<'py> EitherBytes<'_, 'py> {
+    /// Get the contents of an object implementing the buffer protocol with a byte format, e.g. `memoryview`,
+    /// `None` if the object doesn't support the buffer protocol. Only non-contiguous buffers are copied.
+    pub fn from_buffer(obj: &Bound<'py, PyAny>) -> Option<Self> {
+        // cheap check first so we don't create an exception for every non-buffer input
+        if unsafe { ffi::PyObject_CheckBuffer(obj.as_ptr()) } == 0 {
+            return None;
+        }
+        let buffer = PyBuffer::<u8>::get(obj).ok()?;
+        if buffer.is_c_contiguous() {
+            Some(Self::Buffer(buffer))
+        } else {
+            buffer.to_vec(obj.py()).ok().map(Self::from)
+        }
+    }
+
     pub fn as_slice(&self) -> &[u8] {
         match self {
             EitherBytes::Cow(bytes) => bytes,
             EitherBytes::Py(py_bytes) => py_bytes.as_bytes(),
+            // Safety: the buffer is C-contiguous (see `from_buffer`) and kept alive by `PyBuffer`
+            EitherBytes::Buffer(buffer) => unsafe {
+                std::slice::from_raw_parts(buffer.buf_ptr().cast::<u8>(), buffer.len_bytes())
+            },
         }
     }
 
@@ -562,6 +623,7 @@ impl EitherBytes<'_, '_> {
         match self {
             EitherBytes::Cow(bytes) => Ok(bytes.len()),
             EitherBytes::Py(py_bytes) => py_bytes.len(),
+            EitherBytes::Buffer(buffer) => Ok(buffer.len_bytes()),
         }
     }
 }
@@ -616,7 +678,7 @@ impl<'py> EitherInt<'py> {
                 Ok(i) => Ok(Int::I64(i)),
                 Err(_) => Ok(Int::Big(BigInt::from(*u))),
             },
-            EitherInt::BigInt(b) => Ok(Int::Big(b.clone())),
+            EitherInt::BigInt(b) => Ok(Int::from_big(b.clone())),
             EitherInt::Py(i) => i
                 .extract()
                 .map_err(|_| ValError::new(ErrorTypeDefaults::IntParsingSize, i)),
@@ -685,13 +747,64 @@ where
         .serialize(serializer)
 }
 
+impl Int {
+    /// Build an `Int` from a `BigInt`, keeping values which fit in an `i64` off the heap so
+    /// `Int::Big` is only ever used for values outside the `i64` range.
+    pub fn from_big(big: BigInt) -> Self {
+        match i64::try_from(&big) {
+            Ok(i) => Int::I64(i),
+            Err(_) => Int::Big(big),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Int::I64(i) => *i == 0,
+            Int::Big(b) => b.sign() == Sign::NoSign,
+        }
+    }
+
+    /// Whether `self` is an exact multiple of `divisor`, zero is only treated as a multiple of zero.
+    pub fn is_multiple_of(&self, divisor: &Int) -> bool {
+        if divisor.is_zero() {
+            return self.is_zero();
+        }
+        match (self, divisor) {
+            // `i64::MIN % -1` overflows, but everything is a multiple of -1
+            (Int::I64(i1), Int::I64(i2)) => *i2 == -1 || i1 % i2 == 0,
+            (Int::Big(b1), Int::Big(b2)) => (b1 % b2).sign() == Sign::NoSign,
+            (Int::Big(b), Int::I64(i)) => (b % *i).sign() == Sign::NoSign,
+            (Int::I64(i), Int::Big(b)) => (BigInt::from(*i) % b).sign() == Sign::NoSign,
+        }
+    }
+}
+
+/// Compare an `i64` with a `BigInt` without allocating, anything outside the `i64` range
+/// is ordered by its sign alone.
+fn cmp_i64_big(i: i64, b: &BigInt) -> Ordering {
+    match i64::try_from(b) {
+        Ok(b) => i.cmp(&b),
+        Err(_) if b.sign() == Sign::Minus => Ordering::Greater,
+        Err(_) => Ordering::Less,
+    }
+}
+
+impl std::fmt::Display for Int {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Int::I64(i) => write!(f, "{i}"),
+            Int::Big(b) => write!(f, "{b}"),
+        }
+    }
+}
+
 impl PartialOrd for Int {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match (self, other) {
             (Int::I64(i1), Int::I64(i2)) => Some(i1.cmp(i2)),
             (Int::Big(b1), Int::Big(b2)) => Some(b1.cmp(b2)),
-            (Int::I64(i), Int::Big(b)) => Some(BigInt::from(*i).cmp(b)),
-            (Int::Big(b), Int::I64(i)) => Some(b.cmp(&BigInt::from(*i))),
+            (Int::I64(i), Int::Big(b)) => Some(cmp_i64_big(*i, b)),
+            (Int::Big(b), Int::I64(i)) => Some(cmp_i64_big(*i, b).reverse()),
         }
     }
 }
@@ -702,19 +815,6 @@ impl PartialEq for Int {
     }
 }
 
-impl Rem for &Int {
-    type Output = Int;
-
-    fn rem(self, rhs: Self) -> Self::Output {
-        match (self, rhs) {
-            (Int::I64(i1), Int::I64(i2)) => Int::I64(i1 % i2),
-            (Int::Big(b1), Int::Big(b2)) => Int::Big(b1 % b2),
-            (Int::I64(i), Int::Big(b)) => Int::Big(BigInt::from(*i) % b),
-            (Int::Big(b), Int::I64(i)) => Int::Big(b % BigInt::from(*i)),
-        }
-    }
-}
-
 impl FromPyObject<'_> for Int {
     fn extract_bound(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
         match extract_int(obj) {
@@ -0,0 +1,414 @@
+//! strptime style parsing for the `formats` option of the `date`, `time` and `datetime` schemas, and the
+//! matching strftime style formatting used by `ser_json_temporal_format`.
+//!
+//! Directives follow python's `datetime.strptime` (in the C locale), only the subset below is supported:
+//! `%Y %y %m %d %j %H %I %p %M %S %f %z %b %h %B %a %A %%`. As in python, whitespace in the format matches
+//! one or more whitespace characters and literal characters are matched case-insensitively.
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::fmt::Write;
+
+use speedate::{Date, DateTime, Time};
+
+use crate::build_tools::py_schema_err;
+use crate::tools::SchemaDict;
+
+use super::Input;
+
+const SUPPORTED_DIRECTIVES: &[u8] = b"YymdjHIpMSfzbhBaA%";
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+const WEEKDAYS: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+/// Check `format` only uses supported directives
+pub(crate) fn check_format(format: &str) -> PyResult<()> {
+    let mut bytes = format.bytes();
+    while let Some(b) = bytes.next() {
+        if b == b'%' {
+            match bytes.next() {
+                Some(directive) if SUPPORTED_DIRECTIVES.contains(&directive) => (),
+                Some(directive) => {
+                    return py_schema_err!(
+                        "Invalid format {:?}, unsupported directive '%{}'",
+                        format,
+                        directive as char
+                    )
+                }
+                None => return py_schema_err!("Invalid format {:?}, stray '%' at the end", format),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The formats from a schema's `formats` key, tried in order before the default parsing in lax mode
+#[derive(Debug, Clone)]
+pub struct DateTimeFormats(Vec<String>);
+
+impl DateTimeFormats {
+    pub fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let Some(formats) = schema.get_as::<Vec<String>>(intern!(schema.py(), "formats"))? else {
+            return Ok(None);
+        };
+        for format in &formats {
+            check_format(format)?;
+        }
+        Ok((!formats.is_empty()).then_some(Self(formats)))
+    }
+
+    /// Parse a string input with the first format which matches it, `None` if the input isn't a string or
+    /// doesn't match any of the formats
+    pub fn parse<'py>(&self, input: &(impl Input<'py> + ?Sized)) -> Option<StrptimeParts> {
+        let either_str = input.validate_str(true, false).ok()?.into_inner();
+        let s = either_str.as_cow().ok()?;
+        self.0.iter().find_map(|format| StrptimeParts::parse(&s, format))
+    }
+}
+
+/// The components read from a string, with python's defaults for those missing from the format
+#[derive(Debug)]
+pub struct StrptimeParts {
+    year: u16,
+    month: u8,
+    day: u8,
+    day_of_year: Option<u16>,
+    hour: u8,
+    // `%I` was used, so `hour` is on the 12-hour clock
+    hour_12: bool,
+    pm: bool,
+    minute: u8,
+    second: u8,
+    microsecond: u32,
+    tz_offset: Option<i32>,
+}
+
+impl Default for StrptimeParts {
+    fn default() -> Self {
+        Self {
+            year: 1900,
+            month: 1,
+            day: 1,
+            day_of_year: None,
+            hour: 0,
+            hour_12: false,
+            pm: false,
+            minute: 0,
+            second: 0,
+            microsecond: 0,
+            tz_offset: None,
+        }
+    }
+}
+
+impl StrptimeParts {
+    fn parse(s: &str, format: &str) -> Option<Self> {
+        let mut parts = Self::default();
+        let mut input = s.as_bytes();
+        let mut format = format.bytes();
+        while let Some(f) = format.next() {
+            input = match f {
+                b'%' => parts.parse_directive(format.next()?, input)?,
+                f if f.is_ascii_whitespace() => {
+                    let whitespace = input.iter().take_while(|b| b.is_ascii_whitespace()).count();
+                    if whitespace == 0 {
+                        return None;
+                    }
+                    &input[whitespace..]
+                }
+                f => match input.split_first() {
+                    Some((c, rest)) if c.eq_ignore_ascii_case(&f) => rest,
+                    _ => return None,
+                },
+            };
+        }
+        input.is_empty().then_some(parts)
+    }
+
+    fn parse_directive<'a>(&mut self, directive: u8, input: &'a [u8]) -> Option<&'a [u8]> {
+        match directive {
+            b'Y' => {
+                let (year, rest) = number(input, 4, 4)?;
+                self.year = u16::try_from(year).ok().filter(|y| *y >= 1)?;
+                Some(rest)
+            }
+            b'y' => {
+                // POSIX convention, as used by python: 69-99 are 1969-1999, 0-68 are 2000-2068
+                let (year, rest) = number(input, 2, 2)?;
+                self.year = if year >= 69 { 1900 + year } else { 2000 + year } as u16;
+                Some(rest)
+            }
+            b'm' => {
+                let (month, rest) = number(input, 1, 2)?;
+                self.month = in_range(month, 1, 12)?;
+                Some(rest)
+            }
+            b'd' => {
+                let (day, rest) = number(input, 1, 2)?;
+                self.day = in_range(day, 1, 31)?;
+                Some(rest)
+            }
+            b'j' => {
+                let (day_of_year, rest) = number(input, 1, 3)?;
+                self.day_of_year = Some(in_range(day_of_year, 1, 366)?);
+                Some(rest)
+            }
+            b'H' => {
+                let (hour, rest) = number(input, 1, 2)?;
+                self.hour = in_range(hour, 0, 23)?;
+                self.hour_12 = false;
+                Some(rest)
+            }
+            b'I' => {
+                let (hour, rest) = number(input, 1, 2)?;
+                self.hour = in_range(hour, 1, 12)?;
+                self.hour_12 = true;
+                Some(rest)
+            }
+            b'p' => {
+                let (index, rest) = name(input, &["am", "pm"], None)?;
+                self.pm = index == 1;
+                Some(rest)
+            }
+            b'M' => {
+                let (minute, rest) = number(input, 1, 2)?;
+                self.minute = in_range(minute, 0, 59)?;
+                Some(rest)
+            }
+            b'S' => {
+                let (second, rest) = number(input, 1, 2)?;
+                self.second = in_range(second, 0, 59)?;
+                Some(rest)
+            }
+            b'f' => {
+                let digits = input.iter().take(6).take_while(|b| b.is_ascii_digit()).count();
+                let (fraction, rest) = number(input, 1, 6)?;
+                self.microsecond = fraction * 10u32.pow(6 - u32::try_from(digits).ok()?);
+                Some(rest)
+            }
+            b'z' => {
+                let (tz_offset, rest) = tz_offset(input)?;
+                self.tz_offset = Some(tz_offset);
+                Some(rest)
+            }
+            b'b' | b'h' => {
+                let (index, rest) = name(input, &MONTHS, Some(3))?;
+                self.month = u8::try_from(index + 1).ok()?;
+                Some(rest)
+            }
+            b'B' => {
+                let (index, rest) = name(input, &MONTHS, None)?;
+                self.month = u8::try_from(index + 1).ok()?;
+                Some(rest)
+            }
+            // the weekday is checked to be a valid name but is otherwise ignored, as by python
+            b'a' => name(input, &WEEKDAYS, Some(3)).map(|(_, rest)| rest),
+            b'A' => name(input, &WEEKDAYS, None).map(|(_, rest)| rest),
+            b'%' => input.strip_prefix(b"%"),
+            _ => None,
+        }
+    }
+
+    pub fn date(&self) -> Option<Date> {
+        let leap = self.year % 4 == 0 && (self.year % 100 != 0 || self.year % 400 == 0);
+        let days_in_month = |month: u8| match month {
+            2 if leap => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        };
+        let (month, day) = match self.day_of_year {
+            Some(mut day_of_year) => {
+                let mut month = 1;
+                while day_of_year > u16::from(days_in_month(month)) {
+                    day_of_year -= u16::from(days_in_month(month));
+                    month += 1;
+                    if month > 12 {
+                        return None;
+                    }
+                }
+                (month, u8::try_from(day_of_year).ok()?)
+            }
+            None if self.day > days_in_month(self.month) => return None,
+            None => (self.month, self.day),
+        };
+        Some(Date {
+            year: self.year,
+            month,
+            day,
+        })
+    }
+
+    pub fn time(&self) -> Option<Time> {
+        let hour = if self.hour_12 {
+            self.hour % 12 + if self.pm { 12 } else { 0 }
+        } else {
+            self.hour
+        };
+        Some(Time {
+            hour,
+            minute: self.minute,
+            second: self.second,
+            microsecond: self.microsecond,
+            tz_offset: self.tz_offset,
+        })
+    }
+
+    pub fn datetime(&self) -> Option<DateTime> {
+        Some(DateTime {
+            date: self.date()?,
+            time: self.time()?,
+        })
+    }
+}
+
+/// Read between `min` and `max` ascii digits
+fn number(input: &[u8], min: usize, max: usize) -> Option<(u32, &[u8])> {
+    let len = input.iter().take(max).take_while(|b| b.is_ascii_digit()).count();
+    if len < min {
+        return None;
+    }
+    let value = input[..len].iter().fold(0, |acc, b| acc * 10 + u32::from(b - b'0'));
+    Some((value, &input[len..]))
+}
+
+fn in_range<T: TryFrom<u32>>(value: u32, min: u32, max: u32) -> Option<T> {
+    if (min..=max).contains(&value) {
+        T::try_from(value).ok()
+    } else {
+        None
+    }
+}
+
+/// Match one of `names` case-insensitively, or its first `abbreviation` characters, returning its index
+fn name<'a>(input: &'a [u8], names: &[&str], abbreviation: Option<usize>) -> Option<(usize, &'a [u8])> {
+    names.iter().enumerate().find_map(|(index, name)| {
+        let name = abbreviation.map_or(name.as_bytes(), |len| &name.as_bytes()[..len]);
+        let candidate = input.get(..name.len())?;
+        candidate
+            .eq_ignore_ascii_case(name)
+            .then(|| (index, &input[name.len()..]))
+    })
+}
+
+/// `Z`, `±HHMM` or `±HH:MM`, returning the offset in seconds
+fn tz_offset(input: &[u8]) -> Option<(i32, &[u8])> {
+    let (sign, rest) = match input.first()? {
+        b'Z' | b'z' => return Some((0, &input[1..])),
+        b'+' => (1, &input[1..]),
+        b'-' => (-1, &input[1..]),
+        _ => return None,
+    };
+    let (hours, rest) = number(rest, 2, 2)?;
+    let rest = rest.strip_prefix(b":").unwrap_or(rest);
+    let (minutes, rest) = number(rest, 2, 2)?;
+    let hours: i32 = in_range(hours, 0, 23)?;
+    let minutes: i32 = in_range(minutes, 0, 59)?;
+    Some((sign * (hours * 3600 + minutes * 60), rest))
+}
+
+/// Format a date and time with a format already checked by `check_format`, as python's `strftime` would
+pub(crate) fn strftime(format: &str, date: &Date, time: &Time) -> String {
+    let mut out = String::with_capacity(format.len() + 16);
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        // `write!` to a `String` can't fail
+        let _ = match chars.next() {
+            Some('Y') => write!(out, "{:04}", date.year),
+            Some('y') => write!(out, "{:02}", date.year % 100),
+            Some('m') => write!(out, "{:02}", date.month),
+            Some('d') => write!(out, "{:02}", date.day),
+            Some('j') => write!(out, "{:03}", day_of_year(date)),
+            Some('H') => write!(out, "{:02}", time.hour),
+            Some('I') => write!(out, "{:02}", (time.hour + 11) % 12 + 1),
+            Some('p') => write!(out, "{}", if time.hour < 12 { "AM" } else { "PM" }),
+            Some('M') => write!(out, "{:02}", time.minute),
+            Some('S') => write!(out, "{:02}", time.second),
+            Some('f') => write!(out, "{:06}", time.microsecond),
+            Some('z') => match time.tz_offset {
+                Some(offset) => write!(out, "{}", format_offset(offset)),
+                None => Ok(()),
+            },
+            Some('b' | 'h') => write!(out, "{}", title(&MONTHS[usize::from(date.month) - 1][..3])),
+            Some('B') => write!(out, "{}", title(MONTHS[usize::from(date.month) - 1])),
+            Some('a') => write!(out, "{}", title(&WEEKDAYS[weekday(date)][..3])),
+            Some('A') => write!(out, "{}", title(WEEKDAYS[weekday(date)])),
+            Some('%') | None => write!(out, "%"),
+            Some(other) => write!(out, "%{other}"),
+        };
+    }
+    out
+}
+
+/// `±HHMM`, with seconds appended if the offset isn't a whole number of minutes, as python's `%z`
+pub(crate) fn format_offset(offset: i32) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.unsigned_abs();
+    let (hours, minutes, seconds) = (offset / 3600, offset / 60 % 60, offset % 60);
+    if seconds == 0 {
+        format!("{sign}{hours:02}{minutes:02}")
+    } else {
+        format!("{sign}{hours:02}{minutes:02}{seconds:02}")
+    }
+}
+
+/// Days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+pub(crate) fn days_from_civil(date: &Date) -> i64 {
+    let month = i64::from(date.month);
+    let year = i64::from(date.year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(date.day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn day_of_year(date: &Date) -> i64 {
+    let new_year = Date {
+        year: date.year,
+        month: 1,
+        day: 1,
+    };
+    days_from_civil(date) - days_from_civil(&new_year) + 1
+}
+
+/// Index into `WEEKDAYS`, 1970-01-01 was a Thursday
+fn weekday(date: &Date) -> usize {
+    // rem_euclid(7) is always in 0..7
+    (days_from_civil(date) + 3).rem_euclid(7) as usize
+}
+
+fn title(name: &str) -> String {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
@@ -15,31 +15,40 @@ use validators::ValBytesMode;
 mod py_gc;
 
 mod argument_markers;
+mod arrow;
 mod build_tools;
 mod common;
 mod definitions;
+mod email;
 mod errors;
 mod input;
 mod lookup_key;
+mod merge_patch;
 mod recursion_guard;
+mod secret;
 mod serializers;
 mod tools;
 mod url;
 mod validators;
 
 // required for benchmarks
+pub use self::email::PyEmailAddress;
 pub use self::input::TzInfo;
+pub use self::secret::PySecret;
 pub use self::url::{PyMultiHostUrl, PyUrl};
 pub use argument_markers::{ArgsKwargs, PydanticUndefinedType};
+pub use arrow::ArrowArray;
 pub use build_tools::SchemaError;
 pub use errors::{
     list_all_errors, PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault, ValidationError,
 };
 pub use serializers::{
-    to_json, to_jsonable_python, PydanticSerializationError, PydanticSerializationUnexpectedValue, SchemaSerializer,
-    WarningsArg,
+    copy_validated, register_ob_type, to_json, to_jsonable_python, PydanticSerializationError,
+    PydanticSerializationUnexpectedValue, SchemaSerializer, SetOrder, WarningsArg,
+};
+pub use validators::{
+    validate_core_schema, ModelFieldGetter, PySome, SchemaValidator, ValidationReport, ValidationResult,
 };
-pub use validators::{validate_core_schema, PySome, SchemaValidator};
 
 use crate::input::Input;
 
@@ -53,7 +62,7 @@ pub fn from_json<'py>(
 ) -> PyResult<Bound<'py, PyAny>> {
     let v_match = data
         .validate_bytes(false, ValBytesMode { ser: BytesMode::Utf8 })
-        .map_err(|_| PyTypeError::new_err("Expected bytes, bytearray or str"))?;
+        .map_err(|_| PyTypeError::new_err("Expected bytes, bytearray, memoryview or str"))?;
     let json_either_bytes = v_match.into_inner();
     let json_bytes = json_either_bytes.as_slice();
     let parse_builder = PythonParse {
@@ -113,10 +122,11 @@ mod _pydantic_core {
 
     #[pymodule_export]
     use crate::{
-        from_json, list_all_errors, to_json, to_jsonable_python, validate_core_schema, ArgsKwargs, PyMultiHostUrl,
+        copy_validated, from_json, list_all_errors, register_ob_type, to_json, to_jsonable_python,
+        validate_core_schema, ArgsKwargs, ArrowArray, ModelFieldGetter, PyEmailAddress, PyMultiHostUrl, PySecret,
         PySome, PyUrl, PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticSerializationError,
         PydanticSerializationUnexpectedValue, PydanticUndefinedType, PydanticUseDefault, SchemaError, SchemaSerializer,
-        SchemaValidator, TzInfo, ValidationError,
+        SchemaValidator, TzInfo, ValidationError, ValidationReport, ValidationResult,
     };
 
     #[pymodule_init]
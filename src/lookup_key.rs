@@ -1,9 +1,11 @@
+use std::borrow::Cow;
 use std::convert::Infallible;
 use std::fmt;
+use std::sync::Arc;
 
 use pyo3::exceptions::{PyAttributeError, PyTypeError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyMapping, PyString};
+use pyo3::types::{PyDict, PyList, PyMapping, PyString, PyTuple};
 use pyo3::IntoPyObjectExt;
 
 use jiter::{JsonObject, JsonValue};
@@ -21,7 +23,8 @@ pub(crate) enum LookupKey {
     /// look up a key by either string, equivalent to `d.get(choice1, d.get(choice2))`
     Choice { path1: LookupPath, path2: LookupPath },
     /// look up keys by one or more "paths" a path might be `['foo', 'bar']` to get `d.?foo.?bar`
-    /// ints are also supported to index arrays/lists/tuples and dicts with int keys
+    /// ints are also supported to index arrays/lists/tuples and dicts with int keys, negative ints index from the end,
+    /// `'*'` after the first item collects the rest of the path from every item of an array/list/tuple/dict into a list
     /// we reuse Location as the enum is the same, and the meaning is the same
     PathChoices(Vec<LookupPath>),
 }
@@ -117,9 +120,8 @@ impl LookupKey {
                     let Some(first_value) = dict.get_item(&path.first_item.py_key)? else {
                         continue;
                     };
-                    // iterate over the path and plug each value into the py_any from the last step,
-                    // this could just be a loop but should be somewhat faster with a functional design
-                    if let Some(v) = path.rest.iter().try_fold(first_value, |d, loc| loc.py_get_item(&d)) {
+                    // iterate over the path and plug each value into the py_any from the last step
+                    if let Some(v) = py_get_path(&path.rest, first_value, &|loc, d| Ok(loc.py_get_item(d)))? {
                         // Successfully found an item, return it
                         return Ok(Some((path, v)));
                     }
@@ -172,9 +174,8 @@ impl LookupKey {
                     let Some(first_value) = dict.get_item(&path.first_item.py_key).ok() else {
                         continue;
                     };
-                    // iterate over the path and plug each value into the py_any from the last step,
-                    // this could just be a loop but should be somewhat faster with a functional design
-                    if let Some(v) = path.rest.iter().try_fold(first_value, |d, loc| loc.py_get_item(&d)) {
+                    // iterate over the path and plug each value into the py_any from the last step
+                    if let Some(v) = py_get_path(&path.rest, first_value, &|loc, d| Ok(loc.py_get_item(d)))? {
                         // Successfully found an item, return it
                         return Ok(Some((path, v)));
                     }
@@ -211,23 +212,15 @@ impl LookupKey {
                 },
             },
             Self::PathChoices(path_choices) => {
-                'outer: for path in path_choices {
-                    // similar to above, but using `py_get_attrs`, we can't use try_fold because of the extra Err
-                    // so we have to loop manually
-                    let Some(mut v) = path.first_item.py_get_attrs(obj)? else {
+                for path in path_choices {
+                    // similar to above, but using `py_get_attrs`
+                    let Some(first_value) = path.first_item.py_get_attrs(obj)? else {
                         continue;
                     };
-                    for loc in &path.rest {
-                        v = match loc.py_get_attrs(&v) {
-                            Ok(Some(v)) => v,
-                            Ok(None) => {
-                                continue 'outer;
-                            }
-                            Err(e) => return Err(e),
-                        }
+                    if let Some(v) = py_get_path(&path.rest, first_value, &|loc, d| loc.py_get_attrs(d))? {
+                        // Successfully found an item, return it
+                        return Ok(Some((path, v)));
                     }
-                    // Successfully found an item, return it
-                    return Ok(Some((path, v)));
                 }
                 // got to the end of path_choices, without a match, return None
                 Ok(None)
@@ -261,7 +254,7 @@ impl LookupKey {
     pub fn json_get<'a, 'data, 's>(
         &'s self,
         dict: &'a JsonObject<'data>,
-    ) -> ValResult<Option<(&'s LookupPath, &'a JsonValue<'data>)>> {
+    ) -> ValResult<Option<(&'s LookupPath, Cow<'a, JsonValue<'data>>)>> {
         // FIXME: use of find_map in here probably leads to quadratic complexity
         match self {
             Self::Simple(path) => match dict
@@ -271,7 +264,7 @@ impl LookupKey {
             {
                 Some(value) => {
                     debug_assert!(path.rest.is_empty());
-                    Ok(Some((path, value)))
+                    Ok(Some((path, Cow::Borrowed(value))))
                 }
                 None => Ok(None),
             },
@@ -282,7 +275,7 @@ impl LookupKey {
             {
                 Some(value) => {
                     debug_assert!(path1.rest.is_empty());
-                    Ok(Some((path1, value)))
+                    Ok(Some((path1, Cow::Borrowed(value))))
                 }
                 None => match dict
                     .iter()
@@ -291,7 +284,7 @@ impl LookupKey {
                 {
                     Some(value) => {
                         debug_assert!(path2.rest.is_empty());
-                        Ok(Some((path2, value)))
+                        Ok(Some((path2, Cow::Borrowed(value))))
                     }
                     None => Ok(None),
                 },
@@ -309,12 +302,10 @@ impl LookupKey {
                         None => continue,
                     };
 
-                    let mut path_iter = path.rest.iter();
-
                     // similar to above
                     // iterate over the path and plug each value into the JsonValue from the last step, starting with v
-                    // from the first step, this could just be a loop but should be somewhat faster with a functional design
-                    if let Some(v) = path_iter.try_fold(v, |d, loc| loc.json_get(d)) {
+                    // from the first step
+                    if let Some(v) = json_get_path(&path.rest, v) {
                         // Successfully found an item, return it
                         return Ok(Some((path, v)));
                     }
@@ -418,6 +409,19 @@ impl LookupPath {
         }
     }
 
+    /// the alias which this path looks up, a `str` for a single key, otherwise a list of the path's items
+    pub fn to_alias<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        if self.rest.is_empty() {
+            return Ok(self.first_item.py_key.bind(py).clone().into_any());
+        }
+        let mut items = Vec::with_capacity(1 + self.rest.len());
+        items.push(self.first_item.py_key.bind(py).clone().into_any());
+        for item in &self.rest {
+            items.push(item.into_pyobject(py)?);
+        }
+        Ok(PyList::new(py, items)?.into_any())
+    }
+
     /// get the `str` from the first item in the path, note paths always have length > 0, and the first item
     /// is always a string
     pub fn first_key(&self) -> &str {
@@ -431,6 +435,9 @@ pub(crate) enum PathItem {
     /// integer key, used to get items from a list, tuple OR a dict with int keys `dict[int, ...]` (python only)
     Pos(usize),
     Neg(usize),
+    /// `...`, look up the rest of the path in every item of an array/list/tuple or every value of a dict,
+    /// and collect the matches into a list. Shown as `*` in locations
+    Wildcard,
 }
 
 /// string type key, used to get or identify items from a dict or anything that implements `__getitem__`
@@ -447,6 +454,7 @@ impl fmt::Display for PathItem {
             Self::S(key) => key.fmt(f),
             Self::Pos(key) => write!(f, "{key}"),
             Self::Neg(key) => write!(f, "-{key}"),
+            Self::Wildcard => write!(f, "*"),
         }
     }
 }
@@ -470,6 +478,7 @@ impl<'py> IntoPyObject<'py> for &'_ PathItem {
                 let neg_value = -(*val as i64);
                 neg_value.into_bound_py_any(py)
             }
+            PathItem::Wildcard => Ok(py.Ellipsis().into_bound(py)),
         }
     }
 }
@@ -486,6 +495,10 @@ impl<'a, 'py> IntoPyObject<'py> for &'a PathItemString {
 
 impl PathItem {
     pub fn from_py(obj: Bound<'_, PyAny>) -> PyResult<Self> {
+        // an explicit marker rather than `'*'`, so a key of `'*'` can still be looked up
+        if obj.is(&obj.py().Ellipsis()) {
+            return Ok(Self::Wildcard);
+        }
         let obj = match obj.downcast_into::<PyString>() {
             Ok(py_str_key) => {
                 let str_key = py_str_key.to_str()?.to_string();
@@ -536,7 +549,7 @@ impl PathItem {
                         None
                     }
                 }
-                Self::S(..) => None,
+                Self::S(..) | Self::Wildcard => None,
             },
             _ => None,
         }
@@ -554,6 +567,7 @@ impl PathItem {
             Self::S(PathItemString { key, .. }) => LocItem::from(key),
             Self::Pos(index) => LocItem::from(*index),
             Self::Neg(index) => LocItem::from(-(*index as i64)),
+            Self::Wildcard => LocItem::from(WILDCARD),
         }
     }
 }
@@ -569,6 +583,67 @@ impl PathItemString {
     }
 }
 
+const WILDCARD: &str = "*";
+
+/// follow `rest` from `value` using `get` for each step, when a wildcard is reached the remainder of the path
+/// is followed from each item of `value` and the matches are collected into a list, items without a match are skipped
+fn py_get_path<'py>(
+    rest: &[PathItem],
+    mut value: Bound<'py, PyAny>,
+    get: &impl Fn(&PathItem, &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>>,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    for (index, item) in rest.iter().enumerate() {
+        if let PathItem::Wildcard = item {
+            let items: Vec<Bound<'py, PyAny>> = if let Ok(list) = value.downcast::<PyList>() {
+                list.iter().collect()
+            } else if let Ok(tuple) = value.downcast::<PyTuple>() {
+                tuple.iter().collect()
+            } else if let Ok(dict) = value.downcast::<PyDict>() {
+                dict.values().iter().collect()
+            } else {
+                return Ok(None);
+            };
+            let mut matches = Vec::with_capacity(items.len());
+            for item_value in items {
+                if let Some(v) = py_get_path(&rest[index + 1..], item_value, get)? {
+                    matches.push(v);
+                }
+            }
+            return Ok(Some(PyList::new(value.py(), matches)?.into_any()));
+        }
+        value = match get(item, &value)? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+    }
+    Ok(Some(value))
+}
+
+/// JSON equivalent of `py_get_path`, values are only copied when a wildcard creates a new array
+fn json_get_path<'a, 'data>(rest: &[PathItem], mut value: &'a JsonValue<'data>) -> Option<Cow<'a, JsonValue<'data>>> {
+    for (index, item) in rest.iter().enumerate() {
+        if let PathItem::Wildcard = item {
+            let rest = &rest[index + 1..];
+            let matches: Vec<JsonValue<'data>> = match value {
+                JsonValue::Array(array) => array
+                    .iter()
+                    .filter_map(|v| json_get_path(rest, v))
+                    .map(Cow::into_owned)
+                    .collect(),
+                JsonValue::Object(object) => object
+                    .iter()
+                    .filter_map(|(_, v)| json_get_path(rest, v))
+                    .map(Cow::into_owned)
+                    .collect(),
+                _ => return None,
+            };
+            return Some(Cow::Owned(JsonValue::Array(Arc::new(matches))));
+        }
+        value = item.json_get(value)?;
+    }
+    Some(Cow::Borrowed(value))
+}
+
 /// wrapper around `getitem` that excludes string indexing `None` for strings
 fn py_get_item<'py>(py_any: &Bound<'py, PyAny>, index: impl IntoPyObject<'py>) -> Option<Bound<'py, PyAny>> {
     // we definitely don't want to index strings, so explicitly omit this case
@@ -0,0 +1,124 @@
+//! JSON merge patch application, see https://www.rfc-editor.org/rfc/rfc7386
+//!
+//! The patch is merged into the current state of the instance as python data, the caller then validates the
+//! result so the patched state is subject to the same checks as any other input.
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PySet, PyString, PyType};
+
+use crate::validators::force_setattr;
+
+/// Apply `patch` to `target`: objects in the patch are merged recursively, `null` removes a key and any other
+/// value replaces the target wholesale.
+pub fn merge_patch<'py>(target: Option<&Bound<'py, PyAny>>, patch: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let Ok(patch_dict) = patch.downcast::<PyDict>() else {
+        return Ok(patch.clone());
+    };
+    let py = patch.py();
+    let merged = match target {
+        Some(target) => object_state(target)?.unwrap_or_else(|| PyDict::new(py)),
+        None => PyDict::new(py),
+    };
+    for (key, value) in patch_dict {
+        if value.is_none() {
+            if merged.contains(&key)? {
+                merged.del_item(&key)?;
+            }
+        } else {
+            let current = merged.get_item(&key)?;
+            merged.set_item(&key, merge_patch(current.as_ref(), &value)?)?;
+        }
+    }
+    Ok(merged.into_any())
+}
+
+/// A fresh dict holding the members of `value` if it's a JSON object: a dict, a model or a dataclass.
+fn object_state<'py>(value: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let py = value.py();
+    if let Ok(dict) = value.downcast::<PyDict>() {
+        Ok(Some(dict.copy()?))
+    } else if value.hasattr(intern!(py, "__pydantic_fields_set__"))? {
+        let state = value.getattr(intern!(py, "__dict__"))?.downcast::<PyDict>()?.copy()?;
+        if let Ok(model_extra) = value.getattr(intern!(py, "__pydantic_extra__")) {
+            if let Ok(model_extra) = model_extra.downcast::<PyDict>() {
+                state.update(model_extra.as_mapping())?;
+            }
+        }
+        Ok(Some(state))
+    } else if value.hasattr(intern!(py, "__dataclass_fields__"))? && !value.is_instance_of::<PyType>() {
+        // `dataclasses.fields()` leaves out `InitVar` and `ClassVar` pseudo-fields
+        let fields = py
+            .import(intern!(py, "dataclasses"))?
+            .call_method1(intern!(py, "fields"), (value,))?;
+        let state = PyDict::new(py);
+        for field in fields.try_iter()? {
+            let name = field?.getattr(intern!(py, "name"))?.downcast_into::<PyString>()?;
+            state.set_item(&name, value.getattr(&name)?)?;
+        }
+        Ok(Some(state))
+    } else {
+        Ok(None)
+    }
+}
+
+/// The state of models is re-validated from all of their fields, so after validation models in `output` would have
+/// every field set. Instead each keeps the fields set on its `original`, plus those the patch set (by name, or by
+/// alias where that changed the value), less those the patch removed.
+pub fn restore_fields_set(
+    original: &Bound<'_, PyAny>,
+    patch: &Bound<'_, PyAny>,
+    output: &Bound<'_, PyAny>,
+) -> PyResult<()> {
+    let Ok(patch) = patch.downcast::<PyDict>() else {
+        return Ok(());
+    };
+    let py = patch.py();
+    let (Some(original_state), Some(output_state)) = (members(original)?, members(output)?) else {
+        return Ok(());
+    };
+    for (key, value) in patch {
+        if let (Some(original_value), Some(output_value)) =
+            (original_state.get_item(&key)?, output_state.get_item(&key)?)
+        {
+            restore_fields_set(&original_value, &value, &output_value)?;
+        }
+    }
+
+    let fields_set_attr = intern!(py, "__pydantic_fields_set__");
+    let (Ok(original_fields_set), Ok(output_fields_set)) =
+        (original.getattr(fields_set_attr), output.getattr(fields_set_attr))
+    else {
+        return Ok(());
+    };
+    let fields_set = PySet::empty(py)?;
+    for name in output_fields_set.try_iter()? {
+        let name = name?;
+        let is_set = match patch.get_item(&name)? {
+            Some(value) => !value.is_none(),
+            None => {
+                original_fields_set.contains(&name)?
+                    || match (original_state.get_item(&name)?, output_state.get_item(&name)?) {
+                        (Some(original_value), Some(output_value)) => original_value.ne(output_value)?,
+                        _ => true,
+                    }
+            }
+        };
+        if is_set {
+            fields_set.add(name)?;
+        }
+    }
+    force_setattr(py, output, fields_set_attr, fields_set)
+}
+
+/// The members of a dict or a model, without copying
+fn members<'py>(value: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let py = value.py();
+    if let Ok(dict) = value.downcast::<PyDict>() {
+        Ok(Some(dict.clone()))
+    } else if value.hasattr(intern!(py, "__pydantic_fields_set__"))? {
+        Ok(value.getattr(intern!(py, "__dict__"))?.downcast_into::<PyDict>().ok())
+    } else {
+        Ok(None)
+    }
+}
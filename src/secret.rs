@@ -0,0 +1,87 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{PyBytes, PyString};
+use pyo3::IntoPyObjectExt;
+
+/// Shown in place of the value of a secret, in reprs, error inputs and serialized output
+pub const MASKED_SECRET: &str = "**********";
+
+#[pyclass(name = "Secret", module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+pub struct PySecret {
+    // either a `str` or `bytes`
+    value: PyObject,
+}
+
+impl PySecret {
+    pub fn new(value: PyObject) -> Self {
+        Self { value }
+    }
+
+    pub fn value<'py>(&self, py: Python<'py>) -> &Bound<'py, PyAny> {
+        self.value.bind(py)
+    }
+
+    fn is_bytes(&self, py: Python) -> bool {
+        self.value.bind(py).is_instance_of::<PyBytes>()
+    }
+
+    fn is_empty(&self, py: Python) -> PyResult<bool> {
+        Ok(self.value.bind(py).len()? == 0)
+    }
+}
+
+#[pymethods]
+impl PySecret {
+    #[new]
+    pub fn py_new(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if value.is_exact_instance_of::<PyString>() || value.is_exact_instance_of::<PyBytes>() {
+            Ok(Self::new(value.clone().unbind()))
+        } else {
+            Err(PyTypeError::new_err("Secret value must be a str or bytes"))
+        }
+    }
+
+    pub fn get_secret_value(&self, py: Python) -> PyObject {
+        self.value.clone_ref(py)
+    }
+
+    pub fn __str__(&self, py: Python) -> PyResult<&'static str> {
+        Ok(if self.is_empty(py)? { "" } else { MASKED_SECRET })
+    }
+
+    pub fn __repr__(&self, py: Python) -> PyResult<String> {
+        let prefix = if self.is_bytes(py) { "b" } else { "" };
+        Ok(format!("Secret({prefix}'{}')", self.__str__(py)?))
+    }
+
+    fn __len__(&self, py: Python) -> PyResult<usize> {
+        self.value.bind(py).len()
+    }
+
+    fn __richcmp__(&self, py: Python, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let Ok(other) = other.downcast::<Self>() else {
+            return Ok(py.NotImplemented());
+        };
+        let other_value = other.get().value.bind(py);
+        match op {
+            CompareOp::Eq => self.value.bind(py).eq(other_value)?.into_py_any(py),
+            CompareOp::Ne => self.value.bind(py).ne(other_value)?.into_py_any(py),
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+
+    fn __hash__(&self, py: Python) -> PyResult<isize> {
+        self.value.bind(py).hash()
+    }
+
+    #[pyo3(signature = (_memo, /))]
+    pub fn __deepcopy__(&self, py: Python, _memo: Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        Self::new(self.value.clone_ref(py)).into_py_any(py)
+    }
+
+    fn __getnewargs__(&self, py: Python) -> (PyObject,) {
+        (self.value.clone_ref(py),)
+    }
+}
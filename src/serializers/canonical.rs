@@ -0,0 +1,123 @@
+//! Canonical JSON output as defined by [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785) (JCS),
+//! see the `canonical` argument of `to_json`.
+//!
+//! Numbers are written by `CanonicalFormatter` as the serializer runs, members of objects are
+//! sorted by the UTF-16 code units of their keys by the dict and fields serializers as they're written.
+//! String escaping in `PythonSerializer` already matches the spec.
+use std::cmp::Ordering;
+use std::io;
+
+use serde_json::ser::{CompactFormatter, Formatter};
+
+/// Compares keys as RFC 8785 requires, by UTF-16 code units rather than by code points
+pub(crate) fn compare_utf16(a: &str, b: &str) -> Ordering {
+    a.encode_utf16().cmp(b.encode_utf16())
+}
+
+/// A compact formatter which writes numbers as ECMAScript's `Number.prototype.toString()` does,
+/// integers which can't be represented exactly as an IEEE 754 double, `NaN` and `Infinity` are errors
+pub(crate) struct CanonicalFormatter;
+
+macro_rules! write_integer {
+    ($($method:ident: $t:ty,)*) => {
+        $(
+            fn $method<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: $t) -> io::Result<()> {
+                write_integer_str(writer, &value.to_string())
+            }
+        )*
+    };
+}
+
+impl Formatter for CanonicalFormatter {
+    write_integer! {
+        write_i64: i64,
+        write_i128: i128,
+        write_u64: u64,
+        write_u128: u128,
+    }
+
+    fn write_f32<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: f32) -> io::Result<()> {
+        write_es_number(writer, f64::from(value))
+    }
+
+    fn write_f64<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: f64) -> io::Result<()> {
+        write_es_number(writer, value)
+    }
+
+    fn write_string_fragment<W: ?Sized + io::Write>(&mut self, writer: &mut W, fragment: &str) -> io::Result<()> {
+        // other escapes are written with `write_char_escape`, so a fragment can only contain a backslash
+        // if it's a lone surrogate escape written with `ser_json_invalid_utf8='surrogatepass'`
+        if fragment.contains('\\') {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "lone surrogates are not permitted in canonical JSON",
+            ))
+        } else {
+            CompactFormatter.write_string_fragment(writer, fragment)
+        }
+    }
+
+    fn write_number_str<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: &str) -> io::Result<()> {
+        match value.parse::<f64>() {
+            Ok(_) if value.bytes().all(|b| b.is_ascii_digit() || b == b'-') => write_integer_str(writer, value),
+            Ok(number) if number.is_finite() => write_es_number(writer, number),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{value} is not permitted in canonical JSON, consider setting `inf_nan_mode`"),
+            )),
+        }
+    }
+}
+
+fn write_integer_str<W: ?Sized + io::Write>(writer: &mut W, value: &str) -> io::Result<()> {
+    let number: f64 = value.parse().map_err(|_| invalid_number(value))?;
+    if format!("{number:.0}") == value {
+        write_es_number(writer, number)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{value} cannot be represented exactly in canonical JSON, integers are limited to IEEE 754 double precision"),
+        ))
+    }
+}
+
+/// Write a finite number as ECMAScript's `Number.prototype.toString()` would, see section 3.2.2.3 of RFC 8785
+fn write_es_number<W: ?Sized + io::Write>(writer: &mut W, value: f64) -> io::Result<()> {
+    if value == 0.0 {
+        // covers `-0`
+        return CompactFormatter.write_number_str(writer, "0");
+    }
+    // rust's `{:e}` gives the shortest representation which round trips, e.g. `-1.2345e-7`
+    let sci = format!("{value:e}");
+    let (mantissa, exponent) = sci.split_once('e').ok_or_else(|| invalid_number(&sci))?;
+    let exponent: i32 = exponent.parse().map_err(|_| invalid_number(&sci))?;
+    let (sign, mantissa) = match mantissa.strip_prefix('-') {
+        Some(mantissa) => ("-", mantissa),
+        None => ("", mantissa),
+    };
+    let digits = mantissa.replace('.', "");
+    // `digits` x 10^(n - k) is the value, using the names from the ECMAScript spec
+    let k = i32::try_from(digits.len()).map_err(|_| invalid_number(&sci))?;
+    let n = exponent + 1;
+
+    let number = if k <= n && n <= 21 {
+        format!("{sign}{digits}{}", "0".repeat((n - k).unsigned_abs() as usize))
+    } else if 0 < n && n <= 21 {
+        let (int, frac) = digits.split_at(n.unsigned_abs() as usize);
+        format!("{sign}{int}.{frac}")
+    } else if -6 < n && n <= 0 {
+        format!("{sign}0.{}{digits}", "0".repeat(n.unsigned_abs() as usize))
+    } else {
+        let exp_sign = if n - 1 < 0 { '-' } else { '+' };
+        let exp = (n - 1).unsigned_abs();
+        match digits.split_at(1) {
+            (first, "") => format!("{sign}{first}e{exp_sign}{exp}"),
+            (first, rest) => format!("{sign}{first}.{rest}e{exp_sign}{exp}"),
+        }
+    };
+    CompactFormatter.write_number_str(writer, &number)
+}
+
+fn invalid_number(value: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("invalid number {value}"))
+}
@@ -1,17 +1,16 @@
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
 use pyo3::{intern, PyTraverseError, PyVisit};
-use serde::ser::SerializeMap;
-use serde::Serialize;
 
-use crate::build_tools::py_schema_error_type;
+use crate::build_tools::{py_schema_error_type, AliasGenerator};
 use crate::definitions::DefinitionsBuilder;
 use crate::py_gc::PyGcTraverse;
 use crate::serializers::filter::SchemaFilter;
-use crate::serializers::shared::{BuildSerializer, CombinedSerializer, PydanticSerializer, TypeSerializer};
+use crate::serializers::shared::{BuildSerializer, CombinedSerializer, TypeSerializer};
 use crate::tools::SchemaDict;
 
 use super::errors::py_err_se_err;
+use super::ordering::FilteredValue;
 use super::Extra;
 
 #[derive(Debug)]
@@ -39,6 +38,11 @@ impl ComputedFields {
         self.0.len()
     }
 
+    /// Sort by property name, for `field_order='alphabetical'`
+    pub fn sort_alphabetically(&mut self) {
+        self.0.sort_by(|a, b| a.property_name.cmp(&b.property_name));
+    }
+
     pub fn to_python(
         &self,
         model: &Bound<'_, PyAny>,
@@ -62,15 +66,16 @@ impl ComputedFields {
         Ok(())
     }
 
-    pub fn serde_serialize<S: serde::ser::Serializer>(
-        &self,
-        model: &Bound<'_, PyAny>,
-        map: &mut S::SerializeMap,
+    /// Passes the key, property name, serializer and value of each computed field of `model` to `add_member`
+    pub fn serde_serialize<'a, 'py, E: serde::ser::Error>(
+        &'a self,
+        model: &Bound<'py, PyAny>,
         filter: &SchemaFilter<isize>,
-        include: Option<&Bound<'_, PyAny>>,
-        exclude: Option<&Bound<'_, PyAny>>,
+        include: Option<&Bound<'py, PyAny>>,
+        exclude: Option<&Bound<'py, PyAny>>,
         extra: &Extra,
-    ) -> Result<(), S::Error> {
+        mut add_member: impl FnMut(&'a str, &'a str, &'a CombinedSerializer, FilteredValue<'py>) -> Result<(), E>,
+    ) -> Result<(), E> {
         if extra.round_trip {
             // Do not serialize computed fields
             return Ok(());
@@ -87,22 +92,16 @@ impl ComputedFields {
                 if extra.exclude_none && value.is_none() {
                     continue;
                 }
-                let field_extra = Extra {
-                    field_name: Some(computed_field.property_name.as_str()),
-                    ..*extra
-                };
-                let cfs = ComputedFieldSerializer {
-                    model,
-                    computed_field,
-                    include: next_include.as_ref(),
-                    exclude: next_exclude.as_ref(),
-                    extra: &field_extra,
-                };
                 let key = match extra.serialize_by_alias_or(computed_field.serialize_by_alias) {
                     true => computed_field.alias.as_str(),
                     false => computed_field.property_name.as_str(),
                 };
-                map.serialize_entry(key, &cfs)?;
+                add_member(
+                    key,
+                    computed_field.property_name.as_str(),
+                    &computed_field.serializer,
+                    (value, next_include, next_exclude),
+                )?;
             }
         }
         Ok(())
@@ -131,9 +130,13 @@ impl ComputedField {
         let return_schema = schema.get_as_req(intern!(py, "return_schema"))?;
         let serializer = CombinedSerializer::build(&return_schema, config, definitions)
             .map_err(|e| py_schema_error_type!("Computed field `{}`:\n  {}", property_name, e))?;
-        let alias_py = schema
-            .get_as(intern!(py, "alias"))?
-            .unwrap_or_else(|| property_name.clone());
+        let alias_py = match schema.get_as(intern!(py, "alias"))? {
+            Some(alias) => alias,
+            None => match AliasGenerator::serialization(config)? {
+                Some(generator) => PyString::new(py, &generator.generate(property_name.to_str()?)),
+                None => property_name.clone(),
+            },
+        };
         Ok(Self {
             property_name: property_name.extract()?,
             property_name_py: property_name.into(),
@@ -175,14 +178,6 @@ impl ComputedField {
     }
 }
 
-pub(crate) struct ComputedFieldSerializer<'py> {
-    model: &'py Bound<'py, PyAny>,
-    computed_field: &'py ComputedField,
-    include: Option<&'py Bound<'py, PyAny>>,
-    exclude: Option<&'py Bound<'py, PyAny>>,
-    extra: &'py Extra<'py>,
-}
-
 impl_py_gc_traverse!(ComputedField { serializer });
 
 impl PyGcTraverse for ComputedFields {
@@ -190,21 +185,3 @@ impl PyGcTraverse for ComputedFields {
         self.0.py_gc_traverse(visit)
     }
 }
-
-impl_py_gc_traverse!(ComputedFieldSerializer<'_> { computed_field });
-
-impl Serialize for ComputedFieldSerializer<'_> {
-    fn serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let py = self.model.py();
-        let property_name_py = self.computed_field.property_name_py.bind(py);
-        let next_value = self.model.getattr(property_name_py).map_err(py_err_se_err)?;
-        let s = PydanticSerializer::new(
-            &next_value,
-            &self.computed_field.serializer,
-            self.include,
-            self.exclude,
-            self.extra,
-        );
-        s.serialize(serializer)
-    }
-}
@@ -3,16 +3,20 @@ use std::str::{from_utf8, FromStr, Utf8Error};
 
 use base64::Engine;
 use pyo3::prelude::*;
-use pyo3::types::{PyDelta, PyDict, PyString};
+use pyo3::types::{PyBool, PyDelta, PyDict, PyString};
 use pyo3::{intern, IntoPyObjectExt};
 
 use serde::ser::Error;
+use serde::Serialize;
+use speedate::{Date, DateTime, Duration, Time};
 
 use crate::build_tools::py_schema_err;
-use crate::input::EitherTimedelta;
+use crate::input::{check_format, days_from_civil, format_offset, strftime, EitherTimedelta, Int};
 use crate::tools::SchemaDict;
 
-use super::errors::py_err_se_err;
+use super::errors::{py_err_se_err, SERIALIZATION_ERR_MARKER};
+use super::extra::SerMode;
+use crate::PydanticSerializationError;
 
 #[derive(Debug, Clone)]
 #[allow(clippy::struct_field_names)]
@@ -20,6 +24,14 @@ pub(crate) struct SerializationConfig {
     pub timedelta_mode: TimedeltaMode,
     pub bytes_mode: BytesMode,
     pub inf_nan_mode: InfNanMode,
+    pub temporal_format: TemporalFormat,
+    pub uuid_mode: UuidMode,
+    pub float_format: FloatFormat,
+    pub bigint_mode: BigIntMode,
+    pub invalid_utf8_mode: InvalidUtf8Mode,
+    pub enum_mode: EnumMode,
+    /// `serialize_by_alias` from the serializer's config, used for fields which don't set it in their own config
+    pub serialize_by_alias: Option<bool>,
 }
 
 impl SerializationConfig {
@@ -27,18 +39,47 @@ impl SerializationConfig {
         let timedelta_mode = TimedeltaMode::from_config(config)?;
         let bytes_mode = BytesMode::from_config(config)?;
         let inf_nan_mode = InfNanMode::from_config(config)?;
+        let temporal_format = TemporalFormat::from_config(config)?;
+        let uuid_mode = UuidMode::from_config(config)?;
+        let float_format = FloatFormat::from_config(config)?;
+        let bigint_mode = BigIntMode::from_config(config)?;
+        let invalid_utf8_mode = InvalidUtf8Mode::from_config(config)?;
+        let enum_mode = EnumMode::from_config(config)?;
+        let serialize_by_alias = match config {
+            Some(config) => config.get_as(intern!(config.py(), "serialize_by_alias"))?,
+            None => None,
+        };
         Ok(Self {
             timedelta_mode,
             bytes_mode,
             inf_nan_mode,
+            temporal_format,
+            uuid_mode,
+            float_format,
+            bigint_mode,
+            invalid_utf8_mode,
+            enum_mode,
+            serialize_by_alias,
         })
     }
 
-    pub fn from_args(timedelta_mode: &str, bytes_mode: &str, inf_nan_mode: &str) -> PyResult<Self> {
+    pub fn from_args(
+        timedelta_mode: &str,
+        bytes_mode: &str,
+        inf_nan_mode: &str,
+        temporal_format: &str,
+    ) -> PyResult<Self> {
         Ok(Self {
             timedelta_mode: TimedeltaMode::from_str(timedelta_mode)?,
             bytes_mode: BytesMode::from_str(bytes_mode)?,
             inf_nan_mode: InfNanMode::from_str(inf_nan_mode)?,
+            temporal_format: TemporalFormat::from_str(temporal_format)?,
+            uuid_mode: UuidMode::default(),
+            float_format: FloatFormat::default(),
+            bigint_mode: BigIntMode::default(),
+            invalid_utf8_mode: InvalidUtf8Mode::default(),
+            enum_mode: EnumMode::default(),
+            serialize_by_alias: None,
         })
     }
 }
@@ -89,6 +130,9 @@ serialization_mode! {
     "ser_json_timedelta",
     Iso8601 => "iso8601",
     Float => "float",
+    MillisecondsInt => "milliseconds_int",
+    Clock => "hh:mm:ss",
+    Human => "human",
 }
 
 serialization_mode! {
@@ -96,15 +140,126 @@ serialization_mode! {
     "ser_json_bytes",
     Utf8 => "utf8",
     Base64 => "base64",
+    Base64Url => "base64url",
+    Base32 => "base32",
     Hex => "hex",
 }
 
+serialization_mode! {
+    UuidMode,
+    "ser_json_uuid",
+    Str => "str",
+    Int => "int",
+}
+
+serialization_mode! {
+    BigIntMode,
+    "ser_json_bigint",
+    Int => "int",
+    String => "string",
+    Error => "error",
+}
+
 serialization_mode! {
     InfNanMode,
     "ser_json_inf_nan",
     Null => "null",
     Constants => "constants",
     Strings => "strings",
+    Error => "error",
+}
+
+impl InfNanMode {
+    /// Error message for `InfNanMode::Error`
+    pub fn non_finite_error(value: f64) -> String {
+        let value = if value.is_nan() {
+            "nan"
+        } else if value.is_sign_positive() {
+            "inf"
+        } else {
+            "-inf"
+        };
+        format!(
+            "Non-finite float {value} is not permitted, set `ser_json_inf_nan` to 'null', 'constants' or 'strings' \
+             to serialize it"
+        )
+    }
+}
+
+serialization_mode! {
+    InvalidUtf8Mode,
+    "ser_json_invalid_utf8",
+    Error => "error",
+    Replace => "replace",
+    SurrogatePass => "surrogatepass",
+}
+
+serialization_mode! {
+    EnumMode,
+    "ser_enum",
+    Object => "object",
+    Value => "value",
+    Name => "name",
+}
+
+impl EnumMode {
+    /// Whether enum members are replaced when serializing to python, in JSON mode they always are
+    pub fn replaces_member(self, mode: &SerMode) -> bool {
+        mode.is_json() || self != Self::Object
+    }
+
+    /// The attribute of an enum member which is serialized in its place
+    pub fn attribute(self, py: Python<'_>) -> &Bound<'_, PyString> {
+        match self {
+            Self::Name => intern!(py, "name"),
+            Self::Object | Self::Value => intern!(py, "value"),
+        }
+    }
+}
+
+/// `Number.MAX_SAFE_INTEGER`, the largest integer JavaScript can represent exactly
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+impl BigIntMode {
+    fn is_safe(int: &Int) -> bool {
+        match int {
+            Int::I64(i) => i.unsigned_abs() <= MAX_SAFE_INTEGER,
+            // `Int::Big` is only used for values outside the `i64` range
+            Int::Big(_) => false,
+        }
+    }
+
+    fn unsafe_error(int: &Int) -> String {
+        format!(
+            "Integer {int} is outside the range JavaScript can represent exactly (±{MAX_SAFE_INTEGER}), \
+             set `ser_json_bigint` to 'string' to serialize it as a string"
+        )
+    }
+
+    /// An `int` in JSON compatible python output, `value` is used unchanged if it's in the safe range
+    pub fn to_python(self, value: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let py = value.py();
+        if self == Self::Int {
+            return Ok(value.clone().unbind());
+        }
+        let int: Int = value.extract()?;
+        match self {
+            _ if Self::is_safe(&int) => Ok(value.clone().unbind()),
+            Self::String => int.to_string().into_py_any(py),
+            _ => Err(PydanticSerializationError::new_err(Self::unsafe_error(&int))),
+        }
+    }
+
+    pub fn serialize_int<S: serde::ser::Serializer>(self, int: &Int, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            _ if self == Self::Int || Self::is_safe(int) => int.serialize(serializer),
+            Self::String => serializer.serialize_str(&int.to_string()),
+            _ => Err(S::Error::custom(format!(
+                "{SERIALIZATION_ERR_MARKER}{}",
+                Self::unsafe_error(int)
+            ))),
+        }
+    }
 }
 
 impl TimedeltaMode {
@@ -125,6 +280,9 @@ impl TimedeltaMode {
                 let seconds = Self::total_seconds(&py_timedelta)?;
                 Ok(seconds.unbind())
             }
+            Self::MillisecondsInt => total_milliseconds(&either_delta.to_duration()?).into_py_any(py),
+            Self::Clock => clock_string(&either_delta.to_duration()?).into_py_any(py),
+            Self::Human => human_string(&either_delta.to_duration()?).into_py_any(py),
         }
     }
 
@@ -139,6 +297,9 @@ impl TimedeltaMode {
                 let seconds: f64 = Self::total_seconds(&py_timedelta)?.extract()?;
                 Ok(seconds.to_string().into())
             }
+            Self::MillisecondsInt => Ok(total_milliseconds(&either_delta.to_duration()?).to_string().into()),
+            Self::Clock => Ok(clock_string(&either_delta.to_duration()?).into()),
+            Self::Human => Ok(human_string(&either_delta.to_duration()?).into()),
         }
     }
 
@@ -159,10 +320,281 @@ impl TimedeltaMode {
                 let seconds: f64 = seconds.extract().map_err(py_err_se_err)?;
                 serializer.serialize_f64(seconds)
             }
+            Self::MillisecondsInt => {
+                let d = either_delta.to_duration().map_err(py_err_se_err)?;
+                serializer.serialize_i64(total_milliseconds(&d))
+            }
+            Self::Clock => {
+                let d = either_delta.to_duration().map_err(py_err_se_err)?;
+                serializer.serialize_str(&clock_string(&d))
+            }
+            Self::Human => {
+                let d = either_delta.to_duration().map_err(py_err_se_err)?;
+                serializer.serialize_str(&human_string(&d))
+            }
+        }
+    }
+}
+
+/// Whole milliseconds, rounded down as `timedelta // timedelta(milliseconds=1)` would be
+fn total_milliseconds(d: &Duration) -> i64 {
+    let micros = (i64::from(d.day) * 86_400 + i64::from(d.second)) * 1_000_000 + i64::from(d.microsecond);
+    let micros = if d.positive { micros } else { -micros };
+    micros.div_euclid(1_000)
+}
+
+fn sign(d: &Duration) -> &'static str {
+    if d.positive {
+        ""
+    } else {
+        "-"
+    }
+}
+
+/// `[-]HH:MM:SS[.ffffff]`, hours aren't wrapped at a day so `timedelta(days=1, hours=2)` is `26:00:00`
+fn clock_string(d: &Duration) -> String {
+    let hours = u64::from(d.day) * 24 + u64::from(d.second / 3600);
+    let (minutes, seconds) = (d.second / 60 % 60, d.second % 60);
+    let clock = format!("{}{hours:02}:{minutes:02}:{seconds:02}", sign(d));
+    if d.microsecond == 0 {
+        clock
+    } else {
+        format!("{clock}.{:06}", d.microsecond)
+    }
+}
+
+/// e.g. `1d 2h 3m 4.5s`, leaving out zero components
+fn human_string(d: &Duration) -> String {
+    let mut parts = Vec::new();
+    if d.day != 0 {
+        parts.push(format!("{}d", d.day));
+    }
+    let (hours, minutes, seconds) = (d.second / 3600, d.second / 60 % 60, d.second % 60);
+    if hours != 0 {
+        parts.push(format!("{hours}h"));
+    }
+    if minutes != 0 {
+        parts.push(format!("{minutes}m"));
+    }
+    if d.microsecond != 0 {
+        let fraction = format!("{:06}", d.microsecond);
+        parts.push(format!("{seconds}.{}s", fraction.trim_end_matches('0')));
+    } else if seconds != 0 || parts.is_empty() {
+        parts.push(format!("{seconds}s"));
+    }
+    format!("{}{}", sign(d), parts.join(" "))
+}
+
+/// How `datetime`, `date` and `time` values are serialized to JSON, from `ser_json_temporal_format`: one of the
+/// presets or a strftime style pattern.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub enum TemporalFormat {
+    #[default]
+    Iso8601,
+    Iso8601Basic,
+    Rfc2822,
+    EpochMillis,
+    Pattern(String),
+}
+
+impl FromStr for TemporalFormat {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "iso8601" => Ok(Self::Iso8601),
+            "iso8601_basic" => Ok(Self::Iso8601Basic),
+            "rfc2822" => Ok(Self::Rfc2822),
+            "epoch_millis" => Ok(Self::EpochMillis),
+            s if s.contains('%') => {
+                check_format(s)?;
+                Ok(Self::Pattern(s.to_string()))
+            }
+            s => py_schema_err!(
+                "Invalid TemporalFormat serialization mode: `{}`, expected iso8601 or iso8601_basic or rfc2822 or \
+                 epoch_millis or a strftime style pattern",
+                s
+            ),
         }
     }
 }
 
+impl FromConfig for TemporalFormat {
+    fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let Some(config_dict) = config else {
+            return Ok(Self::default());
+        };
+        let raw_mode =
+            config_dict.get_as::<Bound<'_, PyString>>(intern!(config_dict.py(), "ser_json_temporal_format"))?;
+        raw_mode.map_or_else(|| Ok(Self::default()), |raw| Self::from_str(raw.to_str()?))
+    }
+}
+
+/// How finite floats are serialized to JSON, from `ser_json_float`: `'repr'` (the shortest representation which
+/// round trips), `'scientific'` or a number of decimal places to round to.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatFormat {
+    #[default]
+    Repr,
+    Scientific,
+    Round(usize),
+}
+
+impl FloatFormat {
+    pub fn from_py(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(places) = value.extract::<usize>() {
+            if !value.is_instance_of::<PyBool>() {
+                return Ok(Self::Round(places));
+            }
+        }
+        match value.downcast::<PyString>().map(ToString::to_string).as_deref() {
+            Ok("repr") => Ok(Self::Repr),
+            Ok("scientific") => Ok(Self::Scientific),
+            _ => py_schema_err!(
+                "Invalid FloatFormat serialization mode: `{}`, expected repr or scientific or a number of decimal places",
+                value
+            ),
+        }
+    }
+
+    /// Round `value` if a number of decimal places is set, values which aren't finite are unchanged
+    pub fn round(self, value: f64) -> f64 {
+        match self {
+            Self::Round(places) if value.is_finite() => format!("{value:.places$}").parse().unwrap_or(value),
+            _ => value,
+        }
+    }
+
+    /// Serialize a finite float
+    pub fn serialize_f64<S: serde::ser::Serializer>(self, value: f64, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Repr => serializer.serialize_f64(value),
+            Self::Round(_) => serializer.serialize_f64(self.round(value)),
+            Self::Scientific => {
+                let number = serde_json::Number::from_str(&format!("{value:e}")).map_err(S::Error::custom)?;
+                number.serialize(serializer)
+            }
+        }
+    }
+}
+
+impl FromConfig for FloatFormat {
+    fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let Some(config_dict) = config else {
+            return Ok(Self::default());
+        };
+        let raw_mode = config_dict.get_as::<Bound<'_, PyAny>>(intern!(config_dict.py(), "ser_json_float"))?;
+        raw_mode.map_or_else(|| Ok(Self::default()), |raw| Self::from_py(&raw))
+    }
+}
+
+/// A `datetime`, `date` or `time` value to be serialized
+pub(crate) enum Temporal {
+    DateTime(DateTime),
+    Date(Date),
+    Time(Time),
+}
+
+/// A serialized temporal value, only `epoch_millis` gives a number
+pub(crate) enum FormattedTemporal {
+    Str(String),
+    Int(i64),
+}
+
+impl FormattedTemporal {
+    pub fn into_py_any(self, py: Python) -> PyResult<PyObject> {
+        match self {
+            Self::Str(s) => s.into_py_any(py),
+            Self::Int(i) => i.into_py_any(py),
+        }
+    }
+
+    pub fn into_string(self) -> String {
+        match self {
+            Self::Str(s) => s,
+            Self::Int(i) => i.to_string(),
+        }
+    }
+
+    pub fn serialize<S: serde::ser::Serializer>(self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Str(s) => serializer.serialize_str(&s),
+            Self::Int(i) => serializer.serialize_i64(i),
+        }
+    }
+}
+
+impl TemporalFormat {
+    pub(crate) fn format(&self, value: Temporal) -> FormattedTemporal {
+        // python's defaults for the parts of a value which are missing
+        let no_date = Date {
+            year: 1900,
+            month: 1,
+            day: 1,
+        };
+        let no_time = Time {
+            hour: 0,
+            minute: 0,
+            second: 0,
+            microsecond: 0,
+            tz_offset: None,
+        };
+        let (date, time) = match &value {
+            Temporal::DateTime(dt) => (&dt.date, &dt.time),
+            Temporal::Date(date) => (date, &no_time),
+            Temporal::Time(time) => (&no_date, time),
+        };
+        let pattern = match self {
+            Self::Iso8601 => {
+                return FormattedTemporal::Str(match value {
+                    Temporal::DateTime(dt) => dt.to_string(),
+                    Temporal::Date(date) => date.to_string(),
+                    Temporal::Time(time) => time.to_string(),
+                })
+            }
+            Self::EpochMillis => {
+                let day_millis = match value {
+                    Temporal::Time(_) => 0,
+                    _ => days_from_civil(date) * 86_400_000,
+                };
+                let time_millis = i64::from(time.hour) * 3_600_000
+                    + i64::from(time.minute) * 60_000
+                    + i64::from(time.second) * 1_000
+                    + i64::from(time.microsecond / 1_000)
+                    - i64::from(time.tz_offset.unwrap_or(0)) * 1_000;
+                return FormattedTemporal::Int(day_millis + time_millis);
+            }
+            Self::Pattern(pattern) => return FormattedTemporal::Str(strftime(pattern, date, time)),
+            Self::Iso8601Basic => match value {
+                Temporal::Date(_) => "%Y%m%d",
+                Temporal::DateTime(_) if time.microsecond == 0 => "%Y%m%dT%H%M%S",
+                Temporal::DateTime(_) => "%Y%m%dT%H%M%S.%f",
+                Temporal::Time(_) if time.microsecond == 0 => "%H%M%S",
+                Temporal::Time(_) => "%H%M%S.%f",
+            },
+            Self::Rfc2822 => match value {
+                Temporal::Date(_) => "%a, %d %b %Y",
+                Temporal::DateTime(_) => "%a, %d %b %Y %H:%M:%S",
+                Temporal::Time(_) => "%H:%M:%S",
+            },
+        };
+        let mut formatted = strftime(pattern, date, time);
+        match (self, time.tz_offset) {
+            (_, _) if matches!(value, Temporal::Date(_)) => (),
+            (Self::Iso8601Basic, Some(0)) => formatted.push('Z'),
+            (Self::Iso8601Basic, Some(offset)) => formatted.push_str(&format_offset(offset)),
+            (Self::Rfc2822, Some(offset)) => {
+                formatted.push(' ');
+                formatted.push_str(&format_offset(offset));
+            }
+            // RFC 2822 uses `-0000` for a time with no information about the local offset
+            (Self::Rfc2822, None) if matches!(value, Temporal::DateTime(_)) => formatted.push_str(" -0000"),
+            _ => (),
+        }
+        FormattedTemporal::Str(formatted)
+    }
+}
+
 impl BytesMode {
     pub fn bytes_to_string<'a>(self, py: Python, bytes: &'a [u8]) -> PyResult<Cow<'a, str>> {
         match self {
@@ -170,6 +602,10 @@ impl BytesMode {
                 .map_err(|err| utf8_py_error(py, err, bytes))
                 .map(Cow::Borrowed),
             Self::Base64 => Ok(Cow::Owned(base64::engine::general_purpose::URL_SAFE.encode(bytes))),
+            Self::Base64Url => Ok(Cow::Owned(
+                base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes),
+            )),
+            Self::Base32 => Ok(Cow::Owned(base32_encode(bytes))),
             Self::Hex => Ok(Cow::Owned(
                 bytes.iter().fold(String::new(), |acc, b| acc + &format!("{b:02x}")),
             )),
@@ -183,11 +619,66 @@ impl BytesMode {
                 Err(e) => Err(Error::custom(e.to_string())),
             },
             Self::Base64 => serializer.serialize_str(&base64::engine::general_purpose::URL_SAFE.encode(bytes)),
+            Self::Base64Url => {
+                serializer.serialize_str(&base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes))
+            }
+            Self::Base32 => serializer.serialize_str(&base32_encode(bytes)),
             Self::Hex => serializer.serialize_str(hex::encode(bytes).as_str()),
         }
     }
 }
 
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Encode `bytes` with the RFC 4648 base32 alphabet, padded with `=` to a multiple of 8 characters like
+/// python's `base64.b32encode`
+fn base32_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(5) * 8);
+    for chunk in bytes.chunks(5) {
+        let mut buffer = [0u8; 5];
+        buffer[..chunk.len()].copy_from_slice(chunk);
+        let bits = buffer.iter().fold(0u64, |acc, &b| (acc << 8) | u64::from(b));
+        // each 5 byte chunk is 8 characters, a partial chunk only needs enough characters to cover its bits
+        let chars = (chunk.len() * 8).div_ceil(5);
+        for i in 0..8 {
+            if i < chars {
+                let index = (bits >> (35 - i * 5)) & 0x1f;
+                encoded.push(BASE32_ALPHABET[index as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Decode RFC 4648 base32, lowercase characters are accepted and padding is optional
+pub(crate) fn base32_decode(s: &str) -> Result<Vec<u8>, String> {
+    let data = s.trim_end_matches('=');
+    let mut decoded = Vec::with_capacity(data.len() * 5 / 8);
+    let mut bits: u64 = 0;
+    let mut bit_count = 0;
+    for (offset, c) in data.bytes().enumerate() {
+        let value = match c.to_ascii_uppercase() {
+            c @ b'A'..=b'Z' => c - b'A',
+            c @ b'2'..=b'7' => c - b'2' + 26,
+            _ => return Err(format!("Invalid symbol {c}, offset {offset}.")),
+        };
+        bits = (bits << 5) | u64::from(value);
+        bit_count += 5;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            decoded.push((bits >> bit_count) as u8);
+            bits &= (1 << bit_count) - 1;
+        }
+    }
+    // only 2, 4, 5 or 7 trailing characters can be produced by encoding
+    if matches!(data.len() % 8, 1 | 3 | 6) || bits != 0 {
+        return Err("Invalid input length or trailing bits.".to_string());
+    }
+    Ok(decoded)
+}
+
 pub fn utf8_py_error(py: Python, err: Utf8Error, data: &[u8]) -> PyErr {
     match pyo3::exceptions::PyUnicodeDecodeError::new_utf8(py, data, err) {
         Ok(decode_err) => PyErr::from_value(decode_err.into_any()),
@@ -200,3 +691,9 @@ impl FromPyObject<'_> for InfNanMode {
         Self::from_str(ob.downcast::<PyString>()?.to_str()?)
     }
 }
+
+impl FromPyObject<'_> for EnumMode {
+    fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Self::from_str(ob.downcast::<PyString>()?.to_str()?)
+    }
+}
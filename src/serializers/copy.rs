@@ -0,0 +1,265 @@
+//! Copying of validated values without going through `copy.deepcopy`, see `copy_validated`.
+//!
+//! Values are classified with `ObTypeLookup` as they are for inference: immutable builtin values are shared,
+//! builtin containers and models are rebuilt here, and anything else falls back to `copy.deepcopy` with the same
+//! memo, so objects referenced more than once are still only copied once. Models whose class overrides
+//! `__deepcopy__` (or `__copy__` for shallow copies) are also left to `copy`.
+use pyo3::exceptions::PyRecursionError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyFrozenSet, PyList, PySet, PyString, PyTuple, PyType};
+
+use crate::validators::{create_class, force_setattr};
+use crate::PydanticUndefinedType;
+
+use super::ob_type::{IsType, ObType, ObTypeLookup};
+
+static COPY_COPY: GILOnceCell<PyObject> = GILOnceCell::new();
+static COPY_DEEPCOPY: GILOnceCell<PyObject> = GILOnceCell::new();
+
+/// How deeply values can be nested, the default of `sys.getrecursionlimit()` which bounds `copy.deepcopy`
+const COPY_RECURSION_LIMIT: u16 = 1000;
+
+fn copy_function<'py>(
+    py: Python<'py>,
+    cell: &'static GILOnceCell<PyObject>,
+    name: &str,
+) -> PyResult<&'py Bound<'py, PyAny>> {
+    let function = cell.get_or_try_init(py, || Ok::<_, PyErr>(py.import("copy")?.getattr(name)?.unbind()))?;
+    Ok(function.bind(py))
+}
+
+pub(super) struct Copier<'py> {
+    lookup: &'py ObTypeLookup,
+    deep: bool,
+    /// maps the `id()` of each original to its copy, shared with `copy.deepcopy`
+    memo: Bound<'py, PyDict>,
+    depth: u16,
+}
+
+impl<'py> Copier<'py> {
+    pub fn new(py: Python<'py>, deep: bool) -> Self {
+        Self {
+            lookup: ObTypeLookup::cached(py),
+            deep,
+            memo: PyDict::new(py),
+            depth: 0,
+        }
+    }
+
+    pub fn copy(&mut self, value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let py = value.py();
+        if !self.deep {
+            return match model_fields_set(value, intern!(py, "__copy__"))? {
+                Some(fields_set) => self.copy_model(value, &fields_set),
+                None => copy_function(py, &COPY_COPY, "copy")?.call1((value,)),
+            };
+        }
+
+        let ob_type = self.lookup.get_type(value);
+        match ob_type {
+            // immutable, so as with `copy.deepcopy` the value itself is its copy
+            ObType::None
+            | ObType::Int
+            | ObType::Bool
+            | ObType::Float
+            | ObType::Str
+            | ObType::Bytes
+            | ObType::Decimal
+            | ObType::Datetime
+            | ObType::Date
+            | ObType::Time
+            | ObType::Timedelta
+            | ObType::Url
+            | ObType::MultiHostUrl
+            | ObType::Email
+            | ObType::Uuid
+            | ObType::Complex
+                if self.is_exact(value, ob_type) =>
+            {
+                return Ok(value.clone());
+            }
+            // members are singletons, `Enum.__deepcopy__` returns the member itself
+            ObType::Enum => return Ok(value.clone()),
+            _ => (),
+        }
+
+        let id = value.as_ptr() as usize;
+        if let Some(copied) = self.memo.get_item(id)? {
+            return Ok(copied);
+        }
+        if self.depth >= COPY_RECURSION_LIMIT {
+            return Err(PyRecursionError::new_err(
+                "Maximum recursion depth exceeded while copying",
+            ));
+        }
+        self.depth += 1;
+        let result = self.copy_deep(value, ob_type, id);
+        self.depth -= 1;
+        result
+    }
+
+    fn copy_deep(&mut self, value: &Bound<'py, PyAny>, ob_type: ObType, id: usize) -> PyResult<Bound<'py, PyAny>> {
+        let py = value.py();
+        match ob_type {
+            ObType::List if self.is_exact(value, ob_type) => {
+                let copied = PyList::empty(py);
+                self.memo.set_item(id, &copied)?;
+                for item in value.downcast::<PyList>()? {
+                    copied.append(self.copy(&item)?)?;
+                }
+                Ok(copied.into_any())
+            }
+            ObType::Dict if self.is_exact(value, ob_type) => {
+                let copied = PyDict::new(py);
+                self.memo.set_item(id, &copied)?;
+                for (key, item) in value.downcast::<PyDict>()? {
+                    copied.set_item(self.copy(&key)?, self.copy(&item)?)?;
+                }
+                Ok(copied.into_any())
+            }
+            ObType::Set if self.is_exact(value, ob_type) => {
+                let copied = PySet::empty(py)?;
+                self.memo.set_item(id, &copied)?;
+                for item in value.downcast::<PySet>()? {
+                    copied.add(self.copy(&item)?)?;
+                }
+                Ok(copied.into_any())
+            }
+            ObType::Tuple if self.is_exact(value, ob_type) => {
+                let items = self.copy_items(value.downcast::<PyTuple>()?.iter())?;
+                self.finish_immutable(value, id, items, |items| Ok(PyTuple::new(py, items)?.into_any()))
+            }
+            ObType::Frozenset if self.is_exact(value, ob_type) => {
+                let items = self.copy_items(value.downcast::<PyFrozenSet>()?.iter())?;
+                self.finish_immutable(value, id, items, |items| Ok(PyFrozenSet::new(py, items)?.into_any()))
+            }
+            _ => match model_fields_set(value, intern!(py, "__deepcopy__"))? {
+                Some(fields_set) => self.copy_model(value, &fields_set),
+                None => copy_function(py, &COPY_DEEPCOPY, "deepcopy")?.call1((value, &self.memo)),
+            },
+        }
+    }
+
+    fn copy_items(
+        &mut self,
+        items: impl Iterator<Item = Bound<'py, PyAny>>,
+    ) -> PyResult<Vec<(Bound<'py, PyAny>, bool)>> {
+        items
+            .map(|item| {
+                let copied = self.copy(&item)?;
+                let unchanged = copied.is(&item);
+                Ok((copied, unchanged))
+            })
+            .collect()
+    }
+
+    /// Immutable containers are only rebuilt when one of their items was copied, as with `copy.deepcopy`;
+    /// an item may refer back to the container itself, in which case it's already in the memo
+    fn finish_immutable(
+        &self,
+        value: &Bound<'py, PyAny>,
+        id: usize,
+        items: Vec<(Bound<'py, PyAny>, bool)>,
+        build: impl FnOnce(Vec<Bound<'py, PyAny>>) -> PyResult<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if items.iter().all(|(_, unchanged)| *unchanged) {
+            return Ok(value.clone());
+        }
+        if let Some(copied) = self.memo.get_item(id)? {
+            return Ok(copied);
+        }
+        let copied = build(items.into_iter().map(|(item, _)| item).collect())?;
+        self.memo.set_item(id, &copied)?;
+        Ok(copied)
+    }
+
+    /// Copies a model as `BaseModel.__copy__` and `__deepcopy__` do, `__pydantic_fields_set__` is always
+    /// a new set and unset private attributes are left out
+    fn copy_model(&mut self, model: &Bound<'py, PyAny>, fields_set: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let py = model.py();
+        let copied = create_class(&model.get_type())?;
+        if self.deep {
+            self.memo.set_item(model.as_ptr() as usize, &copied)?;
+        }
+
+        let dict = model.getattr(intern!(py, "__dict__"))?;
+        force_setattr(py, &copied, intern!(py, "__dict__"), self.copy_dict(&dict)?)?;
+
+        let model_extra = model.getattr(intern!(py, "__pydantic_extra__"))?;
+        let model_extra = if model_extra.is_none() {
+            model_extra
+        } else {
+            self.copy_dict(&model_extra)?
+        };
+        force_setattr(py, &copied, intern!(py, "__pydantic_extra__"), model_extra)?;
+
+        let fields_set = fields_set.call_method0(intern!(py, "copy"))?;
+        force_setattr(py, &copied, intern!(py, "__pydantic_fields_set__"), fields_set)?;
+
+        let private = model.getattr(intern!(py, "__pydantic_private__"))?;
+        let private = match private.downcast::<PyDict>() {
+            Ok(private) => {
+                let undefined = PydanticUndefinedType::new(py);
+                let copied_private = PyDict::new(py);
+                for (key, item) in private {
+                    if item.is(&undefined) {
+                        continue;
+                    }
+                    let item = if self.deep { self.copy(&item)? } else { item };
+                    copied_private.set_item(key, item)?;
+                }
+                copied_private.into_any()
+            }
+            Err(_) => private,
+        };
+        force_setattr(py, &copied, intern!(py, "__pydantic_private__"), private)?;
+        Ok(copied)
+    }
+
+    fn copy_dict(&mut self, dict: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        if self.deep {
+            self.copy(dict)
+        } else {
+            Ok(dict.downcast::<PyDict>()?.copy()?.into_any())
+        }
+    }
+
+    fn is_exact(&self, value: &Bound<'_, PyAny>, ob_type: ObType) -> bool {
+        matches!(self.lookup.is_type(value, ob_type), IsType::Exact)
+    }
+}
+
+/// `__pydantic_fields_set__` of an instance of a model which is copied here, `None` for any other value
+/// or if the model's class overrides `copy_method`
+fn model_fields_set<'py>(
+    value: &Bound<'py, PyAny>,
+    copy_method: &Bound<'py, PyString>,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    if value.is_instance_of::<PyType>() {
+        return Ok(None);
+    }
+    let Some(fields_set) = value.getattr_opt(intern!(value.py(), "__pydantic_fields_set__"))? else {
+        return Ok(None);
+    };
+    match overrides_copy(&value.get_type(), copy_method)? {
+        true => Ok(None),
+        false => Ok(Some(fields_set)),
+    }
+}
+
+/// Whether `copy_method` is defined by a subclass of the class declaring `__pydantic_fields_set__`
+/// (e.g. `BaseModel`), rather than by that class itself
+fn overrides_copy(class: &Bound<'_, PyType>, copy_method: &Bound<'_, PyString>) -> PyResult<bool> {
+    let py = class.py();
+    for base in class.mro() {
+        let namespace = base.getattr(intern!(py, "__dict__"))?;
+        if namespace.contains(intern!(py, "__pydantic_fields_set__"))? {
+            return Ok(false);
+        } else if namespace.contains(copy_method)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
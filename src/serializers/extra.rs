@@ -13,6 +13,7 @@ use serde::ser::Error;
 use super::config::SerializationConfig;
 use super::errors::{PydanticSerializationUnexpectedValue, UNEXPECTED_TYPE_SER_MARKER};
 use super::ob_type::ObTypeLookup;
+use super::ordering::SetOrder;
 use crate::recursion_guard::ContainsRecursionState;
 use crate::recursion_guard::RecursionError;
 use crate::recursion_guard::RecursionGuard;
@@ -67,10 +68,10 @@ impl DuckTypingSerMode {
 }
 
 impl SerializationState {
-    pub fn new(timedelta_mode: &str, bytes_mode: &str, inf_nan_mode: &str) -> PyResult<Self> {
+    pub fn new(timedelta_mode: &str, bytes_mode: &str, inf_nan_mode: &str, temporal_format: &str) -> PyResult<Self> {
         let warnings = CollectWarnings::new(WarningsMode::None);
         let rec_guard = SerRecursionState::default();
-        let config = SerializationConfig::from_args(timedelta_mode, bytes_mode, inf_nan_mode)?;
+        let config = SerializationConfig::from_args(timedelta_mode, bytes_mode, inf_nan_mode, temporal_format)?;
         Ok(Self {
             warnings,
             rec_guard,
@@ -139,6 +140,15 @@ pub(crate) struct Extra<'a> {
     pub fallback: Option<&'a Bound<'a, PyAny>>,
     pub duck_typing_ser_mode: DuckTypingSerMode,
     pub context: Option<&'a Bound<'a, PyAny>>,
+    // whether secrets are serialized as their value rather than masked
+    pub serialize_secrets: bool,
+    // emitted in place of model fields which aren't in `__pydantic_fields_set__`
+    pub missing_value: Option<&'a Bound<'a, PyAny>>,
+    // whether JSON objects are written with their keys sorted
+    pub sort_keys: bool,
+    pub set_order: SetOrder,
+    // whether JSON is written in the RFC 8785 canonical form, implies sorted keys
+    pub canonical: bool,
 }
 
 impl<'a> Extra<'a> {
@@ -177,6 +187,11 @@ impl<'a> Extra<'a> {
             fallback,
             duck_typing_ser_mode,
             context,
+            serialize_secrets: false,
+            missing_value: None,
+            sort_keys: false,
+            set_order: SetOrder::Insertion,
+            canonical: false,
         }
     }
 
@@ -205,7 +220,10 @@ impl<'a> Extra<'a> {
     }
 
     pub fn serialize_by_alias_or(&self, serialize_by_alias: Option<bool>) -> bool {
-        self.by_alias.or(serialize_by_alias).unwrap_or(false)
+        self.by_alias
+            .or(serialize_by_alias)
+            .or(self.config.serialize_by_alias)
+            .unwrap_or(false)
     }
 }
 
@@ -245,6 +263,11 @@ pub(crate) struct ExtraOwned {
     pub fallback: Option<PyObject>,
     duck_typing_ser_mode: DuckTypingSerMode,
     pub context: Option<PyObject>,
+    serialize_secrets: bool,
+    pub missing_value: Option<PyObject>,
+    sort_keys: bool,
+    set_order: SetOrder,
+    canonical: bool,
 }
 
 impl ExtraOwned {
@@ -266,6 +289,11 @@ impl ExtraOwned {
             fallback: extra.fallback.map(|model| model.clone().into()),
             duck_typing_ser_mode: extra.duck_typing_ser_mode,
             context: extra.context.map(|model| model.clone().into()),
+            serialize_secrets: extra.serialize_secrets,
+            missing_value: extra.missing_value.map(|value| value.clone().into()),
+            sort_keys: extra.sort_keys,
+            set_order: extra.set_order,
+            canonical: extra.canonical,
         }
     }
 
@@ -288,6 +316,11 @@ impl ExtraOwned {
             fallback: self.fallback.as_ref().map(|m| m.bind(py)),
             duck_typing_ser_mode: self.duck_typing_ser_mode,
             context: self.context.as_ref().map(|m| m.bind(py)),
+            serialize_secrets: self.serialize_secrets,
+            missing_value: self.missing_value.as_ref().map(|m| m.bind(py)),
+            sort_keys: self.sort_keys,
+            set_order: self.set_order,
+            canonical: self.canonical,
         }
     }
 }
@@ -384,6 +417,8 @@ pub(crate) struct CollectWarnings {
     mode: WarningsMode,
     // FIXME: mutex is to satisfy PyO3 0.23, we should be able to refactor this away
     warnings: Mutex<Vec<PydanticSerializationUnexpectedValue>>,
+    // (union name, location, count) for values which only matched a union choice after a lax retry
+    lax_retries: Mutex<Vec<(String, String, usize)>>,
 }
 
 impl Clone for CollectWarnings {
@@ -391,6 +426,7 @@ impl Clone for CollectWarnings {
         Self {
             mode: self.mode,
             warnings: Mutex::new(self.warnings.lock().expect("lock poisoned").clone()),
+            lax_retries: Mutex::new(self.lax_retries.lock().expect("lock poisoned").clone()),
         }
     }
 }
@@ -400,6 +436,7 @@ impl CollectWarnings {
         Self {
             mode,
             warnings: Mutex::new(Vec::new()),
+            lax_retries: Mutex::new(Vec::new()),
         }
     }
 
@@ -409,6 +446,28 @@ impl CollectWarnings {
         }
     }
 
+    /// Record that a union only matched `union_name` after retrying with a lax check, events are
+    /// grouped by union and location so each is reported once with a count in `final_check`.
+    pub fn register_lax_retry(&self, union_name: &str, extra: &Extra) {
+        if self.mode == WarningsMode::None {
+            return;
+        }
+        let loc = match (extra.model_type_name(), extra.field_name) {
+            (Some(model_name), Some(field_name)) => format!("{model_name}.{field_name}"),
+            (None, Some(field_name)) => field_name.to_string(),
+            (Some(model_name), None) => model_name.to_string(),
+            (None, None) => "<root>".to_string(),
+        };
+        let mut lax_retries = self.lax_retries.lock().expect("lock poisoned");
+        match lax_retries
+            .iter_mut()
+            .find(|(name, existing_loc, _)| name == union_name && *existing_loc == loc)
+        {
+            Some((_, _, count)) => *count += 1,
+            None => lax_retries.push((union_name.to_string(), loc, 1)),
+        }
+    }
+
     pub fn on_fallback_py(&self, field_type: &str, value: &Bound<'_, PyAny>, extra: &Extra) -> PyResult<()> {
         // special case for None as it's very common e.g. as a default value
         if value.is_none() {
@@ -459,12 +518,17 @@ impl CollectWarnings {
             return Ok(());
         }
         let warnings = self.warnings.lock().expect("lock poisoned");
+        let lax_retries = self.lax_retries.lock().expect("lock poisoned");
 
-        if warnings.is_empty() {
+        if warnings.is_empty() && lax_retries.is_empty() {
             return Ok(());
         }
 
-        let formatted_warnings: Vec<String> = warnings.iter().map(|w| w.__repr__(py).to_string()).collect();
+        let mut formatted_warnings: Vec<String> = warnings.iter().map(|w| w.__repr__(py).to_string()).collect();
+        formatted_warnings.extend(lax_retries.iter().map(|(union_name, loc, count)| {
+            let times = if *count == 1 { "time" } else { "times" };
+            format!("`{union_name}` at `{loc}` only matched after a lax retry ({count} {times})")
+        }));
 
         let message = format!("Pydantic serializer warnings:\n  {}", formatted_warnings.join("\n  "));
         if self.mode == WarningsMode::Warn {
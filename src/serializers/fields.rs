@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 
@@ -7,8 +8,10 @@ use ahash::AHashMap;
 use serde::ser::SerializeMap;
 use smallvec::SmallVec;
 
+use crate::build_tools::py_schema_err;
 use crate::serializers::extra::SerCheck;
 use crate::serializers::DuckTypingSerMode;
+use crate::tools::SchemaDict;
 use crate::PydanticSerializationUnexpectedValue;
 
 use super::computed_fields::ComputedFields;
@@ -16,6 +19,7 @@ use super::errors::py_err_se_err;
 use super::extra::Extra;
 use super::filter::SchemaFilter;
 use super::infer::{infer_json_key, infer_serialize, infer_to_python, SerializeInfer};
+use super::ordering::{sorted_dict, FilteredValue, ObjectMembers};
 use super::shared::PydanticSerializer;
 use super::shared::{CombinedSerializer, TypeSerializer};
 
@@ -29,9 +33,13 @@ pub(super) struct SerField {
     pub serializer: Option<CombinedSerializer>,
     pub required: bool,
     pub serialize_by_alias: Option<bool>,
+    // omit the field when its value is `None`, like `exclude_none` but for this field only
+    pub exclude_none: bool,
+    // omit the field when this callable returns a truthy value for its value
+    pub exclude_if: Option<PyObject>,
 }
 
-impl_py_gc_traverse!(SerField { serializer });
+impl_py_gc_traverse!(SerField { serializer, exclude_if });
 
 impl SerField {
     pub fn new(
@@ -50,9 +58,22 @@ impl SerField {
             serializer,
             required,
             serialize_by_alias,
+            exclude_none: false,
+            exclude_if: None,
         }
     }
 
+    /// Set the per-field exclusion conditions, `serialization_exclude_none` and `serialization_exclude_if`,
+    /// from the field's schema
+    pub fn with_exclude_conditions(mut self, field_info: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let py = field_info.py();
+        self.exclude_none = field_info
+            .get_as(intern!(py, "serialization_exclude_none"))?
+            .unwrap_or(false);
+        self.exclude_if = field_info.get_as(intern!(py, "serialization_exclude_if"))?;
+        Ok(self)
+    }
+
     pub fn get_key_py<'py>(&self, py: Python<'py>, extra: &Extra) -> &Bound<'py, PyAny> {
         if extra.serialize_by_alias_or(self.serialize_by_alias) {
             if let Some(ref alias_py) = self.alias_py {
@@ -72,7 +93,23 @@ impl SerField {
     }
 }
 
-fn exclude_default(value: &Bound<'_, PyAny>, extra: &Extra, serializer: &CombinedSerializer) -> PyResult<bool> {
+fn skip_field(
+    value: &Bound<'_, PyAny>,
+    extra: &Extra,
+    field: &SerField,
+    serializer: &CombinedSerializer,
+) -> PyResult<bool> {
+    if serializer.omit_field(value, extra) {
+        return Ok(true);
+    }
+    if field.exclude_none && value.is_none() {
+        return Ok(true);
+    }
+    if let Some(ref exclude_if) = field.exclude_if {
+        if exclude_if.call1(value.py(), (value,))?.is_truthy(value.py())? {
+            return Ok(true);
+        }
+    }
     if extra.exclude_defaults {
         if let Some(default) = serializer.get_default(value.py())? {
             if value.eq(default)? {
@@ -83,6 +120,11 @@ fn exclude_default(value: &Bound<'_, PyAny>, extra: &Extra, serializer: &Combine
     Ok(false)
 }
 
+/// Whether `value` was put in place of an unset model field by `missing_value`
+fn is_missing_value(value: &Bound<'_, PyAny>, extra: &Extra) -> bool {
+    extra.missing_value.is_some_and(|missing_value| value.is(missing_value))
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub(super) enum FieldsMode {
     // typeddict with no extra items
@@ -93,12 +135,91 @@ pub(super) enum FieldsMode {
     TypedDictAllow,
 }
 
+/// Order in which fields are emitted, set with the `field_order` config
+#[derive(Debug, Clone, Default)]
+pub(super) enum FieldOrder {
+    /// the order of the value being serialized, e.g. `__dict__` for models
+    #[default]
+    Insertion,
+    /// the order of the fields in the schema, other keys follow in insertion order
+    Schema(AHashMap<String, usize>),
+    /// sorted by field name
+    Alphabetical,
+}
+
+impl FieldOrder {
+    pub fn from_config(
+        py: Python,
+        config: Option<&Bound<'_, PyDict>>,
+        field_names: impl Iterator<Item = PyResult<String>>,
+    ) -> PyResult<Self> {
+        let field_order: Option<Bound<'_, PyString>> = config.get_as(intern!(py, "field_order"))?;
+        match field_order.as_ref().map(|s| s.to_str()).transpose()? {
+            None | Some("insertion") => Ok(Self::Insertion),
+            Some("schema") => Ok(Self::Schema(
+                field_names
+                    .enumerate()
+                    .map(|(index, name)| Ok((name?, index)))
+                    .collect::<PyResult<_>>()?,
+            )),
+            Some("alphabetical") => Ok(Self::Alphabetical),
+            Some(s) => py_schema_err!(
+                "Invalid field_order: `{}`, expected `schema`, `alphabetical` or `insertion`",
+                s
+            ),
+        }
+    }
+
+    /// Items in this order, the items are only collected to be sorted if an order other than `Insertion` is set
+    fn apply<'py, I>(&self, items: I) -> PyResult<OrderedItems<'py, I>>
+    where
+        I: Iterator<Item = PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)>>,
+    {
+        let mut sorted = match self {
+            Self::Insertion => return Ok(OrderedItems::Insertion(items)),
+            _ => items.collect::<PyResult<Vec<_>>>()?,
+        };
+        match self {
+            Self::Insertion => (),
+            // stable sorts, so keys which aren't fields keep their relative order
+            Self::Schema(positions) => sorted.sort_by_key(|(key, _)| {
+                key_str(key)
+                    .ok()
+                    .and_then(|key| positions.get(key).copied())
+                    .unwrap_or(usize::MAX)
+            }),
+            Self::Alphabetical => sorted.sort_by_cached_key(|(key, _)| key_str(key).ok().map(str::to_owned)),
+        }
+        Ok(OrderedItems::Sorted(sorted.into_iter()))
+    }
+}
+
+enum OrderedItems<'py, I> {
+    Insertion(I),
+    Sorted(std::vec::IntoIter<(Bound<'py, PyAny>, Bound<'py, PyAny>)>),
+}
+
+impl<'py, I> Iterator for OrderedItems<'py, I>
+where
+    I: Iterator<Item = PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)>>,
+{
+    type Item = PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Insertion(items) => items.next(),
+            Self::Sorted(items) => items.next().map(Ok),
+        }
+    }
+}
+
 /// General purpose serializer for fields - used by dataclasses, models and typed_dicts
 #[derive(Debug)]
 pub struct GeneralFieldsSerializer {
     fields: AHashMap<String, SerField>,
     computed_fields: Option<ComputedFields>,
     mode: FieldsMode,
+    field_order: FieldOrder,
     extra_serializer: Option<Box<CombinedSerializer>>,
     // isize because we look up filter via `.hash()` which returns an isize
     filter: SchemaFilter<isize>,
@@ -120,11 +241,17 @@ impl GeneralFieldsSerializer {
         mode: FieldsMode,
         extra_serializer: Option<CombinedSerializer>,
         computed_fields: Option<ComputedFields>,
+        field_order: FieldOrder,
     ) -> Self {
         let required_fields = fields.values().filter(|f| f.required).count();
+        let mut computed_fields = computed_fields;
+        if let (Some(computed_fields), FieldOrder::Alphabetical) = (&mut computed_fields, &field_order) {
+            computed_fields.sort_alphabetically();
+        }
         Self {
             fields,
             mode,
+            field_order,
             extra_serializer: extra_serializer.map(Box::new),
             filter: SchemaFilter::default(),
             computed_fields,
@@ -156,9 +283,9 @@ impl GeneralFieldsSerializer {
         let output_dict = PyDict::new(py);
         let mut used_req_fields: usize = 0;
 
-        // NOTE! we maintain the order of the input dict assuming that's right
-        for result in main_iter {
-            let (key, value) = result?;
+        // NOTE! unless `field_order` is set, we maintain the order of the input dict assuming that's right
+        for item in self.field_order.apply(main_iter)? {
+            let (key, value) = item?;
             let key_str = key_str(&key)?;
             let op_field = self.fields.get(key_str);
             if extra.exclude_none && value.is_none() {
@@ -170,8 +297,12 @@ impl GeneralFieldsSerializer {
             };
             if let Some((next_include, next_exclude)) = self.filter.key_filter(&key, include, exclude)? {
                 if let Some(field) = op_field {
-                    if let Some(ref serializer) = field.serializer {
-                        if !exclude_default(&value, &field_extra, serializer)? {
+                    if is_missing_value(&value, &field_extra) {
+                        let value = infer_to_python(&value, None, None, &field_extra)?;
+                        let output_key = field.get_key_py(output_dict.py(), &field_extra);
+                        output_dict.set_item(output_key, value)?;
+                    } else if let Some(ref serializer) = field.serializer {
+                        if !skip_field(&value, &field_extra, field, serializer)? {
                             let value = serializer.to_python(
                                 &value,
                                 next_include.as_ref(),
@@ -224,21 +355,32 @@ impl GeneralFieldsSerializer {
         }
     }
 
-    pub(crate) fn main_serde_serialize<'py, S: serde::ser::Serializer>(
-        &self,
+    /// Writes the fields from `main_iter`, the extra fields from `extra_dict` and the computed fields of `model`
+    /// as a JSON object, sorted by key when `sort_keys` or `canonical` is set
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn main_serde_serialize<'a, 'py, S: serde::ser::Serializer>(
+        &'a self,
         main_iter: impl Iterator<Item = PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)>>,
+        extra_dict: Option<&Bound<'py, PyDict>>,
         expected_len: usize,
         serializer: S,
+        model: Option<&Bound<'py, PyAny>>,
         include: Option<&Bound<'py, PyAny>>,
         exclude: Option<&Bound<'py, PyAny>>,
-        extra: Extra,
-    ) -> Result<S::SerializeMap, S::Error> {
-        // NOTE! As above, we maintain the order of the input dict assuming that's right
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        // NOTE! As above, we maintain the order of the input dict (unless `field_order` is set)
         // we don't both with `used_fields` here because on unions, `to_python(..., mode='json')` is used
         let mut map = serializer.serialize_map(Some(expected_len))?;
+        let extra = Extra { model, ..*extra };
+        let mut members: ObjectMembers<FieldsMember<'a, 'py>> = ObjectMembers::new(&extra);
+        let mut add_member = |map: &mut S::SerializeMap, key: Cow<'_, str>, member| match members.add(key, member) {
+            Some((key, member)) => write_member::<S>(map, &key, member, &extra),
+            None => Ok(()),
+        };
 
-        for result in main_iter {
-            let (key, value) = result.map_err(py_err_se_err)?;
+        for item in self.field_order.apply(main_iter).map_err(py_err_se_err)? {
+            let (key, value) = item.map_err(py_err_se_err)?;
             if extra.exclude_none && value.is_none() {
                 continue;
             }
@@ -250,29 +392,79 @@ impl GeneralFieldsSerializer {
 
             let filter = self.filter.key_filter(&key, include, exclude).map_err(py_err_se_err)?;
             if let Some((next_include, next_exclude)) = filter {
-                if let Some(field) = self.fields.get(key_str) {
-                    if let Some(ref serializer) = field.serializer {
-                        if !exclude_default(&value, &field_extra, serializer).map_err(py_err_se_err)? {
-                            let s = PydanticSerializer::new(
-                                &value,
-                                serializer,
-                                next_include.as_ref(),
-                                next_exclude.as_ref(),
-                                &field_extra,
-                            );
+                if let Some((name, field)) = self.fields.get_key_value(key_str) {
+                    if is_missing_value(&value, &field_extra) {
+                        let output_key = field.get_key_json(key_str, &field_extra);
+                        let member = FieldsMember::Field {
+                            name,
+                            serializer: None,
+                            value: (value, None, None),
+                        };
+                        add_member(&mut map, output_key, member)?;
+                    } else if let Some(ref serializer) = field.serializer {
+                        if !skip_field(&value, &field_extra, field, serializer).map_err(py_err_se_err)? {
                             let output_key = field.get_key_json(key_str, &field_extra);
-                            map.serialize_entry(&output_key, &s)?;
+                            let member = FieldsMember::Field {
+                                name,
+                                serializer: Some(serializer),
+                                value: (value, next_include, next_exclude),
+                            };
+                            add_member(&mut map, output_key, member)?;
                         }
                     }
                 } else if self.mode == FieldsMode::TypedDictAllow {
                     let output_key = infer_json_key(&key, &field_extra).map_err(py_err_se_err)?;
-                    let s = SerializeInfer::new(&value, next_include.as_ref(), next_exclude.as_ref(), &field_extra);
-                    map.serialize_entry(&output_key, &s)?;
+                    let member = FieldsMember::Extra {
+                        typed_dict_key: Some(key.clone()),
+                        value: (value, next_include, next_exclude),
+                    };
+                    add_member(&mut map, output_key, member)?;
                 }
                 // no error case here since unions (which need the error case) use `to_python(..., mode='json')`
             }
         }
-        Ok(map)
+
+        // this is used to include `__pydantic_extra__` in serialization on models
+        if let Some(extra_dict) = extra_dict {
+            for item in self.field_order.apply(dict_items(extra_dict)).map_err(py_err_se_err)? {
+                let (key, value) = item.map_err(py_err_se_err)?;
+                if extra.exclude_none && value.is_none() {
+                    continue;
+                }
+                let filter = self.filter.key_filter(&key, include, exclude).map_err(py_err_se_err)?;
+                if let Some((next_include, next_exclude)) = filter {
+                    let output_key = infer_json_key(&key, &extra).map_err(py_err_se_err)?;
+                    let member = FieldsMember::Extra {
+                        typed_dict_key: None,
+                        value: (value, next_include, next_exclude),
+                    };
+                    add_member(&mut map, output_key, member)?;
+                }
+            }
+        }
+
+        if let (Some(computed_fields), Some(model)) = (&self.computed_fields, model) {
+            computed_fields.serde_serialize(
+                model,
+                &self.filter,
+                include,
+                exclude,
+                &extra,
+                |key, name, serializer, value| {
+                    let member = FieldsMember::Field {
+                        name,
+                        serializer: Some(serializer),
+                        value,
+                    };
+                    add_member(&mut map, Cow::Borrowed(key), member)
+                },
+            )?;
+        }
+
+        for (key, member) in members.into_sorted() {
+            write_member::<S>(&mut map, &key, member, &extra)?;
+        }
+        map.end()
     }
 
     pub(crate) fn add_computed_fields_python(
@@ -292,22 +484,6 @@ impl GeneralFieldsSerializer {
         Ok(())
     }
 
-    pub(crate) fn add_computed_fields_json<S: serde::ser::Serializer>(
-        &self,
-        model: Option<&Bound<'_, PyAny>>,
-        map: &mut S::SerializeMap,
-        include: Option<&Bound<'_, PyAny>>,
-        exclude: Option<&Bound<'_, PyAny>>,
-        extra: &Extra,
-    ) -> Result<(), S::Error> {
-        if let Some(ref computed_fields) = self.computed_fields {
-            if let Some(model) = model {
-                computed_fields.serde_serialize::<S>(model, map, &self.filter, include, exclude, extra)?;
-            }
-        }
-        Ok(())
-    }
-
     pub(crate) fn computed_field_count(&self) -> usize {
         option_length!(self.computed_fields)
     }
@@ -358,7 +534,8 @@ impl TypeSerializer for GeneralFieldsSerializer {
 
         // this is used to include `__pydantic_extra__` in serialization on models
         if let Some(extra_dict) = extra_dict {
-            for (key, value) in extra_dict {
+            for item in self.field_order.apply(dict_items(&extra_dict))? {
+                let (key, value) = item?;
                 if extra.exclude_none && value.is_none() {
                     continue;
                 }
@@ -374,7 +551,7 @@ impl TypeSerializer for GeneralFieldsSerializer {
             }
         }
         self.add_computed_fields_python(model, &output_dict, include, exclude, extra)?;
-        Ok(output_dict.into())
+        Ok(sorted_dict(output_dict, extra)?.into())
     }
 
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
@@ -419,34 +596,16 @@ impl TypeSerializer for GeneralFieldsSerializer {
             FieldsMode::TypedDictAllow => main_dict.len() + self.computed_field_count(),
             _ => self.fields.len() + option_length!(extra_dict) + self.computed_field_count(),
         };
-        // NOTE! As above, we maintain the order of the input dict assuming that's right
-        // we don't both with `used_fields` here because on unions, `to_python(..., mode='json')` is used
-        let mut map = self.main_serde_serialize(
+        self.main_serde_serialize(
             dict_items(&main_dict),
+            extra_dict.as_ref(),
             expected_len,
             serializer,
+            model,
             include,
             exclude,
-            Extra { model, ..*extra },
-        )?;
-
-        // this is used to include `__pydantic_extra__` in serialization on models
-        if let Some(extra_dict) = extra_dict {
-            for (key, value) in extra_dict {
-                if extra.exclude_none && value.is_none() {
-                    continue;
-                }
-                let filter = self.filter.key_filter(&key, include, exclude).map_err(py_err_se_err)?;
-                if let Some((next_include, next_exclude)) = filter {
-                    let output_key = infer_json_key(&key, extra).map_err(py_err_se_err)?;
-                    let s = SerializeInfer::new(&value, next_include.as_ref(), next_exclude.as_ref(), extra);
-                    map.serialize_entry(&output_key, &s)?;
-                }
-            }
-        }
-
-        self.add_computed_fields_json::<S>(model, &mut map, include, exclude, extra)?;
-        map.end()
+            extra,
+        )
     }
 
     fn get_name(&self) -> &'static str {
@@ -454,6 +613,68 @@ impl TypeSerializer for GeneralFieldsSerializer {
     }
 }
 
+/// A member of the JSON object written by `main_serde_serialize`, held while keys are sorted so fields,
+/// extra fields and computed fields are sorted together
+pub(crate) enum FieldsMember<'a, 'py> {
+    /// a field or computed field, values replaced by `missing_value` have no serializer and are inferred
+    Field {
+        name: &'a str,
+        serializer: Option<&'a CombinedSerializer>,
+        value: FilteredValue<'py>,
+    },
+    /// an extra field, which is inferred, typed dicts set the field name to its key
+    Extra {
+        typed_dict_key: Option<Bound<'py, PyAny>>,
+        value: FilteredValue<'py>,
+    },
+}
+
+fn write_member<S: serde::ser::Serializer>(
+    map: &mut S::SerializeMap,
+    key: &str,
+    member: FieldsMember,
+    extra: &Extra,
+) -> Result<(), S::Error> {
+    match member {
+        FieldsMember::Field {
+            name,
+            serializer,
+            value: (value, next_include, next_exclude),
+        } => {
+            let field_extra = Extra {
+                field_name: Some(name),
+                ..*extra
+            };
+            match serializer {
+                Some(serializer) => {
+                    let s = PydanticSerializer::new(
+                        &value,
+                        serializer,
+                        next_include.as_ref(),
+                        next_exclude.as_ref(),
+                        &field_extra,
+                    );
+                    map.serialize_entry(key, &s)
+                }
+                None => map.serialize_entry(key, &SerializeInfer::new(&value, None, None, &field_extra)),
+            }
+        }
+        FieldsMember::Extra {
+            typed_dict_key,
+            value: (value, next_include, next_exclude),
+        } => {
+            let field_name = typed_dict_key
+                .as_ref()
+                .map(key_str)
+                .transpose()
+                .map_err(py_err_se_err)?;
+            let field_extra = Extra { field_name, ..*extra };
+            let s = SerializeInfer::new(&value, next_include.as_ref(), next_exclude.as_ref(), &field_extra);
+            map.serialize_entry(key, &s)
+        }
+    }
+}
+
 fn key_str<'a>(key: &'a Bound<'_, PyAny>) -> PyResult<&'a str> {
     key.downcast::<PyString>()?.to_str()
 }
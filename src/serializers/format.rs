@@ -0,0 +1,144 @@
+//! Layout of JSON output, see the `indent`, `separators`, `newline` and `ensure_ascii` arguments of `to_json`
+use std::io;
+
+use serde_json::ser::Formatter;
+
+/// Options controlling how JSON is laid out, the default is compact output with no whitespace
+#[derive(Debug, Clone, Default)]
+pub(crate) struct JsonFormat {
+    pub indent: Option<usize>,
+    // `(item_separator, key_separator)` as in `json.dumps()`
+    pub separators: Option<(String, String)>,
+    pub newline: Option<String>,
+    pub ensure_ascii: bool,
+}
+
+impl JsonFormat {
+    /// Whether any option needs `CustomFormatter` rather than serde's compact or pretty formatters
+    pub fn is_custom(&self) -> bool {
+        self.separators.is_some() || self.newline.is_some() || self.ensure_ascii
+    }
+
+    pub fn formatter(&self) -> CustomFormatter<'_> {
+        // match `json.dumps()`, where the default key separator includes a space when indenting
+        let (item_separator, key_separator) = match (&self.separators, self.indent) {
+            (Some((item, key)), _) => (item.as_str(), key.as_str()),
+            (None, Some(_)) => (",", ": "),
+            (None, None) => (",", ":"),
+        };
+        CustomFormatter {
+            indent: self.indent.map(|indent| " ".repeat(indent)),
+            item_separator,
+            key_separator,
+            newline: self.newline.as_deref().unwrap_or("\n"),
+            ensure_ascii: self.ensure_ascii,
+            current_indent: 0,
+            has_value: false,
+        }
+    }
+}
+
+/// A formatter with configurable separators, newlines and escaping of non-ASCII characters,
+/// layout otherwise matches `serde_json`'s `PrettyFormatter` when indenting and `CompactFormatter` when not
+pub(crate) struct CustomFormatter<'a> {
+    indent: Option<String>,
+    item_separator: &'a str,
+    key_separator: &'a str,
+    newline: &'a str,
+    ensure_ascii: bool,
+    current_indent: usize,
+    has_value: bool,
+}
+
+impl CustomFormatter<'_> {
+    fn write_newline_indent<W: ?Sized + io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        if let Some(indent) = &self.indent {
+            writer.write_all(self.newline.as_bytes())?;
+            for _ in 0..self.current_indent {
+                writer.write_all(indent.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn begin_value<W: ?Sized + io::Write>(&mut self, writer: &mut W, first: bool) -> io::Result<()> {
+        if !first {
+            writer.write_all(self.item_separator.as_bytes())?;
+        }
+        self.write_newline_indent(writer)
+    }
+
+    fn begin_nested<W: ?Sized + io::Write>(&mut self, writer: &mut W, open: &[u8]) -> io::Result<()> {
+        self.current_indent += 1;
+        self.has_value = false;
+        writer.write_all(open)
+    }
+
+    fn end_nested<W: ?Sized + io::Write>(&mut self, writer: &mut W, close: &[u8]) -> io::Result<()> {
+        self.current_indent -= 1;
+        if self.has_value {
+            self.write_newline_indent(writer)?;
+        }
+        writer.write_all(close)
+    }
+}
+
+impl Formatter for CustomFormatter<'_> {
+    fn begin_array<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.begin_nested(writer, b"[")
+    }
+
+    fn end_array<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.end_nested(writer, b"]")
+    }
+
+    fn begin_array_value<W: ?Sized + io::Write>(&mut self, writer: &mut W, first: bool) -> io::Result<()> {
+        self.begin_value(writer, first)
+    }
+
+    fn end_array_value<W: ?Sized + io::Write>(&mut self, _writer: &mut W) -> io::Result<()> {
+        self.has_value = true;
+        Ok(())
+    }
+
+    fn begin_object<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.begin_nested(writer, b"{")
+    }
+
+    fn end_object<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.end_nested(writer, b"}")
+    }
+
+    fn begin_object_key<W: ?Sized + io::Write>(&mut self, writer: &mut W, first: bool) -> io::Result<()> {
+        self.begin_value(writer, first)
+    }
+
+    fn begin_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(self.key_separator.as_bytes())
+    }
+
+    fn end_object_value<W: ?Sized + io::Write>(&mut self, _writer: &mut W) -> io::Result<()> {
+        self.has_value = true;
+        Ok(())
+    }
+
+    fn write_string_fragment<W: ?Sized + io::Write>(&mut self, writer: &mut W, fragment: &str) -> io::Result<()> {
+        if !self.ensure_ascii || fragment.is_ascii() {
+            return writer.write_all(fragment.as_bytes());
+        }
+        let mut start = 0;
+        for (index, c) in fragment.char_indices() {
+            if c.is_ascii() {
+                continue;
+            }
+            writer.write_all(&fragment.as_bytes()[start..index])?;
+            // characters outside the BMP are written as a surrogate pair, as `json.dumps()` does
+            let mut units = [0; 2];
+            for unit in c.encode_utf16(&mut units) {
+                write!(writer, "\\u{unit:04x}")?;
+            }
+            start = index + c.len_utf8();
+        }
+        writer.write_all(&fragment.as_bytes()[start..])
+    }
+}
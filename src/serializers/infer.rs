@@ -10,8 +10,11 @@ use pyo3::types::{PyByteArray, PyBytes, PyDict, PyFrozenSet, PyIterator, PyList,
 use pyo3::IntoPyObjectExt;
 use serde::ser::{Error, Serialize, SerializeMap, SerializeSeq, Serializer};
 
+use crate::email::PyEmailAddress;
 use crate::input::{EitherTimedelta, Int};
+use crate::secret::PySecret;
 use crate::serializers::type_serializers;
+use crate::serializers::type_serializers::secret::SecretStrSerializer;
 use crate::tools::{extract_int, py_err, safe_repr};
 use crate::url::{PyMultiHostUrl, PyUrl};
 
@@ -21,6 +24,7 @@ use super::errors::{py_err_se_err, PydanticSerializationError};
 use super::extra::{Extra, SerMode};
 use super::filter::{AnyFilter, SchemaFilter};
 use super::ob_type::ObType;
+use super::ordering::{sorted_dict, FilteredValue, ObjectMembers};
 use super::shared::{any_dataclass_iter, PydanticSerializer, TypeSerializer};
 use super::SchemaSerializer;
 
@@ -94,45 +98,55 @@ pub(crate) fn infer_to_python_known(
     let serialize_with_serializer = || {
         let py_serializer = value.getattr(intern!(py, "__pydantic_serializer__"))?;
         let serializer: PyRef<SchemaSerializer> = py_serializer.extract()?;
-        let extra = serializer.build_extra(
-            py,
-            extra.mode,
-            extra.by_alias,
-            extra.warnings,
-            extra.exclude_unset,
-            extra.exclude_defaults,
-            extra.exclude_none,
-            extra.round_trip,
-            extra.rec_guard,
-            extra.serialize_unknown,
-            extra.fallback,
-            extra.duck_typing_ser_mode,
-            extra.context,
-        );
+        // the ordering of the output applies to the whole value, including the values of nested models
+        let extra = Extra {
+            sort_keys: extra.sort_keys,
+            set_order: extra.set_order,
+            canonical: extra.canonical,
+            ..serializer.build_extra(
+                py,
+                extra.mode,
+                extra.by_alias,
+                extra.warnings,
+                extra.exclude_unset,
+                extra.exclude_defaults,
+                extra.exclude_none,
+                extra.round_trip,
+                extra.rec_guard,
+                extra.serialize_unknown,
+                extra.fallback,
+                extra.duck_typing_ser_mode,
+                extra.context,
+            )
+        };
         serializer.serializer.to_python(value, include, exclude, &extra)
     };
 
     let value = match extra.mode {
         SerMode::Json => match ob_type {
             // `bool` and `None` can't be subclasses, `ObType::Int`, `ObType::Float`, `ObType::Str` refer to exact types
-            ObType::None | ObType::Bool | ObType::Int | ObType::Str => value.clone().unbind(),
+            ObType::None | ObType::Bool => value.clone().unbind(),
+            ObType::Int => extra.config.bigint_mode.to_python(value)?,
             // have to do this to make sure subclasses of for example str are upcast to `str`
             ObType::IntSubclass => {
                 if let Some(i) = extract_int(value) {
-                    i.into_py_any(py)?
+                    extra.config.bigint_mode.to_python(&i.into_bound_py_any(py)?)?
                 } else {
                     return py_err!(PyTypeError; "Expected int, got {}", safe_repr(value));
                 }
             }
             ObType::Float | ObType::FloatSubclass => {
                 let v = value.extract::<f64>()?;
+                type_serializers::float::check_inf_nan(v, extra.config.inf_nan_mode)?;
                 if (v.is_nan() || v.is_infinite()) && extra.config.inf_nan_mode == InfNanMode::Null {
                     return Ok(py.None());
                 }
-                v.into_py_any(py)?
+                extra.config.float_format.round(v).into_py_any(py)?
             }
             ObType::Decimal => value.to_string().into_py_any(py)?,
-            ObType::StrSubclass => PyString::new(py, value.downcast::<PyString>()?.to_str()?).into(),
+            ObType::Str | ObType::StrSubclass => {
+                type_serializers::string::json_py_str(value.downcast()?, extra.config.invalid_utf8_mode)?.into()
+            }
             ObType::Bytes => extra
                 .config
                 .bytes_mode
@@ -157,12 +171,13 @@ pub(crate) fn infer_to_python_known(
                 let elements = serialize_seq_filter!(PyList);
                 PyList::new(py, elements)?.into()
             }
-            ObType::Set => {
-                let elements = serialize_seq!(PySet);
-                PyList::new(py, elements)?.into()
-            }
-            ObType::Frozenset => {
-                let elements = serialize_seq!(PyFrozenSet);
+            ObType::Set | ObType::Frozenset => {
+                let elements = extra
+                    .set_order
+                    .elements(value)?
+                    .iter()
+                    .map(|v| infer_to_python(v, None, None, extra))
+                    .collect::<PyResult<Vec<PyObject>>>()?;
                 PyList::new(py, elements)?.into()
             }
             ObType::Dict => {
@@ -172,15 +187,27 @@ pub(crate) fn infer_to_python_known(
                 })?
             }
             ObType::Datetime => {
-                let iso_dt = super::type_serializers::datetime_etc::datetime_to_string(value.downcast()?)?;
+                let iso_dt = super::type_serializers::datetime_etc::datetime_to_json(
+                    value.downcast()?,
+                    &extra.config.temporal_format,
+                    false,
+                )?;
                 iso_dt.into_py_any(py)?
             }
             ObType::Date => {
-                let iso_date = super::type_serializers::datetime_etc::date_to_string(value.downcast()?)?;
+                let iso_date = super::type_serializers::datetime_etc::date_to_json(
+                    value.downcast()?,
+                    &extra.config.temporal_format,
+                    false,
+                )?;
                 iso_date.into_py_any(py)?
             }
             ObType::Time => {
-                let iso_time = super::type_serializers::datetime_etc::time_to_string(value.downcast()?)?;
+                let iso_time = super::type_serializers::datetime_etc::time_to_json(
+                    value.downcast()?,
+                    &extra.config.temporal_format,
+                    false,
+                )?;
                 iso_time.into_py_any(py)?
             }
             ObType::Timedelta => {
@@ -192,16 +219,17 @@ pub(crate) fn infer_to_python_known(
             }
             ObType::Url => {
                 let py_url: PyUrl = value.extract()?;
-                py_url.__str__().into_py_any(py)?
+                py_url.serialized_str(extra.round_trip).into_py_any(py)?
             }
             ObType::MultiHostUrl => {
                 let py_url: PyMultiHostUrl = value.extract()?;
-                py_url.__str__().into_py_any(py)?
+                py_url.serialized_str(extra.round_trip).into_py_any(py)?
             }
-            ObType::Uuid => {
-                let uuid = super::type_serializers::uuid::uuid_to_string(value)?;
-                uuid.into_py_any(py)?
+            ObType::Email => {
+                let py_email: PyEmailAddress = value.extract()?;
+                py_email.__str__().into_py_any(py)?
             }
+            ObType::Uuid => super::type_serializers::uuid::uuid_to_json(value, extra.config.uuid_mode)?,
             ObType::PydanticSerializable => serialize_with_serializer()?,
             ObType::Dataclass => {
                 serialize_pairs_python(py, any_dataclass_iter(value)?.0, include, exclude, extra, |k| {
@@ -209,7 +237,7 @@ pub(crate) fn infer_to_python_known(
                 })?
             }
             ObType::Enum => {
-                let v = value.getattr(intern!(py, "value"))?;
+                let v = value.getattr(extra.config.enum_mode.attribute(py))?;
                 infer_to_python(&v, include, exclude, extra)?
             }
             ObType::Generator => {
@@ -239,7 +267,9 @@ pub(crate) fn infer_to_python_known(
             ObType::Path => value.str()?.into_py_any(py)?,
             ObType::Pattern => value.getattr(intern!(py, "pattern"))?.unbind(),
             ObType::Unknown => {
-                if let Some(fallback) = extra.fallback {
+                if value.is_instance_of::<PySecret>() {
+                    return SecretStrSerializer.to_python(value, include, exclude, extra);
+                } else if let Some(fallback) = extra.fallback {
                     let next_value = fallback.call1((value,))?;
                     let next_result = infer_to_python(&next_value, include, exclude, extra);
                     return next_result;
@@ -269,7 +299,15 @@ pub(crate) fn infer_to_python_known(
             }
             ObType::Dict => {
                 let dict = value.downcast::<PyDict>()?;
-                serialize_pairs_python(py, dict.iter().map(Ok), include, exclude, extra, Ok)?
+                let enum_mode = extra.config.enum_mode;
+                // enum keys are replaced as values are, other keys are returned unchanged
+                serialize_pairs_python(py, dict.iter().map(Ok), include, exclude, extra, |k| {
+                    if enum_mode.replaces_member(extra.mode) && extra.ob_type_lookup.get_type(&k) == ObType::Enum {
+                        k.getattr(enum_mode.attribute(py))
+                    } else {
+                        Ok(k)
+                    }
+                })?
             }
             ObType::PydanticSerializable => serialize_with_serializer()?,
             ObType::Dataclass => serialize_pairs_python(py, any_dataclass_iter(value)?.0, include, exclude, extra, Ok)?,
@@ -288,8 +326,14 @@ pub(crate) fn infer_to_python_known(
                 let v = value.downcast::<PyComplex>()?;
                 v.into_py_any(py)?
             }
+            ObType::Enum if extra.config.enum_mode.replaces_member(extra.mode) => {
+                let v = value.getattr(extra.config.enum_mode.attribute(py))?;
+                infer_to_python(&v, include, exclude, extra)?
+            }
             ObType::Unknown => {
-                if let Some(fallback) = extra.fallback {
+                if value.is_instance_of::<PySecret>() {
+                    return SecretStrSerializer.to_python(value, include, exclude, extra);
+                } else if let Some(fallback) = extra.fallback {
                     let next_value = fallback.call1((value,))?;
                     let next_result = infer_to_python(&next_value, include, exclude, extra);
                     return next_result;
@@ -379,18 +423,6 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         };
     }
 
-    macro_rules! serialize_seq {
-        ($t:ty) => {{
-            let py_seq = value.downcast::<$t>().map_err(py_err_se_err)?;
-            let mut seq = serializer.serialize_seq(Some(py_seq.len()))?;
-            for element in py_seq.iter() {
-                let item_serializer = SerializeInfer::new(&element, include, exclude, extra);
-                seq.serialize_element(&item_serializer)?
-            }
-            seq.end()
-        }};
-    }
-
     macro_rules! serialize_seq_filter {
         ($t:ty) => {{
             let py_seq = value.downcast::<$t>().map_err(py_err_se_err)?;
@@ -414,7 +446,10 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
 
     let ser_result = match ob_type {
         ObType::None => serializer.serialize_none(),
-        ObType::Int | ObType::IntSubclass => serialize!(Int),
+        ObType::Int | ObType::IntSubclass => match value.extract::<Int>() {
+            Ok(v) => extra.config.bigint_mode.serialize_int(&v, serializer),
+            Err(e) => Err(py_err_se_err(e)),
+        },
         ObType::Bool => serialize!(bool),
         ObType::Complex => {
             let v = value.downcast::<PyComplex>().map_err(py_err_se_err)?;
@@ -423,12 +458,12 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         }
         ObType::Float | ObType::FloatSubclass => {
             let v = value.extract::<f64>().map_err(py_err_se_err)?;
-            type_serializers::float::serialize_f64(v, serializer, extra.config.inf_nan_mode)
+            type_serializers::float::serialize_f64(v, serializer, extra.config.inf_nan_mode, extra.config.float_format)
         }
         ObType::Decimal => value.to_string().serialize(serializer),
         ObType::Str | ObType::StrSubclass => {
             let py_str = value.downcast::<PyString>().map_err(py_err_se_err)?;
-            super::type_serializers::string::serialize_py_str(py_str, serializer)
+            super::type_serializers::string::serialize_py_str(py_str, serializer, extra.config.invalid_utf8_mode)
         }
         ObType::Bytes => {
             let py_bytes = value.downcast::<PyBytes>().map_err(py_err_se_err)?;
@@ -451,22 +486,35 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         }
         ObType::List => serialize_seq_filter!(PyList),
         ObType::Tuple => serialize_seq_filter!(PyTuple),
-        ObType::Set => serialize_seq!(PySet),
-        ObType::Frozenset => serialize_seq!(PyFrozenSet),
+        ObType::Set | ObType::Frozenset => {
+            let elements = extra.set_order.elements(value).map_err(py_err_se_err)?;
+            let mut seq = serializer.serialize_seq(Some(elements.len()))?;
+            for element in &elements {
+                let item_serializer = SerializeInfer::new(element, include, exclude, extra);
+                seq.serialize_element(&item_serializer)?;
+            }
+            seq.end()
+        }
         ObType::Datetime => {
             let py_dt = value.downcast().map_err(py_err_se_err)?;
-            let iso_dt = super::type_serializers::datetime_etc::datetime_to_string(py_dt).map_err(py_err_se_err)?;
-            serializer.serialize_str(&iso_dt)
+            let iso_dt =
+                super::type_serializers::datetime_etc::datetime_to_json(py_dt, &extra.config.temporal_format, false)
+                    .map_err(py_err_se_err)?;
+            iso_dt.serialize(serializer)
         }
         ObType::Date => {
             let py_date = value.downcast().map_err(py_err_se_err)?;
-            let iso_date = super::type_serializers::datetime_etc::date_to_string(py_date).map_err(py_err_se_err)?;
-            serializer.serialize_str(&iso_date)
+            let iso_date =
+                super::type_serializers::datetime_etc::date_to_json(py_date, &extra.config.temporal_format, false)
+                    .map_err(py_err_se_err)?;
+            iso_date.serialize(serializer)
         }
         ObType::Time => {
             let py_time = value.downcast().map_err(py_err_se_err)?;
-            let iso_time = super::type_serializers::datetime_etc::time_to_string(py_time).map_err(py_err_se_err)?;
-            serializer.serialize_str(&iso_time)
+            let iso_time =
+                super::type_serializers::datetime_etc::time_to_json(py_time, &extra.config.temporal_format, false)
+                    .map_err(py_err_se_err)?;
+            iso_time.serialize(serializer)
         }
         ObType::Timedelta => {
             let either_delta = EitherTimedelta::try_from(value).map_err(py_err_se_err)?;
@@ -477,11 +525,15 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         }
         ObType::Url => {
             let py_url: PyUrl = value.extract().map_err(py_err_se_err)?;
-            serializer.serialize_str(py_url.__str__())
+            serializer.serialize_str(py_url.serialized_str(extra.round_trip))
         }
         ObType::MultiHostUrl => {
             let py_url: PyMultiHostUrl = value.extract().map_err(py_err_se_err)?;
-            serializer.serialize_str(&py_url.__str__())
+            serializer.serialize_str(&py_url.serialized_str(extra.round_trip))
+        }
+        ObType::Email => {
+            let py_email: PyEmailAddress = value.extract().map_err(py_err_se_err)?;
+            serializer.serialize_str(&py_email.__str__())
         }
         ObType::PydanticSerializable => {
             let py = value.py();
@@ -489,21 +541,26 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
                 .getattr(intern!(py, "__pydantic_serializer__"))
                 .map_err(py_err_se_err)?;
             let extracted_serializer: PyRef<SchemaSerializer> = py_serializer.extract().map_err(py_err_se_err)?;
-            let extra = extracted_serializer.build_extra(
-                py,
-                extra.mode,
-                extra.by_alias,
-                extra.warnings,
-                extra.exclude_unset,
-                extra.exclude_defaults,
-                extra.exclude_none,
-                extra.round_trip,
-                extra.rec_guard,
-                extra.serialize_unknown,
-                extra.fallback,
-                extra.duck_typing_ser_mode,
-                extra.context,
-            );
+            let extra = Extra {
+                sort_keys: extra.sort_keys,
+                set_order: extra.set_order,
+                canonical: extra.canonical,
+                ..extracted_serializer.build_extra(
+                    py,
+                    extra.mode,
+                    extra.by_alias,
+                    extra.warnings,
+                    extra.exclude_unset,
+                    extra.exclude_defaults,
+                    extra.exclude_none,
+                    extra.round_trip,
+                    extra.rec_guard,
+                    extra.serialize_unknown,
+                    extra.fallback,
+                    extra.duck_typing_ser_mode,
+                    extra.context,
+                )
+            };
             let pydantic_serializer =
                 PydanticSerializer::new(value, &extracted_serializer.serializer, include, exclude, &extra);
             pydantic_serializer.serialize(serializer)
@@ -512,12 +569,11 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
             let (pairs_iter, fields_dict) = any_dataclass_iter(value).map_err(py_err_se_err)?;
             serialize_pairs_json(pairs_iter, fields_dict.len(), serializer, include, exclude, extra)
         }
-        ObType::Uuid => {
-            let uuid = super::type_serializers::uuid::uuid_to_string(value).map_err(py_err_se_err)?;
-            serializer.serialize_str(&uuid)
-        }
+        ObType::Uuid => super::type_serializers::uuid::serialize_uuid(value, extra.config.uuid_mode, serializer),
         ObType::Enum => {
-            let v = value.getattr(intern!(value.py(), "value")).map_err(py_err_se_err)?;
+            let v = value
+                .getattr(extra.config.enum_mode.attribute(value.py()))
+                .map_err(py_err_se_err)?;
             infer_serialize(&v, serializer, include, exclude, extra)
         }
         ObType::Generator => {
@@ -552,7 +608,9 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
             serializer.serialize_str(&s)
         }
         ObType::Unknown => {
-            if let Some(fallback) = extra.fallback {
+            if value.is_instance_of::<PySecret>() {
+                return SecretStrSerializer.serde_serialize(value, serializer, include, exclude, extra);
+            } else if let Some(fallback) = extra.fallback {
                 let next_value = fallback.call1((value,)).map_err(py_err_se_err)?;
                 let next_result = infer_serialize(&next_value, serializer, include, exclude, extra);
                 return next_result;
@@ -603,6 +661,7 @@ pub(crate) fn infer_json_key_known<'a>(
         ObType::Int | ObType::IntSubclass => super::type_serializers::simple::to_str_json_key(key),
         ObType::Float | ObType::FloatSubclass => {
             let v = key.extract::<f64>()?;
+            super::type_serializers::float::check_inf_nan(v, extra.config.inf_nan_mode)?;
             if (v.is_nan() || v.is_infinite()) && extra.config.inf_nan_mode == InfNanMode::Null {
                 super::type_serializers::simple::none_json_key()
             } else {
@@ -611,7 +670,9 @@ pub(crate) fn infer_json_key_known<'a>(
         }
         ObType::Decimal => Ok(Cow::Owned(key.to_string())),
         ObType::Bool => super::type_serializers::simple::bool_json_key(key),
-        ObType::Str | ObType::StrSubclass => key.downcast::<PyString>()?.to_cow(),
+        ObType::Str | ObType::StrSubclass => {
+            super::type_serializers::string::json_key_py_str(key.downcast()?, extra.config.invalid_utf8_mode)
+        }
         ObType::Bytes => extra
             .config
             .bytes_mode
@@ -629,19 +690,31 @@ pub(crate) fn infer_json_key_known<'a>(
             .map(|cow| Cow::Owned(cow.into_owned()))
         }
         ObType::Datetime => {
-            let iso_dt = super::type_serializers::datetime_etc::datetime_to_string(key.downcast()?)?;
-            Ok(Cow::Owned(iso_dt))
+            let iso_dt = super::type_serializers::datetime_etc::datetime_to_json(
+                key.downcast()?,
+                &extra.config.temporal_format,
+                false,
+            )?;
+            Ok(Cow::Owned(iso_dt.into_string()))
         }
         ObType::Date => {
-            let iso_date = super::type_serializers::datetime_etc::date_to_string(key.downcast()?)?;
-            Ok(Cow::Owned(iso_date))
+            let iso_date = super::type_serializers::datetime_etc::date_to_json(
+                key.downcast()?,
+                &extra.config.temporal_format,
+                false,
+            )?;
+            Ok(Cow::Owned(iso_date.into_string()))
         }
         ObType::Time => {
-            let iso_time = super::type_serializers::datetime_etc::time_to_string(key.downcast()?)?;
-            Ok(Cow::Owned(iso_time))
+            let iso_time = super::type_serializers::datetime_etc::time_to_json(
+                key.downcast()?,
+                &extra.config.temporal_format,
+                false,
+            )?;
+            Ok(Cow::Owned(iso_time.into_string()))
         }
         ObType::Uuid => {
-            let uuid = super::type_serializers::uuid::uuid_to_string(key)?;
+            let uuid = super::type_serializers::uuid::uuid_json_key(key, extra.config.uuid_mode)?;
             Ok(Cow::Owned(uuid))
         }
         ObType::Timedelta => {
@@ -650,11 +723,15 @@ pub(crate) fn infer_json_key_known<'a>(
         }
         ObType::Url => {
             let py_url: PyUrl = key.extract()?;
-            Ok(Cow::Owned(py_url.__str__().to_string()))
+            Ok(Cow::Owned(py_url.serialized_str(extra.round_trip).to_string()))
         }
         ObType::MultiHostUrl => {
             let py_url: PyMultiHostUrl = key.extract()?;
-            Ok(Cow::Owned(py_url.__str__()))
+            Ok(Cow::Owned(py_url.serialized_str(extra.round_trip).into_owned()))
+        }
+        ObType::Email => {
+            let py_email: PyEmailAddress = key.extract()?;
+            Ok(Cow::Owned(py_email.__str__()))
         }
         ObType::Tuple => {
             let mut key_build = super::type_serializers::tuple::KeyBuilder::new();
@@ -673,7 +750,7 @@ pub(crate) fn infer_json_key_known<'a>(
             Ok(Cow::Owned(key))
         }
         ObType::Enum => {
-            let k = key.getattr(intern!(key.py(), "value"))?;
+            let k = key.getattr(extra.config.enum_mode.attribute(key.py()))?;
             infer_json_key(&k, extra).map(|cow| Cow::Owned(cow.into_owned()))
         }
         ObType::Path => {
@@ -691,7 +768,9 @@ pub(crate) fn infer_json_key_known<'a>(
                 .into_owned(),
         )),
         ObType::Unknown => {
-            if let Some(fallback) = extra.fallback {
+            if key.is_instance_of::<PySecret>() {
+                SecretStrSerializer.json_key(key, extra)
+            } else if let Some(fallback) = extra.fallback {
                 let next_key = fallback.call1((key,))?;
                 infer_json_key(&next_key, extra).map(|cow| Cow::Owned(cow.into_owned()))
             } else if extra.serialize_unknown {
@@ -723,7 +802,7 @@ fn serialize_pairs_python<'py>(
             new_dict.set_item(k, v)?;
         }
     }
-    Ok(new_dict.into())
+    Ok(sorted_dict(new_dict, extra)?.into())
 }
 
 fn serialize_pairs_json<'py, S: Serializer>(
@@ -736,6 +815,11 @@ fn serialize_pairs_json<'py, S: Serializer>(
 ) -> Result<S::Ok, S::Error> {
     let mut map = serializer.serialize_map(Some(iter_size))?;
     let filter = AnyFilter::new();
+    let mut members = ObjectMembers::new(extra);
+    let write_member = |map: &mut S::SerializeMap, key: &str, (value, next_include, next_exclude): FilteredValue| {
+        let value_serializer = SerializeInfer::new(&value, next_include.as_ref(), next_exclude.as_ref(), extra);
+        map.serialize_entry(key, &value_serializer)
+    };
 
     for result in pairs_iter {
         let (key, value) = result.map_err(py_err_se_err)?;
@@ -743,9 +827,13 @@ fn serialize_pairs_json<'py, S: Serializer>(
         let op_next = filter.key_filter(&key, include, exclude).map_err(py_err_se_err)?;
         if let Some((next_include, next_exclude)) = op_next {
             let key = infer_json_key(&key, extra).map_err(py_err_se_err)?;
-            let value_serializer = SerializeInfer::new(&value, next_include.as_ref(), next_exclude.as_ref(), extra);
-            map.serialize_entry(&key, &value_serializer)?;
+            if let Some((key, member)) = members.add(key, (value, next_include, next_exclude)) {
+                write_member(&mut map, &key, member)?;
+            }
         }
     }
+    for (key, member) in members.into_sorted() {
+        write_member(&mut map, &key, member)?;
+    }
     map.end()
 }
@@ -1,29 +1,42 @@
 use std::fmt::Debug;
+use std::io::{self, Write};
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyDict, PyTuple, PyType};
 use pyo3::{PyTraverseError, PyVisit};
 
+use crate::build_tools::normalize_schema;
 use crate::definitions::{Definitions, DefinitionsBuilder};
 use crate::py_gc::PyGcTraverse;
 
-pub(crate) use config::BytesMode;
 use config::SerializationConfig;
+pub(crate) use config::{base32_decode, BytesMode};
+use copy::Copier;
 pub use errors::{PydanticSerializationError, PydanticSerializationUnexpectedValue};
 use extra::{CollectWarnings, SerRecursionState, WarningsMode};
 pub(crate) use extra::{DuckTypingSerMode, Extra, SerMode, SerializationState};
+use format::JsonFormat;
+pub(crate) use ob_type::{ObType, ObTypeLookup};
+pub use ordering::SetOrder;
+use output::{JsonOutput, PyFileWriter, TempFile};
 pub use shared::CombinedSerializer;
-use shared::{to_json_bytes, TypeSerializer};
+use shared::{to_json_bytes, to_json_writer, TypeSerializer};
 
+mod canonical;
 mod computed_fields;
 mod config;
+mod copy;
 mod errors;
 mod extra;
 mod fields;
 mod filter;
+mod format;
 mod infer;
 mod ob_type;
+mod ordering;
+mod output;
 mod prebuilt;
 pub mod ser;
 mod shared;
@@ -83,6 +96,64 @@ impl SchemaSerializer {
             context,
         )
     }
+
+    /// Serialize `value` to JSON, shared by `to_json()` and `to_json_into()`
+    #[allow(clippy::too_many_arguments)]
+    fn write_json<W: io::Write>(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        writer: W,
+        format: &JsonFormat,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: WarningsArg,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+        serialize_secrets: bool,
+        missing_value: Option<&Bound<'_, PyAny>>,
+        sort_keys: bool,
+        set_order: SetOrder,
+        canonical: bool,
+    ) -> PyResult<W> {
+        check_canonical_format(canonical, format)?;
+        let warnings_mode = match warnings {
+            WarningsArg::Bool(b) => b.into(),
+            WarningsArg::Literal(mode) => mode,
+        };
+        let warnings = CollectWarnings::new(warnings_mode);
+        let rec_guard = SerRecursionState::default();
+        let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
+        let mut extra = self.build_extra(
+            py,
+            &SerMode::Json,
+            by_alias,
+            &warnings,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            round_trip,
+            &rec_guard,
+            false,
+            fallback,
+            duck_typing_ser_mode,
+            context,
+        );
+        extra.serialize_secrets = serialize_secrets;
+        extra.missing_value = missing_value;
+        extra.sort_keys = sort_keys;
+        extra.set_order = set_order;
+        extra.canonical = canonical;
+        let writer = to_json_writer(value, &self.serializer, include, exclude, &extra, format, writer)?;
+        warnings.final_check(py)?;
+        Ok(writer)
+    }
 }
 
 #[pymethods]
@@ -108,7 +179,7 @@ impl SchemaSerializer {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, mode = None, include = None, exclude = None, by_alias = None,
         exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = WarningsArg::Bool(true),
-        fallback = None, serialize_as_any = false, context = None))]
+        fallback = None, serialize_as_any = false, context = None, serialize_secrets = false, missing_value = None))]
     pub fn to_python(
         &self,
         py: Python,
@@ -125,6 +196,8 @@ impl SchemaSerializer {
         fallback: Option<&Bound<'_, PyAny>>,
         serialize_as_any: bool,
         context: Option<&Bound<'_, PyAny>>,
+        serialize_secrets: bool,
+        missing_value: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<PyObject> {
         let mode: SerMode = mode.into();
         let warnings_mode = match warnings {
@@ -134,7 +207,7 @@ impl SchemaSerializer {
         let warnings = CollectWarnings::new(warnings_mode);
         let rec_guard = SerRecursionState::default();
         let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
-        let extra = self.build_extra(
+        let mut extra = self.build_extra(
             py,
             &mode,
             by_alias,
@@ -149,6 +222,8 @@ impl SchemaSerializer {
             duck_typing_ser_mode,
             context,
         );
+        extra.serialize_secrets = serialize_secrets;
+        extra.missing_value = missing_value;
         let v = self.serializer.to_python(value, include, exclude, &extra)?;
         warnings.final_check(py)?;
         Ok(v)
@@ -157,7 +232,9 @@ impl SchemaSerializer {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = None,
         exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = WarningsArg::Bool(true),
-        fallback = None, serialize_as_any = false, context = None))]
+        fallback = None, serialize_as_any = false, context = None, serialize_secrets = false, missing_value = None,
+        sort_keys = false, set_order = SetOrder::Insertion, canonical = false, separators = None, newline = None,
+        ensure_ascii = false))]
     pub fn to_json(
         &self,
         py: Python,
@@ -174,46 +251,142 @@ impl SchemaSerializer {
         fallback: Option<&Bound<'_, PyAny>>,
         serialize_as_any: bool,
         context: Option<&Bound<'_, PyAny>>,
+        serialize_secrets: bool,
+        missing_value: Option<&Bound<'_, PyAny>>,
+        sort_keys: bool,
+        set_order: SetOrder,
+        canonical: bool,
+        separators: Option<(String, String)>,
+        newline: Option<String>,
+        ensure_ascii: bool,
     ) -> PyResult<PyObject> {
-        let warnings_mode = match warnings {
-            WarningsArg::Bool(b) => b.into(),
-            WarningsArg::Literal(mode) => mode,
+        let format = JsonFormat {
+            indent,
+            separators,
+            newline,
+            ensure_ascii,
         };
-        let warnings = CollectWarnings::new(warnings_mode);
-        let rec_guard = SerRecursionState::default();
-        let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
-        let extra = self.build_extra(
+        let writer = Vec::with_capacity(self.expected_json_size.load(Ordering::Relaxed));
+        let bytes = self.write_json(
             py,
-            &SerMode::Json,
+            value,
+            writer,
+            &format,
+            include,
+            exclude,
             by_alias,
-            &warnings,
             exclude_unset,
             exclude_defaults,
             exclude_none,
             round_trip,
-            &rec_guard,
-            false,
+            warnings,
             fallback,
-            duck_typing_ser_mode,
+            serialize_as_any,
             context,
-        );
-        let bytes = to_json_bytes(
-            value,
-            &self.serializer,
-            include,
-            exclude,
-            &extra,
-            indent,
-            self.expected_json_size.load(Ordering::Relaxed),
+            serialize_secrets,
+            missing_value,
+            sort_keys,
+            set_order,
+            canonical,
         )?;
 
-        warnings.final_check(py)?;
-
         self.expected_json_size.store(bytes.len(), Ordering::Relaxed);
         let py_bytes = PyBytes::new(py, &bytes);
         Ok(py_bytes.into())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, file, *, buffer_size = 65536, indent = None, include = None, exclude = None,
+        by_alias = None, exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false,
+        warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None,
+        serialize_secrets = false, missing_value = None, sort_keys = false, set_order = SetOrder::Insertion,
+        canonical = false, separators = None, newline = None, ensure_ascii = false))]
+    pub fn to_json_into(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        file: JsonOutput<'_>,
+        buffer_size: usize,
+        indent: Option<usize>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: WarningsArg,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+        serialize_secrets: bool,
+        missing_value: Option<&Bound<'_, PyAny>>,
+        sort_keys: bool,
+        set_order: SetOrder,
+        canonical: bool,
+        separators: Option<(String, String)>,
+        newline: Option<String>,
+        ensure_ascii: bool,
+    ) -> PyResult<()> {
+        if buffer_size == 0 {
+            return Err(PyValueError::new_err("`buffer_size` must be greater than 0"));
+        }
+        let format = JsonFormat {
+            indent,
+            separators,
+            newline,
+            ensure_ascii,
+        };
+        macro_rules! write_json {
+            ($writer:expr) => {
+                self.write_json(
+                    py,
+                    value,
+                    $writer,
+                    &format,
+                    include,
+                    exclude,
+                    by_alias,
+                    exclude_unset,
+                    exclude_defaults,
+                    exclude_none,
+                    round_trip,
+                    warnings,
+                    fallback,
+                    serialize_as_any,
+                    context,
+                    serialize_secrets,
+                    missing_value,
+                    sort_keys,
+                    set_order,
+                    canonical,
+                )
+            };
+        }
+        match file {
+            JsonOutput::Path(path) => {
+                let (temp_file, writer) = TempFile::create(path, buffer_size)?;
+                let writer = write_json!(writer)?;
+                drop(writer.into_inner().map_err(io::IntoInnerError::into_error)?);
+                temp_file.persist()?;
+            }
+            JsonOutput::File(file) => {
+                let mut writer = PyFileWriter::new(file, buffer_size);
+                // errors raised by the file's `write()` are raised as they are, not as serialization errors
+                let result = write_json!(&mut writer).and_then(|writer| Ok(writer.flush()?));
+                if let Some(err) = writer.error.take() {
+                    return Err(err);
+                }
+                result?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn dump_schema<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        normalize_schema(self.py_schema.bind(py).as_any())
+    }
+
     pub fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
         let init_args = (&slf.get().py_schema, &slf.get().py_config).into_pyobject(slf.py())?;
         Ok((slf.get_type(), init_args))
@@ -241,8 +414,9 @@ impl SchemaSerializer {
 #[pyfunction]
 #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
     exclude_none = false, round_trip = false, timedelta_mode = "iso8601", bytes_mode = "utf8",
-    inf_nan_mode = "constants", serialize_unknown = false, fallback = None, serialize_as_any = false,
-    context = None))]
+    inf_nan_mode = "constants", temporal_format = "iso8601", serialize_unknown = false, fallback = None,
+    serialize_as_any = false, context = None, serialize_secrets = false, sort_keys = false,
+    set_order = SetOrder::Insertion, canonical = false, separators = None, newline = None, ensure_ascii = false))]
 pub fn to_json(
     py: Python,
     value: &Bound<'_, PyAny>,
@@ -255,14 +429,29 @@ pub fn to_json(
     timedelta_mode: &str,
     bytes_mode: &str,
     inf_nan_mode: &str,
+    temporal_format: &str,
     serialize_unknown: bool,
     fallback: Option<&Bound<'_, PyAny>>,
     serialize_as_any: bool,
     context: Option<&Bound<'_, PyAny>>,
+    serialize_secrets: bool,
+    sort_keys: bool,
+    set_order: SetOrder,
+    canonical: bool,
+    separators: Option<(String, String)>,
+    newline: Option<String>,
+    ensure_ascii: bool,
 ) -> PyResult<PyObject> {
-    let state = SerializationState::new(timedelta_mode, bytes_mode, inf_nan_mode)?;
+    let format = JsonFormat {
+        indent,
+        separators,
+        newline,
+        ensure_ascii,
+    };
+    check_canonical_format(canonical, &format)?;
+    let state = SerializationState::new(timedelta_mode, bytes_mode, inf_nan_mode, temporal_format)?;
     let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
-    let extra = state.extra(
+    let mut extra = state.extra(
         py,
         &SerMode::Json,
         Some(by_alias),
@@ -273,18 +462,43 @@ pub fn to_json(
         duck_typing_ser_mode,
         context,
     );
+    extra.serialize_secrets = serialize_secrets;
+    extra.sort_keys = sort_keys;
+    extra.set_order = set_order;
+    extra.canonical = canonical;
     let serializer = type_serializers::any::AnySerializer.into();
-    let bytes = to_json_bytes(value, &serializer, include, exclude, &extra, indent, 1024)?;
+    let bytes = to_json_bytes(value, &serializer, include, exclude, &extra, &format, 1024)?;
     state.final_check(py)?;
     let py_bytes = PyBytes::new(py, &bytes);
     Ok(py_bytes.into())
 }
 
+fn check_canonical_format(canonical: bool, format: &JsonFormat) -> PyResult<()> {
+    if !canonical {
+        return Ok(());
+    }
+    let conflict = if format.indent.is_some() {
+        "indent"
+    } else if format.separators.is_some() {
+        "separators"
+    } else if format.newline.is_some() {
+        "newline"
+    } else if format.ensure_ascii {
+        "ensure_ascii"
+    } else {
+        return Ok(());
+    };
+    Err(PyValueError::new_err(format!(
+        "`{conflict}` cannot be used with `canonical=True`"
+    )))
+}
+
 #[allow(clippy::too_many_arguments)]
 #[pyfunction]
 #[pyo3(signature = (value, *, include = None, exclude = None, by_alias = true, exclude_none = false, round_trip = false,
-    timedelta_mode = "iso8601", bytes_mode = "utf8", inf_nan_mode = "constants", serialize_unknown = false, fallback = None,
-    serialize_as_any = false, context = None))]
+    timedelta_mode = "iso8601", bytes_mode = "utf8", inf_nan_mode = "constants", temporal_format = "iso8601",
+    serialize_unknown = false, fallback = None, serialize_as_any = false, context = None, serialize_secrets = false,
+    sort_keys = false, set_order = SetOrder::Insertion))]
 pub fn to_jsonable_python(
     py: Python,
     value: &Bound<'_, PyAny>,
@@ -296,14 +510,18 @@ pub fn to_jsonable_python(
     timedelta_mode: &str,
     bytes_mode: &str,
     inf_nan_mode: &str,
+    temporal_format: &str,
     serialize_unknown: bool,
     fallback: Option<&Bound<'_, PyAny>>,
     serialize_as_any: bool,
     context: Option<&Bound<'_, PyAny>>,
+    serialize_secrets: bool,
+    sort_keys: bool,
+    set_order: SetOrder,
 ) -> PyResult<PyObject> {
-    let state = SerializationState::new(timedelta_mode, bytes_mode, inf_nan_mode)?;
+    let state = SerializationState::new(timedelta_mode, bytes_mode, inf_nan_mode, temporal_format)?;
     let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
-    let extra = state.extra(
+    let mut extra = state.extra(
         py,
         &SerMode::Json,
         Some(by_alias),
@@ -314,7 +532,23 @@ pub fn to_jsonable_python(
         duck_typing_ser_mode,
         context,
     );
+    extra.serialize_secrets = serialize_secrets;
+    extra.sort_keys = sort_keys;
+    extra.set_order = set_order;
     let v = infer::infer_to_python(value, include, exclude, &extra)?;
     state.final_check(py)?;
     Ok(v)
 }
+
+/// Copies `value` as `copy.deepcopy` or `copy.copy` would, with models and builtin containers copied in rust
+#[pyfunction]
+#[pyo3(signature = (value, *, deep = true))]
+pub fn copy_validated<'py>(value: &Bound<'py, PyAny>, deep: bool) -> PyResult<Bound<'py, PyAny>> {
+    Copier::new(value.py(), deep).copy(value)
+}
+
+/// Registers `cls` to be serialized by inference, and validated in lax mode, like the type named by `ob_type`
+#[pyfunction]
+pub fn register_ob_type(cls: &Bound<'_, PyType>, ob_type: &str) -> PyResult<()> {
+    ObTypeLookup::cached(cls.py()).register(cls, ob_type)
+}
@@ -1,3 +1,6 @@
+use std::sync::RwLock;
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
 use pyo3::types::{
@@ -6,12 +9,14 @@ use pyo3::types::{
 };
 use pyo3::{intern, PyTypeInfo};
 
+use ahash::AHashMap;
 use strum::Display;
 use strum_macros::EnumString;
 
+use crate::email::PyEmailAddress;
 use crate::url::{PyMultiHostUrl, PyUrl};
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct ObTypeLookup {
     // valid JSON types
     none: usize,
@@ -38,6 +43,7 @@ pub struct ObTypeLookup {
     // types from this package
     url: usize,
     multi_host_url: usize,
+    email: usize,
     // enum type
     enum_object: PyObject,
     // generator
@@ -49,6 +55,8 @@ pub struct ObTypeLookup {
     // uuid type
     uuid_object: PyObject,
     complex: usize,
+    // types registered with `register_ob_type`, the type is kept so its pointer remains valid
+    registered: RwLock<AHashMap<usize, (Py<PyType>, ObType)>>,
 }
 
 static TYPE_LOOKUP: GILOnceCell<ObTypeLookup> = GILOnceCell::new();
@@ -82,12 +90,14 @@ impl ObTypeLookup {
             timedelta: PyDelta::type_object_raw(py) as usize,
             url: PyUrl::type_object_raw(py) as usize,
             multi_host_url: PyMultiHostUrl::type_object_raw(py) as usize,
+            email: PyEmailAddress::type_object_raw(py) as usize,
             enum_object: py.import("enum").unwrap().getattr("Enum").unwrap().unbind(),
             generator_object: py.import("types").unwrap().getattr("GeneratorType").unwrap().unbind(),
             path_object: py.import("pathlib").unwrap().getattr("Path").unwrap().unbind(),
             pattern_object: py.import("re").unwrap().getattr("Pattern").unwrap().unbind(),
             uuid_object: py.import("uuid").unwrap().getattr("UUID").unwrap().unbind(),
             complex: PyComplex::type_object_raw(py) as usize,
+            registered: RwLock::new(AHashMap::new()),
         }
     }
 
@@ -95,10 +105,61 @@ impl ObTypeLookup {
         TYPE_LOOKUP.get_or_init(py, || Self::new(py))
     }
 
+    /// Registers `py_type` to be treated as `ob_type` by inference and type checks in serializers,
+    /// only types which can be converted from an instance of `py_type` are supported
+    pub fn register(&self, py_type: &Bound<'_, PyType>, ob_type: &str) -> PyResult<()> {
+        let py = py_type.py();
+        let (ob_type, base_type) = match ob_type {
+            // values are converted with `__index__`, `__float__` and `str()` respectively
+            "int" => (ObType::IntSubclass, None),
+            "float" => (ObType::FloatSubclass, None),
+            "decimal" => (ObType::Decimal, None),
+            "path" => (ObType::Path, None),
+            // these are serialized using the builtin type, so `py_type` must be a subclass of it
+            "str" => (ObType::StrSubclass, Some(PyString::type_object(py))),
+            "bytes" => (ObType::Bytes, Some(PyBytes::type_object(py))),
+            "datetime" => (ObType::Datetime, Some(PyDateTime::type_object(py))),
+            "date" => (ObType::Date, Some(PyDate::type_object(py))),
+            "time" => (ObType::Time, Some(PyTime::type_object(py))),
+            "timedelta" => (ObType::Timedelta, Some(PyDelta::type_object(py))),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unsupported ob_type `{ob_type}`, expected one of \
+                    'int', 'float', 'decimal', 'path', 'str', 'bytes', 'datetime', 'date', 'time' or 'timedelta'"
+                )))
+            }
+        };
+        if let Some(base_type) = base_type {
+            if !py_type.is_subclass(&base_type)? {
+                return Err(PyTypeError::new_err(format!(
+                    "`{}` must be a subclass of `{}` to be registered as `{ob_type}`",
+                    py_type.qualname()?,
+                    base_type.qualname()?,
+                )));
+            }
+        }
+        let mut registered = self.registered.write().expect("registered types lock poisoned");
+        registered.insert(py_type.as_ptr() as usize, (py_type.clone().unbind(), ob_type));
+        Ok(())
+    }
+
+    /// The `ObType` `py_type` was registered as, base types are not considered
+    pub fn registered(&self, py_type: &Bound<'_, PyType>) -> Option<ObType> {
+        let registered = self.registered.read().expect("registered types lock poisoned");
+        if registered.is_empty() {
+            return None;
+        }
+        registered
+            .get(&(py_type.as_ptr() as usize))
+            .map(|(_, ob_type)| *ob_type)
+    }
+
     pub fn is_type(&self, value: &Bound<'_, PyAny>, expected_ob_type: ObType) -> IsType {
         match self.ob_type_is_expected(Some(value), &value.get_type(), &expected_ob_type) {
             IsType::False => {
-                if expected_ob_type == self.fallback_isinstance(value) {
+                if self.registered(&value.get_type()) == Some(expected_ob_type)
+                    || expected_ob_type == self.fallback_isinstance(value)
+                {
                     IsType::Subclass
                 } else {
                     IsType::False
@@ -150,6 +211,7 @@ impl ObTypeLookup {
             ObType::Bytearray => self.bytearray == ob_type,
             ObType::Url => self.url == ob_type,
             ObType::MultiHostUrl => self.multi_host_url == ob_type,
+            ObType::Email => self.email == ob_type,
             ObType::Dataclass => is_dataclass(op_value),
             ObType::PydanticSerializable => is_pydantic_serializable(op_value),
             ObType::Enum => self.enum_object.as_ptr() as usize == ob_type,
@@ -237,10 +299,14 @@ impl ObTypeLookup {
             ObType::Url
         } else if ob_type == self.multi_host_url {
             ObType::MultiHostUrl
+        } else if ob_type == self.email {
+            ObType::Email
         } else if ob_type == self.complex {
             ObType::Complex
         } else if ob_type == self.uuid_object.as_ptr() as usize {
             ObType::Uuid
+        } else if let Some(ob_type) = self.registered(py_type) {
+            ob_type
         } else if is_pydantic_serializable(op_value) {
             ObType::PydanticSerializable
         } else if is_dataclass(op_value) {
@@ -321,6 +387,8 @@ impl ObTypeLookup {
             ObType::Url
         } else if value.is_instance_of::<PyMultiHostUrl>() {
             ObType::MultiHostUrl
+        } else if value.is_instance_of::<PyEmailAddress>() {
+            ObType::Email
         } else if value.is_instance(self.decimal_object.bind(py)).unwrap_or(false) {
             ObType::Decimal
         } else if value.is_instance(self.uuid_object.bind(py)).unwrap_or(false) {
@@ -400,6 +468,7 @@ pub enum ObType {
     // types from this package
     Url,
     MultiHostUrl,
+    Email,
     // anything with __pydantic_serializer__, including BaseModel and pydantic dataclasses
     PydanticSerializable,
     // vanilla dataclasses
@@ -0,0 +1,132 @@
+//! Deterministic ordering of serialized output, see the `sort_keys` and `set_order` arguments of `to_json`
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use super::canonical::compare_utf16;
+use super::extra::Extra;
+
+/// How elements of sets and frozensets are ordered when they're serialized to a JSON array
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SetOrder {
+    /// iteration order of the set, this depends on hashes so may differ between processes
+    #[default]
+    Insertion,
+    /// elements sorted by value, falling back to sorting by type name then `repr()` if values aren't comparable
+    Sorted,
+}
+
+impl<'py> FromPyObject<'py> for SetOrder {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        match ob.extract::<&str>() {
+            Ok("insertion") => Ok(Self::Insertion),
+            Ok("sorted") => Ok(Self::Sorted),
+            Ok(s) => Err(PyValueError::new_err(format!(
+                "Invalid set_order value: '{s}', expected 'insertion' or 'sorted'"
+            ))),
+            Err(_) => Err(PyTypeError::new_err(
+                "Invalid set_order value, expected 'insertion' or 'sorted'",
+            )),
+        }
+    }
+}
+
+impl SetOrder {
+    /// Elements of `set` in the order they should be serialized
+    pub fn elements<'py>(self, set: &Bound<'py, PyAny>) -> PyResult<Vec<Bound<'py, PyAny>>> {
+        let elements = set.try_iter()?.collect::<PyResult<Vec<_>>>()?;
+        match self {
+            Self::Insertion => Ok(elements),
+            Self::Sorted => sort_values(elements),
+        }
+    }
+}
+
+fn sort_values(elements: Vec<Bound<'_, PyAny>>) -> PyResult<Vec<Bound<'_, PyAny>>> {
+    let Some(first) = elements.first() else {
+        return Ok(elements);
+    };
+    let list = PyList::new(first.py(), &elements)?;
+    if list.sort().is_ok() {
+        return Ok(list.iter().collect());
+    }
+    // values can't be compared with each other (e.g. `{1, 'a'}`), so use a key which always can be
+    let mut keyed = elements
+        .into_iter()
+        .map(|element| {
+            let type_name = element.get_type().qualname()?.to_string();
+            let repr = element.repr()?.to_string();
+            Ok(((type_name, repr), element))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(keyed.into_iter().map(|(_, element)| element).collect())
+}
+
+/// How the keys of JSON objects are compared when they're sorted, `None` if members keep their order,
+/// from the `sort_keys` and `canonical` arguments
+pub(crate) fn key_order(extra: &Extra) -> Option<fn(&str, &str) -> Ordering> {
+    if extra.canonical {
+        Some(compare_utf16)
+    } else if extra.sort_keys {
+        Some(str::cmp)
+    } else {
+        None
+    }
+}
+
+/// A value with the include and exclude filters which apply to it, held by `ObjectMembers`
+pub(crate) type FilteredValue<'py> = (Bound<'py, PyAny>, Option<Bound<'py, PyAny>>, Option<Bound<'py, PyAny>>);
+
+/// Members of a JSON object which are written as they're added, unless keys are sorted, then they're held
+/// until all members have been added and written in order from `into_sorted`
+pub(crate) struct ObjectMembers<T> {
+    compare: Option<fn(&str, &str) -> Ordering>,
+    held: Vec<(String, T)>,
+}
+
+impl<T> ObjectMembers<T> {
+    pub fn new(extra: &Extra) -> Self {
+        Self {
+            compare: key_order(extra),
+            held: Vec::new(),
+        }
+    }
+
+    /// The member back if it should be written now, otherwise it's held
+    pub fn add<'k>(&mut self, key: Cow<'k, str>, member: T) -> Option<(Cow<'k, str>, T)> {
+        if self.compare.is_none() {
+            return Some((key, member));
+        }
+        self.held.push((key.into_owned(), member));
+        None
+    }
+
+    /// The held members sorted by key, members with equal keys keep their order
+    pub fn into_sorted(mut self) -> impl Iterator<Item = (String, T)> {
+        if let Some(compare) = self.compare {
+            self.held.sort_by(|(a, _), (b, _)| compare(a, b));
+        }
+        self.held.into_iter()
+    }
+}
+
+/// `dict` with its items sorted by key if `extra` sorts keys, for `to_python` in JSON mode
+pub(crate) fn sorted_dict<'py>(dict: Bound<'py, PyDict>, extra: &Extra) -> PyResult<Bound<'py, PyDict>> {
+    let Some(compare) = key_order(extra) else {
+        return Ok(dict);
+    };
+    let mut items = dict
+        .iter()
+        .map(|(key, value)| Ok((key.str()?.to_string(), key, value)))
+        .collect::<PyResult<Vec<_>>>()?;
+    items.sort_by(|(a, ..), (b, ..)| compare(a, b));
+    let sorted = PyDict::new(dict.py());
+    for (_, key, value) in items {
+        sorted.set_item(key, value)?;
+    }
+    Ok(sorted)
+}
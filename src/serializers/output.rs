@@ -0,0 +1,132 @@
+//! Destinations for `SchemaSerializer.to_json_into()`, JSON is written in chunks as it's serialized
+//! rather than being collected into a single `bytes` object
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use pyo3::exceptions::{PyOSError, PyTypeError};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyInt};
+
+/// Where `to_json_into()` writes to, either a path or a binary file-like object with a `write()` method
+pub enum JsonOutput<'py> {
+    Path(PathBuf),
+    File(Bound<'py, PyAny>),
+}
+
+impl<'py> FromPyObject<'py> for JsonOutput<'py> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(path) = ob.extract::<PathBuf>() {
+            Ok(Self::Path(path))
+        } else if ob.hasattr(intern!(ob.py(), "write"))? {
+            Ok(Self::File(ob.clone()))
+        } else {
+            Err(PyTypeError::new_err(
+                "Expected a path or a binary file-like object with a `write()` method",
+            ))
+        }
+    }
+}
+
+/// Buffers output and passes it to a python file-like object's `write()` in chunks of `buffer_size` bytes
+pub(crate) struct PyFileWriter<'py> {
+    file: Bound<'py, PyAny>,
+    buffer: Vec<u8>,
+    buffer_size: usize,
+    /// the first error raised by `write()`, so it can be raised instead of a generic serialization error
+    pub error: Option<PyErr>,
+}
+
+impl<'py> PyFileWriter<'py> {
+    pub fn new(file: Bound<'py, PyAny>, buffer_size: usize) -> Self {
+        Self {
+            file,
+            buffer: Vec::with_capacity(buffer_size),
+            buffer_size,
+            error: None,
+        }
+    }
+
+    fn write_buffer(&mut self) -> PyResult<()> {
+        let py = self.file.py();
+        let mut written = 0;
+        // unbuffered (raw) files may write fewer bytes than they're given, so write until they've all been taken
+        while written < self.buffer.len() {
+            let chunk = PyBytes::new(py, &self.buffer[written..]);
+            let result = self.file.call_method1(intern!(py, "write"), (chunk,))?;
+            written += match result.downcast::<PyInt>() {
+                Ok(count) => match count.extract::<usize>()? {
+                    0 => return Err(PyOSError::new_err("`write()` didn't accept any bytes")),
+                    count => count,
+                },
+                // `write()` doesn't have to return a count, e.g. if it isn't an `io` class, assume it took everything
+                Err(_) => self.buffer.len() - written,
+            };
+        }
+        self.buffer.clear();
+        Ok(())
+    }
+}
+
+impl Write for PyFileWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        if self.buffer.len() >= self.buffer_size {
+            self.flush()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_buffer().map_err(|err| {
+            let message = err.to_string();
+            self.error.get_or_insert(err);
+            io::Error::other(message)
+        })
+    }
+}
+
+/// A file next to `path` which JSON is written to before it replaces `path`,
+/// so `path` is left unchanged if serialization fails, the file is removed unless `persist()` is called
+pub(crate) struct TempFile {
+    temp_path: PathBuf,
+    path: PathBuf,
+    persisted: bool,
+}
+
+impl TempFile {
+    pub fn create(path: PathBuf, buffer_size: usize) -> PyResult<(Self, BufWriter<File>)> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let file_name = path.file_name().unwrap_or(path.as_os_str()).to_string_lossy();
+        let temp_path = path.with_file_name(format!(
+            ".{file_name}.{}.{}.tmp",
+            process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let file = OpenOptions::new().write(true).create_new(true).open(&temp_path)?;
+        let temp_file = Self {
+            temp_path,
+            path,
+            persisted: false,
+        };
+        Ok((temp_file, BufWriter::with_capacity(buffer_size, file)))
+    }
+
+    /// Replace `path` with the file, the file must be closed first
+    pub fn persist(mut self) -> PyResult<()> {
+        fs::rename(&self.temp_path, &self.path)?;
+        self.persisted = true;
+        Ok(())
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        if !self.persisted {
+            let _ = fs::remove_file(&self.temp_path);
+        }
+    }
+}
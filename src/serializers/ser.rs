@@ -4,6 +4,7 @@ use serde::{ser::Impossible, serde_if_integer128, Serialize, Serializer};
 use serde_json::ser::{CompactFormatter, Formatter, PrettyFormatter, State};
 
 use super::errors::PythonSerializerError;
+use super::type_serializers::string::{surrogatepass_chunks, SurrogatePassChunk};
 
 macro_rules! tri {
     ($e:expr $(,)?) => {
@@ -16,6 +17,9 @@ macro_rules! tri {
 
 type Result<T> = std::result::Result<T, PythonSerializerError>;
 const TOKEN: &str = "$serde_json::private::Number";
+/// Name and field of a struct holding a string with lone surrogates, encoded with python's `surrogatepass`
+/// error handler, the surrogates are written as `\uXXXX` escapes as `json.dumps()` does
+pub(crate) const SURROGATES_TOKEN: &str = "$pydantic_core::private::Surrogates";
 pub struct PythonSerializer<W, F = CompactFormatter> {
     writer: W,
     formatter: F,
@@ -356,6 +360,7 @@ where
     fn serialize_struct(self, name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
         match name {
             TOKEN => Ok(Compound::Number { ser: self }),
+            SURROGATES_TOKEN => Ok(Compound::Surrogates { ser: self }),
             _ => self.serialize_map(Some(len)),
         }
     }
@@ -415,7 +420,7 @@ where
                     .map_err(|e| PythonSerializerError { message: e.to_string() }));
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::Number { .. } | Compound::Surrogates { .. } => unreachable!(),
         }
     }
 
@@ -431,7 +436,7 @@ where
                 }
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::Number { .. } | Compound::Surrogates { .. } => unreachable!(),
         }
     }
 }
@@ -517,7 +522,7 @@ where
                     .map_err(|e| PythonSerializerError { message: e.to_string() }));
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::Number { .. } | Compound::Surrogates { .. } => unreachable!(),
         }
     }
 }
@@ -551,7 +556,7 @@ where
                     .map_err(|e| PythonSerializerError { message: e.to_string() }));
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::Number { .. } | Compound::Surrogates { .. } => unreachable!(),
         }
     }
 
@@ -573,7 +578,7 @@ where
                     .map_err(|e| PythonSerializerError { message: e.to_string() }));
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::Number { .. } | Compound::Surrogates { .. } => unreachable!(),
         }
     }
 
@@ -590,7 +595,7 @@ where
                 }
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::Number { .. } | Compound::Surrogates { .. } => unreachable!(),
         }
     }
 }
@@ -618,6 +623,14 @@ where
                     Err(invalid_number())
                 }
             }
+            Compound::Surrogates { ser, .. } => {
+                if key == SURROGATES_TOKEN {
+                    tri!(value.serialize(SurrogatesEmitter(ser)));
+                    Ok(())
+                } else {
+                    Err(invalid_surrogates())
+                }
+            }
         }
     }
 
@@ -625,7 +638,7 @@ where
     fn end(self) -> Result<()> {
         match self {
             Compound::Map { .. } => serde::ser::SerializeMap::end(self),
-            Compound::Number { .. } => Ok(()),
+            Compound::Number { .. } | Compound::Surrogates { .. } => Ok(()),
         }
     }
 }
@@ -645,7 +658,7 @@ where
     {
         match *self {
             Compound::Map { .. } => serde::ser::SerializeStruct::serialize_field(self, key, value),
-            Compound::Number { .. } => unreachable!(),
+            Compound::Number { .. } | Compound::Surrogates { .. } => unreachable!(),
         }
     }
 
@@ -670,12 +683,12 @@ where
                     .map_err(|e| PythonSerializerError { message: e.to_string() }));
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::Number { .. } | Compound::Surrogates { .. } => unreachable!(),
         }
     }
 }
 
-fn format_escaped_str<W, F>(writer: &mut W, formatter: &mut F, value: &str) -> io::Result<()>
+pub(crate) fn format_escaped_str<W, F>(writer: &mut W, formatter: &mut F, value: &str) -> io::Result<()>
 where
     W: ?Sized + io::Write,
     F: ?Sized + Formatter,
@@ -685,6 +698,24 @@ where
     formatter.end_string(writer)
 }
 
+/// Write a string encoded with python's `surrogatepass` error handler, lone surrogates are written as escapes
+fn format_escaped_surrogates<W, F>(writer: &mut W, formatter: &mut F, value: &[u8]) -> io::Result<()>
+where
+    W: ?Sized + io::Write,
+    F: ?Sized + Formatter,
+{
+    tri!(formatter.begin_string(writer));
+    for chunk in surrogatepass_chunks(value) {
+        match chunk {
+            SurrogatePassChunk::Str(s) => tri!(format_escaped_str_contents(writer, formatter, s)),
+            SurrogatePassChunk::Surrogate(unit) => {
+                tri!(formatter.write_string_fragment(writer, &format!("\\u{unit:04x}")));
+            }
+        }
+    }
+    formatter.end_string(writer)
+}
+
 fn format_escaped_str_contents<W, F>(writer: &mut W, formatter: &mut F, value: &str) -> io::Result<()>
 where
     W: ?Sized + io::Write,
@@ -757,6 +788,9 @@ pub enum Compound<'a, W: 'a, F: 'a> {
     Number {
         ser: &'a mut PythonSerializer<W, F>,
     },
+    Surrogates {
+        ser: &'a mut PythonSerializer<W, F>,
+    },
 }
 
 /// Represents a character escape code in a type-safe manner.
@@ -793,6 +827,11 @@ fn invalid_number() -> PythonSerializerError {
         message: "Invalid Number".to_string(),
     }
 }
+fn invalid_surrogates() -> PythonSerializerError {
+    PythonSerializerError {
+        message: "Invalid string with surrogates".to_string(),
+    }
+}
 
 impl<W, F> serde::ser::Serializer for MapKeySerializer<'_, W, F>
 where
@@ -1291,3 +1330,171 @@ impl<W: io::Write, F: Formatter> serde::ser::Serializer for NumberStrEmitter<'_,
         Err(invalid_number())
     }
 }
+
+struct SurrogatesEmitter<'a, W: 'a + io::Write, F: 'a + Formatter>(&'a mut PythonSerializer<W, F>);
+
+impl<W: io::Write, F: Formatter> serde::ser::Serializer for SurrogatesEmitter<'_, W, F> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    type SerializeSeq = Impossible<(), PythonSerializerError>;
+    type SerializeTuple = Impossible<(), PythonSerializerError>;
+    type SerializeTupleStruct = Impossible<(), PythonSerializerError>;
+    type SerializeTupleVariant = Impossible<(), PythonSerializerError>;
+    type SerializeMap = Impossible<(), PythonSerializerError>;
+    type SerializeStruct = Impossible<(), PythonSerializerError>;
+    type SerializeStructVariant = Impossible<(), PythonSerializerError>;
+
+    fn serialize_bool(self, _v: bool) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    serde_if_integer128! {
+        fn serialize_i128(self, _v: i128) -> Result<()> {
+            Err(invalid_surrogates())
+        }
+    }
+
+    fn serialize_u8(self, _v: u8) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    serde_if_integer128! {
+        fn serialize_u128(self, _v: u128) -> Result<()> {
+            Err(invalid_surrogates())
+        }
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_char(self, _v: char) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_str(self, _value: &str) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<()> {
+        let SurrogatesEmitter(serializer) = self;
+        format_escaped_surrogates(&mut serializer.writer, &mut serializer.formatter, value)
+            .map_err(|e| PythonSerializerError { message: e.to_string() })
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_some<T>(self, _value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str) -> Result<()> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, _value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeTupleStruct> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Err(invalid_surrogates())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(invalid_surrogates())
+    }
+}
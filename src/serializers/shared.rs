@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 use std::fmt::Debug;
+use std::io;
 
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
@@ -9,7 +10,7 @@ use pyo3::{intern, PyTraverseError, PyVisit};
 
 use enum_dispatch::enum_dispatch;
 use serde::Serialize;
-use serde_json::ser::PrettyFormatter;
+use serde_json::ser::{CompactFormatter, Formatter, PrettyFormatter};
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::py_schema_error_type;
@@ -18,8 +19,10 @@ use crate::py_gc::PyGcTraverse;
 use crate::serializers::ser::PythonSerializer;
 use crate::tools::{py_err, SchemaDict};
 
+use super::canonical::CanonicalFormatter;
 use super::errors::se_err_py_err;
 use super::extra::Extra;
+use super::format::JsonFormat;
 use super::infer::infer_json_key;
 use super::ob_type::{IsType, ObType};
 
@@ -98,6 +101,7 @@ combined_serializer! {
         super::type_serializers::other::IsInstanceBuilder;
         super::type_serializers::other::IsSubclassBuilder;
         super::type_serializers::other::CallableBuilder;
+        super::type_serializers::other::NamedTupleBuilder;
         super::type_serializers::definitions::DefinitionsSerializerBuilder;
         super::type_serializers::dataclass::DataclassArgsBuilder;
         super::type_serializers::function::FunctionBeforeSerializerBuilder;
@@ -116,6 +120,7 @@ combined_serializer! {
         Bool: super::type_serializers::simple::BoolSerializer;
         Float: super::type_serializers::float::FloatSerializer;
         Decimal: super::type_serializers::decimal::DecimalSerializer;
+        Fraction: super::type_serializers::fraction::FractionSerializer;
         Str: super::type_serializers::string::StrSerializer;
         Bytes: super::type_serializers::bytes::BytesSerializer;
         Datetime: super::type_serializers::datetime_etc::DatetimeSerializer;
@@ -127,17 +132,29 @@ combined_serializer! {
         FrozenSet: super::type_serializers::set_frozenset::FrozenSetSerializer;
         Generator: super::type_serializers::generator::GeneratorSerializer;
         Dict: super::type_serializers::dict::DictSerializer;
+        IterDict: super::type_serializers::iter_dict::IterDictSerializer;
+        Deque: super::type_serializers::collections::DequeSerializer;
+        Counter: super::type_serializers::collections::CounterSerializer;
+        DefaultDict: super::type_serializers::collections::DefaultDictSerializer;
         Model: super::type_serializers::model::ModelSerializer;
         Dataclass: super::type_serializers::dataclass::DataclassSerializer;
         Url: super::type_serializers::url::UrlSerializer;
         MultiHostUrl: super::type_serializers::url::MultiHostUrlSerializer;
+        Email: super::type_serializers::url::EmailSerializer;
         Uuid: super::type_serializers::uuid::UuidSerializer;
+        Path: super::type_serializers::path::PathSerializer;
+        IpAddress: super::type_serializers::ip::IpAddressSerializer;
+        IpNetwork: super::type_serializers::ip::IpNetworkSerializer;
+        IpInterface: super::type_serializers::ip::IpInterfaceSerializer;
+        SecretStr: super::type_serializers::secret::SecretStrSerializer;
+        SecretBytes: super::type_serializers::secret::SecretBytesSerializer;
         Any: super::type_serializers::any::AnySerializer;
         Format: super::type_serializers::format::FormatSerializer;
         ToString: super::type_serializers::format::ToStringSerializer;
         WithDefault: super::type_serializers::with_default::WithDefaultSerializer;
         Json: super::type_serializers::json::JsonSerializer;
         JsonOrPython: super::type_serializers::json_or_python::JsonOrPythonSerializer;
+        ContextSwitch: super::type_serializers::context_switch::ContextSwitchSerializer;
         Union: super::type_serializers::union::UnionSerializer;
         TaggedUnion: super::type_serializers::union::TaggedUnionSerializer;
         Literal: super::type_serializers::literal::LiteralSerializer;
@@ -145,6 +162,7 @@ combined_serializer! {
         Recursive: super::type_serializers::definitions::DefinitionRefSerializer;
         Tuple: super::type_serializers::tuple::TupleSerializer;
         Complex: super::type_serializers::complex::ComplexSerializer;
+        ProtocolCustom: super::type_serializers::protocol_custom::ProtocolCustomSerializer;
     }
 }
 
@@ -250,6 +268,7 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::Bool(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Float(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Decimal(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Fraction(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Str(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Bytes(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Datetime(inner) => inner.py_gc_traverse(visit),
@@ -261,16 +280,22 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::FrozenSet(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Generator(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Dict(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::IterDict(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Deque(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Counter(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::DefaultDict(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Model(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Dataclass(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Url(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::MultiHostUrl(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Email(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Any(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Format(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::ToString(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::WithDefault(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Json(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::JsonOrPython(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::ContextSwitch(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Union(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::TaggedUnion(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Literal(inner) => inner.py_gc_traverse(visit),
@@ -278,7 +303,14 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::Recursive(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Tuple(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Uuid(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Path(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::IpAddress(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::IpNetwork(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::IpInterface(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::SecretStr(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::SecretBytes(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Complex(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::ProtocolCustom(inner) => inner.py_gc_traverse(visit),
         }
     }
 }
@@ -329,6 +361,11 @@ pub(crate) trait TypeSerializer: Send + Sync + Debug {
     fn get_default(&self, _py: Python) -> PyResult<Option<PyObject>> {
         Ok(None)
     }
+
+    /// Whether a model, typed dict or dataclass field with this value should be left out of the output
+    fn omit_field(&self, _value: &Bound<'_, PyAny>, _extra: &Extra) -> bool {
+        false
+    }
 }
 
 pub(crate) struct PydanticSerializer<'py> {
@@ -371,27 +408,43 @@ pub(crate) fn to_json_bytes(
     include: Option<&Bound<'_, PyAny>>,
     exclude: Option<&Bound<'_, PyAny>>,
     extra: &Extra,
-    indent: Option<usize>,
+    format: &JsonFormat,
     expected_json_size: usize,
 ) -> PyResult<Vec<u8>> {
+    let writer: Vec<u8> = Vec::with_capacity(expected_json_size);
+    to_json_writer(value, serializer, include, exclude, extra, format, writer)
+}
+
+/// Serialize `value` to JSON, writing it to `writer` as it's serialized
+pub(crate) fn to_json_writer<W: io::Write>(
+    value: &Bound<'_, PyAny>,
+    serializer: &CombinedSerializer,
+    include: Option<&Bound<'_, PyAny>>,
+    exclude: Option<&Bound<'_, PyAny>>,
+    extra: &Extra,
+    format: &JsonFormat,
+    writer: W,
+) -> PyResult<W> {
     let serializer = PydanticSerializer::new(value, serializer, include, exclude, extra);
 
-    let writer: Vec<u8> = Vec::with_capacity(expected_json_size);
-    let bytes = match indent {
+    if extra.canonical {
+        return write_json(&serializer, writer, CanonicalFormatter);
+    }
+    match format.indent {
+        _ if format.is_custom() => write_json(&serializer, writer, format.formatter()),
         Some(indent) => {
             let indent = vec![b' '; indent];
             let formatter = PrettyFormatter::with_indent(&indent);
-            let mut ser = PythonSerializer::with_formatter(writer, formatter);
-            serializer.serialize(&mut ser).map_err(se_err_py_err)?;
-            ser.into_inner()
-        }
-        None => {
-            let mut ser = PythonSerializer::new(writer);
-            serializer.serialize(&mut ser).map_err(se_err_py_err)?;
-            ser.into_inner()
+            write_json(&serializer, writer, formatter)
         }
-    };
-    Ok(bytes)
+        None => write_json(&serializer, writer, CompactFormatter),
+    }
+}
+
+fn write_json<W: io::Write, F: Formatter>(serializer: &PydanticSerializer, writer: W, formatter: F) -> PyResult<W> {
+    let mut ser = PythonSerializer::with_formatter(writer, formatter);
+    serializer.serialize(&mut ser).map_err(se_err_py_err)?;
+    Ok(ser.into_inner())
 }
 
 #[allow(clippy::type_complexity)]
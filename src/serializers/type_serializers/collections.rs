@@ -0,0 +1,129 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use pyo3::IntoPyObjectExt;
+
+use crate::definitions::DefinitionsBuilder;
+use crate::validators::collections::CollectionType;
+
+use super::dict::DictSerializer;
+use super::list::ListSerializer;
+use super::{
+    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, SerMode,
+    TypeSerializer,
+};
+
+fn is_instance(value: &Bound<'_, PyAny>, collection_type: CollectionType) -> bool {
+    collection_type
+        .py_type(value.py())
+        .and_then(|class| value.is_instance(class))
+        .unwrap_or(false)
+}
+
+/// The value passed to the inner serializer, deques are serialized as lists, counters and defaultdicts as dicts
+fn inner_value<'py>(value: &Bound<'py, PyAny>, collection_type: CollectionType) -> PyResult<Bound<'py, PyAny>> {
+    match collection_type {
+        CollectionType::Deque => {
+            Ok(PyList::new(value.py(), value.try_iter()?.collect::<PyResult<Vec<_>>>()?)?.into_any())
+        }
+        CollectionType::Counter | CollectionType::DefaultDict => Ok(value.clone()),
+    }
+}
+
+/// Rebuild the collection from the serialized items in python mode, keeping a deque's `maxlen` and
+/// a defaultdict's `default_factory`
+fn rebuild(value: &Bound<'_, PyAny>, collection_type: CollectionType, items: PyObject) -> PyResult<PyObject> {
+    let py = value.py();
+    let class = collection_type.py_type(py)?;
+    match collection_type {
+        CollectionType::Deque => class.call1((items, value.getattr(intern!(py, "maxlen"))?)),
+        CollectionType::Counter => class.call1((items,)),
+        CollectionType::DefaultDict => class.call1((value.getattr(intern!(py, "default_factory"))?, items)),
+    }?
+    .into_py_any(py)
+}
+
+macro_rules! collection_serializer {
+    ($struct_name:ident, $expected_type:literal, $collection_type:expr, $inner:ident) => {
+        #[derive(Debug)]
+        pub struct $struct_name {
+            serializer: Box<CombinedSerializer>,
+        }
+
+        impl BuildSerializer for $struct_name {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                schema: &Bound<'_, PyDict>,
+                config: Option<&Bound<'_, PyDict>>,
+                definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+            ) -> PyResult<CombinedSerializer> {
+                let serializer = Box::new($inner::build(schema, config, definitions)?);
+                Ok(Self { serializer }.into())
+            }
+        }
+
+        impl_py_gc_traverse!($struct_name { serializer });
+
+        impl TypeSerializer for $struct_name {
+            fn to_python(
+                &self,
+                value: &Bound<'_, PyAny>,
+                include: Option<&Bound<'_, PyAny>>,
+                exclude: Option<&Bound<'_, PyAny>>,
+                extra: &Extra,
+            ) -> PyResult<PyObject> {
+                if is_instance(value, $collection_type) {
+                    let inner = inner_value(value, $collection_type)?;
+                    let items = self.serializer.to_python(&inner, include, exclude, extra)?;
+                    match extra.mode {
+                        SerMode::Json => Ok(items),
+                        _ => rebuild(value, $collection_type, items),
+                    }
+                } else {
+                    extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                    infer_to_python(value, include, exclude, extra)
+                }
+            }
+
+            fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+                self.invalid_as_json_key(key, extra, Self::EXPECTED_TYPE)
+            }
+
+            fn serde_serialize<S: serde::ser::Serializer>(
+                &self,
+                value: &Bound<'_, PyAny>,
+                serializer: S,
+                include: Option<&Bound<'_, PyAny>>,
+                exclude: Option<&Bound<'_, PyAny>>,
+                extra: &Extra,
+            ) -> Result<S::Ok, S::Error> {
+                if is_instance(value, $collection_type) {
+                    let inner = inner_value(value, $collection_type).map_err(py_err_se_err)?;
+                    self.serializer
+                        .serde_serialize(&inner, serializer, include, exclude, extra)
+                } else {
+                    extra
+                        .warnings
+                        .on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                    infer_serialize(value, serializer, include, exclude, extra)
+                }
+            }
+
+            fn get_name(&self) -> &str {
+                Self::EXPECTED_TYPE
+            }
+        }
+    };
+}
+
+collection_serializer!(DequeSerializer, "deque", CollectionType::Deque, ListSerializer);
+collection_serializer!(CounterSerializer, "counter", CollectionType::Counter, DictSerializer);
+collection_serializer!(
+    DefaultDictSerializer,
+    "defaultdict",
+    CollectionType::DefaultDict,
+    DictSerializer
+);
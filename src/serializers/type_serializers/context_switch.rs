@@ -0,0 +1,118 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::build_tools::py_schema_err;
+use crate::definitions::DefinitionsBuilder;
+use crate::tools::SchemaDict;
+use crate::validators::get_context_value;
+
+use super::any::AnySerializer;
+use super::{BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
+
+#[derive(Debug)]
+pub struct ContextSwitchSerializer {
+    context_key: Py<PyString>,
+    // maps the expected context value to the index of the serializer in `choices`
+    lookup: Py<PyDict>,
+    choices: Vec<CombinedSerializer>,
+    default: Box<CombinedSerializer>,
+    name: String,
+}
+
+impl BuildSerializer for ContextSwitchSerializer {
+    const EXPECTED_TYPE: &'static str = "context-switch";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let context_key: Bound<'_, PyString> = schema.get_as_req(intern!(py, "context_key"))?;
+        let schema_choices: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "choices"))?;
+        if schema_choices.is_empty() {
+            return py_schema_err!("One or more context-switch choices required");
+        }
+
+        let lookup = PyDict::new(py);
+        let mut choices = Vec::with_capacity(schema_choices.len());
+        for (choice_key, choice_schema) in schema_choices {
+            lookup.set_item(choice_key, choices.len())?;
+            let choice_schema = choice_schema.downcast_into::<PyDict>()?;
+            choices.push(CombinedSerializer::build(&choice_schema, config, definitions)?);
+        }
+
+        let default = match schema.get_as::<Bound<'_, PyDict>>(intern!(py, "default_schema"))? {
+            Some(default_schema) => CombinedSerializer::build(&default_schema, config, definitions)?,
+            None => AnySerializer::build(schema, config, definitions)?,
+        };
+
+        let descr = choices
+            .iter()
+            .map(TypeSerializer::get_name)
+            .collect::<Vec<_>>()
+            .join(", ");
+        Ok(Self {
+            name: format!("{}[{}][{descr}]", Self::EXPECTED_TYPE, context_key.to_str()?),
+            context_key: context_key.unbind(),
+            lookup: lookup.unbind(),
+            choices,
+            default: Box::new(default),
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(ContextSwitchSerializer {
+    lookup,
+    choices,
+    default
+});
+
+impl ContextSwitchSerializer {
+    fn select(&self, py: Python<'_>, extra: &Extra) -> PyResult<&CombinedSerializer> {
+        if let Some(value) = get_context_value(extra.context, self.context_key.bind(py))? {
+            // unhashable context values simply don't match any choice
+            if let Ok(Some(index)) = self.lookup.bind(py).get_item(value) {
+                return Ok(&self.choices[index.extract::<usize>()?]);
+            }
+        }
+        Ok(&self.default)
+    }
+}
+
+impl TypeSerializer for ContextSwitchSerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        self.select(value.py(), extra)?
+            .to_python(value, include, exclude, extra)
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        self.select(key.py(), extra)?.json_key(key, extra)
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        let choice = self.select(value.py(), extra).map_err(super::py_err_se_err)?;
+        choice.serde_serialize(value, serializer, include, exclude, extra)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
@@ -4,17 +4,16 @@ use pyo3::types::{PyDict, PyList, PyString, PyType};
 use std::borrow::Cow;
 
 use ahash::AHashMap;
-use serde::ser::SerializeMap;
 
-use crate::build_tools::{py_schema_error_type, ExtraBehavior};
+use crate::build_tools::{nested_config, py_schema_error_type, AliasGenerator, ExtraBehavior};
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::DuckTypingSerMode;
 use crate::tools::SchemaDict;
 
 use super::{
-    infer_json_key, infer_json_key_known, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer,
-    CombinedSerializer, ComputedFields, Extra, FieldsMode, GeneralFieldsSerializer, ObType, SerCheck, SerField,
-    TypeSerializer,
+    infer_json_key, infer_json_key_known, infer_serialize, infer_to_python, py_err_se_err, sorted_dict,
+    BuildSerializer, CombinedSerializer, ComputedFields, Extra, FieldOrder, FieldsMode, GeneralFieldsSerializer,
+    ObType, SerCheck, SerField, TypeSerializer,
 };
 
 pub struct DataclassArgsBuilder;
@@ -39,6 +38,7 @@ impl BuildSerializer for DataclassArgsBuilder {
 
         let serialize_by_alias = config.get_as(intern!(py, "serialize_by_alias"))?;
 
+        let alias_generator = AliasGenerator::serialization(config)?;
         for (index, item) in fields_list.iter().enumerate() {
             let field_info = item.downcast::<PyDict>()?;
             let name: String = field_info.get_as_req(intern!(py, "name"))?;
@@ -53,18 +53,28 @@ impl BuildSerializer for DataclassArgsBuilder {
                     let serializer = CombinedSerializer::build(&schema, config, definitions)
                         .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", index, e))?;
 
-                    let alias = field_info.get_as(intern!(py, "serialization_alias"))?;
+                    let alias = field_info
+                        .get_as(intern!(py, "serialization_alias"))?
+                        .or_else(|| alias_generator.map(|generator| generator.generate(&name)));
                     fields.insert(
                         name,
-                        SerField::new(py, key_py, alias, Some(serializer), true, serialize_by_alias),
+                        SerField::new(py, key_py, alias, Some(serializer), true, serialize_by_alias)
+                            .with_exclude_conditions(field_info)?,
                     );
                 }
             }
         }
 
         let computed_fields = ComputedFields::new(schema, config, definitions)?;
-
-        Ok(GeneralFieldsSerializer::new(fields, fields_mode, None, computed_fields).into())
+        let field_order = FieldOrder::from_config(
+            py,
+            config,
+            fields_list
+                .iter()
+                .map(|field| field.get_item(intern!(py, "name"))?.extract()),
+        )?;
+
+        Ok(GeneralFieldsSerializer::new(fields, fields_mode, None, computed_fields, field_order).into())
     }
 }
 
@@ -81,13 +91,13 @@ impl BuildSerializer for DataclassSerializer {
 
     fn build(
         schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        config: Option<&Bound<'_, PyDict>>,
         definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
         let py = schema.py();
 
-        // models ignore the parent config and always use the config from this model
-        let config = schema.get_as(intern!(py, "config"))?;
+        // dataclasses use the config from this dataclass, combined with the parent config according to `config_merge`
+        let config = nested_config(schema, config)?;
 
         let class: Bound<'_, PyType> = schema.get_as_req(intern!(py, "cls"))?;
         let sub_schema = schema.get_as_req(intern!(py, "schema"))?;
@@ -162,7 +172,7 @@ impl TypeSerializer for DataclassSerializer {
                 )?;
 
                 fields_serializer.add_computed_fields_python(model, &output_dict, include, exclude, extra)?;
-                Ok(output_dict.into())
+                Ok(sorted_dict(output_dict, extra)?.into())
             } else {
                 let inner_value = self.get_inner_value(value)?;
                 self.serializer.to_python(&inner_value, include, exclude, &dc_extra)
@@ -203,16 +213,16 @@ impl TypeSerializer for DataclassSerializer {
         if self.allow_value(value, &dc_extra).map_err(py_err_se_err)? {
             if let CombinedSerializer::Fields(ref fields_serializer) = *self.serializer {
                 let expected_len = self.fields.len() + fields_serializer.computed_field_count();
-                let mut map = fields_serializer.main_serde_serialize(
+                fields_serializer.main_serde_serialize(
                     known_dataclass_iter(&self.fields, value),
+                    None,
                     expected_len,
                     serializer,
+                    model,
                     include,
                     exclude,
-                    dc_extra,
-                )?;
-                fields_serializer.add_computed_fields_json::<S>(model, &mut map, include, exclude, extra)?;
-                map.end()
+                    &dc_extra,
+                )
             } else {
                 let inner_value = self.get_inner_value(value).map_err(py_err_se_err)?;
                 self.serializer
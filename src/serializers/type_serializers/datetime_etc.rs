@@ -1,11 +1,13 @@
 use std::borrow::Cow;
 
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDate, PyDateTime, PyDict, PyTime};
-use pyo3::IntoPyObjectExt;
 
 use crate::definitions::DefinitionsBuilder;
 use crate::input::{pydate_as_date, pydatetime_as_datetime, pytime_as_time};
+use crate::serializers::config::{FormattedTemporal, FromConfig, Temporal, TemporalFormat};
+use crate::tools::SchemaDict;
 use crate::PydanticSerializationUnexpectedValue;
 
 use super::{
@@ -13,16 +15,45 @@ use super::{
     SerMode, TypeSerializer,
 };
 
-pub(crate) fn datetime_to_string(py_dt: &Bound<'_, PyDateTime>) -> PyResult<String> {
-    pydatetime_as_datetime(py_dt).map(|dt| dt.to_string())
+/// `zone_key` adds the key of a `zoneinfo.ZoneInfo` zone as an RFC 9557 suffix, e.g. `[Europe/Paris]`, to ISO 8601
+/// output
+pub(crate) fn datetime_to_json(
+    py_dt: &Bound<'_, PyDateTime>,
+    temporal_format: &TemporalFormat,
+    zone_key: bool,
+) -> PyResult<FormattedTemporal> {
+    let formatted = temporal_format.format(Temporal::DateTime(pydatetime_as_datetime(py_dt)?));
+    match formatted {
+        FormattedTemporal::Str(s) if zone_key && *temporal_format == TemporalFormat::Iso8601 => {
+            let py = py_dt.py();
+            let key = py_dt
+                .getattr(intern!(py, "tzinfo"))?
+                .getattr(intern!(py, "key"))
+                .ok()
+                .and_then(|key| key.extract::<String>().ok());
+            Ok(FormattedTemporal::Str(match key {
+                Some(key) => format!("{s}[{key}]"),
+                None => s,
+            }))
+        }
+        formatted => Ok(formatted),
+    }
 }
 
-pub(crate) fn date_to_string(py_date: &Bound<'_, PyDate>) -> PyResult<String> {
-    pydate_as_date(py_date).map(|dt| dt.to_string())
+pub(crate) fn date_to_json(
+    py_date: &Bound<'_, PyDate>,
+    temporal_format: &TemporalFormat,
+    _zone_key: bool,
+) -> PyResult<FormattedTemporal> {
+    pydate_as_date(py_date).map(|date| temporal_format.format(Temporal::Date(date)))
 }
 
-pub(crate) fn time_to_string(py_time: &Bound<'_, PyTime>) -> PyResult<String> {
-    pytime_as_time(py_time, None).map(|dt| dt.to_string())
+pub(crate) fn time_to_json(
+    py_time: &Bound<'_, PyTime>,
+    temporal_format: &TemporalFormat,
+    _zone_key: bool,
+) -> PyResult<FormattedTemporal> {
+    pytime_as_time(py_time, None).map(|time| temporal_format.format(Temporal::Time(time)))
 }
 
 fn downcast_date_reject_datetime<'a, 'py>(py_date: &'a Bound<'py, PyAny>) -> PyResult<&'a Bound<'py, PyDate>> {
@@ -40,17 +71,29 @@ fn downcast_date_reject_datetime<'a, 'py>(py_date: &'a Bound<'py, PyAny>) -> PyR
 macro_rules! build_serializer {
     ($struct_name:ident, $expected_type:literal, $downcast:path, $convert_func:ident $(, $json_check_func:ident)?) => {
         #[derive(Debug)]
-        pub struct $struct_name;
+        pub struct $struct_name {
+            temporal_format: TemporalFormat,
+            // only used by datetimes, see `datetime_to_json`
+            preserve_zone_key: bool,
+        }
 
         impl BuildSerializer for $struct_name {
             const EXPECTED_TYPE: &'static str = $expected_type;
 
             fn build(
-                _schema: &Bound<'_, PyDict>,
-                _config: Option<&Bound<'_, PyDict>>,
+                schema: &Bound<'_, PyDict>,
+                config: Option<&Bound<'_, PyDict>>,
                 _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
             ) -> PyResult<CombinedSerializer> {
-                Ok(Self {}.into())
+                let temporal_format = TemporalFormat::from_config(config)?;
+                let preserve_zone_key = schema
+                    .get_as(intern!(schema.py(), "preserve_zone_key"))?
+                    .unwrap_or(false);
+                Ok(Self {
+                    temporal_format,
+                    preserve_zone_key,
+                }
+                .into())
             }
         }
 
@@ -67,10 +110,12 @@ macro_rules! build_serializer {
                 let py = value.py();
                 match $downcast(value) {
                     Ok(py_value) => match extra.mode {
-                        SerMode::Json => {
-                            let s = $convert_func(py_value)?;
-                            s.into_py_any(py)
-                        }
+                        SerMode::Json => $convert_func(
+                            py_value,
+                            &self.temporal_format,
+                            self.preserve_zone_key && extra.round_trip,
+                        )?
+                        .into_py_any(py),
                         _ => Ok(value.clone().unbind()),
                     },
                     Err(_) => {
@@ -82,7 +127,14 @@ macro_rules! build_serializer {
 
             fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
                 match $downcast(key) {
-                    Ok(py_value) => Ok(Cow::Owned($convert_func(py_value)?)),
+                    Ok(py_value) => Ok(Cow::Owned(
+                        $convert_func(
+                            py_value,
+                            &self.temporal_format,
+                            self.preserve_zone_key && extra.round_trip,
+                        )?
+                        .into_string(),
+                    )),
                     Err(_) => {
                         extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
                         infer_json_key(key, extra)
@@ -99,10 +151,13 @@ macro_rules! build_serializer {
                 extra: &Extra,
             ) -> Result<S::Ok, S::Error> {
                 match $downcast(value) {
-                    Ok(py_value) => {
-                        let s = $convert_func(py_value).map_err(py_err_se_err)?;
-                        serializer.serialize_str(&s)
-                    }
+                    Ok(py_value) => $convert_func(
+                        py_value,
+                        &self.temporal_format,
+                        self.preserve_zone_key && extra.round_trip,
+                    )
+                    .map_err(py_err_se_err)?
+                    .serialize(serializer),
                     Err(_) => {
                         extra
                             .warnings
@@ -123,7 +178,7 @@ build_serializer!(
     DatetimeSerializer,
     "datetime",
     PyAnyMethods::downcast::<PyDateTime>,
-    datetime_to_string
+    datetime_to_json
 );
-build_serializer!(DateSerializer, "date", downcast_date_reject_datetime, date_to_string);
-build_serializer!(TimeSerializer, "time", PyAnyMethods::downcast::<PyTime>, time_to_string);
+build_serializer!(DateSerializer, "date", downcast_date_reject_datetime, date_to_json);
+build_serializer!(TimeSerializer, "time", PyAnyMethods::downcast::<PyTime>, time_to_json);
@@ -1,32 +1,53 @@
 use std::borrow::Cow;
 
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::infer::{infer_json_key_known, infer_serialize_known, infer_to_python_known};
 use crate::serializers::ob_type::{IsType, ObType};
+use crate::tools::SchemaDict;
+use crate::validators::decimal::DecimalFormat;
 
 use super::{
-    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, TypeSerializer,
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    TypeSerializer,
 };
 
 #[derive(Debug)]
-pub struct DecimalSerializer {}
+pub struct DecimalSerializer {
+    format: Option<DecimalFormat>,
+}
+
+impl DecimalSerializer {
+    fn format<'py>(&self, value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        match self.format {
+            Some(ref format) => format.apply(value),
+            None => Ok(value.clone()),
+        }
+    }
+}
 
 impl BuildSerializer for DecimalSerializer {
     const EXPECTED_TYPE: &'static str = "decimal";
 
     fn build(
-        _schema: &Bound<'_, PyDict>,
+        schema: &Bound<'_, PyDict>,
         _config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        Ok(Self {}.into())
+        let py = schema.py();
+        let places = schema.get_as(intern!(py, "ser_decimal_places"))?;
+        let normalize = schema.get_as(intern!(py, "ser_normalize"))?.unwrap_or(false);
+        Ok(Self {
+            format: DecimalFormat::build(schema, places, normalize)?,
+        }
+        .into())
     }
 }
 
-impl_py_gc_traverse!(DecimalSerializer {});
+impl_py_gc_traverse!(DecimalSerializer { format });
 
 impl TypeSerializer for DecimalSerializer {
     fn to_python(
@@ -36,9 +57,10 @@ impl TypeSerializer for DecimalSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
-        let _py = value.py();
         match extra.ob_type_lookup.is_type(value, ObType::Decimal) {
-            IsType::Exact | IsType::Subclass => infer_to_python_known(ObType::Decimal, value, include, exclude, extra),
+            IsType::Exact | IsType::Subclass => {
+                infer_to_python_known(ObType::Decimal, &self.format(value)?, include, exclude, extra)
+            }
             IsType::False => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
                 infer_to_python(value, include, exclude, extra)
@@ -48,7 +70,12 @@ impl TypeSerializer for DecimalSerializer {
 
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
         match extra.ob_type_lookup.is_type(key, ObType::Decimal) {
-            IsType::Exact | IsType::Subclass => infer_json_key_known(ObType::Decimal, key, extra),
+            IsType::Exact | IsType::Subclass => {
+                let key = self.format(key)?;
+                Ok(Cow::Owned(
+                    infer_json_key_known(ObType::Decimal, &key, extra)?.into_owned(),
+                ))
+            }
             IsType::False => {
                 extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
                 infer_json_key(key, extra)
@@ -66,7 +93,8 @@ impl TypeSerializer for DecimalSerializer {
     ) -> Result<S::Ok, S::Error> {
         match extra.ob_type_lookup.is_type(value, ObType::Decimal) {
             IsType::Exact | IsType::Subclass => {
-                infer_serialize_known(ObType::Decimal, value, serializer, include, exclude, extra)
+                let value = self.format(value).map_err(py_err_se_err)?;
+                infer_serialize_known(ObType::Decimal, &value, serializer, include, exclude, extra)
             }
             IsType::False => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
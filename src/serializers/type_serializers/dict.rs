@@ -12,8 +12,8 @@ use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
 use super::{
-    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer,
-    SchemaFilter, SerMode, TypeSerializer,
+    infer_serialize, infer_to_python, py_err_se_err, sorted_dict, BuildSerializer, CombinedSerializer, Extra,
+    FilteredValue, ObjectMembers, PydanticSerializer, SchemaFilter, SerMode, TypeSerializer,
 };
 
 #[derive(Debug)]
@@ -97,7 +97,7 @@ impl TypeSerializer for DictSerializer {
                         new_dict.set_item(key, value)?;
                     }
                 }
-                Ok(new_dict.into())
+                Ok(sorted_dict(new_dict, extra)?.into())
             }
             Err(_) => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
@@ -123,11 +123,9 @@ impl TypeSerializer for DictSerializer {
                 let mut map = serializer.serialize_map(Some(py_dict.len()))?;
                 let key_serializer = self.key_serializer.as_ref();
                 let value_serializer = self.value_serializer.as_ref();
-
-                for (key, value) in py_dict.iter() {
-                    let op_next = self.filter.key_filter(&key, include, exclude).map_err(py_err_se_err)?;
-                    if let Some((next_include, next_exclude)) = op_next {
-                        let key = key_serializer.json_key(&key, extra).map_err(py_err_se_err)?;
+                let mut members = ObjectMembers::new(extra);
+                let write_member =
+                    |map: &mut S::SerializeMap, key: &str, (value, next_include, next_exclude): FilteredValue| {
                         let value_serialize = PydanticSerializer::new(
                             &value,
                             value_serializer,
@@ -135,9 +133,21 @@ impl TypeSerializer for DictSerializer {
                             next_exclude.as_ref(),
                             extra,
                         );
-                        map.serialize_entry(&key, &value_serialize)?;
+                        map.serialize_entry(key, &value_serialize)
+                    };
+
+                for (key, value) in py_dict.iter() {
+                    let op_next = self.filter.key_filter(&key, include, exclude).map_err(py_err_se_err)?;
+                    if let Some((next_include, next_exclude)) = op_next {
+                        let key = key_serializer.json_key(&key, extra).map_err(py_err_se_err)?;
+                        if let Some((key, member)) = members.add(key, (value, next_include, next_exclude)) {
+                            write_member(&mut map, &key, member)?;
+                        }
                     }
                 }
+                for (key, member) in members.into_sorted() {
+                    write_member(&mut map, &key, member)?;
+                }
                 map.end()
             }
             Err(_) => {
@@ -1,11 +1,12 @@
 use std::borrow::Cow;
 
-use crate::build_tools::py_schema_err;
+use crate::build_tools::{py_schema_err, schema_or_config_same};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyType};
 
 use crate::definitions::DefinitionsBuilder;
+use crate::serializers::config::EnumMode;
 use crate::serializers::errors::py_err_se_err;
 use crate::serializers::infer::{infer_json_key, infer_serialize, infer_to_python};
 use crate::tools::SchemaDict;
@@ -19,6 +20,17 @@ use super::{BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
 pub struct EnumSerializer {
     class: Py<PyType>,
     serializer: Option<Box<CombinedSerializer>>,
+    mode: EnumMode,
+}
+
+impl EnumSerializer {
+    /// The serializer for the attribute serialized in place of members, names are always inferred
+    fn attribute_serializer(&self) -> Option<&CombinedSerializer> {
+        match self.mode {
+            EnumMode::Name => None,
+            EnumMode::Object | EnumMode::Value => self.serializer.as_deref(),
+        }
+    }
 }
 
 impl BuildSerializer for EnumSerializer {
@@ -34,13 +46,16 @@ impl BuildSerializer for EnumSerializer {
         let serializer = match sub_type.as_deref() {
             Some("int") => Some(Box::new(IntSerializer::new().into())),
             Some("str") => Some(Box::new(StrSerializer::new().into())),
-            Some("float") => Some(Box::new(FloatSerializer::new(schema.py(), config)?.into())),
+            Some("float") => Some(Box::new(FloatSerializer::new(schema, config)?.into())),
             Some(_) => return py_schema_err!("`sub_type` must be one of: 'int', 'str', 'float' or None"),
             None => None,
         };
+        // can be set on the schema to override the config for a single enum
+        let mode = schema_or_config_same(schema, config, intern!(schema.py(), "ser_enum"))?.unwrap_or_default();
         Ok(Self {
             class: schema.get_as_req(intern!(schema.py(), "cls"))?,
             serializer,
+            mode,
         }
         .into())
     }
@@ -58,15 +73,15 @@ impl TypeSerializer for EnumSerializer {
     ) -> PyResult<PyObject> {
         let py = value.py();
         if value.is_exact_instance(self.class.bind(py)) {
-            // if we're in JSON mode, we need to get the value attribute and serialize that
-            if extra.mode.is_json() {
-                let dot_value = value.getattr(intern!(py, "value"))?;
-                match self.serializer {
-                    Some(ref s) => s.to_python(&dot_value, include, exclude, extra),
-                    None => infer_to_python(&dot_value, include, exclude, extra),
+            // in JSON mode, or if configured to, we need to get the value or name attribute and serialize that
+            if self.mode.replaces_member(extra.mode) {
+                let attribute = value.getattr(self.mode.attribute(py))?;
+                match self.attribute_serializer() {
+                    Some(s) => s.to_python(&attribute, include, exclude, extra),
+                    None => infer_to_python(&attribute, include, exclude, extra),
                 }
             } else {
-                // if we're not in JSON mode, we assume the value is safe to return directly
+                // otherwise, we assume the value is safe to return directly
                 Ok(value.clone().unbind())
             }
         } else {
@@ -78,12 +93,12 @@ impl TypeSerializer for EnumSerializer {
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
         let py = key.py();
         if key.is_exact_instance(self.class.bind(py)) {
-            let dot_value = key.getattr(intern!(py, "value"))?;
-            let k = match self.serializer {
-                Some(ref s) => s.json_key(&dot_value, extra),
-                None => infer_json_key(&dot_value, extra),
+            let attribute = key.getattr(self.mode.attribute(py))?;
+            let k = match self.attribute_serializer() {
+                Some(s) => s.json_key(&attribute, extra),
+                None => infer_json_key(&attribute, extra),
             }?;
-            // since dot_value is a local reference, we need to allocate it and returned an
+            // since attribute is a local reference, we need to allocate it and returned an
             // owned variant of cow.
             Ok(Cow::Owned(k.into_owned()))
         } else {
@@ -101,10 +116,10 @@ impl TypeSerializer for EnumSerializer {
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         if value.is_exact_instance(self.class.bind(value.py())) {
-            let dot_value = value.getattr(intern!(value.py(), "value")).map_err(py_err_se_err)?;
-            match self.serializer {
-                Some(ref s) => s.serde_serialize(&dot_value, serializer, include, exclude, extra),
-                None => infer_serialize(&dot_value, serializer, include, exclude, extra),
+            let attribute = value.getattr(self.mode.attribute(value.py())).map_err(py_err_se_err)?;
+            match self.attribute_serializer() {
+                Some(s) => s.serde_serialize(&attribute, serializer, include, exclude, extra),
+                None => infer_serialize(&attribute, serializer, include, exclude, extra),
             }
         } else {
             extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
@@ -3,35 +3,50 @@ use pyo3::{intern, prelude::*, IntoPyObjectExt};
 
 use std::borrow::Cow;
 
+use serde::ser::Error;
 use serde::Serializer;
 
+use crate::build_tools::schema_or_config_same;
 use crate::definitions::DefinitionsBuilder;
-use crate::serializers::config::InfNanMode;
-use crate::tools::SchemaDict;
+use crate::serializers::config::{FloatFormat, InfNanMode};
 
 use super::simple::to_str_json_key;
 use super::{
     infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, IsType, ObType,
     SerCheck, SerMode, TypeSerializer,
 };
-use crate::serializers::errors::PydanticSerializationUnexpectedValue;
+use crate::serializers::errors::{PydanticSerializationUnexpectedValue, SERIALIZATION_ERR_MARKER};
+use crate::PydanticSerializationError;
 
 #[derive(Debug)]
 pub struct FloatSerializer {
     inf_nan_mode: InfNanMode,
+    float_format: FloatFormat,
 }
 
 impl FloatSerializer {
-    pub fn new(py: Python, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
-        let inf_nan_mode = config
-            .and_then(|c| c.get_as(intern!(py, "ser_json_inf_nan")).transpose())
-            .transpose()?
-            .unwrap_or_default();
-        Ok(Self { inf_nan_mode })
+    pub fn new(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let py = schema.py();
+        // both can be set on the schema to override the config for a single field
+        let inf_nan_mode = schema_or_config_same(schema, config, intern!(py, "ser_json_inf_nan"))?.unwrap_or_default();
+        let float_format =
+            match schema_or_config_same::<Bound<'_, PyAny>>(schema, config, intern!(py, "ser_json_float"))? {
+                Some(raw) => FloatFormat::from_py(&raw)?,
+                None => FloatFormat::default(),
+            };
+        Ok(Self {
+            inf_nan_mode,
+            float_format,
+        })
     }
 }
 
-pub fn serialize_f64<S: Serializer>(v: f64, serializer: S, inf_nan_mode: InfNanMode) -> Result<S::Ok, S::Error> {
+pub fn serialize_f64<S: Serializer>(
+    v: f64,
+    serializer: S,
+    inf_nan_mode: InfNanMode,
+    float_format: FloatFormat,
+) -> Result<S::Ok, S::Error> {
     if v.is_nan() || v.is_infinite() {
         match inf_nan_mode {
             InfNanMode::Null => serializer.serialize_none(),
@@ -43,9 +58,22 @@ pub fn serialize_f64<S: Serializer>(v: f64, serializer: S, inf_nan_mode: InfNanM
                     serializer.serialize_str(if v.is_sign_positive() { "Infinity" } else { "-Infinity" })
                 }
             }
+            InfNanMode::Error => Err(S::Error::custom(format!(
+                "{SERIALIZATION_ERR_MARKER}{}",
+                InfNanMode::non_finite_error(v)
+            ))),
         }
     } else {
-        serializer.serialize_f64(v)
+        float_format.serialize_f64(v, serializer)
+    }
+}
+
+/// Non-finite floats can't be included in JSON compatible python output with `InfNanMode::Error`
+pub fn check_inf_nan(v: f64, inf_nan_mode: InfNanMode) -> PyResult<()> {
+    if inf_nan_mode == InfNanMode::Error && (v.is_nan() || v.is_infinite()) {
+        Err(PydanticSerializationError::new_err(InfNanMode::non_finite_error(v)))
+    } else {
+        Ok(())
     }
 }
 
@@ -57,7 +85,7 @@ impl BuildSerializer for FloatSerializer {
         config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        Self::new(schema.py(), config).map(Into::into)
+        Self::new(schema, config).map(Into::into)
     }
 }
 
@@ -73,11 +101,25 @@ impl TypeSerializer for FloatSerializer {
     ) -> PyResult<PyObject> {
         let py = value.py();
         match extra.ob_type_lookup.is_type(value, ObType::Float) {
-            IsType::Exact => Ok(value.clone().unbind()),
+            IsType::Exact => match extra.mode {
+                SerMode::Json => {
+                    let v = value.extract()?;
+                    check_inf_nan(v, self.inf_nan_mode)?;
+                    match self.float_format {
+                        FloatFormat::Round(_) => self.float_format.round(v).into_py_any(py),
+                        _ => Ok(value.clone().unbind()),
+                    }
+                }
+                _ => Ok(value.clone().unbind()),
+            },
             IsType::Subclass => match extra.check {
                 SerCheck::Strict => Err(PydanticSerializationUnexpectedValue::new_from_msg(None).to_py_err()),
                 SerCheck::Lax | SerCheck::None => match extra.mode {
-                    SerMode::Json => value.extract::<f64>()?.into_py_any(py),
+                    SerMode::Json => {
+                        let v = value.extract()?;
+                        check_inf_nan(v, self.inf_nan_mode)?;
+                        self.float_format.round(v).into_py_any(py)
+                    }
                     _ => infer_to_python(value, include, exclude, extra),
                 },
             },
@@ -104,11 +146,10 @@ impl TypeSerializer for FloatSerializer {
         serializer: S,
         include: Option<&Bound<'_, PyAny>>,
         exclude: Option<&Bound<'_, PyAny>>,
-        // TODO: Merge extra.config into self.inf_nan_mode?
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         match value.extract::<f64>() {
-            Ok(v) => serialize_f64(v, serializer, self.inf_nan_mode),
+            Ok(v) => serialize_f64(v, serializer, self.inf_nan_mode, self.float_format),
             Err(_) => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
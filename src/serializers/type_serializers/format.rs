@@ -140,13 +140,13 @@ impl TypeSerializer for FormatSerializer {
         serializer: S,
         _include: Option<&Bound<'_, PyAny>>,
         _exclude: Option<&Bound<'_, PyAny>>,
-        _extra: &Extra,
+        extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         if self.when_used.should_use_json(value) {
             match self.call(value) {
                 Ok(v) => {
                     let py_str = v.bind(value.py()).downcast().map_err(py_err_se_err)?;
-                    serialize_py_str(py_str, serializer)
+                    serialize_py_str(py_str, serializer, extra.config.invalid_utf8_mode)
                 }
                 Err(e) => Err(S::Error::custom(e)),
             }
@@ -211,11 +211,11 @@ impl TypeSerializer for ToStringSerializer {
         serializer: S,
         _include: Option<&Bound<'_, PyAny>>,
         _exclude: Option<&Bound<'_, PyAny>>,
-        _extra: &Extra,
+        extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         if self.when_used.should_use_json(value) {
             let s = value.str().map_err(py_err_se_err)?;
-            serialize_py_str(&s, serializer)
+            serialize_py_str(&s, serializer, extra.config.invalid_utf8_mode)
         } else {
             serializer.serialize_none()
         }
@@ -0,0 +1,84 @@
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::IntoPyObjectExt;
+
+use crate::definitions::DefinitionsBuilder;
+use crate::validators::fraction::get_fraction_type;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    SerMode, TypeSerializer,
+};
+
+fn is_fraction(value: &Bound<'_, PyAny>) -> bool {
+    value.is_instance(get_fraction_type(value.py())).unwrap_or(false)
+}
+
+#[derive(Debug)]
+pub struct FractionSerializer;
+
+impl_py_gc_traverse!(FractionSerializer {});
+
+impl BuildSerializer for FractionSerializer {
+    const EXPECTED_TYPE: &'static str = "fraction";
+
+    fn build(
+        _schema: &Bound<'_, PyDict>,
+        _config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        Ok(Self {}.into())
+    }
+}
+
+impl TypeSerializer for FractionSerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        if is_fraction(value) {
+            match extra.mode {
+                SerMode::Json => value.str()?.into_py_any(value.py()),
+                _ => Ok(value.clone().unbind()),
+            }
+        } else {
+            extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+            infer_to_python(value, include, exclude, extra)
+        }
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        if is_fraction(key) {
+            Ok(Cow::Owned(key.str()?.to_string()))
+        } else {
+            extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+            infer_json_key(key, extra)
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        if is_fraction(value) {
+            let py_str = value.str().map_err(py_err_se_err)?;
+            serializer.serialize_str(py_str.to_str().map_err(py_err_se_err)?)
+        } else {
+            extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+            infer_serialize(value, serializer, include, exclude, extra)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
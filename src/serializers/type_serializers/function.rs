@@ -5,7 +5,7 @@ use pyo3::exceptions::{PyAttributeError, PyRecursionError, PyRuntimeError};
 use pyo3::gc::PyVisit;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PySet};
 use pyo3::PyTraverseError;
 
 use pyo3::types::PyString;
@@ -476,6 +476,9 @@ impl SerializationCallable {
         if let Some(context) = &self.extra_owned.context {
             visit.call(context)?;
         }
+        if let Some(missing_value) = &self.extra_owned.missing_value {
+            visit.call(missing_value)?;
+        }
         Ok(())
     }
 
@@ -485,20 +488,63 @@ impl SerializationCallable {
         self.extra_owned.model = None;
         self.extra_owned.fallback = None;
         self.extra_owned.context = None;
+        self.extra_owned.missing_value = None;
+    }
+}
+
+/// Restrict `include` to the names in `only_fields`, dict includes keep the nested include of each name
+fn include_only_fields<'py>(
+    include: Option<&Bound<'py, PyAny>>,
+    only_fields: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = only_fields.py();
+    let only_fields = PySet::new(py, only_fields.try_iter()?.collect::<PyResult<Vec<_>>>()?)?;
+    let Some(include) = include else {
+        return Ok(only_fields.into_any());
+    };
+    if let Ok(include_dict) = include.downcast::<PyDict>() {
+        let new_include = PyDict::new(py);
+        for (key, value) in include_dict {
+            if only_fields.contains(&key)? {
+                new_include.set_item(key, value)?;
+            }
+        }
+        Ok(new_include.into_any())
+    } else {
+        let new_include = PySet::empty(py)?;
+        for key in include.try_iter()? {
+            let key = key?;
+            if only_fields.contains(&key)? {
+                new_include.add(key)?;
+            }
+        }
+        Ok(new_include.into_any())
     }
 }
 
 #[pymethods]
 impl SerializationCallable {
-    #[pyo3(signature = (value, index_key=None))]
+    #[pyo3(signature = (value, index_key=None, *, include=None, exclude=None, only_fields=None))]
     fn __call__(
         &mut self,
         py: Python,
         value: &Bound<'_, PyAny>,
         index_key: Option<&Bound<'_, PyAny>>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        only_fields: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<Option<PyObject>> {
-        let include = self.include.as_ref().map(|o| o.bind(py));
-        let exclude = self.exclude.as_ref().map(|o| o.bind(py));
+        // `include` and `exclude` passed to the handler take precedence over those of the current call
+        let include = include
+            .cloned()
+            .or_else(|| self.include.as_ref().map(|o| o.bind(py).clone()));
+        let exclude = exclude.or_else(|| self.exclude.as_ref().map(|o| o.bind(py)));
+        // `only_fields` skips every other field, rather than serializing them and dropping the output
+        let include = match only_fields {
+            Some(only_fields) => Some(include_only_fields(include.as_ref(), only_fields)?),
+            None => include,
+        };
+        let include = include.as_ref();
         let extra = self.extra_owned.to_extra(py);
 
         if let Some(index_key) = index_key {
@@ -0,0 +1,114 @@
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyTuple, PyType};
+use pyo3::IntoPyObjectExt;
+
+use crate::definitions::DefinitionsBuilder;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    SerMode, TypeSerializer,
+};
+
+fn import_types<'py>(
+    py: Python<'py>,
+    cell: &'static GILOnceCell<Py<PyTuple>>,
+    names: [&str; 2],
+) -> &'py Bound<'py, PyTuple> {
+    cell.get_or_init(py, || {
+        let module = py.import("ipaddress").unwrap();
+        let types: Vec<Bound<'_, PyType>> = names
+            .iter()
+            .map(|name| module.getattr(*name).unwrap().downcast_into().unwrap())
+            .collect();
+        PyTuple::new(py, types).unwrap().unbind()
+    })
+    .bind(py)
+}
+
+macro_rules! build_serializer {
+    ($struct_name:ident, $expected_type:literal, $v4:literal, $v6:literal) => {
+        #[derive(Debug)]
+        pub struct $struct_name;
+
+        impl $struct_name {
+            fn is_instance(value: &Bound<'_, PyAny>) -> bool {
+                static TYPES: GILOnceCell<Py<PyTuple>> = GILOnceCell::new();
+                value
+                    .is_instance(import_types(value.py(), &TYPES, [$v4, $v6]))
+                    .unwrap_or(false)
+            }
+        }
+
+        impl BuildSerializer for $struct_name {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                _schema: &Bound<'_, PyDict>,
+                _config: Option<&Bound<'_, PyDict>>,
+                _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+            ) -> PyResult<CombinedSerializer> {
+                Ok(Self {}.into())
+            }
+        }
+
+        impl_py_gc_traverse!($struct_name {});
+
+        impl TypeSerializer for $struct_name {
+            fn to_python(
+                &self,
+                value: &Bound<'_, PyAny>,
+                include: Option<&Bound<'_, PyAny>>,
+                exclude: Option<&Bound<'_, PyAny>>,
+                extra: &Extra,
+            ) -> PyResult<PyObject> {
+                if Self::is_instance(value) {
+                    match extra.mode {
+                        SerMode::Json => value.str()?.into_py_any(value.py()),
+                        _ => Ok(value.clone().unbind()),
+                    }
+                } else {
+                    extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                    infer_to_python(value, include, exclude, extra)
+                }
+            }
+
+            fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+                if Self::is_instance(key) {
+                    Ok(Cow::Owned(key.str()?.to_string()))
+                } else {
+                    extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                    infer_json_key(key, extra)
+                }
+            }
+
+            fn serde_serialize<S: serde::ser::Serializer>(
+                &self,
+                value: &Bound<'_, PyAny>,
+                serializer: S,
+                include: Option<&Bound<'_, PyAny>>,
+                exclude: Option<&Bound<'_, PyAny>>,
+                extra: &Extra,
+            ) -> Result<S::Ok, S::Error> {
+                if Self::is_instance(value) {
+                    let py_str = value.str().map_err(py_err_se_err)?;
+                    serializer.serialize_str(py_str.to_str().map_err(py_err_se_err)?)
+                } else {
+                    extra
+                        .warnings
+                        .on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                    infer_serialize(value, serializer, include, exclude, extra)
+                }
+            }
+
+            fn get_name(&self) -> &str {
+                Self::EXPECTED_TYPE
+            }
+        }
+    };
+}
+build_serializer!(IpAddressSerializer, "ip-address", "IPv4Address", "IPv6Address");
+build_serializer!(IpNetworkSerializer, "ip-network", "IPv4Network", "IPv6Network");
+build_serializer!(IpInterfaceSerializer, "ip-interface", "IPv4Interface", "IPv6Interface");
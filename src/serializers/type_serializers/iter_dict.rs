@@ -0,0 +1,182 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyIterator, PyString, PyTuple};
+
+use pyo3::IntoPyObjectExt;
+use serde::ser::SerializeMap;
+
+use crate::definitions::DefinitionsBuilder;
+use crate::tools::SchemaDict;
+
+use super::any::AnySerializer;
+use super::{
+    infer_serialize, infer_to_python, py_err_se_err, sorted_dict, BuildSerializer, CombinedSerializer, Extra,
+    FilteredValue, ObjectMembers, PydanticSerializationError, PydanticSerializer, SchemaFilter, SerMode,
+    TypeSerializer,
+};
+
+/// Serializes an iterable of `(key, value)` pairs (e.g. a generator or `dict.items()`) as a dict,
+/// in JSON the pairs are written to the output one at a time without building an intermediate dict
+#[derive(Debug)]
+pub struct IterDictSerializer {
+    key_serializer: Box<CombinedSerializer>,
+    value_serializer: Box<CombinedSerializer>,
+    // isize because we look up include exclude via `.hash()` which returns an isize
+    filter: SchemaFilter<isize>,
+    name: String,
+}
+
+impl BuildSerializer for IterDictSerializer {
+    const EXPECTED_TYPE: &'static str = "iter-dict";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let key_serializer = match schema.get_as(intern!(py, "keys_schema"))? {
+            Some(keys_schema) => CombinedSerializer::build(&keys_schema, config, definitions)?,
+            None => AnySerializer::build(schema, config, definitions)?,
+        };
+        let value_serializer = match schema.get_as(intern!(py, "values_schema"))? {
+            Some(values_schema) => CombinedSerializer::build(&values_schema, config, definitions)?,
+            None => AnySerializer::build(schema, config, definitions)?,
+        };
+        let name = format!(
+            "{}[{}, {}]",
+            Self::EXPECTED_TYPE,
+            key_serializer.get_name(),
+            value_serializer.get_name()
+        );
+        Ok(Self {
+            key_serializer: Box::new(key_serializer),
+            value_serializer: Box::new(value_serializer),
+            filter: SchemaFilter::default(),
+            name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(IterDictSerializer {
+    key_serializer,
+    value_serializer
+});
+
+/// Iterator over the pairs of `value`, `None` if `value` can't be serialized as an iter-dict
+fn iter_pairs<'py>(value: &Bound<'py, PyAny>) -> Option<Bound<'py, PyIterator>> {
+    if let Ok(py_dict) = value.downcast::<PyDict>() {
+        py_dict.items().try_iter().ok()
+    } else if value.is_instance_of::<PyString>() || value.is_instance_of::<PyBytes>() {
+        None
+    } else {
+        value.try_iter().ok()
+    }
+}
+
+fn as_pair(item: PyResult<Bound<'_, PyAny>>) -> PyResult<(Bound<'_, PyAny>, Bound<'_, PyAny>)> {
+    let item = item?;
+    match item.downcast::<PyTuple>() {
+        Ok(pair) if pair.len() == 2 => Ok((pair.get_item(0)?, pair.get_item(1)?)),
+        _ => Err(PydanticSerializationError::new_err(format!(
+            "Expected a `(key, value)` pair, got {}",
+            item.repr()?
+        ))),
+    }
+}
+
+impl TypeSerializer for IterDictSerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match iter_pairs(value) {
+            Some(py_iter) => {
+                let value_serializer = self.value_serializer.as_ref();
+
+                let new_dict = PyDict::new(py);
+                for item in py_iter {
+                    let (key, value) = as_pair(item)?;
+                    let op_next = self.filter.key_filter(&key, include, exclude)?;
+                    if let Some((next_include, next_exclude)) = op_next {
+                        let key = match extra.mode {
+                            SerMode::Json => self.key_serializer.json_key(&key, extra)?.into_py_any(py)?,
+                            _ => self.key_serializer.to_python(&key, None, None, extra)?,
+                        };
+                        let value =
+                            value_serializer.to_python(&value, next_include.as_ref(), next_exclude.as_ref(), extra)?;
+                        new_dict.set_item(key, value)?;
+                    }
+                }
+                Ok(sorted_dict(new_dict, extra)?.into())
+            }
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        self.invalid_as_json_key(key, extra, Self::EXPECTED_TYPE)
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match iter_pairs(value) {
+            Some(py_iter) => {
+                let mut map = serializer.serialize_map(value.len().ok())?;
+                let key_serializer = self.key_serializer.as_ref();
+                let value_serializer = self.value_serializer.as_ref();
+                let mut members = ObjectMembers::new(extra);
+                let write_member =
+                    |map: &mut S::SerializeMap, key: &str, (value, next_include, next_exclude): FilteredValue| {
+                        let value_serialize = PydanticSerializer::new(
+                            &value,
+                            value_serializer,
+                            next_include.as_ref(),
+                            next_exclude.as_ref(),
+                            extra,
+                        );
+                        map.serialize_entry(key, &value_serialize)
+                    };
+
+                for item in py_iter {
+                    let (key, value) = as_pair(item).map_err(py_err_se_err)?;
+                    let op_next = self.filter.key_filter(&key, include, exclude).map_err(py_err_se_err)?;
+                    if let Some((next_include, next_exclude)) = op_next {
+                        let key = key_serializer.json_key(&key, extra).map_err(py_err_se_err)?;
+                        if let Some((key, member)) = members.add(key, (value, next_include, next_exclude)) {
+                            write_member(&mut map, &key, member)?;
+                        }
+                    }
+                }
+                for (key, member) in members.into_sorted() {
+                    write_member(&mut map, &key, member)?;
+                }
+                map.end()
+            }
+            None => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
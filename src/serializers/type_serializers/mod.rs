@@ -1,6 +1,8 @@
 pub mod any;
 pub mod bytes;
+pub mod collections;
 pub mod complex;
+pub mod context_switch;
 pub mod dataclass;
 pub mod datetime_etc;
 pub mod decimal;
@@ -9,8 +11,11 @@ pub mod dict;
 pub mod enum_;
 pub mod float;
 pub mod format;
+pub mod fraction;
 pub mod function;
 pub mod generator;
+pub mod ip;
+pub mod iter_dict;
 pub mod json;
 pub mod json_or_python;
 pub mod list;
@@ -18,6 +23,9 @@ pub mod literal;
 pub mod model;
 pub mod nullable;
 pub mod other;
+pub mod path;
+pub mod protocol_custom;
+pub mod secret;
 pub mod set_frozenset;
 pub mod simple;
 pub mod string;
@@ -33,8 +41,10 @@ use super::computed_fields::ComputedFields;
 use super::config::utf8_py_error;
 use super::errors::{py_err_se_err, PydanticSerializationError};
 use super::extra::{Extra, ExtraOwned, SerCheck, SerMode};
-use super::fields::{FieldsMode, GeneralFieldsSerializer, SerField};
+use super::fields::{FieldOrder, FieldsMode, GeneralFieldsSerializer, SerField};
 use super::filter::{AnyFilter, SchemaFilter};
+use super::format::JsonFormat;
 use super::infer::{infer_json_key, infer_json_key_known, infer_serialize, infer_to_python};
 use super::ob_type::{IsType, ObType};
+use super::ordering::{sorted_dict, FilteredValue, ObjectMembers};
 use super::shared::{to_json_bytes, BuildSerializer, CombinedSerializer, PydanticSerializer, TypeSerializer};
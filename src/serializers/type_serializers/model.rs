@@ -9,11 +9,11 @@ use pyo3::IntoPyObjectExt;
 
 use super::{
     infer_json_key, infer_json_key_known, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer,
-    CombinedSerializer, ComputedFields, Extra, FieldsMode, GeneralFieldsSerializer, ObType, SerCheck, SerField,
-    TypeSerializer,
+    CombinedSerializer, ComputedFields, Extra, FieldOrder, FieldsMode, GeneralFieldsSerializer, ObType, SerCheck,
+    SerField, TypeSerializer,
 };
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{py_schema_error_type, ExtraBehavior};
+use crate::build_tools::{nested_config, py_schema_error_type, AliasGenerator, ExtraBehavior};
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::errors::PydanticSerializationUnexpectedValue;
 use crate::serializers::extra::DuckTypingSerMode;
@@ -49,7 +49,8 @@ impl BuildSerializer for ModelFieldsBuilder {
 
         let serialize_by_alias = config.get_as(intern!(py, "serialize_by_alias"))?;
 
-        for (key, value) in fields_dict {
+        let alias_generator = AliasGenerator::serialization(config)?;
+        for (key, value) in &fields_dict {
             let key_py = key.downcast_into::<PyString>()?;
             let key: String = key_py.extract()?;
             let field_info = value.downcast()?;
@@ -59,7 +60,9 @@ impl BuildSerializer for ModelFieldsBuilder {
             if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
                 fields.insert(key, SerField::new(py, key_py, None, None, true, serialize_by_alias));
             } else {
-                let alias: Option<String> = field_info.get_as(intern!(py, "serialization_alias"))?;
+                let alias: Option<String> = field_info
+                    .get_as(intern!(py, "serialization_alias"))?
+                    .or_else(|| alias_generator.map(|generator| generator.generate(&key)));
 
                 let schema = field_info.get_as_req(intern!(py, "schema"))?;
                 let serializer = CombinedSerializer::build(&schema, config, definitions)
@@ -67,14 +70,16 @@ impl BuildSerializer for ModelFieldsBuilder {
 
                 fields.insert(
                     key,
-                    SerField::new(py, key_py, alias, Some(serializer), true, serialize_by_alias),
+                    SerField::new(py, key_py, alias, Some(serializer), true, serialize_by_alias)
+                        .with_exclude_conditions(field_info)?,
                 );
             }
         }
 
         let computed_fields = ComputedFields::new(schema, config, definitions)?;
+        let field_order = FieldOrder::from_config(py, config, fields_dict.keys().iter().map(|key| key.extract()))?;
 
-        Ok(GeneralFieldsSerializer::new(fields, fields_mode, extra_serializer, computed_fields).into())
+        Ok(GeneralFieldsSerializer::new(fields, fields_mode, extra_serializer, computed_fields, field_order).into())
     }
 }
 
@@ -92,13 +97,13 @@ impl BuildSerializer for ModelSerializer {
 
     fn build(
         schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        config: Option<&Bound<'_, PyDict>>,
         definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
         let py = schema.py();
 
-        // models ignore the parent config and always use the config from this model
-        let config = schema.get_as(intern!(py, "config"))?;
+        // models use the config from this model, combined with the parent config according to `config_merge`
+        let config = nested_config(schema, config)?;
 
         let class: Py<PyType> = schema.get_as_req(intern!(py, "cls"))?;
         let sub_schema = schema.get_as_req(intern!(py, "schema"))?;
@@ -149,6 +154,19 @@ impl ModelSerializer {
                 }
             }
             attrs = new_attrs;
+        } else if let Some(missing_value) = extra.missing_value {
+            let fields_set = model
+                .getattr(intern!(py, "__pydantic_fields_set__"))?
+                .downcast_into::<PySet>()?;
+
+            // the fields serializer recognises `missing_value` by identity and serializes it by inference
+            let new_attrs = attrs.copy()?;
+            for key in attrs.keys() {
+                if !fields_set.contains(&key)? {
+                    new_attrs.set_item(key, missing_value)?;
+                }
+            }
+            attrs = new_attrs;
         }
 
         if self.has_extra {
@@ -3,15 +3,51 @@ use std::borrow::Cow;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use pyo3::{PyTraverseError, PyVisit};
 
 use crate::definitions::DefinitionsBuilder;
+use crate::errors::PydanticOmit;
+use crate::py_gc::PyGcTraverse;
 use crate::tools::SchemaDict;
 
-use super::{infer_json_key_known, BuildSerializer, CombinedSerializer, Extra, IsType, ObType, TypeSerializer};
+use super::{
+    infer_json_key, infer_json_key_known, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra,
+    IsType, ObType, TypeSerializer,
+};
+
+/// What `None` is replaced with when serializing to JSON, set with the `none_as` key
+#[derive(Debug)]
+enum NoneAs {
+    Null,
+    Value(PyObject),
+    /// `none_as=PydanticOmit`, the key is left out when the value is a field
+    Omit,
+}
+
+impl NoneAs {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        match schema.get_as::<Bound<'_, PyAny>>(intern!(py, "none_as"))? {
+            None => Ok(Self::Null),
+            Some(v) if v.is(&py.get_type::<PydanticOmit>()) => Ok(Self::Omit),
+            Some(v) => Ok(Self::Value(v.unbind())),
+        }
+    }
+}
+
+impl PyGcTraverse for NoneAs {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Self::Value(obj) = self {
+            visit.call(obj)?;
+        }
+        Ok(())
+    }
+}
 
 #[derive(Debug)]
 pub struct NullableSerializer {
     serializer: Box<CombinedSerializer>,
+    none_as: NoneAs,
 }
 
 impl BuildSerializer for NullableSerializer {
@@ -25,12 +61,13 @@ impl BuildSerializer for NullableSerializer {
         let sub_schema = schema.get_as_req(intern!(schema.py(), "schema"))?;
         Ok(Self {
             serializer: Box::new(CombinedSerializer::build(&sub_schema, config, definitions)?),
+            none_as: NoneAs::from_schema(schema)?,
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(NullableSerializer { serializer });
+impl_py_gc_traverse!(NullableSerializer { serializer, none_as });
 
 impl TypeSerializer for NullableSerializer {
     fn to_python(
@@ -42,7 +79,12 @@ impl TypeSerializer for NullableSerializer {
     ) -> PyResult<PyObject> {
         let py = value.py();
         match extra.ob_type_lookup.is_type(value, ObType::None) {
-            IsType::Exact => Ok(py.None()),
+            IsType::Exact => match self.none_as {
+                NoneAs::Value(ref none_as) if extra.mode.is_json() => {
+                    infer_to_python(none_as.bind(py), None, None, extra)
+                }
+                _ => Ok(py.None()),
+            },
             // I don't think subclasses of None can exist
             _ => self.serializer.to_python(value, include, exclude, extra),
         }
@@ -50,7 +92,12 @@ impl TypeSerializer for NullableSerializer {
 
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
         match extra.ob_type_lookup.is_type(key, ObType::None) {
-            IsType::Exact => infer_json_key_known(ObType::None, key, extra),
+            IsType::Exact => match self.none_as {
+                NoneAs::Value(ref none_as) => {
+                    Ok(Cow::Owned(infer_json_key(none_as.bind(key.py()), extra)?.into_owned()))
+                }
+                _ => infer_json_key_known(ObType::None, key, extra),
+            },
             _ => self.serializer.json_key(key, extra),
         }
     }
@@ -64,7 +111,10 @@ impl TypeSerializer for NullableSerializer {
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         match extra.ob_type_lookup.is_type(value, ObType::None) {
-            IsType::Exact => serializer.serialize_none(),
+            IsType::Exact => match self.none_as {
+                NoneAs::Value(ref none_as) => infer_serialize(none_as.bind(value.py()), serializer, None, None, extra),
+                _ => serializer.serialize_none(),
+            },
             _ => self
                 .serializer
                 .serde_serialize(value, serializer, include, exclude, extra),
@@ -78,4 +128,8 @@ impl TypeSerializer for NullableSerializer {
     fn retry_with_lax_check(&self) -> bool {
         self.serializer.retry_with_lax_check()
     }
+
+    fn omit_field(&self, value: &Bound<'_, PyAny>, extra: &Extra) -> bool {
+        matches!(self.none_as, NoneAs::Omit) && extra.mode.is_json() && value.is_none()
+    }
 }
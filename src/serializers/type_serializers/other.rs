@@ -7,6 +7,7 @@ use crate::definitions::DefinitionsBuilder;
 use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
+use super::tuple::TupleSerializer;
 use super::{BuildSerializer, CombinedSerializer};
 
 pub struct ChainBuilder;
@@ -91,6 +92,25 @@ impl BuildSerializer for ArgumentsBuilder {
     }
 }
 
+pub struct NamedTupleBuilder;
+
+impl BuildSerializer for NamedTupleBuilder {
+    const EXPECTED_TYPE: &'static str = "namedtuple";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        // with item schemas, namedtuples serialize like the equivalent fixed length tuple
+        if schema.contains(intern!(schema.py(), "items_schema"))? {
+            TupleSerializer::build(schema, config, definitions)
+        } else {
+            AnySerializer::build(schema, config, definitions)
+        }
+    }
+}
+
 macro_rules! any_build_serializer {
     ($struct_name:ident, $expected_type:literal) => {
         pub struct $struct_name;
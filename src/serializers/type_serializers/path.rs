@@ -0,0 +1,93 @@
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyType};
+use pyo3::{intern, IntoPyObjectExt};
+
+use crate::definitions::DefinitionsBuilder;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    SerMode, TypeSerializer,
+};
+
+static PURE_PATH_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+fn is_path(value: &Bound<'_, PyAny>) -> bool {
+    let py = value.py();
+    let pure_path = PURE_PATH_TYPE.get_or_init(py, || {
+        py.import(intern!(py, "pathlib"))
+            .and_then(|pathlib| pathlib.getattr(intern!(py, "PurePath")))
+            .and_then(|pure_path| pure_path.extract())
+            .unwrap()
+    });
+    value.is_instance(pure_path.bind(py)).unwrap_or(false)
+}
+
+#[derive(Debug)]
+pub struct PathSerializer;
+
+impl_py_gc_traverse!(PathSerializer {});
+
+impl BuildSerializer for PathSerializer {
+    const EXPECTED_TYPE: &'static str = "path";
+
+    fn build(
+        _schema: &Bound<'_, PyDict>,
+        _config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        Ok(Self {}.into())
+    }
+}
+
+impl TypeSerializer for PathSerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        if is_path(value) {
+            match extra.mode {
+                SerMode::Json => value.str()?.into_py_any(value.py()),
+                _ => Ok(value.clone().unbind()),
+            }
+        } else {
+            extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+            infer_to_python(value, include, exclude, extra)
+        }
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        if is_path(key) {
+            Ok(Cow::Owned(key.str()?.to_string()))
+        } else {
+            extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+            infer_json_key(key, extra)
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        if is_path(value) {
+            let py_str = value.str().map_err(py_err_se_err)?;
+            serializer.serialize_str(py_str.to_str().map_err(py_err_se_err)?)
+        } else {
+            extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+            infer_serialize(value, serializer, include, exclude, extra)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
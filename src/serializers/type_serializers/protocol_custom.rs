@@ -0,0 +1,91 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::definitions::DefinitionsBuilder;
+use crate::tools::SchemaDict;
+
+use super::any::AnySerializer;
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    TypeSerializer,
+};
+
+/// Serializes instances of `cls` by calling `__serialize_core__` on them, then inferring how to
+/// serialize whatever that returns. The function is looked up on the class once when the
+/// serializer is built.
+#[derive(Debug)]
+pub struct ProtocolCustomSerializer {
+    class: PyObject,
+    serialize_core: PyObject,
+}
+
+impl BuildSerializer for ProtocolCustomSerializer {
+    const EXPECTED_TYPE: &'static str = "protocol-custom";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        _config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let class: Bound<'_, PyAny> = schema.get_as_req(intern!(py, "cls"))?;
+        match class.getattr_opt(intern!(py, "__serialize_core__"))? {
+            Some(serialize_core) => Ok(Self {
+                class: class.unbind(),
+                serialize_core: serialize_core.unbind(),
+            }
+            .into()),
+            None => Ok(AnySerializer.into()),
+        }
+    }
+}
+
+impl_py_gc_traverse!(ProtocolCustomSerializer { class, serialize_core });
+
+impl ProtocolCustomSerializer {
+    /// Call `__serialize_core__` on instances of `cls`, anything else is returned unchanged
+    fn prepare<'py>(&self, value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let py = value.py();
+        if value.is_instance(self.class.bind(py))? {
+            self.serialize_core.bind(py).call1((value,))
+        } else {
+            Ok(value.clone())
+        }
+    }
+}
+
+impl TypeSerializer for ProtocolCustomSerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        infer_to_python(&self.prepare(value)?, include, exclude, extra)
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        let prepared = self.prepare(key)?;
+        Ok(Cow::Owned(infer_json_key(&prepared, extra)?.into_owned()))
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        let prepared = self.prepare(value).map_err(py_err_se_err)?;
+        infer_serialize(&prepared, serializer, include, exclude, extra)
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
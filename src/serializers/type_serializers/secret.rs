@@ -0,0 +1,105 @@
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::IntoPyObjectExt;
+
+use crate::definitions::DefinitionsBuilder;
+use crate::secret::{PySecret, MASKED_SECRET};
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, SerMode,
+    TypeSerializer,
+};
+
+macro_rules! build_serializer {
+    ($struct_name:ident, $expected_type:literal) => {
+        #[derive(Debug)]
+        pub struct $struct_name;
+
+        impl BuildSerializer for $struct_name {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                _schema: &Bound<'_, PyDict>,
+                _config: Option<&Bound<'_, PyDict>>,
+                _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+            ) -> PyResult<CombinedSerializer> {
+                Ok(Self {}.into())
+            }
+        }
+
+        impl_py_gc_traverse!($struct_name {});
+
+        // secrets are masked unless `serialize_secrets` is set, in python mode the `Secret` itself is returned
+        impl TypeSerializer for $struct_name {
+            fn to_python(
+                &self,
+                value: &Bound<'_, PyAny>,
+                include: Option<&Bound<'_, PyAny>>,
+                exclude: Option<&Bound<'_, PyAny>>,
+                extra: &Extra,
+            ) -> PyResult<PyObject> {
+                let py = value.py();
+                match value.downcast::<PySecret>() {
+                    Ok(secret) if extra.serialize_secrets => {
+                        infer_to_python(secret.get().value(py), include, exclude, extra)
+                    }
+                    Ok(_) => match extra.mode {
+                        SerMode::Json => MASKED_SECRET.into_py_any(py),
+                        _ => Ok(value.clone().unbind()),
+                    },
+                    Err(_) => {
+                        extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                        infer_to_python(value, include, exclude, extra)
+                    }
+                }
+            }
+
+            fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+                match key.downcast::<PySecret>() {
+                    Ok(secret) if extra.serialize_secrets => Ok(Cow::Owned(
+                        infer_json_key(secret.get().value(key.py()), extra)?.into_owned(),
+                    )),
+                    Ok(_) => Ok(Cow::Borrowed(MASKED_SECRET)),
+                    Err(_) => {
+                        extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                        infer_json_key(key, extra)
+                    }
+                }
+            }
+
+            fn serde_serialize<S: serde::ser::Serializer>(
+                &self,
+                value: &Bound<'_, PyAny>,
+                serializer: S,
+                include: Option<&Bound<'_, PyAny>>,
+                exclude: Option<&Bound<'_, PyAny>>,
+                extra: &Extra,
+            ) -> Result<S::Ok, S::Error> {
+                match value.downcast::<PySecret>() {
+                    Ok(secret) if extra.serialize_secrets => infer_serialize(
+                        secret.get().value(value.py()),
+                        serializer,
+                        include,
+                        exclude,
+                        extra,
+                    ),
+                    Ok(_) => serializer.serialize_str(MASKED_SECRET),
+                    Err(_) => {
+                        extra
+                            .warnings
+                            .on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                        infer_serialize(value, serializer, include, exclude, extra)
+                    }
+                }
+            }
+
+            fn get_name(&self) -> &str {
+                Self::EXPECTED_TYPE
+            }
+        }
+    };
+}
+build_serializer!(SecretStrSerializer, "secret_str");
+build_serializer!(SecretBytesSerializer, "secret_bytes");
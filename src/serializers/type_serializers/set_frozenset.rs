@@ -11,8 +11,8 @@ use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
 use super::{
-    infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer, SerMode,
-    TypeSerializer,
+    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer,
+    SerMode, TypeSerializer,
 };
 
 macro_rules! build_serializer {
@@ -61,12 +61,19 @@ macro_rules! build_serializer {
                         let item_serializer = self.item_serializer.as_ref();
 
                         let mut items = Vec::with_capacity(py_set.len());
-                        for element in py_set.iter() {
-                            items.push(item_serializer.to_python(&element, include, exclude, extra)?);
-                        }
                         match extra.mode {
-                            SerMode::Json => Ok(PyList::new(py, items)?.into()),
-                            _ => <$py_type>::new(py, &items)?.into_py_any(py),
+                            SerMode::Json => {
+                                for element in extra.set_order.elements(py_set)? {
+                                    items.push(item_serializer.to_python(&element, include, exclude, extra)?);
+                                }
+                                Ok(PyList::new(py, items)?.into())
+                            }
+                            _ => {
+                                for element in py_set.iter() {
+                                    items.push(item_serializer.to_python(&element, include, exclude, extra)?);
+                                }
+                                <$py_type>::new(py, &items)?.into_py_any(py)
+                            }
                         }
                     }
                     Err(_) => {
@@ -93,7 +100,7 @@ macro_rules! build_serializer {
                         let mut seq = serializer.serialize_seq(Some(py_set.len()))?;
                         let item_serializer = self.item_serializer.as_ref();
 
-                        for value in py_set.iter() {
+                        for value in extra.set_order.elements(py_set).map_err(py_err_se_err)? {
                             let item_serialize =
                                 PydanticSerializer::new(&value, item_serializer, include, exclude, extra);
                             seq.serialize_element(&item_serialize)?;
@@ -87,7 +87,16 @@ impl TypeSerializer for NoneSerializer {
 }
 
 macro_rules! build_simple_serializer {
-    ($struct_name:ident, $expected_type:literal, $rust_type:ty, $ob_type:expr, $key_method:ident, $subtypes_allowed:expr) => {
+    (
+        $struct_name:ident,
+        $expected_type:literal,
+        $rust_type:ty,
+        $ob_type:expr,
+        $key_method:ident,
+        $subtypes_allowed:expr,
+        $json_python:path,
+        $serialize:path
+    ) => {
         #[derive(Debug)]
         pub struct $struct_name;
 
@@ -121,11 +130,16 @@ macro_rules! build_simple_serializer {
             ) -> PyResult<PyObject> {
                 let py = value.py();
                 match extra.ob_type_lookup.is_type(value, $ob_type) {
-                    IsType::Exact => Ok(value.clone().unbind()),
+                    IsType::Exact => match extra.mode {
+                        SerMode::Json => $json_python(value, extra),
+                        _ => Ok(value.clone().unbind()),
+                    },
                     IsType::Subclass => match extra.check {
                         SerCheck::Strict => Err(PydanticSerializationUnexpectedValue::new_from_msg(None).to_py_err()),
                         SerCheck::Lax | SerCheck::None => match extra.mode {
-                            SerMode::Json => value.extract::<$rust_type>()?.into_py_any(py),
+                            SerMode::Json => {
+                                $json_python(&value.extract::<$rust_type>()?.into_bound_py_any(py)?, extra)
+                            }
                             _ => infer_to_python(value, include, exclude, extra),
                         },
                     },
@@ -155,7 +169,7 @@ macro_rules! build_simple_serializer {
                 extra: &Extra,
             ) -> Result<S::Ok, S::Error> {
                 match value.extract::<$rust_type>() {
-                    Ok(v) => v.serialize(serializer),
+                    Ok(v) => $serialize(v, serializer, extra),
                     Err(_) => {
                         extra
                             .warnings
@@ -180,7 +194,24 @@ pub(crate) fn to_str_json_key<'a>(key: &'a Bound<'_, PyAny>) -> PyResult<Cow<'a,
     Ok(Cow::Owned(key.str()?.to_string_lossy().into_owned()))
 }
 
-build_simple_serializer!(IntSerializer, "int", Int, ObType::Int, to_str_json_key, true);
+fn int_json_python(value: &Bound<'_, PyAny>, extra: &Extra) -> PyResult<PyObject> {
+    extra.config.bigint_mode.to_python(value)
+}
+
+fn serialize_int<S: serde::ser::Serializer>(value: Int, serializer: S, extra: &Extra) -> Result<S::Ok, S::Error> {
+    extra.config.bigint_mode.serialize_int(&value, serializer)
+}
+
+build_simple_serializer!(
+    IntSerializer,
+    "int",
+    Int,
+    ObType::Int,
+    to_str_json_key,
+    true,
+    int_json_python,
+    serialize_int
+);
 
 pub(crate) fn bool_json_key<'a>(key: &'a Bound<'_, PyAny>) -> PyResult<Cow<'a, str>> {
     let v = if key.is_truthy().unwrap_or(false) {
@@ -191,4 +222,21 @@ pub(crate) fn bool_json_key<'a>(key: &'a Bound<'_, PyAny>) -> PyResult<Cow<'a, s
     Ok(Cow::Borrowed(v))
 }
 
-build_simple_serializer!(BoolSerializer, "bool", bool, ObType::Bool, bool_json_key, false);
+fn bool_json_python(value: &Bound<'_, PyAny>, _extra: &Extra) -> PyResult<PyObject> {
+    Ok(value.clone().unbind())
+}
+
+fn serialize_bool<S: serde::ser::Serializer>(value: bool, serializer: S, _extra: &Extra) -> Result<S::Ok, S::Error> {
+    value.serialize(serializer)
+}
+
+build_simple_serializer!(
+    BoolSerializer,
+    "bool",
+    bool,
+    ObType::Bool,
+    bool_json_key,
+    false,
+    bool_json_python,
+    serialize_bool
+);
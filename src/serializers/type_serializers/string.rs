@@ -1,9 +1,15 @@
 use std::borrow::Cow;
 
-use pyo3::types::{PyDict, PyString};
-use pyo3::{prelude::*, IntoPyObjectExt};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyString};
+use serde::ser::SerializeStruct;
+use serde::Serialize;
 
 use crate::definitions::DefinitionsBuilder;
+use crate::serializers::config::InvalidUtf8Mode;
+use crate::serializers::errors::PydanticSerializationError;
+use crate::serializers::ser::SURROGATES_TOKEN;
 
 use super::{
     infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
@@ -41,11 +47,10 @@ impl TypeSerializer for StrSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
-        let py = value.py();
+        let mode = extra.config.invalid_utf8_mode;
         match extra.ob_type_lookup.is_type(value, ObType::Str) {
-            IsType::Exact => Ok(value.clone().unbind()),
-            IsType::Subclass => match extra.mode {
-                SerMode::Json => value.downcast::<PyString>()?.to_str()?.into_py_any(py),
+            IsType::Exact | IsType::Subclass => match extra.mode {
+                SerMode::Json => Ok(json_py_str(value.downcast()?, mode)?.into_any().unbind()),
                 _ => Ok(value.clone().unbind()),
             },
             IsType::False => {
@@ -57,8 +62,7 @@ impl TypeSerializer for StrSerializer {
 
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
         if let Ok(py_str) = key.downcast::<PyString>() {
-            // FIXME py cow to avoid the copy
-            Ok(Cow::Owned(py_str.to_string_lossy().into_owned()))
+            json_key_py_str(py_str, extra.config.invalid_utf8_mode)
         } else {
             extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
             infer_json_key(key, extra)
@@ -74,7 +78,7 @@ impl TypeSerializer for StrSerializer {
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         match value.downcast::<PyString>() {
-            Ok(py_str) => serialize_py_str(py_str, serializer),
+            Ok(py_str) => serialize_py_str(py_str, serializer, extra.config.invalid_utf8_mode),
             Err(_) => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
@@ -90,7 +94,125 @@ impl TypeSerializer for StrSerializer {
 pub fn serialize_py_str<S: serde::ser::Serializer>(
     py_str: &Bound<'_, PyString>,
     serializer: S,
+    mode: InvalidUtf8Mode,
 ) -> Result<S::Ok, S::Error> {
-    let s = py_str.to_str().map_err(py_err_se_err)?;
-    serializer.serialize_str(s)
+    match py_str.to_str() {
+        Ok(s) => serializer.serialize_str(s),
+        Err(err) => match mode {
+            InvalidUtf8Mode::Error => Err(py_err_se_err(err)),
+            InvalidUtf8Mode::Replace => serializer.serialize_str(&replace_surrogates(py_str).map_err(py_err_se_err)?),
+            InvalidUtf8Mode::SurrogatePass => {
+                let encoded = encode_surrogatepass(py_str).map_err(py_err_se_err)?;
+                let mut surrogates = serializer.serialize_struct(SURROGATES_TOKEN, 1)?;
+                surrogates.serialize_field(SURROGATES_TOKEN, &SurrogatePassBytes(encoded.as_bytes()))?;
+                surrogates.end()
+            }
+        },
+    }
+}
+
+/// An exact `str` for JSON compatible python output, `py_str` is returned unchanged if possible
+pub(crate) fn json_py_str<'py>(py_str: &Bound<'py, PyString>, mode: InvalidUtf8Mode) -> PyResult<Bound<'py, PyString>> {
+    let py = py_str.py();
+    let exact = py_str.is_exact_instance_of::<PyString>();
+    // only `replace` changes exact strings, so avoid checking them for surrogates otherwise
+    if exact && mode != InvalidUtf8Mode::Replace {
+        return Ok(py_str.clone());
+    }
+    match py_str.to_str() {
+        Ok(_) if exact => Ok(py_str.clone()),
+        Ok(s) => Ok(PyString::new(py, s)),
+        Err(err) => match mode {
+            InvalidUtf8Mode::Error => Err(err),
+            InvalidUtf8Mode::Replace => Ok(PyString::new(py, &replace_surrogates(py_str)?)),
+            InvalidUtf8Mode::SurrogatePass => {
+                PyString::from_object(encode_surrogatepass(py_str)?.as_any(), "utf-8", "surrogatepass")
+            }
+        },
+    }
+}
+
+/// Dict keys are written as rust strings, so lone surrogates can only be replaced,
+/// by default keys are written lossily rather than raising an error as values do
+pub(crate) fn json_key_py_str<'a>(py_str: &'a Bound<'_, PyString>, mode: InvalidUtf8Mode) -> PyResult<Cow<'a, str>> {
+    match py_str.to_cow() {
+        Ok(s) => Ok(s),
+        Err(_) => match mode {
+            InvalidUtf8Mode::Error => Ok(py_str.to_string_lossy()),
+            InvalidUtf8Mode::Replace => Ok(Cow::Owned(replace_surrogates(py_str)?)),
+            InvalidUtf8Mode::SurrogatePass => Err(PydanticSerializationError::new_err(
+                "Dict keys containing lone surrogates can't be serialized with `ser_json_invalid_utf8='surrogatepass'`"
+                    .to_string(),
+            )),
+        },
+    }
+}
+
+/// `str.encode(value, 'utf-8', 'surrogatepass')`, called on `str` so subclasses can't override `encode`
+fn encode_surrogatepass<'py>(py_str: &Bound<'py, PyString>) -> PyResult<Bound<'py, PyBytes>> {
+    let py = py_str.py();
+    py.get_type::<PyString>()
+        .call_method1(
+            intern!(py, "encode"),
+            (py_str, intern!(py, "utf-8"), intern!(py, "surrogatepass")),
+        )?
+        .downcast_into()
+        .map_err(Into::into)
+}
+
+/// Replace each lone surrogate with U+FFFD, the replacement character
+fn replace_surrogates(py_str: &Bound<'_, PyString>) -> PyResult<String> {
+    let encoded = encode_surrogatepass(py_str)?;
+    let mut replaced = String::with_capacity(encoded.as_bytes().len());
+    for chunk in surrogatepass_chunks(encoded.as_bytes()) {
+        match chunk {
+            SurrogatePassChunk::Str(s) => replaced.push_str(s),
+            SurrogatePassChunk::Surrogate(_) => replaced.push(char::REPLACEMENT_CHARACTER),
+        }
+    }
+    Ok(replaced)
+}
+
+struct SurrogatePassBytes<'a>(&'a [u8]);
+
+impl Serialize for SurrogatePassBytes<'_> {
+    fn serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+pub(crate) enum SurrogatePassChunk<'a> {
+    Str(&'a str),
+    Surrogate(u16),
+}
+
+/// Split UTF-8 encoded with the `surrogatepass` error handler into valid strings and lone surrogates,
+/// which are encoded as three bytes `0xED 0xA0..=0xBF 0x80..=0xBF`
+pub(crate) fn surrogatepass_chunks(mut bytes: &[u8]) -> impl Iterator<Item = SurrogatePassChunk<'_>> {
+    std::iter::from_fn(move || {
+        if bytes.is_empty() {
+            return None;
+        }
+        let valid_up_to = match std::str::from_utf8(bytes) {
+            Ok(s) => {
+                bytes = &[];
+                return Some(SurrogatePassChunk::Str(s));
+            }
+            Err(err) => err.valid_up_to(),
+        };
+        if valid_up_to > 0 {
+            let (valid, rest) = bytes.split_at(valid_up_to);
+            bytes = rest;
+            return std::str::from_utf8(valid).ok().map(SurrogatePassChunk::Str);
+        }
+        match *bytes {
+            [0xED, high @ 0xA0..=0xBF, low @ 0x80..=0xBF, ref rest @ ..] => {
+                bytes = rest;
+                let unit = 0xD000 | (u16::from(high & 0x3F) << 6) | u16::from(low & 0x3F);
+                Some(SurrogatePassChunk::Surrogate(unit))
+            }
+            // not produced by `surrogatepass`
+            _ => None,
+        }
+    })
 }
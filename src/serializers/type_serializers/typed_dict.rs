@@ -5,11 +5,13 @@ use pyo3::types::{PyDict, PyString};
 use ahash::AHashMap;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{py_schema_error_type, schema_or_config, ExtraBehavior};
+use crate::build_tools::{nested_config, py_schema_error_type, schema_or_config, AliasGenerator, ExtraBehavior};
 use crate::definitions::DefinitionsBuilder;
 use crate::tools::SchemaDict;
 
-use super::{BuildSerializer, CombinedSerializer, ComputedFields, FieldsMode, GeneralFieldsSerializer, SerField};
+use super::{
+    BuildSerializer, CombinedSerializer, ComputedFields, FieldOrder, FieldsMode, GeneralFieldsSerializer, SerField,
+};
 
 #[derive(Debug)]
 pub struct TypedDictBuilder;
@@ -24,6 +26,14 @@ impl BuildSerializer for TypedDictBuilder {
     ) -> PyResult<CombinedSerializer> {
         let py = schema.py();
 
+        // a typed dict's own config is combined with the parent config according to `config_merge`,
+        // typed dicts without one use the parent config
+        let config = match schema.contains(intern!(py, "config"))? {
+            true => nested_config(schema, config)?,
+            false => config.cloned(),
+        };
+        let config = config.as_ref();
+
         let total =
             schema_or_config(schema, config, intern!(py, "total"), intern!(py, "typed_dict_total"))?.unwrap_or(true);
 
@@ -45,7 +55,8 @@ impl BuildSerializer for TypedDictBuilder {
             (_, _) => None,
         };
 
-        for (key, value) in fields_dict {
+        let alias_generator = AliasGenerator::serialization(config)?;
+        for (key, value) in &fields_dict {
             let key_py = key.downcast_into::<PyString>()?;
             let key: String = key_py.extract()?;
             let field_info = value.downcast()?;
@@ -56,20 +67,24 @@ impl BuildSerializer for TypedDictBuilder {
             if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
                 fields.insert(key, SerField::new(py, key_py, None, None, required, serialize_by_alias));
             } else {
-                let alias: Option<String> = field_info.get_as(intern!(py, "serialization_alias"))?;
+                let alias: Option<String> = field_info
+                    .get_as(intern!(py, "serialization_alias"))?
+                    .or_else(|| alias_generator.map(|generator| generator.generate(&key)));
 
                 let schema = field_info.get_as_req(intern!(py, "schema"))?;
                 let serializer = CombinedSerializer::build(&schema, config, definitions)
                     .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", key, e))?;
                 fields.insert(
                     key,
-                    SerField::new(py, key_py, alias, Some(serializer), required, serialize_by_alias),
+                    SerField::new(py, key_py, alias, Some(serializer), required, serialize_by_alias)
+                        .with_exclude_conditions(field_info)?,
                 );
             }
         }
 
         let computed_fields = ComputedFields::new(schema, config, definitions)?;
+        let field_order = FieldOrder::from_config(py, config, fields_dict.keys().iter().map(|key| key.extract()))?;
 
-        Ok(GeneralFieldsSerializer::new(fields, fields_mode, extra_serializer, computed_fields).into())
+        Ok(GeneralFieldsSerializer::new(fields, fields_mode, extra_serializer, computed_fields, field_order).into())
     }
 }
@@ -20,6 +20,7 @@ use super::{
 pub struct UnionSerializer {
     choices: Vec<CombinedSerializer>,
     name: String,
+    warn_lax_retry: bool,
 }
 
 impl BuildSerializer for UnionSerializer {
@@ -43,12 +44,13 @@ impl BuildSerializer for UnionSerializer {
             })
             .collect::<PyResult<Vec<CombinedSerializer>>>()?;
 
-        Self::from_choices(choices)
+        let warn_lax_retry = config.get_as(intern!(py, "ser_warn_lax_retry"))?.unwrap_or(false);
+        Self::from_choices(choices, warn_lax_retry)
     }
 }
 
 impl UnionSerializer {
-    fn from_choices(choices: Vec<CombinedSerializer>) -> PyResult<CombinedSerializer> {
+    fn from_choices(choices: Vec<CombinedSerializer>, warn_lax_retry: bool) -> PyResult<CombinedSerializer> {
         match choices.len() {
             0 => py_schema_err!("One or more union choices required"),
             1 => Ok(choices.into_iter().next().unwrap()),
@@ -61,11 +63,16 @@ impl UnionSerializer {
                 Ok(Self {
                     choices,
                     name: format!("Union[{descr}]"),
+                    warn_lax_retry,
                 }
                 .into())
             }
         }
     }
+
+    fn report_lax_retry(&self) -> Option<&str> {
+        self.warn_lax_retry.then_some(self.name.as_str())
+    }
 }
 
 impl_py_gc_traverse!(UnionSerializer { choices });
@@ -78,6 +85,8 @@ fn union_serialize<S>(
     extra: &Extra,
     choices: &[CombinedSerializer],
     retry_with_lax_check: bool,
+    // union name to report to `extra.warnings` when a choice only matches after the lax retry
+    report_lax_retry: Option<&str>,
     py: Python<'_>,
 ) -> PyResult<Option<S>> {
     // try the serializers in left to right order with error_on fallback=true
@@ -97,6 +106,9 @@ fn union_serialize<S>(
         new_extra.check = SerCheck::Lax;
         for comb_serializer in choices {
             if let Ok(v) = selector(comb_serializer, &new_extra) {
+                if let Some(union_name) = report_lax_retry {
+                    extra.warnings.register_lax_retry(union_name, extra);
+                }
                 return Ok(Some(v));
             }
         }
@@ -140,6 +152,7 @@ impl TypeSerializer for UnionSerializer {
             extra,
             &self.choices,
             self.retry_with_lax_check(),
+            self.report_lax_retry(),
             value.py(),
         )?
         .map_or_else(|| infer_to_python(value, include, exclude, extra), Ok)
@@ -151,6 +164,7 @@ impl TypeSerializer for UnionSerializer {
             extra,
             &self.choices,
             self.retry_with_lax_check(),
+            self.report_lax_retry(),
             key.py(),
         )?
         .map_or_else(|| infer_json_key(key, extra), Ok)
@@ -169,6 +183,7 @@ impl TypeSerializer for UnionSerializer {
             extra,
             &self.choices,
             self.retry_with_lax_check(),
+            self.report_lax_retry(),
             value.py(),
         ) {
             Ok(Some(v)) => infer_serialize(v.bind(value.py()), serializer, None, None, extra),
@@ -192,6 +207,7 @@ pub struct TaggedUnionSerializer {
     lookup: HashMap<String, usize>,
     choices: Vec<CombinedSerializer>,
     name: String,
+    warn_lax_retry: bool,
 }
 
 impl BuildSerializer for TaggedUnionSerializer {
@@ -227,6 +243,7 @@ impl BuildSerializer for TaggedUnionSerializer {
             lookup,
             choices,
             name: format!("TaggedUnion[{descr}]"),
+            warn_lax_retry: config.get_as(intern!(py, "ser_warn_lax_retry"))?.unwrap_or(false),
         }
         .into())
     }
@@ -292,6 +309,10 @@ impl TypeSerializer for TaggedUnionSerializer {
 }
 
 impl TaggedUnionSerializer {
+    fn report_lax_retry(&self) -> Option<&str> {
+        self.warn_lax_retry.then_some(self.name.as_str())
+    }
+
     fn get_discriminator_value<'py>(&self, value: &Bound<'py, PyAny>) -> Option<Bound<'py, PyAny>> {
         let py = value.py();
         match &self.discriminator {
@@ -307,6 +328,20 @@ impl TaggedUnionSerializer {
                 .map(|(_, tag)| tag)
             }
             Discriminator::Function(func) => func.bind(py).call1((value,)).ok(),
+            Discriminator::Composite(lookup_keys) => {
+                let value_dict = value.downcast::<PyDict>().ok();
+                let tag = lookup_keys
+                    .iter()
+                    .map(|lookup_key| {
+                        match value_dict {
+                            Some(value_dict) => lookup_key.py_get_dict_item(value_dict).ok().flatten(),
+                            None => lookup_key.simple_py_get_attr(value).ok().flatten(),
+                        }
+                        .map(|(_, tag)| tag)
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                PyTuple::new(py, tag).ok().map(Bound::into_any)
+            }
         }
     }
 
@@ -332,6 +367,9 @@ impl TaggedUnionSerializer {
                         if self.retry_with_lax_check() {
                             new_extra.check = SerCheck::Lax;
                             if let Ok(v) = selector(selected_serializer, &new_extra) {
+                                if let Some(union_name) = self.report_lax_retry() {
+                                    extra.warnings.register_lax_retry(union_name, extra);
+                                }
                                 return Ok(Some(v));
                             }
                         }
@@ -353,6 +391,13 @@ impl TaggedUnionSerializer {
         // if we haven't returned at this point, we should fallback to the union serializer
         // which preserves the historical expectation that we do our best with serialization
         // even if that means we resort to inference
-        union_serialize(selector, extra, &self.choices, self.retry_with_lax_check(), value.py())
+        union_serialize(
+            selector,
+            extra,
+            &self.choices,
+            self.retry_with_lax_check(),
+            self.report_lax_retry(),
+            value.py(),
+        )
     }
 }
@@ -6,6 +6,7 @@ use pyo3::IntoPyObjectExt;
 
 use crate::definitions::DefinitionsBuilder;
 
+use crate::email::PyEmailAddress;
 use crate::url::{PyMultiHostUrl, PyUrl};
 
 use super::{
@@ -13,6 +14,28 @@ use super::{
     TypeSerializer,
 };
 
+trait SerializedStr {
+    fn to_serialized_str(&self, round_trip: bool) -> Cow<'_, str>;
+}
+
+impl SerializedStr for PyUrl {
+    fn to_serialized_str(&self, round_trip: bool) -> Cow<'_, str> {
+        Cow::Borrowed(self.serialized_str(round_trip))
+    }
+}
+
+impl SerializedStr for PyMultiHostUrl {
+    fn to_serialized_str(&self, round_trip: bool) -> Cow<'_, str> {
+        self.serialized_str(round_trip)
+    }
+}
+
+impl SerializedStr for PyEmailAddress {
+    fn to_serialized_str(&self, _round_trip: bool) -> Cow<'_, str> {
+        Cow::Owned(self.__str__())
+    }
+}
+
 macro_rules! build_serializer {
     ($struct_name:ident, $expected_type:literal, $extract:ty) => {
         #[derive(Debug)]
@@ -43,7 +66,7 @@ macro_rules! build_serializer {
                 let py = value.py();
                 match value.extract::<$extract>() {
                     Ok(py_url) => match extra.mode {
-                        SerMode::Json => py_url.__str__().into_py_any(py),
+                        SerMode::Json => py_url.to_serialized_str(extra.round_trip).into_py_any(py),
                         _ => Ok(value.clone().unbind()),
                     },
                     Err(_) => {
@@ -55,7 +78,9 @@ macro_rules! build_serializer {
 
             fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
                 match key.extract::<$extract>() {
-                    Ok(py_url) => Ok(Cow::Owned(py_url.__str__().to_string())),
+                    Ok(py_url) => Ok(Cow::Owned(
+                        py_url.to_serialized_str(extra.round_trip).into_owned(),
+                    )),
                     Err(_) => {
                         extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
                         infer_json_key(key, extra)
@@ -72,7 +97,7 @@ macro_rules! build_serializer {
                 extra: &Extra,
             ) -> Result<S::Ok, S::Error> {
                 match value.extract::<$extract>() {
-                    Ok(py_url) => serializer.serialize_str(&py_url.__str__()),
+                    Ok(py_url) => serializer.serialize_str(&py_url.to_serialized_str(extra.round_trip)),
                     Err(_) => {
                         extra
                             .warnings
@@ -90,3 +115,4 @@ macro_rules! build_serializer {
 }
 build_serializer!(UrlSerializer, "url", PyUrl);
 build_serializer!(MultiHostUrlSerializer, "multi-host-url", PyMultiHostUrl);
+build_serializer!(EmailSerializer, "email", PyEmailAddress);
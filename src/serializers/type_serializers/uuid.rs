@@ -5,21 +5,52 @@ use pyo3::{intern, prelude::*, IntoPyObjectExt};
 use uuid::Uuid;
 
 use crate::definitions::DefinitionsBuilder;
+use crate::serializers::config::{FromConfig, UuidMode};
 
 use super::{
     infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
     IsType, ObType, SerMode, TypeSerializer,
 };
 
+fn uuid_to_int(py_uuid: &Bound<'_, PyAny>) -> PyResult<u128> {
+    py_uuid.getattr(intern!(py_uuid.py(), "int"))?.extract()
+}
+
 pub(crate) fn uuid_to_string(py_uuid: &Bound<'_, PyAny>) -> PyResult<String> {
-    let py = py_uuid.py();
-    let uuid_int_val: u128 = py_uuid.getattr(intern!(py, "int"))?.extract()?;
-    let uuid = Uuid::from_u128(uuid_int_val);
+    let uuid = Uuid::from_u128(uuid_to_int(py_uuid)?);
     Ok(uuid.to_string())
 }
 
+pub(crate) fn uuid_to_json(py_uuid: &Bound<'_, PyAny>, uuid_mode: UuidMode) -> PyResult<PyObject> {
+    let py = py_uuid.py();
+    match uuid_mode {
+        UuidMode::Str => uuid_to_string(py_uuid)?.into_py_any(py),
+        UuidMode::Int => uuid_to_int(py_uuid)?.into_py_any(py),
+    }
+}
+
+pub(crate) fn uuid_json_key(py_uuid: &Bound<'_, PyAny>, uuid_mode: UuidMode) -> PyResult<String> {
+    match uuid_mode {
+        UuidMode::Str => uuid_to_string(py_uuid),
+        UuidMode::Int => Ok(uuid_to_int(py_uuid)?.to_string()),
+    }
+}
+
+pub(crate) fn serialize_uuid<S: serde::ser::Serializer>(
+    py_uuid: &Bound<'_, PyAny>,
+    uuid_mode: UuidMode,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match uuid_mode {
+        UuidMode::Str => serializer.serialize_str(&uuid_to_string(py_uuid).map_err(py_err_se_err)?),
+        UuidMode::Int => serializer.serialize_u128(uuid_to_int(py_uuid).map_err(py_err_se_err)?),
+    }
+}
+
 #[derive(Debug)]
-pub struct UuidSerializer;
+pub struct UuidSerializer {
+    uuid_mode: UuidMode,
+}
 
 impl_py_gc_traverse!(UuidSerializer {});
 
@@ -28,10 +59,11 @@ impl BuildSerializer for UuidSerializer {
 
     fn build(
         _schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        Ok(Self {}.into())
+        let uuid_mode = UuidMode::from_config(config)?;
+        Ok(Self { uuid_mode }.into())
     }
 }
 
@@ -43,10 +75,9 @@ impl TypeSerializer for UuidSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
-        let py = value.py();
         match extra.ob_type_lookup.is_type(value, ObType::Uuid) {
             IsType::Exact | IsType::Subclass => match extra.mode {
-                SerMode::Json => uuid_to_string(value)?.into_py_any(py),
+                SerMode::Json => uuid_to_json(value, self.uuid_mode),
                 _ => Ok(value.clone().unbind()),
             },
             IsType::False => {
@@ -58,10 +89,7 @@ impl TypeSerializer for UuidSerializer {
 
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
         match extra.ob_type_lookup.is_type(key, ObType::Uuid) {
-            IsType::Exact | IsType::Subclass => {
-                let str = uuid_to_string(key)?;
-                Ok(Cow::Owned(str))
-            }
+            IsType::Exact | IsType::Subclass => Ok(Cow::Owned(uuid_json_key(key, self.uuid_mode)?)),
             IsType::False => {
                 extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
                 infer_json_key(key, extra)
@@ -78,10 +106,7 @@ impl TypeSerializer for UuidSerializer {
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         match extra.ob_type_lookup.is_type(value, ObType::Uuid) {
-            IsType::Exact | IsType::Subclass => {
-                let s = uuid_to_string(value).map_err(py_err_se_err)?;
-                serializer.serialize_str(&s)
-            }
+            IsType::Exact | IsType::Subclass => serialize_uuid(value, self.uuid_mode, serializer),
             IsType::False => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
@@ -71,14 +71,18 @@ impl TypeSerializer for WithDefaultSerializer {
         self.serializer.retry_with_lax_check()
     }
 
+    fn omit_field(&self, value: &Bound<'_, PyAny>, extra: &Extra) -> bool {
+        self.serializer.omit_field(value, extra)
+    }
+
     fn get_default(&self, py: Python) -> PyResult<Option<PyObject>> {
-        if let DefaultType::DefaultFactory(_, _takes_data @ true) = self.default {
+        if let DefaultType::DefaultFactory(_, true, _) | DefaultType::DefaultFactory(_, _, true) = self.default {
             // We currently don't compute the default if the default factory takes
-            // the data from other fields.
+            // the data from other fields or the validation context.
             Ok(None)
         } else {
             self.default.default_value(
-                py, None, // Won't be used.
+                py, None, None, // Won't be used.
             )
         }
     }
@@ -199,3 +199,48 @@ pub fn write_truncated_to_limited_bytes<F: fmt::Write>(f: &mut F, val: &str, max
         write!(f, "{val}")
     }
 }
+
+/// Splits an identifier into words on non-alphanumeric characters and case changes,
+/// e.g. `"HTTPResponse-code2"` becomes `["HTTP", "Response", "code2"]`
+pub(crate) fn split_words(s: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for part in s.split(|c: char| !c.is_alphanumeric()).filter(|part| !part.is_empty()) {
+        let chars: Vec<(usize, char)> = part.char_indices().collect();
+        let mut start = 0;
+        for (i, &(index, c)) in chars.iter().enumerate().skip(1) {
+            let previous = chars[i - 1].1;
+            let next_is_lower = chars.get(i + 1).is_some_and(|&(_, next)| next.is_lowercase());
+            // "fooBar" and "foo2Bar" split before the "B", "HTTPResponse" splits before the "R"
+            if c.is_uppercase() && (!previous.is_uppercase() || next_is_lower) {
+                words.push(&part[start..index]);
+                start = index;
+            }
+        }
+        words.push(&part[start..]);
+    }
+    words
+}
+
+pub(crate) fn to_snake_case(s: &str) -> String {
+    split_words(s)
+        .into_iter()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+pub(crate) fn to_camel_case(s: &str) -> String {
+    let mut camel = String::with_capacity(s.len());
+    for (i, word) in split_words(s).into_iter().enumerate() {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            if i == 0 {
+                camel.extend(first.to_lowercase());
+            } else {
+                camel.extend(first.to_uppercase());
+            }
+            camel.extend(chars.flat_map(char::to_lowercase));
+        }
+    }
+    camel
+}
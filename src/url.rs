@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::collections::hash_map::DefaultHasher;
 use std::fmt;
 use std::fmt::Formatter;
@@ -7,7 +8,7 @@ use idna::punycode::decode_to_string;
 use pyo3::exceptions::PyValueError;
 use pyo3::pyclass::CompareOp;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyDict, PyType};
+use pyo3::types::{PyDict, PyString, PyType};
 use pyo3::{intern, prelude::*, IntoPyObjectExt};
 use url::Url;
 
@@ -15,25 +16,101 @@ use crate::tools::SchemaDict;
 use crate::SchemaValidator;
 
 static SCHEMA_DEFINITION_URL: GILOnceCell<SchemaValidator> = GILOnceCell::new();
+// used to unpickle relative references, which `Url()` rejects
+static RELATIVE_URL_VALIDATOR: GILOnceCell<Py<SchemaValidator>> = GILOnceCell::new();
 
 #[pyclass(name = "Url", module = "pydantic_core._pydantic_core", subclass, frozen)]
-#[derive(Clone, Hash)]
+#[derive(Clone)]
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub struct PyUrl {
     lib_url: Url,
+    // the input string before normalization, kept with `preserve_original`
+    original: Option<String>,
+    // a relative reference, `lib_url` is the reference resolved against `RELATIVE_BASE`
+    relative: bool,
 }
 
+/// Scheme of the placeholder base relative references are resolved against, it's non-special so
+/// relative references without a host are represented with an empty host
+pub(crate) const RELATIVE_SCHEME: &str = "relative-ref";
+
 impl PyUrl {
     pub fn new(lib_url: Url) -> Self {
-        Self { lib_url }
+        Self {
+            lib_url,
+            original: None,
+            relative: false,
+        }
+    }
+
+    /// `lib_url` must be a reference resolved against `relative_base()`
+    pub fn new_relative(lib_url: Url) -> Self {
+        Self {
+            lib_url,
+            original: None,
+            relative: true,
+        }
+    }
+
+    #[must_use]
+    pub fn with_original(mut self, original: String) -> Self {
+        self.original = Some(original);
+        self
     }
 
     pub fn url(&self) -> &Url {
         &self.lib_url
     }
+
+    pub fn mut_url(&mut self) -> &mut Url {
+        &mut self.lib_url
+    }
+
+    /// The string to serialize, the original input when round tripping and it was preserved
+    pub fn serialized_str(&self, round_trip: bool) -> &str {
+        match &self.original {
+            Some(original) if round_trip => original,
+            _ => self.__str__(),
+        }
+    }
+
+    /// Remove the placeholder base from the string of a relative reference
+    fn strip_relative_base<'a>(&self, url_str: &'a str) -> &'a str {
+        if !self.relative {
+            return url_str;
+        }
+        // skip "relative-ref:", then the "//" too if there's no host
+        let url_str = &url_str[RELATIVE_SCHEME.len() + 1..];
+        match self.lib_url.host_str() {
+            Some("") | None => &url_str[2..],
+            Some(_) => url_str,
+        }
+    }
+
+    /// Build a URL of the same type from `lib_url`, relative references can't be revalidated by the
+    /// type's constructor so are always a plain `Url`
+    fn new_like<'py>(slf: &Bound<'py, Self>, lib_url: Url) -> PyResult<Bound<'py, PyAny>> {
+        if slf.get().relative && lib_url.scheme() == RELATIVE_SCHEME {
+            Ok(Bound::new(slf.py(), Self::new_relative(lib_url))?.into_any())
+        } else {
+            slf.get_type().call1((lib_url.as_str(),))
+        }
+    }
+}
+
+pub(crate) fn relative_base() -> Url {
+    Url::parse(&format!("{RELATIVE_SCHEME}://")).expect("relative base is a valid URL")
 }
 
-fn build_schema_validator(py: Python, schema_type: &str) -> SchemaValidator {
+// equality only considers the normalized URL, so the hash must too
+impl Hash for PyUrl {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.relative.hash(state);
+        self.lib_url.hash(state);
+    }
+}
+
+pub(crate) fn build_schema_validator(py: Python, schema_type: &str) -> SchemaValidator {
     let schema = PyDict::new(py);
     schema.set_item("type", schema_type).unwrap();
     SchemaValidator::py_new(py, &schema, None).unwrap()
@@ -45,13 +122,17 @@ impl PyUrl {
     pub fn py_new(py: Python, url: &Bound<'_, PyAny>) -> PyResult<Self> {
         let schema_obj = SCHEMA_DEFINITION_URL
             .get_or_init(py, || build_schema_validator(py, "url"))
-            .validate_python(py, url, None, None, None, None, false.into(), None, None)?;
+            .validate_python_default(py, url)?;
         schema_obj.extract(py)
     }
 
     #[getter]
     pub fn scheme(&self) -> &str {
-        self.lib_url.scheme()
+        if self.relative {
+            ""
+        } else {
+            self.lib_url.scheme()
+        }
     }
 
     #[getter]
@@ -69,14 +150,17 @@ impl PyUrl {
 
     #[getter]
     pub fn host(&self) -> Option<&str> {
-        self.lib_url.host_str()
+        match self.lib_url.host_str() {
+            Some("") if self.relative => None,
+            host => host,
+        }
     }
 
     // string representation of the host, with punycode decoded when appropriate
     pub fn unicode_host(&self) -> Option<String> {
         match self.lib_url.host() {
             Some(url::Host::Domain(domain)) if is_punnycode_domain(&self.lib_url, domain) => decode_punycode(domain),
-            _ => self.lib_url.host_str().map(ToString::to_string),
+            _ => self.host().map(ToString::to_string),
         }
     }
 
@@ -107,32 +191,60 @@ impl PyUrl {
             .into_pyobject(py)
     }
 
+    /// A copy of the URL with the query replaced, either by a string or by key/value pairs to encode
+    pub fn with_query<'py>(slf: &Bound<'py, Self>, query: &Bound<'_, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let mut lib_url = slf.get().lib_url.clone();
+        set_query(&mut lib_url, query)?;
+        Self::new_like(slf, lib_url)
+    }
+
+    /// Resolve `url`, which may be relative, against this URL
+    pub fn join<'py>(slf: &Bound<'py, Self>, url: &str) -> PyResult<Bound<'py, PyAny>> {
+        let joined = slf
+            .get()
+            .lib_url
+            .join(url)
+            .map_err(|e| PyValueError::new_err(format!("unable to join URL: {e}")))?;
+        Self::new_like(slf, joined)
+    }
+
     #[getter]
     pub fn fragment(&self) -> Option<&str> {
         self.lib_url.fragment()
     }
 
+    #[getter]
+    pub fn original(&self) -> Option<&str> {
+        self.original.as_deref()
+    }
+
+    #[getter]
+    pub fn is_relative(&self) -> bool {
+        self.relative
+    }
+
     // string representation of the URL, with punycode decoded when appropriate
     pub fn unicode_string(&self) -> String {
-        unicode_url(&self.lib_url)
+        self.strip_relative_base(&unicode_url(&self.lib_url)).to_string()
     }
 
     pub fn __str__(&self) -> &str {
-        self.lib_url.as_str()
+        self.strip_relative_base(self.lib_url.as_str())
     }
 
     pub fn __repr__(&self) -> String {
-        format!("Url('{}')", self.lib_url)
+        format!("Url('{}')", self.__str__())
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp) -> PyResult<bool> {
+        let (a, b) = ((self.relative, &self.lib_url), (other.relative, &other.lib_url));
         match op {
-            CompareOp::Lt => Ok(self.lib_url < other.lib_url),
-            CompareOp::Le => Ok(self.lib_url <= other.lib_url),
-            CompareOp::Eq => Ok(self.lib_url == other.lib_url),
-            CompareOp::Ne => Ok(self.lib_url != other.lib_url),
-            CompareOp::Gt => Ok(self.lib_url > other.lib_url),
-            CompareOp::Ge => Ok(self.lib_url >= other.lib_url),
+            CompareOp::Lt => Ok(a < b),
+            CompareOp::Le => Ok(a <= b),
+            CompareOp::Eq => Ok(a == b),
+            CompareOp::Ne => Ok(a != b),
+            CompareOp::Gt => Ok(a > b),
+            CompareOp::Ge => Ok(a >= b),
         }
     }
 
@@ -151,8 +263,29 @@ impl PyUrl {
         self.clone().into_py_any(py)
     }
 
-    fn __getnewargs__(&self) -> (&str,) {
-        (self.__str__(),)
+    /// Like pickle's default for `__getnewargs__`, except relative references are rebuilt by a validator which
+    /// allows them, as `Url()` doesn't
+    #[allow(clippy::type_complexity)]
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (String,), Option<Bound<'py, PyAny>>)> {
+        let py = slf.py();
+        let url = slf.get().__str__().to_string();
+        if slf.get().relative {
+            let validator = RELATIVE_URL_VALIDATOR.get_or_try_init(py, || {
+                let schema = PyDict::new(py);
+                schema.set_item(intern!(py, "type"), intern!(py, "url"))?;
+                schema.set_item(intern!(py, "allow_relative"), true)?;
+                Py::new(py, SchemaValidator::py_new(py, &schema, None)?)
+            })?;
+            Ok((
+                validator.bind(py).getattr(intern!(py, "validate_python"))?,
+                (url,),
+                None,
+            ))
+        } else {
+            // instances of subclasses may have a `__dict__`
+            let state = slf.getattr(intern!(py, "__dict__")).ok();
+            Ok((slf.get_type().into_any(), (url,), state))
+        }
     }
 
     #[classmethod]
@@ -215,6 +348,29 @@ impl PyMultiHostUrl {
     pub fn mut_lib_url(&mut self) -> &mut Url {
         &mut self.ref_url.lib_url
     }
+
+    /// A URL for each host, in order, the last is the reference URL
+    pub fn host_urls(&self) -> Vec<&Url> {
+        match &self.extra_urls {
+            Some(extra_urls) => extra_urls.iter().chain([&self.ref_url.lib_url]).collect(),
+            None if self.ref_url.lib_url.has_host() => vec![&self.ref_url.lib_url],
+            None => vec![],
+        }
+    }
+
+    #[must_use]
+    pub fn with_original(mut self, original: String) -> Self {
+        self.ref_url.original = Some(original);
+        self
+    }
+
+    /// The string to serialize, the original input when round tripping and it was preserved
+    pub fn serialized_str(&self, round_trip: bool) -> Cow<'_, str> {
+        match &self.ref_url.original {
+            Some(original) if round_trip => Cow::Borrowed(original),
+            _ => Cow::Owned(self.__str__()),
+        }
+    }
 }
 
 static SCHEMA_DEFINITION_MULTI_HOST_URL: GILOnceCell<SchemaValidator> = GILOnceCell::new();
@@ -225,7 +381,7 @@ impl PyMultiHostUrl {
     pub fn py_new(py: Python, url: &Bound<'_, PyAny>) -> PyResult<Self> {
         let schema_obj = SCHEMA_DEFINITION_MULTI_HOST_URL
             .get_or_init(py, || build_schema_validator(py, "multi-host-url"))
-            .validate_python(py, url, None, None, None, None, false.into(), None, None)?;
+            .validate_python_default(py, url)?;
         schema_obj.extract(py)
     }
 
@@ -235,18 +391,7 @@ impl PyMultiHostUrl {
     }
 
     pub fn hosts<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
-        if let Some(extra_urls) = &self.extra_urls {
-            let mut hosts = Vec::with_capacity(extra_urls.len() + 1);
-            for url in extra_urls {
-                hosts.push(host_to_dict(py, url)?);
-            }
-            hosts.push(host_to_dict(py, &self.ref_url.lib_url)?);
-            Ok(hosts)
-        } else if self.ref_url.lib_url.has_host() {
-            Ok(vec![host_to_dict(py, &self.ref_url.lib_url)?])
-        } else {
-            Ok(vec![])
-        }
+        self.host_urls().into_iter().map(|url| host_to_dict(py, url)).collect()
     }
 
     #[getter]
@@ -263,11 +408,23 @@ impl PyMultiHostUrl {
         self.ref_url.query_params(py)
     }
 
+    /// A copy of the URL with the query replaced, either by a string or by key/value pairs to encode
+    pub fn with_query<'py>(slf: &Bound<'py, Self>, query: &Bound<'_, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let mut url = slf.get().clone();
+        set_query(url.mut_lib_url(), query)?;
+        slf.get_type().call1((url.__str__(),))
+    }
+
     #[getter]
     pub fn fragment(&self) -> Option<&str> {
         self.ref_url.fragment()
     }
 
+    #[getter]
+    pub fn original(&self) -> Option<&str> {
+        self.ref_url.original()
+    }
+
     // string representation of the URL, with punycode decoded when appropriate
     pub fn unicode_string(&self) -> String {
         if let Some(extra_urls) = &self.extra_urls {
@@ -467,6 +624,21 @@ impl fmt::Display for UrlHostParts {
     }
 }
 
+fn set_query(lib_url: &mut Url, query: &Bound<'_, PyAny>) -> PyResult<()> {
+    if query.is_none() {
+        lib_url.set_query(None);
+    } else if let Ok(query) = query.downcast::<PyString>() {
+        lib_url.set_query(Some(query.to_str()?));
+    } else {
+        let pairs: Vec<(String, String)> = match query.downcast::<PyDict>() {
+            Ok(dict) => dict.items().extract()?,
+            Err(_) => query.extract()?,
+        };
+        lib_url.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+    Ok(())
+}
+
 fn host_to_dict<'a>(py: Python<'a>, lib_url: &Url) -> PyResult<Bound<'a, PyDict>> {
     let dict = PyDict::new(py);
     dict.set_item("username", Some(lib_url.username()).filter(|s| !s.is_empty()))?;
@@ -240,16 +240,26 @@ impl Validator for ArgumentsValidator {
                         parameter.name.clone(),
                     ));
                 }
-                (Some(pos_value), None) => match parameter.validator.validate(py, pos_value.borrow_input(), state) {
-                    Ok(value) => output_args.push(value),
-                    Err(ValError::LineErrors(line_errors)) => {
-                        errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                (Some(pos_value), None) => {
+                    let report_mark = state.report_mark();
+                    match parameter.validator.validate(py, pos_value.borrow_input(), state) {
+                        Ok(value) => {
+                            state.reports_with_outer_location(report_mark, index);
+                            output_args.push(value);
+                        }
+                        Err(ValError::LineErrors(line_errors)) => {
+                            errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                        }
+                        Err(err) => return Err(err),
                     }
-                    Err(err) => return Err(err),
-                },
+                }
                 (None, Some((lookup_path, kw_value))) => {
+                    let report_mark = state.report_mark();
                     match parameter.validator.validate(py, kw_value.borrow_input(), state) {
-                        Ok(value) => output_kwargs.set_item(parameter.kwarg_key.as_ref().unwrap(), value)?,
+                        Ok(value) => {
+                            state.reports_with_outer_location(report_mark, parameter.name.as_str());
+                            output_kwargs.set_item(parameter.kwarg_key.as_ref().unwrap(), value)?;
+                        }
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(
                                 line_errors
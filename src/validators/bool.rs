@@ -40,7 +40,7 @@ impl Validator for BoolValidator {
         // and back again, might be worth profiling?
         input
             .validate_bool(state.strict_or(self.strict))
-            .and_then(|val_match| Ok(val_match.unpack(state).into_py_any(py)?))
+            .and_then(|val_match| Ok(val_match.unpack_coerced(state, input, "bool").into_py_any(py)?))
     }
 
     fn get_name(&self) -> &str {
@@ -52,7 +52,7 @@ impl Validator for BytesValidator {
     ) -> ValResult<PyObject> {
         input
             .validate_bytes(state.strict_or(self.strict), self.bytes_mode)
-            .and_then(|m| Ok(m.unpack(state).into_py_any(py)?))
+            .and_then(|m| Ok(m.unpack_coerced(state, input, "bytes").into_py_any(py)?))
     }
 
     fn get_name(&self) -> &str {
@@ -79,7 +79,7 @@ impl Validator for BytesConstrainedValidator {
     ) -> ValResult<PyObject> {
         let either_bytes = input
             .validate_bytes(state.strict_or(self.strict), self.bytes_mode)?
-            .unpack(state);
+            .unpack_coerced(state, input, "bytes");
         let len = either_bytes.len()?;
 
         if let Some(min_length) = self.min_length {
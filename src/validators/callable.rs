@@ -1,40 +1,253 @@
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple, PyWeakrefMethods, PyWeakrefReference};
+use pyo3::{PyTraverseError, PyVisit};
 
-use crate::errors::{ErrorTypeDefaults, ValError, ValResult};
+use crate::build_tools::py_schema_err;
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::Input;
+use crate::tools::SchemaDict;
 
 use super::validation_state::Exactness;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
+static INSPECT_SIGNATURE: GILOnceCell<PyObject> = GILOnceCell::new();
+static INSPECT_ISCOROUTINEFUNCTION: GILOnceCell<PyObject> = GILOnceCell::new();
+
 #[derive(Debug, Clone)]
-pub struct CallableValidator;
+pub struct CallableValidator {
+    arity: Option<usize>,
+    required_keyword_params: Option<RequiredKeywords>,
+    must_be_coroutine: bool,
+    // `{id(callable): (weakref to callable, CallableCheck)}`, so each callable's signature is only inspected once,
+    // keyed on identity as callables which compare equal may have different signatures.
+    // `None` if there's nothing to check beyond `callable()`
+    checked: Option<Py<PyDict>>,
+}
+
+/// The outcome of checking a callable's signature
+#[derive(Debug, Clone, Copy)]
+enum CallableCheck {
+    Valid,
+    Arity,
+    Keywords,
+    Coroutine,
+}
+
+impl CallableCheck {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Valid),
+            1 => Some(Self::Arity),
+            2 => Some(Self::Keywords),
+            3 => Some(Self::Coroutine),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RequiredKeywords {
+    // `{name: None}` for each parameter, passed to `Signature.bind()`
+    kwargs: Py<PyDict>,
+    // used in the error message, e.g. `'a', 'b'`
+    repr: String,
+}
 
 impl BuildValidator for CallableValidator {
     const EXPECTED_TYPE: &'static str = "callable";
 
     fn build(
-        _schema: &Bound<'_, PyDict>,
+        schema: &Bound<'_, PyDict>,
         _config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        Ok(Self.into())
+        let py = schema.py();
+        let arity = match (
+            schema.get_as::<usize>(intern!(py, "arity"))?,
+            schema.get_item(intern!(py, "callable_type"))?,
+        ) {
+            (Some(_), Some(_)) => return py_schema_err!("'arity' and 'callable_type' cannot be used together"),
+            (Some(arity), None) => Some(arity),
+            (None, Some(callable_type)) => callable_type_arity(&callable_type)?,
+            (None, None) => None,
+        };
+        let required_keyword_params =
+            match schema.get_as::<Bound<'_, PyList>>(intern!(py, "required_keyword_params"))? {
+                Some(params) if !params.is_empty() => {
+                    let kwargs = PyDict::new(py);
+                    let mut reprs = Vec::with_capacity(params.len());
+                    for param in params.iter() {
+                        let param = param.downcast_into::<PyString>()?;
+                        reprs.push(param.repr()?.to_string());
+                        kwargs.set_item(param, py.None())?;
+                    }
+                    Some(RequiredKeywords {
+                        kwargs: kwargs.unbind(),
+                        repr: reprs.join(", "),
+                    })
+                }
+                _ => None,
+            };
+        let must_be_coroutine = schema.get_as(intern!(py, "must_be_coroutine"))?.unwrap_or(false);
+        let checked = if arity.is_some() || required_keyword_params.is_some() || must_be_coroutine {
+            Some(PyDict::new(py).unbind())
+        } else {
+            None
+        };
+        Ok(Self {
+            arity,
+            required_keyword_params,
+            must_be_coroutine,
+            checked,
+        }
+        .into())
+    }
+}
+
+/// Weakref callback removing a garbage collected callable from `CallableValidator`'s cache
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+struct ForgetCallable {
+    checked: Py<PyDict>,
+    key: usize,
+}
+
+#[pymethods]
+impl ForgetCallable {
+    fn __call__(&self, py: Python<'_>, _weakref: &Bound<'_, PyAny>) -> PyResult<()> {
+        let checked = self.checked.bind(py);
+        if checked.contains(self.key)? {
+            checked.del_item(self.key)?;
+        }
+        Ok(())
+    }
+
+    fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
+        visit.call(&self.checked)
+    }
+}
+
+/// The number of parameters of a `Callable[[int, str], bool]` style type, `None` for `Callable[..., bool]`
+fn callable_type_arity(callable_type: &Bound<'_, PyAny>) -> PyResult<Option<usize>> {
+    let py = callable_type.py();
+    let args = py
+        .import(intern!(py, "typing"))?
+        .call_method1(intern!(py, "get_args"), (callable_type,))?;
+    let args = args.downcast::<PyTuple>()?;
+    match args.len() {
+        0 => Ok(None),
+        2 => {
+            let params = args.get_item(0)?;
+            if params.is(&py.Ellipsis()) {
+                Ok(None)
+            } else if let Ok(params) = params.len() {
+                Ok(Some(params))
+            } else {
+                py_schema_err!("'callable_type' parameters should be a list of types or `...`")
+            }
+        }
+        _ => py_schema_err!("'callable_type' should be a `Callable[[...], ...]` type"),
     }
 }
 
-impl_py_gc_traverse!(CallableValidator {});
+impl_py_gc_traverse!(RequiredKeywords { kwargs });
+
+impl_py_gc_traverse!(CallableValidator {
+    required_keyword_params,
+    checked
+});
+
+impl CallableValidator {
+    /// The outcome of checking `callable`, from the cache if it's been checked before. Callables which can't be
+    /// weakly referenced, and bound methods (a new object on each attribute access), are checked each time.
+    fn cached_check(&self, py: Python<'_>, callable: &Bound<'_, PyAny>) -> PyResult<CallableCheck> {
+        let Some(checked) = &self.checked else {
+            return Ok(CallableCheck::Valid);
+        };
+        let checked = checked.bind(py);
+        let key = callable.as_ptr() as usize;
+        if let Some(cached) = checked.get_item(key)? {
+            let (weakref, check): (Bound<'_, PyWeakrefReference>, u8) = cached.extract()?;
+            if weakref
+                .upgrade()
+                .is_some_and(|cached_callable| cached_callable.is(callable))
+            {
+                if let Some(check) = CallableCheck::from_u8(check) {
+                    return Ok(check);
+                }
+            }
+        }
+        let check = self.check(py, callable)?;
+        // the entry is removed when the callable is garbage collected, so its id can't be reused by another callable
+        let forget = ForgetCallable {
+            checked: checked.clone().unbind(),
+            key,
+        };
+        if let Ok(weakref) = PyWeakrefReference::new_with(callable, forget) {
+            checked.set_item(key, (weakref, check as u8))?;
+        }
+        Ok(check)
+    }
+
+    fn check(&self, py: Python<'_>, callable: &Bound<'_, PyAny>) -> PyResult<CallableCheck> {
+        match self.required_keyword_params {
+            // checked together so a parameter can't be satisfied by both a positional and keyword argument
+            Some(ref keywords) => {
+                let kwargs = keywords.kwargs.bind(py);
+                // without `arity`, other parameters are assumed to be passed positionally
+                let partial = self.arity.is_none();
+                if !accepts_args(py, callable, self.arity.unwrap_or(0), Some(kwargs), partial)? {
+                    // an arity error if the callable can't take `arity` positional arguments at all
+                    return match self.arity {
+                        Some(arity) if !accepts_args(py, callable, arity, None, true)? => Ok(CallableCheck::Arity),
+                        _ => Ok(CallableCheck::Keywords),
+                    };
+                }
+            }
+            None => {
+                if let Some(arity) = self.arity {
+                    if !accepts_args(py, callable, arity, None, false)? {
+                        return Ok(CallableCheck::Arity);
+                    }
+                }
+            }
+        }
+        if self.must_be_coroutine && !is_coroutine_function(py, callable)? {
+            return Ok(CallableCheck::Coroutine);
+        }
+        Ok(CallableCheck::Valid)
+    }
+}
 
 impl Validator for CallableValidator {
     fn validate<'py>(
         &self,
-        _py: Python<'py>,
+        py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         state.floor_exactness(Exactness::Lax);
         if let Some(py_input) = input.as_python() {
             if py_input.is_callable() {
-                return Ok(py_input.clone().unbind());
+                return match self.cached_check(py, py_input)? {
+                    CallableCheck::Valid => Ok(py_input.clone().unbind()),
+                    CallableCheck::Arity => {
+                        let arity = self.arity.unwrap_or(0);
+                        Err(ValError::new(ErrorType::CallableArity { arity, context: None }, input))
+                    }
+                    CallableCheck::Keywords => {
+                        let keywords = self.required_keyword_params.as_ref().map_or("", |k| k.repr.as_str());
+                        Err(ValError::new(
+                            ErrorType::CallableKeywords {
+                                keywords: keywords.to_string(),
+                                context: None,
+                            },
+                            input,
+                        ))
+                    }
+                    CallableCheck::Coroutine => Err(ValError::new(ErrorTypeDefaults::CallableCoroutine, input)),
+                };
             }
         }
         Err(ValError::new(ErrorTypeDefaults::CallableType, input))
@@ -44,3 +257,40 @@ impl Validator for CallableValidator {
         Self::EXPECTED_TYPE
     }
 }
+
+/// Best-effort check that the callable can be called with `arity` positional arguments and `kwargs`, callables
+/// without an inspectable signature (e.g. some builtins) are assumed to be compatible.
+/// If `partial` is true, required parameters may be missing, as with `Signature.bind_partial()`.
+fn accepts_args(
+    py: Python<'_>,
+    callable: &Bound<'_, PyAny>,
+    arity: usize,
+    kwargs: Option<&Bound<'_, PyDict>>,
+    partial: bool,
+) -> PyResult<bool> {
+    let signature = INSPECT_SIGNATURE.get_or_try_init(py, || -> PyResult<PyObject> {
+        Ok(py
+            .import(intern!(py, "inspect"))?
+            .getattr(intern!(py, "signature"))?
+            .unbind())
+    })?;
+    let Ok(signature) = signature.call1(py, (callable,)) else {
+        return Ok(true);
+    };
+    let args = PyTuple::new(py, std::iter::repeat(py.None()).take(arity))?;
+    let method = match partial {
+        true => intern!(py, "bind_partial"),
+        false => intern!(py, "bind"),
+    };
+    Ok(signature.call_method(py, method, args, kwargs).is_ok())
+}
+
+fn is_coroutine_function(py: Python<'_>, callable: &Bound<'_, PyAny>) -> PyResult<bool> {
+    let is_coroutine_function = INSPECT_ISCOROUTINEFUNCTION.get_or_try_init(py, || -> PyResult<PyObject> {
+        Ok(py
+            .import(intern!(py, "inspect"))?
+            .getattr(intern!(py, "iscoroutinefunction"))?
+            .unbind())
+    })?;
+    is_coroutine_function.bind(py).call1((callable,))?.is_truthy()
+}
@@ -0,0 +1,213 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyList, PyType};
+
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::{Input, InputType};
+use crate::tools::SchemaDict;
+
+use super::dict::DictValidator;
+use super::list::ListValidator;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+static COLLECTIONS_TYPES: GILOnceCell<[Py<PyType>; 3]> = GILOnceCell::new();
+
+#[derive(Debug, Clone, Copy)]
+pub enum CollectionType {
+    Deque,
+    Counter,
+    DefaultDict,
+}
+
+impl CollectionType {
+    pub fn py_type(self, py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+        let types = COLLECTIONS_TYPES.get_or_try_init(py, || -> PyResult<[Py<PyType>; 3]> {
+            let module = py.import(intern!(py, "collections"))?;
+            Ok([
+                module.getattr(intern!(py, "deque"))?.extract()?,
+                module.getattr(intern!(py, "Counter"))?.extract()?,
+                module.getattr(intern!(py, "defaultdict"))?.extract()?,
+            ])
+        })?;
+        Ok(types[self as usize].bind(py))
+    }
+
+    /// Returns `Some(input)` if the input is an instance of this collection type, errors in strict mode if not
+    fn instance<'a, 'py>(
+        self,
+        py: Python<'py>,
+        input: &'a (impl Input<'py> + ?Sized),
+        strict: bool,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Option<&'a Bound<'py, PyAny>>> {
+        let class = self.py_type(py)?;
+        if let Some(py_input) = input.as_python().filter(|any| any.is_instance(class).unwrap_or(false)) {
+            return Ok(Some(py_input));
+        }
+        if state.extra().input_type == InputType::Python {
+            if strict {
+                let class = class.qualname()?.to_string();
+                return Err(ValError::new(ErrorType::IsInstanceOf { class, context: None }, input));
+            }
+            state.floor_exactness(Exactness::Lax);
+        }
+        Ok(None)
+    }
+}
+
+#[derive(Debug)]
+pub struct DequeValidator {
+    strict: bool,
+    list_validator: Box<CombinedValidator>,
+    maxlen: Option<usize>,
+}
+
+impl BuildValidator for DequeValidator {
+    const EXPECTED_TYPE: &'static str = "deque";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        Ok(Self {
+            strict: crate::build_tools::is_strict(schema, config)?,
+            list_validator: Box::new(ListValidator::build(schema, config, definitions)?),
+            maxlen: schema.get_as(intern!(schema.py(), "maxlen"))?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(DequeValidator { list_validator });
+
+impl Validator for DequeValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let strict = state.strict_or(self.strict);
+        let output = match CollectionType::Deque.instance(py, input, strict, state)? {
+            // items are validated as a list, which a deque isn't in strict mode
+            Some(deque) => {
+                let items = PyList::new(py, deque.try_iter()?.collect::<PyResult<Vec<_>>>()?)?;
+                self.list_validator.validate(py, items.as_any(), state)?
+            }
+            None => self.list_validator.validate(py, input, state)?,
+        };
+        let deque = CollectionType::Deque.py_type(py)?.call1((output, self.maxlen))?;
+        Ok(deque.unbind())
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+#[derive(Debug)]
+pub struct CounterValidator {
+    strict: bool,
+    dict_validator: Box<CombinedValidator>,
+}
+
+impl BuildValidator for CounterValidator {
+    const EXPECTED_TYPE: &'static str = "counter";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        // counts are ints unless a values schema is given
+        let dict_schema = schema.copy()?;
+        let values_key = intern!(py, "values_schema");
+        if !dict_schema.contains(values_key)? {
+            let int_schema = PyDict::new(py);
+            int_schema.set_item(intern!(py, "type"), intern!(py, "int"))?;
+            dict_schema.set_item(values_key, int_schema)?;
+        }
+        Ok(Self {
+            strict: crate::build_tools::is_strict(schema, config)?,
+            dict_validator: Box::new(DictValidator::build(&dict_schema, config, definitions)?),
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(CounterValidator { dict_validator });
+
+impl Validator for CounterValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        CollectionType::Counter.instance(py, input, state.strict_or(self.strict), state)?;
+        let output = self.dict_validator.validate(py, input, state)?;
+        Ok(CollectionType::Counter.py_type(py)?.call1((output,))?.unbind())
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+#[derive(Debug)]
+pub struct DefaultDictValidator {
+    strict: bool,
+    dict_validator: Box<CombinedValidator>,
+    default_factory: Option<PyObject>,
+}
+
+impl BuildValidator for DefaultDictValidator {
+    const EXPECTED_TYPE: &'static str = "defaultdict";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        Ok(Self {
+            strict: crate::build_tools::is_strict(schema, config)?,
+            dict_validator: Box::new(DictValidator::build(schema, config, definitions)?),
+            default_factory: schema.get_as(intern!(schema.py(), "default_factory"))?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(DefaultDictValidator {
+    dict_validator,
+    default_factory
+});
+
+impl Validator for DefaultDictValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let instance = CollectionType::DefaultDict.instance(py, input, state.strict_or(self.strict), state)?;
+        // without a `default_factory` in the schema, the factory of a `defaultdict` input is kept
+        let default_factory = match (&self.default_factory, instance) {
+            (Some(default_factory), _) => default_factory.bind(py).clone(),
+            (None, Some(instance)) => instance.getattr(intern!(py, "default_factory"))?,
+            (None, None) => py.None().into_bound(py),
+        };
+        let output = self.dict_validator.validate(py, input, state)?;
+        let defaultdict = CollectionType::DefaultDict
+            .py_type(py)?
+            .call1((default_factory, output))?;
+        Ok(defaultdict.unbind())
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
@@ -45,7 +45,9 @@ impl Validator for ComplexValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let res = input.validate_complex(self.strict, py)?.unpack(state);
+        let res = input
+            .validate_complex(self.strict, py)?
+            .unpack_coerced(state, input, "complex");
         Ok(res.into_pyobject(py)?.into())
     }
 
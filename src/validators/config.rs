@@ -7,11 +7,15 @@ use base64::{alphabet, DecodeError, Engine};
 use pyo3::types::{PyDict, PyString};
 use pyo3::{intern, prelude::*};
 
-use crate::errors::ErrorType;
-use crate::input::EitherBytes;
-use crate::serializers::BytesMode;
+use crate::build_tools::py_schema_err;
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::{EitherBytes, Input};
+use crate::py_gc::PyGcTraverse;
+use crate::serializers::{base32_decode, BytesMode};
 use crate::tools::SchemaDict;
 
+use super::function::convert_err;
+
 const URL_SAFE_OPTIONAL_PADDING: GeneralPurpose = GeneralPurpose::new(
     &alphabet::URL_SAFE,
     GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
@@ -51,6 +55,22 @@ impl ValBytesMode {
                     encoding_error: err.to_string(),
                     context: None,
                 }),
+            BytesMode::Base64Url => URL_SAFE_OPTIONAL_PADDING
+                .decode(s)
+                .map(EitherBytes::from)
+                .map_err(|err| ErrorType::BytesInvalidEncoding {
+                    encoding: "base64url".to_string(),
+                    encoding_error: err.to_string(),
+                    context: None,
+                }),
+            BytesMode::Base32 => match base32_decode(s) {
+                Ok(vec) => Ok(EitherBytes::from(vec)),
+                Err(encoding_error) => Err(ErrorType::BytesInvalidEncoding {
+                    encoding: "base32".to_string(),
+                    encoding_error,
+                    context: None,
+                }),
+            },
             BytesMode::Hex => match hex::decode(s) {
                 Ok(vec) => Ok(EitherBytes::from(vec)),
                 Err(err) => Err(ErrorType::BytesInvalidEncoding {
@@ -62,3 +82,43 @@ impl ValBytesMode {
         }
     }
 }
+
+/// A callable from the `coerce_int`, `coerce_float` or `coerce_decimal` config, in lax mode it's called with
+/// inputs the validator can't convert and its return value is validated instead
+#[derive(Debug, Clone)]
+pub struct CoerceHook(Py<PyAny>);
+
+impl CoerceHook {
+    pub fn from_config(config: Option<&Bound<'_, PyDict>>, key: &Bound<'_, PyString>) -> PyResult<Option<Self>> {
+        match config.get_as::<Bound<'_, PyAny>>(key)? {
+            Some(hook) if hook.is_callable() => Ok(Some(Self(hook.unbind()))),
+            Some(hook) => py_schema_err!("'{}' must be callable, got {}", key, hook.get_type().qualname()?),
+            None => Ok(None),
+        }
+    }
+}
+
+impl PyGcTraverse for CoerceHook {
+    fn py_gc_traverse(&self, visit: &pyo3::PyVisit<'_>) -> Result<(), pyo3::PyTraverseError> {
+        self.0.py_gc_traverse(visit)
+    }
+}
+
+/// Calls `hook` with an input which failed validation with `error`, `error` is returned unchanged if there's
+/// no hook, in strict mode, or if it isn't a validation error
+pub fn coerce_with_hook<'py>(
+    hook: Option<&CoerceHook>,
+    py: Python<'py>,
+    input: &(impl Input<'py> + ?Sized),
+    strict: bool,
+    error: ValError,
+) -> ValResult<Bound<'py, PyAny>> {
+    match (hook, error) {
+        (Some(hook), ValError::LineErrors(_)) if !strict => hook
+            .0
+            .bind(py)
+            .call1((input.to_object(py)?,))
+            .map_err(|err| convert_err(py, err, input)),
+        (_, error) => Err(error),
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt::Write;
+
+use pyo3::exceptions::{PyKeyError, PyTypeError};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::build_tools::py_schema_err;
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::literal::LiteralLookup;
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// Look up `key` in the validation (or serialization) context, a missing context, a missing key
+/// and a context which isn't a mapping are all treated as "not found".
+pub(crate) fn get_context_value<'py>(
+    context: Option<&Bound<'py, PyAny>>,
+    key: &Bound<'py, PyString>,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let Some(context) = context else {
+        return Ok(None);
+    };
+    if let Ok(dict) = context.downcast::<PyDict>() {
+        return dict.get_item(key);
+    }
+    match context.get_item(key) {
+        Ok(value) => Ok(Some(value)),
+        Err(err)
+            if err.is_instance_of::<PyKeyError>(context.py()) || err.is_instance_of::<PyTypeError>(context.py()) =>
+        {
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+#[derive(Debug)]
+pub struct ContextSwitchValidator {
+    context_key: Py<PyString>,
+    lookup: LiteralLookup<CombinedValidator>,
+    default: Option<Box<CombinedValidator>>,
+    expected_repr: String,
+    name: String,
+}
+
+impl BuildValidator for ContextSwitchValidator {
+    const EXPECTED_TYPE: &'static str = "context-switch";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let context_key: Bound<'_, PyString> = schema.get_as_req(intern!(py, "context_key"))?;
+
+        let schema_choices: Bound<PyDict> = schema.get_as_req(intern!(py, "choices"))?;
+        if schema_choices.is_empty() {
+            return py_schema_err!("One or more context-switch choices required");
+        }
+        let mut expected_repr = String::with_capacity(50);
+        let mut descr = String::with_capacity(50);
+        let mut lookup_map = Vec::with_capacity(schema_choices.len());
+        for (choice_key, choice_schema) in schema_choices {
+            let validator = build_validator(&choice_schema, config, definitions)?;
+            if lookup_map.is_empty() {
+                write!(expected_repr, "{}", choice_key.repr()?).unwrap();
+                descr.push_str(validator.get_name());
+            } else {
+                write!(expected_repr, ", {}", choice_key.repr()?).unwrap();
+                write!(descr, ",{}", validator.get_name()).unwrap();
+            }
+            lookup_map.push((choice_key, validator));
+        }
+        let lookup = LiteralLookup::new(py, lookup_map.into_iter())?;
+
+        let default = match schema.get_as(intern!(py, "default_schema"))? {
+            Some(default_schema) => Some(Box::new(build_validator(&default_schema, config, definitions)?)),
+            None => None,
+        };
+
+        Ok(Self {
+            name: format!("{}[{}][{descr}]", Self::EXPECTED_TYPE, context_key.to_str()?),
+            context_key: context_key.unbind(),
+            lookup,
+            default,
+            expected_repr,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(ContextSwitchValidator { lookup, default });
+
+impl Validator for ContextSwitchValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        state.mark_context_dependent();
+        let context_key = self.context_key.bind(py);
+        let context_value = get_context_value(state.extra().context.as_ref(), context_key)?;
+        let validator = match context_value {
+            Some(ref value) => match self.lookup.validate(py, value)? {
+                Some((_, validator)) => validator,
+                None => match self.default {
+                    Some(ref default) => default,
+                    None => {
+                        return Err(ValError::new(
+                            ErrorType::ContextSwitchInvalid {
+                                context_key: context_key.to_string(),
+                                value: value.repr()?.to_string(),
+                                expected_values: self.expected_repr.clone(),
+                                context: None,
+                            },
+                            input,
+                        ))
+                    }
+                },
+            },
+            None => match self.default {
+                Some(ref default) => default,
+                None => {
+                    return Err(ValError::new(
+                        ErrorType::ContextSwitchNotFound {
+                            context_key: context_key.to_string(),
+                            context: None,
+                        },
+                        input,
+                    ))
+                }
+            },
+        };
+        if let Some(trace) = state.trace.as_deref_mut() {
+            trace.selected(py, &self.name, context_value.as_ref(), validator.get_name())?;
+        }
+        validator.validate(py, input, state)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
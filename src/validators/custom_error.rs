@@ -4,7 +4,7 @@ use pyo3::types::PyDict;
 
 use crate::build_tools::py_schema_err;
 use crate::errors::ToErrorValue;
-use crate::errors::{ErrorType, PydanticCustomError, PydanticKnownError, ValError, ValResult};
+use crate::errors::{ErrorSeverity, ErrorType, PydanticCustomError, PydanticKnownError, ValError, ValResult};
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
@@ -44,6 +44,7 @@ impl CustomError {
                 error_type,
                 schema.get_as_req::<String>(intern!(py, "custom_error_message"))?,
                 context,
+                ErrorSeverity::Error,
             );
             Ok(Some(Self::Custom(error)))
         }
@@ -7,7 +7,9 @@ use ahash::AHashSet;
 use pyo3::IntoPyObjectExt;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config_same, ExtraBehavior};
+use crate::build_tools::{
+    alias_or_generated, is_strict, nested_config, schema_or_config_same, AliasGenerator, ExtraBehavior,
+};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{
     input_as_python_instance, Arguments, BorrowInput, Input, InputType, KeywordArgs, PositionalArgs, ValidationMatch,
@@ -69,6 +71,7 @@ impl BuildValidator for DataclassArgsValidator {
 
         let mut positional_count = 0;
 
+        let alias_generator = AliasGenerator::validation(config)?;
         for field in fields_schema {
             let field = field.downcast::<PyDict>()?;
 
@@ -93,7 +96,12 @@ impl BuildValidator for DataclassArgsValidator {
                 positional_count += 1;
             }
 
-            let validation_alias = field.get_item(intern!(py, "validation_alias"))?;
+            let validation_alias = alias_or_generated(
+                py,
+                field.get_item(intern!(py, "validation_alias"))?,
+                alias_generator,
+                name.as_str(),
+            );
             let lookup_key_collection = LookupKeyCollection::new(py, validation_alias, name.as_str())?;
 
             fields.push(Field {
@@ -214,7 +222,7 @@ impl Validator for DataclassArgsValidator {
             }
             let kw_value = kw_value.as_ref().map(|(path, value)| (path, value.borrow_input()));
 
-            let state = &mut state.rebind_extra(|extra| extra.field_name = Some(field.name_py.bind(py).clone()));
+            let state = &mut state.enter_field(field.name_py.bind(py), &field.name);
 
             match (pos_value, kw_value) {
                 // found both positional and keyword arguments, error
@@ -226,31 +234,40 @@ impl Validator for DataclassArgsValidator {
                     ));
                 }
                 // found a positional argument, validate it
-                (Some(pos_value), None) => match field.validator.validate(py, pos_value.borrow_input(), state) {
-                    Ok(value) => {
-                        set_item!(field, value);
-                        fields_set_count += 1;
-                    }
-                    Err(ValError::LineErrors(line_errors)) => {
-                        errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                (Some(pos_value), None) => {
+                    let report_mark = state.report_mark();
+                    match field.validator.validate(py, pos_value.borrow_input(), state) {
+                        Ok(value) => {
+                            state.reports_with_outer_location(report_mark, index);
+                            set_item!(field, value);
+                            fields_set_count += 1;
+                        }
+                        Err(ValError::LineErrors(line_errors)) => {
+                            errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                        }
+                        Err(err) => return Err(err),
                     }
-                    Err(err) => return Err(err),
-                },
+                }
                 // found a keyword argument, validate it
-                (None, Some((lookup_path, kw_value))) => match field.validator.validate(py, kw_value, state) {
-                    Ok(value) => {
-                        set_item!(field, value);
-                        fields_set_count += 1;
-                    }
-                    Err(ValError::LineErrors(line_errors)) => {
-                        errors.extend(
-                            line_errors
-                                .into_iter()
-                                .map(|err| lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name)),
-                        );
+                (None, Some((lookup_path, kw_value))) => {
+                    let report_mark = state.report_mark();
+                    state.record_alias_hit(|| lookup_path.to_alias(py).map(Bound::unbind))?;
+                    match field.validator.validate(py, kw_value, state) {
+                        Ok(value) => {
+                            state.reports_with_outer_location(report_mark, field.name.as_str());
+                            set_item!(field, value);
+                            fields_set_count += 1;
+                        }
+                        Err(ValError::LineErrors(line_errors)) => {
+                            errors.extend(
+                                line_errors
+                                    .into_iter()
+                                    .map(|err| lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name)),
+                            );
+                        }
+                        Err(err) => return Err(err),
                     }
-                    Err(err) => return Err(err),
-                },
+                }
                 // found neither, check if there is a default value, otherwise error
                 (None, None) => {
                     match field.validator.default_value(py, Some(field.name.as_str()), state) {
@@ -406,10 +423,8 @@ impl Validator for DataclassArgsValidator {
                 }
             }
 
-            let state = &mut state.rebind_extra(|extra| {
-                extra.data = Some(data_dict.clone());
-                extra.field_name = Some(field.name_py.bind(py).clone());
-            });
+            let state = &mut state.rebind_extra(|extra| extra.data = Some(data_dict.clone()));
+            let state = &mut state.enter_field(field.name_py.bind(py), &field.name);
 
             match field.validator.validate(py, field_value, state) {
                 Ok(output) => ok(output),
@@ -455,6 +470,8 @@ pub struct DataclassValidator {
     generic_origin: Option<Py<PyType>>,
     fields: Vec<Py<PyString>>,
     post_init: Option<Py<PyString>>,
+    validate_after_post_init: bool,
+    context: Option<Py<PyDict>>,
     revalidate: Revalidate,
     name: String,
     frozen: bool,
@@ -466,13 +483,13 @@ impl BuildValidator for DataclassValidator {
 
     fn build(
         schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        config: Option<&Bound<'_, PyDict>>,
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
 
-        // dataclasses ignore the parent config and always use the config from this dataclasses
-        let config = schema.get_as(intern!(py, "config"))?;
+        // dataclasses use the config from this dataclass, combined with the parent config according to `config_merge`
+        let config = nested_config(schema, config)?;
         let config = config.as_ref();
 
         let class = schema.get_as_req::<Bound<'_, PyType>>(intern!(py, "cls"))?;
@@ -499,6 +516,8 @@ impl BuildValidator for DataclassValidator {
             generic_origin: generic_origin.map(std::convert::Into::into),
             fields,
             post_init,
+            validate_after_post_init: schema.get_as(intern!(py, "validate_after_post_init"))?.unwrap_or(false),
+            context: schema.get_as(intern!(py, "context"))?,
             revalidate: Revalidate::from_str(
                 schema_or_config_same::<Bound<'_, PyString>>(schema, config, intern!(py, "revalidate_instances"))?
                     .as_ref()
@@ -516,6 +535,7 @@ impl BuildValidator for DataclassValidator {
 impl_py_gc_traverse!(DataclassValidator {
     class,
     generic_origin,
+    context,
     validator
 });
 
@@ -526,6 +546,16 @@ impl Validator for DataclassValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        // same as models, entries of `context` are visible to the dataclass's fields
+        let mut layered_state;
+        let state = match self.context {
+            Some(ref entries) => {
+                let context = state.extra().layered_context(entries.bind(py))?;
+                layered_state = state.rebind_extra(|extra| extra.context = Some(context));
+                &mut *layered_state
+            }
+            None => state,
+        };
         if let Some(self_instance) = state.extra().self_instance {
             // in the case that self_instance is Some, we're calling validation from within `BaseModel.__init__`
             return self.validate_init(py, self_instance, input, state);
@@ -558,7 +588,7 @@ impl Validator for DataclassValidator {
                 let input_dict = self.dataclass_to_dict(py_input)?;
                 let val_output = self.validator.validate(py, input_dict.as_any(), state)?;
                 let dc = create_class(self.class.bind(py))?;
-                self.set_dict_call(py, &dc, val_output, input)?;
+                self.set_dict_call(py, &dc, val_output, input, state)?;
                 Ok(dc.into())
             } else {
                 Ok(input.to_object(py)?.unbind())
@@ -572,11 +602,13 @@ impl Validator for DataclassValidator {
                 input,
             ))
         } else {
-            let val_output = self.validator.validate(py, input, state)?;
-            state.floor_exactness(Exactness::Strict);
-            let dc = create_class(self.class.bind(py))?;
-            self.set_dict_call(py, &dc, val_output, input)?;
-            Ok(dc.into())
+            state.memoize(py, self, self.frozen, input, |state| {
+                let val_output = self.validator.validate(py, input, state)?;
+                state.floor_exactness(Exactness::Strict);
+                let dc = create_class(self.class.bind(py))?;
+                self.set_dict_call(py, &dc, val_output, input, state)?;
+                Ok(dc.into())
+            })
         }
     }
 
@@ -591,6 +623,15 @@ impl Validator for DataclassValidator {
         if self.frozen {
             return Err(ValError::new(ErrorTypeDefaults::FrozenInstance, field_value));
         }
+        let mut layered_state;
+        let state = match self.context {
+            Some(ref entries) => {
+                let context = state.extra().layered_context(entries.bind(py))?;
+                layered_state = state.rebind_extra(|extra| extra.context = Some(context));
+                &mut *layered_state
+            }
+            None => state,
+        };
 
         let new_dict = self.dataclass_to_dict(obj)?;
 
@@ -624,7 +665,7 @@ impl DataclassValidator {
     fn validate_init<'py>(
         &self,
         py: Python<'py>,
-        self_instance: &Bound<'_, PyAny>,
+        self_instance: &Bound<'py, PyAny>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
@@ -633,7 +674,7 @@ impl DataclassValidator {
         let state = &mut state.rebind_extra(|extra| extra.self_instance = None);
         let val_output = self.validator.validate(py, input, state)?;
 
-        self.set_dict_call(py, self_instance, val_output, input)?;
+        self.set_dict_call(py, self_instance, val_output, input, state)?;
 
         Ok(self_instance.clone().unbind())
     }
@@ -651,21 +692,28 @@ impl DataclassValidator {
     fn set_dict_call<'py>(
         &self,
         py: Python<'py>,
-        dc: &Bound<'_, PyAny>,
+        dc: &Bound<'py, PyAny>,
         val_output: PyObject,
         input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<()> {
         let (dc_dict, post_init_kwargs): (Bound<'_, PyAny>, Bound<'_, PyAny>) = val_output.extract(py)?;
+        let dc_dict = dc_dict.downcast_into::<PyDict>()?;
         if self.slots {
-            let dc_dict = dc_dict.downcast::<PyDict>()?;
             for (key, value) in dc_dict.iter() {
                 force_setattr(py, dc, key, value)?;
             }
         } else {
-            force_setattr(py, dc, intern!(py, "__dict__"), dc_dict)?;
+            force_setattr(py, dc, intern!(py, "__dict__"), &dc_dict)?;
         }
 
         if let Some(ref post_init) = self.post_init {
+            // `__post_init__` can modify `__dict__` in place, so keep a copy of the validated values
+            let validated = match (self.validate_after_post_init, self.slots) {
+                (false, _) => None,
+                (true, true) => Some(dc_dict),
+                (true, false) => Some(dc_dict.copy()?),
+            };
             let post_init = post_init.bind(py);
             let r = if PyAnyMethods::is_none(&post_init_kwargs) {
                 dc.call_method0(post_init)
@@ -674,6 +722,61 @@ impl DataclassValidator {
                 dc.call_method1(post_init, args.clone()) // FIXME should not need clone here
             };
             r.map_err(|e| convert_err(py, e, input))?;
+            if let Some(validated) = validated {
+                self.validate_post_init_changes(py, dc, &validated, state)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate fields which `__post_init__` set or replaced, as if they'd been assigned to,
+    /// so values it sets are subject to the same constraints as those passed to `__init__`
+    fn validate_post_init_changes<'py>(
+        &self,
+        py: Python<'py>,
+        dc: &Bound<'py, PyAny>,
+        validated: &Bound<'py, PyDict>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<()> {
+        let mut current = PyDict::new(py);
+        let mut changed = Vec::new();
+        for field_name in &self.fields {
+            let field_name = field_name.bind(py);
+            // fields with `init=False` and no default might not have been set at all
+            let Some(value) = dc.getattr_opt(field_name)? else {
+                continue;
+            };
+            if !validated.get_item(field_name)?.is_some_and(|v| v.is(&value)) {
+                changed.push(field_name.clone());
+            }
+            current.set_item(field_name, value)?;
+        }
+
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for field_name in &changed {
+            let value = current
+                .get_item(field_name)?
+                .ok_or_else(|| PyKeyError::new_err(field_name.clone().unbind()))?;
+            match self
+                .validator
+                .validate_assignment(py, current.as_any(), field_name.to_str()?, &value, state)
+            {
+                Ok(output) => {
+                    let (new_dict, _): (Bound<'_, PyDict>, Bound<'_, PyAny>) = output.extract(py)?;
+                    current = new_dict;
+                }
+                Err(ValError::LineErrors(line_errors)) => errors.extend(line_errors),
+                Err(err) => return Err(err),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(ValError::LineErrors(errors));
+        }
+
+        for field_name in &changed {
+            if let Some(value) = current.get_item(field_name)? {
+                force_setattr(py, dc, field_name, value)?;
+            }
         }
         Ok(())
     }
@@ -7,7 +7,7 @@ use strum::EnumMessage;
 
 use crate::build_tools::{is_strict, py_schema_error_type};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::{EitherDate, Input};
+use crate::input::{DateTimeFormats, EitherDate, Input};
 
 use crate::validators::datetime::{NowConstraint, NowOp};
 
@@ -18,6 +18,7 @@ use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationSta
 pub struct DateValidator {
     strict: bool,
     constraints: Option<DateConstraints>,
+    formats: Option<DateTimeFormats>,
 }
 
 impl BuildValidator for DateValidator {
@@ -31,6 +32,7 @@ impl BuildValidator for DateValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             constraints: DateConstraints::from_py(schema)?,
+            formats: DateTimeFormats::from_schema(schema)?,
         }
         .into())
     }
@@ -46,14 +48,26 @@ impl Validator for DateValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
-        let date = match input.validate_date(strict) {
-            Ok(val_match) => val_match.unpack(state),
-            // if the error was a parsing error, in lax mode we allow datetimes at midnight
-            Err(line_errors @ ValError::LineErrors(..)) if !strict => {
-                state.floor_exactness(Exactness::Lax);
-                date_from_datetime(input)?.ok_or(line_errors)?
+        let formatted = match &self.formats {
+            Some(formats) if !strict => formats.parse(input).and_then(|parts| parts.date()),
+            _ => None,
+        };
+        let date = if let Some(date) = formatted {
+            state.floor_exactness(Exactness::Lax);
+            state.record_coercion(input, "date");
+            EitherDate::Raw(date)
+        } else {
+            match input.validate_date(strict) {
+                Ok(val_match) => val_match.unpack_coerced(state, input, "date"),
+                // if the error was a parsing error, in lax mode we allow datetimes at midnight
+                Err(line_errors @ ValError::LineErrors(..)) if !strict => {
+                    state.floor_exactness(Exactness::Lax);
+                    let date = date_from_datetime(input)?.ok_or(line_errors)?;
+                    state.record_coercion(input, "date");
+                    date
+                }
+                Err(otherwise) => return Err(otherwise),
             }
-            Err(otherwise) => return Err(otherwise),
         };
         if let Some(constraints) = &self.constraints {
             let raw_date = date.as_raw()?;
@@ -2,8 +2,9 @@ use pyo3::exceptions::PyValueError;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDateTime, PyDict, PyList, PyString};
 use speedate::{DateTime, MicrosecondsPrecisionOverflowBehavior, Time};
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use strum::EnumMessage;
 
@@ -11,7 +12,9 @@ use crate::build_tools::{is_strict, py_schema_error_type};
 use crate::build_tools::{py_schema_err, schema_or_config_same};
 use crate::errors::ToErrorValue;
 use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::{EitherDateTime, Input};
+use crate::input::{
+    bytes_as_datetime, epoch_as_datetime, DateTimeFormats, EitherDateTime, EpochUnit, Input, ValidationMatch,
+};
 
 use crate::tools::SchemaDict;
 
@@ -23,6 +26,9 @@ pub struct DateTimeValidator {
     strict: bool,
     constraints: Option<DateTimeConstraints>,
     microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
+    formats: Option<DateTimeFormats>,
+    epoch_unit: EpochUnit,
+    preserve_zone_key: bool,
 }
 
 pub(crate) fn extract_microseconds_precision(
@@ -51,6 +57,14 @@ impl BuildValidator for DateTimeValidator {
             strict: is_strict(schema, config)?,
             constraints: DateTimeConstraints::from_py(schema)?,
             microseconds_precision: extract_microseconds_precision(schema, config)?,
+            formats: DateTimeFormats::from_schema(schema)?,
+            epoch_unit: match schema.get_as::<Bound<'_, PyString>>(intern!(schema.py(), "epoch_unit"))? {
+                Some(unit) => EpochUnit::from_str(unit.to_str()?)?,
+                None => EpochUnit::Infer,
+            },
+            preserve_zone_key: schema
+                .get_as(intern!(schema.py(), "preserve_zone_key"))?
+                .unwrap_or(false),
         }
         .into())
     }
@@ -66,14 +80,42 @@ impl Validator for DateTimeValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
-        let datetime = match input.validate_datetime(strict, self.microseconds_precision) {
-            Ok(val_match) => val_match.unpack(state),
-            // if the error was a parsing error, in lax mode we allow dates and add the time 00:00:00
-            Err(line_errors @ ValError::LineErrors(..)) if !strict => {
-                state.floor_exactness(Exactness::Lax);
-                datetime_from_date(input)?.ok_or(line_errors)?
+        let formatted = match &self.formats {
+            Some(formats) if !strict => formats.parse(input).and_then(|parts| parts.datetime()),
+            _ => None,
+        };
+        let epoch = match self.epoch_unit {
+            EpochUnit::Infer => None,
+            _ if strict || formatted.is_some() => None,
+            unit => epoch_as_datetime(py, input, unit)?,
+        };
+        let zoned = if self.preserve_zone_key && formatted.is_none() && epoch.is_none() {
+            zoned_datetime(py, input, strict, self.microseconds_precision)?
+        } else {
+            None
+        };
+        let datetime = if let Some(datetime) = formatted {
+            state.floor_exactness(Exactness::Lax);
+            state.record_coercion(input, "datetime");
+            EitherDateTime::Raw(datetime)
+        } else if let Some(datetime) = epoch {
+            state.floor_exactness(Exactness::Lax);
+            state.record_coercion(input, "datetime");
+            datetime
+        } else if let Some(val_match) = zoned {
+            val_match.unpack_coerced(state, input, "datetime")
+        } else {
+            match input.validate_datetime(strict, self.microseconds_precision) {
+                Ok(val_match) => val_match.unpack_coerced(state, input, "datetime"),
+                // if the error was a parsing error, in lax mode we allow dates and add the time 00:00:00
+                Err(line_errors @ ValError::LineErrors(..)) if !strict => {
+                    state.floor_exactness(Exactness::Lax);
+                    let datetime = datetime_from_date(input)?.ok_or(line_errors)?;
+                    state.record_coercion(input, "datetime");
+                    datetime
+                }
+                Err(otherwise) => return Err(otherwise),
             }
-            Err(otherwise) => return Err(otherwise),
         };
         if let Some(constraints) = &self.constraints {
             // if we get an error from as_speedate, it's probably because the input datetime was invalid
@@ -130,6 +172,10 @@ impl Validator for DateTimeValidator {
             if let Some(ref tz_constraint) = constraints.tz {
                 tz_constraint.tz_check(speedate_dt.time.tz_offset, input)?;
             }
+
+            if let Some(ref tz_allowed) = constraints.tz_allowed {
+                tz_allowed.tz_check(&datetime, speedate_dt.time.tz_offset, input)?;
+            }
         }
         datetime.try_into_py(py, input)
     }
@@ -139,6 +185,59 @@ impl Validator for DateTimeValidator {
     }
 }
 
+static ZONE_INFO: GILOnceCell<PyObject> = GILOnceCell::new();
+
+/// RFC 9557 style strings with a zone key suffix, e.g. `2024-01-02T03:04:05+01:00[Europe/Paris]`, the zone is
+/// attached as a `zoneinfo.ZoneInfo` so it survives a round trip.
+/// Ok(None) means the input wasn't a string with a zone key suffix.
+fn zoned_datetime<'py>(
+    py: Python<'py>,
+    input: &(impl Input<'py> + ?Sized),
+    strict: bool,
+    microseconds_precision: MicrosecondsPrecisionOverflowBehavior,
+) -> ValResult<Option<ValidationMatch<EitherDateTime<'py>>>> {
+    // python strings are only accepted for datetimes in lax mode
+    let from_python = input.as_python().is_some();
+    if strict && from_python {
+        return Ok(None);
+    }
+    let Ok(either_str) = input.validate_str(true, false) else {
+        return Ok(None);
+    };
+    let either_str = either_str.into_inner();
+    let s = either_str.as_cow()?;
+    let Some((datetime, key)) = s.strip_suffix(']').and_then(|s| s.rsplit_once('[')) else {
+        return Ok(None);
+    };
+    let datetime = bytes_as_datetime(input, datetime.as_bytes(), microseconds_precision)?.try_into_py(py, input)?;
+    let zone = ZONE_INFO
+        .import(py, "zoneinfo", "ZoneInfo")?
+        .call1((key,))
+        .map_err(|_| {
+            ValError::new(
+                ErrorType::DatetimeParsing {
+                    error: Cow::Owned(format!("unknown time zone key {key:?}")),
+                    context: None,
+                },
+                input,
+            )
+        })?;
+    let datetime = datetime.bind(py);
+    let zoned = if PyAnyMethods::is_none(&datetime.getattr(intern!(py, "tzinfo"))?) {
+        let kwargs = PyDict::new(py);
+        kwargs.set_item(intern!(py, "tzinfo"), zone)?;
+        datetime.call_method(intern!(py, "replace"), (), Some(&kwargs))?
+    } else {
+        datetime.call_method1(intern!(py, "astimezone"), (zone,))?
+    };
+    let zoned: EitherDateTime<'py> = zoned.downcast_into::<PyDateTime>()?.into();
+    Ok(Some(if from_python {
+        ValidationMatch::lax(zoned)
+    } else {
+        ValidationMatch::strict(zoned)
+    }))
+}
+
 /// In lax mode, if the input is not a datetime, we try parsing the input as a date and add the "00:00:00" time.
 /// Ok(None) means that this is not relevant to datetimes (the input was not a date nor a string)
 fn datetime_from_date<'py>(input: &(impl Input<'py> + ?Sized)) -> Result<Option<EitherDateTime<'py>>, ValError> {
@@ -188,6 +287,7 @@ struct DateTimeConstraints {
     gt: Option<DateTime>,
     now: Option<NowConstraint>,
     tz: Option<TZConstraint>,
+    tz_allowed: Option<TzAllowed>,
 }
 
 impl DateTimeConstraints {
@@ -200,8 +300,16 @@ impl DateTimeConstraints {
             gt: py_datetime_as_datetime(schema, intern!(py, "gt"))?,
             now: NowConstraint::from_py(schema)?,
             tz: TZConstraint::from_py(schema)?,
+            tz_allowed: TzAllowed::from_py(schema)?,
         };
-        if c.le.is_some() || c.lt.is_some() || c.ge.is_some() || c.gt.is_some() || c.now.is_some() || c.tz.is_some() {
+        if c.le.is_some()
+            || c.lt.is_some()
+            || c.ge.is_some()
+            || c.gt.is_some()
+            || c.now.is_some()
+            || c.tz.is_some()
+            || c.tz_allowed.is_some()
+        {
             Ok(Some(c))
         } else {
             Ok(None)
@@ -330,3 +438,105 @@ impl TZConstraint {
         Ok(())
     }
 }
+
+/// The `tz_allowed` constraint: aware datetimes must either be in one of the named zones or have an offset within
+/// one of the ranges.
+#[derive(Debug, Clone)]
+struct TzAllowed {
+    names: Vec<String>,
+    // inclusive ranges of UTC offsets in seconds
+    offsets: Vec<(i32, i32)>,
+    // description of the allowed zones used in errors
+    expected: String,
+}
+
+impl TzAllowed {
+    fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let Some(tz_allowed) = schema.get_as::<Bound<'_, PyList>>(intern!(py, "tz_allowed"))? else {
+            return Ok(None);
+        };
+        if tz_allowed.is_empty() {
+            return py_schema_err!("`tz_allowed` should not be empty");
+        }
+        let mut names = Vec::new();
+        let mut offsets = Vec::new();
+        let mut descriptions = Vec::new();
+        for item in tz_allowed.iter() {
+            if let Ok(name) = item.downcast::<PyString>() {
+                let name = name.to_str()?.to_string();
+                descriptions.push(format!("'{name}'"));
+                names.push(name);
+            } else if let Ok(offset) = item.extract::<i32>() {
+                descriptions.push(format!("an offset of {offset}"));
+                offsets.push((offset, offset));
+            } else if let Ok((min, max)) = item.extract::<(i32, i32)>() {
+                if min > max {
+                    return py_schema_err!(
+                        "Invalid `tz_allowed` offset range ({}, {}), min is greater than max",
+                        min,
+                        max
+                    );
+                }
+                descriptions.push(format!("an offset between {min} and {max}"));
+                offsets.push((min, max));
+            } else {
+                return py_schema_err!(
+                    "Invalid `tz_allowed` item {}, should be a zone name, an offset or a range of offsets",
+                    item.repr()?
+                );
+            }
+        }
+        let expected = match descriptions.split_last() {
+            Some((last, [])) => last.clone(),
+            Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+            None => unreachable!(),
+        };
+        Ok(Some(Self {
+            names,
+            offsets,
+            expected,
+        }))
+    }
+
+    fn tz_check(
+        &self,
+        datetime: &EitherDateTime<'_>,
+        tz_offset: Option<i32>,
+        input: impl ToErrorValue,
+    ) -> ValResult<()> {
+        let Some(tz_offset) = tz_offset else {
+            return Err(ValError::new(ErrorTypeDefaults::TimezoneAware, input));
+        };
+        let zone_name = match datetime {
+            EitherDateTime::Py(dt) => zone_name(dt),
+            EitherDateTime::Raw(_) => None,
+        };
+        let name_allowed = match zone_name {
+            Some(ref name) => self.names.contains(name),
+            // without a zone name, a zero offset is taken to be UTC
+            None => tz_offset == 0 && self.names.iter().any(|name| name == "UTC"),
+        };
+        if name_allowed || self.offsets.iter().any(|(min, max)| (*min..=*max).contains(&tz_offset)) {
+            Ok(())
+        } else {
+            Err(ValError::new(
+                ErrorType::TimezoneNotAllowed {
+                    tz_allowed: self.expected.clone(),
+                    tz_actual: zone_name.map_or_else(|| tz_offset.to_string(), |name| format!("'{name}'")),
+                    context: None,
+                },
+                input,
+            ))
+        }
+    }
+}
+
+/// The IANA name of a datetime's zone, from `key` for `zoneinfo.ZoneInfo` or `zone` for pytz timezones
+fn zone_name(dt: &Bound<'_, PyDateTime>) -> Option<String> {
+    let py = dt.py();
+    let tzinfo = dt.getattr(intern!(py, "tzinfo")).ok()?;
+    [intern!(py, "key"), intern!(py, "zone")]
+        .into_iter()
+        .find_map(|attr| tzinfo.getattr(attr).ok()?.extract::<String>().ok())
+}
@@ -4,7 +4,7 @@ use pyo3::sync::GILOnceCell;
 use pyo3::types::{IntoPyDict, PyDict, PyString, PyTuple, PyType};
 use pyo3::{prelude::*, PyTypeInfo};
 
-use crate::build_tools::{is_strict, schema_or_config_same};
+use crate::build_tools::{is_strict, py_schema_err, schema_or_config_same};
 use crate::errors::ErrorType;
 use crate::errors::ValResult;
 use crate::errors::{ErrorTypeDefaults, Number};
@@ -12,7 +12,8 @@ use crate::errors::{ToErrorValue, ValError};
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::config::{coerce_with_hook, CoerceHook};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
 
 static DECIMAL_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
 
@@ -44,6 +45,89 @@ fn validate_as_decimal(
     }
 }
 
+const ROUNDING_MODES: [&str; 8] = [
+    "ROUND_UP",
+    "ROUND_DOWN",
+    "ROUND_CEILING",
+    "ROUND_FLOOR",
+    "ROUND_HALF_UP",
+    "ROUND_HALF_DOWN",
+    "ROUND_HALF_EVEN",
+    "ROUND_05UP",
+];
+
+/// Rounds and normalizes decimals without being limited by the thread's decimal context, so values
+/// are never silently truncated to its precision.
+#[derive(Debug, Clone)]
+pub(crate) struct DecimalFormat {
+    context: Py<PyAny>,
+    /// exponent to quantize to, e.g. `Decimal('1e-2')` for two decimal places
+    quantize_exp: Option<Py<PyAny>>,
+    normalize: bool,
+}
+
+impl DecimalFormat {
+    /// Build from the `rounding` key in the schema, returns `None` if there's nothing to do
+    pub fn build(schema: &Bound<'_, PyDict>, quantize_places: Option<u64>, normalize: bool) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let rounding: Option<Bound<'_, PyString>> = schema.get_as(intern!(py, "rounding"))?;
+        if let Some(ref rounding) = rounding {
+            if !ROUNDING_MODES.contains(&rounding.to_str()?) {
+                return py_schema_err!("Invalid rounding mode: {}", rounding);
+            }
+        }
+        if quantize_places.is_none() && !normalize {
+            return Ok(None);
+        }
+
+        let decimal_module = py.import(intern!(py, "decimal"))?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item(intern!(py, "prec"), decimal_module.getattr(intern!(py, "MAX_PREC"))?)?;
+        kwargs.set_item(intern!(py, "Emax"), decimal_module.getattr(intern!(py, "MAX_EMAX"))?)?;
+        kwargs.set_item(intern!(py, "Emin"), decimal_module.getattr(intern!(py, "MIN_EMIN"))?)?;
+        kwargs.set_item(intern!(py, "rounding"), rounding)?;
+        let context = decimal_module
+            .getattr(intern!(py, "Context"))?
+            .call((), Some(&kwargs))?;
+        let quantize_exp = quantize_places
+            .map(|places| get_decimal_type(py).call1((format!("1e-{places}"),)))
+            .transpose()?;
+        Ok(Some(Self {
+            context: context.unbind(),
+            quantize_exp: quantize_exp.map(Bound::unbind),
+            normalize,
+        }))
+    }
+
+    /// Apply quantization then normalization, non-finite values are returned unchanged
+    pub fn apply<'py>(&self, decimal: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let py = decimal.py();
+        if !decimal.call_method0(intern!(py, "is_finite"))?.extract::<bool>()? {
+            return Ok(decimal.clone());
+        }
+        let context = self.context.bind(py);
+        let mut decimal = decimal.clone();
+        if let Some(ref exp) = self.quantize_exp {
+            decimal = context.call_method1(intern!(py, "quantize"), (decimal, exp))?;
+        }
+        if self.normalize {
+            decimal = context.call_method1(intern!(py, "normalize"), (decimal,))?;
+            // `normalize` switches whole numbers with trailing zeros to exponent form, e.g. `1E+2`,
+            // quantizing to `1` reverts that, so `100.0` becomes `100` rather than `1E+2`
+            let (_, _, exponent): (Bound<'_, PyAny>, Bound<'_, PyAny>, i64) =
+                decimal.call_method0(intern!(py, "as_tuple"))?.extract()?;
+            if exponent > 0 {
+                let one = 1u8.into_pyobject(py)?;
+                decimal =
+                    context.call_method1(intern!(py, "quantize"), (decimal, get_decimal_type(py).call1((one,))?))?;
+            }
+        }
+        Ok(decimal)
+    }
+}
+
+impl_py_gc_traverse!(DecimalFormat { context, quantize_exp });
+
 #[derive(Debug, Clone)]
 pub struct DecimalValidator {
     strict: bool,
@@ -56,6 +140,8 @@ pub struct DecimalValidator {
     gt: Option<Py<PyAny>>,
     max_digits: Option<u64>,
     decimal_places: Option<u64>,
+    format: Option<DecimalFormat>,
+    coerce_hook: Option<CoerceHook>,
 }
 
 impl BuildValidator for DecimalValidator {
@@ -75,6 +161,11 @@ impl BuildValidator for DecimalValidator {
                 "allow_inf_nan=True cannot be used with max_digits or decimal_places",
             ));
         }
+        let quantize = schema.get_as(intern!(py, "quantize"))?.unwrap_or(false);
+        if quantize && decimal_places.is_none() {
+            return py_schema_err!("'quantize' requires 'decimal_places' to be set");
+        }
+        let normalize = schema.get_as(intern!(py, "normalize"))?.unwrap_or(false);
 
         Ok(Self {
             strict: is_strict(schema, config)?,
@@ -87,6 +178,8 @@ impl BuildValidator for DecimalValidator {
             ge: validate_as_decimal(py, schema, intern!(py, "ge"))?,
             gt: validate_as_decimal(py, schema, intern!(py, "gt"))?,
             max_digits,
+            format: DecimalFormat::build(schema, decimal_places.filter(|_| quantize), normalize)?,
+            coerce_hook: CoerceHook::from_config(config, intern!(py, "coerce_decimal"))?,
         }
         .into())
     }
@@ -97,7 +190,9 @@ impl_py_gc_traverse!(DecimalValidator {
     le,
     lt,
     ge,
-    gt
+    gt,
+    format,
+    coerce_hook
 });
 
 fn extract_decimal_digits_info(decimal: &Bound<'_, PyAny>, normalized: bool) -> ValResult<(u64, u64)> {
@@ -140,7 +235,21 @@ impl Validator for DecimalValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let decimal = input.validate_decimal(state.strict_or(self.strict), py)?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        let decimal = match input.validate_decimal(strict, py) {
+            Ok(val_match) => val_match.unpack_coerced(state, input, "decimal"),
+            Err(err) => {
+                let coerced = coerce_with_hook(self.coerce_hook.as_ref(), py, input, strict, err)?;
+                let decimal = coerced.validate_decimal(false, py)?.into_inner();
+                state.floor_exactness(Exactness::Lax);
+                state.record_coercion(input, "decimal");
+                decimal
+            }
+        };
+        let decimal = match self.format {
+            Some(ref format) => format.apply(&decimal)?,
+            None => decimal,
+        };
 
         if !self.allow_inf_nan || self.check_digits {
             if !decimal.call_method0(intern!(py, "is_finite"))?.extract()? {
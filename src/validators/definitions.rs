@@ -104,6 +104,16 @@ impl Validator for DefinitionRefValidator {
         })
     }
 
+    fn matching_choice<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Option<String>> {
+        self.definition
+            .read(|validator| validator.unwrap().matching_choice(py, input, state))
+    }
+
     fn validate_assignment<'py>(
         &self,
         py: Python<'py>,
@@ -121,6 +131,38 @@ impl Validator for DefinitionRefValidator {
         })
     }
 
+    fn validate_assignments<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        assignments: &Bound<'py, PyDict>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        self.definition.read(|validator| {
+            let validator = validator.unwrap();
+            let Ok(mut guard) = RecursionGuard::new(state, py_identity(obj), self.definition.id()) else {
+                return Err(ValError::new(ErrorTypeDefaults::RecursionLoop, obj));
+            };
+            validator.validate_assignments(py, obj, assignments, guard.state())
+        })
+    }
+
+    fn construct<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        fields_set: Option<&Bound<'py, PyAny>>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        self.definition.read(|validator| {
+            let validator = validator.unwrap();
+            let Ok(mut guard) = RecursionGuard::new(state, py_identity(input), self.definition.id()) else {
+                return Err(ValError::new(ErrorTypeDefaults::RecursionLoop, input));
+            };
+            validator.construct(py, input, fields_set, guard.state())
+        })
+    }
+
     fn get_name(&self) -> &str {
         self.definition.get_or_init_name(|v| v.get_name().into())
     }
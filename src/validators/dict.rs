@@ -1,9 +1,9 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyFrozenSet, PyList, PySet, PyString};
 
-use crate::build_tools::is_strict;
-use crate::errors::{LocItem, ValError, ValLineError, ValResult};
+use crate::build_tools::{is_strict, schema_or_config};
+use crate::errors::{ErrorType, ErrorTypeDefaults, LocItem, ValError, ValLineError, ValResult};
 use crate::input::BorrowInput;
 use crate::input::ConsumeIterator;
 use crate::input::{Input, ValidatedDict};
@@ -12,6 +12,7 @@ use crate::tools::SchemaDict;
 
 use super::any::AnyValidator;
 use super::list::length_check;
+use super::string::Pattern;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -21,6 +22,10 @@ pub struct DictValidator {
     value_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    strict_exact_type: bool,
+    key_pattern: Option<Pattern>,
+    required_keys: Vec<Py<PyAny>>,
+    forbidden_keys: Option<Py<PyFrozenSet>>,
     name: String,
 }
 
@@ -53,6 +58,24 @@ impl BuildValidator for DictValidator {
             value_validator,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            strict_exact_type: schema_or_config(
+                schema,
+                config,
+                intern!(py, "strict_exact_type"),
+                intern!(py, "dict_strict_exact_type"),
+            )?
+            .unwrap_or(false),
+            key_pattern: schema
+                .get_as(intern!(py, "key_pattern"))?
+                .map(|pattern| Pattern::build(pattern, schema, config, false))
+                .transpose()?,
+            required_keys: schema
+                .get_as::<Bound<'_, PyList>>(intern!(py, "required_keys"))?
+                .map_or_else(Vec::new, |keys| keys.iter().map(Bound::unbind).collect()),
+            forbidden_keys: schema
+                .get_as::<Bound<'_, PyList>>(intern!(py, "forbidden_keys"))?
+                .map(|keys| PyFrozenSet::new(py, keys).map(Bound::unbind))
+                .transpose()?,
             name,
         }
         .into())
@@ -61,7 +84,9 @@ impl BuildValidator for DictValidator {
 
 impl_py_gc_traverse!(DictValidator {
     key_validator,
-    value_validator
+    value_validator,
+    required_keys,
+    forbidden_keys
 });
 
 impl Validator for DictValidator {
@@ -72,14 +97,20 @@ impl Validator for DictValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
+        // in strict mode dict subclasses are usually accepted, `strict_exact_type` rejects them too
+        if self.strict_exact_type
+            && strict
+            && input
+                .as_python()
+                .is_some_and(|py_input| !py_input.is_exact_instance_of::<PyDict>())
+        {
+            return Err(ValError::new(ErrorTypeDefaults::DictType, input));
+        }
         let dict = input.validate_dict(strict)?;
         dict.iterate(ValidateToDict {
             py,
             input,
-            min_length: self.min_length,
-            max_length: self.max_length,
-            key_validator: &self.key_validator,
-            value_validator: &self.value_validator,
+            validator: self,
             state,
         })?
     }
@@ -89,13 +120,42 @@ impl Validator for DictValidator {
     }
 }
 
+impl DictValidator {
+    /// The error for a validated key which is in `forbidden_keys` or doesn't match `key_pattern`
+    fn check_key<'py>(
+        &self,
+        py: Python<'py>,
+        output_key: &Bound<'py, PyAny>,
+        input_key: &(impl Input<'py> + ?Sized),
+    ) -> ValResult<Option<ValLineError>> {
+        if let Some(ref forbidden_keys) = self.forbidden_keys {
+            if forbidden_keys.bind(py).contains(output_key)? {
+                return Ok(Some(ValLineError::new_with_loc(
+                    ErrorTypeDefaults::DictKeyForbidden,
+                    input_key,
+                    "[key]",
+                )));
+            }
+        }
+        if let Some(ref key_pattern) = self.key_pattern {
+            let error_type = match output_key.downcast::<PyString>() {
+                Ok(key) if key_pattern.is_match(py, key.to_str()?)? => return Ok(None),
+                Ok(_) => ErrorType::StringPatternMismatch {
+                    pattern: key_pattern.pattern.clone(),
+                    context: None,
+                },
+                Err(_) => ErrorTypeDefaults::StringType,
+            };
+            return Ok(Some(ValLineError::new_with_loc(error_type, input_key, "[key]")));
+        }
+        Ok(None)
+    }
+}
+
 struct ValidateToDict<'a, 's, 'py, I: Input<'py> + ?Sized> {
     py: Python<'py>,
     input: &'a I,
-    min_length: Option<usize>,
-    max_length: Option<usize>,
-    key_validator: &'a CombinedValidator,
-    value_validator: &'a CombinedValidator,
+    validator: &'a DictValidator,
     state: &'a mut ValidationState<'s, 'py>,
 }
 
@@ -107,15 +167,38 @@ where
 {
     type Output = ValResult<PyObject>;
     fn consume_iterator(self, iterator: impl Iterator<Item = ValResult<(Key, Value)>>) -> ValResult<PyObject> {
+        let validator = self.validator;
         let output = PyDict::new(self.py);
         let mut errors: Vec<ValLineError> = Vec::new();
         let allow_partial = self.state.allow_partial;
+        // keys which were present, even if their value was invalid, to check `required_keys`
+        let present_keys = if validator.required_keys.is_empty() {
+            None
+        } else {
+            Some(PySet::empty(self.py)?)
+        };
 
         for (_, is_last_partial, item_result) in self.state.enumerate_last_partial(iterator) {
             self.state.allow_partial = false.into();
             let (key, value) = item_result?;
-            let output_key = match self.key_validator.validate(self.py, key.borrow_input(), self.state) {
-                Ok(value) => Some(value),
+            let report_mark = self.state.report_mark();
+            let output_key = match validator
+                .key_validator
+                .validate(self.py, key.borrow_input(), self.state)
+            {
+                Ok(value) => {
+                    self.state.reports_with_outer_location(report_mark, "[key]");
+                    if let Some(ref present_keys) = present_keys {
+                        present_keys.add(&value)?;
+                    }
+                    match validator.check_key(self.py, value.bind(self.py), key.borrow_input())? {
+                        Some(err) => {
+                            errors.push(err.with_outer_location(key.clone()));
+                            None
+                        }
+                        None => Some(value),
+                    }
+                }
                 Err(ValError::LineErrors(line_errors)) => {
                     for err in line_errors {
                         // these are added in reverse order so [key] is shunted along by the second call
@@ -123,14 +206,21 @@ where
                     }
                     None
                 }
-                Err(ValError::Omit) => continue,
+                Err(ValError::Omit) => {
+                    self.state.omitted_with_outer_location(report_mark, "[key]");
+                    self.state.reports_with_outer_location(report_mark, key.clone());
+                    continue;
+                }
                 Err(err) => return Err(err),
             };
             self.state.allow_partial = match is_last_partial {
                 true => allow_partial,
                 false => false.into(),
             };
-            let output_value = match self.value_validator.validate(self.py, value.borrow_input(), self.state) {
+            let output_value = match validator
+                .value_validator
+                .validate(self.py, value.borrow_input(), self.state)
+            {
                 Ok(value) => value,
                 Err(ValError::LineErrors(line_errors)) => {
                     if !is_last_partial {
@@ -138,17 +228,34 @@ where
                     }
                     continue;
                 }
-                Err(ValError::Omit) => continue,
+                Err(ValError::Omit) => {
+                    self.state.omitted_with_outer_location(report_mark, key.clone());
+                    continue;
+                }
                 Err(err) => return Err(err),
             };
+            self.state.reports_with_outer_location(report_mark, key.clone());
             if let Some(key) = output_key {
                 output.set_item(key, output_value)?;
             }
         }
 
+        if let Some(present_keys) = present_keys {
+            for required_key in &validator.required_keys {
+                let required_key = required_key.bind(self.py);
+                if !present_keys.contains(required_key)? {
+                    errors.push(ValLineError::new_with_loc(
+                        ErrorTypeDefaults::Missing,
+                        self.input,
+                        required_key,
+                    ));
+                }
+            }
+        }
+
         if errors.is_empty() {
             let input = self.input;
-            length_check!(input, "Dictionary", self.min_length, self.max_length, output);
+            length_check!(input, "Dictionary", validator.min_length, validator.max_length, output);
             Ok(output.into())
         } else {
             Err(ValError::LineErrors(errors))
@@ -0,0 +1,72 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::IntoPyObjectExt;
+
+use crate::build_tools::is_strict;
+use crate::email::PyEmailAddress;
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::downcast_python_input;
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::Exactness;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+#[derive(Debug, Clone)]
+pub struct EmailValidator {
+    strict: bool,
+    allow_smtputf8: bool,
+}
+
+impl BuildValidator for EmailValidator {
+    const EXPECTED_TYPE: &'static str = "email";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            allow_smtputf8: schema.get_as(intern!(schema.py(), "allow_smtputf8"))?.unwrap_or(true),
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(EmailValidator {});
+
+impl Validator for EmailValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        match input.validate_str(state.strict_or(self.strict), false) {
+            Ok(val_match) => {
+                let either_str = val_match.into_inner();
+                let email = PyEmailAddress::parse(&either_str.as_cow()?, self.allow_smtputf8)
+                    .map_err(|error| ValError::new(ErrorType::EmailParsing { error, context: None }, input))?;
+                // Lax rather than strict so str wins over email in a union, as with urls
+                state.floor_exactness(Exactness::Lax);
+                Ok(email.into_py_any(py)?)
+            }
+            Err(_) => match downcast_python_input::<PyEmailAddress>(input) {
+                Some(py_email) => {
+                    if !self.allow_smtputf8 && !py_email.get().local_part().is_ascii() {
+                        let error = "internationalized characters before the @-sign are not supported".to_string();
+                        return Err(ValError::new(ErrorType::EmailParsing { error, context: None }, input));
+                    }
+                    Ok(py_email.clone().into_any().unbind())
+                }
+                None => Err(ValError::new(ErrorTypeDefaults::EmailType, input)),
+            },
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
@@ -32,21 +32,64 @@ impl BuildValidator for BuildEnumValidator {
         }
 
         let py = schema.py();
+        let by = match schema.get_as::<Bound<'_, PyString>>(intern!(py, "by"))? {
+            Some(by) => match by.to_str()? {
+                "value" => EnumBy::Value,
+                "name" => EnumBy::Name,
+                "either" => EnumBy::Either,
+                _ => return py_schema_err!("`by` must be one of: 'value', 'name' or 'either'"),
+            },
+            None => EnumBy::Value,
+        };
+
         let value_str = intern!(py, "value");
         let expected: Vec<(Bound<'_, PyAny>, PyObject)> = members
             .iter()
             .map(|v| Ok((v.getattr(value_str)?, v.into())))
             .collect::<PyResult<_>>()?;
-
-        let repr_args: Vec<String> = expected
+        let name_str = intern!(py, "name");
+        let expected_names: Vec<(Bound<'_, PyAny>, PyObject)> = members
             .iter()
-            .map(|(k, _)| k.repr()?.extract())
+            .map(|v| Ok((v.getattr(name_str)?, v.into())))
             .collect::<PyResult<_>>()?;
 
+        // the error message lists whatever input would be accepted
+        let repr_args: Vec<String> = match by {
+            EnumBy::Value => expected.iter().collect::<Vec<_>>(),
+            EnumBy::Name => expected_names.iter().collect(),
+            EnumBy::Either => expected.iter().chain(expected_names.iter()).collect(),
+        }
+        .into_iter()
+        .map(|(k, _)| k.repr()?.extract())
+        .collect::<PyResult<_>>()?;
+
         let class: Bound<PyType> = schema.get_as_req(intern!(py, "cls"))?;
         let class_repr = class_repr(schema, &class)?;
 
         let lookup = LiteralLookup::new(py, expected.into_iter())?;
+        let names = match by {
+            EnumBy::Value => None,
+            EnumBy::Name | EnumBy::Either => Some(LiteralLookup::new(py, expected_names.into_iter())?),
+        };
+        let aliases = match schema.get_as::<Bound<'_, PyDict>>(intern!(py, "aliases"))? {
+            Some(aliases) => {
+                let aliases: Vec<(Bound<'_, PyAny>, PyObject)> = aliases
+                    .iter()
+                    .map(|(old, member)| {
+                        if member.is_instance(&class)? {
+                            Ok((old, member.unbind()))
+                        } else {
+                            py_schema_err!(
+                                "`aliases` values must be members of the enum, got {}",
+                                safe_repr(&member)
+                            )
+                        }
+                    })
+                    .collect::<PyResult<_>>()?;
+                Some(LiteralLookup::new(py, aliases.into_iter())?)
+            }
+            None => None,
+        };
 
         macro_rules! build {
             ($vv:ty, $name_prefix:literal) => {
@@ -54,6 +97,9 @@ impl BuildValidator for BuildEnumValidator {
                     phantom: PhantomData::<$vv>,
                     class: class.clone().into(),
                     lookup,
+                    by,
+                    names,
+                    aliases,
                     missing: schema.get_as(intern!(py, "missing"))?,
                     expected_repr: expected_repr_name(repr_args, "").0,
                     strict: is_strict(schema, config)?,
@@ -83,11 +129,24 @@ pub trait EnumValidateValue: std::fmt::Debug + Clone + Send + Sync {
     ) -> ValResult<Option<PyObject>>;
 }
 
+/// What input is used to find a member, see `by` in `enum_schema`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EnumBy {
+    Value,
+    Name,
+    Either,
+}
+
 #[derive(Debug, Clone)]
 pub struct EnumValidator<T: EnumValidateValue> {
     phantom: PhantomData<T>,
     class: Py<PyType>,
     lookup: LiteralLookup<PyObject>,
+    by: EnumBy,
+    // member names, used unless `by` is 'value'
+    names: Option<LiteralLookup<PyObject>>,
+    // historical values mapped to the member which replaced them
+    aliases: Option<LiteralLookup<PyObject>>,
     missing: Option<PyObject>,
     expected_repr: String,
     strict: bool,
@@ -95,6 +154,18 @@ pub struct EnumValidator<T: EnumValidateValue> {
     name: String,
 }
 
+impl<T: EnumValidateValue> EnumValidator<T> {
+    fn enum_error<'py>(&self, input: &(impl Input<'py> + ?Sized)) -> ValError {
+        ValError::new(
+            ErrorType::Enum {
+                expected: self.expected_repr.clone(),
+                context: None,
+            },
+            input,
+        )
+    }
+}
+
 impl<T: EnumValidateValue> Validator for EnumValidator<T> {
     fn validate<'py>(
         &self,
@@ -120,20 +191,33 @@ impl<T: EnumValidateValue> Validator for EnumValidator<T> {
 
         state.floor_exactness(Exactness::Lax);
 
-        if let Some(v) = T::validate_value(py, input, &self.lookup, strict)? {
-            return Ok(v);
-        } else if let Ok(res) = class.as_unbound().call1(py, (input.as_python(),)) {
+        if self.by != EnumBy::Name {
+            if let Some(v) = T::validate_value(py, input, &self.lookup, strict)? {
+                return Ok(v);
+            }
+        }
+        if let Some(ref names) = self.names {
+            if let Some(v) = names.validate_str(input, true)? {
+                return Ok(v.clone_ref(py));
+            }
+        }
+        if let Some(ref aliases) = self.aliases {
+            if let Some((_, v)) = aliases.validate(py, input)? {
+                return Ok(v.clone_ref(py));
+            }
+        }
+        // calling the class and `_missing_` both look up members by value
+        if self.by == EnumBy::Name {
+            return Err(self.enum_error(input));
+        }
+
+        if let Ok(res) = class.as_unbound().call1(py, (input.as_python(),)) {
             return Ok(res);
         } else if let Some(ref missing) = self.missing {
-            let enum_value = missing.bind(py).call1((input.to_object(py)?,)).map_err(|_| {
-                ValError::new(
-                    ErrorType::Enum {
-                        expected: self.expected_repr.clone(),
-                        context: None,
-                    },
-                    input,
-                )
-            })?;
+            let enum_value = missing
+                .bind(py)
+                .call1((input.to_object(py)?,))
+                .map_err(|_| self.enum_error(input))?;
             // check enum_value is an instance of the class like
             // https://github.com/python/cpython/blob/v3.12.2/Lib/enum.py#L1148
             if enum_value.is_instance(class)? {
@@ -151,13 +235,7 @@ impl<T: EnumValidateValue> Validator for EnumValidator<T> {
             }
         }
 
-        Err(ValError::new(
-            ErrorType::Enum {
-                expected: self.expected_repr.clone(),
-                context: None,
-            },
-            input,
-        ))
+        Err(self.enum_error(input))
     }
 
     fn get_name(&self) -> &str {
@@ -168,7 +246,7 @@ impl<T: EnumValidateValue> Validator for EnumValidator<T> {
 #[derive(Debug, Clone)]
 pub struct PlainEnumValidator;
 
-impl_py_gc_traverse!(EnumValidator<PlainEnumValidator> { class, missing });
+impl_py_gc_traverse!(EnumValidator<PlainEnumValidator> { class, lookup, names, aliases, missing });
 
 impl EnumValidateValue for PlainEnumValidator {
     fn validate_value<'py, I: Input<'py> + ?Sized>(
@@ -186,10 +264,10 @@ impl EnumValidateValue for PlainEnumValidator {
                         if py_input.is_instance_of::<PyString>() {
                             return Ok(lookup.validate_str(input, false)?.map(|v| v.clone_ref(py)));
                         } else if py_input.is_instance_of::<PyInt>() {
-                            return Ok(lookup.validate_int(py, input, false)?.map(|v| v.clone_ref(py)));
+                            return Ok(lookup.validate_int(input, false)?.map(|v| v.clone_ref(py)));
                         // necessary for compatibility with 2.6, where float values are allowed for int enums in lax mode
                         } else if py_input.is_instance_of::<PyFloat>() {
-                            return Ok(lookup.validate_int(py, input, false)?.map(|v| v.clone_ref(py)));
+                            return Ok(lookup.validate_int(input, false)?.map(|v| v.clone_ref(py)));
                         }
                     }
                 }
@@ -202,7 +280,7 @@ impl EnumValidateValue for PlainEnumValidator {
 #[derive(Debug, Clone)]
 pub struct IntEnumValidator;
 
-impl_py_gc_traverse!(EnumValidator<IntEnumValidator> { class, missing });
+impl_py_gc_traverse!(EnumValidator<IntEnumValidator> { class, lookup, names, aliases, missing });
 
 impl EnumValidateValue for IntEnumValidator {
     fn validate_value<'py, I: Input<'py> + ?Sized>(
@@ -211,14 +289,14 @@ impl EnumValidateValue for IntEnumValidator {
         lookup: &LiteralLookup<PyObject>,
         strict: bool,
     ) -> ValResult<Option<PyObject>> {
-        Ok(lookup.validate_int(py, input, strict)?.map(|v| v.clone_ref(py)))
+        Ok(lookup.validate_int(input, strict)?.map(|v| v.clone_ref(py)))
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct StrEnumValidator;
 
-impl_py_gc_traverse!(EnumValidator<StrEnumValidator> { class, missing });
+impl_py_gc_traverse!(EnumValidator<StrEnumValidator> { class, lookup, names, aliases, missing });
 
 impl EnumValidateValue for StrEnumValidator {
     fn validate_value<'py, I: Input<'py> + ?Sized>(
@@ -234,7 +312,7 @@ impl EnumValidateValue for StrEnumValidator {
 #[derive(Debug, Clone)]
 pub struct FloatEnumValidator;
 
-impl_py_gc_traverse!(EnumValidator<FloatEnumValidator> { class, missing });
+impl_py_gc_traverse!(EnumValidator<FloatEnumValidator> { class, lookup, names, aliases, missing });
 
 impl EnumValidateValue for FloatEnumValidator {
     fn validate_value<'py, I: Input<'py> + ?Sized>(
@@ -2,15 +2,16 @@ use std::cmp::Ordering;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString};
 use pyo3::IntoPyObjectExt;
 
-use crate::build_tools::{is_strict, schema_or_config_same};
+use crate::build_tools::{is_strict, py_schema_err, schema_or_config_same};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{EitherFloat, Input};
 use crate::tools::SchemaDict;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::config::{coerce_with_hook, CoerceHook};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
 
 pub struct FloatBuilder;
 
@@ -33,16 +34,30 @@ impl BuildValidator for FloatBuilder {
             Ok(FloatValidator {
                 strict: is_strict(schema, config)?,
                 allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+                coerce_hook: CoerceHook::from_config(config, intern!(py, "coerce_float"))?,
             }
             .into())
         }
     }
 }
 
+/// Validates the value returned by a `coerce_float` hook for `input`
+fn coerced_float<'a, 'py>(
+    coerced: &'a Bound<'py, PyAny>,
+    input: &(impl Input<'py> + ?Sized),
+    state: &mut ValidationState<'_, 'py>,
+) -> ValResult<EitherFloat<'a>> {
+    let either_float = coerced.validate_float(false)?.into_inner();
+    state.floor_exactness(Exactness::Lax);
+    state.record_coercion(input, "float");
+    Ok(either_float)
+}
+
 #[derive(Debug, Clone)]
 pub struct FloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    coerce_hook: Option<CoerceHook>,
 }
 
 impl BuildValidator for FloatValidator {
@@ -57,12 +72,13 @@ impl BuildValidator for FloatValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            coerce_hook: CoerceHook::from_config(config, intern!(py, "coerce_float"))?,
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(FloatValidator {});
+impl_py_gc_traverse!(FloatValidator { coerce_hook });
 
 impl Validator for FloatValidator {
     fn validate<'py>(
@@ -71,7 +87,15 @@ impl Validator for FloatValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_float = input.validate_float(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        let coerced;
+        let either_float = match input.validate_float(strict) {
+            Ok(val_match) => val_match.unpack_coerced(state, input, "float"),
+            Err(err) => {
+                coerced = coerce_with_hook(self.coerce_hook.as_ref(), py, input, strict, err)?;
+                coerced_float(&coerced, input, state)?
+            }
+        };
         if !self.allow_inf_nan && !either_float.as_f64().is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
         }
@@ -83,18 +107,52 @@ impl Validator for FloatValidator {
     }
 }
 
+/// How far a value may be from the nearest multiple of `multiple_of` and still be accepted,
+/// floats like `0.3` are rarely an exact multiple of `0.1`
+#[derive(Debug, Clone, Copy)]
+enum MultipleOfTolerance {
+    Absolute(f64),
+    /// scaled by the magnitude of the value or `multiple_of`, whichever is larger
+    Relative(f64),
+}
+
+impl MultipleOfTolerance {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        let tolerance: f64 = schema.get_as(intern!(py, "multiple_of_tolerance"))?.unwrap_or(1e-9);
+        if !(tolerance >= 0.0 && tolerance.is_finite()) {
+            return py_schema_err!("'multiple_of_tolerance' must be a finite, non-negative number");
+        }
+        let mode = schema.get_as::<Bound<'_, PyString>>(intern!(py, "multiple_of_tolerance_mode"))?;
+        match mode.as_ref().map(|m| m.to_str()).transpose()? {
+            None | Some("absolute") => Ok(Self::Absolute(tolerance)),
+            Some("relative") => Ok(Self::Relative(tolerance)),
+            Some(s) => py_schema_err!("Invalid multiple_of_tolerance_mode: {}", s),
+        }
+    }
+
+    fn allowed_diff(self, float: f64, multiple_of: f64) -> f64 {
+        match self {
+            Self::Absolute(tolerance) => tolerance,
+            Self::Relative(tolerance) => tolerance * float.abs().max(multiple_of.abs()),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ConstrainedFloatValidator {
     strict: bool,
     allow_inf_nan: bool,
     multiple_of: Option<f64>,
+    multiple_of_tolerance: MultipleOfTolerance,
     le: Option<f64>,
     lt: Option<f64>,
     ge: Option<f64>,
     gt: Option<f64>,
+    coerce_hook: Option<CoerceHook>,
 }
 
-impl_py_gc_traverse!(ConstrainedFloatValidator {});
+impl_py_gc_traverse!(ConstrainedFloatValidator { coerce_hook });
 
 impl Validator for ConstrainedFloatValidator {
     fn validate<'py>(
@@ -103,16 +161,23 @@ impl Validator for ConstrainedFloatValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_float = input.validate_float(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        let coerced;
+        let either_float = match input.validate_float(strict) {
+            Ok(val_match) => val_match.unpack_coerced(state, input, "float"),
+            Err(err) => {
+                coerced = coerce_with_hook(self.coerce_hook.as_ref(), py, input, strict, err)?;
+                coerced_float(&coerced, input, state)?
+            }
+        };
         let float: f64 = either_float.as_f64();
         if !self.allow_inf_nan && !float.is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
         }
         if let Some(multiple_of) = self.multiple_of {
-            let tolerance = 1e-9;
             let rounded_div = (float / multiple_of).round();
             let diff = (float - (rounded_div * multiple_of)).abs();
-            if diff > tolerance {
+            if diff > self.multiple_of_tolerance.allowed_diff(float, multiple_of) {
                 return Err(ValError::new(
                     ErrorType::MultipleOf {
                         multiple_of: multiple_of.into(),
@@ -186,10 +251,12 @@ impl BuildValidator for ConstrainedFloatValidator {
             strict: is_strict(schema, config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
+            multiple_of_tolerance: MultipleOfTolerance::from_schema(schema)?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
             ge: schema.get_as(intern!(py, "ge"))?,
             gt: schema.get_as(intern!(py, "gt"))?,
+            coerce_hook: CoerceHook::from_config(config, intern!(py, "coerce_float"))?,
         }
         .into())
     }
@@ -0,0 +1,191 @@
+use pyo3::exceptions::{PyArithmeticError, PyValueError};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{IntoPyDict, PyDict, PyString, PyType};
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ErrorTypeDefaults, Number, ValError, ValResult};
+use crate::input::{Input, InputType, ValidationMatch};
+
+use super::decimal::get_decimal_type;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+static FRACTION_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+pub fn get_fraction_type(py: Python<'_>) -> &Bound<'_, PyType> {
+    FRACTION_TYPE
+        .get_or_init(py, || {
+            py.import("fractions")
+                .and_then(|fractions_module| fractions_module.getattr("Fraction"))
+                .unwrap()
+                .extract()
+                .unwrap()
+        })
+        .bind(py)
+}
+
+fn validate_as_fraction(
+    py: Python,
+    schema: &Bound<'_, PyDict>,
+    key: &Bound<'_, PyString>,
+) -> PyResult<Option<Py<PyAny>>> {
+    match schema.get_item(key)? {
+        Some(value) => match get_fraction_type(py).call1((value,)) {
+            Ok(fraction) => Ok(Some(fraction.unbind())),
+            Err(_) => py_schema_err!("'{}' must be coercible to a Fraction instance", key),
+        },
+        None => Ok(None),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FractionValidator {
+    strict: bool,
+    le: Option<Py<PyAny>>,
+    lt: Option<Py<PyAny>>,
+    ge: Option<Py<PyAny>>,
+    gt: Option<Py<PyAny>>,
+}
+
+impl BuildValidator for FractionValidator {
+    const EXPECTED_TYPE: &'static str = "fraction";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            le: validate_as_fraction(py, schema, intern!(py, "le"))?,
+            lt: validate_as_fraction(py, schema, intern!(py, "lt"))?,
+            ge: validate_as_fraction(py, schema, intern!(py, "ge"))?,
+            gt: validate_as_fraction(py, schema, intern!(py, "gt"))?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(FractionValidator { le, lt, ge, gt });
+
+impl Validator for FractionValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let class = get_fraction_type(py);
+        let fraction = match input.as_python().filter(|any| any.is_instance(class).unwrap_or(false)) {
+            Some(py_input) => py_input.clone(),
+            None => {
+                if state.strict_or(self.strict) && state.extra().input_type == InputType::Python {
+                    let class = class.qualname()?.to_string();
+                    return Err(ValError::new(ErrorType::IsInstanceOf { class, context: None }, input));
+                }
+                // as with decimals from JSON, strings and numbers from JSON are an exact match,
+                // anything from python is a coercion
+                if state.extra().input_type == InputType::Python {
+                    state.floor_exactness(Exactness::Lax);
+                }
+                create_fraction(&fraction_arg(py, input)?, input)?
+            }
+        };
+        self.check_constraints(py, &fraction, input)?;
+        Ok(fraction.unbind())
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+impl FractionValidator {
+    fn check_constraints<'py>(
+        &self,
+        py: Python<'py>,
+        fraction: &Bound<'py, PyAny>,
+        input: &(impl Input<'py> + ?Sized),
+    ) -> ValResult<()> {
+        if let Some(le) = &self.le {
+            if !fraction.le(le)? {
+                return Err(ValError::new(
+                    ErrorType::LessThanEqual {
+                        le: Number::String(le.to_string()),
+                        context: Some([("le", le)].into_py_dict(py)?.into()),
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(lt) = &self.lt {
+            if !fraction.lt(lt)? {
+                return Err(ValError::new(
+                    ErrorType::LessThan {
+                        lt: Number::String(lt.to_string()),
+                        context: Some([("lt", lt)].into_py_dict(py)?.into()),
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(ge) = &self.ge {
+            if !fraction.ge(ge)? {
+                return Err(ValError::new(
+                    ErrorType::GreaterThanEqual {
+                        ge: Number::String(ge.to_string()),
+                        context: Some([("ge", ge)].into_py_dict(py)?.into()),
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(gt) = &self.gt {
+            if !fraction.gt(gt)? {
+                return Err(ValError::new(
+                    ErrorType::GreaterThan {
+                        gt: Number::String(gt.to_string()),
+                        context: Some([("gt", gt)].into_py_dict(py)?.into()),
+                    },
+                    input,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The argument to construct the `Fraction` from: a string (e.g. `"3/4"`), an int, a `Decimal` or a float
+fn fraction_arg<'py>(py: Python<'py>, input: &(impl Input<'py> + ?Sized)) -> ValResult<Bound<'py, PyAny>> {
+    if let Some(either_str) = input.validate_str(true, false).ok().map(ValidationMatch::into_inner) {
+        return Ok(PyString::new(py, &either_str.as_cow()?).into_any());
+    }
+    // strict so bools are rejected
+    if let Ok(either_int) = input.validate_int(true) {
+        return Ok(either_int.into_inner().as_int()?.into_pyobject(py)?);
+    }
+    // checked before floats since decimals can be converted to a float, but not exactly
+    if let Some(py_input) = input.as_python() {
+        if py_input.is_instance(get_decimal_type(py))? {
+            return Ok(py_input.clone());
+        }
+    }
+    if let Ok(either_float) = input.validate_float(true) {
+        return Ok(either_float.into_inner().as_f64().into_pyobject(py)?.into_any());
+    }
+    Err(ValError::new(ErrorTypeDefaults::FractionType, input))
+}
+
+fn create_fraction<'py>(arg: &Bound<'py, PyAny>, input: &(impl Input<'py> + ?Sized)) -> ValResult<Bound<'py, PyAny>> {
+    let py = arg.py();
+    get_fraction_type(py).call1((arg,)).map_err(|e| {
+        // invalid strings raise `ValueError`, zero denominators and infinite floats raise `ArithmeticError`s
+        if e.is_instance_of::<PyValueError>(py) || e.is_instance_of::<PyArithmeticError>(py) {
+            ValError::new(ErrorTypeDefaults::FractionParsing, input)
+        } else {
+            ValError::InternalErr(e)
+        }
+    })
+}
@@ -34,7 +34,10 @@ impl Validator for FrozenSetValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let collection = input.validate_frozenset(state.strict_or(self.strict))?.unpack(state);
+        let collection =
+            input
+                .validate_frozenset(state.strict_or(self.strict))?
+                .unpack_coerced(state, input, "frozenset");
         let f_set = PyFrozenSet::empty(py)?;
         collection.iterate(ValidateToFrozenSet {
             py,
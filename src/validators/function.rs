@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use pyo3::exceptions::{PyAssertionError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyDict, PyString};
+use pyo3::types::{PyAny, PyDict, PyString, PyTuple};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::errors::{
@@ -16,7 +16,7 @@ use crate::PydanticUseDefault;
 
 use super::generator::InternalValidator;
 use super::{
-    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Extra, InputType, ValidationState,
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, FieldPath, InputType, ValidationState,
     Validator,
 };
 
@@ -100,13 +100,14 @@ impl FunctionBeforeValidator {
         state: &'s mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let r = if self.info_arg {
+            state.mark_context_dependent();
             let field_name = state
                 .extra()
                 .field_name
                 .clone()
                 .map(Bound::unbind)
                 .or_else(|| self.field_name.clone());
-            let info = ValidationInfo::new(py, state.extra(), &self.config, field_name);
+            let info = ValidationInfo::new(py, state, &self.config, field_name);
             self.func.call1(py, (input.to_object(py)?, info))
         } else {
             self.func.call1(py, (input.to_object(py)?,))
@@ -148,6 +149,30 @@ impl Validator for FunctionBeforeValidator {
         self._validate(validate, py, obj, state)
     }
 
+    fn validate_assignments<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        assignments: &Bound<'py, PyDict>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let validate =
+            move |v, s: &mut ValidationState<'_, 'py>| self.validator.validate_assignments(py, &v, assignments, s);
+        #[allow(clippy::used_underscore_items)]
+        self._validate(validate, py, obj, state)
+    }
+
+    /// functions aren't called when constructing, as with `model_construct()`
+    fn construct<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        fields_set: Option<&Bound<'py, PyAny>>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        self.validator.construct(py, input, fields_set, state)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -175,13 +200,14 @@ impl FunctionAfterValidator {
     ) -> ValResult<PyObject> {
         let v = call(input, state)?;
         let r = if self.info_arg {
+            state.mark_context_dependent();
             let field_name = state
                 .extra()
                 .field_name
                 .clone()
                 .map(Bound::unbind)
                 .or_else(|| self.field_name.clone());
-            let info = ValidationInfo::new(py, state.extra(), &self.config, field_name);
+            let info = ValidationInfo::new(py, state, &self.config, field_name);
             self.func.call1(py, (v, info))
         } else {
             self.func.call1(py, (v,))
@@ -222,6 +248,30 @@ impl Validator for FunctionAfterValidator {
         self._validate(validate, py, obj, state)
     }
 
+    fn validate_assignments<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        assignments: &Bound<'py, PyDict>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let validate = move |v: &Bound<'py, PyAny>, s: &mut ValidationState<'_, 'py>| {
+            self.validator.validate_assignments(py, v, assignments, s)
+        };
+        #[allow(clippy::used_underscore_items)]
+        self._validate(validate, py, obj, state)
+    }
+
+    fn construct<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        fields_set: Option<&Bound<'py, PyAny>>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        self.validator.construct(py, input, fields_set, state)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -270,13 +320,14 @@ impl Validator for FunctionPlainValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let r = if self.info_arg {
+            state.mark_context_dependent();
             let field_name = state
                 .extra()
                 .field_name
                 .clone()
                 .map(Bound::unbind)
                 .or_else(|| self.field_name.clone());
-            let info = ValidationInfo::new(py, state.extra(), &self.config, field_name);
+            let info = ValidationInfo::new(py, state, &self.config, field_name);
             self.func.call1(py, (input.to_object(py)?, info))
         } else {
             self.func.call1(py, (input.to_object(py)?,))
@@ -340,13 +391,14 @@ impl FunctionWrapValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let r = if self.info_arg {
+            state.mark_context_dependent();
             let field_name = state
                 .extra()
                 .field_name
                 .clone()
                 .map(Bound::unbind)
                 .or_else(|| self.field_name.clone());
-            let info = ValidationInfo::new(py, state.extra(), &self.config, field_name);
+            let info = ValidationInfo::new(py, state, &self.config, field_name);
             self.func.call1(py, (input.to_object(py)?, handler, info))
         } else {
             self.func.call1(py, (input.to_object(py)?, handler))
@@ -402,13 +454,43 @@ impl Validator for FunctionWrapValidator {
                 self.hide_input_in_errors,
                 self.validation_error_cause,
             ),
-            updated_field_name: field_name.to_string(),
-            updated_field_value: field_value.clone().into(),
+            assignment: Assignment::Field(field_name.to_string(), field_value.clone().unbind()),
         };
         #[allow(clippy::used_underscore_items)]
         self._validate(Bound::new(py, handler)?.as_any(), py, obj, state)
     }
 
+    fn validate_assignments<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        assignments: &Bound<'py, PyDict>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let handler = AssignmentValidatorCallable {
+            validator: InternalValidator::new(
+                "AssignmentValidatorCallable",
+                self.validator.clone(),
+                state,
+                self.hide_input_in_errors,
+                self.validation_error_cause,
+            ),
+            assignment: Assignment::Fields(assignments.clone().unbind()),
+        };
+        #[allow(clippy::used_underscore_items)]
+        self._validate(Bound::new(py, handler)?.as_any(), py, obj, state)
+    }
+
+    fn construct<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        fields_set: Option<&Bound<'py, PyAny>>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        self.validator.construct(py, input, fields_set, state)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -449,11 +531,17 @@ impl ValidatorCallable {
 #[pyclass(module = "pydantic_core._pydantic_core")]
 #[derive(Debug)]
 struct AssignmentValidatorCallable {
-    updated_field_name: String,
-    updated_field_value: Py<PyAny>,
+    assignment: Assignment,
     validator: InternalValidator,
 }
 
+#[derive(Debug)]
+enum Assignment {
+    Field(String, Py<PyAny>),
+    /// from `SchemaValidator.validate_assignments`
+    Fields(Py<PyDict>),
+}
+
 #[pymethods]
 impl AssignmentValidatorCallable {
     #[pyo3(signature = (input_value, outer_location=None))]
@@ -464,13 +552,16 @@ impl AssignmentValidatorCallable {
         outer_location: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<PyObject> {
         let outer_location = outer_location.map(Into::into);
-        self.validator.validate_assignment(
-            py,
-            input_value,
-            self.updated_field_name.as_str(),
-            self.updated_field_value.bind(py),
-            outer_location,
-        )
+        match &self.assignment {
+            Assignment::Field(field_name, field_value) => {
+                self.validator
+                    .validate_assignment(py, input_value, field_name, field_value.bind(py), outer_location)
+            }
+            Assignment::Fields(assignments) => {
+                self.validator
+                    .validate_assignments(py, input_value, assignments.bind(py), outer_location)
+            }
+        }
     }
 
     fn __repr__(&self) -> String {
@@ -525,21 +616,29 @@ pub fn convert_err(py: Python<'_>, err: PyErr, input: impl ToErrorValue) -> ValE
     }
 }
 
-#[pyclass(module = "pydantic_core._pydantic_core", get_all)]
+#[pyclass(module = "pydantic_core._pydantic_core")]
 pub struct ValidationInfo {
+    #[pyo3(get)]
     config: PyObject,
+    #[pyo3(get)]
     context: Option<PyObject>,
+    #[pyo3(get)]
     data: Option<Py<PyDict>>,
+    #[pyo3(get)]
     field_name: Option<Py<PyString>>,
+    field_path: FieldPath,
+    #[pyo3(get)]
     mode: InputType,
 }
 
 impl ValidationInfo {
-    fn new(py: Python, extra: &Extra, config: &PyObject, field_name: Option<Py<PyString>>) -> Self {
+    fn new(py: Python, state: &ValidationState, config: &PyObject, field_name: Option<Py<PyString>>) -> Self {
+        let extra = state.extra();
         Self {
             config: config.clone_ref(py),
-            context: extra.context.map(|ctx| ctx.clone().into()),
+            context: extra.context.as_ref().map(|ctx| ctx.clone().into()),
             field_name,
+            field_path: state.field_path.clone(),
             data: extra.data.as_ref().map(|data| data.clone().into()),
             mode: extra.input_type,
         }
@@ -560,6 +659,11 @@ impl ValidationInfo {
 
 #[pymethods]
 impl ValidationInfo {
+    #[getter]
+    fn field_path<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        self.field_path.to_tuple(py)
+    }
+
     fn __repr__(&self, py: Python) -> PyResult<String> {
         let context = match self.context {
             Some(ref context) => safe_repr(context.bind(py)).to_string(),
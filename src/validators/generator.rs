@@ -1,6 +1,7 @@
 use std::fmt;
 use std::sync::Arc;
 
+use pyo3::exceptions::PyTypeError;
 use pyo3::types::{PyDict, PyString};
 use pyo3::{prelude::*, IntoPyObjectExt, PyTraverseError, PyVisit};
 
@@ -13,7 +14,8 @@ use crate::ValidationError;
 
 use super::list::get_items_schema;
 use super::{
-    BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, Extra, InputType, ValidationState, Validator,
+    BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, Extra, FieldPath, InputType, ValidationState,
+    Validator,
 };
 
 #[derive(Debug, Clone)]
@@ -197,8 +199,41 @@ impl ValidatorIterator {
         }
     }
 
+    #[getter]
+    fn min_length(&self) -> Option<usize> {
+        self.min_length
+    }
+
+    #[getter]
+    fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Name of the validator applied to each item, `any` if items aren't validated
+    #[getter]
+    fn item_schema(&self) -> &str {
+        match self.validator {
+            Some(ref v) => v.validator_name(),
+            None => "any",
+        }
+    }
+
     fn __repr__(&self) -> String {
-        format!("ValidatorIterator(index={}, schema={:?})", self.index(), self.validator)
+        let fmt_length = |length: Option<usize>| length.map_or_else(|| "None".to_string(), |l| l.to_string());
+        format!(
+            "ValidatorIterator(index={}, item_schema='{}', min_length={}, max_length={})",
+            self.index(),
+            self.item_schema(),
+            fmt_length(self.min_length),
+            fmt_length(self.max_length),
+        )
+    }
+
+    fn __reduce__(&self) -> PyResult<()> {
+        Err(PyTypeError::new_err(
+            "ValidatorIterator cannot be pickled since it wraps a partially consumed iterator, \
+             consume it first (e.g. with `list()`) and pickle the result",
+        ))
     }
 
     fn __str__(&self) -> String {
@@ -222,6 +257,7 @@ pub struct InternalValidator {
     strict: Option<bool>,
     from_attributes: Option<bool>,
     context: Option<PyObject>,
+    field_path: FieldPath,
     self_instance: Option<PyObject>,
     recursion_guard: RecursionState,
     pub(crate) exactness: Option<Exactness>,
@@ -239,6 +275,10 @@ impl fmt::Debug for InternalValidator {
 }
 
 impl InternalValidator {
+    pub fn validator_name(&self) -> &str {
+        self.validator.get_name()
+    }
+
     pub fn new(
         name: &str,
         validator: Arc<CombinedValidator>,
@@ -253,7 +293,8 @@ impl InternalValidator {
             data: extra.data.as_ref().map(|d| d.clone().into()),
             strict: extra.strict,
             from_attributes: extra.from_attributes,
-            context: extra.context.map(|d| d.clone().unbind()),
+            context: extra.context.as_ref().map(|d| d.clone().unbind()),
+            field_path: state.field_path.clone(),
             self_instance: extra.self_instance.map(|d| d.clone().unbind()),
             recursion_guard: state.recursion_guard.clone(),
             exactness: state.exactness,
@@ -272,35 +313,61 @@ impl InternalValidator {
         field_name: &str,
         field_value: &Bound<'py, PyAny>,
         outer_location: Option<LocItem>,
+    ) -> PyResult<PyObject> {
+        self.validate_assignment_with(
+            py,
+            Some(PyString::new(py, field_name)),
+            outer_location,
+            |validator, state| validator.validate_assignment(py, model, field_name, field_value, state),
+        )
+    }
+
+    pub fn validate_assignments<'py>(
+        &mut self,
+        py: Python<'py>,
+        model: &Bound<'py, PyAny>,
+        assignments: &Bound<'py, PyDict>,
+        outer_location: Option<LocItem>,
+    ) -> PyResult<PyObject> {
+        self.validate_assignment_with(py, None, outer_location, |validator, state| {
+            validator.validate_assignments(py, model, assignments, state)
+        })
+    }
+
+    fn validate_assignment_with<'py>(
+        &mut self,
+        py: Python<'py>,
+        field_name: Option<Bound<'py, PyString>>,
+        outer_location: Option<LocItem>,
+        validate: impl FnOnce(&CombinedValidator, &mut ValidationState<'_, 'py>) -> ValResult<PyObject>,
     ) -> PyResult<PyObject> {
         let extra = Extra {
             input_type: self.validation_mode,
             data: self.data.as_ref().map(|data| data.bind(py).clone()),
             strict: self.strict,
+            strict_overrides: None,
             from_attributes: self.from_attributes,
-            field_name: Some(PyString::new(py, field_name)),
-            context: self.context.as_ref().map(|data| data.bind(py)),
+            field_name,
+            context: self.context.as_ref().map(|data| data.bind(py).clone()),
             self_instance: self.self_instance.as_ref().map(|data| data.bind(py)),
             cache_str: self.cache_str,
             by_alias: None,
             by_name: None,
         };
         let mut state = ValidationState::new(extra, &mut self.recursion_guard, false.into());
+        state.field_path = self.field_path.clone();
         state.exactness = self.exactness;
-        let result = self
-            .validator
-            .validate_assignment(py, model, field_name, field_value, &mut state)
-            .map_err(|e| {
-                ValidationError::from_val_error(
-                    py,
-                    PyString::new(py, &self.name).into(),
-                    InputType::Python,
-                    e,
-                    outer_location,
-                    self.hide_input_in_errors,
-                    self.validation_error_cause,
-                )
-            });
+        let result = validate(&self.validator, &mut state).map_err(|e| {
+            ValidationError::from_val_error(
+                py,
+                PyString::new(py, &self.name).into(),
+                InputType::Python,
+                e,
+                outer_location,
+                self.hide_input_in_errors,
+                self.validation_error_cause,
+            )
+        });
         self.exactness = state.exactness;
         result
     }
@@ -315,15 +382,17 @@ impl InternalValidator {
             input_type: self.validation_mode,
             data: self.data.as_ref().map(|data| data.bind(py).clone()),
             strict: self.strict,
+            strict_overrides: None,
             from_attributes: self.from_attributes,
             field_name: None,
-            context: self.context.as_ref().map(|data| data.bind(py)),
+            context: self.context.as_ref().map(|data| data.bind(py).clone()),
             self_instance: self.self_instance.as_ref().map(|data| data.bind(py)),
             cache_str: self.cache_str,
             by_alias: None,
             by_name: None,
         };
         let mut state = ValidationState::new(extra, &mut self.recursion_guard, false.into());
+        state.field_path = self.field_path.clone();
         state.exactness = self.exactness;
         state.fields_set_count = self.fields_set_count;
         let result = self.validator.validate(py, input, &mut state).map_err(|e| {
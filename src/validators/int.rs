@@ -6,10 +6,12 @@ use pyo3::types::{PyDict, PyString};
 use pyo3::IntoPyObjectExt;
 
 use crate::build_tools::is_strict;
-use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{Input, Int};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{EitherInt, Input, Int};
+use crate::tools::SchemaDict;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::config::{coerce_with_hook, CoerceHook};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
 
 fn validate_as_int(schema: &Bound<'_, PyDict>, key: &Bound<'_, PyString>) -> PyResult<Option<Int>> {
     match schema.get_item(key)? {
@@ -28,9 +30,104 @@ fn validate_as_int(schema: &Bound<'_, PyDict>, key: &Bound<'_, PyString>) -> PyR
     }
 }
 
+/// How lax mode converts strings and other inputs which aren't ints
+#[derive(Debug, Clone)]
+struct LaxInt {
+    allow_underscores: bool,
+    allow_radix_prefixes: bool,
+    coerce_hook: Option<CoerceHook>,
+}
+
+impl LaxInt {
+    fn build(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let py = schema.py();
+        Ok(Self {
+            allow_underscores: schema.get_as(intern!(py, "allow_underscores"))?.unwrap_or(true),
+            allow_radix_prefixes: schema.get_as(intern!(py, "allow_radix_prefixes"))?.unwrap_or(false),
+            coerce_hook: CoerceHook::from_config(config, intern!(py, "coerce_int"))?,
+        })
+    }
+
+    /// `coerced` holds the value returned by the `coerce_int` hook, which the result may borrow from
+    fn validate_int<'a, 'py, I: Input<'py> + ?Sized>(
+        &self,
+        py: Python<'py>,
+        input: &'a I,
+        state: &mut ValidationState<'_, 'py>,
+        strict: bool,
+        coerced: &'a mut Option<Bound<'py, PyAny>>,
+    ) -> ValResult<EitherInt<'a>> {
+        match input.validate_int(strict) {
+            Ok(val_match) => {
+                if !self.allow_underscores && !strict {
+                    if let Ok(either_str) = input.exact_str() {
+                        if either_str.as_cow()?.contains('_') {
+                            return Err(ValError::new(ErrorTypeDefaults::IntParsing, input));
+                        }
+                    }
+                }
+                Ok(val_match.unpack_coerced(state, input, "int"))
+            }
+            Err(err) if strict => Err(err),
+            Err(err) => {
+                if self.allow_radix_prefixes {
+                    if let Ok(either_str) = input.exact_str() {
+                        if let Some(int) = parse_radix_int(&either_str.as_cow()?, self.allow_underscores) {
+                            state.floor_exactness(Exactness::Lax);
+                            state.record_coercion(input, "int");
+                            return Ok(int);
+                        }
+                    }
+                }
+                let value = coerce_with_hook(self.coerce_hook.as_ref(), py, input, strict, err)?;
+                let either_int = coerced.insert(value).validate_int(false)?.into_inner();
+                state.floor_exactness(Exactness::Lax);
+                state.record_coercion(input, "int");
+                Ok(either_int)
+            }
+        }
+    }
+}
+
+/// Parses strings with a `0x`, `0o` or `0b` prefix like python's `int(s, 0)`
+fn parse_radix_int(s: &str, allow_underscores: bool) -> Option<EitherInt<'static>> {
+    let s = s.trim();
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let radix = match s.get(..2)? {
+        "0x" | "0X" => 16,
+        "0o" | "0O" => 8,
+        "0b" | "0B" => 2,
+        _ => return None,
+    };
+    let mut digits = &s[2..];
+    if allow_underscores {
+        // as in python, an underscore may follow the prefix and separate digits
+        digits = digits.strip_prefix('_').unwrap_or(digits);
+    }
+    let separators_valid = match allow_underscores {
+        true => !digits.starts_with('_') && !digits.ends_with('_') && !digits.contains("__"),
+        false => !digits.contains('_'),
+    };
+    if digits.is_empty() || !separators_valid || !digits.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_') {
+        return None;
+    }
+    let int = BigInt::parse_bytes(digits.replace('_', "").as_bytes(), radix)?;
+    let int = if negative { -int } else { int };
+    Some(match i64::try_from(&int) {
+        Ok(i) => EitherInt::I64(i),
+        Err(_) => EitherInt::BigInt(int),
+    })
+}
+
+impl_py_gc_traverse!(LaxInt { coerce_hook });
+
 #[derive(Debug, Clone)]
 pub struct IntValidator {
     strict: bool,
+    lax: LaxInt,
 }
 
 impl BuildValidator for IntValidator {
@@ -52,13 +149,14 @@ impl BuildValidator for IntValidator {
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                lax: LaxInt::build(schema, config)?,
             }
             .into())
         }
     }
 }
 
-impl_py_gc_traverse!(IntValidator {});
+impl_py_gc_traverse!(IntValidator { lax });
 
 impl Validator for IntValidator {
     fn validate<'py>(
@@ -67,9 +165,10 @@ impl Validator for IntValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        input
-            .validate_int(state.strict_or(self.strict))
-            .and_then(|val_match| Ok(val_match.unpack(state).into_py_any(py)?))
+        let strict = state.strict_or(self.strict);
+        let mut coerced = None;
+        let either_int = self.lax.validate_int(py, input, state, strict, &mut coerced)?;
+        Ok(either_int.into_py_any(py)?)
     }
 
     fn get_name(&self) -> &str {
@@ -85,24 +184,30 @@ pub struct ConstrainedIntValidator {
     lt: Option<Int>,
     ge: Option<Int>,
     gt: Option<Int>,
+    lax: LaxInt,
 }
 
 impl ConstrainedIntValidator {
     fn build(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<CombinedValidator> {
         let py = schema.py();
+        let multiple_of = validate_as_int(schema, intern!(py, "multiple_of"))?;
+        if multiple_of.as_ref().is_some_and(Int::is_zero) {
+            return Err(PyValueError::new_err("'multiple_of' must not be zero"));
+        }
         Ok(Self {
             strict: is_strict(schema, config)?,
-            multiple_of: validate_as_int(schema, intern!(py, "multiple_of"))?,
+            multiple_of,
             le: validate_as_int(schema, intern!(py, "le"))?,
             lt: validate_as_int(schema, intern!(py, "lt"))?,
             ge: validate_as_int(schema, intern!(py, "ge"))?,
             gt: validate_as_int(schema, intern!(py, "gt"))?,
+            lax: LaxInt::build(schema, config)?,
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(ConstrainedIntValidator {});
+impl_py_gc_traverse!(ConstrainedIntValidator { lax });
 
 impl Validator for ConstrainedIntValidator {
     fn validate<'py>(
@@ -111,11 +216,13 @@ impl Validator for ConstrainedIntValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_int = input.validate_int(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        let mut coerced = None;
+        let either_int = self.lax.validate_int(py, input, state, strict, &mut coerced)?;
         let int_value = either_int.as_int()?;
 
         if let Some(ref multiple_of) = self.multiple_of {
-            if &int_value % multiple_of != Int::Big(BigInt::from(0)) {
+            if !int_value.is_multiple_of(multiple_of) {
                 return Err(ValError::new(
                     ErrorType::MultipleOf {
                         multiple_of: multiple_of.clone().into(),
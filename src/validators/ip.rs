@@ -0,0 +1,309 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyType};
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::{Input, InputType, Int, ValidationMatch};
+use crate::serializers::BytesMode;
+use crate::tools::SchemaDict;
+
+use super::config::ValBytesMode;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+static IP_TYPES: GILOnceCell<[Py<PyType>; 6]> = GILOnceCell::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpKind {
+    Address,
+    Network,
+    Interface,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IpVersion {
+    V4,
+    V6,
+}
+
+impl IpVersion {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        match schema.get_as::<u8>(intern!(schema.py(), "version"))? {
+            None => Ok(None),
+            Some(4) => Ok(Some(Self::V4)),
+            Some(6) => Ok(Some(Self::V6)),
+            Some(v) => py_schema_err!("Invalid IP version {}, expected 4 or 6", v),
+        }
+    }
+
+    fn of(addr: &IpAddr) -> Self {
+        match addr {
+            IpAddr::V4(_) => Self::V4,
+            IpAddr::V6(_) => Self::V6,
+        }
+    }
+}
+
+impl IpKind {
+    /// The `ipaddress` classes for this kind, IPv4 first then IPv6
+    pub fn py_types(self, py: Python<'_>) -> PyResult<[&Bound<'_, PyType>; 2]> {
+        let types = IP_TYPES.get_or_try_init(py, || -> PyResult<[Py<PyType>; 6]> {
+            let module = py.import(intern!(py, "ipaddress"))?;
+            let get = |name: &str| -> PyResult<Py<PyType>> { module.getattr(name)?.extract() };
+            Ok([
+                get("IPv4Address")?,
+                get("IPv6Address")?,
+                get("IPv4Network")?,
+                get("IPv6Network")?,
+                get("IPv4Interface")?,
+                get("IPv6Interface")?,
+            ])
+        })?;
+        let offset = match self {
+            Self::Address => 0,
+            Self::Network => 2,
+            Self::Interface => 4,
+        };
+        Ok([types[offset].bind(py), types[offset + 1].bind(py)])
+    }
+
+    fn py_type(self, py: Python<'_>, version: IpVersion) -> PyResult<&Bound<'_, PyType>> {
+        let [v4, v6] = self.py_types(py)?;
+        Ok(match version {
+            IpVersion::V4 => v4,
+            IpVersion::V6 => v6,
+        })
+    }
+
+    fn description(self, version: Option<IpVersion>) -> String {
+        let version = match version {
+            None => "IP",
+            Some(IpVersion::V4) => "IPv4",
+            Some(IpVersion::V6) => "IPv6",
+        };
+        let kind = match self {
+            Self::Address => "address",
+            Self::Network => "network",
+            Self::Interface => "interface",
+        };
+        format!("{version} {kind}")
+    }
+}
+
+macro_rules! ip_validator_builder {
+    ($builder:ident, $expected_type:literal, $kind:expr) => {
+        #[derive(Debug)]
+        pub struct $builder;
+
+        impl BuildValidator for $builder {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                schema: &Bound<'_, PyDict>,
+                config: Option<&Bound<'_, PyDict>>,
+                _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+            ) -> PyResult<CombinedValidator> {
+                Ok(IpValidator {
+                    kind: $kind,
+                    version: IpVersion::from_schema(schema)?,
+                    strict: is_strict(schema, config)?,
+                    name: $expected_type,
+                }
+                .into())
+            }
+        }
+    };
+}
+
+ip_validator_builder!(IpAddressValidatorBuilder, "ip-address", IpKind::Address);
+ip_validator_builder!(IpNetworkValidatorBuilder, "ip-network", IpKind::Network);
+ip_validator_builder!(IpInterfaceValidatorBuilder, "ip-interface", IpKind::Interface);
+
+#[derive(Debug, Clone)]
+pub struct IpValidator {
+    kind: IpKind,
+    version: Option<IpVersion>,
+    strict: bool,
+    name: &'static str,
+}
+
+impl_py_gc_traverse!(IpValidator {});
+
+impl Validator for IpValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        if let Some(py_input) = self.as_instance(py, input)? {
+            return Ok(py_input.clone().unbind());
+        }
+        if state.strict_or(self.strict) && state.extra().input_type == InputType::Python {
+            let class = match self.version {
+                Some(version) => self.kind.py_type(py, version)?.qualname()?.to_string(),
+                None => {
+                    let [v4, v6] = self.kind.py_types(py)?;
+                    format!("{} | {}", v4.qualname()?, v6.qualname()?)
+                }
+            };
+            return Err(ValError::new(ErrorType::IsInstanceOf { class, context: None }, input));
+        }
+
+        // as with UUIDs, a string from JSON is an exact match, anything from python is a coercion
+        if state.extra().input_type == InputType::Python {
+            state.floor_exactness(Exactness::Lax);
+        }
+        let (addr, prefix) = self.parse_input(input)?;
+        let value = match addr {
+            IpAddr::V4(addr) => u32::from(addr).into_pyobject(py)?,
+            IpAddr::V6(addr) => u128::from(addr).into_pyobject(py)?,
+        };
+        let class = self.kind.py_type(py, IpVersion::of(&addr))?;
+        let output = match self.kind {
+            IpKind::Address => class.call1((value,))?,
+            IpKind::Network | IpKind::Interface => class.call1(((value, prefix),))?,
+        };
+        Ok(output.unbind())
+    }
+
+    fn get_name(&self) -> &str {
+        self.name
+    }
+}
+
+impl IpValidator {
+    fn as_instance<'a, 'py>(
+        &self,
+        py: Python<'py>,
+        input: &'a (impl Input<'py> + ?Sized),
+    ) -> PyResult<Option<&'a Bound<'py, PyAny>>> {
+        let Some(py_input) = input.as_python() else {
+            return Ok(None);
+        };
+        let [v4, v6] = self.kind.py_types(py)?;
+        let is_instance = match self.version {
+            Some(IpVersion::V4) => py_input.is_instance(v4)?,
+            Some(IpVersion::V6) => py_input.is_instance(v6)?,
+            None => py_input.is_instance(v4)? || py_input.is_instance(v6)?,
+        };
+        Ok(is_instance.then_some(py_input))
+    }
+
+    fn parse_input<'py>(&self, input: &(impl Input<'py> + ?Sized)) -> ValResult<(IpAddr, u8)> {
+        let parsed = if let Some(either_str) = input.validate_str(true, false).ok().map(ValidationMatch::into_inner) {
+            self.parse_str(&either_str.as_cow()?)
+        } else if let Ok(either_bytes) = input.validate_bytes(true, ValBytesMode { ser: BytesMode::Utf8 }) {
+            self.parse_packed(either_bytes.into_inner().as_slice())
+                .map(|addr| (addr, max_prefix(&addr)))
+        } else if let Ok(either_int) = input.validate_int(true) {
+            self.parse_int(&either_int.into_inner().as_int()?)
+                .map(|addr| (addr, max_prefix(&addr)))
+        } else {
+            let kind = self.kind.description(self.version);
+            return Err(ValError::new(ErrorType::IpType { kind, context: None }, input));
+        };
+        parsed.map_err(|error| {
+            let kind = self.kind.description(self.version);
+            ValError::new(
+                ErrorType::IpParsing {
+                    kind,
+                    error,
+                    context: None,
+                },
+                input,
+            )
+        })
+    }
+
+    fn parse_str(&self, value: &str) -> Result<(IpAddr, u8), String> {
+        let (addr, prefix) = match (self.kind, value.split_once('/')) {
+            (IpKind::Address, _) => return self.parse_addr(value).map(|addr| (addr, max_prefix(&addr))),
+            (_, Some((addr, prefix))) => {
+                let addr = self.parse_addr(addr)?;
+                (addr, parse_prefix(prefix, &addr)?)
+            }
+            (_, None) => {
+                let addr = self.parse_addr(value)?;
+                (addr, max_prefix(&addr))
+            }
+        };
+        if self.kind == IpKind::Network && host_bits(&addr, prefix) != 0 {
+            return Err("host bits set".to_string());
+        }
+        Ok((addr, prefix))
+    }
+
+    fn parse_addr(&self, value: &str) -> Result<IpAddr, String> {
+        match self.version {
+            Some(IpVersion::V4) => value.parse::<Ipv4Addr>().map(IpAddr::V4),
+            Some(IpVersion::V6) => value.parse::<Ipv6Addr>().map(IpAddr::V6),
+            None => value.parse::<IpAddr>(),
+        }
+        .map_err(|e| e.to_string())
+    }
+
+    fn parse_packed(&self, bytes: &[u8]) -> Result<IpAddr, String> {
+        match (self.version, <[u8; 4]>::try_from(bytes), <[u8; 16]>::try_from(bytes)) {
+            (Some(IpVersion::V4) | None, Ok(octets), _) => Ok(IpAddr::V4(Ipv4Addr::from(octets))),
+            (Some(IpVersion::V6) | None, _, Ok(octets)) => Ok(IpAddr::V6(Ipv6Addr::from(octets))),
+            (Some(IpVersion::V4), ..) => Err("packed address should be 4 bytes".to_string()),
+            (Some(IpVersion::V6), ..) => Err("packed address should be 16 bytes".to_string()),
+            (None, ..) => Err("packed address should be 4 or 16 bytes".to_string()),
+        }
+    }
+
+    fn parse_int(&self, int: &Int) -> Result<IpAddr, String> {
+        let value = match int {
+            Int::I64(i) => u128::try_from(*i).ok(),
+            Int::Big(b) => u128::try_from(b).ok(),
+        };
+        let Some(value) = value else {
+            return Err("integer out of range".to_string());
+        };
+        match (self.version, u32::try_from(value)) {
+            (Some(IpVersion::V4) | None, Ok(v4)) => Ok(IpAddr::V4(Ipv4Addr::from(v4))),
+            (Some(IpVersion::V6) | None, _) => Ok(IpAddr::V6(Ipv6Addr::from(value))),
+            (Some(IpVersion::V4), Err(_)) => Err("integer out of range".to_string()),
+        }
+    }
+}
+
+fn max_prefix(addr: &IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+/// Parse a prefix length, or for IPv4 a netmask (e.g. `255.255.255.0`) or hostmask (e.g. `0.0.0.255`)
+fn parse_prefix(value: &str, addr: &IpAddr) -> Result<u8, String> {
+    if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+        return match value.parse::<u8>() {
+            Ok(prefix) if prefix <= max_prefix(addr) => Ok(prefix),
+            _ => Err(format!("invalid prefix length '{value}'")),
+        };
+    }
+    if let (IpAddr::V4(_), Ok(mask)) = (addr, value.parse::<Ipv4Addr>()) {
+        let mask = u32::from(mask);
+        for bits in [mask, !mask] {
+            let prefix = bits.leading_ones();
+            if bits.checked_shl(prefix).unwrap_or(0) == 0 {
+                return Ok(prefix as u8);
+            }
+        }
+        return Err(format!("invalid netmask '{value}'"));
+    }
+    Err(format!("invalid prefix length '{value}'"))
+}
+
+/// Bits of the address not covered by the prefix
+fn host_bits(addr: &IpAddr, prefix: u8) -> u128 {
+    match addr {
+        IpAddr::V4(addr) => u128::from(u32::from(*addr).checked_shl(u32::from(prefix)).unwrap_or(0)),
+        IpAddr::V6(addr) => u128::from(*addr).checked_shl(u32::from(prefix)).unwrap_or(0),
+    }
+}
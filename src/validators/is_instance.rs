@@ -1,18 +1,24 @@
+use pyo3::exceptions::PyKeyError;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyType};
+use pyo3::types::{PyDict, PyString, PyTuple, PyType};
 
 use crate::build_tools::py_schema_err;
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct IsInstanceValidator {
     class: PyObject,
+    // set if `cls` is a runtime checkable protocol whose members are checked in rust, see `protocol_check`
+    protocol_members: Option<Vec<ProtocolMember>>,
     class_repr: String,
+    fallback: Option<Box<CombinedValidator>>,
     name: String,
 }
 
@@ -21,8 +27,8 @@ impl BuildValidator for IsInstanceValidator {
 
     fn build(
         schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
-        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
         let cls_key = intern!(py, "cls");
@@ -34,26 +40,64 @@ impl BuildValidator for IsInstanceValidator {
             return py_schema_err!("'cls' must be valid as the first argument to 'isinstance'");
         }
 
+        let protocol_members = match schema.get_as::<Bound<'_, PyString>>(intern!(py, "protocol_check"))? {
+            Some(check) => match check.to_str()? {
+                "attributes" => protocol_members(&class)?,
+                "isinstance" => None,
+                _ => return py_schema_err!("`protocol_check` must be one of: 'attributes' or 'isinstance'"),
+            },
+            None => None,
+        };
+
         let class_repr = class_repr(schema, &class)?;
-        let name = format!("{}[{class_repr}]", Self::EXPECTED_TYPE);
+        let fallback = match schema.get_as(intern!(py, "fallback_schema"))? {
+            Some(fallback_schema) => Some(Box::new(build_validator(&fallback_schema, config, definitions)?)),
+            None => None,
+        };
+        let name = match fallback {
+            Some(ref fallback) => format!("{}[{class_repr},{}]", Self::EXPECTED_TYPE, fallback.get_name()),
+            None => format!("{}[{class_repr}]", Self::EXPECTED_TYPE),
+        };
         Ok(Self {
             class: class.into(),
+            protocol_members,
             class_repr,
+            fallback,
             name,
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(IsInstanceValidator { class });
+impl_py_gc_traverse!(IsInstanceValidator { class, fallback });
+
+impl IsInstanceValidator {
+    fn is_instance(&self, obj: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let class = self.class.bind(obj.py());
+        match self.protocol_members {
+            Some(ref members) => is_protocol_instance(obj, class, members),
+            None => obj.is_instance(class),
+        }
+    }
+}
 
 impl Validator for IsInstanceValidator {
     fn validate<'py>(
         &self,
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
-        _state: &mut ValidationState<'_, 'py>,
+        state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        if let Some(ref fallback) = self.fallback {
+            if let Some(obj) = input.as_python() {
+                if self.is_instance(obj)? {
+                    return Ok(obj.clone().unbind());
+                }
+                // an existing instance is preferred over one built by the fallback, e.g. in smart unions
+                state.floor_exactness(Exactness::Lax);
+            }
+            return fallback.validate(py, input, state);
+        }
         let Some(obj) = input.as_python() else {
             let method_name = "isinstance".to_string();
             return Err(ValError::new(
@@ -64,7 +108,7 @@ impl Validator for IsInstanceValidator {
                 input,
             ));
         };
-        match obj.is_instance(self.class.bind(py))? {
+        match self.is_instance(obj)? {
             true => Ok(obj.clone().unbind()),
             false => Err(ValError::new(
                 ErrorType::IsInstanceOf {
@@ -90,3 +134,109 @@ pub fn class_repr(schema: &Bound<'_, PyDict>, class: &Bound<'_, PyAny>) -> PyRes
         },
     }
 }
+
+#[derive(Debug)]
+struct ProtocolMember {
+    name: Py<PyString>,
+    // methods must not be `None` on instances, as in `typing._ProtocolMeta.__instancecheck__`
+    callable: bool,
+}
+
+/// The members of `class` if it's a runtime checkable protocol using the standard `__instancecheck__`,
+/// otherwise `None` so `isinstance` is used
+fn protocol_members(class: &Bound<'_, PyAny>) -> PyResult<Option<Vec<ProtocolMember>>> {
+    let py = class.py();
+    let is_runtime_protocol = |class: &Bound<'_, PyAny>| -> PyResult<bool> {
+        Ok(class.getattr(intern!(py, "_is_protocol"))?.is_truthy()?
+            && class.getattr(intern!(py, "_is_runtime_protocol"))?.is_truthy()?)
+    };
+    if !class.is_instance_of::<PyType>() || !is_runtime_protocol(class).unwrap_or(false) {
+        return Ok(None);
+    }
+    // metaclasses which override `__instancecheck__` can't be checked in rust
+    let typing = py.import(intern!(py, "typing"))?;
+    let instance_check = intern!(py, "__instancecheck__");
+    let protocol_meta = typing.getattr(intern!(py, "Protocol"))?.get_type();
+    if !class
+        .get_type()
+        .getattr(instance_check)?
+        .is(&protocol_meta.getattr(instance_check)?)
+    {
+        return Ok(None);
+    }
+
+    // `__protocol_attrs__` is set from python 3.12, earlier versions find members on each check
+    let attrs = match class.getattr(intern!(py, "__protocol_attrs__")) {
+        Ok(attrs) => attrs,
+        Err(_) => typing.getattr(intern!(py, "_get_protocol_attrs"))?.call1((class,))?,
+    };
+    let mut members = attrs
+        .try_iter()?
+        .map(|attr| {
+            let name = attr?.downcast_into::<PyString>()?;
+            let callable = class.getattr(&name).is_ok_and(|value| value.is_callable());
+            Ok(ProtocolMember {
+                name: name.unbind(),
+                callable,
+            })
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    // attrs is a set, sort so the order members are checked in is deterministic
+    members.sort_by_cached_key(|member| member.name.to_string());
+    Ok(Some(members))
+}
+
+/// Whether `obj` has all of the protocol's members, if not `isinstance` is used so classes registered
+/// with the protocol and those accepted by a `__subclasshook__` are still instances
+fn is_protocol_instance(
+    obj: &Bound<'_, PyAny>,
+    class: &Bound<'_, PyAny>,
+    members: &[ProtocolMember],
+) -> PyResult<bool> {
+    let mro = obj.get_type().mro();
+    // explicit subclasses of the protocol are always instances
+    if mro.iter().any(|base| base.is(class)) {
+        return Ok(true);
+    }
+    let py = obj.py();
+    for member in members {
+        match getattr_static(obj, &mro, member.name.bind(py))? {
+            Some(value) if member.callable && value.is(&py.None()) => return obj.is_instance(class),
+            Some(_) => (),
+            None => return obj.is_instance(class),
+        }
+    }
+    Ok(true)
+}
+
+/// Find `name` on `obj` as `inspect.getattr_static()` does, without calling descriptors or `__getattr__`,
+/// first in the instance's `__dict__` then in the `__dict__` of each class in `mro`
+fn getattr_static<'py>(
+    obj: &Bound<'py, PyAny>,
+    mro: &Bound<'py, PyTuple>,
+    name: &Bound<'py, PyString>,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let py = obj.py();
+    let find_in_mro = |name: &Bound<'py, PyString>| -> PyResult<Option<Bound<'py, PyAny>>> {
+        for base in mro {
+            let base_dict = base.getattr(intern!(py, "__dict__"))?;
+            match base_dict.get_item(name) {
+                Ok(value) => return Ok(Some(value)),
+                Err(err) if err.is_instance_of::<PyKeyError>(py) => (),
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(None)
+    };
+    // `__dict__` is itself a descriptor on the class, objects with `__slots__` may not have one
+    let dunder_dict = intern!(py, "__dict__");
+    if let Some(dict_descriptor) = find_in_mro(dunder_dict)? {
+        let instance_dict = dict_descriptor.call_method1(intern!(py, "__get__"), (obj, obj.get_type()))?;
+        if let Ok(instance_dict) = instance_dict.downcast::<PyDict>() {
+            if let Some(value) = instance_dict.get_item(name)? {
+                return Ok(Some(value));
+            }
+        }
+    }
+    find_in_mro(name)
+}
@@ -1,6 +1,6 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyIterator};
 
 use jiter::{FloatMode, JsonValue, PythonParse};
 
@@ -99,6 +99,21 @@ pub fn validate_json_bytes<'a, 'py>(
     }
 }
 
+/// The data passed to `validate_json`, either the whole JSON document or an iterator of chunks of it
+pub enum JsonData<'a, 'py> {
+    Bytes(&'a [u8]),
+    Chunks(&'a Bound<'py, PyIterator>),
+}
+
+/// The chunks of an iterable passed to `validate_json`, e.g. a list or a generator, mappings aren't taken as chunks
+pub fn json_chunks<'py>(input: &Bound<'py, PyAny>) -> Option<Bound<'py, PyIterator>> {
+    if input.is_instance_of::<PyDict>() {
+        None
+    } else {
+        input.try_iter().ok()
+    }
+}
+
 fn map_bytes_error(line_error: ValLineError) -> ValLineError {
     match line_error.error_type {
         ErrorType::BytesType { .. } => {
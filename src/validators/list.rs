@@ -1,15 +1,21 @@
 use std::sync::OnceLock;
 
-use pyo3::types::PyDict;
+use pyo3::exceptions::PyTypeError;
+use pyo3::types::{PyDict, PyList};
 use pyo3::{prelude::*, IntoPyObjectExt};
 
-use crate::errors::ValResult;
+use crate::build_tools::schema_or_config;
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::{
-    no_validator_iter_to_vec, validate_iter_to_vec, BorrowInput, ConsumeIterator, Input, MaxLengthCheck, ValidatedList,
+    array_like_as_list, no_validator_iter_to_vec, validate_iter_to_vec, BorrowInput, ConsumeIterator, Input,
+    MaxLengthCheck, ValidatedList,
 };
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::function::convert_err;
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
+};
 
 #[derive(Debug)]
 pub struct ListValidator {
@@ -19,6 +25,9 @@ pub struct ListValidator {
     max_length: Option<usize>,
     name: OnceLock<String>,
     fail_fast: bool,
+    strict_exact_type: bool,
+    allow_array_like: bool,
+    unique_items: Option<UniqueItems>,
 }
 
 pub fn get_items_schema(
@@ -38,6 +47,76 @@ pub fn get_items_schema(
     }
 }
 
+/// The `unique_items` constraint of lists and tuples, checked on the validated items
+#[derive(Debug)]
+pub(crate) struct UniqueItems {
+    /// called with each item to get the value compared, the item itself is compared if this is `None`
+    key: Option<Py<PyAny>>,
+}
+
+impl UniqueItems {
+    pub fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        if !schema.get_as(pyo3::intern!(py, "unique_items"))?.unwrap_or(false) {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            key: schema.get_as(pyo3::intern!(py, "unique_items_key"))?,
+        }))
+    }
+
+    /// Errors at the first item which is equal to an earlier one, unhashable values are compared with `==`,
+    /// errors raised by `key` are reported at the item's index as with function validators
+    pub fn check(&self, py: Python<'_>, items: &[PyObject]) -> ValResult<()> {
+        let first_indexes = PyDict::new(py);
+        let mut unhashable: Vec<(Bound<'_, PyAny>, usize)> = Vec::new();
+        for (index, item) in items.iter().enumerate() {
+            let item = item.bind(py);
+            let value = match self.key {
+                Some(ref key) => key
+                    .bind(py)
+                    .call1((item,))
+                    .map_err(|err| convert_err(py, err, item).with_outer_location(index))?,
+                None => item.clone(),
+            };
+            let first_index = match value.hash() {
+                Ok(_) => match first_indexes.get_item(&value)? {
+                    Some(first_index) => Some(first_index.extract()?),
+                    None => {
+                        first_indexes.set_item(&value, index)?;
+                        None
+                    }
+                },
+                Err(err) if err.is_instance_of::<PyTypeError>(py) => {
+                    let mut first_index = None;
+                    for (other, other_index) in &unhashable {
+                        if other.eq(&value)? {
+                            first_index = Some(*other_index);
+                            break;
+                        }
+                    }
+                    if first_index.is_none() {
+                        unhashable.push((value, index));
+                    }
+                    first_index
+                }
+                Err(err) => return Err(err.into()),
+            };
+            if let Some(first_index) = first_index {
+                return Err(ValError::new_with_loc(
+                    ErrorType::DuplicateItem {
+                        first_index,
+                        context: None,
+                    },
+                    item,
+                    index,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
 macro_rules! length_check {
     ($input:ident, $field_type:literal, $min_length:expr, $max_length:expr, $obj:ident) => {{
         let mut op_actual_length: Option<usize> = None;
@@ -111,12 +190,32 @@ impl BuildValidator for ListValidator {
             max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
             name: OnceLock::new(),
             fail_fast: schema.get_as(pyo3::intern!(py, "fail_fast"))?.unwrap_or(false),
+            strict_exact_type: schema_or_config(
+                schema,
+                config,
+                pyo3::intern!(py, "strict_exact_type"),
+                pyo3::intern!(py, "list_strict_exact_type"),
+            )?
+            .unwrap_or(false),
+            allow_array_like: schema_or_config(
+                schema,
+                config,
+                pyo3::intern!(py, "allow_array_like"),
+                pyo3::intern!(py, "list_allow_array_like"),
+            )?
+            .unwrap_or(false),
+            unique_items: UniqueItems::from_schema(schema)?,
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(ListValidator { item_validator });
+impl_py_gc_traverse!(UniqueItems { key });
+
+impl_py_gc_traverse!(ListValidator {
+    item_validator,
+    unique_items
+});
 
 impl Validator for ListValidator {
     fn validate<'py>(
@@ -125,7 +224,24 @@ impl Validator for ListValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let seq = input.validate_list(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        // in strict mode list subclasses are usually accepted, `strict_exact_type` rejects them too
+        if self.strict_exact_type
+            && strict
+            && input
+                .as_python()
+                .is_some_and(|py_input| !py_input.is_exact_instance_of::<PyList>())
+        {
+            return Err(ValError::new(ErrorTypeDefaults::ListType, input));
+        }
+        // arrays are converted to a list in one go, then validated like any other list
+        if self.allow_array_like {
+            if let Some(py_list) = input.as_python().map(array_like_as_list).transpose()?.flatten() {
+                state.floor_exactness(Exactness::Lax);
+                return self.validate(py, py_list.as_any(), state);
+            }
+        }
+        let seq = input.validate_list(strict)?.unpack_coerced(state, input, "list");
 
         let actual_length = seq.len();
         let output = match self.item_validator {
@@ -140,7 +256,7 @@ impl Validator for ListValidator {
                 fail_fast: self.fail_fast,
             })??,
             None => {
-                if let Some(py_list) = seq.as_py_list() {
+                if let Some(py_list) = seq.as_py_list().filter(|_| self.unique_items.is_none()) {
                     length_check!(input, "List", self.min_length, self.max_length, py_list);
                     let list_copy = py_list.get_slice(0, usize::MAX);
                     return Ok(list_copy.into());
@@ -156,6 +272,9 @@ impl Validator for ListValidator {
             }
         };
         min_length_check!(input, "List", self.min_length, output);
+        if let Some(ref unique_items) = self.unique_items {
+            unique_items.check(py, &output)?;
+        }
         Ok(output.into_py_any(py)?)
     }
 
@@ -4,17 +4,19 @@ use core::fmt::Debug;
 use std::cell::OnceCell;
 
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyInt, PyList};
+use pyo3::types::{PyBytes, PyDict, PyInt, PyList};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
 use ahash::AHashMap;
+use num_bigint::BigInt;
 
 use crate::build_tools::{py_schema_err, py_schema_error_type};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{Input, ValidationMatch};
+use crate::input::{EitherInt, Input, Int, ValidationMatch};
 use crate::py_gc::PyGcTraverse;
 use crate::tools::SchemaDict;
 
+use super::config::ValBytesMode;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone, Default)]
@@ -23,6 +25,43 @@ struct BoolLiteral {
     pub false_id: Option<usize>,
 }
 
+/// Lookup of the expected ints, values forming a contiguous range (e.g. the members of most int enums) are
+/// looked up by their offset from the start of the range instead of hashing
+#[derive(Debug, Clone)]
+enum IntLookup {
+    Range { start: i64, ids: Vec<usize> },
+    Map(AHashMap<i64, usize>),
+}
+
+impl IntLookup {
+    fn new(expected: AHashMap<i64, usize>) -> Self {
+        let (Some(&start), Some(&end)) = (expected.keys().min(), expected.keys().max()) else {
+            return Self::Map(expected);
+        };
+        // keys are unique, so a range with as many values as there are keys contains every key
+        match end.checked_sub(start).and_then(|span| usize::try_from(span).ok()) {
+            Some(span) if span < expected.len() => {
+                let mut ids = vec![0; expected.len()];
+                for (int, id) in expected {
+                    ids[(int - start) as usize] = id;
+                }
+                Self::Range { start, ids }
+            }
+            _ => Self::Map(expected),
+        }
+    }
+
+    fn get(&self, int: i64) -> Option<usize> {
+        match self {
+            Self::Range { start, ids } => {
+                let offset = usize::try_from(int.checked_sub(*start)?).ok()?;
+                ids.get(offset).copied()
+            }
+            Self::Map(map) => map.get(&int).copied(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct LiteralLookup<T: Debug> {
     // Specialized lookups for ints, bools and strings because they
@@ -30,9 +69,14 @@ pub struct LiteralLookup<T: Debug> {
     // (2) hashing them in Rust is very fast
     // (3) are the most commonly used things in Literal[...]
     expected_bool: Option<BoolLiteral>,
-    expected_int: Option<AHashMap<i64, usize>>,
+    expected_int: Option<IntLookup>,
+    // ints which don't fit in an i64
+    expected_big_int: Option<AHashMap<BigInt, usize>>,
     expected_str: Option<AHashMap<String, usize>>,
-    // Catch all for hashable types like Enum and bytes (the latter only because it is seldom used)
+    // bytes are looked up in rust so strings from JSON can be decoded according to `bytes_mode`
+    expected_bytes: Option<AHashMap<Vec<u8>, usize>>,
+    bytes_mode: ValBytesMode,
+    // Catch all for hashable types like Enum
     expected_py_dict: Option<Py<PyDict>>,
     // Catch all for unhashable types like list
     expected_py_values: Option<Vec<(Py<PyAny>, usize)>>,
@@ -48,7 +92,9 @@ impl<T: Debug> LiteralLookup<T> {
     pub fn new<'py>(py: Python<'py>, expected: impl Iterator<Item = (Bound<'py, PyAny>, T)>) -> PyResult<Self> {
         let mut expected_bool = BoolLiteral::default();
         let mut expected_int = AHashMap::new();
+        let mut expected_big_int = AHashMap::new();
         let mut expected_str: AHashMap<String, usize> = AHashMap::new();
+        let mut expected_bytes = AHashMap::new();
         let expected_py_dict = PyDict::new(py);
         let mut expected_py_values = Vec::new();
         let expected_py_primitives = PyDict::new(py);
@@ -71,7 +117,8 @@ impl<T: Debug> LiteralLookup<T> {
                     expected_py_primitives.set_item(&k, id)?;
                 } else {
                     // cover the case of an int that's > i64::MAX etc.
-                    expected_py_dict.set_item(k, id)?;
+                    expected_big_int.insert(k.extract::<BigInt>()?, id);
+                    expected_py_primitives.set_item(&k, id)?;
                 }
             } else if let Ok(either_str) = k.exact_str() {
                 let str = either_str
@@ -79,6 +126,8 @@ impl<T: Debug> LiteralLookup<T> {
                     .map_err(|_| py_schema_error_type!("error extracting str {:?}", k))?;
                 expected_str.insert(str.to_string(), id);
                 expected_py_primitives.set_item(&k, id)?;
+            } else if let Ok(py_bytes) = k.downcast_exact::<PyBytes>() {
+                expected_bytes.insert(py_bytes.as_bytes().to_vec(), id);
             } else if expected_py_dict.set_item(&k, id).is_err() {
                 expected_py_values.push((k.as_unbound().clone_ref(py), id));
             }
@@ -87,8 +136,11 @@ impl<T: Debug> LiteralLookup<T> {
         Ok(Self {
             expected_bool: (expected_bool.true_id.is_some() || expected_bool.false_id.is_some())
                 .then_some(expected_bool),
-            expected_int: (!expected_int.is_empty()).then_some(expected_int),
+            expected_int: (!expected_int.is_empty()).then(|| IntLookup::new(expected_int)),
+            expected_big_int: (!expected_big_int.is_empty()).then_some(expected_big_int),
             expected_str: (!expected_str.is_empty()).then_some(expected_str),
+            expected_bytes: (!expected_bytes.is_empty()).then_some(expected_bytes),
+            bytes_mode: ValBytesMode::default(),
             expected_py_dict: (!expected_py_dict.is_empty()).then_some(expected_py_dict.into()),
             expected_py_values: (!expected_py_values.is_empty()).then_some(expected_py_values),
             expected_py_primitives: (!expected_py_primitives.is_empty()).then_some(expected_py_primitives.into()),
@@ -96,6 +148,19 @@ impl<T: Debug> LiteralLookup<T> {
         })
     }
 
+    /// How strings from JSON are decoded before looking them up in the expected bytes
+    pub fn with_bytes_mode(mut self, bytes_mode: ValBytesMode) -> Self {
+        self.bytes_mode = bytes_mode;
+        self
+    }
+
+    fn get_int(&self, either_int: &EitherInt<'_>) -> ValResult<Option<usize>> {
+        match either_int.as_int()? {
+            Int::I64(int) => Ok(self.expected_int.as_ref().and_then(|ints| ints.get(int))),
+            Int::Big(int) => Ok(self.expected_big_int.as_ref().and_then(|ints| ints.get(&int).copied())),
+        }
+    }
+
     pub fn validate<'a, 'py, I: Input<'py> + ?Sized>(
         &self,
         py: Python<'py>,
@@ -112,11 +177,10 @@ impl<T: Debug> LiteralLookup<T> {
                 }
             }
         }
-        if let Some(expected_ints) = &self.expected_int {
+        if self.expected_int.is_some() || self.expected_big_int.is_some() {
             if let Ok(either_int) = input.exact_int() {
-                let int = either_int.into_i64(py)?;
-                if let Some(id) = expected_ints.get(&int) {
-                    return Ok(Some((input, &self.values[*id])));
+                if let Some(id) = self.get_int(&either_int)? {
+                    return Ok(Some((input, &self.values[id])));
                 }
             }
         }
@@ -139,6 +203,14 @@ impl<T: Debug> LiteralLookup<T> {
                 }
             }
         }
+        if let Some(expected_bytes) = &self.expected_bytes {
+            // strict, so python `str`s aren't encoded, strings from JSON are decoded using `bytes_mode`
+            if let Ok(either_bytes) = input.validate_bytes(true, self.bytes_mode) {
+                if let Some(id) = expected_bytes.get(either_bytes.into_inner().as_slice()) {
+                    return Ok(Some((input, &self.values[*id])));
+                }
+            }
+        }
         // cache py_input if needed, since we might need it for multiple lookups
         let py_input = OnceCell::new();
         let get_py_input = || match py_input.get() {
@@ -184,17 +256,11 @@ impl<T: Debug> LiteralLookup<T> {
     }
 
     /// Used by int enums
-    pub fn validate_int<'a, 'py, I: Input<'py> + ?Sized>(
-        &self,
-        py: Python<'py>,
-        input: &'a I,
-        strict: bool,
-    ) -> ValResult<Option<&T>> {
-        if let Some(expected_ints) = &self.expected_int {
+    pub fn validate_int<'a, 'py, I: Input<'py> + ?Sized>(&self, input: &'a I, strict: bool) -> ValResult<Option<&T>> {
+        if self.expected_int.is_some() || self.expected_big_int.is_some() {
             if let Ok(either_int) = input.validate_int(strict) {
-                let int = either_int.into_inner().into_i64(py)?;
-                if let Some(id) = expected_ints.get(&int) {
-                    return Ok(Some(&self.values[*id]));
+                if let Some(id) = self.get_int(&either_int.into_inner())? {
+                    return Ok(Some(&self.values[id]));
                 }
             }
         }
@@ -254,7 +320,7 @@ impl BuildValidator for LiteralValidator {
 
     fn build(
         schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let expected: Bound<PyList> = schema.get_as_req(intern!(schema.py(), "expected"))?;
@@ -267,7 +333,8 @@ impl BuildValidator for LiteralValidator {
             repr_args.push(item.repr()?.extract()?);
         }
         let (expected_repr, name) = expected_repr_name(repr_args, "literal");
-        let lookup = LiteralLookup::new(py, expected.into_iter().map(|v| (v.clone(), v.into())))?;
+        let lookup = LiteralLookup::new(py, expected.into_iter().map(|v| (v.clone(), v.into())))?
+            .with_bytes_mode(ValBytesMode::from_config(config)?);
         Ok(CombinedValidator::Literal(Self {
             lookup,
             expected_repr,
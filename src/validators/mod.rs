@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::sync::OnceLock;
 
 use enum_dispatch::enum_dispatch;
 use jiter::{PartialMode, StringCacheMode};
@@ -6,17 +7,19 @@ use jiter::{PartialMode, StringCacheMode};
 use pyo3::exceptions::PyTypeError;
 use pyo3::ffi::c_str;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyAny, PyDict, PyString, PyTuple, PyType};
+use pyo3::types::{PyAny, PyDict, PyList, PyString, PyTuple, PyType};
 use pyo3::{intern, PyTraverseError, PyVisit};
 use pyo3::{prelude::*, IntoPyObjectExt};
 
-use crate::build_tools::{py_schema_err, py_schema_error_type, SchemaError};
+use crate::arrow::{ArrowArray, ArrowType};
+use crate::build_tools::{normalize_schema, py_schema_err, py_schema_error_type, schema_hash, SchemaError};
 use crate::definitions::{Definitions, DefinitionsBuilder};
 use crate::errors::{LocItem, ValError, ValResult, ValidationError};
-use crate::input::{Input, InputType, StringMapping};
+use crate::input::{Input, InputLimits, InputType, JsonChunks, JsonLimitsError, LimitsState, StringMapping};
+use crate::merge_patch::{merge_patch, restore_fields_set};
 use crate::py_gc::PyGcTraverse;
 use crate::recursion_guard::RecursionState;
-use crate::tools::SchemaDict;
+use crate::tools::{py_err, SchemaDict};
 pub(crate) use config::ValBytesMode;
 
 mod any;
@@ -27,8 +30,10 @@ mod bytes;
 mod call;
 mod callable;
 mod chain;
+pub(crate) mod collections;
 pub(crate) mod complex;
 mod config;
+mod context_switch;
 mod custom_error;
 mod dataclass;
 mod date;
@@ -36,24 +41,31 @@ mod datetime;
 pub(crate) mod decimal;
 mod definitions;
 mod dict;
+mod email;
 mod enum_;
 mod float;
+pub(crate) mod fraction;
 mod frozenset;
 mod function;
 mod generator;
 mod int;
+mod ip;
 mod is_instance;
 mod is_subclass;
-mod json;
+pub(crate) mod json;
 mod json_or_python;
 mod lax_or_strict;
 mod list;
 mod literal;
 mod model;
 mod model_fields;
+mod namedtuple;
 mod none;
 mod nullable;
+mod path;
 mod prebuilt;
+mod protocol_custom;
+mod secret;
 mod set;
 mod string;
 mod time;
@@ -66,9 +78,17 @@ mod uuid;
 mod validation_state;
 mod with_default;
 
-pub use self::validation_state::{Exactness, ValidationState};
+pub use self::validation_state::{
+    AliasHitLog, CoercionLog, Exactness, FieldPath, StrictOverrides, SuppressedErrorLog, TakenReports, ValidationMemo,
+    ValidationState, ValidationTrace,
+};
+pub(crate) use context_switch::get_context_value;
+pub use model::ModelFieldGetter;
+pub(crate) use model::{create_class, force_setattr};
 pub use with_default::DefaultType;
 
+use self::json::{json_chunks, JsonData};
+
 #[pyclass(module = "pydantic_core._pydantic_core", name = "Some")]
 pub struct PySome {
     #[pyo3(get)]
@@ -104,6 +124,83 @@ impl PySome {
     }
 }
 
+/// The report returned alongside the validated value by `SchemaValidator.validate_python` and `validate_json`
+/// when `trace`, `coercions`, `suppressed_errors` or `alias_hits` is set, holding each report which was requested
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+pub struct ValidationReport {
+    #[pyo3(get)]
+    trace: Option<Py<PyList>>,
+    #[pyo3(get)]
+    coercions: Option<Py<PyList>>,
+    #[pyo3(get)]
+    suppressed_errors: Option<Py<PyList>>,
+    #[pyo3(get)]
+    alias_hits: Option<Py<PyList>>,
+}
+
+#[pymethods]
+impl ValidationReport {
+    pub fn __repr__(&self, py: Python) -> PyResult<String> {
+        let report_repr = |report: &Option<Py<PyList>>| match report {
+            Some(report) => Ok(report.bind(py).repr()?.to_string()),
+            None => Ok::<_, PyErr>("None".to_string()),
+        };
+        Ok(format!(
+            "ValidationReport(trace={}, coercions={}, suppressed_errors={}, alias_hits={})",
+            report_repr(&self.trace)?,
+            report_repr(&self.coercions)?,
+            report_repr(&self.suppressed_errors)?,
+            report_repr(&self.alias_hits)?,
+        ))
+    }
+}
+
+/// The outcome of `SchemaValidator.try_validate_python`, holding either the validated value or the
+/// validation error, which is only rendered when `errors` is accessed.
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+pub struct ValidationResult {
+    result: Result<PyObject, Py<ValidationError>>,
+}
+
+#[pymethods]
+impl ValidationResult {
+    #[getter]
+    pub fn ok(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    #[getter]
+    pub fn value(&self, py: Python) -> PyObject {
+        match self.result {
+            Ok(ref value) => value.clone_ref(py),
+            Err(_) => py.None(),
+        }
+    }
+
+    #[getter]
+    pub fn error(&self, py: Python) -> Option<Py<ValidationError>> {
+        self.result.as_ref().err().map(|error| error.clone_ref(py))
+    }
+
+    #[getter]
+    pub fn errors(&self, py: Python) -> PyResult<Py<PyList>> {
+        match self.result {
+            Ok(_) => Ok(PyList::empty(py).unbind()),
+            Err(ref error) => error.borrow(py).errors(py, true, true, true, None),
+        }
+    }
+
+    pub fn __repr__(&self, py: Python) -> PyResult<String> {
+        match self.result {
+            Ok(ref value) => Ok(format!("ValidationResult(ok=True, value={})", value.bind(py).repr()?)),
+            Err(ref error) => Ok(format!(
+                "ValidationResult(ok=False, error_count={})",
+                error.borrow(py).error_count()
+            )),
+        }
+    }
+}
+
 #[pyclass(module = "pydantic_core._pydantic_core", frozen)]
 #[derive(Debug)]
 pub struct SchemaValidator {
@@ -118,6 +215,9 @@ pub struct SchemaValidator {
     hide_input_in_errors: bool,
     validation_error_cause: bool,
     cache_str: StringCacheMode,
+    input_limits: Option<InputLimits>,
+    // computed the first time it's needed, hashing a large schema isn't free
+    schema_hash: OnceLock<u64>,
 }
 
 #[pymethods]
@@ -147,6 +247,7 @@ impl SchemaValidator {
         let cache_str: StringCacheMode = config
             .get_as(intern!(py, "cache_strings"))?
             .unwrap_or(StringCacheMode::All);
+        let input_limits = InputLimits::from_config(config)?;
         Ok(Self {
             validator,
             definitions,
@@ -156,37 +257,229 @@ impl SchemaValidator {
             hide_input_in_errors,
             validation_error_cause,
             cache_str,
+            input_limits,
+            schema_hash: OnceLock::new(),
         })
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None))]
+    #[pyo3(signature = (input, *, strict=None, strict_overrides=None, from_attributes=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None, trace=false, memoize=false, coercions=false, suppressed_errors=false, alias_hits=false))]
     pub fn validate_python(
         &self,
         py: Python,
         input: &Bound<'_, PyAny>,
         strict: Option<bool>,
+        strict_overrides: Option<&Bound<'_, PyDict>>,
         from_attributes: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        trace: bool,
+        memoize: bool,
+        coercions: bool,
+        suppressed_errors: bool,
+        alias_hits: bool,
     ) -> PyResult<PyObject> {
+        let strict_overrides = strict_overrides.map(StrictOverrides::new).transpose()?;
+        let mut validation_trace = trace.then(ValidationTrace::default);
+        let mut memo = memoize.then(ValidationMemo::default);
+        let mut coercion_log = coercions.then(CoercionLog::default);
+        let mut suppressed_error_log = suppressed_errors.then(SuppressedErrorLog::default);
+        let mut alias_hit_log = alias_hits.then(AliasHitLog::default);
         #[allow(clippy::used_underscore_items)]
-        self._validate(
+        let result = self._validate(
             py,
             input,
             InputType::Python,
             strict,
+            strict_overrides.as_ref(),
             from_attributes,
             context,
             self_instance,
             allow_partial,
             by_alias,
             by_name,
-        )
-        .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
+            validation_trace.as_mut(),
+            memo.as_mut(),
+            coercion_log.as_mut(),
+            suppressed_error_log.as_mut(),
+            alias_hit_log.as_mut(),
+        );
+        let output = match result {
+            Ok(output) => output,
+            Err(e) => {
+                let err = self.prepare_validation_err(py, e, InputType::Python);
+                // keep the steps leading up to the failure, they're most useful when validation fails
+                if let (Some(trace), Ok(validation_error)) =
+                    (validation_trace, err.value(py).downcast::<ValidationError>())
+                {
+                    validation_error
+                        .borrow_mut()
+                        .set_trace(trace.into_py_list(py)?.unbind());
+                }
+                return Err(err);
+            }
+        };
+        let report = Report {
+            trace: validation_trace,
+            coercions: coercion_log,
+            suppressed_errors: suppressed_error_log,
+            alias_hits: alias_hit_log,
+        };
+        report.with_output(py, output, InputType::Python)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None))]
+    pub fn try_validate_python(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+        self_instance: Option<&Bound<'_, PyAny>>,
+        allow_partial: PartialMode,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<ValidationResult> {
+        #[allow(clippy::used_underscore_items)]
+        match self._validate(
+            py,
+            input,
+            InputType::Python,
+            strict,
+            None,
+            from_attributes,
+            context,
+            self_instance,
+            allow_partial,
+            by_alias,
+            by_name,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ) {
+            Ok(value) => Ok(ValidationResult { result: Ok(value) }),
+            Err(ValError::LineErrors(line_errors)) => {
+                let error = ValidationError::from_line_errors(
+                    py,
+                    self.title.clone_ref(py),
+                    InputType::Python,
+                    line_errors,
+                    None,
+                    self.hide_input_in_errors,
+                    self.validation_error_cause,
+                )?;
+                self.set_error_origin(py, &error)?;
+                Ok(ValidationResult {
+                    result: Err(error.unbind()),
+                })
+            }
+            Err(e) => Err(self.prepare_validation_err(py, e, InputType::Python)),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, by_alias=None, by_name=None))]
+    pub fn validate_python_arrow(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<ArrowArray> {
+        let (arrow_type, nullable) = self.arrow_type(py)?;
+        #[allow(clippy::used_underscore_items)]
+        let output = self
+            ._validate(
+                py,
+                input,
+                InputType::Python,
+                strict,
+                None,
+                from_attributes,
+                context,
+                None,
+                false.into(),
+                by_alias,
+                by_name,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))?;
+        ArrowArray::new(arrow_type, nullable, output.bind(py).downcast()?)
+    }
+
+    #[pyo3(signature = (input, *, strict=None, context=None, by_alias=None, by_name=None))]
+    pub fn validate_json_arrow(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<ArrowArray> {
+        let (arrow_type, nullable) = self.arrow_type(py)?;
+        let output = self.validate_json(
+            py,
+            input,
+            strict,
+            None,
+            context,
+            None,
+            PartialMode::Off,
+            by_alias,
+            by_name,
+            false,
+            false,
+            false,
+        )?;
+        ArrowArray::new(arrow_type, nullable, output.bind(py).downcast()?)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, by_alias=None, by_name=None))]
+    pub fn matches(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<Option<String>> {
+        let mut recursion_guard = RecursionState::default();
+        let mut state = ValidationState::new(
+            Extra::new(
+                strict,
+                None,
+                from_attributes,
+                context,
+                None,
+                InputType::Python,
+                self.cache_str,
+                by_alias,
+                by_name,
+            ),
+            &mut recursion_guard,
+            false.into(),
+        );
+        self.validator
+            .matching_choice(py, input, &mut state)
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -208,12 +501,18 @@ impl SchemaValidator {
             input,
             InputType::Python,
             strict,
+            None,
             from_attributes,
             context,
             self_instance,
             false.into(),
             by_alias,
             by_name,
+            None,
+            None,
+            None,
+            None,
+            None,
         ) {
             Ok(_) => Ok(true),
             Err(ValError::InternalErr(err)) => Err(err),
@@ -224,49 +523,89 @@ impl SchemaValidator {
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None))]
+    #[pyo3(signature = (input, *, strict=None, strict_overrides=None, context=None, self_instance=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None, coercions=false, suppressed_errors=false, alias_hits=false))]
     pub fn validate_json(
         &self,
         py: Python,
         input: &Bound<'_, PyAny>,
         strict: Option<bool>,
+        strict_overrides: Option<&Bound<'_, PyDict>>,
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        coercions: bool,
+        suppressed_errors: bool,
+        alias_hits: bool,
     ) -> PyResult<PyObject> {
+        let strict_overrides = strict_overrides.map(StrictOverrides::new).transpose()?;
+        let mut coercion_log = coercions.then(CoercionLog::default);
+        let mut suppressed_error_log = suppressed_errors.then(SuppressedErrorLog::default);
+        let mut alias_hit_log = alias_hits.then(AliasHitLog::default);
         let r = match json::validate_json_bytes(input) {
             #[allow(clippy::used_underscore_items)]
             Ok(v_match) => self._validate_json(
                 py,
                 input,
-                v_match.into_inner().as_slice(),
+                JsonData::Bytes(v_match.into_inner().as_slice()),
                 strict,
+                strict_overrides.as_ref(),
                 context,
                 self_instance,
                 allow_partial,
                 by_alias,
                 by_name,
+                coercion_log.as_mut(),
+                suppressed_error_log.as_mut(),
+                alias_hit_log.as_mut(),
             ),
-            Err(err) => Err(err),
+            // an iterable of chunks, e.g. a list or an HTTP body iterator, is parsed as the chunks are read
+            Err(err) => match json_chunks(input) {
+                #[allow(clippy::used_underscore_items)]
+                Some(chunks) => self._validate_json(
+                    py,
+                    input,
+                    JsonData::Chunks(&chunks),
+                    strict,
+                    strict_overrides.as_ref(),
+                    context,
+                    self_instance,
+                    allow_partial,
+                    by_alias,
+                    by_name,
+                    coercion_log.as_mut(),
+                    suppressed_error_log.as_mut(),
+                    alias_hit_log.as_mut(),
+                ),
+                None => Err(err),
+            },
+        };
+        let output = r.map_err(|e| self.prepare_validation_err(py, e, InputType::Json))?;
+        let report = Report {
+            trace: None,
+            coercions: coercion_log,
+            suppressed_errors: suppressed_error_log,
+            alias_hits: alias_hit_log,
         };
-        r.map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
+        report.with_output(py, output, InputType::Json)
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (input, *, strict=None, context=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None))]
+    #[pyo3(signature = (input, *, strict=None, strict_overrides=None, context=None, allow_partial=PartialMode::Off, by_alias=None, by_name=None))]
     pub fn validate_strings(
         &self,
         py: Python,
         input: Bound<'_, PyAny>,
         strict: Option<bool>,
+        strict_overrides: Option<&Bound<'_, PyDict>>,
         context: Option<&Bound<'_, PyAny>>,
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
     ) -> PyResult<PyObject> {
         let t = InputType::String;
+        let strict_overrides = strict_overrides.map(StrictOverrides::new).transpose()?;
         let string_mapping = StringMapping::new_value(input).map_err(|e| self.prepare_validation_err(py, e, t))?;
 
         #[allow(clippy::used_underscore_items)]
@@ -275,12 +614,18 @@ impl SchemaValidator {
             &string_mapping,
             t,
             strict,
+            strict_overrides.as_ref(),
             None,
             context,
             None,
             allow_partial,
             by_alias,
             by_name,
+            None,
+            None,
+            None,
+            None,
+            None,
         ) {
             Ok(r) => Ok(r),
             Err(e) => Err(self.prepare_validation_err(py, e, t)),
@@ -305,9 +650,10 @@ impl SchemaValidator {
             input_type: InputType::Python,
             data: None,
             strict,
+            strict_overrides: None,
             from_attributes,
             field_name: Some(PyString::new(py, field_name)),
-            context,
+            context: context.cloned(),
             self_instance: None,
             cache_str: self.cache_str,
             by_alias,
@@ -321,6 +667,113 @@ impl SchemaValidator {
             .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
     }
 
+    /// Validate several assignments to `obj` in one pass, `obj` is only updated if they're all valid,
+    /// and any errors are combined in one `ValidationError`
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (obj, assignments, *, strict=None, from_attributes=None, context=None, by_alias=None, by_name=None))]
+    pub fn validate_assignments(
+        &self,
+        py: Python,
+        obj: Bound<'_, PyAny>,
+        assignments: &Bound<'_, PyDict>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<PyObject> {
+        let extra = Extra {
+            input_type: InputType::Python,
+            data: None,
+            strict,
+            strict_overrides: None,
+            from_attributes,
+            field_name: None,
+            context: context.cloned(),
+            self_instance: None,
+            cache_str: self.cache_str,
+            by_alias,
+            by_name,
+        };
+
+        let guard = &mut RecursionState::default();
+        let mut state = ValidationState::new(extra, guard, false.into());
+        self.validator
+            .validate_assignments(py, &obj, assignments, &mut state)
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
+    }
+
+    /// Apply a JSON merge patch (RFC 7386) to `instance` and validate the resulting state, `instance` itself
+    /// isn't modified
+    #[pyo3(signature = (instance, patch, *, strict=None, context=None))]
+    pub fn apply_merge_patch(
+        &self,
+        py: Python,
+        instance: &Bound<'_, PyAny>,
+        patch: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let patch = crate::from_json(py, patch, true, StringCacheMode::All, PartialMode::Off)?;
+        let merged = merge_patch(Some(instance), &patch)?;
+        // the state of models is keyed by field name, whatever their aliases
+        #[allow(clippy::used_underscore_items)]
+        let output = self
+            ._validate(
+                py,
+                &merged,
+                InputType::Python,
+                strict,
+                None,
+                None,
+                context,
+                None,
+                PartialMode::Off,
+                None,
+                Some(true),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))?;
+        restore_fields_set(instance, &patch, output.bind(py))?;
+        Ok(output)
+    }
+
+    /// Create a model instance from trusted data without validation, as `BaseModel.model_construct()` does
+    #[pyo3(signature = (data, *, fields_set=None, context=None, by_alias=None, by_name=None))]
+    pub fn construct_python(
+        &self,
+        py: Python,
+        data: &Bound<'_, PyAny>,
+        fields_set: Option<&Bound<'_, PyAny>>,
+        context: Option<&Bound<'_, PyAny>>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
+    ) -> PyResult<PyObject> {
+        let mut recursion_guard = RecursionState::default();
+        let mut state = ValidationState::new(
+            Extra::new(
+                None,
+                None,
+                None,
+                context,
+                None,
+                InputType::Python,
+                self.cache_str,
+                by_alias,
+                by_name,
+            ),
+            &mut recursion_guard,
+            false.into(),
+        );
+        self.validator
+            .construct(py, data, fields_set, &mut state)
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
+    }
+
     #[pyo3(signature = (*, strict=None, context=None))]
     pub fn get_default_value(
         &self,
@@ -332,9 +785,10 @@ impl SchemaValidator {
             input_type: InputType::Python,
             data: None,
             strict,
+            strict_overrides: None,
             from_attributes: None,
             field_name: None,
-            context,
+            context: context.cloned(),
             self_instance: None,
             cache_str: self.cache_str,
             by_alias: None,
@@ -352,6 +806,15 @@ impl SchemaValidator {
         }
     }
 
+    pub fn dump_schema<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        normalize_schema(self.py_schema.bind(py))
+    }
+
+    #[getter(schema_hash)]
+    pub fn py_schema_hash(&self, py: Python) -> PyResult<u64> {
+        self.schema_hash(py)
+    }
+
     pub fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyType>, Bound<'py, PyTuple>)> {
         let init_args = (&slf.get().py_schema, &slf.get().py_config).into_pyobject(slf.py())?;
         Ok((slf.get_type(), init_args))
@@ -382,6 +845,65 @@ impl SchemaValidator {
 }
 
 impl SchemaValidator {
+    /// Validates a Python object with every validation option left at its default, for internal
+    /// callers like `Url.__new__` which shouldn't track the `validate_python` signature
+    pub fn validate_python_default(&self, py: Python, input: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        #[allow(clippy::used_underscore_items)]
+        self._validate(
+            py,
+            input,
+            InputType::Python,
+            None,
+            None,
+            None,
+            None,
+            None,
+            PartialMode::Off,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
+    }
+
+    /// Validates JSON `str`, `bytes` or `bytearray` input with every validation option left at its default
+    pub fn validate_json_default(&self, py: Python, input: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let json_data = json::validate_json_bytes(input)
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Json))?
+            .into_inner();
+        #[allow(clippy::used_underscore_items)]
+        self._validate_json(
+            py,
+            input,
+            JsonData::Bytes(json_data.as_slice()),
+            None,
+            None,
+            None,
+            None,
+            PartialMode::Off,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
+    }
+
+    fn arrow_type(&self, py: Python) -> PyResult<(ArrowType, bool)> {
+        match ArrowType::from_schema(self.py_schema.bind(py))? {
+            Some(arrow_type) => Ok(arrow_type),
+            None => py_err!(
+                PyTypeError;
+                "Arrow output is only supported for list schemas of int, float, bool or str items"
+            ),
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn _validate<'py>(
         &self,
@@ -389,17 +911,25 @@ impl SchemaValidator {
         input: &(impl Input<'py> + ?Sized),
         input_type: InputType,
         strict: Option<bool>,
+        strict_overrides: Option<&StrictOverrides>,
         from_attributes: Option<bool>,
         context: Option<&Bound<'py, PyAny>>,
         self_instance: Option<&Bound<'py, PyAny>>,
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        trace: Option<&mut ValidationTrace>,
+        memo: Option<&mut ValidationMemo>,
+        coercions: Option<&mut CoercionLog>,
+        suppressed_errors: Option<&mut SuppressedErrorLog>,
+        alias_hits: Option<&mut AliasHitLog>,
     ) -> ValResult<PyObject> {
         let mut recursion_guard = RecursionState::default();
+        let mut limits = self.input_limits.map(LimitsState::new);
         let mut state = ValidationState::new(
             Extra::new(
                 strict,
+                strict_overrides,
                 from_attributes,
                 context,
                 self_instance,
@@ -410,8 +940,18 @@ impl SchemaValidator {
             ),
             &mut recursion_guard,
             allow_partial,
-        );
-        self.validator.validate(py, input, &mut state)
+        )
+        .with_trace(trace)
+        .with_memo(memo)
+        .with_coercions(coercions)
+        .with_suppressed_errors(suppressed_errors)
+        .with_alias_hits(alias_hits)
+        .with_limits(limits.as_mut());
+        let result = self.validator.validate(py, input, &mut state);
+        match limits.and_then(|limits| limits.exceeded_error(py)) {
+            Some(exceeded) => Err(exceeded),
+            None => result,
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -419,33 +959,55 @@ impl SchemaValidator {
         &self,
         py: Python,
         input: &Bound<'_, PyAny>,
-        json_data: &[u8],
+        json_data: JsonData<'_, '_>,
         strict: Option<bool>,
+        strict_overrides: Option<&StrictOverrides>,
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
         allow_partial: PartialMode,
         by_alias: Option<bool>,
         by_name: Option<bool>,
+        coercions: Option<&mut CoercionLog>,
+        suppressed_errors: Option<&mut SuppressedErrorLog>,
+        alias_hits: Option<&mut AliasHitLog>,
     ) -> ValResult<PyObject> {
-        let json_value = jiter::JsonValue::parse_with_config(json_data, true, allow_partial)
-            .map_err(|e| json::map_json_err(input, e, json_data))?;
+        let json_value = match (json_data, &self.input_limits) {
+            (JsonData::Bytes(json_data), Some(input_limits)) => {
+                match input_limits.parse_json(input, json_data, allow_partial) {
+                    Ok(json_value) => json_value,
+                    Err(JsonLimitsError::Json(e)) => return Err(json::map_json_err(input, e, json_data)),
+                    Err(JsonLimitsError::Limit(e)) => return Err(e),
+                }
+            }
+            (JsonData::Bytes(json_data), None) => jiter::JsonValue::parse_with_config(json_data, true, allow_partial)
+                .map_err(|e| json::map_json_err(input, e, json_data))?,
+            (JsonData::Chunks(chunks), input_limits) => {
+                JsonChunks::new(input, chunks).parse(&input_limits.unwrap_or_default(), allow_partial)?
+            }
+        };
         #[allow(clippy::used_underscore_items)]
         self._validate(
             py,
             &json_value,
             InputType::Json,
             strict,
+            strict_overrides,
             None,
             context,
             self_instance,
             allow_partial,
             by_alias,
             by_name,
+            None,
+            None,
+            coercions,
+            suppressed_errors,
+            alias_hits,
         )
     }
 
     fn prepare_validation_err(&self, py: Python, error: ValError, input_type: InputType) -> PyErr {
-        ValidationError::from_val_error(
+        let err = ValidationError::from_val_error(
             py,
             self.title.clone_ref(py),
             input_type,
@@ -453,7 +1015,70 @@ impl SchemaValidator {
             None,
             self.hide_input_in_errors,
             self.validation_error_cause,
-        )
+        );
+        if let Ok(validation_error) = err.value(py).downcast::<ValidationError>() {
+            if let Err(hash_err) = self.set_error_origin(py, validation_error) {
+                return hash_err;
+            }
+        }
+        err
+    }
+
+    fn schema_hash(&self, py: Python) -> PyResult<u64> {
+        if let Some(hash) = self.schema_hash.get() {
+            return Ok(*hash);
+        }
+        let hash = schema_hash(self.py_schema.bind(py), self.py_config.as_ref().map(|c| c.bind(py)))?;
+        Ok(*self.schema_hash.get_or_init(|| hash))
+    }
+
+    /// Records the title and hash of the schema on errors raised by this validator
+    fn set_error_origin(&self, py: Python, validation_error: &Bound<'_, ValidationError>) -> PyResult<()> {
+        let schema_hash = self.schema_hash(py)?;
+        validation_error
+            .borrow_mut()
+            .set_schema_origin(self.title.clone_ref(py), schema_hash);
+        Ok(())
+    }
+}
+
+/// The reports requested by the `trace`, `coercions`, `suppressed_errors` and `alias_hits` arguments
+struct Report {
+    trace: Option<ValidationTrace>,
+    coercions: Option<CoercionLog>,
+    suppressed_errors: Option<SuppressedErrorLog>,
+    alias_hits: Option<AliasHitLog>,
+}
+
+impl Report {
+    /// The output alone, or a `(value, report)` tuple if a report was requested
+    fn with_output(self, py: Python, output: PyObject, input_type: InputType) -> PyResult<PyObject> {
+        if self.trace.is_none()
+            && self.coercions.is_none()
+            && self.suppressed_errors.is_none()
+            && self.alias_hits.is_none()
+        {
+            return Ok(output);
+        }
+        let report = ValidationReport {
+            trace: self.trace.map(|t| t.into_py_list(py)).transpose()?.map(Bound::unbind),
+            coercions: self
+                .coercions
+                .map(|c| c.into_py_list(py))
+                .transpose()?
+                .map(Bound::unbind),
+            suppressed_errors: self
+                .suppressed_errors
+                .map(|e| e.into_py_list(py, input_type))
+                .transpose()?
+                .map(Bound::unbind),
+            alias_hits: self
+                .alias_hits
+                .map(|a| a.into_py_list(py))
+                .transpose()?
+                .map(Bound::unbind),
+        };
+        (output, report).into_py_any(py)
     }
 }
 
@@ -477,7 +1102,17 @@ impl<'py> SelfValidator<'py> {
         let py = schema.py();
         let mut recursion_guard = RecursionState::default();
         let mut state = ValidationState::new(
-            Extra::new(strict, None, None, None, InputType::Python, true.into(), None, None),
+            Extra::new(
+                strict,
+                None,
+                None,
+                None,
+                None,
+                InputType::Python,
+                true.into(),
+                None,
+                None,
+            ),
             &mut recursion_guard,
             false.into(),
         );
@@ -509,6 +1144,8 @@ impl<'py> SelfValidator<'py> {
             hide_input_in_errors: false,
             validation_error_cause: false,
             cache_str: true.into(),
+            input_limits: None,
+            schema_hash: OnceLock::new(),
         })
     }
 }
@@ -619,6 +1256,8 @@ fn build_validator_inner(
         float::FloatBuilder,
         // decimals
         decimal::DecimalValidator,
+        // fractions
+        fraction::FractionValidator,
         // tuples
         tuple::TupleValidator,
         // list/arrays
@@ -627,6 +1266,12 @@ fn build_validator_inner(
         set::SetValidator,
         // dicts/objects (recursive)
         dict::DictValidator,
+        // deques, counters and defaultdicts from the `collections` module
+        collections::DequeValidator,
+        collections::CounterValidator,
+        collections::DefaultDictValidator,
+        // namedtuples
+        namedtuple::NamedTupleValidator,
         // None/null
         none::NoneValidator,
         // functions - before, after, plain & wrap
@@ -658,6 +1303,7 @@ fn build_validator_inner(
         is_instance::IsInstanceValidator,
         is_subclass::IsSubclassValidator,
         callable::CallableValidator,
+        protocol_custom::ProtocolCustomValidator,
         // arguments
         arguments::ArgumentsValidator,
         arguments_v3::ArgumentsV3Validator,
@@ -669,6 +1315,8 @@ fn build_validator_inner(
         lax_or_strict::LaxOrStrictValidator,
         // json or python
         json_or_python::JsonOrPython,
+        // switch on a validation context value
+        context_switch::ContextSwitchValidator,
         // generator validators
         generator::GeneratorValidator,
         // custom error
@@ -678,8 +1326,19 @@ fn build_validator_inner(
         // url types
         url::UrlValidator,
         url::MultiHostUrlValidator,
+        // email
+        email::EmailValidator,
         // uuid types
         uuid::UuidValidator,
+        // path types
+        path::PathValidator,
+        // ip address, network and interface types
+        ip::IpAddressValidatorBuilder,
+        ip::IpNetworkValidatorBuilder,
+        ip::IpInterfaceValidatorBuilder,
+        // secrets
+        secret::SecretStrValidatorBuilder,
+        secret::SecretBytesValidatorBuilder,
         // recursive (self-referencing) models
         definitions::DefinitionRefValidator,
         definitions::DefinitionsValidatorBuilder,
@@ -697,10 +1356,12 @@ pub struct Extra<'a, 'py> {
     pub data: Option<Bound<'py, PyDict>>,
     /// whether we're in strict or lax mode
     pub strict: Option<bool>,
+    /// Strictness of individual fields within the value being validated, see `enter_field`
+    pub strict_overrides: Option<&'a StrictOverrides>,
     /// Validation time setting of `from_attributes`
     pub from_attributes: Option<bool>,
-    /// context used in validator functions
-    pub context: Option<&'a Bound<'py, PyAny>>,
+    /// context used in validator functions, models and dataclasses with a `context` layer entries over it
+    pub context: Option<Bound<'py, PyAny>>,
     /// The name of the field being validated, if applicable
     pub field_name: Option<Bound<'py, PyString>>,
     /// This is an instance of the model or dataclass being validated, when validation is performed from `__init__`
@@ -717,6 +1378,7 @@ impl<'a, 'py> Extra<'a, 'py> {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         strict: Option<bool>,
+        strict_overrides: Option<&'a StrictOverrides>,
         from_attributes: Option<bool>,
         context: Option<&'a Bound<'py, PyAny>>,
         self_instance: Option<&'a Bound<'py, PyAny>>,
@@ -729,9 +1391,10 @@ impl<'a, 'py> Extra<'a, 'py> {
             input_type,
             data: None,
             strict,
+            strict_overrides,
             from_attributes,
             field_name: None,
-            context,
+            context: context.cloned(),
             self_instance,
             cache_str,
             by_alias,
@@ -740,21 +1403,52 @@ impl<'a, 'py> Extra<'a, 'py> {
     }
 }
 
-impl Extra<'_, '_> {
+impl<'py> Extra<'_, 'py> {
     pub fn as_strict(&self) -> Self {
         Self {
             input_type: self.input_type,
             data: self.data.clone(),
             strict: Some(true),
+            strict_overrides: None,
             from_attributes: self.from_attributes,
             field_name: self.field_name.clone(),
-            context: self.context,
+            context: self.context.clone(),
             self_instance: self.self_instance,
             cache_str: self.cache_str,
             by_alias: self.by_alias,
             by_name: self.by_name,
         }
     }
+
+    /// Sets the field being validated, `strict_overrides` is narrowed to the field and its strictness applied
+    pub fn enter_field(&mut self, field_name: Bound<'py, PyString>, name: &str) {
+        self.field_name = Some(field_name);
+        if let Some(strict_overrides) = self.strict_overrides {
+            self.strict_overrides = strict_overrides.field(name);
+            if let Some(strict) = self.strict_overrides.and_then(StrictOverrides::strict) {
+                self.strict = Some(strict);
+            }
+        }
+    }
+
+    /// A copy of the context with `entries` added, used by models and dataclasses with a `context`, so the
+    /// entries are visible to their fields and discarded (along with any changes to them) once they're validated
+    pub fn layered_context(&self, entries: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyAny>> {
+        let layered = match self.context {
+            Some(ref context) if !PyAnyMethods::is_none(context) => match context.downcast::<PyDict>() {
+                Ok(context) => context.copy()?,
+                Err(_) => {
+                    return Err(PyTypeError::new_err(format!(
+                        "context must be a dict to add the entries of a schema's `context`, got {}",
+                        context.get_type().qualname()?
+                    )))
+                }
+            },
+            _ => PyDict::new(entries.py()),
+        };
+        layered.update(entries.as_mapping())?;
+        Ok(layered.into_any())
+    }
 }
 
 #[derive(Debug)]
@@ -786,6 +1480,8 @@ pub enum CombinedValidator {
     ConstrainedFloat(float::ConstrainedFloatValidator),
     // decimals
     Decimal(decimal::DecimalValidator),
+    // fractions
+    Fraction(fraction::FractionValidator),
     // lists
     List(list::ListValidator),
     // sets - unique lists
@@ -794,6 +1490,11 @@ pub enum CombinedValidator {
     Tuple(tuple::TupleValidator),
     // dicts/objects (recursive)
     Dict(dict::DictValidator),
+    // deques, counters and defaultdicts from the `collections` module
+    Deque(collections::DequeValidator),
+    Counter(collections::CounterValidator),
+    DefaultDict(collections::DefaultDictValidator),
+    NamedTuple(namedtuple::NamedTupleValidator),
     // None/null
     None(none::NoneValidator),
     // functions
@@ -829,6 +1530,7 @@ pub enum CombinedValidator {
     IsInstance(is_instance::IsInstanceValidator),
     IsSubclass(is_subclass::IsSubclassValidator),
     Callable(callable::CallableValidator),
+    ProtocolCustom(protocol_custom::ProtocolCustomValidator),
     // arguments
     Arguments(arguments::ArgumentsValidator),
     ArgumentsV3(arguments_v3::ArgumentsV3Validator),
@@ -847,17 +1549,50 @@ pub enum CombinedValidator {
     // url types
     Url(url::UrlValidator),
     MultiHostUrl(url::MultiHostUrlValidator),
+    Email(email::EmailValidator),
     // uuid types
     Uuid(uuid::UuidValidator),
+    Path(path::PathValidator),
+    Ip(ip::IpValidator),
+    Secret(secret::SecretValidator),
     // reference to definition, useful for recursive (self-referencing) models
     DefinitionRef(definitions::DefinitionRefValidator),
     // input dependent
     JsonOrPython(json_or_python::JsonOrPython),
+    // context dependent
+    ContextSwitch(context_switch::ContextSwitchValidator),
     Complex(complex::ComplexValidator),
     // uses a reference to an existing SchemaValidator to reduce memory usage
     Prebuilt(prebuilt::PrebuiltValidator),
 }
 
+impl CombinedValidator {
+    /// Validates via the `Validator` impl, recording the validator in the trace when validating with `trace=True`.
+    /// As an inherent method this takes precedence over `Validator::validate`, so every validator entered through
+    /// a `CombinedValidator` is recorded, not just the top level one.
+    pub fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        if let Some(trace) = state.trace.as_deref_mut() {
+            trace.validate(py, self.get_name())?;
+        }
+        let entered = match (state.limits.as_deref_mut(), input.as_limits_input()) {
+            (Some(limits), Some(limits_input)) => limits.enter(limits_input)?,
+            _ => false,
+        };
+        let result = Validator::validate(self, py, input, state);
+        if entered {
+            if let Some(limits) = state.limits.as_deref_mut() {
+                limits.exit();
+            }
+        }
+        result
+    }
+}
+
 /// This trait must be implemented by all validators, it allows various validators to be accessed consistently,
 /// validators defined in `build_validator` also need `EXPECTED_TYPE` as a const, but that can't be part of the trait
 #[enum_dispatch(CombinedValidator)]
@@ -894,6 +1629,41 @@ pub trait Validator: Send + Sync + Debug {
         Err(py_err.into())
     }
 
+    /// Validate several assignments to `obj` at once, used by `SchemaValidator.validate_assignments`
+    fn validate_assignments<'py>(
+        &self,
+        _py: Python<'py>,
+        _obj: &Bound<'py, PyAny>,
+        _assignments: &Bound<'py, PyDict>,
+        _state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let py_err = PyTypeError::new_err(format!("validate_assignments is not supported for {}", self.get_name()));
+        Err(py_err.into())
+    }
+
+    /// Find which choice of a union `input` matches, used by `SchemaValidator.matches`
+    fn matching_choice<'py>(
+        &self,
+        _py: Python<'py>,
+        _input: &(impl Input<'py> + ?Sized),
+        _state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Option<String>> {
+        let py_err = PyTypeError::new_err(format!("matches is not supported for {}", self.get_name()));
+        Err(py_err.into())
+    }
+
+    /// Create an instance from trusted data without validating it, used by `SchemaValidator.construct_python`
+    fn construct<'py>(
+        &self,
+        _py: Python<'py>,
+        _input: &Bound<'py, PyAny>,
+        _fields_set: Option<&Bound<'py, PyAny>>,
+        _state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let py_err = PyTypeError::new_err(format!("construct_python is not supported for {}", self.get_name()));
+        Err(py_err.into())
+    }
+
     /// `get_name` generally returns `Self::EXPECTED_TYPE` or some other clear identifier of the validator
     /// this is used in the error location in unions, and in the top level message in `ValidationError`
     fn get_name(&self) -> &str;
@@ -1,8 +1,13 @@
+use std::ffi::CStr;
 use std::ptr::null_mut;
+use std::sync::Arc;
 
-use pyo3::exceptions::PyTypeError;
-use pyo3::types::{PyDict, PySet, PyString, PyTuple, PyType};
-use pyo3::{ffi, BoundObject, IntoPyObjectExt};
+use ahash::AHashMap;
+
+use pyo3::exceptions::{PyAttributeError, PyTypeError};
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyList, PySet, PyString, PyTuple, PyType};
+use pyo3::{ffi, ffi::c_str, wrap_pyfunction, BoundObject, IntoPyObjectExt};
 use pyo3::{intern, prelude::*};
 
 use super::function::convert_err;
@@ -10,9 +15,9 @@ use super::validation_state::Exactness;
 use super::{
     build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Extra, ValidationState, Validator,
 };
-use crate::build_tools::py_schema_err;
 use crate::build_tools::schema_or_config_same;
-use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::build_tools::{nested_config, py_schema_err};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{input_as_python_instance, py_error_on_minusone, Input};
 use crate::tools::{py_err, SchemaDict};
 use crate::PydanticUndefinedType;
@@ -22,6 +27,7 @@ const DUNDER_DICT: &str = "__dict__";
 const DUNDER_FIELDS_SET_KEY: &str = "__pydantic_fields_set__";
 const DUNDER_MODEL_EXTRA_KEY: &str = "__pydantic_extra__";
 const DUNDER_MODEL_PRIVATE_KEY: &str = "__pydantic_private__";
+const MASKED_REPR: &str = "**********";
 
 #[derive(Debug, Clone)]
 pub(super) enum Revalidate {
@@ -57,6 +63,7 @@ pub struct ModelValidator {
     class: Py<PyType>,
     generic_origin: Option<Py<PyType>>,
     post_init: Option<Py<PyString>>,
+    context: Option<Py<PyDict>>,
     frozen: bool,
     custom_init: bool,
     root_model: bool,
@@ -69,12 +76,12 @@ impl BuildValidator for ModelValidator {
 
     fn build(
         schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        config: Option<&Bound<'_, PyDict>>,
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
-        // models ignore the parent config and always use the config from this model
-        let config = schema.get_as(intern!(py, "config"))?;
+        // models use the config from this model, combined with the parent config according to `config_merge`
+        let config = nested_config(schema, config)?;
 
         let class: Bound<'_, PyType> = schema.get_as_req(intern!(py, "cls"))?;
         let generic_origin: Option<Bound<'_, PyType>> = schema.get_as(intern!(py, "generic_origin"))?;
@@ -82,6 +89,38 @@ impl BuildValidator for ModelValidator {
         let validator = build_validator(&sub_schema, config.as_ref(), definitions)?;
         let name = class.getattr(intern!(py, "__name__"))?.extract()?;
 
+        if schema.get_as(intern!(py, "generate_repr"))?.unwrap_or(false) {
+            let field_reprs = Arc::new(FieldRepr::from_schema(sub_schema.downcast()?)?);
+            class.setattr(
+                intern!(py, "__repr__"),
+                ModelRepr {
+                    field_reprs: field_reprs.clone(),
+                    rich: false,
+                },
+            )?;
+            class.setattr(
+                intern!(py, "__rich_repr__"),
+                ModelRepr {
+                    field_reprs,
+                    rich: true,
+                },
+            )?;
+        }
+        if schema.get_as(intern!(py, "generate_eq"))?.unwrap_or(false) {
+            let state = ModelEq {
+                origin: generic_origin.as_ref().unwrap_or(&class).clone().unbind(),
+                field_names: field_names(sub_schema.downcast()?)?,
+            };
+            let (eq, hash) = model_eq_methods(&class, state)?;
+            class.setattr(intern!(py, "__eq__"), eq)?;
+            // as Python does for classes defining `__eq__`, only frozen models are hashable
+            if schema.get_as(intern!(py, "frozen"))?.unwrap_or(false) {
+                class.setattr(intern!(py, "__hash__"), hash)?;
+            } else {
+                class.setattr(intern!(py, "__hash__"), py.None())?;
+            }
+        }
+
         Ok(Self {
             revalidate: Revalidate::from_str(
                 schema_or_config_same::<Bound<'_, PyString>>(
@@ -97,6 +136,7 @@ impl BuildValidator for ModelValidator {
             class: class.into(),
             generic_origin: generic_origin.map(std::convert::Into::into),
             post_init: schema.get_as(intern!(py, "post_init"))?,
+            context: schema.get_as(intern!(py, "context"))?,
             frozen: schema.get_as(intern!(py, "frozen"))?.unwrap_or(false),
             custom_init: schema.get_as(intern!(py, "custom_init"))?.unwrap_or(false),
             root_model: schema.get_as(intern!(py, "root_model"))?.unwrap_or(false),
@@ -111,6 +151,7 @@ impl BuildValidator for ModelValidator {
 impl_py_gc_traverse!(ModelValidator {
     class,
     generic_origin,
+    context,
     validator
 });
 
@@ -121,6 +162,16 @@ impl Validator for ModelValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        // entries of `context` are visible to the model's fields and its post init
+        let mut layered_state;
+        let state = match self.context {
+            Some(ref entries) => {
+                let context = state.extra().layered_context(entries.bind(py))?;
+                layered_state = state.rebind_extra(|extra| extra.context = Some(context));
+                &mut *layered_state
+            }
+            None => state,
+        };
         if let Some(self_instance) = state.extra().self_instance {
             // in the case that self_instance is Some, we're calling validation from within `BaseModel.__init__`
             return self.validate_init(py, self_instance, input, state);
@@ -175,9 +226,11 @@ impl Validator for ModelValidator {
                 Ok(input.to_object(py)?.unbind())
             }
         } else {
-            // Having to construct a new model is not an exact match
-            state.floor_exactness(Exactness::Strict);
-            self.validate_construct(py, input, None, state)
+            state.memoize(py, self, self.frozen, input, |state| {
+                // Having to construct a new model is not an exact match
+                state.floor_exactness(Exactness::Strict);
+                self.validate_construct(py, input, None, state)
+            })
         }
     }
 
@@ -191,7 +244,17 @@ impl Validator for ModelValidator {
     ) -> ValResult<PyObject> {
         if self.frozen {
             return Err(ValError::new(ErrorTypeDefaults::FrozenInstance, field_value));
-        } else if self.root_model {
+        }
+        let mut layered_state;
+        let state = match self.context {
+            Some(ref entries) => {
+                let context = state.extra().layered_context(entries.bind(py))?;
+                layered_state = state.rebind_extra(|extra| extra.context = Some(context));
+                &mut *layered_state
+            }
+            None => state,
+        };
+        if self.root_model {
             return if field_name != ROOT_FIELD {
                 Err(ValError::new_with_loc(
                     ErrorType::NoSuchAttribute {
@@ -239,6 +302,118 @@ impl Validator for ModelValidator {
         Ok(model.into_py_any(py)?)
     }
 
+    fn validate_assignments<'py>(
+        &self,
+        py: Python<'py>,
+        model: &Bound<'py, PyAny>,
+        assignments: &Bound<'py, PyDict>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        if self.frozen {
+            return Err(ValError::new(ErrorTypeDefaults::FrozenInstance, assignments.as_any()));
+        }
+        let mut layered_state;
+        let state = match self.context {
+            Some(ref entries) => {
+                let context = state.extra().layered_context(entries.bind(py))?;
+                layered_state = state.rebind_extra(|extra| extra.context = Some(context));
+                &mut *layered_state
+            }
+            None => state,
+        };
+        if self.root_model {
+            let mut errors = Vec::new();
+            for (field_name, field_value) in assignments {
+                if !field_name.eq(intern!(py, ROOT_FIELD))? {
+                    errors.push(ValLineError::new_with_loc(
+                        ErrorType::NoSuchAttribute {
+                            attribute: field_name.to_string(),
+                            context: None,
+                        },
+                        &field_value,
+                        field_name.to_string(),
+                    ));
+                }
+            }
+            if !errors.is_empty() {
+                return Err(ValError::LineErrors(errors));
+            }
+            return match assignments.get_item(intern!(py, ROOT_FIELD))? {
+                Some(root_value) => {
+                    let state = &mut state.rebind_extra(|extra| extra.field_name = Some(PyString::new(py, ROOT_FIELD)));
+                    let output = self.validator.validate(py, &root_value, state)?;
+                    force_setattr(py, model, intern!(py, ROOT_FIELD), output)?;
+                    Ok(model.into_py_any(py)?)
+                }
+                None => Ok(model.into_py_any(py)?),
+            };
+        }
+        let old_dict = model.getattr(intern!(py, DUNDER_DICT))?.downcast_into::<PyDict>()?;
+
+        let input_dict = old_dict.copy()?;
+        if let Ok(old_extra) = model.getattr(intern!(py, DUNDER_MODEL_EXTRA_KEY))?.downcast::<PyDict>() {
+            input_dict.update(old_extra.as_mapping())?;
+        }
+        input_dict.update(assignments.as_mapping())?;
+
+        let output = self
+            .validator
+            .validate_assignments(py, input_dict.as_any(), assignments, state)?;
+
+        let (validated_dict, validated_extra, validated_fields_set): (
+            Bound<'_, PyDict>,
+            Bound<'_, PyAny>,
+            Bound<'_, PySet>,
+        ) = output.extract(py)?;
+
+        if let Ok(fields_set) = model.getattr(intern!(py, DUNDER_FIELDS_SET_KEY)) {
+            let fields_set = fields_set.downcast::<PySet>()?;
+            for field_name in validated_fields_set {
+                fields_set.add(field_name)?;
+            }
+        }
+
+        force_setattr(py, model, intern!(py, DUNDER_DICT), validated_dict)?;
+        force_setattr(py, model, intern!(py, DUNDER_MODEL_EXTRA_KEY), validated_extra)?;
+        Ok(model.into_py_any(py)?)
+    }
+
+    fn construct<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        fields_set: Option<&Bound<'py, PyAny>>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let mut layered_state;
+        let state = match self.context {
+            Some(ref entries) => {
+                let context = state.extra().layered_context(entries.bind(py))?;
+                layered_state = state.rebind_extra(|extra| extra.context = Some(context));
+                &mut *layered_state
+            }
+            None => state,
+        };
+        let class = self.class.bind(py);
+        let instance = create_class(class)?;
+
+        if self.root_model {
+            let fields_set = match fields_set {
+                Some(fields_set) => fields_set.clone(),
+                None => PySet::new(py, [ROOT_FIELD])?.into_any(),
+            };
+            force_setattr(py, &instance, intern!(py, DUNDER_FIELDS_SET_KEY), &fields_set)?;
+            force_setattr(py, &instance, intern!(py, ROOT_FIELD), input)?;
+        } else {
+            let output = self.validator.construct(py, input, None, state)?;
+            let (model_dict, model_extra, val_fields_set): (Bound<PyAny>, Bound<PyAny>, Bound<PyAny>) =
+                output.extract(py)?;
+            let fields_set = fields_set.unwrap_or(&val_fields_set);
+            set_model_attrs(&instance, &model_dict, &model_extra, fields_set)?;
+        }
+        self.call_post_init(py, instance, input, state.extra())
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -332,16 +507,371 @@ impl ModelValidator {
     ) -> ValResult<PyObject> {
         if let Some(ref post_init) = self.post_init {
             instance
-                .call_method1(post_init.bind(py), (extra.context,))
+                .call_method1(post_init.bind(py), (extra.context.as_ref(),))
                 .map_err(|e| convert_err(py, e, input))?;
         }
         Ok(instance.into())
     }
 }
 
+/// `ModelFieldGetter(name)(instance)` reads field `name` (or extra `name`) of a model instance straight from
+/// its `__dict__` (or `__pydantic_extra__`) without going through `__getattr__`, like `operator.attrgetter`
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+pub struct ModelFieldGetter {
+    name: Py<PyString>,
+}
+
+#[pymethods]
+impl ModelFieldGetter {
+    #[new]
+    fn py_new(name: Bound<'_, PyString>) -> Self {
+        Self { name: name.unbind() }
+    }
+
+    fn __call__<'py>(&self, instance: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let py = instance.py();
+        let name = self.name.bind(py);
+        match instance.getattr(intern!(py, DUNDER_DICT)) {
+            Ok(dict) => {
+                if let Some(value) = dict.downcast::<PyDict>()?.get_item(name)? {
+                    return Ok(value);
+                }
+            }
+            // fields of instances without a `__dict__` live in slots
+            Err(_) => return instance.getattr(name),
+        }
+        if let Ok(model_extra) = instance.getattr(intern!(py, DUNDER_MODEL_EXTRA_KEY)) {
+            if let Ok(model_extra) = model_extra.downcast::<PyDict>() {
+                if let Some(value) = model_extra.get_item(name)? {
+                    return Ok(value);
+                }
+            }
+        }
+        let type_name = instance.get_type().name()?;
+        Err(PyAttributeError::new_err(format!(
+            "'{type_name}' object has no field '{name}'"
+        )))
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(format!("ModelFieldGetter({})", self.name.bind(py).repr()?))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum FieldRepr {
+    Hidden,
+    Masked,
+}
+
+impl FieldRepr {
+    /// Fields with `repr` set to `False` or `'masked'` in a `model-fields` schema
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<AHashMap<String, Self>> {
+        let py = schema.py();
+        let mut field_reprs = AHashMap::new();
+        let schema_type: Bound<'_, PyString> = schema.get_as_req(intern!(py, "type"))?;
+        if schema_type.to_str()? != "model-fields" {
+            return Ok(field_reprs);
+        }
+        let fields: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
+        for (name, field) in fields {
+            let field = field.downcast_into::<PyDict>()?;
+            let Some(repr) = field.get_item(intern!(py, "repr"))? else {
+                continue;
+            };
+            let field_repr = if let Ok(repr) = repr.extract::<bool>() {
+                if repr {
+                    continue;
+                }
+                Self::Hidden
+            } else if repr.extract::<&str>().is_ok_and(|repr| repr == "masked") {
+                Self::Masked
+            } else {
+                return py_schema_err!("Invalid repr value for field '{}': {}", name, repr.repr()?);
+            };
+            field_reprs.insert(name.extract()?, field_repr);
+        }
+        Ok(field_reprs)
+    }
+}
+
+/// Installed as `__repr__` and `__rich_repr__` on model classes whose schema sets `generate_repr`,
+/// builds the repr from the instance's `__dict__` and `__pydantic_extra__`, skipping fields with `repr=False`
+/// and masking fields with `repr='masked'`
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+pub struct ModelRepr {
+    field_reprs: Arc<AHashMap<String, FieldRepr>>,
+    rich: bool,
+}
+
+#[pymethods]
+impl ModelRepr {
+    fn __get__(
+        slf: Bound<'_, Self>,
+        instance: Option<Bound<'_, PyAny>>,
+        _owner: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let py = slf.py();
+        match instance {
+            Some(instance) if !PyAnyMethods::is_none(&instance) => BoundModelRepr {
+                field_reprs: slf.get().field_reprs.clone(),
+                rich: slf.get().rich,
+                instance: instance.unbind(),
+            }
+            .into_py_any(py),
+            _ => Ok(slf.into_any().unbind()),
+        }
+    }
+}
+
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+struct BoundModelRepr {
+    field_reprs: Arc<AHashMap<String, FieldRepr>>,
+    rich: bool,
+    instance: PyObject,
+}
+
+#[pymethods]
+impl BoundModelRepr {
+    fn __call__(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let args = self.repr_args(py)?;
+        if self.rich {
+            return PyList::new(py, args)?.into_py_any(py);
+        }
+        let args = args
+            .iter()
+            .map(|(name, value)| Ok(format!("{name}={}", value.repr()?)))
+            .collect::<PyResult<Vec<_>>>()?;
+        let type_name = self.instance.bind(py).get_type().name()?;
+        format!("{type_name}({})", args.join(", ")).into_py_any(py)
+    }
+
+    fn __traverse__(&self, visit: pyo3::PyVisit<'_>) -> Result<(), pyo3::PyTraverseError> {
+        visit.call(&self.instance)
+    }
+}
+
+impl BoundModelRepr {
+    fn repr_args<'py>(&self, py: Python<'py>) -> PyResult<Vec<(Bound<'py, PyAny>, Bound<'py, PyAny>)>> {
+        let instance = self.instance.bind(py);
+        let mut args = Vec::new();
+        let mut push_items = |dict: &Bound<'py, PyDict>| -> PyResult<()> {
+            for (name, value) in dict {
+                match self.field_reprs.get(name.downcast::<PyString>()?.to_str()?) {
+                    Some(FieldRepr::Hidden) => {}
+                    Some(FieldRepr::Masked) => args.push((name, intern!(py, MASKED_REPR).clone().into_any())),
+                    None => args.push((name, value)),
+                }
+            }
+            Ok(())
+        };
+        push_items(instance.getattr(intern!(py, DUNDER_DICT))?.downcast()?)?;
+        if let Ok(model_extra) = instance.getattr(intern!(py, DUNDER_MODEL_EXTRA_KEY)) {
+            if let Ok(model_extra) = model_extra.downcast::<PyDict>() {
+                push_items(model_extra)?;
+            }
+        }
+        Ok(args)
+    }
+}
+
+/// Field names of a `model-fields` schema, `None` for any other schema, in which case all of `__dict__` is used
+fn field_names(schema: &Bound<'_, PyDict>) -> PyResult<Option<Vec<Py<PyString>>>> {
+    let py = schema.py();
+    let schema_type: Bound<'_, PyString> = schema.get_as_req(intern!(py, "type"))?;
+    if schema_type.to_str()? != "model-fields" {
+        return Ok(None);
+    }
+    let fields: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
+    fields
+        .keys()
+        .iter()
+        .map(|name| Ok(name.downcast_into::<PyString>()?.unbind()))
+        .collect::<PyResult<_>>()
+        .map(Some)
+}
+
+/// `__eq__` and `__hash__` are plain python functions calling `model_eq` and `model_hash` with the class's
+/// `ModelEq`, rather than descriptors, so comparing instances doesn't create a bound method object each time
+const MODEL_EQ_METHODS: &CStr = c_str!(
+    r#"
+def model_eq_methods(model_eq, model_hash, state, qualname):
+    def __eq__(self, other):
+        return model_eq(state, self, other)
+
+    def __hash__(self):
+        return model_hash(state, self)
+
+    __eq__.__qualname__ = f'{qualname}.__eq__'
+    __hash__.__qualname__ = f'{qualname}.__hash__'
+    __eq__.__pydantic_model_eq__ = state
+    return __eq__, __hash__
+"#
+);
+
+static MAKE_MODEL_EQ_METHODS: GILOnceCell<(PyObject, PyObject, PyObject)> = GILOnceCell::new();
+
+/// `__eq__` and `__hash__` for `class`, see `model_eq` and `model_hash`
+fn model_eq_methods<'py>(
+    class: &Bound<'py, PyType>,
+    state: ModelEq,
+) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)> {
+    let py = class.py();
+    let (make_methods, eq, hash) = MAKE_MODEL_EQ_METHODS.get_or_try_init(py, || {
+        let globals = PyDict::new(py);
+        py.run(MODEL_EQ_METHODS, Some(&globals), None)?;
+        Ok::<_, PyErr>((
+            globals
+                .get_as_req::<Bound<'_, PyAny>>(intern!(py, "model_eq_methods"))?
+                .unbind(),
+            wrap_pyfunction!(model_eq, py)?.into_any().unbind(),
+            wrap_pyfunction!(model_hash, py)?.into_any().unbind(),
+        ))
+    })?;
+    make_methods
+        .bind(py)
+        .call1((eq, hash, state, class.qualname()?))?
+        .extract()
+}
+
+/// The state behind a model class's generated `__eq__` and `__hash__`
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+pub struct ModelEq {
+    origin: Py<PyType>,
+    field_names: Option<Vec<Py<PyString>>>,
+}
+
+#[pymethods]
+impl ModelEq {
+    fn __traverse__(&self, visit: pyo3::PyVisit<'_>) -> Result<(), pyo3::PyTraverseError> {
+        visit.call(&self.origin)
+    }
+}
+
+/// `__eq__` of model classes whose schema sets `generate_eq`, instances are equal when their classes have the same
+/// generic origin (or are the same class) and their fields, extras and private attributes are equal,
+/// as with `BaseModel.__eq__`; fields missing from `__dict__` are only equal to other missing fields
+#[pyfunction]
+fn model_eq(
+    py: Python<'_>,
+    state: &Bound<'_, ModelEq>,
+    instance: &Bound<'_, PyAny>,
+    other: &Bound<'_, PyAny>,
+) -> PyResult<PyObject> {
+    // only instances of models with a generated `__eq__` are compared
+    let other_state = other
+        .get_type()
+        .getattr(intern!(py, "__eq__"))?
+        .getattr_opt(intern!(py, "__pydantic_model_eq__"))?
+        .and_then(|other_state| other_state.downcast_into::<ModelEq>().ok());
+    let Some(other_state) = other_state else {
+        return Ok(py.NotImplemented());
+    };
+    let state = state.get();
+    if !state.origin.bind(py).is(other_state.get().origin.bind(py)) {
+        return false.into_py_any(py);
+    }
+    for attr_name in [
+        intern!(py, DUNDER_MODEL_PRIVATE_KEY),
+        intern!(py, DUNDER_MODEL_EXTRA_KEY),
+    ] {
+        if !attr_or_none(instance, attr_name)?.eq(attr_or_none(other, attr_name)?)? {
+            return false.into_py_any(py);
+        }
+    }
+
+    let dict = instance.getattr(intern!(py, DUNDER_DICT))?.downcast_into::<PyDict>()?;
+    let other_dict = other.getattr(intern!(py, DUNDER_DICT))?.downcast_into::<PyDict>()?;
+    if dict.eq(&other_dict)? {
+        return true.into_py_any(py);
+    }
+    // `__dict__` may hold more than the fields, e.g. values of `cached_property`
+    let Some(field_names) = state.field_names.as_ref() else {
+        return false.into_py_any(py);
+    };
+    for field_name in field_names {
+        let field_name = field_name.bind(py);
+        let equal = match (dict.get_item(field_name)?, other_dict.get_item(field_name)?) {
+            (Some(value), Some(other_value)) => value.eq(other_value)?,
+            (None, None) => true,
+            _ => false,
+        };
+        if !equal {
+            return false.into_py_any(py);
+        }
+    }
+    true.into_py_any(py)
+}
+
+fn attr_or_none<'py>(obj: &Bound<'py, PyAny>, attr_name: &Bound<'py, PyString>) -> PyResult<Bound<'py, PyAny>> {
+    Ok(obj
+        .getattr_opt(attr_name)?
+        .unwrap_or_else(|| obj.py().None().into_bound(obj.py())))
+}
+
+/// `__hash__` of frozen model classes whose schema sets `generate_eq`, the hash is the same as `hash()` of a tuple
+/// of the field values, but the tuple is never built; without fields, values are hashed in the order of their keys
+/// so instances which are equal always hash the same
+#[pyfunction]
+fn model_hash(py: Python<'_>, state: &Bound<'_, ModelEq>, instance: &Bound<'_, PyAny>) -> PyResult<isize> {
+    let dict = instance.getattr(intern!(py, DUNDER_DICT))?.downcast_into::<PyDict>()?;
+    let hashes = match state.get().field_names.as_ref() {
+        // missing fields hash as 0
+        Some(field_names) => field_names
+            .iter()
+            .map(|field_name| dict.get_item(field_name)?.map_or(Ok(0), |value| value.hash()))
+            .collect::<PyResult<Vec<_>>>()?,
+        None => {
+            let mut items = dict
+                .iter()
+                .map(|(key, value)| Ok((key.downcast_into::<PyString>()?.to_string_lossy().into_owned(), value)))
+                .collect::<PyResult<Vec<_>>>()?;
+            items.sort_by(|(a, _), (b, _)| a.cmp(b));
+            items.iter().map(|(_, value)| value.hash()).collect::<PyResult<_>>()?
+        }
+    };
+    Ok(tuple_hash(&hashes))
+}
+
+#[cfg(target_pointer_width = "64")]
+mod xxprime {
+    pub const PRIME_1: usize = 11_400_714_785_074_694_791;
+    pub const PRIME_2: usize = 14_029_467_366_897_019_727;
+    pub const PRIME_5: usize = 2_870_177_450_012_600_261;
+    pub const ROTATE: u32 = 31;
+}
+
+#[cfg(not(target_pointer_width = "64"))]
+mod xxprime {
+    pub const PRIME_1: usize = 2_654_435_761;
+    pub const PRIME_2: usize = 2_246_822_519;
+    pub const PRIME_5: usize = 374_761_393;
+    pub const ROTATE: u32 = 13;
+}
+
+/// Combines item hashes as CPython's `tuplehash` does
+fn tuple_hash(hashes: &[isize]) -> isize {
+    let mut acc = xxprime::PRIME_5;
+    for &lane in hashes {
+        acc = acc.wrapping_add((lane as usize).wrapping_mul(xxprime::PRIME_2));
+        acc = acc.rotate_left(xxprime::ROTATE);
+        acc = acc.wrapping_mul(xxprime::PRIME_1);
+    }
+    acc = acc.wrapping_add(hashes.len() ^ (xxprime::PRIME_5 ^ 0x0035_d373));
+    if acc == usize::MAX {
+        1_546_275_796
+    } else {
+        acc as isize
+    }
+}
+
 /// based on the following but with the second argument of new_func set to an empty tuple as required
 /// https://github.com/PyO3/pyo3/blob/d2caa056e9aacc46374139ef491d112cb8af1a25/src/pyclass_init.rs#L35-L77
-pub(super) fn create_class<'py>(class: &Bound<'py, PyType>) -> PyResult<Bound<'py, PyAny>> {
+pub(crate) fn create_class<'py>(class: &Bound<'py, PyType>) -> PyResult<Bound<'py, PyAny>> {
     let py = class.py();
     let args = PyTuple::empty(py);
     let raw_type = class.as_type_ptr();
@@ -374,7 +904,7 @@ fn set_model_attrs(
     Ok(())
 }
 
-pub(super) fn force_setattr<'py, N, V>(py: Python<'py>, obj: &Bound<'py, PyAny>, attr_name: N, value: V) -> PyResult<()>
+pub(crate) fn force_setattr<'py, N, V>(py: Python<'py>, obj: &Bound<'py, PyAny>, attr_name: N, value: V) -> PyResult<()>
 where
     N: IntoPyObject<'py>,
     V: IntoPyObject<'py>,
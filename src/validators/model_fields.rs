@@ -1,13 +1,14 @@
-use pyo3::exceptions::PyKeyError;
+use pyo3::exceptions::{PyKeyError, PyTypeError};
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PySet, PyString, PyType};
+use pyo3::types::{PyDict, PyMapping, PySet, PyString, PyType};
 
 use ahash::AHashSet;
 use pyo3::IntoPyObjectExt;
+use regex::Regex;
 
-use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config_same, ExtraBehavior};
+use crate::build_tools::{alias_or_generated, is_strict, schema_or_config_same, AliasGenerator, ExtraBehavior};
+use crate::build_tools::{py_schema_err, py_schema_error_type};
 use crate::errors::LocItem;
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::ConsumeIterator;
@@ -28,12 +29,79 @@ struct Field {
 
 impl_py_gc_traverse!(Field { validator });
 
+#[derive(Debug)]
+struct ExtrasPatternValidator {
+    pattern: Regex,
+    validator: CombinedValidator,
+}
+
+impl_py_gc_traverse!(ExtrasPatternValidator { validator });
+
+/// Validators for extra keys matching `extras_pattern_schemas`, shared with the typed dict validator
+#[derive(Debug, Default)]
+pub(super) struct ExtrasPatternValidators {
+    validators: Vec<ExtrasPatternValidator>,
+}
+
+impl_py_gc_traverse!(ExtrasPatternValidators { validators });
+
+impl ExtrasPatternValidators {
+    pub fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+        extra_behavior: ExtraBehavior,
+    ) -> PyResult<Self> {
+        let py = schema.py();
+        match (
+            schema.get_as::<Bound<'_, PyDict>>(intern!(py, "extras_pattern_schemas"))?,
+            extra_behavior,
+        ) {
+            (Some(pattern_schemas), ExtraBehavior::Allow) => pattern_schemas
+                .iter()
+                .map(|(pattern, schema)| {
+                    let pattern: String = pattern.extract()?;
+                    Ok(ExtrasPatternValidator {
+                        pattern: Regex::new(&pattern)
+                            .map_err(|e| py_schema_error_type!("Invalid extras pattern {:?}: {}", pattern, e))?,
+                        validator: build_validator(&schema, config, definitions)?,
+                    })
+                })
+                .collect::<PyResult<_>>()
+                .map(|validators| Self { validators }),
+            (Some(_), _) => py_schema_err!("extras_pattern_schemas can only be used if extra_behavior=allow"),
+            (None, _) => Ok(Self::default()),
+        }
+    }
+
+    /// Pick the validator for an extra key, the schema of the first pattern matching the key, otherwise
+    /// `extras_schema`. If there are patterns, none match and there's no `extras_schema` the key is forbidden.
+    pub fn select<'a>(&'a self, key: &str, extras_validator: Option<&'a CombinedValidator>) -> ExtraKeyValidator<'a> {
+        match (
+            self.validators.iter().find(|p| p.pattern.is_match(key)),
+            extras_validator,
+        ) {
+            (Some(p), _) => ExtraKeyValidator::Validate(&p.validator),
+            (None, Some(validator)) => ExtraKeyValidator::Validate(validator),
+            (None, None) if self.validators.is_empty() => ExtraKeyValidator::Any,
+            (None, None) => ExtraKeyValidator::Forbidden,
+        }
+    }
+}
+
+pub(super) enum ExtraKeyValidator<'a> {
+    Any,
+    Validate(&'a CombinedValidator),
+    Forbidden,
+}
+
 #[derive(Debug)]
 pub struct ModelFieldsValidator {
     fields: Vec<Field>,
     model_name: String,
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Box<CombinedValidator>>,
+    extras_pattern_validators: ExtrasPatternValidators,
     extras_keys_validator: Option<Box<CombinedValidator>>,
     strict: bool,
     from_attributes: bool,
@@ -63,6 +131,7 @@ impl BuildValidator for ModelFieldsValidator {
             (Some(_), _) => return py_schema_err!("extras_schema can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
+        let extras_pattern_validators = ExtrasPatternValidators::build(schema, config, definitions, extra_behavior)?;
         let extras_keys_validator = match (schema.get_item(intern!(py, "extras_keys_schema"))?, &extra_behavior) {
             (Some(v), ExtraBehavior::Allow) => Some(Box::new(build_validator(&v, config, definitions)?)),
             (Some(_), _) => return py_schema_err!("extras_keys_schema can only be used if extra_behavior=allow"),
@@ -75,6 +144,7 @@ impl BuildValidator for ModelFieldsValidator {
         let fields_dict: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: Vec<Field> = Vec::with_capacity(fields_dict.len());
 
+        let alias_generator = AliasGenerator::validation(config)?;
         for (key, value) in fields_dict {
             let field_info = value.downcast::<PyDict>()?;
             let field_name_py: Bound<'_, PyString> = key.extract()?;
@@ -87,7 +157,12 @@ impl BuildValidator for ModelFieldsValidator {
                 Err(err) => return py_schema_err!("Field \"{}\":\n  {}", field_name, err),
             };
 
-            let validation_alias = field_info.get_item(intern!(py, "validation_alias"))?;
+            let validation_alias = alias_or_generated(
+                py,
+                field_info.get_item(intern!(py, "validation_alias"))?,
+                alias_generator,
+                field_name,
+            );
             let lookup_key_collection = LookupKeyCollection::new(py, validation_alias, field_name)?;
 
             fields.push(Field {
@@ -104,6 +179,7 @@ impl BuildValidator for ModelFieldsValidator {
             model_name,
             extra_behavior,
             extras_validator,
+            extras_pattern_validators,
             extras_keys_validator,
             strict,
             from_attributes,
@@ -117,9 +193,36 @@ impl BuildValidator for ModelFieldsValidator {
 
 impl_py_gc_traverse!(ModelFieldsValidator {
     fields,
-    extras_validator
+    extras_validator,
+    extras_pattern_validators
 });
 
+impl ModelFieldsValidator {
+    /// Moves values which aren't fields out of `new_data` after an assignment, they're the new `__pydantic_extra__`
+    fn split_extra(&self, py: Python<'_>, new_data: &Bound<'_, PyDict>) -> PyResult<PyObject> {
+        match &self.extra_behavior {
+            ExtraBehavior::Allow => {
+                let non_extra_data = PyDict::new(py);
+                self.fields.iter().try_for_each(|f| -> PyResult<()> {
+                    let Some(popped_value) = new_data.get_item(&f.name)? else {
+                        // field not present in __dict__ for some reason; let the rest of the
+                        // validation pipeline handle it later
+                        return Ok(());
+                    };
+                    new_data.del_item(&f.name)?;
+                    non_extra_data.set_item(&f.name, popped_value)?;
+                    Ok(())
+                })?;
+                let new_extra = new_data.copy()?;
+                new_data.clear();
+                new_data.update(non_extra_data.as_mapping())?;
+                Ok(new_extra.into())
+            }
+            _ => Ok(py.None()),
+        }
+    }
+}
+
 impl Validator for ModelFieldsValidator {
     fn validate<'py>(
         &self,
@@ -198,16 +301,21 @@ impl Validator for ModelFieldsValidator {
                         used_keys.insert(lookup_path.first_key());
                     }
 
-                    let state =
-                        &mut state.rebind_extra(|extra| extra.field_name = Some(field.name_py.bind(py).clone()));
+                    let state = &mut state.enter_field(field.name_py.bind(py), &field.name);
 
+                    let report_mark = state.report_mark();
+                    state.record_alias_hit(|| lookup_path.to_alias(py).map(Bound::unbind))?;
                     match field.validator.validate(py, value.borrow_input(), state) {
                         Ok(value) => {
+                            state.reports_with_outer_location(report_mark, field.name.as_str());
                             model_dict.set_item(&field.name_py, value)?;
                             fields_set_vec.push(field.name_py.clone_ref(py));
                             fields_set_count += 1;
                         }
-                        Err(ValError::Omit) => continue,
+                        Err(ValError::Omit) => {
+                            state.omitted_with_outer_location(report_mark, field.name.as_str());
+                            continue;
+                        }
                         Err(ValError::LineErrors(line_errors)) => {
                             for err in line_errors {
                                 errors.push(lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name));
@@ -255,6 +363,7 @@ impl Validator for ModelFieldsValidator {
                 fields_set_vec: &'a mut Vec<Py<PyString>>,
                 extra_behavior: ExtraBehavior,
                 extras_validator: Option<&'a CombinedValidator>,
+                extras_pattern_validators: &'a ExtrasPatternValidators,
                 extras_keys_validator: Option<&'a CombinedValidator>,
                 state: &'a mut ValidationState<'s, 'py>,
             }
@@ -306,6 +415,19 @@ impl Validator for ModelFieldsValidator {
                             }
                             ExtraBehavior::Ignore => {}
                             ExtraBehavior::Allow => {
+                                let extras_validator =
+                                    match self.extras_pattern_validators.select(&cow, self.extras_validator) {
+                                        ExtraKeyValidator::Any => None,
+                                        ExtraKeyValidator::Validate(validator) => Some(validator),
+                                        ExtraKeyValidator::Forbidden => {
+                                            self.errors.push(ValLineError::new_with_loc(
+                                                ErrorTypeDefaults::ExtraForbidden,
+                                                value,
+                                                raw_key.clone(),
+                                            ));
+                                            continue;
+                                        }
+                                    };
                                 let py_key = match self.extras_keys_validator {
                                     Some(validator) => {
                                         match validator.validate(self.py, raw_key.borrow_input(), self.state) {
@@ -322,7 +444,7 @@ impl Validator for ModelFieldsValidator {
                                     None => either_str.as_py_string(self.py, self.state.cache_str()),
                                 };
 
-                                if let Some(validator) = self.extras_validator {
+                                if let Some(validator) = extras_validator {
                                     match validator.validate(self.py, value, self.state) {
                                         Ok(value) => {
                                             model_extra_dict.set_item(&py_key, value)?;
@@ -353,6 +475,7 @@ impl Validator for ModelFieldsValidator {
                 fields_set_vec: &mut fields_set_vec,
                 extra_behavior: self.extra_behavior,
                 extras_validator: self.extras_validator.as_deref(),
+                extras_pattern_validators: &self.extras_pattern_validators,
                 extras_keys_validator: self.extras_keys_validator.as_deref(),
                 state,
             })??;
@@ -378,6 +501,74 @@ impl Validator for ModelFieldsValidator {
         }
     }
 
+    /// Values are taken as they are, fields which are missing take their default if they have one,
+    /// with `extra_behavior='allow'` other keys are kept as extras
+    fn construct<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        _fields_set: Option<&Bound<'py, PyAny>>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let dict = match input.downcast::<PyDict>() {
+            Ok(dict) => dict.clone(),
+            Err(_) => match input.downcast::<PyMapping>() {
+                Ok(mapping) => {
+                    let dict = PyDict::new(py);
+                    dict.update(mapping)?;
+                    dict
+                }
+                Err(_) => {
+                    let type_name = input.get_type().qualname()?;
+                    return Err(
+                        PyTypeError::new_err(format!("Expected a mapping to construct from, got {type_name}")).into(),
+                    );
+                }
+            },
+        };
+
+        let model_dict = PyDict::new(py);
+        let fields_set = PySet::empty(py)?;
+        let mut used_keys: AHashSet<&str> = AHashSet::with_capacity(self.fields.len());
+
+        let validate_by_alias = state.validate_by_alias_or(self.validate_by_alias);
+        let validate_by_name = state.validate_by_name_or(self.validate_by_name);
+
+        for field in &self.fields {
+            let lookup_key = field
+                .lookup_key_collection
+                .select(validate_by_alias, validate_by_name)?;
+            if let Some((lookup_path, value)) = lookup_key.py_get_dict_item(&dict)? {
+                used_keys.insert(lookup_path.first_key());
+                model_dict.set_item(&field.name_py, value)?;
+                fields_set.add(&field.name_py)?;
+            } else if let CombinedValidator::WithDefault(ref validator) = field.validator {
+                if let Some(default) =
+                    validator.construct_default(py, Some(&model_dict), state.extra().context.as_ref())?
+                {
+                    model_dict.set_item(&field.name_py, default)?;
+                }
+            }
+        }
+
+        let model_extra = if self.extra_behavior == ExtraBehavior::Allow {
+            let model_extra = PyDict::new(py);
+            for (key, value) in dict.iter() {
+                if let Ok(key) = key.downcast::<PyString>() {
+                    if used_keys.contains(key.to_str()?) {
+                        continue;
+                    }
+                }
+                fields_set.add(&key)?;
+                model_extra.set_item(key, value)?;
+            }
+            Some(model_extra)
+        } else {
+            None
+        };
+        Ok((model_dict, model_extra, fields_set).into_py_any(py)?)
+    }
+
     fn validate_assignment<'py>(
         &self,
         py: Python<'py>,
@@ -426,7 +617,7 @@ impl Validator for ModelFieldsValidator {
                     ));
                 }
 
-                let state = &mut state.rebind_extra(|extra| extra.field_name = Some(field.name_py.bind(py).clone()));
+                let state = &mut state.enter_field(field.name_py.bind(py), &field.name);
 
                 prepare_result(field.validator.validate(py, field_value, state))?
             } else {
@@ -436,9 +627,21 @@ impl Validator for ModelFieldsValidator {
                 // For models / typed dicts we forbid assigning extra attributes
                 // unless the user explicitly set extra_behavior to 'allow'
                 match self.extra_behavior {
-                    ExtraBehavior::Allow => match self.extras_validator {
-                        Some(ref validator) => prepare_result(validator.validate(py, field_value, state))?,
-                        None => get_updated_dict(field_value)?,
+                    ExtraBehavior::Allow => match self
+                        .extras_pattern_validators
+                        .select(field_name, self.extras_validator.as_deref())
+                    {
+                        ExtraKeyValidator::Validate(validator) => {
+                            prepare_result(validator.validate(py, field_value, state))?
+                        }
+                        ExtraKeyValidator::Any => get_updated_dict(field_value)?,
+                        ExtraKeyValidator::Forbidden => {
+                            return Err(ValError::new_with_loc(
+                                ErrorTypeDefaults::ExtraForbidden,
+                                field_value,
+                                field_name.to_string(),
+                            ))
+                        }
                     },
                     ExtraBehavior::Forbid | ExtraBehavior::Ignore => {
                         return Err(ValError::new_with_loc(
@@ -454,28 +657,89 @@ impl Validator for ModelFieldsValidator {
             }
         };
 
-        let new_extra = match &self.extra_behavior {
-            ExtraBehavior::Allow => {
-                let non_extra_data = PyDict::new(py);
-                self.fields.iter().try_for_each(|f| -> PyResult<()> {
-                    let Some(popped_value) = new_data.get_item(&f.name)? else {
-                        // field not present in __dict__ for some reason; let the rest of the
-                        // validation pipeline handle it later
-                        return Ok(());
-                    };
-                    new_data.del_item(&f.name)?;
-                    non_extra_data.set_item(&f.name, popped_value)?;
-                    Ok(())
-                })?;
-                let new_extra = new_data.copy()?;
-                new_data.clear();
-                new_data.update(non_extra_data.as_mapping())?;
-                new_extra.into()
+        let new_extra = self.split_extra(py, new_data)?;
+        let fields_set = PySet::new(py, &[field_name.to_string()])?;
+        Ok((new_data, new_extra, fields_set).into_py_any(py)?)
+    }
+
+    fn validate_assignments<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        assignments: &Bound<'py, PyDict>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let new_data = obj.downcast::<PyDict>()?;
+
+        // as with a single assignment, the values being assigned aren't in `data` until they've been validated,
+        // fields are validated in order so each sees the validated values of the fields before it
+        let data_dict = new_data.copy()?;
+        for field_name in assignments.keys() {
+            data_dict.del_item(field_name)?;
+        }
+        let mut errors: Vec<ValLineError> = Vec::new();
+        let mut set_output = |name: &str, result: ValResult<PyObject>| -> ValResult<()> {
+            match result {
+                Ok(output) => {
+                    new_data.set_item(name, &output)?;
+                    data_dict.set_item(name, output)?;
+                }
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(line_errors.into_iter().map(|e| e.with_outer_location(name)));
+                }
+                Err(err) => return Err(err),
             }
-            _ => py.None(),
+            Ok(())
         };
 
-        let fields_set = PySet::new(py, &[field_name.to_string()])?;
+        for field in &self.fields {
+            let Some(field_value) = assignments.get_item(&field.name)? else {
+                continue;
+            };
+            let result = if field.frozen {
+                Err(ValError::new(ErrorTypeDefaults::FrozenField, &field_value))
+            } else {
+                let state = &mut state.rebind_extra(|extra| extra.data = Some(data_dict.clone()));
+                let state = &mut state.enter_field(field.name_py.bind(py), &field.name);
+                field.validator.validate(py, &field_value, state)
+            };
+            set_output(&field.name, result)?;
+        }
+
+        for (field_name, field_value) in assignments {
+            let field_name = field_name.downcast_into::<PyString>()?;
+            let field_name = field_name.to_str()?;
+            if self.fields.iter().any(|f| f.name == field_name) {
+                continue;
+            }
+            let result = match self.extra_behavior {
+                ExtraBehavior::Allow => match self
+                    .extras_pattern_validators
+                    .select(field_name, self.extras_validator.as_deref())
+                {
+                    ExtraKeyValidator::Validate(validator) => {
+                        let state = &mut state.rebind_extra(|extra| extra.data = Some(data_dict.clone()));
+                        validator.validate(py, &field_value, state)
+                    }
+                    ExtraKeyValidator::Any => Ok(field_value.unbind()),
+                    ExtraKeyValidator::Forbidden => Err(ValError::new(ErrorTypeDefaults::ExtraForbidden, &field_value)),
+                },
+                ExtraBehavior::Forbid | ExtraBehavior::Ignore => Err(ValError::new(
+                    ErrorType::NoSuchAttribute {
+                        attribute: field_name.to_string(),
+                        context: None,
+                    },
+                    &field_value,
+                )),
+            };
+            set_output(field_name, result)?;
+        }
+
+        if !errors.is_empty() {
+            return Err(ValError::LineErrors(errors));
+        }
+        let new_extra = self.split_extra(py, new_data)?;
+        let fields_set = PySet::new(py, assignments.keys())?;
         Ok((new_data, new_extra, fields_set).into_py_any(py)?)
     }
 
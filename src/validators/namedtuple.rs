@@ -0,0 +1,209 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple, PyType};
+
+use ahash::AHashSet;
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ErrorTypeDefaults, LocItem, ValError, ValLineError, ValResult};
+use crate::input::{Arguments, BorrowInput, Input, InputType, KeywordArgs, PositionalArgs};
+use crate::lookup_key::LookupKey;
+use crate::tools::SchemaDict;
+
+use super::any::AnyValidator;
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
+};
+
+#[derive(Debug)]
+struct NamedTupleField {
+    name: String,
+    lookup_key: LookupKey,
+    validator: CombinedValidator,
+    default: Option<PyObject>,
+}
+
+impl_py_gc_traverse!(NamedTupleField { validator, default });
+
+#[derive(Debug)]
+pub struct NamedTupleValidator {
+    class: Py<PyType>,
+    fields: Vec<NamedTupleField>,
+    strict: bool,
+    name: String,
+}
+
+impl BuildValidator for NamedTupleValidator {
+    const EXPECTED_TYPE: &'static str = "namedtuple";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let class: Bound<'_, PyType> = schema.get_as_req(intern!(py, "cls"))?;
+
+        // `_fields` already reflects `rename=True`, so positional and keyword lookups use the final names
+        let field_names: Vec<String> = match class.getattr(intern!(py, "_fields")) {
+            Ok(fields) => fields.extract()?,
+            Err(_) => return py_schema_err!("'cls' must be a NamedTuple class, got {}", class.qualname()?),
+        };
+        let field_defaults: Bound<'_, PyDict> = class.getattr(intern!(py, "_field_defaults"))?.downcast_into()?;
+
+        let items_schema: Option<Bound<'_, PyList>> = schema.get_as(intern!(py, "items_schema"))?;
+        if let Some(ref items_schema) = items_schema {
+            if items_schema.len() != field_names.len() {
+                return py_schema_err!(
+                    "'items_schema' must have one schema per field, expected {}, got {}",
+                    field_names.len(),
+                    items_schema.len()
+                );
+            }
+        }
+
+        let mut fields = Vec::with_capacity(field_names.len());
+        for (index, name) in field_names.into_iter().enumerate() {
+            let validator = match items_schema {
+                Some(ref items_schema) => build_validator(&items_schema.get_item(index)?, config, definitions)?,
+                None => AnyValidator::build(schema, config, definitions)?,
+            };
+            fields.push(NamedTupleField {
+                lookup_key: LookupKey::from_string(py, &name),
+                default: field_defaults.get_item(&name)?.map(Bound::unbind),
+                name,
+                validator,
+            });
+        }
+
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, class.qualname()?);
+        Ok(Self {
+            class: class.unbind(),
+            fields,
+            strict: is_strict(schema, config)?,
+            name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(NamedTupleValidator { class, fields });
+
+impl Validator for NamedTupleValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        // this validator does not yet support partial validation, disable it to avoid incorrect results
+        state.allow_partial = false.into();
+
+        let class = self.class.bind(py);
+        let is_instance = input
+            .as_python()
+            .is_some_and(|any| any.is_instance(class).unwrap_or(false));
+        if !is_instance && state.extra().input_type == InputType::Python {
+            if state.strict_or(self.strict) {
+                let class = class.qualname()?.to_string();
+                return Err(ValError::new(ErrorType::IsInstanceOf { class, context: None }, input));
+            }
+            state.floor_exactness(Exactness::Lax);
+        }
+
+        // tuples (including instances of the class) and lists are positional, mappings are by field name
+        let args = input.validate_args()?;
+
+        let mut output: Vec<PyObject> = Vec::with_capacity(self.fields.len());
+        let mut errors: Vec<ValLineError> = Vec::new();
+        let mut used_kwargs: AHashSet<&str> = AHashSet::with_capacity(self.fields.len());
+
+        for (index, field) in self.fields.iter().enumerate() {
+            let pos_value = args.args().and_then(|args| args.get_item(index));
+            let mut kw_value = None;
+            if let Some(kwargs) = args.kwargs() {
+                if let Some((lookup_path, value)) = kwargs.get_item(&field.lookup_key)? {
+                    used_kwargs.insert(lookup_path.first_key());
+                    kw_value = Some(value);
+                }
+            }
+
+            let (value, loc): (_, LocItem) = match (pos_value, kw_value) {
+                (Some(_), Some(kw_value)) => {
+                    errors.push(ValLineError::new_with_loc(
+                        ErrorTypeDefaults::MultipleArgumentValues,
+                        kw_value.borrow_input(),
+                        field.name.clone(),
+                    ));
+                    continue;
+                }
+                (Some(pos_value), None) => (
+                    field.validator.validate(py, pos_value.borrow_input(), state),
+                    index.into(),
+                ),
+                (None, Some(kw_value)) => (
+                    field.validator.validate(py, kw_value.borrow_input(), state),
+                    field.name.clone().into(),
+                ),
+                (None, None) => {
+                    if let Some(ref default) = field.default {
+                        output.push(default.clone_ref(py));
+                    } else {
+                        errors.push(ValLineError::new_with_loc(
+                            ErrorTypeDefaults::MissingArgument,
+                            input,
+                            field.name.clone(),
+                        ));
+                    }
+                    continue;
+                }
+            };
+            match value {
+                Ok(value) => output.push(value),
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(loc.clone())));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        if let Some(args) = args.args() {
+            for (index, item) in args.iter().enumerate().skip(self.fields.len()) {
+                errors.push(ValLineError::new_with_loc(
+                    ErrorTypeDefaults::UnexpectedPositionalArgument,
+                    item,
+                    index,
+                ));
+            }
+        }
+
+        if let Some(kwargs) = args.kwargs() {
+            if kwargs.len() > used_kwargs.len() {
+                for result in kwargs.iter() {
+                    let (raw_key, value) = result?;
+                    let is_used = match raw_key.borrow_input().validate_str(true, false) {
+                        Ok(key) => used_kwargs.contains(key.into_inner().as_cow()?.as_ref()),
+                        Err(_) => false,
+                    };
+                    if !is_used {
+                        errors.push(ValLineError::new_with_loc(
+                            ErrorTypeDefaults::UnexpectedKeywordArgument,
+                            value,
+                            raw_key.clone(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(ValError::LineErrors(errors));
+        }
+        let output = PyTuple::new(py, output)?;
+        Ok(class.call1(output)?.unbind())
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,170 @@
+use ahash::AHashSet;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyList, PyString, PyType};
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{Input, InputType, ValidationMatch};
+use crate::serializers::BytesMode;
+use crate::tools::SchemaDict;
+
+use super::config::ValBytesMode;
+use super::literal::expected_repr_name;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+static PATH_TYPES: GILOnceCell<[Py<PyType>; 3]> = GILOnceCell::new();
+
+#[derive(Debug, Clone, Copy)]
+enum PathType {
+    Path,
+    Posix,
+    Windows,
+}
+
+impl PathType {
+    fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let path_type: Option<Bound<'_, PyString>> = schema.get_as(intern!(schema.py(), "path_type"))?;
+        match path_type.as_ref().map(|s| s.to_str()).transpose()? {
+            None | Some("path") => Ok(Self::Path),
+            Some("posix") => Ok(Self::Posix),
+            Some("windows") => Ok(Self::Windows),
+            Some(s) => py_schema_err!("Invalid path_type value: {}", s),
+        }
+    }
+
+    fn py_type(self, py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+        let types = PATH_TYPES.get_or_try_init(py, || -> PyResult<[Py<PyType>; 3]> {
+            let module = py.import(intern!(py, "pathlib"))?;
+            Ok([
+                module.getattr(intern!(py, "Path"))?.extract()?,
+                module.getattr(intern!(py, "PurePosixPath"))?.extract()?,
+                module.getattr(intern!(py, "PureWindowsPath"))?.extract()?,
+            ])
+        })?;
+        Ok(types[self as usize].bind(py))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PathValidator {
+    strict: bool,
+    path_type: PathType,
+    must_be_absolute: bool,
+    allowed_suffixes: Option<(AHashSet<String>, String)>,
+    name: String,
+}
+
+impl BuildValidator for PathValidator {
+    const EXPECTED_TYPE: &'static str = "path";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let (allowed_suffixes, name) = match schema.get_as::<Bound<'_, PyList>>(intern!(py, "allowed_suffixes"))? {
+            Some(list) => {
+                if list.is_empty() {
+                    return py_schema_err!("`allowed_suffixes` should have length > 0");
+                }
+                let mut suffixes = AHashSet::new();
+                let mut repr_args = Vec::new();
+                for item in list {
+                    let suffix: String = item.extract()?;
+                    repr_args.push(format!("'{suffix}'"));
+                    suffixes.insert(suffix);
+                }
+                let (repr, name) = expected_repr_name(repr_args, Self::EXPECTED_TYPE);
+                (Some((suffixes, repr)), name)
+            }
+            None => (None, Self::EXPECTED_TYPE.to_string()),
+        };
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            path_type: PathType::from_schema(schema)?,
+            must_be_absolute: schema.get_as(intern!(py, "must_be_absolute"))?.unwrap_or(false),
+            allowed_suffixes,
+            name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(PathValidator {});
+
+impl Validator for PathValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let class = self.path_type.py_type(py)?;
+        let path = match input.as_python().filter(|any| any.is_instance(class).unwrap_or(false)) {
+            Some(py_input) => py_input.clone(),
+            None => {
+                if state.strict_or(self.strict) && state.extra().input_type == InputType::Python {
+                    let class = class.qualname()?.to_string();
+                    return Err(ValError::new(ErrorType::IsInstanceOf { class, context: None }, input));
+                }
+                // as with UUIDs, a string from JSON is an exact match, anything from python is a coercion
+                if state.extra().input_type == InputType::Python {
+                    state.floor_exactness(Exactness::Lax);
+                }
+                class.call1((self.path_arg(py, input)?,))?
+            }
+        };
+        self.check_constraints(&path, input)?;
+        Ok(path.unbind())
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl PathValidator {
+    /// The argument to construct the path from, `str` for strings and bytes, or the `os.PathLike` input itself
+    fn path_arg<'py>(&self, py: Python<'py>, input: &(impl Input<'py> + ?Sized)) -> ValResult<Bound<'py, PyAny>> {
+        if let Some(either_str) = input.validate_str(true, false).ok().map(ValidationMatch::into_inner) {
+            return Ok(PyString::new(py, &either_str.as_cow()?).into_any());
+        }
+        // lax so bytearray and other buffers are accepted too, strings have already been handled above
+        if let Ok(either_bytes) = input.validate_bytes(false, ValBytesMode { ser: BytesMode::Utf8 }) {
+            let bytes = either_bytes.into_inner().into_pyobject(py)?;
+            return Ok(py
+                .import(intern!(py, "os"))?
+                .call_method1(intern!(py, "fsdecode"), (bytes,))?);
+        }
+        if let Some(py_input) = input.as_python() {
+            if py_input.hasattr(intern!(py, "__fspath__"))? {
+                return Ok(py_input.clone());
+            }
+        }
+        Err(ValError::new(ErrorTypeDefaults::PathType, input))
+    }
+
+    fn check_constraints<'py>(&self, path: &Bound<'py, PyAny>, input: &(impl Input<'py> + ?Sized)) -> ValResult<()> {
+        let py = path.py();
+        if self.must_be_absolute && !path.call_method0(intern!(py, "is_absolute"))?.is_truthy()? {
+            return Err(ValError::new(ErrorTypeDefaults::PathNotAbsolute, input));
+        }
+        if let Some((ref allowed_suffixes, ref expected_suffixes)) = self.allowed_suffixes {
+            let suffix = path.getattr(intern!(py, "suffix"))?;
+            if !allowed_suffixes.contains(suffix.downcast::<PyString>()?.to_str()?) {
+                let expected_suffixes = expected_suffixes.clone();
+                return Err(ValError::new(
+                    ErrorType::PathSuffix {
+                        expected_suffixes,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::py_schema_err;
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::function::convert_err;
+use super::is_instance::class_repr;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// Validates by calling `cls.__validate_core__(input)`, the bound method is looked up once when the
+/// validator is built rather than on every call.
+#[derive(Debug)]
+pub struct ProtocolCustomValidator {
+    validate_core: PyObject,
+    name: String,
+}
+
+impl BuildValidator for ProtocolCustomValidator {
+    const EXPECTED_TYPE: &'static str = "protocol-custom";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        _config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let class: Bound<'_, PyAny> = schema.get_as_req(intern!(py, "cls"))?;
+        let Some(validate_core) = class.getattr_opt(intern!(py, "__validate_core__"))? else {
+            return py_schema_err!("'cls' must define a '__validate_core__' method");
+        };
+        if !validate_core.is_callable() {
+            return py_schema_err!("'cls.__validate_core__' must be callable");
+        }
+        Ok(Self {
+            validate_core: validate_core.unbind(),
+            name: format!("{}[{}]", Self::EXPECTED_TYPE, class_repr(schema, &class)?),
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(ProtocolCustomValidator { validate_core });
+
+impl Validator for ProtocolCustomValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        _state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        self.validate_core
+            .call1(py, (input.to_object(py)?,))
+            .map_err(|e| convert_err(py, e, input))
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
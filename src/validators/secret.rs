@@ -0,0 +1,124 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyString};
+use pyo3::IntoPyObjectExt;
+
+use crate::build_tools::is_strict;
+use crate::errors::{ErrorType, InputValue, ValError, ValResult};
+use crate::input::{downcast_python_input, Input, InputType};
+use crate::secret::{PySecret, MASKED_SECRET};
+
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum SecretKind {
+    Str,
+    Bytes,
+}
+
+impl SecretKind {
+    /// Replaces the input of errors, so the value of the secret never appears in a `ValidationError`
+    fn mask_error(self, py: Python, error: ValError) -> ValError {
+        match error {
+            ValError::LineErrors(line_errors) => ValError::LineErrors(
+                line_errors
+                    .into_iter()
+                    .map(|mut line_error| {
+                        let masked = match self {
+                            Self::Str => PyString::new(py, MASKED_SECRET).into_any(),
+                            Self::Bytes => PyBytes::new(py, MASKED_SECRET.as_bytes()).into_any(),
+                        };
+                        line_error.input_value = InputValue::Python(masked.unbind());
+                        line_error
+                    })
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SecretValidator {
+    kind: SecretKind,
+    validator: Box<CombinedValidator>,
+    strict: bool,
+    name: &'static str,
+}
+
+macro_rules! secret_validator_builder {
+    ($builder:ident, $expected_type:literal, $kind:expr, $inner_type:literal) => {
+        #[derive(Debug)]
+        pub struct $builder;
+
+        impl BuildValidator for $builder {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                schema: &Bound<'_, PyDict>,
+                config: Option<&Bound<'_, PyDict>>,
+                definitions: &mut DefinitionsBuilder<CombinedValidator>,
+            ) -> PyResult<CombinedValidator> {
+                let py = schema.py();
+                // the value is validated by a `str` or `bytes` validator with the same length constraints
+                let inner_schema = PyDict::new(py);
+                inner_schema.set_item(intern!(py, "type"), intern!(py, $inner_type))?;
+                for key in [intern!(py, "min_length"), intern!(py, "max_length")] {
+                    if let Some(value) = schema.get_item(key)? {
+                        inner_schema.set_item(key, value)?;
+                    }
+                }
+                Ok(SecretValidator {
+                    kind: $kind,
+                    validator: Box::new(build_validator(&inner_schema, config, definitions)?),
+                    strict: is_strict(schema, config)?,
+                    name: $expected_type,
+                }
+                .into())
+            }
+        }
+    };
+}
+
+secret_validator_builder!(SecretStrValidatorBuilder, "secret_str", SecretKind::Str, "str");
+secret_validator_builder!(SecretBytesValidatorBuilder, "secret_bytes", SecretKind::Bytes, "bytes");
+
+impl_py_gc_traverse!(SecretValidator { validator });
+
+impl Validator for SecretValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let value = match downcast_python_input::<PySecret>(input) {
+            // the value of an existing secret is validated as well, e.g. for its length
+            Some(secret) => self.validator.validate(py, secret.get().value(py), state),
+            None => {
+                if state.extra().input_type == InputType::Python {
+                    if state.strict_or(self.strict) {
+                        let error = ValError::new(
+                            ErrorType::IsInstanceOf {
+                                class: "Secret".to_string(),
+                                context: None,
+                            },
+                            input,
+                        );
+                        return Err(self.kind.mask_error(py, error));
+                    }
+                    state.floor_exactness(Exactness::Lax);
+                }
+                self.validator.validate(py, input, state)
+            }
+        };
+        let value = value.map_err(|error| self.kind.mask_error(py, error))?;
+        Ok(PySecret::new(value).into_py_any(py)?)
+    }
+
+    fn get_name(&self) -> &str {
+        self.name
+    }
+}
@@ -65,7 +65,9 @@ impl Validator for SetValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let collection = input.validate_set(state.strict_or(self.strict))?.unpack(state);
+        let collection = input
+            .validate_set(state.strict_or(self.strict))?
+            .unpack_coerced(state, input, "set");
         let set = PySet::empty(py)?;
         collection.iterate(ValidateToSet {
             py,
@@ -1,13 +1,14 @@
+use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 use pyo3::IntoPyObjectExt;
 use regex::Regex;
 
-use crate::build_tools::{is_strict, py_schema_error_type, schema_or_config, schema_or_config_same};
-use crate::errors::{ErrorType, ValError, ValResult};
+use crate::build_tools::{is_strict, py_schema_err, py_schema_error_type, schema_or_config, schema_or_config_same};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::Input;
-use crate::tools::SchemaDict;
+use crate::tools::{to_camel_case, to_snake_case, SchemaDict};
 
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
@@ -52,7 +53,7 @@ impl Validator for StrValidator {
             .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)
             .and_then(|val_match| {
                 Ok(val_match
-                    .unpack(state)
+                    .unpack_coerced(state, input, "str")
                     .as_py_string(py, state.cache_str())
                     .into_py_any(py)?)
             })
@@ -70,10 +71,18 @@ pub struct StrConstrainedValidator {
     pattern: Option<Pattern>,
     max_length: Option<usize>,
     min_length: Option<usize>,
+    max_length_bytes: Option<usize>,
+    min_length_bytes: Option<usize>,
     strip_whitespace: bool,
     to_lower: bool,
     to_upper: bool,
+    to_title: bool,
+    to_snake: bool,
+    to_camel: bool,
+    normalize: Option<UnicodeForm>,
+    casefold: bool,
     coerce_numbers_to_str: bool,
+    strict_exact_type: bool,
 }
 
 impl_py_gc_traverse!(StrConstrainedValidator {});
@@ -85,14 +94,29 @@ impl Validator for StrConstrainedValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        let strict = state.strict_or(self.strict);
+        // in strict mode str subclasses are usually accepted, `strict_exact_type` rejects them too
+        if self.strict_exact_type
+            && strict
+            && input
+                .as_python()
+                .is_some_and(|py_input| !py_input.is_exact_instance_of::<PyString>())
+        {
+            return Err(ValError::new(ErrorTypeDefaults::StringType, input));
+        }
         let either_str = input
-            .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)?
-            .unpack(state);
+            .validate_str(strict, self.coerce_numbers_to_str)?
+            .unpack_coerced(state, input, "str");
         let cow = either_str.as_cow()?;
         let mut str = cow.as_ref();
         if self.strip_whitespace {
             str = str.trim();
         }
+        // canonicalize before checking constraints so they apply to the value which is returned
+        let canonical = self.canonicalize(py, str)?;
+        if let Some(ref canonical) = canonical {
+            str = canonical;
+        }
 
         let str_len: Option<usize> = if self.min_length.is_some() | self.max_length.is_some() {
             Some(str.chars().count())
@@ -122,6 +146,32 @@ impl Validator for StrConstrainedValidator {
             }
         }
 
+        // rust strings are UTF-8, so the byte length is what the value occupies when encoded
+        if let Some(min_length_bytes) = self.min_length_bytes {
+            if str.len() < min_length_bytes {
+                return Err(ValError::new(
+                    ErrorType::StringTooShortBytes {
+                        min_length_bytes,
+                        actual_length_bytes: str.len(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(max_length_bytes) = self.max_length_bytes {
+            if str.len() > max_length_bytes {
+                return Err(ValError::new(
+                    ErrorType::StringTooLongBytes {
+                        max_length_bytes,
+                        actual_length_bytes: str.len(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+
         if let Some(pattern) = &self.pattern {
             if !pattern.is_match(py, str)? {
                 return Err(ValError::new(
@@ -138,7 +188,13 @@ impl Validator for StrConstrainedValidator {
             state.maybe_cached_str(py, &str.to_lowercase())
         } else if self.to_upper {
             state.maybe_cached_str(py, &str.to_uppercase())
-        } else if self.strip_whitespace {
+        } else if self.to_title {
+            state.maybe_cached_str(py, &to_title_case(str))
+        } else if self.to_snake {
+            state.maybe_cached_str(py, &to_snake_case(str))
+        } else if self.to_camel {
+            state.maybe_cached_str(py, &to_camel_case(str))
+        } else if self.strip_whitespace || canonical.is_some() {
             state.maybe_cached_str(py, str)
         } else {
             // we haven't modified the string, return the original as it might be a PyString
@@ -153,30 +209,47 @@ impl Validator for StrConstrainedValidator {
 }
 
 impl StrConstrainedValidator {
+    /// Apply `casefold` then `normalize`, returns `None` if the string is unchanged
+    fn canonicalize(&self, py: Python<'_>, str: &str) -> PyResult<Option<String>> {
+        let casefolded = if self.casefold {
+            // full case folding (e.g. `ß` -> `ss`) isn't available in std, so use python's implementation
+            let folded: String = PyString::new(py, str)
+                .call_method0(intern!(py, "casefold"))?
+                .extract()?;
+            (folded != str).then_some(folded)
+        } else {
+            None
+        };
+        let current = casefolded.as_deref().unwrap_or(str);
+        Ok(match self.normalize {
+            Some(form) if !form.is_normalized(current) => Some(form.normalize(current)),
+            _ => casefolded,
+        })
+    }
+
     fn build(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
         let py = schema.py();
 
         let pattern = schema
             .get_as(intern!(py, "pattern"))?
             .map(|s| {
-                let regex_engine = schema_or_config::<Bound<'_, PyString>>(
-                    schema,
-                    config,
-                    intern!(py, "regex_engine"),
-                    intern!(py, "regex_engine"),
-                )?;
-                let regex_engine = regex_engine
-                    .as_ref()
-                    .map(|s| s.to_str())
-                    .transpose()?
-                    .unwrap_or(RegexEngine::RUST_REGEX);
-                Pattern::compile(s, regex_engine)
+                let fullmatch = match schema.get_as::<Bound<'_, PyString>>(intern!(py, "pattern_mode"))? {
+                    Some(mode) => match mode.to_str()? {
+                        "search" => false,
+                        "fullmatch" => true,
+                        mode => return py_schema_err!("Invalid pattern_mode: {}", mode),
+                    },
+                    None => false,
+                };
+                Pattern::build(s, schema, config, fullmatch)
             })
             .transpose()?;
         let min_length: Option<usize> =
             schema_or_config(schema, config, intern!(py, "min_length"), intern!(py, "str_min_length"))?;
         let max_length: Option<usize> =
             schema_or_config(schema, config, intern!(py, "max_length"), intern!(py, "str_max_length"))?;
+        let min_length_bytes: Option<usize> = schema.get_as(intern!(py, "min_length_bytes"))?;
+        let max_length_bytes: Option<usize> = schema.get_as(intern!(py, "max_length_bytes"))?;
 
         let strip_whitespace: bool = schema_or_config(
             schema,
@@ -189,19 +262,51 @@ impl StrConstrainedValidator {
             schema_or_config(schema, config, intern!(py, "to_lower"), intern!(py, "str_to_lower"))?.unwrap_or(false);
         let to_upper: bool =
             schema_or_config(schema, config, intern!(py, "to_upper"), intern!(py, "str_to_upper"))?.unwrap_or(false);
+        let to_title: bool =
+            schema_or_config(schema, config, intern!(py, "to_title"), intern!(py, "str_to_title"))?.unwrap_or(false);
+        let to_snake: bool =
+            schema_or_config(schema, config, intern!(py, "to_snake"), intern!(py, "str_to_snake"))?.unwrap_or(false);
+        let to_camel: bool =
+            schema_or_config(schema, config, intern!(py, "to_camel"), intern!(py, "str_to_camel"))?.unwrap_or(false);
+
+        let normalize = schema_or_config::<Bound<'_, PyString>>(
+            schema,
+            config,
+            intern!(py, "normalize"),
+            intern!(py, "str_normalize"),
+        )?
+        .map(|form| UnicodeForm::from_str(form.to_str()?))
+        .transpose()?;
+        let casefold: bool =
+            schema_or_config(schema, config, intern!(py, "casefold"), intern!(py, "str_casefold"))?.unwrap_or(false);
 
         let coerce_numbers_to_str: bool =
             schema_or_config_same(schema, config, intern!(py, "coerce_numbers_to_str"))?.unwrap_or(false);
+        let strict_exact_type: bool = schema_or_config(
+            schema,
+            config,
+            intern!(py, "strict_exact_type"),
+            intern!(py, "str_strict_exact_type"),
+        )?
+        .unwrap_or(false);
 
         Ok(Self {
             strict: is_strict(schema, config)?,
             pattern,
             min_length,
             max_length,
+            min_length_bytes,
+            max_length_bytes,
             strip_whitespace,
             to_lower,
             to_upper,
+            to_title,
+            to_snake,
+            to_camel,
+            normalize,
+            casefold,
             coerce_numbers_to_str,
+            strict_exact_type,
         })
     }
 
@@ -211,16 +316,81 @@ impl StrConstrainedValidator {
         self.pattern.is_some()
             || self.max_length.is_some()
             || self.min_length.is_some()
+            || self.max_length_bytes.is_some()
+            || self.min_length_bytes.is_some()
             || self.strip_whitespace
             || self.to_lower
             || self.to_upper
+            || self.to_title
+            || self.to_snake
+            || self.to_camel
+            || self.normalize.is_some()
+            || self.casefold
+            || self.strict_exact_type
     }
 }
 
+/// Unicode normalization forms, see https://unicode.org/reports/tr15/
+#[derive(Debug, Clone, Copy)]
+enum UnicodeForm {
+    Nfc,
+    Nfkc,
+    Nfd,
+    Nfkd,
+}
+
+impl UnicodeForm {
+    fn from_str(form: &str) -> PyResult<Self> {
+        match form {
+            "NFC" => Ok(Self::Nfc),
+            "NFKC" => Ok(Self::Nfkc),
+            "NFD" => Ok(Self::Nfd),
+            "NFKD" => Ok(Self::Nfkd),
+            _ => py_schema_err!("Invalid unicode normalization form: {}", form),
+        }
+    }
+
+    fn is_normalized(self, s: &str) -> bool {
+        match self {
+            Self::Nfc => ComposingNormalizer::new_nfc().is_normalized(s),
+            Self::Nfkc => ComposingNormalizer::new_nfkc().is_normalized(s),
+            Self::Nfd => DecomposingNormalizer::new_nfd().is_normalized(s),
+            Self::Nfkd => DecomposingNormalizer::new_nfkd().is_normalized(s),
+        }
+    }
+
+    fn normalize(self, s: &str) -> String {
+        match self {
+            Self::Nfc => ComposingNormalizer::new_nfc().normalize(s),
+            Self::Nfkc => ComposingNormalizer::new_nfkc().normalize(s),
+            Self::Nfd => DecomposingNormalizer::new_nfd().normalize(s),
+            Self::Nfkd => DecomposingNormalizer::new_nfkd().normalize(s),
+        }
+    }
+}
+
+/// Like python's `str.title()`, letters following a letter are lowercased and all others are uppercased
+fn to_title_case(s: &str) -> String {
+    let mut title = String::with_capacity(s.len());
+    let mut previous_cased = false;
+    for c in s.chars() {
+        if previous_cased {
+            title.extend(c.to_lowercase());
+        } else {
+            title.extend(c.to_uppercase());
+        }
+        previous_cased = c.is_lowercase() || c.is_uppercase();
+    }
+    title
+}
+
 #[derive(Debug, Clone)]
-struct Pattern {
-    pattern: String,
+#[allow(clippy::struct_field_names)]
+pub(crate) struct Pattern {
+    pub pattern: String,
     engine: RegexEngine,
+    // whether the pattern must match the whole string rather than anywhere in it
+    fullmatch: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -246,7 +416,29 @@ impl Pattern {
         }
     }
 
-    fn compile(pattern: Bound<'_, PyAny>, engine: &str) -> PyResult<Self> {
+    /// Compiles `pattern` with the `regex_engine` set in the schema or config
+    pub fn build(
+        pattern: Bound<'_, PyAny>,
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        fullmatch: bool,
+    ) -> PyResult<Self> {
+        let py = schema.py();
+        let regex_engine = schema_or_config::<Bound<'_, PyString>>(
+            schema,
+            config,
+            intern!(py, "regex_engine"),
+            intern!(py, "regex_engine"),
+        )?;
+        let regex_engine = regex_engine
+            .as_ref()
+            .map(|s| s.to_str())
+            .transpose()?
+            .unwrap_or(RegexEngine::RUST_REGEX);
+        Self::compile(pattern, regex_engine, fullmatch)
+    }
+
+    fn compile(pattern: Bound<'_, PyAny>, engine: &str, fullmatch: bool) -> PyResult<Self> {
         let pattern_str = Self::extract_pattern_str(&pattern)?;
 
         let py = pattern.py();
@@ -261,11 +453,19 @@ impl Pattern {
             Ok(Self {
                 pattern: pattern_str,
                 engine: RegexEngine::PythonRe(pattern.unbind()),
+                fullmatch,
             })
         } else {
             let engine = match engine {
                 RegexEngine::RUST_REGEX => {
-                    RegexEngine::RustRegex(Regex::new(&pattern_str).map_err(|e| py_schema_error_type!("{}", e))?)
+                    let regex = Regex::new(&pattern_str).map_err(|e| py_schema_error_type!("{}", e))?;
+                    if fullmatch {
+                        // compile the original pattern first so errors refer to it rather than the anchored version
+                        let anchored = format!(r"\A(?:{pattern_str})\z");
+                        RegexEngine::RustRegex(Regex::new(&anchored).map_err(|e| py_schema_error_type!("{}", e))?)
+                    } else {
+                        RegexEngine::RustRegex(regex)
+                    }
                 }
                 RegexEngine::PYTHON_RE => RegexEngine::PythonRe(re_compile.call1((pattern,))?.into()),
                 _ => return Err(py_schema_error_type!("Invalid regex engine: {}", engine)),
@@ -274,15 +474,21 @@ impl Pattern {
             Ok(Self {
                 pattern: pattern_str,
                 engine,
+                fullmatch,
             })
         }
     }
 
-    fn is_match(&self, py: Python<'_>, target: &str) -> PyResult<bool> {
+    pub fn is_match(&self, py: Python<'_>, target: &str) -> PyResult<bool> {
         match &self.engine {
             RegexEngine::RustRegex(regex) => Ok(regex.is_match(target)),
             RegexEngine::PythonRe(py_regex) => {
-                Ok(!py_regex.call_method1(py, intern!(py, "search"), (target,))?.is_none(py))
+                let method = if self.fullmatch {
+                    intern!(py, "fullmatch")
+                } else {
+                    intern!(py, "search")
+                };
+                Ok(!py_regex.call_method1(py, method, (target,))?.is_none(py))
             }
         }
     }
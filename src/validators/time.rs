@@ -7,18 +7,19 @@ use speedate::Time;
 
 use crate::build_tools::is_strict;
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{EitherTime, Input};
+use crate::input::{DateTimeFormats, EitherTime, Input};
 use crate::tools::SchemaDict;
 
 use super::datetime::extract_microseconds_precision;
 use super::datetime::TZConstraint;
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct TimeValidator {
     strict: bool,
     constraints: Option<TimeConstraints>,
     microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
+    formats: Option<DateTimeFormats>,
 }
 
 impl BuildValidator for TimeValidator {
@@ -33,6 +34,7 @@ impl BuildValidator for TimeValidator {
             strict: is_strict(schema, config)?,
             constraints: TimeConstraints::from_py(schema)?,
             microseconds_precision: extract_microseconds_precision(schema, config)?,
+            formats: DateTimeFormats::from_schema(schema)?,
         };
         Ok(s.into())
     }
@@ -47,9 +49,20 @@ impl Validator for TimeValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let time = input
-            .validate_time(state.strict_or(self.strict), self.microseconds_precision)?
-            .unpack(state);
+        let strict = state.strict_or(self.strict);
+        let formatted = match &self.formats {
+            Some(formats) if !strict => formats.parse(input).and_then(|parts| parts.time()),
+            _ => None,
+        };
+        let time = if let Some(time) = formatted {
+            state.floor_exactness(Exactness::Lax);
+            state.record_coercion(input, "time");
+            EitherTime::Raw(time)
+        } else {
+            input
+                .validate_time(strict, self.microseconds_precision)?
+                .unpack_coerced(state, input, "time")
+        };
         if let Some(constraints) = &self.constraints {
             let raw_time = time.as_raw()?;
 
@@ -78,7 +78,7 @@ impl Validator for TimeDeltaValidator {
     ) -> ValResult<PyObject> {
         let timedelta = input
             .validate_timedelta(state.strict_or(self.strict), self.microseconds_precision)?
-            .unpack(state);
+            .unpack_coerced(state, input, "timedelta");
         let py_timedelta = timedelta.clone().into_pyobject(py)?;
         if let Some(constraints) = &self.constraints {
             let raw_timedelta = timedelta.to_duration()?;
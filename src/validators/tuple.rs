@@ -1,15 +1,18 @@
 use std::collections::VecDeque;
+use std::ffi::CString;
 
+use pyo3::exceptions::PyUserWarning;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyTuple};
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_strict, py_schema_err};
 use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::ConsumeIterator;
 use crate::input::{BorrowInput, Input, ValidatedTuple};
 use crate::tools::SchemaDict;
 
+use super::list::UniqueItems;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -19,8 +22,11 @@ pub struct TupleValidator {
     variadic_item_index: Option<usize>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    variadic_min_length: Option<usize>,
+    variadic_max_length: Option<usize>,
     name: String,
     fail_fast: bool,
+    unique_items: Option<UniqueItems>,
 }
 
 impl BuildValidator for TupleValidator {
@@ -39,6 +45,26 @@ impl BuildValidator for TupleValidator {
 
         let mut validator_names = validators.iter().map(Validator::get_name).collect::<Vec<_>>();
         let variadic_item_index: Option<usize> = schema.get_as(intern!(py, "variadic_item_index"))?;
+
+        // only trailing items of the positional head are padded with their default, a default followed by a
+        // required item is never used
+        let head_validators = &validators[..variadic_item_index.unwrap_or(validators.len()).min(validators.len())];
+        let has_default = |v: &CombinedValidator| matches!(v, CombinedValidator::WithDefault(v) if v.has_default());
+        if let Some(first_default) = head_validators.iter().position(has_default) {
+            if let Some(required) = head_validators[first_default..].iter().position(|v| !has_default(v)) {
+                let message = format!(
+                    "Tuple item {first_default} has a default but is followed by required item {}, \
+                     so its default is never used",
+                    first_default + required
+                );
+                PyErr::warn(py, &py.get_type::<PyUserWarning>(), &CString::new(message)?, 0)?;
+            }
+        }
+        let variadic_min_length: Option<usize> = schema.get_as(intern!(py, "variadic_min_length"))?;
+        let variadic_max_length: Option<usize> = schema.get_as(intern!(py, "variadic_max_length"))?;
+        if variadic_item_index.is_none() && (variadic_min_length.is_some() || variadic_max_length.is_some()) {
+            return py_schema_err!("`variadic_min_length` and `variadic_max_length` require `variadic_item_index`");
+        }
         // FIXME add friendly schema error if item out of bounds
         if let Some(variadic_item_index) = variadic_item_index {
             validator_names.insert(variadic_item_index + 1, "...");
@@ -51,14 +77,20 @@ impl BuildValidator for TupleValidator {
             variadic_item_index,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            variadic_min_length,
+            variadic_max_length,
             name,
             fail_fast: schema.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
+            unique_items: UniqueItems::from_schema(schema)?,
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(TupleValidator { validators });
+impl_py_gc_traverse!(TupleValidator {
+    validators,
+    unique_items
+});
 
 impl TupleValidator {
     #[allow(clippy::too_many_arguments)]
@@ -77,17 +109,33 @@ impl TupleValidator {
         // Validate the head:
         for validator in item_validators {
             match collection_iter.next() {
-                Some((index, input_item)) => match validator.validate(py, input_item.borrow_input(), state) {
-                    Ok(item) => self.push_output_item(input, output, item, actual_length)?,
-                    Err(ValError::LineErrors(line_errors)) => {
-                        errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                Some((index, input_item)) => {
+                    let report_mark = state.report_mark();
+                    state.field_path.push_index(index);
+                    let result = validator.validate(py, input_item.borrow_input(), state);
+                    state.field_path.pop();
+                    match result {
+                        Ok(item) => {
+                            state.reports_with_outer_location(report_mark, index);
+                            self.push_output_item(input, output, item, actual_length)?;
+                        }
+                        Err(ValError::LineErrors(line_errors)) => {
+                            errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                        }
+                        Err(ValError::Omit) => {
+                            state.omitted_with_outer_location(report_mark, index);
+                        }
+                        Err(err) => return Err(err),
                     }
-                    Err(ValError::Omit) => (),
-                    Err(err) => return Err(err),
-                },
+                }
                 None => {
                     let index = collection_iter.next_calls() - 1;
-                    if let Some(value) = validator.default_value(py, Some(index), state)? {
+                    // items within `min_length` must be in the input, so aren't padded with their default
+                    let padded = match index >= self.min_length.unwrap_or(0) {
+                        true => validator.default_value(py, Some(index), state)?,
+                        false => None,
+                    };
+                    if let Some(value) = padded {
                         output.push(value);
                     } else {
                         errors.push(ValLineError::new_with_loc(ErrorTypeDefaults::Missing, input, index));
@@ -142,15 +190,27 @@ impl TupleValidator {
                 return Ok(output);
             }
 
+            let errors_before = errors.len();
+            let mut variadic_length = 0;
             let n_tail_validators = tail_validators.len();
             if n_tail_validators == 0 {
                 for (index, input_item) in collection_iter {
-                    match variable_validator.validate(py, input_item.borrow_input(), state) {
-                        Ok(item) => self.push_output_item(input, &mut output, item, actual_length)?,
+                    variadic_length += 1;
+                    let report_mark = state.report_mark();
+                    state.field_path.push_index(index);
+                    let result = variable_validator.validate(py, input_item.borrow_input(), state);
+                    state.field_path.pop();
+                    match result {
+                        Ok(item) => {
+                            state.reports_with_outer_location(report_mark, index);
+                            self.push_output_item(input, &mut output, item, actual_length)?;
+                        }
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
                         }
-                        Err(ValError::Omit) => (),
+                        Err(ValError::Omit) => {
+                            state.omitted_with_outer_location(report_mark, index);
+                        }
                         Err(err) => return Err(err),
                     }
 
@@ -158,6 +218,9 @@ impl TupleValidator {
                         return Ok(output);
                     }
                 }
+                if errors.len() == errors_before {
+                    self.check_variadic_length(input, errors, head_validators.len(), variadic_length);
+                }
             } else {
                 // Populate a buffer with the first n_tail_validators items
                 // NB: We take from collection_iter.inner to avoid increasing the next calls count
@@ -171,13 +234,21 @@ impl TupleValidator {
                 // Iterate over all remaining collection items, validating as items "leave" the buffer
                 for (buffer_item_index, input_item) in collection_iter {
                     index = buffer_item_index;
+                    variadic_length += 1;
                     // This `unwrap` is safe because you can only get here
                     // if there were at least `n_tail_validators` (> 0) items in the iterator
                     let buffered_item = tail_buffer.pop_front().unwrap();
                     tail_buffer.push_back(input_item);
 
-                    match variable_validator.validate(py, buffered_item.borrow_input(), state) {
-                        Ok(item) => self.push_output_item(input, &mut output, item, actual_length)?,
+                    let report_mark = state.report_mark();
+                    state.field_path.push_index(buffer_item_index);
+                    let result = variable_validator.validate(py, buffered_item.borrow_input(), state);
+                    state.field_path.pop();
+                    match result {
+                        Ok(item) => {
+                            state.reports_with_outer_location(report_mark, buffer_item_index);
+                            self.push_output_item(input, &mut output, item, actual_length)?;
+                        }
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(
                                 line_errors
@@ -185,7 +256,9 @@ impl TupleValidator {
                                     .map(|err| err.with_outer_location(buffer_item_index)),
                             );
                         }
-                        Err(ValError::Omit) => (),
+                        Err(ValError::Omit) => {
+                            state.omitted_with_outer_location(report_mark, buffer_item_index);
+                        }
                         Err(err) => return Err(err),
                     }
 
@@ -193,6 +266,9 @@ impl TupleValidator {
                         return Ok(output);
                     }
                 }
+                if errors.len() == errors_before {
+                    self.check_variadic_length(input, errors, head_validators.len(), variadic_length);
+                }
 
                 // Validate the buffered items using the tail validators
                 self.validate_tuple_items(
@@ -241,6 +317,33 @@ impl TupleValidator {
         Ok(output)
     }
 
+    /// Checks the number of input items in the variadic section, errors are located at the index the
+    /// section starts from
+    fn check_variadic_length<'py>(
+        &self,
+        input: &(impl Input<'py> + ?Sized),
+        errors: &mut Vec<ValLineError>,
+        start_index: usize,
+        actual_length: usize,
+    ) {
+        let error_type = match (self.variadic_min_length, self.variadic_max_length) {
+            (Some(min_length), _) if actual_length < min_length => ErrorType::TooShort {
+                field_type: "Tuple variadic section".to_string(),
+                min_length,
+                actual_length,
+                context: None,
+            },
+            (_, Some(max_length)) if actual_length > max_length => ErrorType::TooLong {
+                field_type: "Tuple variadic section".to_string(),
+                max_length,
+                actual_length: Some(actual_length),
+                context: None,
+            },
+            _ => return,
+        };
+        errors.push(ValLineError::new_with_loc(error_type, input, start_index));
+    }
+
     fn push_output_item<'py>(
         &self,
         input: &(impl Input<'py> + ?Sized),
@@ -276,7 +379,9 @@ impl Validator for TupleValidator {
         // this validator does not yet support partial validation, disable it to avoid incorrect results
         state.allow_partial = false.into();
 
-        let collection = input.validate_tuple(state.strict_or(self.strict))?.unpack(state);
+        let collection = input
+            .validate_tuple(state.strict_or(self.strict))?
+            .unpack_coerced(state, input, "tuple");
         let actual_length = collection.len();
 
         let mut errors: Vec<ValLineError> = Vec::new();
@@ -306,6 +411,9 @@ impl Validator for TupleValidator {
         }
 
         if errors.is_empty() {
+            if let Some(ref unique_items) = self.unique_items {
+                unique_items.check(py, &output)?;
+            }
             Ok(PyTuple::new(py, output)?.into())
         } else {
             Err(ValError::LineErrors(errors))
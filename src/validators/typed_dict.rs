@@ -3,9 +3,11 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString, PyType};
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config, ExtraBehavior};
+use crate::build_tools::{
+    alias_or_generated, is_strict, nested_config, schema_or_config, AliasGenerator, ExtraBehavior,
+};
 use crate::errors::LocItem;
-use crate::errors::{ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::BorrowInput;
 use crate::input::ConsumeIterator;
 use crate::input::ValidationMatch;
@@ -15,6 +17,7 @@ use crate::tools::SchemaDict;
 use ahash::AHashSet;
 use jiter::PartialMode;
 
+use super::model_fields::{ExtraKeyValidator, ExtrasPatternValidators};
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -33,6 +36,9 @@ pub struct TypedDictValidator {
     fields: Vec<TypedDictField>,
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Box<CombinedValidator>>,
+    extras_pattern_validators: ExtrasPatternValidators,
+    forbidden_extra_keys: AHashSet<String>,
+    combine_extra_errors: bool,
     strict: bool,
     loc_by_alias: bool,
     validate_by_alias: Option<bool>,
@@ -45,13 +51,13 @@ impl BuildValidator for TypedDictValidator {
 
     fn build(
         schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        config: Option<&Bound<'_, PyDict>>,
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
 
-        // typed dicts ignore the parent config and always use the config from this TypedDict
-        let config = schema.get_as(intern!(py, "config"))?;
+        // typed dicts use the config from this TypedDict, combined with the parent config according to `config_merge`
+        let config = nested_config(schema, config)?;
         let config = config.as_ref();
 
         let strict = is_strict(schema, config)?;
@@ -66,6 +72,12 @@ impl BuildValidator for TypedDictValidator {
             (Some(_), _) => return py_schema_err!("extras_schema can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
+        let extras_pattern_validators = ExtrasPatternValidators::build(schema, config, definitions, extra_behavior)?;
+        let forbidden_extra_keys: AHashSet<String> = schema
+            .get_as::<Vec<String>>(intern!(py, "forbidden_extra_keys"))?
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
 
         let fields_dict: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: Vec<TypedDictField> = Vec::with_capacity(fields_dict.len());
@@ -78,10 +90,14 @@ impl BuildValidator for TypedDictValidator {
             },
         };
 
+        let alias_generator = AliasGenerator::validation(config)?;
         for (key, value) in fields_dict {
             let field_info = value.downcast::<PyDict>()?;
             let field_name_py = key.downcast_into::<PyString>()?;
             let field_name = field_name_py.to_str()?;
+            if forbidden_extra_keys.contains(field_name) {
+                return py_schema_err!("Field '{}' cannot also be in forbidden_extra_keys", field_name);
+            }
 
             let schema = field_info.get_as_req(intern!(py, "schema"))?;
 
@@ -118,7 +134,12 @@ impl BuildValidator for TypedDictValidator {
                 }
             }
 
-            let validation_alias = field_info.get_item(intern!(py, "validation_alias"))?;
+            let validation_alias = alias_or_generated(
+                py,
+                field_info.get_item(intern!(py, "validation_alias"))?,
+                alias_generator,
+                field_name,
+            );
             let lookup_key_collection = LookupKeyCollection::new(py, validation_alias, field_name)?;
 
             fields.push(TypedDictField {
@@ -133,6 +154,9 @@ impl BuildValidator for TypedDictValidator {
             fields,
             extra_behavior,
             extras_validator,
+            extras_pattern_validators,
+            forbidden_extra_keys,
+            combine_extra_errors: schema.get_as(intern!(py, "combine_extra_errors"))?.unwrap_or(false),
             strict,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
             validate_by_alias: config.get_as(intern!(py, "validate_by_alias"))?,
@@ -145,7 +169,8 @@ impl BuildValidator for TypedDictValidator {
 
 impl_py_gc_traverse!(TypedDictValidator {
     fields,
-    extras_validator
+    extras_validator,
+    extras_pattern_validators
 });
 
 impl Validator for TypedDictValidator {
@@ -154,6 +179,23 @@ impl Validator for TypedDictValidator {
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        state.memoize(py, self, false, input, |state| {
+            self.validate_typed_dict(py, input, state)
+        })
+    }
+
+    fn get_name(&self) -> &str {
+        self.cls_name.as_deref().unwrap_or(Self::EXPECTED_TYPE)
+    }
+}
+
+impl TypedDictValidator {
+    fn validate_typed_dict<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
         let dict = input.validate_dict(strict)?;
@@ -173,12 +215,14 @@ impl Validator for TypedDictValidator {
 
         // we only care about which keys have been used if we're iterating over the object for extra after
         // the first pass
-        let mut used_keys: Option<AHashSet<&str>> =
-            if self.extra_behavior == ExtraBehavior::Ignore || dict.is_py_get_attr() {
-                None
-            } else {
-                Some(AHashSet::with_capacity(self.fields.len()))
-            };
+        let mut used_keys: Option<AHashSet<&str>> = if (self.extra_behavior == ExtraBehavior::Ignore
+            && self.forbidden_extra_keys.is_empty())
+            || dict.is_py_get_attr()
+        {
+            None
+        } else {
+            Some(AHashSet::with_capacity(self.fields.len()))
+        };
 
         {
             let state = &mut state.rebind_extra(|extra| extra.data = Some(output_dict.clone()));
@@ -218,15 +262,20 @@ impl Validator for TypedDictValidator {
                         true => allow_partial,
                         false => false.into(),
                     };
-                    let state =
-                        &mut state.rebind_extra(|extra| extra.field_name = Some(field.name_py.bind(py).clone()));
+                    let state = &mut state.enter_field(field.name_py.bind(py), &field.name);
 
+                    let report_mark = state.report_mark();
+                    state.record_alias_hit(|| lookup_path.to_alias(py).map(Bound::unbind))?;
                     match field.validator.validate(py, value.borrow_input(), state) {
                         Ok(value) => {
+                            state.reports_with_outer_location(report_mark, field.name.as_str());
                             output_dict.set_item(&field.name_py, value)?;
                             fields_set_count += 1;
                         }
-                        Err(ValError::Omit) => continue,
+                        Err(ValError::Omit) => {
+                            state.omitted_with_outer_location(report_mark, field.name.as_str());
+                            continue;
+                        }
                         Err(ValError::LineErrors(line_errors)) => {
                             if !is_last_partial || field.required {
                                 for err in line_errors {
@@ -278,6 +327,10 @@ impl Validator for TypedDictValidator {
                 used_keys: AHashSet<&'a str>,
                 errors: &'a mut Vec<ValLineError>,
                 extras_validator: Option<&'a CombinedValidator>,
+                extras_pattern_validators: &'a ExtrasPatternValidators,
+                forbidden_extra_keys: &'a AHashSet<String>,
+                // with `combine_extra_errors`, forbidden keys are collected for a single error
+                combined_forbidden_keys: Option<&'a mut Vec<String>>,
                 output_dict: &'a Bound<'py, PyDict>,
                 state: &'a mut ValidationState<'s, 'py>,
                 extra_behavior: ExtraBehavior,
@@ -291,7 +344,10 @@ impl Validator for TypedDictValidator {
                 Value: BorrowInput<'py>,
             {
                 type Output = ValResult<()>;
-                fn consume_iterator(self, iterator: impl Iterator<Item = ValResult<(Key, Value)>>) -> ValResult<()> {
+                fn consume_iterator(
+                    mut self,
+                    iterator: impl Iterator<Item = ValResult<(Key, Value)>>,
+                ) -> ValResult<()> {
                     for item_result in iterator {
                         let (raw_key, value) = item_result?;
                         let either_str = match raw_key
@@ -318,18 +374,38 @@ impl Validator for TypedDictValidator {
 
                         let value = value.borrow_input();
                         // Unknown / extra field
-                        match self.extra_behavior {
-                            ExtraBehavior::Forbid => {
-                                self.errors.push(ValLineError::new_with_loc(
+                        if self.extra_behavior == ExtraBehavior::Forbid
+                            || self.forbidden_extra_keys.contains(cow.as_ref())
+                        {
+                            match self.combined_forbidden_keys {
+                                Some(ref mut keys) => keys.push(cow.into_owned()),
+                                None => self.errors.push(ValLineError::new_with_loc(
                                     ErrorTypeDefaults::ExtraForbidden,
                                     value,
                                     raw_key.clone(),
-                                ));
+                                )),
                             }
-                            ExtraBehavior::Ignore => {}
+                            continue;
+                        }
+                        match self.extra_behavior {
+                            // forbidden keys are handled above
+                            ExtraBehavior::Forbid | ExtraBehavior::Ignore => {}
                             ExtraBehavior::Allow => {
+                                let extras_validator =
+                                    match self.extras_pattern_validators.select(&cow, self.extras_validator) {
+                                        ExtraKeyValidator::Any => None,
+                                        ExtraKeyValidator::Validate(validator) => Some(validator),
+                                        ExtraKeyValidator::Forbidden => {
+                                            self.errors.push(ValLineError::new_with_loc(
+                                                ErrorTypeDefaults::ExtraForbidden,
+                                                value,
+                                                raw_key.clone(),
+                                            ));
+                                            continue;
+                                        }
+                                    };
                                 let py_key = either_str.as_py_string(self.py, self.state.cache_str());
-                                if let Some(validator) = self.extras_validator {
+                                if let Some(validator) = extras_validator {
                                     let last_partial = self.partial_last_key.as_ref() == Some(&raw_key.clone().into());
                                     self.state.allow_partial = match last_partial {
                                         true => self.allow_partial,
@@ -359,17 +435,36 @@ impl Validator for TypedDictValidator {
                 }
             }
 
+            let mut combined_forbidden_keys = Vec::new();
             dict.iterate(ValidateExtras {
                 used_keys,
                 py,
                 errors: &mut errors,
                 extras_validator: self.extras_validator.as_deref(),
+                extras_pattern_validators: &self.extras_pattern_validators,
+                forbidden_extra_keys: &self.forbidden_extra_keys,
+                combined_forbidden_keys: self.combine_extra_errors.then_some(&mut combined_forbidden_keys),
                 output_dict: &output_dict,
                 state,
                 extra_behavior: self.extra_behavior,
                 partial_last_key,
                 allow_partial,
             })??;
+
+            if !combined_forbidden_keys.is_empty() {
+                let extra_keys = combined_forbidden_keys
+                    .iter()
+                    .map(|key| format!("'{key}'"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                errors.push(ValLineError::new(
+                    ErrorType::ExtraKeysForbidden {
+                        extra_keys,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
         }
 
         if errors.is_empty() {
@@ -378,8 +473,4 @@ impl Validator for TypedDictValidator {
             Err(ValError::LineErrors(errors))
         }
     }
-
-    fn get_name(&self) -> &str {
-        self.cls_name.as_deref().unwrap_or(Self::EXPECTED_TYPE)
-    }
 }
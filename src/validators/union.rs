@@ -10,14 +10,16 @@ use smallvec::SmallVec;
 use crate::build_tools::py_schema_err;
 use crate::build_tools::schema_or_config;
 use crate::common::union::{Discriminator, SMALL_UNION_THRESHOLD};
-use crate::errors::{ErrorType, ToErrorValue, ValError, ValLineError, ValResult};
+use crate::errors::{ErrorType, Location, ToErrorValue, ValError, ValLineError, ValResult};
 use crate::input::{BorrowInput, Input, ValidatedDict};
+use crate::lookup_key::LookupKey;
 use crate::tools::SchemaDict;
 
 use super::custom_error::CustomError;
 use super::literal::LiteralLookup;
 use super::{
-    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, TakenReports, ValidationState,
+    Validator,
 };
 
 #[derive(Debug)]
@@ -38,10 +40,41 @@ impl FromStr for UnionMode {
     }
 }
 
+/// How the errors of each choice are reported when no choice matches
+#[derive(Debug, Clone, Copy, Default)]
+enum UnionErrorMode {
+    // all errors from every choice
+    #[default]
+    All,
+    // only the errors of the closest matching choice
+    BestMatch,
+    // the errors of the closest matching choice, with a single error summarizing each other choice
+    Summarize,
+}
+
+impl FromStr for UnionErrorMode {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            "best-match" => Ok(Self::BestMatch),
+            "summarize" => Ok(Self::Summarize),
+            s => py_schema_err!(
+                "Invalid union error mode: `{}`, expected `all`, `best-match` or `summarize`",
+                s
+            ),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct UnionValidator {
     mode: UnionMode,
+    error_mode: UnionErrorMode,
     choices: Vec<(CombinedValidator, Option<String>)>,
+    // one per choice, empty unless `priorities` is set
+    priorities: Vec<ChoicePriority>,
     custom_error: Option<CustomError>,
     name: String,
 }
@@ -76,6 +109,31 @@ impl BuildValidator for UnionValidator {
         let mode = schema
             .get_as::<Bound<'_, PyString>>(intern!(py, "mode"))?
             .map_or(Ok(UnionMode::Smart), |mode| mode.to_str().and_then(UnionMode::from_str))?;
+        let error_mode = schema_or_config::<Bound<'_, PyString>>(
+            schema,
+            config,
+            intern!(py, "error_mode"),
+            intern!(py, "union_error_mode"),
+        )?
+        .map_or(Ok(UnionErrorMode::default()), |mode| {
+            mode.to_str().and_then(UnionErrorMode::from_str)
+        })?;
+        let priorities = match schema.get_as::<Vec<i64>>(intern!(py, "priorities"))? {
+            Some(priorities) => {
+                if !matches!(mode, UnionMode::Smart) {
+                    return py_schema_err!("'priorities' can only be used with the `smart` union mode");
+                }
+                if priorities.len() != choices.len() {
+                    return py_schema_err!(
+                        "'priorities' must have one value per choice, expected {}, got {}",
+                        choices.len(),
+                        priorities.len()
+                    );
+                }
+                ChoicePriority::from_priorities(&priorities)
+            }
+            None => Vec::new(),
+        };
         match choices.len() {
             0 => py_schema_err!("One or more union choices required"),
             1 if auto_collapse() => Ok(choices.into_iter().next().unwrap().0),
@@ -88,7 +146,9 @@ impl BuildValidator for UnionValidator {
 
                 Ok(Self {
                     mode,
+                    error_mode,
                     choices,
+                    priorities,
                     custom_error: CustomError::build(schema, config, definitions)?,
                     name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
                 }
@@ -98,33 +158,84 @@ impl BuildValidator for UnionValidator {
     }
 }
 
+/// output, exactness, fields set count, priority and name of the best choice so far in smart mode
+type BestMatch<'a> = (Py<PyAny>, Exactness, Option<usize>, i64, &'a str);
+
+#[derive(Debug, Clone, Copy)]
+struct ChoicePriority {
+    priority: i64,
+    // whether a later choice has a higher priority, if so an exact match can't be returned immediately
+    outranked: bool,
+}
+
+impl ChoicePriority {
+    fn from_priorities(priorities: &[i64]) -> Vec<Self> {
+        let mut max_later = i64::MIN;
+        let mut choice_priorities: Vec<Self> = priorities
+            .iter()
+            .rev()
+            .map(|&priority| {
+                let outranked = max_later > priority;
+                max_later = max_later.max(priority);
+                Self { priority, outranked }
+            })
+            .collect();
+        choice_priorities.reverse();
+        choice_priorities
+    }
+}
+
 impl UnionValidator {
+    fn choice_priority(&self, index: usize) -> ChoicePriority {
+        self.priorities.get(index).copied().unwrap_or(ChoicePriority {
+            priority: 0,
+            outranked: false,
+        })
+    }
+
     fn validate_smart<'py>(
         &self,
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
-    ) -> ValResult<PyObject> {
+    ) -> ValResult<(PyObject, &str)> {
         let old_exactness = state.exactness;
         let old_fields_set_count = state.fields_set_count;
 
         let mut errors = MaybeErrors::new(self.custom_error.as_ref());
 
-        let mut best_match: Option<(Py<PyAny>, Exactness, Option<usize>)> = None;
+        let mut best_match: Option<BestMatch<'_>> = None;
+        // only the coercions and suppressed errors of the selected choice are kept
+        let report_mark = state.report_mark();
+        let mut best_reports = TakenReports::default();
 
-        for (choice, label) in &self.choices {
+        for (index, (choice, label)) in self.choices.iter().enumerate() {
             state.exactness = Some(Exactness::Exact);
             state.fields_set_count = None;
             let result = choice.validate(py, input, state);
+            let choice_reports = state.take_reports(report_mark);
+            let choice_name = label.as_deref().unwrap_or(choice.get_name());
+            self.trace_branch(py, state, choice_name, &result)?;
+            let choice_priority = self.choice_priority(index);
+            // with priorities, an exact match is only final if no other exact match could take precedence
+            let is_final = self.priorities.is_empty()
+                || (!choice_priority.outranked
+                    && best_match
+                        .as_ref()
+                        .map_or(true, |(_, cur_exactness, _, cur_priority, _)| {
+                            *cur_exactness < Exactness::Exact || *cur_priority < choice_priority.priority
+                        }));
             match result {
                 Ok(new_success) => match (state.exactness, state.fields_set_count) {
-                    (Some(Exactness::Exact), None) => {
+                    (Some(Exactness::Exact), None) if is_final => {
                         // exact match with no fields set data, return immediately
                         return {
                             // exact match, return, restore any previous exactness
                             state.exactness = old_exactness;
                             state.fields_set_count = old_fields_set_count;
-                            Ok(new_success)
+                            state.restore_reports(choice_reports);
+                            self.trace_selected(py, state, choice_name)?;
+                            Ok((new_success, choice_name))
                         };
                     }
                     _ => {
@@ -140,18 +251,28 @@ impl UnionValidator {
                         // if the fields_set_count is the same, we use the exactness as a tie breaker to determine the best match.
                         // if the fields_set_count is not available for either the current best match or the new candidate,
                         // we use the exactness to determine the best match.
-                        let new_success_is_best_match: bool =
-                            best_match
-                                .as_ref()
-                                .map_or(true, |(_, cur_exactness, cur_fields_set_count)| {
-                                    match (*cur_fields_set_count, new_fields_set_count) {
-                                        (Some(cur), Some(new)) if cur != new => cur < new,
-                                        _ => *cur_exactness < new_exactness,
-                                    }
-                                });
+                        // if the exactness is also the same, the choice with the higher priority wins, then the first.
+                        let new_priority = choice_priority.priority;
+                        let new_success_is_best_match: bool = best_match.as_ref().map_or(
+                            true,
+                            |(_, cur_exactness, cur_fields_set_count, cur_priority, _)| match (
+                                *cur_fields_set_count,
+                                new_fields_set_count,
+                            ) {
+                                (Some(cur), Some(new)) if cur != new => cur < new,
+                                _ => (*cur_exactness, *cur_priority) < (new_exactness, new_priority),
+                            },
+                        );
 
                         if new_success_is_best_match {
-                            best_match = Some((new_success, new_exactness, new_fields_set_count));
+                            best_match = Some((
+                                new_success,
+                                new_exactness,
+                                new_fields_set_count,
+                                new_priority,
+                                choice_name,
+                            ));
+                            best_reports = choice_reports;
                         }
                     }
                 },
@@ -161,7 +282,7 @@ impl UnionValidator {
                         errors.push(choice, label.as_deref(), lines);
                     }
                 }
-                otherwise => return otherwise,
+                Err(err) => return Err(err),
             }
         }
 
@@ -169,16 +290,18 @@ impl UnionValidator {
         state.exactness = old_exactness;
         state.fields_set_count = old_fields_set_count;
 
-        if let Some((best_match, exactness, fields_set_count)) = best_match {
+        if let Some((best_match, exactness, fields_set_count, _, choice_name)) = best_match {
             state.floor_exactness(exactness);
             if let Some(count) = fields_set_count {
                 state.add_fields_set(count);
             }
-            return Ok(best_match);
+            state.restore_reports(best_reports);
+            self.trace_selected(py, state, choice_name)?;
+            return Ok((best_match, choice_name));
         }
 
         // no matches, build errors
-        Err(errors.into_val_error(input))
+        Err(errors.into_val_error(input, self.error_mode))
     }
 
     fn validate_left_to_right<'py>(
@@ -186,17 +309,55 @@ impl UnionValidator {
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
-    ) -> ValResult<PyObject> {
+    ) -> ValResult<(PyObject, &str)> {
         let mut errors = MaybeErrors::new(self.custom_error.as_ref());
 
         for (validator, label) in &self.choices {
-            match validator.validate(py, input, state) {
-                Err(ValError::LineErrors(lines)) => errors.push(validator, label.as_deref(), lines),
-                otherwise => return otherwise,
+            let report_mark = state.report_mark();
+            let result = validator.validate(py, input, state);
+            let choice_name = label.as_deref().unwrap_or(validator.get_name());
+            self.trace_branch(py, state, choice_name, &result)?;
+            match result {
+                Err(ValError::LineErrors(lines)) => {
+                    state.take_reports(report_mark);
+                    errors.push(validator, label.as_deref(), lines);
+                }
+                Ok(output) => {
+                    self.trace_selected(py, state, choice_name)?;
+                    return Ok((output, choice_name));
+                }
+                Err(err) => return Err(err),
             }
         }
 
-        Err(errors.into_val_error(input))
+        Err(errors.into_val_error(input, self.error_mode))
+    }
+
+    fn trace_branch(
+        &self,
+        py: Python<'_>,
+        state: &mut ValidationState<'_, '_>,
+        choice_name: &str,
+        result: &ValResult<PyObject>,
+    ) -> PyResult<()> {
+        let input_type = state.extra().input_type;
+        let Some(trace) = state.trace.as_deref_mut() else {
+            return Ok(());
+        };
+        match result {
+            Ok(_) => trace.union_branch_success(py, &self.name, choice_name, state.exactness),
+            Err(ValError::LineErrors(lines)) => {
+                trace.union_branch_rejected(py, &self.name, choice_name, lines, input_type)
+            }
+            Err(_) => Ok(()),
+        }
+    }
+
+    fn trace_selected(&self, py: Python<'_>, state: &mut ValidationState<'_, '_>, choice_name: &str) -> PyResult<()> {
+        match state.trace.as_deref_mut() {
+            Some(trace) => trace.selected(py, &self.name, None, choice_name),
+            None => Ok(()),
+        }
     }
 }
 
@@ -214,9 +375,27 @@ impl Validator for UnionValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        match self.mode {
+        let (output, _) = match self.mode {
+            UnionMode::Smart => self.validate_smart(py, input, state)?,
+            UnionMode::LeftToRight => self.validate_left_to_right(py, input, state)?,
+        };
+        Ok(output)
+    }
+
+    fn matching_choice<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Option<String>> {
+        let result = match self.mode {
             UnionMode::Smart => self.validate_smart(py, input, state),
             UnionMode::LeftToRight => self.validate_left_to_right(py, input, state),
+        };
+        match result {
+            Ok((_, choice_name)) => Ok(Some(choice_name.to_string())),
+            Err(ValError::LineErrors(_)) => Ok(None),
+            Err(err) => Err(err),
         }
     }
 
@@ -231,6 +410,29 @@ struct ChoiceLineErrors<'a> {
     line_errors: Vec<ValLineError>,
 }
 
+impl ChoiceLineErrors<'_> {
+    fn case_label(&self) -> String {
+        self.label.unwrap_or(self.choice.get_name()).to_string()
+    }
+
+    /// Lower is closer, choices which only failed at the top level (e.g. the wrong type) rank last,
+    /// otherwise the choice with the fewest errors is closest
+    fn distance(&self) -> (bool, usize) {
+        let top_level_only = self
+            .line_errors
+            .iter()
+            .all(|line_error| matches!(line_error.location, Location::Empty));
+        (top_level_only, self.line_errors.len())
+    }
+
+    fn into_line_errors(self) -> impl Iterator<Item = ValLineError> {
+        let case_label = self.case_label();
+        self.line_errors
+            .into_iter()
+            .map(move |err| err.with_outer_location(case_label.clone()))
+    }
+}
+
 enum MaybeErrors<'a> {
     Custom(&'a CustomError),
     Errors(SmallVec<[ChoiceLineErrors<'a>; SMALL_UNION_THRESHOLD]>),
@@ -255,26 +457,38 @@ impl<'a> MaybeErrors<'a> {
         }
     }
 
-    fn into_val_error(self, input: impl ToErrorValue) -> ValError {
+    fn into_val_error(self, input: impl ToErrorValue + Copy, error_mode: UnionErrorMode) -> ValError {
         match self {
             Self::Custom(custom_error) => custom_error.as_val_error(input),
-            Self::Errors(errors) => ValError::LineErrors(
-                errors
-                    .into_iter()
-                    .flat_map(
-                        |ChoiceLineErrors {
-                             choice,
-                             label,
-                             line_errors,
-                         }| {
-                            line_errors.into_iter().map(move |err| {
-                                let case_label = label.unwrap_or(choice.get_name());
-                                err.with_outer_location(case_label)
-                            })
-                        },
-                    )
-                    .collect(),
-            ),
+            Self::Errors(errors) => {
+                let best_index = match error_mode {
+                    UnionErrorMode::All => None,
+                    // `min_by_key` returns the first choice if several are equally close
+                    _ => errors
+                        .iter()
+                        .enumerate()
+                        .min_by_key(|(_, choice_errors)| choice_errors.distance())
+                        .map(|(index, _)| index),
+                };
+                let mut line_errors = Vec::new();
+                for (index, choice_errors) in errors.into_iter().enumerate() {
+                    match (error_mode, best_index) {
+                        (UnionErrorMode::BestMatch, Some(best_index)) if index != best_index => {}
+                        (UnionErrorMode::Summarize, Some(best_index)) if index != best_index => {
+                            line_errors.push(ValLineError::new_with_loc(
+                                ErrorType::UnionMemberFailed {
+                                    error_count: choice_errors.line_errors.len(),
+                                    context: None,
+                                },
+                                input,
+                                choice_errors.case_label(),
+                            ));
+                        }
+                        _ => line_errors.extend(choice_errors.into_line_errors()),
+                    }
+                }
+                ValError::LineErrors(line_errors)
+            }
         }
     }
 }
@@ -283,6 +497,8 @@ impl<'a> MaybeErrors<'a> {
 pub struct TaggedUnionValidator {
     discriminator: Discriminator,
     lookup: LiteralLookup<CombinedValidator>,
+    // tuple of the tags of all choices, only set with composite discriminators to report which value didn't match
+    composite_tags: Option<Py<PyTuple>>,
     from_attributes: bool,
     custom_error: Option<CustomError>,
     tags_repr: String,
@@ -308,7 +524,21 @@ impl BuildValidator for TaggedUnionValidator {
         let mut first = true;
         let schema_choices: Bound<PyDict> = schema.get_as_req(intern!(py, "choices"))?;
         let mut lookup_map = Vec::with_capacity(choices.len());
+        let mut composite_tags = Vec::new();
         for (choice_key, choice_schema) in schema_choices {
+            if let Discriminator::Composite(ref lookup_keys) = discriminator {
+                match choice_key.downcast::<PyTuple>() {
+                    Ok(tag) if tag.len() == lookup_keys.len() => composite_tags.push(tag.clone()),
+                    _ => {
+                        return py_schema_err!(
+                            "Tag {} should be a tuple of {} values to match the discriminator {}",
+                            choice_key.repr()?,
+                            lookup_keys.len(),
+                            discriminator_repr
+                        )
+                    }
+                }
+            }
             let validator = build_validator(&choice_schema, config, definitions)?;
             let tag_repr = choice_key.repr()?.to_string();
             if first {
@@ -324,6 +554,10 @@ impl BuildValidator for TaggedUnionValidator {
         }
 
         let lookup = LiteralLookup::new(py, lookup_map.into_iter())?;
+        let composite_tags = match discriminator {
+            Discriminator::Composite(_) => Some(PyTuple::new(py, composite_tags)?.unbind()),
+            _ => None,
+        };
 
         let key = intern!(py, "from_attributes");
         let from_attributes = schema_or_config(schema, config, key, key)?.unwrap_or(true);
@@ -331,6 +565,7 @@ impl BuildValidator for TaggedUnionValidator {
         Ok(Self {
             discriminator,
             lookup,
+            composite_tags,
             from_attributes,
             custom_error: CustomError::build(schema, config, definitions)?,
             tags_repr,
@@ -341,7 +576,11 @@ impl BuildValidator for TaggedUnionValidator {
     }
 }
 
-impl_py_gc_traverse!(TaggedUnionValidator { discriminator, lookup });
+impl_py_gc_traverse!(TaggedUnionValidator {
+    discriminator,
+    lookup,
+    composite_tags
+});
 
 impl Validator for TaggedUnionValidator {
     fn validate<'py>(
@@ -350,6 +589,38 @@ impl Validator for TaggedUnionValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        let tag = self.get_tag(py, input, state)?;
+        self.find_call_validator(py, &tag, input, state)
+    }
+
+    fn matching_choice<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Option<String>> {
+        let result = self
+            .get_tag(py, input, state)
+            .and_then(|tag| self.find_call_validator(py, &tag, input, state).map(|_| tag));
+        match result {
+            Ok(tag) => Ok(Some(tag.str()?.to_string())),
+            Err(ValError::LineErrors(_)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl TaggedUnionValidator {
+    fn get_tag<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Bound<'py, PyAny>> {
         match &self.discriminator {
             Discriminator::LookupKey(lookup_key) => {
                 let from_attributes = state.extra().from_attributes.unwrap_or(self.from_attributes);
@@ -358,27 +629,71 @@ impl Validator for TaggedUnionValidator {
                 // errors when getting attributes which should be "raised"
                 let tag = match dict.get_item(lookup_key)? {
                     Some((_, value)) => value,
-                    None => return Err(self.tag_not_found(input)),
+                    None => return Err(self.tag_not_found(input, self.discriminator_repr.clone())),
                 };
-                self.find_call_validator(py, &tag.borrow_input().to_object(py)?, input, state)
+                Ok(tag.borrow_input().to_object(py)?)
             }
             Discriminator::Function(func) => {
-                let tag: Py<PyAny> = func.call1(py, (input.to_object(py)?,))?;
-                if tag.is_none(py) {
-                    Err(self.tag_not_found(input))
+                let tag = func.bind(py).call1((input.to_object(py)?,))?;
+                if PyAnyMethods::is_none(&tag) {
+                    Err(self.tag_not_found(input, self.discriminator_repr.clone()))
                 } else {
-                    self.find_call_validator(py, tag.bind(py), input, state)
+                    Ok(tag)
                 }
             }
+            Discriminator::Composite(lookup_keys) => {
+                let from_attributes = state.extra().from_attributes.unwrap_or(self.from_attributes);
+                let dict = input.validate_model_fields(state.strict_or(false), from_attributes)?;
+                let mut tag = Vec::with_capacity(lookup_keys.len());
+                for lookup_key in lookup_keys {
+                    match dict.get_item(lookup_key)? {
+                        Some((_, value)) => tag.push(value.borrow_input().to_object(py)?),
+                        // report the key which is missing rather than the whole discriminator
+                        None => return Err(self.tag_not_found(input, lookup_key.to_string())),
+                    }
+                }
+                Ok(PyTuple::new(py, tag)?.into_any())
+            }
         }
     }
 
-    fn get_name(&self) -> &str {
-        &self.name
+    /// For composite discriminators, find the first value of `tag` which doesn't match any of the choices
+    /// which matched on the preceding values, returns the key, the value and the values which were expected
+    fn composite_mismatch(
+        &self,
+        py: Python<'_>,
+        lookup_keys: &[LookupKey],
+        tag: &Bound<'_, PyTuple>,
+    ) -> PyResult<Option<(String, String, String)>> {
+        let Some(ref composite_tags) = self.composite_tags else {
+            return Ok(None);
+        };
+        let mut candidates = composite_tags
+            .bind(py)
+            .iter()
+            .map(Bound::downcast_into::<PyTuple>)
+            .collect::<Result<Vec<_>, _>>()?;
+        for (index, (lookup_key, value)) in lookup_keys.iter().zip(tag.iter()).enumerate() {
+            let mut matching = Vec::with_capacity(candidates.len());
+            let mut expected: Vec<String> = Vec::new();
+            for candidate in candidates {
+                let candidate_value = candidate.get_item(index)?;
+                if candidate_value.eq(&value).unwrap_or(false) {
+                    matching.push(candidate);
+                }
+                let candidate_repr = candidate_value.repr()?.to_string();
+                if !expected.contains(&candidate_repr) {
+                    expected.push(candidate_repr);
+                }
+            }
+            if matching.is_empty() {
+                return Ok(Some((lookup_key.to_string(), value.to_string(), expected.join(", "))));
+            }
+            candidates = matching;
+        }
+        Ok(None)
     }
-}
 
-impl TaggedUnionValidator {
     fn find_call_validator<'py>(
         &self,
         py: Python<'py>,
@@ -387,31 +702,40 @@ impl TaggedUnionValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         if let Ok(Some((tag, validator))) = self.lookup.validate(py, tag) {
+            if let Some(trace) = state.trace.as_deref_mut() {
+                trace.selected(py, &self.name, Some(tag), validator.get_name())?;
+            }
             return match validator.validate(py, input, state) {
                 Ok(res) => Ok(res),
                 Err(err) => Err(err.with_outer_location(tag)),
             };
         }
-        match self.custom_error {
-            Some(ref custom_error) => Err(custom_error.as_val_error(input)),
-            None => Err(ValError::new(
-                ErrorType::UnionTagInvalid {
-                    discriminator: self.discriminator_repr.clone(),
-                    tag: tag.to_string(),
-                    expected_tags: self.tags_repr.clone(),
-                    context: None,
-                },
-                input,
-            )),
+        if let Some(ref custom_error) = self.custom_error {
+            return Err(custom_error.as_val_error(input));
         }
+        let mismatch = match (&self.discriminator, tag.downcast::<PyTuple>()) {
+            (Discriminator::Composite(lookup_keys), Ok(tag)) => self.composite_mismatch(py, lookup_keys, tag)?,
+            _ => None,
+        };
+        let (discriminator, tag, expected_tags) =
+            mismatch.unwrap_or_else(|| (self.discriminator_repr.clone(), tag.to_string(), self.tags_repr.clone()));
+        Err(ValError::new(
+            ErrorType::UnionTagInvalid {
+                discriminator,
+                tag,
+                expected_tags,
+                context: None,
+            },
+            input,
+        ))
     }
 
-    fn tag_not_found<'py>(&self, input: &(impl Input<'py> + ?Sized)) -> ValError {
+    fn tag_not_found<'py>(&self, input: &(impl Input<'py> + ?Sized), discriminator: String) -> ValError {
         match self.custom_error {
             Some(ref custom_error) => custom_error.as_val_error(input),
             None => ValError::new(
                 ErrorType::UnionTagNotFound {
-                    discriminator: self.discriminator_repr.clone(),
+                    discriminator,
                     context: None,
                 },
                 input,
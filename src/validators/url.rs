@@ -16,7 +16,7 @@ use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::downcast_python_input;
 use crate::input::Input;
 use crate::tools::SchemaDict;
-use crate::url::{schema_is_special, PyMultiHostUrl, PyUrl};
+use crate::url::{relative_base, schema_is_special, PyMultiHostUrl, PyUrl, RELATIVE_SCHEME};
 
 use super::literal::expected_repr_name;
 use super::Exactness;
@@ -33,6 +33,8 @@ pub struct UrlValidator {
     default_host: Option<String>,
     default_port: Option<u16>,
     default_path: Option<String>,
+    preserve_original: bool,
+    allow_relative: bool,
     name: String,
 }
 
@@ -53,6 +55,10 @@ impl BuildValidator for UrlValidator {
             default_host: schema.get_as(intern!(schema.py(), "default_host"))?,
             default_port: schema.get_as(intern!(schema.py(), "default_port"))?,
             default_path: schema.get_as(intern!(schema.py(), "default_path"))?,
+            preserve_original: schema
+                .get_as(intern!(schema.py(), "preserve_original"))?
+                .unwrap_or(false),
+            allow_relative: schema.get_as(intern!(schema.py(), "allow_relative"))?.unwrap_or(false),
             allowed_schemes,
             name,
         }
@@ -115,19 +121,32 @@ impl UrlValidator {
 
                 self.check_length(input, url_str)?;
 
-                parse_url(url_str, input, strict).map(EitherUrl::Rust)
+                let py_url = if self.allow_relative && url_str.starts_with('/') {
+                    // a path-absolute or scheme-relative reference
+                    PyUrl::new_relative(parse_url_with_base(url_str, Some(&relative_base()), input, strict)?)
+                } else {
+                    PyUrl::new(parse_url(url_str, input, strict)?)
+                };
+                if self.preserve_original {
+                    Ok(EitherUrl::Rust(py_url.with_original(url_str.to_string())))
+                } else {
+                    Ok(EitherUrl::Rust(py_url))
+                }
             }
             Err(_) => {
                 // we don't need to worry about whether the url was parsed in strict mode before,
                 // even if it was, any syntax errors would have been fixed by the first validation
                 if let Some(py_url) = downcast_python_input::<PyUrl>(input) {
-                    self.check_length(input, py_url.get().url().as_str())?;
+                    if py_url.get().is_relative() && !self.allow_relative {
+                        return Err(relative_url_err(input));
+                    }
+                    self.check_length(input, py_url.get().__str__())?;
                     Ok(EitherUrl::Py(py_url.clone()))
                 } else if let Some(multi_host_url) = downcast_python_input::<PyMultiHostUrl>(input) {
                     let url_str = multi_host_url.get().__str__();
                     self.check_length(input, &url_str)?;
 
-                    parse_url(&url_str, input, strict).map(EitherUrl::Rust)
+                    parse_url(&url_str, input, strict).map(|url| EitherUrl::Rust(PyUrl::new(url)))
                 } else {
                     Err(ValError::new(ErrorTypeDefaults::UrlType, input))
                 }
@@ -153,7 +172,7 @@ impl UrlValidator {
 
 enum EitherUrl<'py> {
     Py(Bound<'py, PyUrl>),
-    Rust(Url),
+    Rust(PyUrl),
 }
 
 impl<'py> IntoPyObject<'py> for EitherUrl<'py> {
@@ -164,7 +183,7 @@ impl<'py> IntoPyObject<'py> for EitherUrl<'py> {
     fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
         match self {
             EitherUrl::Py(py_url) => Ok(py_url),
-            EitherUrl::Rust(rust_url) => Bound::new(py, PyUrl::new(rust_url)),
+            EitherUrl::Rust(rust_url) => Bound::new(py, rust_url),
         }
     }
 }
@@ -173,17 +192,17 @@ impl CopyFromPyUrl for EitherUrl<'_> {
     fn url(&self) -> &Url {
         match self {
             EitherUrl::Py(py_url) => py_url.get().url(),
-            EitherUrl::Rust(rust_url) => rust_url,
+            EitherUrl::Rust(rust_url) => rust_url.url(),
         }
     }
 
     fn url_mut(&mut self) -> &mut Url {
         if let EitherUrl::Py(py_url) = self {
-            *self = EitherUrl::Rust(py_url.get().url().clone());
+            *self = EitherUrl::Rust(py_url.get().clone());
         }
         match self {
             EitherUrl::Py(_) => unreachable!(),
-            EitherUrl::Rust(rust_url) => rust_url,
+            EitherUrl::Rust(rust_url) => rust_url.mut_url(),
         }
     }
 }
@@ -197,6 +216,11 @@ pub struct MultiHostUrlValidator {
     default_host: Option<String>,
     default_port: Option<u16>,
     default_path: Option<String>,
+    preserve_original: bool,
+    min_hosts: Option<usize>,
+    max_hosts: Option<usize>,
+    port_required: bool,
+    userinfo_required: bool,
     name: String,
 }
 
@@ -224,6 +248,15 @@ impl BuildValidator for MultiHostUrlValidator {
             default_host,
             default_port: schema.get_as(intern!(schema.py(), "default_port"))?,
             default_path: schema.get_as(intern!(schema.py(), "default_path"))?,
+            preserve_original: schema
+                .get_as(intern!(schema.py(), "preserve_original"))?
+                .unwrap_or(false),
+            min_hosts: schema.get_as(intern!(schema.py(), "min_hosts"))?,
+            max_hosts: schema.get_as(intern!(schema.py(), "max_hosts"))?,
+            port_required: schema.get_as(intern!(schema.py(), "port_required"))?.unwrap_or(false),
+            userinfo_required: schema
+                .get_as(intern!(schema.py(), "userinfo_required"))?
+                .unwrap_or(false),
             name,
         }
         .into())
@@ -261,6 +294,7 @@ impl Validator for MultiHostUrlValidator {
             self.default_path.as_ref(),
         ) {
             Ok(()) => {
+                self.check_hosts(input, multi_url.multi_url())?;
                 // Lax rather than strict to preserve V2.4 semantic that str wins over url in union
                 state.floor_exactness(Exactness::Lax);
                 Ok(multi_url.into_py_any(py)?)
@@ -284,7 +318,12 @@ impl MultiHostUrlValidator {
 
                 self.check_length(input, || url_str.len())?;
 
-                parse_multihost_url(url_str, input, strict).map(EitherMultiHostUrl::Rust)
+                let multi_url = parse_multihost_url(url_str, input, strict)?;
+                if self.preserve_original {
+                    Ok(EitherMultiHostUrl::Rust(multi_url.with_original(url_str.to_string())))
+                } else {
+                    Ok(EitherMultiHostUrl::Rust(multi_url))
+                }
             }
             Err(_) => {
                 // we don't need to worry about whether the url was parsed in strict mode before,
@@ -293,6 +332,9 @@ impl MultiHostUrlValidator {
                     self.check_length(input, || multi_url.get().__str__().len())?;
                     Ok(EitherMultiHostUrl::Py(multi_url.clone()))
                 } else if let Some(py_url) = downcast_python_input::<PyUrl>(input) {
+                    if py_url.get().is_relative() {
+                        return Err(relative_url_err(input));
+                    }
                     self.check_length(input, || py_url.get().url().as_str().len())?;
                     Ok(EitherMultiHostUrl::Rust(PyMultiHostUrl::new(
                         py_url.get().url().clone(),
@@ -305,6 +347,54 @@ impl MultiHostUrlValidator {
         }
     }
 
+    /// check the number of hosts, and that each has a port and username if required
+    fn check_hosts<'py>(&self, input: &(impl Input<'py> + ?Sized), multi_url: &PyMultiHostUrl) -> ValResult<()> {
+        let host_urls = multi_url.host_urls();
+        if let Some(min_hosts) = self.min_hosts {
+            if host_urls.len() < min_hosts {
+                return Err(ValError::new(
+                    ErrorType::UrlTooFewHosts {
+                        min_hosts,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(max_hosts) = self.max_hosts {
+            if host_urls.len() > max_hosts {
+                return Err(ValError::new(
+                    ErrorType::UrlTooManyHosts {
+                        max_hosts,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        for (host_index, url) in host_urls.into_iter().enumerate() {
+            if self.port_required && url.port_or_known_default().is_none() {
+                return Err(ValError::new(
+                    ErrorType::UrlHostPortRequired {
+                        host_index,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+            if self.userinfo_required && url.username().is_empty() {
+                return Err(ValError::new(
+                    ErrorType::UrlHostUserinfoRequired {
+                        host_index,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        Ok(())
+    }
+
     fn check_length<'py, F>(&self, input: &(impl Input<'py> + ?Sized), func: F) -> ValResult<()>
     where
         F: FnOnce() -> usize,
@@ -342,6 +432,15 @@ impl<'py> IntoPyObject<'py> for EitherMultiHostUrl<'py> {
     }
 }
 
+impl EitherMultiHostUrl<'_> {
+    fn multi_url(&self) -> &PyMultiHostUrl {
+        match self {
+            EitherMultiHostUrl::Py(py_multi_url) => py_multi_url.get(),
+            EitherMultiHostUrl::Rust(rust_multi_url) => rust_multi_url,
+        }
+    }
+}
+
 impl CopyFromPyUrl for EitherMultiHostUrl<'_> {
     fn url(&self) -> &Url {
         match self {
@@ -480,7 +579,21 @@ fn parse_multihost_url<'py>(
     }
 }
 
+fn relative_url_err(input: impl ToErrorValue) -> ValError {
+    ValError::new(
+        ErrorType::UrlParsing {
+            error: ParseError::RelativeUrlWithoutBase.to_string(),
+            context: None,
+        },
+        input,
+    )
+}
+
 fn parse_url(url_str: &str, input: impl ToErrorValue, strict: bool) -> ValResult<Url> {
+    parse_url_with_base(url_str, None, input, strict)
+}
+
+fn parse_url_with_base(url_str: &str, base: Option<&Url>, input: impl ToErrorValue, strict: bool) -> ValResult<Url> {
     if url_str.is_empty() {
         return Err(ValError::new(
             ErrorType::UrlParsing {
@@ -497,6 +610,7 @@ fn parse_url(url_str: &str, input: impl ToErrorValue, strict: bool) -> ValResult
         // and unlike other parser style validators
         let vios: RefCell<Option<SyntaxViolation>> = RefCell::new(None);
         let r = Url::options()
+            .base_url(base)
             .syntax_violation_callback(Some(&|v| {
                 match v {
                     // telling users offer about credentials in URLs doesn't really make sense in this context
@@ -529,7 +643,7 @@ fn parse_url(url_str: &str, input: impl ToErrorValue, strict: bool) -> ValResult
             )),
         }
     } else {
-        Url::parse(url_str).map_err(move |e| {
+        Url::options().base_url(base).parse(url_str).map_err(move |e| {
             ValError::new(
                 ErrorType::UrlParsing {
                     error: e.to_string(),
@@ -554,7 +668,12 @@ fn check_sub_defaults(
         context: None,
     };
 
-    if !url.url().has_host() {
+    // relative references without a host have an empty host on the placeholder base
+    let has_host = match url.url().host_str() {
+        Some("") => url.url().scheme() != RELATIVE_SCHEME,
+        host => host.is_some(),
+    };
+    if !has_host {
         if let Some(default_host) = default_host {
             url.url_mut().set_host(Some(default_host)).map_err(map_parse_err)?;
         } else if host_required {
@@ -3,15 +3,16 @@ use std::str::from_utf8;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyDict, PyType};
+use pyo3::types::{PyDict, PyString, PyType};
 use uuid::Uuid;
 use uuid::Variant;
 
-use crate::build_tools::is_strict;
-use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ToErrorValue, ValError, ValResult};
 use crate::input::input_as_python_instance;
 use crate::input::Input;
 use crate::input::InputType;
+use crate::input::Int;
 use crate::input::ValidationMatch;
 use crate::serializers::BytesMode;
 use crate::tools::SchemaDict;
@@ -72,6 +73,9 @@ impl From<u8> for Version {
 pub struct UuidValidator {
     strict: bool,
     version: Option<usize>,
+    allow_int: bool,
+    // 16 byte inputs are in the little-endian order of `UUID.bytes_le`
+    bytes_le: bool,
 }
 
 impl BuildValidator for UuidValidator {
@@ -85,9 +89,19 @@ impl BuildValidator for UuidValidator {
         let py = schema.py();
         // Note(lig): let's keep this conversion through the Version enum just for the sake of validation
         let version = schema.get_as::<u8>(intern!(py, "version"))?.map(Version::from);
+        let bytes_le = match schema.get_as::<Bound<'_, PyString>>(intern!(py, "bytes_order"))? {
+            None => false,
+            Some(order) => match order.to_str()? {
+                "big" => false,
+                "little" => true,
+                order => return py_schema_err!("Invalid bytes_order {:?}, expected 'big' or 'little'", order),
+            },
+        };
         Ok(Self {
             strict: is_strict(schema, config)?,
             version: version.map(usize::from),
+            allow_int: schema.get_as(intern!(py, "allow_int"))?.unwrap_or(false),
+            bytes_le,
         }
         .into())
     }
@@ -135,10 +149,18 @@ impl Validator for UuidValidator {
             // In python mode this is a coercion, in JSON mode we treat a UUID string as an
             // exact match.
             // TODO V3: we might want to remove the JSON special case
+            let strict = state.strict_or(self.strict);
+            let uuid = match self.get_uuid_from_int(input, strict)? {
+                Some(uuid) => {
+                    state.floor_exactness(Exactness::Lax);
+                    uuid
+                }
+                None => self.get_uuid(input)?,
+            };
             if state.extra().input_type == InputType::Python {
                 state.floor_exactness(Exactness::Lax);
+                state.record_coercion(input, "uuid");
             }
-            let uuid = self.get_uuid(input)?;
             // This block checks if the UUID version matches the expected version and
             // if the UUID variant conforms to RFC 9562 (superseding RFC 4122).
             // When dealing with Python inputs, UUIDs must adhere to RFC 9562 standards.
@@ -163,6 +185,32 @@ impl Validator for UuidValidator {
 }
 
 impl UuidValidator {
+    /// With `allow_int`, ints in lax mode are taken to be the 128-bit integer form of the UUID, `UUID.int`
+    fn get_uuid_from_int<'py>(&self, input: &(impl Input<'py> + ?Sized), strict: bool) -> ValResult<Option<Uuid>> {
+        if !self.allow_int || strict {
+            return Ok(None);
+        }
+        let Ok(either_int) = input.validate_int(true) else {
+            return Ok(None);
+        };
+        let int = match either_int.into_inner().as_int()? {
+            Int::I64(i) => u128::try_from(i).ok(),
+            Int::Big(b) => u128::try_from(b).ok(),
+        };
+        let int = int.ok_or_else(|| {
+            ValError::new(
+                ErrorType::UuidParsing {
+                    error: "integer should be between 0 and 2**128 - 1".to_string(),
+                    context: None,
+                },
+                input,
+            )
+        })?;
+        let uuid = Uuid::from_u128(int);
+        self.check_version(&uuid, input)?;
+        Ok(Some(uuid))
+    }
+
     fn get_uuid<'py>(&self, input: &(impl Input<'py> + ?Sized)) -> ValResult<Uuid> {
         let uuid = match input.validate_str(true, false).ok().map(ValidationMatch::into_inner) {
             Some(either_string) => {
@@ -191,7 +239,12 @@ impl UuidValidator {
                             break 'parse uuid;
                         }
                     }
-                    Uuid::from_slice(bytes_slice).map_err(|e| {
+                    let from_slice = if self.bytes_le {
+                        Uuid::from_slice_le
+                    } else {
+                        Uuid::from_slice
+                    };
+                    from_slice(bytes_slice).map_err(|e| {
                         ValError::new(
                             ErrorType::UuidParsing {
                                 error: e.to_string(),
@@ -204,6 +257,11 @@ impl UuidValidator {
             }
         };
 
+        self.check_version(&uuid, input)?;
+        Ok(uuid)
+    }
+
+    fn check_version(&self, uuid: &Uuid, input: impl ToErrorValue) -> ValResult<()> {
         if let Some(expected_version) = self.version {
             let v1 = uuid.get_version_num();
             if v1 != expected_version {
@@ -216,7 +274,7 @@ impl UuidValidator {
                 ));
             }
         }
-        Ok(uuid)
+        Ok(())
     }
 
     /// Sets the attributes in a Python type object (`py_type`) to represent a UUID class.
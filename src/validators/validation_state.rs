@@ -1,8 +1,16 @@
+use ahash::{AHashMap, AHashSet};
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyString;
+use pyo3::types::{
+    PyBool, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFloat, PyFrozenSet, PyInt, PyList, PyString, PyTime,
+    PyTuple,
+};
 
 use jiter::{PartialMode, StringCacheMode};
 
+use crate::errors::{InputValue, LocItem, Location, PyLineError, ToErrorValue, ValLineError, ValResult};
+use crate::input::{Input, InputType, LimitsState};
 use crate::recursion_guard::{ContainsRecursionState, RecursionState};
 use crate::tools::new_py_string;
 
@@ -15,6 +23,354 @@ pub enum Exactness {
     Exact,
 }
 
+impl Exactness {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Lax => "lax",
+            Self::Strict => "strict",
+            Self::Exact => "exact",
+        }
+    }
+}
+
+/// Steps recorded while validating with `trace=True`, each step is stored as a python dict
+/// so the trace can be handed back to the caller as is.
+#[derive(Default)]
+pub struct ValidationTrace {
+    events: Vec<Py<PyDict>>,
+}
+
+impl ValidationTrace {
+    fn push<'py>(&mut self, py: Python<'py>, event: &str) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("event", event)?;
+        self.events.push(dict.clone().unbind());
+        Ok(dict)
+    }
+
+    /// validation started with `validator`
+    pub fn validate(&mut self, py: Python, validator: &str) -> PyResult<()> {
+        let event = self.push(py, "validate")?;
+        event.set_item("validator", validator)
+    }
+
+    /// a union choice succeeded with the given exactness
+    pub fn union_branch_success(
+        &mut self,
+        py: Python,
+        union: &str,
+        choice: &str,
+        exactness: Option<Exactness>,
+    ) -> PyResult<()> {
+        let event = self.push(py, "union_branch")?;
+        event.set_item("union", union)?;
+        event.set_item("choice", choice)?;
+        event.set_item("outcome", "success")?;
+        event.set_item("exactness", exactness.map(Exactness::as_str))
+    }
+
+    /// a union choice was rejected, `line_errors` are the reasons why
+    pub fn union_branch_rejected(
+        &mut self,
+        py: Python,
+        union: &str,
+        choice: &str,
+        line_errors: &[ValLineError],
+        input_type: InputType,
+    ) -> PyResult<()> {
+        let errors = PyList::empty(py);
+        for line_error in line_errors {
+            let error = PyDict::new(py);
+            error.set_item("type", line_error.error_type.type_string())?;
+            error.set_item("loc", &line_error.location)?;
+            error.set_item("msg", line_error.error_type.render_message(py, input_type)?)?;
+            errors.append(error)?;
+        }
+        let event = self.push(py, "union_branch")?;
+        event.set_item("union", union)?;
+        event.set_item("choice", choice)?;
+        event.set_item("outcome", "rejected")?;
+        event.set_item("errors", errors)
+    }
+
+    /// `validator` picked `choice` from its choices, either by comparing the results of all choices
+    /// (smart unions) or by looking up `key` (tagged unions and context switches)
+    pub fn selected(
+        &mut self,
+        py: Python,
+        validator: &str,
+        key: Option<&Bound<'_, PyAny>>,
+        choice: &str,
+    ) -> PyResult<()> {
+        let event = self.push(py, "selected")?;
+        event.set_item("validator", validator)?;
+        if let Some(key) = key {
+            event.set_item("key", key)?;
+        }
+        event.set_item("choice", choice)
+    }
+
+    pub fn into_py_list(self, py: Python<'_>) -> PyResult<Bound<'_, PyList>> {
+        PyList::new(py, self.events)
+    }
+}
+
+/// Lax conversions performed while validating with `coercions=True`, e.g. `'123'` to an int
+#[derive(Default)]
+pub struct CoercionLog {
+    coercions: Vec<Coercion>,
+}
+
+pub struct Coercion {
+    // reversed like the location of line errors, containers add their part of the location once the item is validated
+    location: Location,
+    input_value: InputValue,
+    target: &'static str,
+}
+
+impl CoercionLog {
+    pub fn into_py_list(self, py: Python<'_>) -> PyResult<Bound<'_, PyList>> {
+        let coercions = PyList::empty(py);
+        for coercion in self.coercions {
+            let input_value = coercion.input_value.into_pyobject(py)?;
+            let dict = PyDict::new(py);
+            dict.set_item("loc", &coercion.location)?;
+            dict.set_item("input", &input_value)?;
+            dict.set_item("input_type", input_value.get_type().qualname()?)?;
+            dict.set_item("target", coercion.target)?;
+            coercions.append(dict)?;
+        }
+        Ok(coercions)
+    }
+}
+
+/// Errors recovered from by `on_error='default'` or `'omit'` while validating with `suppressed_errors=True`
+#[derive(Default)]
+pub struct SuppressedErrorLog {
+    errors: Vec<ValLineError>,
+}
+
+impl SuppressedErrorLog {
+    pub fn into_py_list(self, py: Python<'_>, input_type: InputType) -> PyResult<Bound<'_, PyList>> {
+        let errors = PyList::empty(py);
+        for error in self.errors {
+            let line_error = PyLineError::from_val_line_error(error);
+            errors.append(line_error.as_dict(py, None, true, input_type, true)?)?;
+        }
+        Ok(errors)
+    }
+}
+
+/// The alias, or alias path, each field was found under while validating with `alias_hits=True`
+#[derive(Default)]
+pub struct AliasHitLog {
+    hits: Vec<AliasHit>,
+}
+
+pub struct AliasHit {
+    // reversed like `Coercion::location`
+    location: Location,
+    alias: PyObject,
+}
+
+impl AliasHitLog {
+    pub fn into_py_list(self, py: Python<'_>) -> PyResult<Bound<'_, PyList>> {
+        let hits = PyList::empty(py);
+        for hit in self.hits {
+            let dict = PyDict::new(py);
+            dict.set_item("loc", &hit.location)?;
+            dict.set_item("alias", hit.alias)?;
+            hits.append(dict)?;
+        }
+        Ok(hits)
+    }
+}
+
+/// How many coercions, suppressed errors and alias hits had been recorded, from `ValidationState::report_mark`
+#[derive(Clone, Copy)]
+pub struct ReportMark {
+    coercions: Option<usize>,
+    suppressed_errors: Option<usize>,
+    alias_hits: Option<usize>,
+}
+
+/// Coercions, suppressed errors and alias hits removed by `ValidationState::take_reports`
+#[derive(Default)]
+pub struct TakenReports {
+    coercions: Vec<Coercion>,
+    suppressed_errors: Vec<ValLineError>,
+    alias_hits: Vec<AliasHit>,
+}
+
+/// Strictness of individual fields set with `strict_overrides`, keyed by the path of field names to the field,
+/// e.g. `{('address', 'postcode'): False}`. The strictness applies to the field and everything nested within it
+/// unless a longer path overrides it again.
+#[derive(Debug, Default)]
+pub struct StrictOverrides {
+    strict: Option<bool>,
+    fields: AHashMap<String, StrictOverrides>,
+}
+
+impl StrictOverrides {
+    pub fn new(overrides: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let mut root = Self::default();
+        for (loc, strict) in overrides {
+            let path: Vec<String> = match loc.downcast::<PyString>() {
+                Ok(field_name) => vec![field_name.to_str()?.to_owned()],
+                Err(_) => match loc.extract() {
+                    Ok(path) => path,
+                    Err(_) => {
+                        return Err(PyTypeError::new_err(format!(
+                            "`strict_overrides` keys must be a field name or a non-empty tuple of field names, got {}",
+                            loc.repr()?
+                        )))
+                    }
+                },
+            };
+            if path.is_empty() {
+                return Err(PyTypeError::new_err(
+                    "`strict_overrides` keys must be a field name or a non-empty tuple of field names, got ()",
+                ));
+            }
+            let node = path
+                .into_iter()
+                .fold(&mut root, |node, field_name| node.fields.entry(field_name).or_default());
+            node.strict = Some(strict.extract()?);
+        }
+        Ok(root)
+    }
+
+    /// Overrides within the field `name`, `None` if there are none
+    pub fn field(&self, name: &str) -> Option<&Self> {
+        self.fields.get(name)
+    }
+
+    pub fn strict(&self) -> Option<bool> {
+        self.strict
+    }
+}
+
+/// The fields and item indices entered from the root of validation, pushed to and popped from as validation
+/// goes, so it's only copied when a `ValidationInfo` or generator keeps it
+#[derive(Debug, Clone, Default)]
+pub struct FieldPath {
+    items: Vec<FieldPathItem>,
+}
+
+#[derive(Debug, Clone)]
+enum FieldPathItem {
+    Field(Py<PyString>),
+    Index(usize),
+}
+
+impl FieldPath {
+    pub fn push_field(&mut self, field_name: &Bound<'_, PyString>) {
+        self.items.push(FieldPathItem::Field(field_name.clone().unbind()));
+    }
+
+    pub fn push_index(&mut self, index: usize) {
+        self.items.push(FieldPathItem::Index(index));
+    }
+
+    pub fn pop(&mut self) {
+        self.items.pop();
+    }
+
+    pub fn to_tuple<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(
+            py,
+            self.items.iter().map(|item| match item {
+                FieldPathItem::Field(field_name) => field_name.bind(py).clone().into_any(),
+                FieldPathItem::Index(index) => PyInt::new(py, *index).into_any(),
+            }),
+        )
+    }
+}
+
+/// Outputs of validators cached by the identity of the validator and the python input, used when
+/// validating with `memoize=True` so shared references to the same input are only validated once.
+/// Only outputs which can't be mutated through another reference are cached, see `MemoOutput`.
+#[derive(Default)]
+pub struct ValidationMemo {
+    entries: AHashMap<MemoKey, MemoEntry>,
+    // addresses of the `MemoOutput::Shared` outputs in `entries`, which are known to be immutable
+    immutable_outputs: AHashSet<usize>,
+    // set when a validator whose output depends on more than its input runs (e.g. a function validator
+    // which can see the context), outputs of the enclosing validators are then not cached
+    context_dependent: bool,
+}
+
+/// Everything a memoized output depends on besides the input's value
+#[derive(PartialEq, Eq, Hash)]
+struct MemoKey {
+    validator: usize,
+    input: usize,
+    strict: Option<bool>,
+    strict_overrides: Option<usize>,
+    from_attributes: Option<bool>,
+}
+
+struct MemoEntry {
+    // held so the input can't be freed and its address reused for another object during the call
+    _input: PyObject,
+    output: MemoOutput,
+    exactness: Option<Exactness>,
+    fields_set_count: Option<usize>,
+}
+
+/// How a cached output is handed out again without callers sharing mutable state
+enum MemoOutput {
+    /// an instance of a frozen model or dataclass whose attributes are all immutable, reused as is
+    Shared(PyObject),
+    /// a dict whose values are all immutable, e.g. of a typed dict, each reuse gets a shallow copy
+    CopiedDict(Py<PyDict>),
+}
+
+impl ValidationMemo {
+    /// How `output` can be reused, `None` if it can't be without risking shared mutation
+    fn reusable_output(&self, output: &Bound<'_, PyAny>, frozen: bool) -> Option<MemoOutput> {
+        let py = output.py();
+        if let Ok(dict) = output.downcast_exact::<PyDict>() {
+            let immutable = dict.values().iter().all(|value| self.is_immutable(&value));
+            return immutable.then(|| MemoOutput::CopiedDict(dict.clone().unbind()));
+        }
+        if !frozen {
+            return None;
+        }
+        let attributes = output.getattr(intern!(py, "__dict__")).ok()?;
+        let attributes = attributes.downcast_exact::<PyDict>().ok()?;
+        // extra fields of a frozen model can still be mutated through `__pydantic_extra__`
+        let no_extra = output
+            .getattr(intern!(py, "__pydantic_extra__"))
+            .map_or(true, |extra| PyAnyMethods::is_none(&extra));
+        let immutable = no_extra && attributes.values().iter().all(|value| self.is_immutable(&value));
+        immutable.then(|| MemoOutput::Shared(output.clone().unbind()))
+    }
+
+    fn is_immutable(&self, value: &Bound<'_, PyAny>) -> bool {
+        if PyAnyMethods::is_none(value)
+            || value.is_exact_instance_of::<PyBool>()
+            || value.is_exact_instance_of::<PyInt>()
+            || value.is_exact_instance_of::<PyFloat>()
+            || value.is_exact_instance_of::<PyString>()
+            || value.is_exact_instance_of::<PyBytes>()
+            || value.is_exact_instance_of::<PyDate>()
+            || value.is_exact_instance_of::<PyDateTime>()
+            || value.is_exact_instance_of::<PyTime>()
+            || value.is_exact_instance_of::<PyDelta>()
+        {
+            true
+        } else if let Ok(tuple) = value.downcast_exact::<PyTuple>() {
+            tuple.iter().all(|item| self.is_immutable(&item))
+        } else if let Ok(set) = value.downcast_exact::<PyFrozenSet>() {
+            set.iter().all(|item| self.is_immutable(&item))
+        } else {
+            // e.g. a nested frozen model which was cached as immutable
+            self.immutable_outputs.contains(&(value.as_ptr() as usize))
+        }
+    }
+}
+
 pub struct ValidationState<'a, 'py> {
     pub recursion_guard: &'a mut RecursionState,
     pub exactness: Option<Exactness>,
@@ -25,6 +381,20 @@ pub struct ValidationState<'a, 'py> {
     pub fields_set_count: Option<usize>,
     // True if `allow_partial=true` and we're validating the last element of a sequence or mapping.
     pub allow_partial: PartialMode,
+    // only set when validating with `trace=True`
+    pub trace: Option<&'a mut ValidationTrace>,
+    // only set when validating with `memoize=True`
+    pub memo: Option<&'a mut ValidationMemo>,
+    // only set when validating with `coercions=True`
+    pub coercions: Option<&'a mut CoercionLog>,
+    // only set when validating with `suppressed_errors=True`
+    pub suppressed_errors: Option<&'a mut SuppressedErrorLog>,
+    // only set when validating with `alias_hits=True`
+    pub alias_hits: Option<&'a mut AliasHitLog>,
+    // only set when the config sets input limits
+    pub limits: Option<&'a mut LimitsState>,
+    // read by `ValidationInfo.field_path`, see `enter_field`
+    pub field_path: FieldPath,
     // deliberately make Extra readonly
     extra: Extra<'a, 'py>,
 }
@@ -36,10 +406,241 @@ impl<'a, 'py> ValidationState<'a, 'py> {
             exactness: None,
             fields_set_count: None,
             allow_partial,
+            trace: None,
+            memo: None,
+            coercions: None,
+            suppressed_errors: None,
+            alias_hits: None,
+            limits: None,
+            field_path: FieldPath::default(),
             extra,
         }
     }
 
+    pub fn with_trace(mut self, trace: Option<&'a mut ValidationTrace>) -> Self {
+        self.trace = trace;
+        self
+    }
+
+    pub fn with_memo(mut self, memo: Option<&'a mut ValidationMemo>) -> Self {
+        self.memo = memo;
+        self
+    }
+
+    pub fn with_coercions(mut self, coercions: Option<&'a mut CoercionLog>) -> Self {
+        self.coercions = coercions;
+        self
+    }
+
+    pub fn with_suppressed_errors(mut self, suppressed_errors: Option<&'a mut SuppressedErrorLog>) -> Self {
+        self.suppressed_errors = suppressed_errors;
+        self
+    }
+
+    pub fn with_alias_hits(mut self, alias_hits: Option<&'a mut AliasHitLog>) -> Self {
+        self.alias_hits = alias_hits;
+        self
+    }
+
+    pub fn with_limits(mut self, limits: Option<&'a mut LimitsState>) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Records that `input` was converted to `target` by a lax conversion, when validating with `coercions=True`
+    pub fn record_coercion(&mut self, input: &(impl Input<'py> + ?Sized), target: &'static str) {
+        if let Some(log) = self.coercions.as_deref_mut() {
+            log.coercions.push(Coercion {
+                location: Location::default(),
+                input_value: input.to_error_value(),
+                target,
+            });
+        }
+    }
+
+    /// Records errors which `on_error` recovered from, when validating with `suppressed_errors=True`
+    pub fn record_suppressed_errors(&mut self, errors: Vec<ValLineError>) {
+        if let Some(log) = self.suppressed_errors.as_deref_mut() {
+            log.errors.extend(errors);
+        }
+    }
+
+    /// Records that the field about to be validated was found under `alias`, when validating with
+    /// `alias_hits=True`, call after `report_mark` so the field adds its name to the location
+    pub fn record_alias_hit(&mut self, alias: impl FnOnce() -> PyResult<PyObject>) -> PyResult<()> {
+        if let Some(log) = self.alias_hits.as_deref_mut() {
+            log.hits.push(AliasHit {
+                location: Location::default(),
+                alias: alias()?,
+            });
+        }
+        Ok(())
+    }
+
+    /// The number of coercions, suppressed errors and alias hits recorded so far, pass to
+    /// `reports_with_outer_location` or `take_reports` after validating an item
+    pub fn report_mark(&self) -> ReportMark {
+        ReportMark {
+            coercions: self.coercions.as_deref().map(|log| log.coercions.len()),
+            suppressed_errors: self.suppressed_errors.as_deref().map(|log| log.errors.len()),
+            alias_hits: self.alias_hits.as_deref().map(|log| log.hits.len()),
+        }
+    }
+
+    /// Adds `loc` to the location of the coercions, suppressed errors and alias hits recorded since `mark`
+    pub fn reports_with_outer_location(&mut self, mark: ReportMark, loc: impl Into<LocItem>) {
+        let loc = loc.into();
+        if let (Some(log), Some(mark)) = (self.coercions.as_deref_mut(), mark.coercions) {
+            for coercion in log.coercions.iter_mut().skip(mark) {
+                coercion.location.with_outer(loc.clone());
+            }
+        }
+        if let (Some(log), Some(mark)) = (self.alias_hits.as_deref_mut(), mark.alias_hits) {
+            for hit in log.hits.iter_mut().skip(mark) {
+                hit.location.with_outer(loc.clone());
+            }
+        }
+        self.suppressed_errors_with_outer_location(mark, loc);
+    }
+
+    /// Drops the coercions and alias hits recorded since `mark` when an item is omitted, but keeps the errors
+    /// which led to it being omitted, with `loc` added to their location
+    pub fn omitted_with_outer_location(&mut self, mark: ReportMark, loc: impl Into<LocItem>) {
+        if let (Some(log), Some(mark)) = (self.coercions.as_deref_mut(), mark.coercions) {
+            log.coercions.truncate(mark);
+        }
+        if let (Some(log), Some(mark)) = (self.alias_hits.as_deref_mut(), mark.alias_hits) {
+            log.hits.truncate(mark);
+        }
+        self.suppressed_errors_with_outer_location(mark, loc.into());
+    }
+
+    fn suppressed_errors_with_outer_location(&mut self, mark: ReportMark, loc: LocItem) {
+        if let (Some(log), Some(mark)) = (self.suppressed_errors.as_deref_mut(), mark.suppressed_errors) {
+            for error in log.errors.iter_mut().skip(mark) {
+                error.location.with_outer(loc.clone());
+            }
+        }
+    }
+
+    /// Removes the coercions, suppressed errors and alias hits recorded since `mark`, e.g. when a union choice isn't used,
+    /// those of the choice which is used are put back with `restore_reports`
+    pub fn take_reports(&mut self, mark: ReportMark) -> TakenReports {
+        let mut taken = TakenReports::default();
+        if let (Some(log), Some(mark)) = (self.coercions.as_deref_mut(), mark.coercions) {
+            taken.coercions = log.coercions.split_off(mark);
+        }
+        if let (Some(log), Some(mark)) = (self.suppressed_errors.as_deref_mut(), mark.suppressed_errors) {
+            taken.suppressed_errors = log.errors.split_off(mark);
+        }
+        if let (Some(log), Some(mark)) = (self.alias_hits.as_deref_mut(), mark.alias_hits) {
+            taken.alias_hits = log.hits.split_off(mark);
+        }
+        taken
+    }
+
+    pub fn restore_reports(&mut self, taken: TakenReports) {
+        if let Some(log) = self.coercions.as_deref_mut() {
+            log.coercions.extend(taken.coercions);
+        }
+        if let Some(log) = self.suppressed_errors.as_deref_mut() {
+            log.errors.extend(taken.suppressed_errors);
+        }
+        if let Some(log) = self.alias_hits.as_deref_mut() {
+            log.hits.extend(taken.alias_hits);
+        }
+    }
+
+    /// Call `f` to validate `input` with `validator`, unless the same python object has already been
+    /// validated by this validator with the same settings during the current call, in which case the previous
+    /// output is reused. `frozen` is whether the output of `validator` is an instance of a frozen class.
+    pub fn memoize<V: ?Sized>(
+        &mut self,
+        py: Python<'py>,
+        validator: &V,
+        frozen: bool,
+        input: &(impl Input<'py> + ?Sized),
+        f: impl FnOnce(&mut Self) -> ValResult<PyObject>,
+    ) -> ValResult<PyObject> {
+        let (Some(memo), Some(py_input)) = (self.memo.as_deref_mut(), input.as_python()) else {
+            return f(self);
+        };
+        if self.allow_partial.is_active() {
+            return f(self);
+        }
+        let key = MemoKey {
+            validator: (validator as *const V).cast::<()>() as usize,
+            input: py_input.as_ptr() as usize,
+            strict: self.extra.strict,
+            strict_overrides: self
+                .extra
+                .strict_overrides
+                .map(|o| (o as *const StrictOverrides) as usize),
+            from_attributes: self.extra.from_attributes,
+        };
+        if let Some(entry) = memo.entries.get(&key) {
+            // the cached exactness is only known if the first validation happened within a union
+            if self.exactness.is_none() || entry.exactness.is_some() {
+                let output = match &entry.output {
+                    MemoOutput::Shared(output) => output.clone_ref(py),
+                    MemoOutput::CopiedDict(dict) => dict.bind(py).copy()?.into_any().unbind(),
+                };
+                let (exactness, fields_set_count) = (entry.exactness, entry.fields_set_count);
+                self.merge_memoized(exactness, fields_set_count);
+                return Ok(output);
+            }
+        }
+        let outer_context_dependent = std::mem::replace(&mut memo.context_dependent, false);
+        let py_input = py_input.clone().unbind();
+
+        let old_exactness = self.exactness;
+        let old_fields_set_count = self.fields_set_count.take();
+        if old_exactness.is_some() {
+            self.exactness = Some(Exactness::Exact);
+        }
+        let result = f(self);
+        let exactness = std::mem::replace(&mut self.exactness, old_exactness);
+        let fields_set_count = std::mem::replace(&mut self.fields_set_count, old_fields_set_count);
+        self.merge_memoized(exactness, fields_set_count);
+
+        if let Some(memo) = self.memo.as_deref_mut() {
+            let context_dependent = memo.context_dependent;
+            memo.context_dependent |= outer_context_dependent;
+            if let (Ok(output), false) = (&result, context_dependent) {
+                if let Some(output) = memo.reusable_output(output.bind(py), frozen) {
+                    if let MemoOutput::Shared(ref shared) = output {
+                        memo.immutable_outputs.insert(shared.as_ptr() as usize);
+                    }
+                    let entry = MemoEntry {
+                        _input: py_input,
+                        output,
+                        exactness,
+                        fields_set_count,
+                    };
+                    memo.entries.insert(key, entry);
+                }
+            }
+        }
+        result
+    }
+
+    fn merge_memoized(&mut self, exactness: Option<Exactness>, fields_set_count: Option<usize>) {
+        if let Some(exactness) = exactness {
+            self.floor_exactness(exactness);
+        }
+        if let Some(fields_set_count) = fields_set_count {
+            self.add_fields_set(fields_set_count);
+        }
+    }
+
+    /// Record that the output of the current validator depends on more than its input, so the outputs
+    /// of the validators enclosing it mustn't be memoized.
+    pub fn mark_context_dependent(&mut self) {
+        if let Some(memo) = self.memo.as_deref_mut() {
+            memo.context_dependent = true;
+        }
+    }
+
     /// Temporarily rebinds the extra field by calling `f` to modify extra.
     ///
     /// When `ValidationStateWithReboundExtra` drops, the extra field is restored to its original value.
@@ -48,8 +649,25 @@ impl<'a, 'py> ValidationState<'a, 'py> {
         f: impl FnOnce(&mut Extra<'a, 'py>),
     ) -> ValidationStateWithReboundExtra<'state, 'a, 'py> {
         let old_extra = self.extra.clone();
+        let old_field_path_len = self.field_path.items.len();
         f(&mut self.extra);
-        ValidationStateWithReboundExtra { state: self, old_extra }
+        ValidationStateWithReboundExtra {
+            state: self,
+            old_extra,
+            old_field_path_len,
+        }
+    }
+
+    /// Rebinds extra to validate a field of a model, dataclass or typed dict (see `Extra::enter_field`),
+    /// the field is added to `field_path` until the returned state is dropped
+    pub fn enter_field<'state>(
+        &'state mut self,
+        field_name: &Bound<'py, PyString>,
+        name: &str,
+    ) -> ValidationStateWithReboundExtra<'state, 'a, 'py> {
+        let rebound = self.rebind_extra(|extra| extra.enter_field(field_name.clone(), name));
+        rebound.state.field_path.push_field(field_name);
+        rebound
     }
 
     pub fn extra(&self) -> &'_ Extra<'a, 'py> {
@@ -111,6 +729,7 @@ impl ContainsRecursionState for ValidationState<'_, '_> {
 pub struct ValidationStateWithReboundExtra<'state, 'a, 'py> {
     state: &'state mut ValidationState<'a, 'py>,
     old_extra: Extra<'a, 'py>,
+    old_field_path_len: usize,
 }
 
 impl<'a, 'py> std::ops::Deref for ValidationStateWithReboundExtra<'_, 'a, 'py> {
@@ -130,6 +749,7 @@ impl std::ops::DerefMut for ValidationStateWithReboundExtra<'_, '_, '_> {
 impl Drop for ValidationStateWithReboundExtra<'_, '_, '_> {
     fn drop(&mut self) {
         std::mem::swap(&mut self.state.extra, &mut self.old_extra);
+        self.state.field_path.items.truncate(self.old_field_path_len);
     }
 }
 
@@ -3,6 +3,7 @@ use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
 use pyo3::types::PyDict;
 use pyo3::types::PyString;
+use pyo3::types::PyTuple;
 use pyo3::PyTraverseError;
 use pyo3::PyVisit;
 
@@ -25,7 +26,8 @@ fn get_deepcopy(py: Python) -> PyResult<PyObject> {
 pub enum DefaultType {
     None,
     Default(PyObject),
-    DefaultFactory(PyObject, bool),
+    /// the factory, whether it takes the validated data and whether it takes the validation context
+    DefaultFactory(PyObject, bool, bool),
 }
 
 impl DefaultType {
@@ -42,26 +44,34 @@ impl DefaultType {
                 schema
                     .get_as::<bool>(intern!(py, "default_factory_takes_data"))?
                     .unwrap_or(false),
+                schema
+                    .get_as::<bool>(intern!(py, "default_factory_takes_context"))?
+                    .unwrap_or(false),
             )),
             (None, None) => Ok(Self::None),
         }
     }
 
-    pub fn default_value(&self, py: Python, validated_data: Option<&Bound<PyDict>>) -> PyResult<Option<PyObject>> {
+    pub fn default_value(
+        &self,
+        py: Python,
+        validated_data: Option<&Bound<PyDict>>,
+        context: Option<&Bound<PyAny>>,
+    ) -> PyResult<Option<PyObject>> {
         match self {
             Self::Default(ref default) => Ok(Some(default.clone_ref(py))),
-            Self::DefaultFactory(ref default_factory, ref takes_data) => {
-                let result = if *takes_data {
-                    if let Some(data) = validated_data {
-                        default_factory.call1(py, (data,))
-                    } else {
-                        default_factory.call1(py, ({},))
+            Self::DefaultFactory(ref default_factory, takes_data, takes_context) => {
+                let mut args = Vec::new();
+                if *takes_data {
+                    match validated_data {
+                        Some(data) => args.push(data.clone().into_any()),
+                        None => args.push(PyDict::new(py).into_any()),
                     }
-                } else {
-                    default_factory.call0(py)
-                };
-
-                Ok(Some(result?))
+                }
+                if *takes_context {
+                    args.push(context.cloned().unwrap_or_else(|| py.None().into_bound(py)));
+                }
+                Ok(Some(default_factory.call1(py, PyTuple::new(py, args)?)?))
             }
             Self::None => Ok(None),
         }
@@ -70,7 +80,7 @@ impl DefaultType {
 
 impl PyGcTraverse for DefaultType {
     fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
-        if let Self::Default(obj) | Self::DefaultFactory(obj, _) = self {
+        if let Self::Default(obj) | Self::DefaultFactory(obj, ..) = self {
             visit.call(obj)?;
         }
         Ok(())
@@ -160,16 +170,30 @@ impl Validator for WithDefaultValidator {
         if input.as_python().is_some_and(|py_input| py_input.is(&self.undefined)) {
             Ok(self.default_value(py, None::<usize>, state)?.unwrap())
         } else {
+            let report_mark = state.report_mark();
             match self.validator.validate(py, input, state) {
                 Ok(v) => Ok(v),
-                Err(e) => match e {
-                    ValError::UseDefault => Ok(self.default_value(py, None::<usize>, state)?.ok_or(e)?),
-                    e => match self.on_error {
-                        OnError::Raise => Err(e),
-                        OnError::Default => Ok(self.default_value(py, None::<usize>, state)?.ok_or(e)?),
-                        OnError::Omit => Err(ValError::Omit),
-                    },
-                },
+                Err(e) => {
+                    // the output of the failed validation isn't used, nor are its coercions or suppressed errors
+                    state.take_reports(report_mark);
+                    match e {
+                        ValError::UseDefault => Ok(self.default_value(py, None::<usize>, state)?.ok_or(e)?),
+                        e => match self.on_error {
+                            OnError::Raise => Err(e),
+                            OnError::Default => match self.default_value(py, None::<usize>, state)? {
+                                Some(default) => {
+                                    record_suppressed(state, e);
+                                    Ok(default)
+                                }
+                                None => Err(e),
+                            },
+                            OnError::Omit => {
+                                record_suppressed(state, e);
+                                Err(ValError::Omit)
+                            }
+                        },
+                    }
+                }
             }
         }
     }
@@ -180,7 +204,14 @@ impl Validator for WithDefaultValidator {
         outer_loc: Option<impl Into<LocItem>>,
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Option<PyObject>> {
-        match self.default.default_value(py, state.extra().data.as_ref())? {
+        if matches!(self.default, DefaultType::DefaultFactory(..)) {
+            // factories may return a different value on each call, or depend on the other fields
+            state.mark_context_dependent();
+        }
+        match self
+            .default
+            .default_value(py, state.extra().data.as_ref(), state.extra().context.as_ref())?
+        {
             Some(stored_dft) => {
                 let dft: Py<PyAny> = if self.copy_default {
                     let deepcopy_func = COPY_DEEPCOPY.get_or_init(py, || get_deepcopy(py).unwrap());
@@ -212,7 +243,29 @@ impl Validator for WithDefaultValidator {
     }
 }
 
+fn record_suppressed(state: &mut ValidationState<'_, '_>, error: ValError) {
+    if let ValError::LineErrors(line_errors) = error {
+        state.record_suppressed_errors(line_errors);
+    }
+}
+
 impl WithDefaultValidator {
+    /// The default without validating it, copied if `copy_default` is set, used when constructing from trusted data
+    pub fn construct_default(
+        &self,
+        py: Python,
+        validated_data: Option<&Bound<PyDict>>,
+        context: Option<&Bound<PyAny>>,
+    ) -> PyResult<Option<PyObject>> {
+        match self.default.default_value(py, validated_data, context)? {
+            Some(dft) if self.copy_default => {
+                let deepcopy_func = COPY_DEEPCOPY.get_or_init(py, || get_deepcopy(py).unwrap());
+                Ok(Some(deepcopy_func.call1(py, (&dft,))?))
+            }
+            dft => Ok(dft),
+        }
+    }
+
     pub fn has_default(&self) -> bool {
         !matches!(self.default, DefaultType::None)
     }
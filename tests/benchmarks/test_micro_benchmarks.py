@@ -8,7 +8,7 @@ import platform
 import sys
 from datetime import date, datetime, timedelta, timezone
 from decimal import Decimal
-from enum import Enum
+from enum import Enum, IntEnum
 from typing import Any
 from uuid import UUID
 
@@ -1475,3 +1475,25 @@ def test_enum_str_core(benchmark):
     assert v.validate_python('apple') is FooStr.a
 
     benchmark(v.validate_python, 'apple')
+
+
+# like the enums of protobuf-generated schemas, members are a contiguous range of ints
+FooIntLarge = IntEnum('FooIntLarge', [(f'm{i}', i) for i in range(500)])
+
+
+@pytest.mark.benchmark(group='enum int large')
+def test_enum_int_large_core(benchmark):
+    v = SchemaValidator(core_schema.enum_schema(FooIntLarge, list(FooIntLarge), sub_type='int'))
+
+    assert v.validate_python(321) is FooIntLarge.m321
+
+    benchmark(v.validate_python, 321)
+
+
+@pytest.mark.benchmark(group='enum int large')
+def test_enum_int_large_literal_core(benchmark):
+    v = SchemaValidator(core_schema.literal_schema(list(range(500))))
+
+    assert v.validate_python(321) == 321
+
+    benchmark(v.validate_python, 321)
@@ -21,6 +21,7 @@ from pydantic_core import (
     SchemaValidator,
     core_schema,
     to_json,
+    to_jsonable_python,
 )
 
 from ..conftest import plain_repr
@@ -639,6 +640,22 @@ def test_ser_json_inf_nan_with_any() -> None:
     assert s.to_json(nan) == b'"NaN"'
 
 
+def test_ser_json_inf_nan_error_with_any() -> None:
+    s = SchemaSerializer(core_schema.any_schema(), core_schema.CoreConfig(ser_json_inf_nan='error'))
+    assert isinf(s.to_python(inf))
+    assert s.to_json(1.5) == b'1.5'
+    with pytest.raises(PydanticSerializationError, match=r'Non-finite float inf is not permitted'):
+        s.to_json({'a': [inf]})
+    with pytest.raises(PydanticSerializationError, match=r'Non-finite float nan is not permitted'):
+        s.to_python([nan], mode='json')
+    with pytest.raises(PydanticSerializationError, match=r'Non-finite float -inf is not permitted'):
+        s.to_json({-inf: 1})
+
+    with pytest.raises(PydanticSerializationError, match=r'Non-finite float nan is not permitted'):
+        to_json(nan, inf_nan_mode='error')
+    assert to_json(1.0, inf_nan_mode='error') == b'1.0'
+
+
 def test_ser_json_inf_nan_with_list_of_any() -> None:
     s = SchemaSerializer(
         core_schema.list_schema(core_schema.any_schema()), core_schema.CoreConfig(ser_json_inf_nan='constants')
@@ -722,3 +739,146 @@ def test_simple_any_ser_schema():
     assert v.to_json({MyEnum.A: 'x'}) == b'{"1":"x"}'
     assert v.to_python(1) == 1
     assert v.to_json(1) == b'1'
+
+
+def test_sort_keys():
+    value = {'b': 1, 'a': {'d': [{'z': 1, 'y': 2}], 'c': None}, 'A': 'x'}
+    assert to_json(value, sort_keys=True) == b'{"A":"x","a":{"c":null,"d":[{"y":2,"z":1}]},"b":1}'
+    assert to_json(value, sort_keys=True, indent=2) == (
+        b'{\n  "A": "x",\n  "a": {\n    "c": null,\n    "d": [\n      {\n        "y": 2,\n        "z": 1\n'
+        b'      }\n    ]\n  },\n  "b": 1\n}'
+    )
+    assert to_json({'b': {}, 'a': []}, sort_keys=True, indent=2) == b'{\n  "a": [],\n  "b": {}\n}'
+    # keys are compared after unescaping, values are left as written
+    assert to_json({'é': 'café', '"q"': nan, 'e': 1.5}, sort_keys=True) == (
+        b'{"\\"q\\"":NaN,"e":1.5,"\xc3\xa9":"caf\xc3\xa9"}'
+    )
+
+    output = to_jsonable_python(value, sort_keys=True)
+    assert output == value
+    assert list(output) == ['A', 'a', 'b']
+    assert list(output['a']) == ['c', 'd']
+    assert list(output['a']['d'][0]) == ['y', 'z']
+
+
+def test_set_order():
+    assert to_json({'b', 'c', 'a'}, set_order='sorted') == b'["a","b","c"]'
+    assert to_json({'x': frozenset({3, 1, 2})}, set_order='sorted') == b'{"x":[1,2,3]}'
+    assert to_jsonable_python({(2, 'b'), (1, 'a')}, set_order='sorted') == [[1, 'a'], [2, 'b']]
+
+
+def test_sort_keys_dataclass():
+    @dataclasses.dataclass
+    class Foo:
+        b: int
+        a: set
+
+    assert to_json(Foo(b=1, a={'y', 'x'}), sort_keys=True, set_order='sorted') == b'{"a":["x","y"],"b":1}'
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (0.0, b'0'),
+        (-0.0, b'0'),
+        (5e-324, b'5e-324'),
+        (-1.7976931348623157e308, b'-1.7976931348623157e+308'),
+        (9007199254740992.0, b'9007199254740992'),
+        (295147905179352830000.0, b'295147905179352830000'),
+        (1e21, b'1e+21'),
+        (1e23, b'1e+23'),
+        (1.5e-7, b'1.5e-7'),
+        (0.000001, b'0.000001'),
+        (333333333.3333333, b'333333333.3333333'),
+        (4.5, b'4.5'),
+        (100, b'100'),
+        (-(2**53), b'-9007199254740992'),
+        (2**70, b'1.1805916207174113e+21'),
+        ('€\x0f\n"', b'"\xe2\x82\xac\\u000f\\n\\""'),
+    ],
+)
+def test_canonical_values(value, expected):
+    assert to_json(value, canonical=True) == expected
+
+
+def test_canonical():
+    # example from RFC 8785 section 3.2.3, keys are sorted by UTF-16 code units so the emoji comes before U+FB33
+    value = {
+        '€': 'Euro Sign',
+        '\r': 'Carriage Return',
+        'דּ': 'Hebrew Letter Dalet With Dagesh',
+        '1': 'One',
+        '\U0001f600': 'Emoji: Grinning Face',
+        '\x80': 'Control',
+        '\xf6': 'Latin Small Letter O With Diaeresis',
+    }
+    assert json.loads(to_json(value, canonical=True)) == value
+    assert list(json.loads(to_json(value, canonical=True))) == [
+        '\r',
+        '1',
+        '\x80',
+        '\xf6',
+        '€',
+        '\U0001f600',
+        'דּ',
+    ]
+    assert to_json({'b': [1e30, {'d': None, 'c': True}], 'a': {3}}, canonical=True) == (
+        b'{"a":[3],"b":[1e+30,{"c":true,"d":null}]}'
+    )
+
+
+def test_canonical_errors():
+    with pytest.raises(PydanticSerializationError, match='9007199254740993 cannot be represented exactly'):
+        to_json(2**53 + 1, canonical=True)
+    with pytest.raises(PydanticSerializationError, match='NaN is not permitted in canonical JSON'):
+        to_json([nan], canonical=True)
+    with pytest.raises(PydanticSerializationError, match='-Infinity is not permitted in canonical JSON'):
+        to_json(-inf, canonical=True)
+    assert to_json([nan, inf], canonical=True, inf_nan_mode='null') == b'[null,null]'
+    with pytest.raises(ValueError, match=r'`indent` cannot be used with `canonical=True`'):
+        to_json({}, canonical=True, indent=2)
+
+
+def test_canonical_schema_serializer():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'z': core_schema.typed_dict_field(core_schema.float_schema()),
+                'y': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    assert s.to_json({'z': 1.0, 'y': 2}, canonical=True) == b'{"y":2,"z":1}'
+
+
+@pytest.mark.parametrize(
+    'kwargs',
+    [
+        {'separators': (', ', ': ')},
+        {'separators': (',', ':'), 'indent': 2},
+        {'indent': 4},
+        {'indent': 2, 'separators': (' ,', ' = ')},
+        {'ensure_ascii': True},
+        {'ensure_ascii': True, 'indent': 1},
+    ],
+)
+def test_json_layout_matches_json_dumps(kwargs):
+    value = {'a': [1, 'é', {'b': None, 'c': []}], 'd': {}, 'emoji': '\U0001f600', 'ß': 1.5}
+    json_kwargs = {'ensure_ascii': False, 'separators': None if 'indent' in kwargs else (',', ':'), **kwargs}
+    expected = json.dumps(value, **json_kwargs).encode()
+    assert to_json(value, **kwargs) == expected
+
+
+def test_json_newline():
+    assert to_json({'a': [1, 2]}, indent=2, newline='\r\n') == b'{\r\n  "a": [\r\n    1,\r\n    2\r\n  ]\r\n}'
+    # newline only applies when indenting
+    assert to_json({'a': [1, 2]}, newline='\r\n') == b'{"a":[1,2]}'
+
+
+def test_json_layout_sort_keys():
+    value = {'b': 'ü', 'a': {'y': 1, 'x': 2}}
+    assert to_json(value, sort_keys=True, separators=(', ', ': '), ensure_ascii=True) == (
+        b'{"a": {"x": 2, "y": 1}, "b": "\\u00fc"}'
+    )
+    with pytest.raises(ValueError, match=r'`ensure_ascii` cannot be used with `canonical=True`'):
+        to_json(value, canonical=True, ensure_ascii=True)
@@ -0,0 +1,43 @@
+from collections import Counter, defaultdict, deque
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+def test_deque():
+    s = SchemaSerializer(core_schema.deque_schema(core_schema.int_schema()))
+    value = deque([1, 2, 3], maxlen=5)
+    output = s.to_python(value)
+    assert output == value
+    assert output.maxlen == 5
+    assert s.to_python(value, mode='json') == [1, 2, 3]
+    assert s.to_json(value) == b'[1,2,3]'
+    assert s.to_python(value, include={0, 2}) == deque([1, 3])
+    assert s.to_json(value, exclude={1}) == b'[1,3]'
+
+
+def test_counter():
+    s = SchemaSerializer(core_schema.counter_schema(core_schema.str_schema()))
+    value = Counter({'a': 2, 'b': 1})
+    output = s.to_python(value)
+    assert output == value
+    assert isinstance(output, Counter)
+    assert s.to_python(value, mode='json') == {'a': 2, 'b': 1}
+    assert s.to_json(value, exclude={'a'}) == b'{"b":1}'
+
+
+def test_defaultdict():
+    s = SchemaSerializer(core_schema.defaultdict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    value = defaultdict(int, a=1)
+    output = s.to_python(value)
+    assert output == {'a': 1}
+    assert output.default_factory is int
+    assert s.to_python(value, mode='json') == {'a': 1}
+    assert s.to_json(value) == b'{"a":1}'
+
+
+def test_wrong_type():
+    s = SchemaSerializer(core_schema.deque_schema())
+    with pytest.warns(UserWarning, match=r'Expected `deque` - serialized value may not be as expected'):
+        assert s.to_python([1], mode='json') == [1]
@@ -0,0 +1,33 @@
+from pydantic_core import SchemaSerializer, core_schema
+
+
+def test_context_switch():
+    s = SchemaSerializer(
+        core_schema.context_switch_schema(
+            'api_version',
+            {
+                'v1': core_schema.int_schema(serialization=core_schema.to_string_ser_schema(when_used='always')),
+                'v2': core_schema.int_schema(),
+            },
+        )
+    )
+    assert s.to_python(1, context={'api_version': 'v1'}) == '1'
+    assert s.to_python(1, context={'api_version': 'v2'}) == 1
+    assert s.to_json(1, context={'api_version': 'v1'}) == b'"1"'
+    assert s.to_json(1, context={'api_version': 'v2'}) == b'1'
+    # no match and no default, fall back to inference
+    assert s.to_python(1) == 1
+    assert s.to_json(1, context={'api_version': 'v3'}) == b'1'
+
+
+def test_context_switch_default():
+    s = SchemaSerializer(
+        core_schema.context_switch_schema(
+            'v',
+            {1: core_schema.int_schema()},
+            default_schema=core_schema.int_schema(serialization=core_schema.to_string_ser_schema(when_used='always')),
+        )
+    )
+    assert s.to_python(1, context={'v': 1}) == 1
+    assert s.to_python(1, context={'v': []}) == '1'
+    assert s.to_python(1, mode='json') == '1'
@@ -0,0 +1,174 @@
+import dataclasses
+from datetime import datetime
+from decimal import Decimal
+from enum import Enum
+
+import pytest
+
+from pydantic_core import PydanticUndefined, SchemaSerializer, SchemaValidator, copy_validated, core_schema
+
+
+class BasicModel:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+def model_schema(**fields):
+    return core_schema.model_schema(
+        BasicModel,
+        core_schema.model_fields_schema(
+            {name: core_schema.model_field(schema) for name, schema in fields.items()}, extra_behavior='allow'
+        ),
+        extra_behavior='allow',
+    )
+
+
+@pytest.fixture(scope='module')
+def validator_serializer():
+    schema = core_schema.definitions_schema(
+        core_schema.definition_reference_schema('model'),
+        [
+            model_schema(
+                a=core_schema.list_schema(core_schema.int_schema()),
+                b=core_schema.with_default_schema(
+                    core_schema.nullable_schema(core_schema.definition_reference_schema('model')), default=None
+                ),
+                c=core_schema.with_default_schema(core_schema.dict_schema(), default_factory=dict),
+            )
+            | {'ref': 'model'}
+        ],
+    )
+    return SchemaValidator(schema), SchemaSerializer(schema)
+
+
+def test_deep_copy(validator_serializer):
+    v, s = validator_serializer
+    m = v.validate_python({'a': [1, 2], 'b': {'a': [3], 'c': {'x': [4]}}, 'extra': [5]})
+
+    copied = copy_validated(m)
+    assert type(copied) is BasicModel
+    assert copied is not m
+    assert s.to_python(copied) == s.to_python(m)
+    assert copied.a is not m.a
+    assert copied.b is not m.b
+    assert copied.b.a is not m.b.a
+    assert copied.b.c['x'] is not m.b.c['x']
+    assert copied.__pydantic_extra__ == {'extra': [5]}
+    assert copied.__pydantic_extra__['extra'] is not m.__pydantic_extra__['extra']
+    assert copied.__pydantic_fields_set__ == {'a', 'b', 'extra'}
+    assert copied.__pydantic_fields_set__ is not m.__pydantic_fields_set__
+    assert copied.b.__pydantic_fields_set__ == {'a', 'c'}
+
+
+def test_shallow_copy(validator_serializer):
+    v, _ = validator_serializer
+    m = v.validate_python({'a': [1], 'b': {'a': [2]}, 'extra': [3]})
+
+    copied = copy_validated(m, deep=False)
+    assert copied.__dict__ == m.__dict__
+    assert copied.__dict__ is not m.__dict__
+    assert copied.a is m.a
+    assert copied.b is m.b
+    assert copied.__pydantic_extra__ is not m.__pydantic_extra__
+    assert copied.__pydantic_extra__['extra'] is m.__pydantic_extra__['extra']
+    assert copied.__pydantic_fields_set__ == m.__pydantic_fields_set__
+    assert copied.__pydantic_fields_set__ is not m.__pydantic_fields_set__
+
+    values = [[1]]
+    assert copy_validated(values, deep=False) == values
+    assert copy_validated(values, deep=False)[0] is values[0]
+
+
+def test_copy_private():
+    m = BasicModel()
+    m.__dict__ = {'a': 1}
+    m.__pydantic_fields_set__ = {'a'}
+    m.__pydantic_extra__ = None
+    m.__pydantic_private__ = {'_x': [1], '_y': PydanticUndefined}
+
+    copied = copy_validated(m)
+    assert copied.__pydantic_extra__ is None
+    assert copied.__pydantic_private__ == {'_x': [1]}
+    assert copied.__pydantic_private__['_x'] is not m.__pydantic_private__['_x']
+
+    m.__pydantic_private__ = None
+    assert copy_validated(m).__pydantic_private__ is None
+
+
+def test_shared_references():
+    inner = [1]
+    value = {'a': inner, 'b': (inner, 'x'), 'c': frozenset([(1, 2)])}
+    copied = copy_validated(value)
+    assert copied == value
+    assert copied['a'] is not inner
+    assert copied['b'][0] is copied['a']
+    # immutable containers of immutable values are shared, as with `copy.deepcopy`
+    assert copied['c'] is value['c']
+
+
+def test_cycles():
+    value = []
+    value.append(value)
+    value.append((value,))
+    copied = copy_validated(value)
+    assert copied is not value
+    assert copied[0] is copied
+    assert copied[1][0] is copied
+
+
+class Color(Enum):
+    red = 'red'
+
+
+@dataclasses.dataclass
+class Point:
+    x: list
+
+
+class MyList(list):
+    pass
+
+
+def test_atomic_and_fallback():
+    point = Point([1])
+    my_list = MyList([[1]])
+    values = ['x', 1, 1.5, None, True, b'x', Decimal('1'), datetime(2000, 1, 1), Color.red, point, my_list]
+    copied = copy_validated(values)
+    assert copied == values
+    for original, new in zip(values[:-2], copied[:-2]):
+        assert new is original
+    assert copied[-2] is not point
+    assert copied[-2].x is not point.x
+    assert type(copied[-1]) is MyList
+    assert copied[-1][0] is not my_list[0]
+
+
+def test_recursion_limit():
+    value = []
+    for _ in range(999):
+        value = [value]
+    copied, original = copy_validated(value), value
+    for _ in range(999):
+        assert copied is not original
+        copied, original = copied[0], original[0]
+    assert copied == []
+    with pytest.raises(RecursionError, match='Maximum recursion depth exceeded while copying'):
+        copy_validated([value])
+
+
+class CustomCopyModel(BasicModel):
+    def __copy__(self):
+        return 'copy'
+
+    def __deepcopy__(self, memo):
+        return 'deepcopy'
+
+
+def test_custom_copy():
+    m = CustomCopyModel()
+    m.__dict__ = {'a': 1}
+    m.__pydantic_fields_set__ = {'a'}
+    m.__pydantic_extra__ = None
+    m.__pydantic_private__ = None
+
+    assert copy_validated([m]) == ['deepcopy']
+    assert copy_validated(m, deep=False) == 'copy'
@@ -94,6 +94,31 @@ def test_serialization_alias():
         assert j == b'{"a":"hello","BAR":"more"}'
 
 
+def test_serialization_exclude_if():
+    schema = core_schema.dataclass_schema(
+        Foo,
+        core_schema.dataclass_args_schema(
+            'Foo',
+            [
+                core_schema.dataclass_field(
+                    name='a', schema=core_schema.str_schema(), serialization_exclude_if=lambda v: v == 'hide'
+                ),
+                core_schema.dataclass_field(
+                    name='b',
+                    schema=core_schema.nullable_schema(core_schema.bytes_schema()),
+                    serialization_exclude_none=True,
+                ),
+            ],
+        ),
+        ['a', 'b'],
+    )
+    s = SchemaSerializer(schema)
+    assert s.to_python(Foo(a='hello', b=b'more')) == {'a': 'hello', 'b': b'more'}
+    assert s.to_python(Foo(a='hide', b=None)) == {}
+    assert s.to_python(Foo(a='hello', b=None), mode='json') == {'a': 'hello'}
+    assert s.to_json(Foo(a='hide', b=b'more')) == b'{"b":"more"}'
+
+
 def test_properties():
     @dataclasses.dataclass
     class FooProp:
@@ -278,3 +303,33 @@ def test_by_alias_and_name_config_interaction(config, runtime, expected) -> None
     )
     s = SchemaSerializer(schema)
     assert s.to_python(Foo(my_field='hello'), by_alias=runtime) == expected
+
+
+@pytest.mark.parametrize(
+    'field_order,expected',
+    [
+        ('insertion', b'{"b":2,"a":1}'),
+        ('schema', b'{"a":1,"b":2}'),
+        ('alphabetical', b'{"a":1,"b":2}'),
+    ],
+)
+def test_field_order(field_order, expected):
+    @dataclasses.dataclass
+    class Foo:
+        b: int
+        a: int
+
+    schema = core_schema.dataclass_schema(
+        Foo,
+        core_schema.dataclass_args_schema(
+            'Foo',
+            [
+                core_schema.dataclass_field(name='a', schema=core_schema.int_schema()),
+                core_schema.dataclass_field(name='b', schema=core_schema.int_schema()),
+            ],
+        ),
+        ['b', 'a'],
+        config=core_schema.CoreConfig(field_order=field_order),
+    )
+    s = SchemaSerializer(schema)
+    assert s.to_json(Foo(b=2, a=1)) == expected
@@ -1,8 +1,10 @@
+import json
 from datetime import date, datetime, time, timedelta, timezone
+from zoneinfo import ZoneInfo
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, core_schema, to_json, to_jsonable_python
 
 
 def test_datetime():
@@ -113,3 +115,75 @@ def test_date_datetime_union():
     assert v.to_python(datetime(2022, 12, 2, 1)) == datetime(2022, 12, 2, 1)
     assert v.to_python(datetime(2022, 12, 2, 1), mode='json') == '2022-12-02T01:00:00'
     assert v.to_json(datetime(2022, 12, 2, 1)) == b'"2022-12-02T01:00:00"'
+
+
+aware_dt = datetime(2024, 1, 2, 3, 4, 5, tzinfo=timezone.utc)
+offset_dt = datetime(2024, 1, 2, 3, 4, 5, 123456, tzinfo=timezone(timedelta(hours=-5, minutes=-30)))
+
+
+@pytest.mark.parametrize(
+    'temporal_format,value,expected',
+    [
+        ('iso8601', aware_dt, '2024-01-02T03:04:05Z'),
+        ('iso8601_basic', aware_dt, '20240102T030405Z'),
+        ('iso8601_basic', offset_dt, '20240102T030405.123456-0530'),
+        ('iso8601_basic', datetime(2024, 1, 2, 3, 4, 5), '20240102T030405'),
+        ('iso8601_basic', date(2024, 1, 2), '20240102'),
+        ('iso8601_basic', time(3, 4, 5, tzinfo=timezone.utc), '030405Z'),
+        ('rfc2822', aware_dt, 'Tue, 02 Jan 2024 03:04:05 +0000'),
+        ('rfc2822', offset_dt, 'Tue, 02 Jan 2024 03:04:05 -0530'),
+        ('rfc2822', datetime(2024, 1, 2, 3, 4, 5), 'Tue, 02 Jan 2024 03:04:05 -0000'),
+        ('rfc2822', date(2024, 1, 2), 'Tue, 02 Jan 2024'),
+        ('rfc2822', time(3, 4, 5), '03:04:05'),
+        ('epoch_millis', aware_dt, 1704164645000),
+        ('epoch_millis', offset_dt, 1704164645123 + 19800000),
+        ('epoch_millis', datetime(1969, 12, 31, 23, 59, 59, 500000), -500),
+        ('epoch_millis', date(2024, 1, 2), 1704153600000),
+        ('epoch_millis', time(3, 4, 5, 6000), 11045006),
+        ('%d/%m/%Y %I:%M %p', datetime(2024, 1, 2, 15, 4), '02/01/2024 03:04 PM'),
+        ('%A %d %B %Y, day %j', date(2024, 12, 31), 'Tuesday 31 December 2024, day 366'),
+        ('%H:%M:%S.%f%z %%', offset_dt.timetz(), '03:04:05.123456-0530 %'),
+    ],
+)
+def test_temporal_format(temporal_format, value, expected):
+    schema = {datetime: core_schema.datetime_schema, date: core_schema.date_schema, time: core_schema.time_schema}[
+        type(value)
+    ]()
+    s = SchemaSerializer(schema, config={'ser_json_temporal_format': temporal_format})
+    assert s.to_python(value) == value
+    assert s.to_python(value, mode='json') == expected
+    assert s.to_json(value) == json.dumps(expected).encode()
+    if '%' in temporal_format:
+        assert value.strftime(temporal_format) == expected
+
+    # the same format is used when inferring the type, including for keys
+    s = SchemaSerializer(core_schema.any_schema(), config={'ser_json_temporal_format': temporal_format})
+    assert s.to_python({value: [value]}, mode='json') == {str(expected): [expected]}
+    assert to_jsonable_python(value, temporal_format=temporal_format) == expected
+    assert to_json(value, temporal_format=temporal_format) == json.dumps(expected).encode()
+
+
+def test_temporal_format_invalid():
+    with pytest.raises(SchemaError, match='Invalid TemporalFormat serialization mode: `unix`'):
+        SchemaSerializer(core_schema.datetime_schema(), config={'ser_json_temporal_format': 'unix'})
+    with pytest.raises(SchemaError, match="Invalid format \"%Y-%q\", unsupported directive '%q'"):
+        SchemaSerializer(core_schema.datetime_schema(), config={'ser_json_temporal_format': '%Y-%q'})
+
+
+def test_preserve_zone_key():
+    s = SchemaSerializer(core_schema.datetime_schema(preserve_zone_key=True))
+    value = datetime(2024, 1, 2, 3, 4, 5, tzinfo=ZoneInfo('Europe/Paris'))
+    assert s.to_python(value, mode='json') == '2024-01-02T03:04:05+01:00'
+    assert s.to_python(value, mode='json', round_trip=True) == '2024-01-02T03:04:05+01:00[Europe/Paris]'
+    assert s.to_json(value, round_trip=True) == b'"2024-01-02T03:04:05+01:00[Europe/Paris]"'
+    # fixed offset zones don't have a key
+    assert s.to_json(aware_dt, round_trip=True) == b'"2024-01-02T03:04:05Z"'
+
+    v = SchemaValidator(core_schema.datetime_schema(preserve_zone_key=True))
+    output = v.validate_json(s.to_json(value, round_trip=True))
+    assert output == value
+    assert output.tzinfo is value.tzinfo
+
+    # the key is only added by schemas with `preserve_zone_key`
+    s = SchemaSerializer(core_schema.datetime_schema())
+    assert s.to_json(value, round_trip=True) == b'"2024-01-02T03:04:05+01:00"'
@@ -61,3 +61,30 @@ def test_any_decimal_key():
 
     assert v.to_python(input_value, mode='json') == {'123.456': 1}
     assert v.to_json(input_value) == b'{"123.456":1}'
+
+
+@pytest.mark.parametrize(
+    'kwargs,value,expected',
+    [
+        ({'ser_decimal_places': 2}, Decimal('1.5'), '1.50'),
+        ({'ser_decimal_places': 2}, Decimal('1.005'), '1.00'),
+        ({'ser_decimal_places': 2, 'rounding': 'ROUND_HALF_UP'}, Decimal('1.005'), '1.01'),
+        ({'ser_decimal_places': 0}, Decimal('12.5'), '12'),
+        ({'ser_normalize': True}, Decimal('1.500'), '1.5'),
+        ({'ser_normalize': True}, Decimal('100.00'), '100'),
+        ({'ser_decimal_places': 2, 'ser_normalize': True}, Decimal('1.5'), '1.5'),
+        ({'ser_decimal_places': 2}, Decimal('inf'), 'Infinity'),
+    ],
+)
+def test_decimal_ser_format(kwargs, value, expected):
+    s = SchemaSerializer(core_schema.decimal_schema(**kwargs))
+    assert s.to_python(value, mode='json') == expected
+    assert s.to_json(value) == f'"{expected}"'.encode()
+    assert str(s.to_python(value)) == expected
+
+
+def test_decimal_ser_format_key():
+    s = SchemaSerializer(
+        core_schema.dict_schema(core_schema.decimal_schema(ser_decimal_places=1), core_schema.int_schema())
+    )
+    assert s.to_json({Decimal('1'): 1}) == b'{"1.0":1}'
@@ -1,8 +1,9 @@
+import json
 from enum import Enum
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
 
 
 def test_plain_enum():
@@ -133,3 +134,77 @@ def test_int_dict_key():
         match=r"Expected `enum` - serialized value may not be as expected \[input_value='x', input_type=str\]",
     ):
         assert v.to_json({'x': 'x'}) == b'{"x":"x"}'
+
+
+class Color(int, Enum):
+    red = 1
+    green = 2
+
+
+@pytest.mark.parametrize(
+    'ser_enum,expected_python,expected_json',
+    [
+        ('object', Color.red, b'1'),
+        ('value', 1, b'1'),
+        ('name', 'red', b'"red"'),
+    ],
+)
+@pytest.mark.parametrize('sub_type', ['int', None])
+def test_ser_enum(ser_enum, expected_python, expected_json, sub_type):
+    v = SchemaSerializer(
+        core_schema.enum_schema(Color, list(Color.__members__.values()), sub_type=sub_type),
+        core_schema.CoreConfig(ser_enum=ser_enum),
+    )
+    python = v.to_python(Color.red)
+    assert python == expected_python
+    assert type(python) is type(expected_python)
+    assert v.to_python(Color.red, mode='json') == json.loads(expected_json)
+    assert v.to_json(Color.red) == expected_json
+
+
+@pytest.mark.parametrize(
+    'ser_enum,expected_python,expected_json',
+    [
+        ('object', {Color.red: [Color.green]}, b'{"1":[2]}'),
+        ('value', {1: [2]}, b'{"1":[2]}'),
+        ('name', {'red': ['green']}, b'{"red":["green"]}'),
+    ],
+)
+def test_ser_enum_infer(ser_enum, expected_python, expected_json):
+    v = SchemaSerializer(core_schema.any_schema(), core_schema.CoreConfig(ser_enum=ser_enum))
+    value = {Color.red: [Color.green]}
+    assert v.to_python(value) == expected_python
+    assert v.to_python(value, mode='json') == json.loads(expected_json)
+    assert v.to_json(value) == expected_json
+
+
+def test_ser_enum_dict_key():
+    v = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.enum_schema(Color, list(Color.__members__.values())), core_schema.int_schema()
+        ),
+        core_schema.CoreConfig(ser_enum='name'),
+    )
+    assert v.to_python({Color.green: 1}) == {'green': 1}
+    assert v.to_json({Color.green: 1}) == b'{"green":1}'
+
+
+def test_ser_enum_schema_override():
+    v = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.enum_schema(Color, list(Color.__members__.values()))),
+                'b': core_schema.typed_dict_field(
+                    core_schema.enum_schema(Color, list(Color.__members__.values()), ser_enum='name')
+                ),
+            }
+        ),
+        core_schema.CoreConfig(ser_enum='value'),
+    )
+    assert v.to_python({'a': Color.red, 'b': Color.red}) == {'a': 1, 'b': 'red'}
+    assert v.to_json({'a': Color.red, 'b': Color.red}) == b'{"a":1,"b":"red"}'
+
+
+def test_ser_enum_invalid():
+    with pytest.raises(SchemaError, match='Invalid EnumMode serialization mode: `label`'):
+        SchemaSerializer(core_schema.any_schema(), core_schema.CoreConfig(ser_enum='label'))
@@ -0,0 +1,31 @@
+from fractions import Fraction
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (Fraction(3, 4), '3/4'),
+        (Fraction(-1, 3), '-1/3'),
+        (Fraction(5), '5'),
+    ],
+)
+def test_fraction(value, expected):
+    s = SchemaSerializer(core_schema.fraction_schema())
+    assert s.to_python(value) is value
+    assert s.to_python(value, mode='json') == expected
+    assert s.to_json(value) == f'"{expected}"'.encode()
+
+
+def test_fraction_dict_key():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.fraction_schema(), core_schema.int_schema()))
+    assert s.to_json({Fraction(1, 2): 1}) == b'{"1/2":1}'
+
+
+def test_fraction_wrong_type():
+    s = SchemaSerializer(core_schema.fraction_schema())
+    with pytest.warns(UserWarning, match=r'Expected `fraction` - serialized value may not be as expected'):
+        assert s.to_python(123, mode='json') == 123
@@ -542,6 +542,58 @@ def test_function_wrap_model():
     assert calls == 6
 
 
+def test_function_wrap_model_handler_include_exclude():
+    serialized = []
+
+    def track(value):
+        serialized.append(value)
+        return value
+
+    def wrap_function(value, handler, info):
+        if info.context == 'only':
+            return handler(value, only_fields=['a', 'b'])
+        elif info.context == 'exclude':
+            return handler(value, exclude={'c'})
+        else:
+            return handler(value, include={'b', 'c'})
+
+    class MyModel:
+        def __init__(self, **kwargs):
+            self.__dict__.update(kwargs)
+
+    field_schema = core_schema.any_schema(serialization=core_schema.plain_serializer_function_ser_schema(track))
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.typed_dict_schema(
+                {
+                    'a': core_schema.typed_dict_field(field_schema),
+                    'b': core_schema.typed_dict_field(field_schema),
+                    'c': core_schema.typed_dict_field(field_schema),
+                }
+            ),
+            serialization=core_schema.wrap_serializer_function_ser_schema(wrap_function, info_arg=True),
+        )
+    )
+    m = MyModel(a=1, b=2, c=3)
+
+    assert s.to_python(m, context='only') == {'a': 1, 'b': 2}
+    assert serialized == [1, 2]
+    serialized.clear()
+    assert s.to_json(m, context='only') == b'{"a":1,"b":2}'
+    assert serialized == [1, 2]
+    serialized.clear()
+
+    # `only_fields` is combined with the include of the current call
+    assert s.to_python(m, context='only', include={'b', 'c'}) == {'b': 2}
+    assert s.to_python(m, context='only', include={'a': True, 'c': True}) == {'a': 1}
+
+    assert s.to_python(m, context='exclude') == {'a': 1, 'b': 2}
+    # the include and exclude passed to the handler replace those of the current call
+    assert s.to_python(m, context='exclude', exclude={'a'}) == {'a': 1, 'b': 2}
+    assert s.to_python(m, context='include', include={'a'}) == {'b': 2, 'c': 3}
+
+
 def test_function_plain_model():
     calls = 0
 
@@ -1,6 +1,17 @@
+import warnings
+from datetime import datetime
 from enum import Enum
 
-from pydantic_core import SchemaSerializer, core_schema
+import pytest
+
+from pydantic_core import (
+    PydanticSerializationError,
+    SchemaSerializer,
+    core_schema,
+    register_ob_type,
+    to_json,
+    to_jsonable_python,
+)
 
 
 # serializing enum calls methods in serializers::infer
@@ -26,3 +37,61 @@ def test_infer_json_key():
 
     v = SchemaSerializer(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values())))
     assert v.to_json(MyEnum.complex_) == b'{"1+2j":1}'
+
+
+class IndexInt:
+    def __init__(self, value):
+        self.value = value
+
+    def __index__(self):
+        return self.value
+
+
+def test_register_ob_type_int():
+    class MyInt(IndexInt):
+        pass
+
+    with pytest.raises(PydanticSerializationError, match='Unable to serialize unknown type'):
+        to_json(MyInt(1))
+
+    register_ob_type(MyInt, 'int')
+    assert to_json([MyInt(1), {'a': MyInt(2)}]) == b'[1,{"a":2}]'
+    assert to_jsonable_python(MyInt(3)) == 3
+    assert type(to_jsonable_python(MyInt(3))) is int
+
+    s = SchemaSerializer(core_schema.int_schema())
+    with warnings.catch_warnings():
+        warnings.simplefilter('error')
+        assert s.to_python(MyInt(4), mode='json') == 4
+        assert s.to_json(MyInt(4)) == b'4'
+
+    class MySubInt(MyInt):
+        pass
+
+    assert to_json(MySubInt(5)) == b'5'
+
+
+def test_register_ob_type_decimal():
+    class MyDecimal:
+        def __str__(self):
+            return '1.50'
+
+    register_ob_type(MyDecimal, 'decimal')
+    assert to_json(MyDecimal()) == b'"1.50"'
+    assert to_jsonable_python({'x': MyDecimal()}) == {'x': '1.50'}
+
+
+def test_register_ob_type_datetime():
+    class MyDatetime(datetime):
+        pass
+
+    register_ob_type(MyDatetime, 'datetime')
+    assert to_json(MyDatetime(2022, 1, 2, 3, 4, 5)) == b'"2022-01-02T03:04:05"'
+
+
+def test_register_ob_type_errors():
+    with pytest.raises(ValueError, match="Unsupported ob_type `list`, expected one of 'int', 'float'"):
+        register_ob_type(IndexInt, 'list')
+
+    with pytest.raises(TypeError, match='`IndexInt` must be a subclass of `datetime` to be registered as `datetime`'):
+        register_ob_type(IndexInt, 'datetime')
@@ -0,0 +1,33 @@
+from ipaddress import IPv4Address, IPv4Interface, IPv6Address, IPv6Network
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+@pytest.mark.parametrize(
+    'schema,value,expected',
+    [
+        (core_schema.ip_address_schema(), IPv4Address('192.168.0.1'), '192.168.0.1'),
+        (core_schema.ip_address_schema(), IPv6Address('::1'), '::1'),
+        (core_schema.ip_network_schema(), IPv6Network('2001:db8::/32'), '2001:db8::/32'),
+        (core_schema.ip_interface_schema(), IPv4Interface('10.0.0.1/8'), '10.0.0.1/8'),
+    ],
+)
+def test_ip(schema, value, expected):
+    s = SchemaSerializer(schema)
+    assert s.to_python(value) is value
+    assert s.to_python(value, mode='json') == expected
+    assert s.to_json(value) == f'"{expected}"'.encode()
+
+
+def test_ip_dict_key():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.ip_address_schema(), core_schema.int_schema()))
+    assert s.to_python({IPv4Address('1.2.3.4'): 1}, mode='json') == {'1.2.3.4': 1}
+    assert s.to_json({IPv6Address('::1'): 1}) == b'{"::1":1}'
+
+
+def test_ip_wrong_type():
+    s = SchemaSerializer(core_schema.ip_network_schema())
+    with pytest.warns(UserWarning, match=r'Expected `ip-network` - serialized value may not be as expected'):
+        assert s.to_python(IPv4Address('1.2.3.4')) == IPv4Address('1.2.3.4')
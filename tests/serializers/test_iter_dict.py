@@ -0,0 +1,67 @@
+import json
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+
+
+def gen_pairs(n):
+    for i in range(n):
+        yield f'k{i}', i
+
+
+@pytest.fixture
+def serializer():
+    return SchemaSerializer(
+        core_schema.any_schema(
+            serialization=core_schema.iter_dict_ser_schema(
+                keys_schema=core_schema.str_schema(), values_schema=core_schema.int_schema()
+            )
+        )
+    )
+
+
+def test_generator(serializer: SchemaSerializer):
+    assert serializer.to_json(gen_pairs(3)) == b'{"k0":0,"k1":1,"k2":2}'
+    assert serializer.to_python(gen_pairs(3)) == {'k0': 0, 'k1': 1, 'k2': 2}
+    assert serializer.to_python(gen_pairs(3), mode='json') == {'k0': 0, 'k1': 1, 'k2': 2}
+
+
+@pytest.mark.parametrize(
+    'value',
+    [
+        {'a': 1, 'b': 2}.items(),
+        iter({'a': 1, 'b': 2}.items()),
+        [('a', 1), ('b', 2)],
+        {'a': 1, 'b': 2},
+    ],
+)
+def test_pair_sources(serializer: SchemaSerializer, value):
+    assert serializer.to_json(value) == b'{"a":1,"b":2}'
+
+
+def test_large_generator(serializer: SchemaSerializer):
+    assert json.loads(serializer.to_json(gen_pairs(10_000))) == {f'k{i}': i for i in range(10_000)}
+
+
+def test_include_exclude(serializer: SchemaSerializer):
+    assert serializer.to_json(gen_pairs(3), exclude={'k1'}) == b'{"k0":0,"k2":2}'
+    assert serializer.to_python(gen_pairs(3), include={'k1'}) == {'k1': 1}
+
+
+def test_infer_types():
+    s = SchemaSerializer(core_schema.any_schema(serialization=core_schema.iter_dict_ser_schema()))
+    assert s.to_json(iter([(1, b'a'), (2.5, None)])) == b'{"1":"a","2.5":null}'
+
+
+def test_not_pair(serializer: SchemaSerializer):
+    with pytest.raises(PydanticSerializationError, match=r"Expected a `\(key, value\)` pair, got 'x'"):
+        serializer.to_json(iter(['x']))
+    with pytest.raises(PydanticSerializationError, match=r"Expected a `\(key, value\)` pair, got \('a', 1, 2\)"):
+        serializer.to_python([('a', 1, 2)])
+
+
+def test_not_iterable(serializer: SchemaSerializer):
+    msg = r'Expected `iter-dict\[str, int\]` - serialized value may not be as expected'
+    with pytest.warns(UserWarning, match=msg):
+        assert serializer.to_json(123) == b'123'
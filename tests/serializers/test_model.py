@@ -15,6 +15,7 @@ from dirty_equals import IsJson
 
 from pydantic_core import (
     PydanticSerializationError,
+    SchemaError,
     SchemaSerializer,
     SchemaValidator,
     core_schema,
@@ -220,6 +221,32 @@ def test_alias():
     assert s.to_python(value, by_alias=True) == IsStrictDict(Meow=0, Woof=1, bird=2)
 
 
+def test_alias_generator():
+    def make_serializer(config):
+        return SchemaSerializer(
+            core_schema.model_schema(
+                BasicModel,
+                core_schema.model_fields_schema(
+                    {
+                        'cat_name': core_schema.model_field(core_schema.int_schema(), serialization_alias='Meow'),
+                        'dog_name': core_schema.model_field(core_schema.int_schema()),
+                    },
+                    computed_fields=[core_schema.computed_field('bird_name', core_schema.int_schema())],
+                ),
+                config=config,
+            )
+        )
+
+    value = BasicModel(cat_name=0, dog_name=1, bird_name=2)
+
+    s = make_serializer(core_schema.CoreConfig(alias_generator='camel'))
+    assert s.to_python(value, by_alias=True) == IsStrictDict(Meow=0, dogName=1, birdName=2)
+    assert s.to_python(value, by_alias=False) == IsStrictDict(cat_name=0, dog_name=1, bird_name=2)
+
+    s = make_serializer(core_schema.CoreConfig(alias_generator='camel', serialization_alias_generator='kebab'))
+    assert s.to_json(value, by_alias=True) == b'{"Meow":0,"dog-name":1,"bird-name":2}'
+
+
 def test_model_wrong_warn():
     s = SchemaSerializer(
         core_schema.model_schema(
@@ -324,6 +351,39 @@ def test_exclude_unset():
     assert s.to_python(m2, exclude_unset=True) == {'bar': 2, 'spam': 3}
 
 
+def test_missing_value():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            FieldsSetModel,
+            core_schema.model_fields_schema(
+                {
+                    'foo': core_schema.model_field(core_schema.int_schema()),
+                    'bar': core_schema.model_field(
+                        core_schema.with_default_schema(core_schema.int_schema(), default=2)
+                    ),
+                    'spam': core_schema.model_field(core_schema.int_schema()),
+                }
+            ),
+        )
+    )
+    m = FieldsSetModel(foo=1, bar=2, spam=3, __pydantic_fields_set__={'foo', 'spam'})
+    sentinel = object()
+    assert s.to_python(m, missing_value=sentinel) == {'foo': 1, 'bar': sentinel, 'spam': 3}
+    assert s.to_python(m, missing_value='<unset>', mode='json') == {'foo': 1, 'bar': '<unset>', 'spam': 3}
+    assert s.to_json(m, missing_value='<unset>') == b'{"foo":1,"bar":"<unset>","spam":3}'
+    # unset fields are reported even when they're equal to the default
+    assert s.to_python(m, missing_value='<unset>', exclude_defaults=True) == {
+        'foo': 1,
+        'bar': '<unset>',
+        'spam': 3,
+    }
+    assert s.to_python(m, missing_value='<unset>', exclude={'bar'}) == {'foo': 1, 'spam': 3}
+    # exclude_unset takes precedence
+    assert s.to_python(m, missing_value='<unset>', exclude_unset=True) == {'foo': 1, 'spam': 3}
+    # the model itself isn't modified
+    assert m.bar == 2
+
+
 @pytest.mark.parametrize(
     'exclude,expected',
     [
@@ -1224,3 +1284,172 @@ def test_by_alias_and_name_config_interaction(config, runtime, expected) -> None
     )
     s = SchemaSerializer(schema)
     assert s.to_python(Model(1), by_alias=runtime) == expected
+
+
+@pytest.mark.parametrize(
+    'model_config,runtime,expected',
+    [
+        (None, None, {'my_alias': 1, 'items': [{'item_alias': 2}]}),
+        (None, False, {'my_field': 1, 'items': [{'item_field': 2}]}),
+        (False, None, {'my_field': 1, 'items': [{'item_field': 2}]}),
+        (False, True, {'my_alias': 1, 'items': [{'item_alias': 2}]}),
+    ],
+)
+def test_serializer_config_serialize_by_alias(model_config, runtime, expected) -> None:
+    """`serialize_by_alias` on the `SchemaSerializer` config applies to fields whose own config doesn't set it."""
+
+    class Model:
+        def __init__(self, my_field: int, items: list) -> None:
+            self.my_field = my_field
+            self.items = items
+
+    schema = core_schema.model_schema(
+        Model,
+        core_schema.model_fields_schema(
+            {
+                'my_field': core_schema.model_field(core_schema.int_schema(), serialization_alias='my_alias'),
+                'items': core_schema.model_field(
+                    core_schema.list_schema(
+                        core_schema.typed_dict_schema(
+                            {
+                                'item_field': core_schema.typed_dict_field(
+                                    core_schema.int_schema(), serialization_alias='item_alias'
+                                )
+                            }
+                        )
+                    )
+                ),
+            }
+        ),
+        config=core_schema.CoreConfig(serialize_by_alias=model_config) if model_config is not None else None,
+    )
+    s = SchemaSerializer(schema, config=core_schema.CoreConfig(serialize_by_alias=True))
+    assert s.to_python(Model(1, [{'item_field': 2}]), by_alias=runtime) == expected
+
+
+@pytest.mark.parametrize(
+    'field_order,expected',
+    [
+        (None, '{"b":2,"c":3,"a":1,"z":"z","y":"y"}'),
+        ('insertion', '{"b":2,"c":3,"a":1,"z":"z","y":"y"}'),
+        ('schema', '{"c":3,"a":1,"b":2,"z":"z","y":"y"}'),
+        ('alphabetical', '{"a":1,"b":2,"c":3,"y":"y","z":"z"}'),
+    ],
+)
+def test_field_order(field_order, expected):
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    schema = core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {
+                'c': core_schema.model_field(core_schema.int_schema()),
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(core_schema.int_schema()),
+            },
+            extra_behavior='allow',
+        ),
+        extra_behavior='allow',
+        config=core_schema.CoreConfig(field_order=field_order) if field_order else None,
+    )
+    s = SchemaSerializer(schema)
+    m = MyModel()
+    m.__dict__ = {'b': 2, 'c': 3, 'a': 1}
+    m.__pydantic_extra__ = {'z': 'z', 'y': 'y'}
+    m.__pydantic_fields_set__ = {'a', 'b', 'c'}
+
+    assert s.to_json(m).decode() == expected
+    assert list(s.to_python(m)) == list(json.loads(expected))
+    assert list(s.to_python(m, mode='json')) == list(json.loads(expected))
+
+
+def test_field_order_computed_fields_last():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+        @property
+        def b_volume(self) -> int:
+            return self.y * 3
+
+        @property
+        def a_area(self) -> int:
+            return self.y * 2
+
+    schema = core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {
+                'y': core_schema.model_field(core_schema.int_schema()),
+                'x': core_schema.model_field(core_schema.int_schema()),
+            },
+            computed_fields=[
+                core_schema.computed_field('b_volume', core_schema.int_schema()),
+                core_schema.computed_field('a_area', core_schema.int_schema()),
+            ],
+        ),
+        config=core_schema.CoreConfig(field_order='alphabetical'),
+    )
+    s = SchemaSerializer(schema)
+    m = MyModel()
+    m.__dict__ = {'y': 2, 'x': 1}
+    assert s.to_json(m) == b'{"x":1,"y":2,"a_area":4,"b_volume":6}'
+    assert list(s.to_python(m)) == ['x', 'y', 'a_area', 'b_volume']
+
+
+def test_field_order_invalid():
+    with pytest.raises(SchemaError, match='Invalid field_order: `random`, expected `schema`, `alphabetical` or'):
+        SchemaSerializer(
+            core_schema.model_schema(
+                type('MyModel', (), {}),
+                core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}),
+                config={'field_order': 'random'},
+            )
+        )
+
+
+def test_to_json_sort_keys():
+    class Model:
+        def __init__(self, zebra: int, apple: dict, tags: set) -> None:
+            self.zebra = zebra
+            self.apple = apple
+            self.tags = tags
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {
+                    'zebra': core_schema.model_field(core_schema.int_schema()),
+                    'apple': core_schema.model_field(core_schema.dict_schema()),
+                    'tags': core_schema.model_field(core_schema.set_schema(core_schema.str_schema())),
+                },
+                computed_fields=[core_schema.computed_field('mango', core_schema.int_schema())],
+            ),
+        )
+    )
+    Model.mango = property(lambda self: self.zebra * 2)
+    m = Model(zebra=1, apple={'y': 1, 'x': 2}, tags={'b', 'a'})
+    assert s.to_json(m, sort_keys=True, set_order='sorted') == (
+        b'{"apple":{"x":2,"y":1},"mango":2,"tags":["a","b"],"zebra":1}'
+    )
+    assert s.to_json(m, set_order='sorted') == b'{"zebra":1,"apple":{"y":1,"x":2},"tags":["a","b"],"mango":2}'
+
+
+def test_sort_keys_extra():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'foo': core_schema.model_field(core_schema.int_schema()),
+                    'bar': core_schema.model_field(core_schema.any_schema()),
+                },
+                extra_behavior='allow',
+            ),
+            extra_behavior='allow',
+        )
+    )
+    m = BasicModel(foo=1, bar=[{'y': 1, 'x': {'d': 2, 'c': 3}}], __pydantic_extra__={'zed': 3, 'cat': 4})
+    assert s.to_json(m, sort_keys=True) == b'{"bar":[{"x":{"c":3,"d":2},"y":1}],"cat":4,"foo":1,"zed":3}'
+    assert s.to_json(m) == b'{"foo":1,"bar":[{"y":1,"x":{"d":2,"c":3}}],"zed":3,"cat":4}'
@@ -1,6 +1,6 @@
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import PydanticOmit, SchemaSerializer, core_schema
 
 
 def test_nullable():
@@ -16,3 +16,48 @@ def test_nullable():
         match=r"Expected `int` - serialized value may not be as expected \[input_value='aaa', input_type=str\]",
     ):
         assert s.to_json('aaa') == b'"aaa"'
+
+
+@pytest.mark.parametrize('none_as,expected_json', [('', b'""'), ('null', b'"null"'), (0, b'0'), ([], b'[]')])
+def test_nullable_none_as(none_as, expected_json):
+    s = SchemaSerializer(core_schema.nullable_schema(core_schema.int_schema(), none_as=none_as))
+    assert s.to_json(None) == expected_json
+    assert s.to_python(None, mode='json') == none_as
+    # python mode is unchanged
+    assert s.to_python(None) is None
+    assert s.to_json(1) == b'1'
+
+
+def test_nullable_none_as_key():
+    key_schema = core_schema.nullable_schema(core_schema.int_schema(), none_as='-')
+    s = SchemaSerializer(core_schema.dict_schema(key_schema, core_schema.int_schema()))
+    assert s.to_json({None: 1, 2: 3}) == b'{"-":1,"2":3}'
+
+
+def test_nullable_none_as_omit():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.nullable_schema(core_schema.int_schema(), none_as=PydanticOmit)
+                ),
+                'b': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.nullable_schema(core_schema.int_schema(), none_as=PydanticOmit), default=None
+                    )
+                ),
+                'c': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.int_schema())),
+            }
+        )
+    )
+    assert s.to_json({'a': None, 'b': None, 'c': None}) == b'{"c":null}'
+    assert s.to_python({'a': None, 'b': None, 'c': None}, mode='json') == {'c': None}
+    assert s.to_json({'a': 1, 'b': 2, 'c': 3}) == b'{"a":1,"b":2,"c":3}'
+    assert s.to_python({'a': None, 'b': None, 'c': None}) == {'a': None, 'b': None, 'c': None}
+
+
+def test_nullable_none_as_omit_outside_fields():
+    s = SchemaSerializer(
+        core_schema.list_schema(core_schema.nullable_schema(core_schema.int_schema(), none_as=PydanticOmit))
+    )
+    assert s.to_json([1, None]) == b'[1,null]'
@@ -99,3 +99,18 @@ def test_serialize_with_extra_on_superclass() -> None:
 
     other = Other.__pydantic_validator__.validate_python({'x': 1, 'y': 'some string'})
     assert Parent.__pydantic_serializer__.to_python(other) == {'x': 1}
+
+
+def test_namedtuple():
+    from typing import NamedTuple
+
+    class Point(NamedTuple):
+        x: int
+        y: int
+
+    s = SchemaSerializer(core_schema.namedtuple_schema(Point, [core_schema.int_schema(), core_schema.int_schema()]))
+    assert s.to_python(Point(1, 2)) == (1, 2)
+    assert s.to_json(Point(1, 2)) == b'[1,2]'
+
+    s = SchemaSerializer(core_schema.namedtuple_schema(Point))
+    assert s.to_json(Point(1, 2)) == b'[1,2]'
@@ -0,0 +1,31 @@
+from pathlib import Path, PurePosixPath, PureWindowsPath
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (PurePosixPath('/a/b.txt'), '/a/b.txt'),
+        (PureWindowsPath('C:/a/b'), 'C:\\a\\b'),
+        (Path('a'), 'a'),
+    ],
+)
+def test_path(value, expected):
+    s = SchemaSerializer(core_schema.path_schema())
+    assert s.to_python(value) is value
+    assert s.to_python(value, mode='json') == expected
+    assert s.to_json(value) == SchemaSerializer(core_schema.str_schema()).to_json(expected)
+
+
+def test_path_dict_key():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.path_schema(), core_schema.int_schema()))
+    assert s.to_json({PurePosixPath('a/b'): 1}) == b'{"a/b":1}'
+
+
+def test_path_wrong_type():
+    s = SchemaSerializer(core_schema.path_schema())
+    with pytest.warns(UserWarning, match=r'Expected `path` - serialized value may not be as expected'):
+        assert s.to_python(123, mode='json') == 123
@@ -0,0 +1,51 @@
+from pydantic_core import SchemaSerializer
+from pydantic_core import core_schema as cs
+
+
+class Point:
+    def __init__(self, x: int, y: int):
+        self.x = x
+        self.y = y
+
+    @classmethod
+    def __validate_core__(cls, value):
+        return cls(*value)
+
+    def __serialize_core__(self):
+        return {'x': self.x, 'y': self.y}
+
+
+def test_protocol_custom_serializer():
+    s = SchemaSerializer(cs.protocol_custom_schema(Point))
+    assert s.to_python(Point(1, 2)) == {'x': 1, 'y': 2}
+    assert s.to_python(Point(1, 2), mode='json') == {'x': 1, 'y': 2}
+    assert s.to_json(Point(1, 2)) == b'{"x":1,"y":2}'
+    # other values are serialized by inference
+    assert s.to_python([1, 2]) == [1, 2]
+
+
+def test_protocol_custom_serializer_dict_key():
+    class Tag:
+        def __init__(self, name: str):
+            self.name = name
+
+        @classmethod
+        def __validate_core__(cls, value):
+            return cls(value)
+
+        def __serialize_core__(self):
+            return self.name
+
+    s = SchemaSerializer(cs.dict_schema(cs.protocol_custom_schema(Tag), cs.int_schema()))
+    assert s.to_json({Tag('a'): 1}) == b'{"a":1}'
+
+
+def test_protocol_custom_serializer_no_method():
+    class Opaque:
+        @classmethod
+        def __validate_core__(cls, value):
+            return value
+
+    s = SchemaSerializer(cs.protocol_custom_schema(Opaque))
+    assert s.to_python(123) == 123
+    assert s.to_json('x') == b'"x"'
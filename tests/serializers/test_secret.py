@@ -0,0 +1,48 @@
+import pytest
+
+from pydantic_core import SchemaSerializer, Secret, core_schema, to_json, to_jsonable_python
+
+
+def test_secret_str():
+    s = SchemaSerializer(core_schema.secret_str_schema())
+    secret = Secret('hunter2')
+    assert s.to_python(secret) is secret
+    assert s.to_python(secret, mode='json') == '**********'
+    assert s.to_json(secret) == b'"**********"'
+
+    assert s.to_python(secret, serialize_secrets=True) == 'hunter2'
+    assert s.to_python(secret, mode='json', serialize_secrets=True) == 'hunter2'
+    assert s.to_json(secret, serialize_secrets=True) == b'"hunter2"'
+
+
+def test_secret_bytes():
+    s = SchemaSerializer(core_schema.secret_bytes_schema())
+    secret = Secret(b'hunter2')
+    assert s.to_json(secret) == b'"**********"'
+    assert s.to_python(secret, serialize_secrets=True) == b'hunter2'
+    assert s.to_json(secret, serialize_secrets=True) == b'"hunter2"'
+
+
+def test_secret_in_dict():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.secret_str_schema()))
+    assert s.to_json({'password': Secret('hunter2')}) == b'{"password":"**********"}'
+    assert s.to_json({'password': Secret('hunter2')}, serialize_secrets=True) == b'{"password":"hunter2"}'
+
+
+def test_secret_unexpected():
+    s = SchemaSerializer(core_schema.secret_str_schema())
+    with pytest.warns(UserWarning, match='Expected `secret_str` - serialized value may not be as expected'):
+        assert s.to_json('hunter2') == b'"hunter2"'
+
+
+def test_secret_inferred():
+    value = {'password': Secret('hunter2'), Secret('key'): [Secret(b'bytes')]}
+    assert to_json(value) == b'{"password":"**********","**********":["**********"]}'
+    assert to_json(value, serialize_secrets=True) == b'{"password":"hunter2","key":["bytes"]}'
+    assert to_jsonable_python(value) == {'password': '**********', '**********': ['**********']}
+    assert to_jsonable_python(value, serialize_secrets=True) == {'password': 'hunter2', 'key': ['bytes']}
+
+    s = SchemaSerializer(core_schema.any_schema())
+    assert s.to_python(value['password']) is value['password']
+    assert s.to_python(value['password'], serialize_secrets=True) == 'hunter2'
+    assert s.to_json(value['password'], serialize_secrets=True) == b'"hunter2"'
@@ -58,3 +58,28 @@ def test_set_fallback(input_value, json_output, expected_type):
         match=f'Expected `{expected_type}` - serialized value may not be as expected',
     ):
         assert json.loads(v.to_json(input_value)) == json_output
+
+
+def test_set_order_sorted():
+    v = SchemaSerializer(core_schema.set_schema(core_schema.int_schema()))
+    assert v.to_json({3, 10, 1, -5}, set_order='sorted') == b'[-5,1,3,10]'
+    assert v.to_json(set(), set_order='sorted') == b'[]'
+
+    v = SchemaSerializer(core_schema.frozenset_schema(core_schema.any_schema()))
+    assert v.to_json(frozenset(['c', 'a', 'b']), set_order='sorted') == b'["a","b","c"]'
+    # values which can't be compared are ordered by type name, then repr
+    assert v.to_json(frozenset(['b', 2, 'a', 1]), set_order='sorted') == b'[1,2,"a","b"]'
+
+    with pytest.raises(ValueError, match="Invalid set_order value: 'random', expected 'insertion' or 'sorted'"):
+        v.to_json(frozenset(), set_order='random')
+
+
+def test_set_order_nested():
+    v = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {'tags': core_schema.typed_dict_field(core_schema.set_schema(core_schema.str_schema()))}
+        )
+    )
+    tags = {'zeta', 'alpha', 'mu'}
+    assert v.to_json({'tags': tags}, set_order='sorted') == b'{"tags":["alpha","mu","zeta"]}'
+    assert v.to_json({'tags': tags}, set_order='insertion') == v.to_json({'tags': tags})
@@ -3,7 +3,7 @@ from enum import IntEnum
 
 import pytest
 
-from pydantic_core import CoreConfig, SchemaSerializer, core_schema
+from pydantic_core import CoreConfig, PydanticSerializationError, SchemaSerializer, core_schema
 
 try:
     import numpy
@@ -169,3 +169,126 @@ def test_float_inf_and_nan_serializers(value, expected_json, config):
 
     # Serialized JSON value respects the ser_json_inf_nan setting
     assert s.to_json(value).decode() == expected_json
+
+
+@pytest.mark.parametrize('value', [float('inf'), float('-inf'), float('nan')])
+def test_float_inf_nan_error(value):
+    s = SchemaSerializer(core_schema.float_schema(), CoreConfig(ser_json_inf_nan='error'))
+    assert s.to_python(value) is value
+    assert s.to_json(1.5) == b'1.5'
+    assert s.to_python(1.5, mode='json') == 1.5
+    msg = r"Non-finite float -?(inf|nan) is not permitted, set `ser_json_inf_nan` to 'null', 'constants' or 'strings'"
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_json(value)
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_python(value, mode='json')
+
+
+def test_float_inf_nan_field_override():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.float_schema()),
+                'b': core_schema.typed_dict_field(core_schema.float_schema(ser_json_inf_nan='strings')),
+                'c': core_schema.typed_dict_field(core_schema.float_schema(ser_json_inf_nan='error')),
+            }
+        ),
+        CoreConfig(ser_json_inf_nan='null'),
+    )
+    inf = float('inf')
+    assert s.to_json({'a': inf, 'b': inf, 'c': 1.0}) == b'{"a":null,"b":"Infinity","c":1.0}'
+    with pytest.raises(PydanticSerializationError, match='Non-finite float inf is not permitted'):
+        s.to_json({'a': inf, 'b': inf, 'c': inf})
+
+
+@pytest.mark.parametrize(
+    'value,ser_json_float,expected_json',
+    [
+        (0.1 + 0.2, 'repr', b'0.30000000000000004'),
+        (0.1 + 0.2, 2, b'0.3'),
+        (1.23456789, 3, b'1.235'),
+        (-2.5, 0, b'-2.0'),
+        (1e300, 2, b'1e300'),
+        (1500.0, 'scientific', b'1.5e3'),
+        (0.00012, 'scientific', b'1.2e-4'),
+        (-3.0, 'scientific', b'-3e0'),
+    ],
+)
+def test_float_format(value, ser_json_float, expected_json):
+    s = SchemaSerializer(core_schema.float_schema(), CoreConfig(ser_json_float=ser_json_float))
+    assert s.to_json(value) == expected_json
+
+
+def test_float_format_python():
+    s = SchemaSerializer(core_schema.float_schema(), CoreConfig(ser_json_float=2))
+    assert s.to_python(1.23456) == 1.23456
+    assert s.to_python(1.23456, mode='json') == 1.23
+    assert s.to_python(FloatSubClass(1.23456), mode='json') == 1.23
+
+    s = SchemaSerializer(core_schema.float_schema(), CoreConfig(ser_json_float='scientific'))
+    assert s.to_python(1.23456, mode='json') == 1.23456
+
+
+def test_float_format_field_and_infer():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.float_schema()),
+                'b': core_schema.typed_dict_field(core_schema.float_schema(ser_json_float='repr')),
+                'c': core_schema.typed_dict_field(core_schema.any_schema()),
+            }
+        ),
+        CoreConfig(ser_json_float=1),
+    )
+    value = {'a': 1.26, 'b': 1.26, 'c': [1.26, float('inf')]}
+    assert s.to_json(value) == b'{"a":1.3,"b":1.26,"c":[1.3,null]}'
+    assert s.to_python(value, mode='json') == {'a': 1.3, 'b': 1.26, 'c': [1.3, None]}
+
+
+@pytest.mark.parametrize('ser_json_float', ['fixed', True, -1])
+def test_float_format_invalid(ser_json_float):
+    with pytest.raises(Exception, match='Invalid FloatFormat serialization mode'):
+        SchemaSerializer(core_schema.float_schema(), CoreConfig(ser_json_float=ser_json_float))
+
+
+@pytest.mark.parametrize(
+    'value,expected_json,expected_python',
+    [
+        (2**53 - 1, b'9007199254740991', 2**53 - 1),
+        (-(2**53 - 1), b'-9007199254740991', -(2**53 - 1)),
+        (2**53, b'"9007199254740992"', '9007199254740992'),
+        (-(2**53), b'"-9007199254740992"', '-9007199254740992'),
+        (10**40, b'"' + _BIG_NUMBER_BYTES + b'"', _BIG_NUMBER_BYTES.decode()),
+        (IntSubClass(2**60), b'"1152921504606846976"', '1152921504606846976'),
+    ],
+)
+def test_bigint_string(value, expected_json, expected_python):
+    s = SchemaSerializer(core_schema.int_schema(), CoreConfig(ser_json_bigint='string'))
+    assert s.to_json(value) == expected_json
+    assert s.to_python(value, mode='json') == expected_python
+    assert s.to_python(value) == value
+
+    s = SchemaSerializer(core_schema.any_schema(), CoreConfig(ser_json_bigint='string'))
+    assert s.to_json([value]) == b'[' + expected_json + b']'
+    assert s.to_python([value], mode='json') == [expected_python]
+
+
+def test_bigint_error():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()), CoreConfig(ser_json_bigint='error'))
+    assert s.to_json([1, 2**53 - 1]) == b'[1,9007199254740991]'
+    msg = r'Integer 9007199254740992 is outside the range JavaScript can represent exactly \(±9007199254740991\)'
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_json([1, 2**53])
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_python([2**53], mode='json')
+    assert s.to_python([2**53]) == [2**53]
+
+    s = SchemaSerializer(core_schema.any_schema(), CoreConfig(ser_json_bigint='error'))
+    with pytest.raises(PydanticSerializationError, match=r'Integer -100000000000000000000000000000000000000000 is'):
+        s.to_json({'a': -(10**41)})
+
+
+def test_bigint_default():
+    s = SchemaSerializer(core_schema.int_schema())
+    assert s.to_json(2**53) == b'9007199254740992'
+    assert s.to_python(2**53, mode='json') == 2**53
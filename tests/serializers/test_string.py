@@ -3,7 +3,7 @@ from enum import Enum
 
 import pytest
 
-from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+from pydantic_core import PydanticSerializationError, SchemaError, SchemaSerializer, core_schema
 
 
 def test_str():
@@ -148,3 +148,62 @@ def test_subclass_str(schema_type, input_value, expected):
     assert type(v) == str
 
     assert s.to_json(input_value) == json.dumps(expected).encode('utf-8')
+
+
+@pytest.mark.parametrize('schema_type', ['str', 'any'])
+def test_invalid_utf8_error(schema_type):
+    s = SchemaSerializer({'type': schema_type})
+    assert s.to_python('a\ud800', mode='json') == 'a\ud800'
+    with pytest.raises(PydanticSerializationError, match="'utf-8' codec can't encode character '\\\\ud800'"):
+        s.to_json('a\ud800')
+
+    # dict keys are written lossily by default
+    s = SchemaSerializer(core_schema.dict_schema({'type': schema_type}, core_schema.int_schema()))
+    assert s.to_json({'a\ud800b': 1}) == '{"a���b":1}'.encode()
+
+
+@pytest.mark.parametrize('schema_type', ['str', 'any'])
+def test_invalid_utf8_replace(schema_type):
+    s = SchemaSerializer({'type': schema_type}, core_schema.CoreConfig(ser_json_invalid_utf8='replace'))
+    assert s.to_json('a\ud800b\udfff') == 'a�b�'.join('""').encode()
+    assert s.to_json('a\ud800', ensure_ascii=True) == b'"a\\ufffd"'
+    assert s.to_python('a\ud800', mode='json') == 'a�'
+    assert s.to_python(StrSubclass('a\ud800'), mode='json') == 'a�'
+    assert s.to_python('a\ud800') == 'a\ud800'
+    assert s.to_json('emoji 💩') == b'"emoji \xf0\x9f\x92\xa9"'
+
+    s = SchemaSerializer(
+        core_schema.dict_schema({'type': schema_type}, core_schema.int_schema()),
+        core_schema.CoreConfig(ser_json_invalid_utf8='replace'),
+    )
+    assert s.to_json({'\ud800': 1}) == '{"�":1}'.encode()
+
+
+@pytest.mark.parametrize('schema_type', ['str', 'any'])
+def test_invalid_utf8_surrogatepass(schema_type):
+    s = SchemaSerializer({'type': schema_type}, core_schema.CoreConfig(ser_json_invalid_utf8='surrogatepass'))
+    value = 'a\ud800é\ude00"b'
+    # lone surrogates are escaped as `json.dumps()` does, other characters are unchanged
+    assert s.to_json(value) == b'"a\\ud800\xc3\xa9\\ude00\\"b"'
+    assert s.to_json(value, ensure_ascii=True) == json.dumps(value).encode()
+    assert json.loads(s.to_json(value)) == value
+    assert s.to_python(value, mode='json') == value
+    assert s.to_python(StrSubclass(value), mode='json') == value
+    assert type(s.to_python(StrSubclass(value), mode='json')) == str
+
+    with pytest.raises(PydanticSerializationError, match='lone surrogates are not permitted in canonical JSON'):
+        s.to_json(value, canonical=True)
+
+
+def test_invalid_utf8_surrogatepass_sort_keys():
+    s = SchemaSerializer(core_schema.any_schema(), core_schema.CoreConfig(ser_json_invalid_utf8='surrogatepass'))
+    assert s.to_json({'b': 'x\udc00', 'a': 1}, sort_keys=True, ensure_ascii=True) == b'{"a":1,"b":"x\\udc00"}'
+    value = {'b': ['x\udc00'], 'a': 1}
+    assert s.to_json(value, indent=2, sort_keys=True) == json.dumps(value, indent=2, sort_keys=True).encode()
+    with pytest.raises(PydanticSerializationError, match='Dict keys containing lone surrogates'):
+        s.to_json({'\ud800': 1})
+
+
+def test_invalid_utf8_mode_invalid():
+    with pytest.raises(SchemaError, match='Invalid InvalidUtf8Mode serialization mode: `ignore`'):
+        SchemaSerializer(core_schema.str_schema(), core_schema.CoreConfig(ser_json_invalid_utf8='ignore'))
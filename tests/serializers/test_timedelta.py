@@ -1,8 +1,9 @@
+import json
 from datetime import timedelta
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaSerializer, core_schema, to_json
 
 try:
     import pandas
@@ -38,6 +39,36 @@ def test_timedelta_float():
     assert v.to_json(timedelta(seconds=4, microseconds=500_000)) == b'4.5'
 
 
+@pytest.mark.parametrize(
+    'mode,value,expected',
+    [
+        ('milliseconds_int', timedelta(days=1, seconds=4, microseconds=500_999), 86_404_500),
+        ('milliseconds_int', timedelta(milliseconds=-1, microseconds=-500), -2),
+        ('milliseconds_int', timedelta(), 0),
+        ('hh:mm:ss', timedelta(days=1, hours=2, minutes=3, seconds=4), '26:03:04'),
+        ('hh:mm:ss', timedelta(seconds=4, microseconds=500_000), '00:00:04.500000'),
+        ('hh:mm:ss', timedelta(minutes=-90), '-01:30:00'),
+        ('human', timedelta(days=2, hours=3, seconds=4, microseconds=500_000), '2d 3h 4.5s'),
+        ('human', timedelta(minutes=5), '5m'),
+        ('human', timedelta(microseconds=250), '0.00025s'),
+        ('human', timedelta(), '0s'),
+        ('human', timedelta(hours=-1, minutes=-1), '-1h 1m'),
+    ],
+)
+def test_timedelta_modes(mode, value, expected):
+    v = SchemaSerializer(core_schema.timedelta_schema(), config={'ser_json_timedelta': mode})
+    assert v.to_python(value) == value
+    assert v.to_python(value, mode='json') == expected
+    assert v.to_json(value) == json.dumps(expected).encode()
+    assert to_json(value, timedelta_mode=mode) == json.dumps(expected).encode()
+
+    v = SchemaSerializer(
+        core_schema.dict_schema(core_schema.timedelta_schema(), core_schema.int_schema()),
+        config={'ser_json_timedelta': mode},
+    )
+    assert v.to_python({value: 1}, mode='json') == {str(expected): 1}
+
+
 def test_timedelta_key():
     v = SchemaSerializer(core_schema.dict_schema(core_schema.timedelta_schema(), core_schema.int_schema()))
     assert v.to_python({timedelta(days=2, hours=3, minutes=4): 1}) == {timedelta(days=2, hours=3, minutes=4): 1}
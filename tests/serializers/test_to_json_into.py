@@ -0,0 +1,112 @@
+import io
+import json
+from pathlib import Path
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+
+s = SchemaSerializer(core_schema.list_schema(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema())))
+value = [{'a': i, 'b': -i} for i in range(1000)]
+
+
+def test_file_object():
+    f = io.BytesIO()
+    assert s.to_json_into(value, f) is None
+    assert f.getvalue() == s.to_json(value)
+
+
+@pytest.mark.parametrize('to_path', [str, Path])
+def test_path(tmp_path: Path, to_path):
+    path = tmp_path / 'out.json'
+    path.write_bytes(b'existing content which is longer than the output' * 1000)
+    s.to_json_into(value, to_path(path))
+    assert path.read_bytes() == s.to_json(value)
+
+
+class RecordingFile:
+    def __init__(self):
+        self.chunks = []
+
+    def write(self, data):
+        self.chunks.append(data)
+
+
+@pytest.mark.parametrize('buffer_size', [1, 100, 10_000_000])
+def test_buffer_size(buffer_size):
+    f = RecordingFile()
+    s.to_json_into(value, f, buffer_size=buffer_size)
+    assert b''.join(f.chunks) == s.to_json(value)
+    assert all(type(chunk) is bytes for chunk in f.chunks)
+    if buffer_size == 10_000_000:
+        assert len(f.chunks) == 1
+    else:
+        assert all(len(chunk) >= buffer_size for chunk in f.chunks[:-1])
+        assert len(f.chunks) > 1
+
+
+def test_buffer_size_invalid():
+    with pytest.raises(ValueError, match='`buffer_size` must be greater than 0'):
+        s.to_json_into(value, io.BytesIO(), buffer_size=0)
+
+
+class PartialWriter(io.RawIOBase):
+    """A raw file which only takes a few bytes at a time."""
+
+    def __init__(self):
+        self.data = bytearray()
+
+    def writable(self):
+        return True
+
+    def write(self, data):
+        chunk = bytes(data[:7])
+        self.data.extend(chunk)
+        return len(chunk)
+
+
+def test_partial_writes():
+    f = PartialWriter()
+    s.to_json_into(value, f, buffer_size=1000)
+    assert bytes(f.data) == s.to_json(value)
+
+
+def test_options():
+    f = io.BytesIO()
+    s.to_json_into([{'b': 1, 'a': 2}], f, indent=2, sort_keys=True)
+    assert f.getvalue() == json.dumps([{'a': 2, 'b': 1}], indent=2).encode()
+
+    f = io.BytesIO()
+    s.to_json_into([{'b': 1, 'a': 2}], f, canonical=True)
+    assert f.getvalue() == b'[{"a":2,"b":1}]'
+
+
+def test_write_error():
+    class BrokenFile:
+        def write(self, data):
+            raise RuntimeError('disk full')
+
+    with pytest.raises(RuntimeError, match='disk full'):
+        s.to_json_into(value, BrokenFile(), buffer_size=10)
+
+
+def test_serialization_error():
+    f = io.BytesIO()
+    with pytest.raises(PydanticSerializationError, match='Expected `int`'):
+        s.to_json_into([{'a': 1}, {'b': 'x'}], f, warnings='error')
+
+
+def test_serialization_error_path(tmp_path: Path):
+    path = tmp_path / 'out.json'
+    path.write_bytes(b'OLD')
+    with pytest.raises(PydanticSerializationError, match='Expected `int`'):
+        s.to_json_into([{'a': 1}, {'b': 'x'}], path, warnings='error', buffer_size=1)
+    assert path.read_bytes() == b'OLD'
+    assert list(tmp_path.iterdir()) == [path]
+
+
+def test_invalid_file():
+    with pytest.raises(TypeError, match='Expected a path or a binary file-like object'):
+        s.to_json_into(value, 123)
+    with pytest.raises(FileNotFoundError):
+        s.to_json_into(value, '/does/not/exist/out.json')
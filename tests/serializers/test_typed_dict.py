@@ -127,6 +127,27 @@ def test_alias():
     assert json.loads(s.to_json(value, by_alias=False)) == IsStrictDict(cat=0, dog=1, bird=2)
 
 
+def test_field_exclude_none_and_exclude_if():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.nullable_schema(core_schema.int_schema()), serialization_exclude_none=True
+                ),
+                'b': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.int_schema()), serialization_exclude_if=lambda v: not v
+                ),
+                'c': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.int_schema())),
+            }
+        )
+    )
+    assert s.to_python({'a': 1, 'b': [2], 'c': 3}) == IsStrictDict(a=1, b=[2], c=3)
+    assert s.to_python({'a': None, 'b': [], 'c': None}) == IsStrictDict(c=None)
+    assert s.to_python({'a': None, 'b': [], 'c': None}, mode='json') == IsStrictDict(c=None)
+    assert s.to_json({'a': None, 'b': [], 'c': None}) == b'{"c":null}'
+    assert s.to_json({'a': 0, 'b': [0], 'c': None}) == b'{"a":0,"b":[0],"c":null}'
+
+
 def test_exclude_none():
     v = SchemaSerializer(
         core_schema.typed_dict_schema(
@@ -367,3 +388,53 @@ def test_by_alias_and_name_config_interaction(config, runtime, expected) -> None
     )
     s = SchemaSerializer(schema, config=core_schema.CoreConfig(serialize_by_alias=config or False))
     assert s.to_python(Model(my_field=1), by_alias=runtime) == expected
+
+
+@pytest.mark.parametrize(
+    'field_order,expected',
+    [
+        ('insertion', '{"b":2,"extra":4,"c":3,"a":1}'),
+        ('schema', '{"c":3,"a":1,"b":2,"extra":4}'),
+        ('alphabetical', '{"a":1,"b":2,"c":3,"extra":4}'),
+    ],
+)
+def test_field_order(field_order, expected):
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'c': core_schema.typed_dict_field(core_schema.int_schema()),
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+            },
+            extra_behavior='allow',
+            config=core_schema.CoreConfig(field_order=field_order),
+        )
+    )
+    value = {'b': 2, 'extra': 4, 'c': 3, 'a': 1}
+    assert s.to_json(value).decode() == expected
+    assert list(s.to_python(value)) == list(json.loads(expected))
+
+
+@pytest.mark.parametrize(
+    'config_merge,expected',
+    [
+        # the typed dict's config replaces the parent config
+        ('replace', '{"cD":2,"aB":1}'),
+        # keys from both configs apply
+        ('inherit', '{"aB":1,"cD":2}'),
+    ],
+)
+def test_config_merge(config_merge, expected):
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'c_d': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'a_b': core_schema.typed_dict_field(core_schema.int_schema()),
+                },
+                config=core_schema.CoreConfig(alias_generator='camel'),
+            )
+        ),
+        config=core_schema.CoreConfig(field_order='alphabetical', config_merge=config_merge),
+    )
+    assert s.to_json([{'c_d': 2, 'a_b': 1}], by_alias=True).decode() == f'[{expected}]'
@@ -9,7 +9,12 @@ from typing import Any, ClassVar, Literal, Union
 
 import pytest
 
-from pydantic_core import PydanticSerializationUnexpectedValue, SchemaSerializer, core_schema
+from pydantic_core import (
+    PydanticSerializationError,
+    PydanticSerializationUnexpectedValue,
+    SchemaSerializer,
+    core_schema,
+)
 
 
 class BaseModel:
@@ -669,6 +674,33 @@ def test_tagged_union() -> None:
     assert s.to_python(model_b) == {'field': 1, 'tag': 'b'}
 
 
+def test_tagged_union_composite_discriminator() -> None:
+    def choice_schema(value_schema: core_schema.CoreSchema) -> core_schema.TypedDictSchema:
+        return core_schema.typed_dict_schema(
+            {
+                'kind': core_schema.typed_dict_field(core_schema.str_schema()),
+                'version': core_schema.typed_dict_field(core_schema.int_schema()),
+                'value': core_schema.typed_dict_field(value_schema),
+            }
+        )
+
+    s = SchemaSerializer(
+        core_schema.tagged_union_schema(
+            choices={
+                ('a', 1): choice_schema(core_schema.int_schema()),
+                ('a', 2): choice_schema(
+                    core_schema.int_schema(serialization=core_schema.to_string_ser_schema(when_used='always'))
+                ),
+            },
+            discriminator=('kind', 'version'),
+        )
+    )
+
+    assert s.to_python({'kind': 'a', 'version': 1, 'value': 3}) == {'kind': 'a', 'version': 1, 'value': 3}
+    assert s.to_python({'kind': 'a', 'version': 2, 'value': 3}) == {'kind': 'a', 'version': 2, 'value': '3'}
+    assert s.to_json({'kind': 'a', 'version': 2, 'value': 3}) == b'{"kind":"a","version":2,"value":"3"}'
+
+
 def test_union_float_int() -> None:
     s = SchemaSerializer(core_schema.union_schema([core_schema.float_schema(), core_schema.int_schema()]))
 
@@ -1110,3 +1142,49 @@ def test_discriminated_union_ser_with_typed_dict() -> None:
 
     assert v.to_python({'type': 'a', 'a': 1}, warnings='error') == {'type': 'a', 'a': 1}
     assert v.to_python({'type': 'b', 'b': 'foo'}, warnings='error') == {'type': 'b', 'b': 'foo'}
+
+
+def test_union_lax_retry_warning() -> None:
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(
+                    core_schema.union_schema([core_schema.int_schema(), core_schema.bool_schema()])
+                ),
+                'y': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        ),
+        config=core_schema.CoreConfig(ser_warn_lax_retry=True),
+    )
+
+    assert s.to_python({'x': 1, 'y': [1]}, warnings='error') == {'x': 1, 'y': [1]}
+
+    with pytest.raises(PydanticSerializationError) as exc_info:
+        s.to_python({'x': IntSubclass(1), 'y': []}, warnings='error')
+    assert str(exc_info.value) == (
+        'Pydantic serializer warnings:\n'
+        '  `Union[int, bool]` at `dict.x` only matched after a lax retry (1 time)'
+    )
+
+    with pytest.warns(UserWarning, match=r'`Union\[int, bool\]` at `dict.x` only matched after a lax retry \(1 time\)'):
+        assert s.to_json({'x': IntSubclass(1), 'y': []}) == b'{"x":1,"y":[]}'
+
+
+def test_union_lax_retry_warning_count() -> None:
+    s = SchemaSerializer(
+        core_schema.list_schema(core_schema.union_schema([core_schema.int_schema(), core_schema.bool_schema()])),
+        config=core_schema.CoreConfig(ser_warn_lax_retry=True),
+    )
+
+    with pytest.raises(PydanticSerializationError) as exc_info:
+        s.to_python([IntSubclass(1), 2, IntSubclass(3)], warnings='error')
+    assert str(exc_info.value) == (
+        'Pydantic serializer warnings:\n'
+        '  `Union[int, bool]` at `<root>` only matched after a lax retry (2 times)'
+    )
+
+
+def test_union_lax_retry_warning_disabled() -> None:
+    s = SchemaSerializer(core_schema.union_schema([core_schema.int_schema(), core_schema.bool_schema()]))
+
+    assert s.to_python(IntSubclass(1), warnings='error') == 1
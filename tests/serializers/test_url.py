@@ -2,7 +2,7 @@ import pickle
 
 import pytest
 
-from pydantic_core import MultiHostUrl, SchemaSerializer, SchemaValidator, Url, core_schema
+from pydantic_core import EmailAddress, MultiHostUrl, SchemaSerializer, SchemaValidator, Url, core_schema
 
 
 def test_url():
@@ -109,3 +109,76 @@ def test_url_pickle(value):
     pickled = pickle.dumps(value)
     unpickled = pickle.loads(pickled)
     assert value == unpickled
+
+
+def test_url_pickle_relative():
+    url = SchemaValidator(core_schema.url_schema(allow_relative=True)).validate_python('/api?a=1')
+    unpickled = pickle.loads(pickle.dumps(url))
+    assert unpickled == url
+    assert unpickled.is_relative
+    assert str(unpickled) == '/api?a=1'
+
+
+class PickleUrl(Url):
+    pass
+
+
+def test_url_subclass_pickle():
+    url = PickleUrl('https://example.com')
+    url.extra = 1
+    unpickled = pickle.loads(pickle.dumps(url))
+    assert type(unpickled) is PickleUrl
+    assert unpickled == url
+    assert unpickled.extra == 1
+
+
+def test_email():
+    v = SchemaValidator(core_schema.email_schema())
+    s = SchemaSerializer(core_schema.email_schema())
+
+    email = v.validate_python('user@Bücher.example')
+    assert isinstance(email, EmailAddress)
+
+    assert s.to_python(email) == email
+    assert s.to_python(email, mode='json') == 'user@bücher.example'
+    assert s.to_json(email) == '"user@bücher.example"'.encode()
+
+    with pytest.warns(UserWarning, match=r'Expected `email` - serialized value may not be as expected'):
+        assert s.to_python('user@example.com', mode='json') == 'user@example.com'
+
+
+def test_email_any_serializer():
+    s = SchemaSerializer(core_schema.any_schema())
+    email = EmailAddress('user@example.com')
+    assert s.to_python(email, mode='json') == 'user@example.com'
+    assert s.to_json({email: [email]}) == b'{"user@example.com":["user@example.com"]}'
+
+
+def test_url_preserve_original():
+    v = SchemaValidator(core_schema.url_schema(preserve_original=True))
+    s = SchemaSerializer(core_schema.url_schema())
+
+    url = v.validate_python('HTTPS://Example.COM')
+    assert str(url) == 'https://example.com/'
+    assert url.original == 'HTTPS://Example.COM'
+    assert url == Url('https://example.com/')
+    assert hash(url) == hash(Url('https://example.com/'))
+
+    assert s.to_python(url, mode='json') == 'https://example.com/'
+    assert s.to_python(url, mode='json', round_trip=True) == 'HTTPS://Example.COM'
+    assert s.to_json(url, round_trip=True) == b'"HTTPS://Example.COM"'
+    assert SchemaSerializer(core_schema.any_schema()).to_json([url], round_trip=True) == b'["HTTPS://Example.COM"]'
+
+    assert SchemaValidator(core_schema.url_schema()).validate_python('HTTPS://Example.COM').original is None
+    assert s.to_json(Url('HTTPS://Example.COM'), round_trip=True) == b'"https://example.com/"'
+
+
+def test_multi_host_url_preserve_original():
+    v = SchemaValidator(core_schema.multi_host_url_schema(preserve_original=True))
+    s = SchemaSerializer(core_schema.multi_host_url_schema())
+
+    url = v.validate_python('https://Host1,HOST2:5433/db')
+    assert str(url) == 'https://host1,host2:5433/db'
+    assert url.original == 'https://Host1,HOST2:5433/db'
+    assert s.to_json(url) == b'"https://host1,host2:5433/db"'
+    assert s.to_json(url, round_trip=True) == b'"https://Host1,HOST2:5433/db"'
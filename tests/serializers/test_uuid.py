@@ -62,3 +62,19 @@ def test_any_uuid_key():
 
     assert v.to_python(input_value, mode='json') == {'12345678-1234-5678-1234-567812345678': 1}
     assert v.to_json(input_value) == b'{"12345678-1234-5678-1234-567812345678":1}'
+
+
+def test_uuid_int():
+    value = UUID('12345678-1234-5678-1234-567812345678')
+    v = SchemaSerializer(core_schema.uuid_schema(), config={'ser_json_uuid': 'int'})
+    assert v.to_python(value) == value
+    assert v.to_python(value, mode='json') == value.int
+    assert v.to_json(value) == str(value.int).encode()
+
+    v = SchemaSerializer(core_schema.dict_schema(core_schema.uuid_schema()), config={'ser_json_uuid': 'int'})
+    assert v.to_json({value: 1}) == f'{{"{value.int}":1}}'.encode()
+
+    v = SchemaSerializer(core_schema.any_schema(), config={'ser_json_uuid': 'int'})
+    assert v.to_python([value], mode='json') == [value.int]
+    assert v.to_json([value]) == f'[{value.int}]'.encode()
+    assert v.to_python({value: 1}, mode='json') == {str(value.int): 1}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use _pydantic_core::{SchemaSerializer, SchemaValidator, WarningsArg};
+    use _pydantic_core::{SchemaSerializer, SchemaValidator, SetOrder, WarningsArg};
     use pyo3::ffi::c_str; // can switch to c"" literals on MSRV >= 1.77
     use pyo3::prelude::*;
     use pyo3::types::PyDict;
@@ -104,6 +104,14 @@ a = A()
                     None,
                     false,
                     None,
+                    false,
+                    None,
+                    false,
+                    SetOrder::Insertion,
+                    false,
+                    None,
+                    None,
+                    false,
                 )
                 .unwrap();
             let serialized: &[u8] = serialized.extract(py).unwrap();
@@ -136,7 +144,7 @@ json_input = '{"a": "something"}'
             let json_input = locals.get_item("json_input").unwrap().unwrap();
             let binding = SchemaValidator::py_new(py, &schema, None)
                 .unwrap()
-                .validate_json(py, &json_input, None, None, None, false.into(), None, None)
+                .validate_json_default(py, &json_input)
                 .unwrap();
             let validation_result: Bound<'_, PyAny> = binding.extract(py).unwrap();
             let repr = format!("{}", validation_result.repr().unwrap());
@@ -212,6 +220,14 @@ dump_json_input_2 = {'a': 'something'}
                     None,
                     false,
                     None,
+                    false,
+                    None,
+                    false,
+                    SetOrder::Insertion,
+                    false,
+                    None,
+                    None,
+                    false,
                 )
                 .unwrap();
             let repr = format!("{}", serialization_result.bind(py).repr().unwrap());
@@ -233,6 +249,14 @@ dump_json_input_2 = {'a': 'something'}
                     None,
                     false,
                     None,
+                    false,
+                    None,
+                    false,
+                    SetOrder::Insertion,
+                    false,
+                    None,
+                    None,
+                    false,
                 )
                 .unwrap();
             let repr = format!("{}", serialization_result.bind(py).repr().unwrap());
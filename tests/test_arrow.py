@@ -0,0 +1,163 @@
+import ctypes
+import struct
+
+import pytest
+
+from pydantic_core import ArrowArray, SchemaValidator, ValidationError, core_schema
+
+
+class ArrowSchemaStruct(ctypes.Structure):
+    _fields_ = [
+        ('format', ctypes.c_char_p),
+        ('name', ctypes.c_char_p),
+        ('metadata', ctypes.c_char_p),
+        ('flags', ctypes.c_int64),
+        ('n_children', ctypes.c_int64),
+        ('children', ctypes.c_void_p),
+        ('dictionary', ctypes.c_void_p),
+        ('release', ctypes.c_void_p),
+        ('private_data', ctypes.c_void_p),
+    ]
+
+
+class ArrowArrayStruct(ctypes.Structure):
+    _fields_ = [
+        ('length', ctypes.c_int64),
+        ('null_count', ctypes.c_int64),
+        ('offset', ctypes.c_int64),
+        ('n_buffers', ctypes.c_int64),
+        ('n_children', ctypes.c_int64),
+        ('buffers', ctypes.POINTER(ctypes.c_void_p)),
+        ('children', ctypes.c_void_p),
+        ('dictionary', ctypes.c_void_p),
+        ('release', ctypes.c_void_p),
+        ('private_data', ctypes.c_void_p),
+    ]
+
+
+def capsule_struct(capsule, name: bytes, struct_type):
+    get_pointer = ctypes.pythonapi.PyCapsule_GetPointer
+    get_pointer.restype = ctypes.c_void_p
+    get_pointer.argtypes = [ctypes.py_object, ctypes.c_char_p]
+    return struct_type.from_address(get_pointer(capsule, name))
+
+
+def read_buffer(array: ArrowArrayStruct, index: int, size: int) -> bytes:
+    return ctypes.string_at(array.buffers[index], size)
+
+
+def decode(arrow_array: ArrowArray):
+    """Read the exported arrays back using only the C data interface."""
+    schema_capsule, array_capsule = arrow_array.__arrow_c_array__()
+    schema = capsule_struct(schema_capsule, b'arrow_schema', ArrowSchemaStruct)
+    array = capsule_struct(array_capsule, b'arrow_array', ArrowArrayStruct)
+    assert schema.release and array.release
+    assert array.offset == 0 and array.n_children == 0
+    length = array.length
+
+    if array.buffers[0]:
+        validity = read_buffer(array, 0, (length + 7) // 8)
+        valid = [bool(validity[i // 8] & (1 << (i % 8))) for i in range(length)]
+    else:
+        valid = [True] * length
+
+    fmt = schema.format
+    if fmt == b'l':
+        values = list(struct.unpack(f'<{length}q', read_buffer(array, 1, 8 * length)))
+    elif fmt == b'g':
+        values = list(struct.unpack(f'<{length}d', read_buffer(array, 1, 8 * length)))
+    elif fmt == b'b':
+        bits = read_buffer(array, 1, (length + 7) // 8)
+        values = [bool(bits[i // 8] & (1 << (i % 8))) for i in range(length)]
+    else:
+        assert fmt == b'u'
+        offsets = struct.unpack(f'<{length + 1}i', read_buffer(array, 1, 4 * (length + 1)))
+        data = read_buffer(array, 2, offsets[-1])
+        values = [data[offsets[i] : offsets[i + 1]].decode() for i in range(length)]
+
+    return fmt.decode(), bool(schema.flags & 2), array.null_count, [v if ok else None for v, ok in zip(values, valid)]
+
+
+@pytest.mark.parametrize(
+    'items_schema,input_value,expected',
+    [
+        (core_schema.int_schema(), [1, '2', -3], ('l', False, 0, [1, 2, -3])),
+        (core_schema.float_schema(), [1.5, '2', 3], ('g', False, 0, [1.5, 2.0, 3.0])),
+        (core_schema.bool_schema(), [True, 'false', 1] * 3, ('b', False, 0, [True, False, True] * 3)),
+        (core_schema.str_schema(), ['a', '', 'héllo'], ('u', False, 0, ['a', '', 'héllo'])),
+        (core_schema.int_schema(), [], ('l', False, 0, [])),
+        (
+            core_schema.nullable_schema(core_schema.int_schema()),
+            [1, None, 3],
+            ('l', True, 1, [1, None, 3]),
+        ),
+        (
+            core_schema.nullable_schema(core_schema.str_schema()),
+            [None, 'b', None],
+            ('u', True, 2, [None, 'b', None]),
+        ),
+        (core_schema.nullable_schema(core_schema.bool_schema()), [True], ('b', True, 0, [True])),
+    ],
+)
+def test_validate_python_arrow(items_schema, input_value, expected):
+    v = SchemaValidator(core_schema.list_schema(items_schema))
+    arrow_array = v.validate_python_arrow(input_value)
+    assert isinstance(arrow_array, ArrowArray)
+    assert len(arrow_array) == len(input_value)
+    assert arrow_array.null_count == expected[2]
+    assert decode(arrow_array) == expected
+
+
+def test_validate_json_arrow():
+    v = SchemaValidator(core_schema.list_schema(core_schema.nullable_schema(core_schema.float_schema())))
+    arrow_array = v.validate_json_arrow('[1, null, 2.5]')
+    assert repr(arrow_array) == 'ArrowArray(type=float64, length=3, null_count=1)'
+    assert arrow_array.type == 'float64'
+    assert decode(arrow_array) == ('g', True, 1, [1.0, None, 2.5])
+
+
+def test_schema_capsule():
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema()))
+    capsule = v.validate_python_arrow([]).__arrow_c_schema__()
+    schema = capsule_struct(capsule, b'arrow_schema', ArrowSchemaStruct)
+    assert schema.format == b'u'
+    assert schema.flags == 0
+
+
+def test_export_multiple_times():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    arrow_array = v.validate_python_arrow([1, 2])
+    assert decode(arrow_array) == decode(arrow_array)
+
+
+def test_validation_error():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python_arrow([1, 'x'])
+
+
+def test_int_out_of_range():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(OverflowError):
+        v.validate_python_arrow([2**64])
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        core_schema.int_schema(),
+        core_schema.list_schema(),
+        core_schema.list_schema(core_schema.bytes_schema()),
+        core_schema.set_schema(core_schema.int_schema()),
+    ],
+)
+def test_unsupported_schema(schema):
+    v = SchemaValidator(schema)
+    with pytest.raises(TypeError, match='Arrow output is only supported for list schemas of int, float, bool or str'):
+        v.validate_python_arrow([1])
+
+
+def test_pyarrow():
+    pa = pytest.importorskip('pyarrow')
+    v = SchemaValidator(core_schema.list_schema(core_schema.nullable_schema(core_schema.int_schema())))
+    assert pa.array(v.validate_python_arrow([1, None, '3'])).to_pylist() == [1, None, 3]
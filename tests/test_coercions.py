@@ -0,0 +1,97 @@
+from datetime import datetime
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def test_coercions_off():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_python('1') == 1
+    value, report = v.validate_python(1, coercions=True)
+    assert value == 1
+    assert report.coercions == []
+    assert report.trace is None
+
+
+def test_coercion_scalar():
+    v = SchemaValidator(core_schema.int_schema())
+    value, report = v.validate_python('1', coercions=True)
+    assert value == 1
+    assert report.coercions == [{'loc': (), 'input': '1', 'input_type': 'str', 'target': 'int'}]
+
+
+def test_coercion_datetime():
+    v = SchemaValidator(core_schema.datetime_schema())
+    value, report = v.validate_python('2032-01-02T03:04:05', coercions=True)
+    assert value == datetime(2032, 1, 2, 3, 4, 5)
+    assert report.coercions == [
+        {'loc': (), 'input': '2032-01-02T03:04:05', 'input_type': 'str', 'target': 'datetime'},
+    ]
+
+
+def test_coercion_locations():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.float_schema())),
+                'c': core_schema.typed_dict_field(
+                    core_schema.dict_schema(core_schema.int_schema(), core_schema.str_schema())
+                ),
+            }
+        )
+    )
+    value, report = v.validate_python({'a': 1, 'b': (1.5, '2'), 'c': {'3': 'x'}}, coercions=True)
+    assert value == {'a': 1, 'b': [1.5, 2.0], 'c': {3: 'x'}}
+    assert report.coercions == [
+        {'loc': ('b',), 'input': (1.5, '2'), 'input_type': 'tuple', 'target': 'list'},
+        {'loc': ('b', 1), 'input': '2', 'input_type': 'str', 'target': 'float'},
+        {'loc': ('c', '3', '[key]'), 'input': '3', 'input_type': 'str', 'target': 'int'},
+    ]
+
+
+def test_coercion_union_selected_choice():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    value, report = v.validate_python('1', coercions=True)
+    assert value == '1'
+    assert report.coercions == []
+    value, report = v.validate_python(1.0, coercions=True)
+    assert value == 1
+    assert report.coercions == [{'loc': (), 'input': 1.0, 'input_type': 'float', 'target': 'int'}]
+
+
+def test_coercion_on_error_default():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.with_default_schema(
+                core_schema.tuple_schema([core_schema.int_schema(), core_schema.int_schema()]),
+                on_error='default',
+                default=None,
+            )
+        )
+    )
+    value, report = v.validate_python([['1', 'x']], coercions=True)
+    assert value == [None]
+    assert report.coercions == []
+
+
+def test_coercion_json():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    value, report = v.validate_json('[1, "2"]', coercions=True)
+    assert value == [1, 2]
+    assert report.coercions == [{'loc': (1,), 'input': '2', 'input_type': 'str', 'target': 'int'}]
+
+
+def test_coercions_with_trace():
+    v = SchemaValidator(core_schema.int_schema())
+    value, report = v.validate_python('1', trace=True, coercions=True)
+    assert value == 1
+    assert report.trace == [{'event': 'validate', 'validator': 'int'}]
+    assert report.coercions == [{'loc': (), 'input': '1', 'input_type': 'str', 'target': 'int'}]
+
+
+def test_coercions_strict():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError):
+        v.validate_python('1', strict=True, coercions=True)
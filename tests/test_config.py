@@ -1,10 +1,12 @@
+import dataclasses
 import math
 import re
+from typing import Optional
 
 import pytest
 from dirty_equals import FunctionCheck, HasAttributes, IsInstance
 
-from pydantic_core import CoreConfig, SchemaValidator, ValidationError
+from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError
 from pydantic_core import core_schema as cs
 
 from .conftest import Err, plain_repr
@@ -149,3 +151,70 @@ def test_cache_strings():
 
     v = SchemaValidator(cs.str_schema(), config=CoreConfig(cache_strings='keys'))
     assert "cache_strings='keys'" in plain_repr(v)
+
+
+def nested_model_schema(config: Optional[CoreConfig] = None):
+    return cs.model_schema(
+        cls=MyModel,
+        config=config,
+        schema=cs.model_fields_schema(fields={'f': cs.model_field(schema=cs.str_schema())}),
+    )
+
+
+@pytest.mark.parametrize(
+    'config_merge,nested_config,expected',
+    [
+        # nested config replaces the parent config entirely
+        (None, CoreConfig(str_to_upper=True), ' ABC '),
+        ('replace', CoreConfig(str_to_upper=True), ' ABC '),
+        ('replace', None, ' abc '),
+        # nested keys win, others inherited from the parent
+        ('inherit', CoreConfig(str_to_upper=True), 'ABC'),
+        ('inherit', CoreConfig(str_to_upper=True, str_strip_whitespace=False), ' ABC '),
+        ('inherit', None, 'abc'),
+        # parent keys win, others taken from the nested config
+        ('override', CoreConfig(str_to_upper=True, str_strip_whitespace=False), 'ABC'),
+        ('override', CoreConfig(str_to_upper=True, str_to_lower=False), 'ABC'),
+    ],
+)
+def test_config_merge(config_merge, nested_config, expected):
+    config = CoreConfig(str_strip_whitespace=True)
+    if config_merge is not None:
+        config['config_merge'] = config_merge
+    v = SchemaValidator(cs.list_schema(nested_model_schema(nested_config)), config=config)
+    assert v.validate_python([{'f': ' abc '}])[0].f == expected
+
+
+def test_config_merge_deeply_nested():
+    # the merged config is the parent config of further nested schemas
+    inner = cs.typed_dict_schema({'g': cs.typed_dict_field(cs.str_schema())}, config=CoreConfig(str_to_lower=True))
+    outer = cs.model_schema(
+        cls=MyModel,
+        config=CoreConfig(str_max_length=5),
+        schema=cs.model_fields_schema(fields={'f': cs.model_field(schema=inner)}),
+    )
+    v = SchemaValidator(outer, config=CoreConfig(str_strip_whitespace=True, config_merge='inherit'))
+    assert v.validate_python({'f': {'g': ' ABC '}}).f == {'g': 'abc'}
+    with pytest.raises(ValidationError, match='String should have at most 5 characters'):
+        v.validate_python({'f': {'g': 'ABCDEF'}})
+
+
+def test_config_merge_dataclass():
+    @dataclasses.dataclass
+    class Foo:
+        a: str
+
+    schema = cs.dataclass_schema(
+        Foo,
+        cs.dataclass_args_schema('Foo', [cs.dataclass_field('a', cs.str_schema())]),
+        ['a'],
+        config=CoreConfig(str_to_upper=True),
+    )
+    v = SchemaValidator(schema, config=CoreConfig(str_max_length=3, config_merge='inherit'))
+    assert v.validate_python({'a': 'abc'}).a == 'ABC'
+    assert not v.isinstance_python({'a': 'abcd'})
+
+
+def test_config_merge_invalid():
+    with pytest.raises(SchemaError, match='Invalid config_merge value: wrong'):
+        SchemaValidator(nested_model_schema(), config=CoreConfig(config_merge='wrong'))
@@ -0,0 +1,181 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaValidator, core_schema
+
+
+class MyModel:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+def model_validator(extra_behavior=None, **fields):
+    return SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(fields, extra_behavior=extra_behavior),
+            extra_behavior=extra_behavior,
+        )
+    )
+
+
+def test_construct():
+    v = model_validator(
+        a=core_schema.model_field(core_schema.int_schema()),
+        b=core_schema.model_field(core_schema.with_default_schema(core_schema.str_schema(), default='b')),
+    )
+    m = v.construct_python({'a': 'not an int', 'c': 'ignored'})
+    assert isinstance(m, MyModel)
+    assert m.__dict__ == {'a': 'not an int', 'b': 'b'}
+    assert m.__pydantic_fields_set__ == {'a'}
+    assert m.__pydantic_extra__ is None
+    assert m.__pydantic_private__ is None
+
+    # required fields which are missing are left unset
+    m = v.construct_python({})
+    assert m.__dict__ == {'b': 'b'}
+    assert m.__pydantic_fields_set__ == set()
+
+
+def test_construct_matches_validation():
+    v = model_validator(
+        a=core_schema.model_field(core_schema.int_schema()),
+        b=core_schema.model_field(core_schema.with_default_schema(core_schema.str_schema(), default='b')),
+    )
+    validated = v.validate_python({'a': 1})
+    constructed = v.construct_python({'a': 1})
+    assert constructed.__dict__ == validated.__dict__
+    assert constructed.__pydantic_fields_set__ == validated.__pydantic_fields_set__
+
+
+def test_construct_fields_set():
+    v = model_validator(a=core_schema.model_field(core_schema.int_schema()))
+    m = v.construct_python({'a': 1}, fields_set={'x'})
+    assert m.__pydantic_fields_set__ == {'x'}
+
+
+def test_construct_defaults():
+    default = [1]
+    v = model_validator(
+        a=core_schema.model_field(core_schema.int_schema()),
+        b=core_schema.model_field(
+            core_schema.with_default_schema(core_schema.list_schema(), default=default, validate_default=True)
+        ),
+        c=core_schema.model_field(
+            core_schema.with_default_schema(
+                core_schema.int_schema(), default_factory=lambda data: data['a'] * 2, default_factory_takes_data=True
+            )
+        ),
+        d=core_schema.model_field(
+            core_schema.with_default_schema(core_schema.int_schema(), default='not validated', validate_default=True)
+        ),
+    )
+    m = v.construct_python({'a': 2})
+    assert m.__dict__ == {'a': 2, 'b': [1], 'c': 4, 'd': 'not validated'}
+    assert m.b is not default
+
+
+def test_construct_alias():
+    v = model_validator(
+        a=core_schema.model_field(core_schema.int_schema(), validation_alias='A'),
+        b=core_schema.model_field(core_schema.int_schema(), validation_alias=[['x', 'y']]),
+    )
+    m = v.construct_python({'A': 1, 'x': {'y': 2}, 'a': 3})
+    assert m.__dict__ == {'a': 1, 'b': 2}
+
+    m = v.construct_python({'a': 3}, by_name=True, by_alias=False)
+    assert m.__dict__ == {'a': 3}
+
+
+def test_construct_extra_allow():
+    v = model_validator('allow', a=core_schema.model_field(core_schema.int_schema(), validation_alias='A'))
+    m = v.construct_python({'A': 1, 'b': 2})
+    assert m.__dict__ == {'a': 1}
+    assert m.__pydantic_extra__ == {'b': 2}
+    assert m.__pydantic_fields_set__ == {'a', 'b'}
+
+
+def test_construct_skips_validators():
+    calls = []
+
+    def f(input_value, info):
+        calls.append(input_value)
+        return input_value
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.with_info_before_validator_function(
+                f, core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())})
+            ),
+        )
+    )
+    m = v.construct_python({'a': 'x'})
+    assert m.__dict__ == {'a': 'x'}
+    assert calls == []
+
+
+def test_construct_post_init():
+    class PostInitModel(MyModel):
+        def call_me_maybe(self, context):
+            self.__pydantic_private__ = {'context': context}
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            PostInitModel,
+            core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}),
+            post_init='call_me_maybe',
+        )
+    )
+    m = v.construct_python({'a': 1}, context={'x': 1})
+    assert m.__pydantic_private__ == {'context': {'x': 1}}
+
+
+def test_construct_root_model():
+    class RootModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+        root: list[int]
+
+    v = SchemaValidator(core_schema.model_schema(RootModel, core_schema.list_schema(), root_model=True))
+    m = v.construct_python(['a'])
+    assert m.root == ['a']
+    assert m.__pydantic_fields_set__ == {'root'}
+
+
+def test_construct_recursive():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('model'),
+            [
+                core_schema.model_schema(
+                    MyModel,
+                    core_schema.model_fields_schema(
+                        {
+                            'child': core_schema.model_field(
+                                core_schema.with_default_schema(
+                                    core_schema.nullable_schema(core_schema.definition_reference_schema('model')),
+                                    default=None,
+                                )
+                            )
+                        }
+                    ),
+                    ref='model',
+                )
+            ],
+        )
+    )
+    # nested values aren't converted, as with `model_construct()`
+    m = v.construct_python({'child': {'child': None}})
+    assert m.child == {'child': None}
+
+
+def test_construct_not_supported():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(TypeError, match='^construct_python is not supported for int$'):
+        v.construct_python(1)
+
+
+def test_construct_not_mapping():
+    v = model_validator(a=core_schema.model_field(core_schema.int_schema()))
+    with pytest.raises(TypeError, match=re.escape('Expected a mapping to construct from, got list')):
+        v.construct_python([1])
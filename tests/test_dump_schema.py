@@ -0,0 +1,124 @@
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+
+def test_simple():
+    schema = core_schema.list_schema(core_schema.int_schema())
+    v = SchemaValidator(schema)
+    dumped = v.dump_schema()
+    assert dumped == schema
+    assert dumped is not schema
+
+
+def test_definitions_unchanged():
+    schema = core_schema.definitions_schema(
+        core_schema.definition_reference_schema('int'), [core_schema.int_schema(ref='int')]
+    )
+    assert SchemaValidator(schema).dump_schema() == schema
+
+
+def test_nested_definitions_hoisted():
+    schema = core_schema.definitions_schema(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.definition_reference_schema('a')),
+                'b': core_schema.typed_dict_field(
+                    core_schema.definitions_schema(
+                        core_schema.list_schema(core_schema.definition_reference_schema('b')),
+                        [core_schema.str_schema(ref='b')],
+                    )
+                ),
+            }
+        ),
+        [core_schema.int_schema(ref='a')],
+    )
+    assert SchemaValidator(schema).dump_schema() == {
+        'type': 'definitions',
+        'schema': core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.definition_reference_schema('a')),
+                'b': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.definition_reference_schema('b'))
+                ),
+            }
+        ),
+        'definitions': [core_schema.int_schema(ref='a'), core_schema.str_schema(ref='b')],
+    }
+    # the original schema is not modified
+    assert schema['schema']['fields']['b']['schema']['type'] == 'definitions'
+
+
+def test_definitions_in_union_choice_tuple():
+    schema = core_schema.union_schema(
+        [
+            (
+                core_schema.definitions_schema(
+                    core_schema.definition_reference_schema('x'), [core_schema.int_schema(ref='x')]
+                ),
+                'label',
+            ),
+            core_schema.str_schema(),
+        ]
+    )
+    assert SchemaValidator(schema).dump_schema() == {
+        'type': 'definitions',
+        'schema': core_schema.union_schema(
+            [(core_schema.definition_reference_schema('x'), 'label'), core_schema.str_schema()]
+        ),
+        'definitions': [core_schema.int_schema(ref='x')],
+    }
+
+
+def test_dumped_schema_rebuilds():
+    schema = core_schema.list_schema(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('x'), [core_schema.int_schema(ref='x')]
+        )
+    )
+    v = SchemaValidator(SchemaValidator(schema).dump_schema())
+    assert v.validate_python(['1', 2]) == [1, 2]
+
+
+def test_metadata_and_default_untouched():
+    default = {'type': 'definitions'}
+    metadata = {'type': 'definitions'}
+    schema = core_schema.with_default_schema(core_schema.any_schema(), default=default, metadata=metadata)
+    dumped = SchemaValidator(schema).dump_schema()
+    assert dumped['default'] is default
+    assert dumped['metadata'] is metadata
+
+
+def test_serializer():
+    schema = core_schema.definitions_schema(
+        core_schema.list_schema(
+            core_schema.definitions_schema(
+                core_schema.definition_reference_schema('x'), [core_schema.int_schema(ref='x')]
+            )
+        ),
+        [],
+    )
+    assert SchemaSerializer(schema).dump_schema() == {
+        'type': 'definitions',
+        'schema': core_schema.list_schema(core_schema.definition_reference_schema('x')),
+        'definitions': [core_schema.int_schema(ref='x')],
+    }
+
+
+def test_fields_named_metadata_and_default():
+    def field(ref):
+        return core_schema.typed_dict_field(
+            core_schema.definitions_schema(
+                core_schema.definition_reference_schema(ref), [core_schema.int_schema(ref=ref)]
+            )
+        )
+
+    schema = core_schema.typed_dict_schema({'metadata': field('x'), 'default': field('y')})
+    assert SchemaValidator(schema).dump_schema() == {
+        'type': 'definitions',
+        'schema': core_schema.typed_dict_schema(
+            {
+                'metadata': core_schema.typed_dict_field(core_schema.definition_reference_schema('x')),
+                'default': core_schema.typed_dict_field(core_schema.definition_reference_schema('y')),
+            }
+        ),
+        'definitions': [core_schema.int_schema(ref='x'), core_schema.int_schema(ref='y')],
+    }
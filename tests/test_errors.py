@@ -1,4 +1,5 @@
 import enum
+import json
 import os
 import pickle
 import re
@@ -83,6 +84,61 @@ def test_pydantic_value_error_usage():
     ]
 
 
+def test_pydantic_value_error_severity():
+    def soft(input_value, info):
+        if input_value < 0:
+            raise PydanticCustomError('suspicious', 'value is suspicious', None, 'warning')
+        return input_value
+
+    def hint(input_value, info):
+        raise PydanticCustomError('hint', 'value could be better', {'better': 1}, 'info')
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.with_info_plain_validator_function(soft)),
+                'b': core_schema.typed_dict_field(core_schema.with_info_plain_validator_function(hint)),
+                'c': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': -1, 'b': 2, 'c': 'x'})
+
+    errors = exc_info.value.errors(include_url=False)
+    assert errors == [
+        {'type': 'suspicious', 'loc': ('a',), 'msg': 'value is suspicious', 'input': -1, 'severity': 'warning'},
+        {
+            'type': 'hint',
+            'loc': ('b',),
+            'msg': 'value could be better',
+            'input': 2,
+            'ctx': {'better': 1},
+            'severity': 'info',
+        },
+        {
+            'type': 'int_parsing',
+            'loc': ('c',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+    ]
+    assert exc_info.value.errors(include_url=False, min_severity='info') == errors
+    assert [e['type'] for e in exc_info.value.errors(min_severity='warning')] == ['suspicious', 'int_parsing']
+    assert [e['type'] for e in exc_info.value.errors(min_severity='error')] == ['int_parsing']
+    assert exc_info.value.error_count() == 3
+    assert [e.get('severity') for e in json.loads(exc_info.value.json())] == ['warning', 'info', None]
+
+    with pytest.raises(ValueError, match='Invalid severity: `fatal`, expected `info`, `warning` or `error`'):
+        exc_info.value.errors(min_severity='fatal')
+
+    e = PydanticCustomError('my_error', 'msg')
+    assert e.severity == 'error'
+    with pytest.raises(ValueError, match='Invalid severity: `high`'):
+        PydanticCustomError('my_error', 'msg', None, 'high')
+
+
 def test_pydantic_value_error_invalid_dict():
     def my_function(input_value, info):
         raise PydanticCustomError('my_error', 'this is a custom error {foo}', {(): 'foobar'})
@@ -263,6 +319,11 @@ all_errors = [
         {'method_name': 'isinstance'},
     ),
     ('recursion_loop', 'Recursion error - cyclic reference detected', None),
+    (
+        'resource_limit_exceeded',
+        'Input exceeds the max_total_items limit of 10',
+        {'limit': 'max_total_items', 'max_value': 10},
+    ),
     ('model_type', 'Input should be a valid dictionary or instance of Foobar', {'class_name': 'Foobar'}),
     ('model_attributes_type', 'Input should be a valid dictionary or object to extract fields from', None),
     ('dataclass_exact_type', 'Input should be an instance of Foobar', {'class_name': 'Foobar'}),
@@ -271,6 +332,7 @@ all_errors = [
     ('frozen_field', 'Field is frozen', None),
     ('frozen_instance', 'Instance is frozen', None),
     ('extra_forbidden', 'Extra inputs are not permitted', None),
+    ('extra_keys_forbidden', "Extra inputs are not permitted: 'a', 'b'", {'extra_keys': "'a', 'b'"}),
     ('invalid_key', 'Keys should be strings', None),
     ('get_attribute_error', 'Error extracting attribute: foo', {'error': 'foo'}),
     ('none_required', 'Input should be None', None),
@@ -292,6 +354,11 @@ all_errors = [
         'Foobar should have at most 42 items after validation, not 50',
         {'field_type': 'Foobar', 'max_length': 42, 'actual_length': 50},
     ),
+    (
+        'duplicate_item',
+        'Items should be unique, this is a duplicate of the item at index 2',
+        {'first_index': 2},
+    ),
     ('string_type', 'Input should be a valid string', None),
     ('string_sub_type', 'Input should be a string, not an instance of a subclass of str', None),
     ('string_unicode', 'Input should be a valid string, unable to parse raw data as a unicode string', None),
@@ -300,8 +367,19 @@ all_errors = [
     ('string_too_short', 'String should have at least 1 character', {'min_length': 1}),
     ('string_too_long', 'String should have at most 42 characters', {'max_length': 42}),
     ('string_too_long', 'String should have at most 1 character', {'max_length': 1}),
+    (
+        'string_too_short_bytes',
+        'String should have at least 4 bytes when UTF-8 encoded, not 3',
+        {'min_length_bytes': 4, 'actual_length_bytes': 3},
+    ),
+    (
+        'string_too_long_bytes',
+        'String should have at most 1 byte when UTF-8 encoded, not 2',
+        {'max_length_bytes': 1, 'actual_length_bytes': 2},
+    ),
     ('dict_type', 'Input should be a valid dictionary', None),
     ('mapping_type', 'Input should be a valid mapping, error: foobar', {'error': 'foobar'}),
+    ('dict_key_forbidden', 'Key is not permitted', None),
     ('iterable_type', 'Input should be iterable', None),
     ('iteration_error', 'Error iterating over object, error: foobar', {'error': 'foobar'}),
     ('list_type', 'Input should be a valid list', None),
@@ -357,18 +435,47 @@ all_errors = [
     ('timezone_naive', 'Input should not have timezone info', None),
     ('timezone_aware', 'Input should have timezone info', None),
     ('timezone_offset', 'Timezone offset of 0 required, got 60', {'tz_expected': 0, 'tz_actual': 60}),
+    (
+        'timezone_not_allowed',
+        "Timezone should be 'UTC' or 'Europe/London', got 'Europe/Paris'",
+        {'tz_allowed': "'UTC' or 'Europe/London'", 'tz_actual': "'Europe/Paris'"},
+    ),
     ('time_delta_type', 'Input should be a valid timedelta', None),
     ('time_delta_parsing', 'Input should be a valid timedelta, foobar', {'error': 'foobar'}),
     ('frozen_set_type', 'Input should be a valid frozenset', None),
     ('is_instance_of', 'Input should be an instance of Foo', {'class': 'Foo'}),
     ('is_subclass_of', 'Input should be a subclass of Foo', {'class': 'Foo'}),
     ('callable_type', 'Input should be callable', None),
+    ('callable_arity', 'Input should be callable with 2 positional arguments', {'arity': 2}),
+    ('callable_arity', 'Input should be callable with 1 positional argument', {'arity': 1}),
+    ('callable_keywords', "Input should be callable with keyword arguments 'a', 'b'", {'keywords': "'a', 'b'"}),
+    ('callable_coroutine', 'Input should be a coroutine function', None),
     (
         'union_tag_invalid',
         "Input tag 'foo' found using bar does not match any of the expected tags: baz",
         {'discriminator': 'bar', 'tag': 'foo', 'expected_tags': 'baz'},
     ),
     ('union_tag_not_found', 'Unable to extract tag using discriminator foo', {'discriminator': 'foo'}),
+    (
+        'union_member_failed',
+        'Input failed validation against this union member with 2 errors',
+        {'error_count': 2},
+    ),
+    (
+        'union_member_failed',
+        'Input failed validation against this union member with 1 error',
+        {'error_count': 1},
+    ),
+    (
+        'context_switch_invalid',
+        "Context value 'v3' for 'api_version' does not match any of the expected values: 'v1', 'v2'",
+        {'context_key': 'api_version', 'value': "'v3'", 'expected_values': "'v1', 'v2'"},
+    ),
+    (
+        'context_switch_not_found',
+        "Validation context should include 'api_version'",
+        {'context_key': 'api_version'},
+    ),
     ('arguments_type', 'Arguments must be a tuple, list or a dictionary', None),
     ('missing_argument', 'Missing required argument', None),
     ('unexpected_keyword_argument', 'Unexpected keyword argument', None),
@@ -382,9 +489,25 @@ all_errors = [
     ('url_too_long', 'URL should have at most 42 characters', {'max_length': 42}),
     ('url_too_long', 'URL should have at most 1 character', {'max_length': 1}),
     ('url_scheme', 'URL scheme should be "foo", "bar" or "spam"', {'expected_schemes': '"foo", "bar" or "spam"'}),
+    ('url_too_few_hosts', 'URL should have at least 2 hosts', {'min_hosts': 2}),
+    ('url_too_few_hosts', 'URL should have at least 1 host', {'min_hosts': 1}),
+    ('url_too_many_hosts', 'URL should have at most 3 hosts', {'max_hosts': 3}),
+    ('url_host_port_required', 'URL host 1 should have a port', {'host_index': 1}),
+    ('url_host_userinfo_required', 'URL host 0 should have a username', {'host_index': 0}),
+    ('email_type', 'Email address input should be a string or EmailAddress', None),
+    ('email_parsing', 'Input should be a valid email address, Foobar', {'error': 'Foobar'}),
     ('uuid_type', 'UUID input should be a string, bytes or UUID object', None),
     ('uuid_parsing', 'Input should be a valid UUID, Foobar', {'error': 'Foobar'}),
     ('uuid_version', 'UUID version 42 expected', {'expected_version': 42}),
+    ('path_type', 'Path input should be a string, bytes or os.PathLike', None),
+    ('path_not_absolute', 'Path should be absolute', None),
+    ('path_suffix', "Path suffix should be '.txt'", {'expected_suffixes': "'.txt'"}),
+    ('ip_type', 'IPv4 address input should be a string, bytes or integer', {'kind': 'IPv4 address'}),
+    (
+        'ip_parsing',
+        'Input should be a valid IPv6 network, host bits set',
+        {'kind': 'IPv6 network', 'error': 'host bits set'},
+    ),
     ('decimal_type', 'Decimal input should be an integer, float, string or Decimal object', None),
     ('decimal_parsing', 'Input should be a valid decimal', None),
     ('decimal_max_digits', 'Decimal input should have no more than 42 digits in total', {'max_digits': 42}),
@@ -401,6 +524,8 @@ all_errors = [
         'Decimal input should have no more than 1 digit before the decimal point',
         {'whole_digits': 1},
     ),
+    ('fraction_type', 'Fraction input should be an integer, float, string or Fraction object', None),
+    ('fraction_parsing', 'Input should be a valid fraction', None),
     (
         'complex_type',
         'Input should be a valid python complex object, a number, or a valid complex string following the rules at https://docs.python.org/3/library/functions.html#complex',
@@ -1166,3 +1291,79 @@ def test_errors_include_url_envvar(env_var, env_var_value, expected_to_have_url)
     if 'PYDANTIC_ERRORS_OMIT_URL' in env:
         assert 'PYDANTIC_ERRORS_OMIT_URL is deprecated' in result.stdout
     assert ('https://errors.pydantic.dev' in result.stdout) == expected_to_have_url
+
+
+def test_schema_origin():
+    v = SchemaValidator(core_schema.int_schema(), config=CoreConfig(title='MyInt'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert exc_info.value.schema_title == 'MyInt'
+    assert exc_info.value.schema_hash == v.schema_hash
+    assert isinstance(v.schema_hash, int)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"x"')
+    assert exc_info.value.schema_hash == v.schema_hash
+
+    result = v.try_validate_python('x')
+    assert result.error.schema_title == 'MyInt'
+    assert result.error.schema_hash == v.schema_hash
+
+    e = ValidationError.from_exception_data('Foobar', [{'type': 'missing', 'loc': ('a',), 'input': None}])
+    assert e.schema_title is None
+    assert e.schema_hash is None
+
+
+def test_schema_hash():
+    def schema(**kwargs):
+        return core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema(**kwargs)),
+                'b': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.str_schema(), default='x')
+                ),
+                'c': core_schema.typed_dict_field(core_schema.no_info_plain_validator_function(test_schema_hash)),
+            }
+        )
+
+    h = SchemaValidator(schema()).schema_hash
+    assert SchemaValidator(schema()).schema_hash == h
+    assert SchemaValidator(schema(metadata={'anything': object()})).schema_hash == h
+    assert SchemaValidator(schema(gt=1)).schema_hash != h
+    assert SchemaValidator(schema(), config=CoreConfig(strict=True)).schema_hash != h
+
+
+def test_schema_hash_field_named_metadata():
+    def schema(field_name, literal):
+        return core_schema.typed_dict_schema(
+            {field_name: core_schema.typed_dict_field(core_schema.literal_schema([literal]))}
+        )
+
+    h = SchemaValidator(schema('metadata', 'metadata')).schema_hash
+    assert SchemaValidator(schema('metadata', 'metadata')).schema_hash == h
+    assert SchemaValidator(schema('other', 'metadata')).schema_hash != h
+    assert SchemaValidator(schema('metadata', 'other')).schema_hash != h
+
+
+@pytest.mark.skipif(sys.platform == 'emscripten', reason='no subprocesses on emscripten')
+def test_schema_hash_stable_across_processes():
+    code = (
+        'from pydantic_core import SchemaValidator, core_schema;'
+        "print(SchemaValidator(core_schema.literal_schema(['a', 'b']), {'title': 'x'}).schema_hash)"
+    )
+    output = subprocess.check_output([sys.executable, '-c', code], env={**os.environ, 'PYTHONHASHSEED': 'random'})
+    v = SchemaValidator(core_schema.literal_schema(['a', 'b']), {'title': 'x'})
+    assert int(output) == v.schema_hash
+
+
+def test_json_input_rendered_on_access():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[{"a": [1, 2]}, "x"]')
+    errors = exc_info.value.errors(include_url=False)
+    assert [e['input'] for e in errors] == [{'a': [1, 2]}, 'x']
+    # each call builds new objects from the JSON input
+    assert exc_info.value.errors(include_url=False) == errors
+    assert errors[0]['input'] is not exc_info.value.errors()[0]['input']
+    assert json.loads(exc_info.value.json())[0]['input'] == {'a': [1, 2]}
+    assert "input_value={'a': [1, 2]}, input_type=dict" in str(exc_info.value)
@@ -0,0 +1,244 @@
+import pytest
+
+from pydantic_core import CoreConfig, SchemaValidator, ValidationError, core_schema
+
+from .conftest import Err
+
+
+def any_validator(**config) -> SchemaValidator:
+    return SchemaValidator(core_schema.any_schema(), config=CoreConfig(**config))
+
+
+def nested_validator(**config) -> SchemaValidator:
+    value = core_schema.definition_reference_schema('value')
+    return SchemaValidator(
+        core_schema.definitions_schema(
+            value,
+            [
+                core_schema.union_schema(
+                    [
+                        core_schema.int_schema(strict=True),
+                        core_schema.str_schema(strict=True),
+                        core_schema.bytes_schema(strict=True),
+                        core_schema.list_schema(value, strict=True),
+                        core_schema.dict_schema(core_schema.str_schema(), value, strict=True),
+                        core_schema.tuple_variable_schema(value, strict=True),
+                        core_schema.set_schema(core_schema.int_schema(), strict=True),
+                    ],
+                    ref='value',
+                )
+            ],
+        ),
+        config=CoreConfig(**config),
+    )
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ([1, 2, 3], [1, 2, 3]),
+        ({'a': [1], 'b': 2}, {'a': [1], 'b': 2}),
+        ([1, 2, 3, 4], Err('Input exceeds the max_total_items limit of 3')),
+        ([[1, 2], [3]], Err('Input exceeds the max_total_items limit of 3')),
+        ({'a': (1, 2), 'b': {3}}, Err('Input exceeds the max_total_items limit of 3')),
+        ('a long string is not an item', 'a long string is not an item'),
+    ],
+)
+def test_max_total_items(input_value, expected):
+    v = nested_validator(max_total_items=3)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=expected.message):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_max_total_items_error_details():
+    v = nested_validator(max_total_items=3)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': [1, 2, 3]})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'resource_limit_exceeded',
+            'loc': ('a',),
+            'msg': 'Input exceeds the max_total_items limit of 3',
+            'input': [1, 2, 3],
+            'ctx': {'limit': 'max_total_items', 'max_value': 3},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,loc',
+    [
+        ('x' * 6, ()),
+        ('é' * 3, ()),
+        (b'x' * 6, ()),
+        (bytearray(6), ()),
+        (memoryview(b'x' * 6), ()),
+        ((1, 'x' * 6), (1,)),
+        (['ok', 'x' * 6], (1,)),
+        ({'x' * 6: 1}, ('xxxxxx',)),
+        ({'a': {'b': 'x' * 6}}, ('a', 'b')),
+    ],
+)
+def test_max_string_length_bytes(input_value, loc):
+    v = nested_validator(max_string_length_bytes=5)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('resource_limit_exceeded', loc)]
+
+
+def test_max_string_length_bytes_ok():
+    v = nested_validator(max_string_length_bytes=5)
+    assert v.validate_python(['xxxxx', b'xxxxx', 'éé']) == ['xxxxx', b'xxxxx', 'éé']
+
+
+@pytest.mark.parametrize(
+    'input_value,valid',
+    [
+        (1, True),
+        ([1], True),
+        ([[1]], True),
+        ({'a': [1]}, True),
+        ([[[1]]], False),
+        ({'a': {'b': {}}}, False),
+        ([({1},)], False),
+    ],
+)
+def test_max_nesting_depth(input_value, valid):
+    v = nested_validator(max_nesting_depth=2)
+    if valid:
+        assert v.validate_python(input_value) == input_value
+    else:
+        with pytest.raises(ValidationError, match='Input exceeds the max_nesting_depth limit of 2'):
+            v.validate_python(input_value)
+
+
+def test_limits_json():
+    v = any_validator(max_total_items=3, max_string_length_bytes=5, max_nesting_depth=2)
+    assert v.validate_json('{"a": [1, 2]}') == {'a': [1, 2]}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, 2, 3, 4]')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'resource_limit_exceeded',
+            'loc': (),
+            'msg': 'Input exceeds the max_total_items limit of 3',
+            'input': '[1, 2, 3, 4]',
+            'ctx': {'limit': 'max_total_items', 'max_value': 3},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": "xxxxxx"}')
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('resource_limit_exceeded', ('a',))]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"xxxxxx": 1}')
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('resource_limit_exceeded', ('xxxxxx',))]
+
+    with pytest.raises(ValidationError, match='Input exceeds the max_nesting_depth limit of 2'):
+        v.validate_json('[[[]]]')
+
+
+def test_limits_json_deep_nesting():
+    deep = '[' * 100_000 + ']' * 100_000
+    with pytest.raises(ValidationError, match='Input exceeds the max_nesting_depth limit of 10'):
+        any_validator(max_nesting_depth=10).validate_json(deep)
+    # depth is always capped once limits are set, like parsing JSON without limits
+    with pytest.raises(ValidationError, match='recursion limit exceeded'):
+        any_validator(max_total_items=1_000_000).validate_json(deep)
+
+
+def test_limits_json_partial():
+    v = any_validator(max_total_items=4)
+    assert v.validate_json('[1, 2, [3', allow_partial=True) == [1, 2, [3]]
+    with pytest.raises(ValidationError, match='Input exceeds the max_total_items limit of 4'):
+        v.validate_json('[1, 2, [3, 4', allow_partial=True)
+
+
+def test_limits_json_chunks():
+    v = any_validator(max_total_items=3, max_nesting_depth=2)
+    assert v.validate_json(iter([b'[1, ', b'[2]]'])) == [1, [2]]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(iter([b'[1, ', b'[2, 3]]']))
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('resource_limit_exceeded', (1,))]
+    with pytest.raises(ValidationError, match='Input exceeds the max_nesting_depth limit of 2'):
+        v.validate_json(iter([b'{"a": [', b'[]]}']))
+
+
+def test_limits_strings():
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()),
+        config=CoreConfig(max_total_items=1),
+    )
+    assert v.validate_strings({'a': '1'}) == {'a': 1}
+    with pytest.raises(ValidationError, match='Input exceeds the max_total_items limit of 1'):
+        v.validate_strings({'a': '1', 'b': '2'})
+
+
+def test_limits_checked_before_validation():
+    calls = []
+
+    def f(input_value):
+        calls.append(input_value)
+        return input_value
+
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.no_info_plain_validator_function(f)),
+        config=CoreConfig(max_total_items=2),
+    )
+    with pytest.raises(ValidationError, match='Input exceeds the max_total_items limit of 2'):
+        v.validate_python([1, 2, 3])
+    assert calls == []
+
+
+def test_limits_only_checked_when_validated():
+    v = any_validator(max_total_items=3, max_string_length_bytes=5)
+    assert v.validate_python([1, 2]) == [1, 2]
+    # the contents of values which aren't validated aren't checked
+    assert v.validate_python([['x' * 6]]) == [['x' * 6]]
+    with pytest.raises(ValidationError, match='Input exceeds the max_total_items limit of 3'):
+        v.validate_python([1, 2, 3, 4])
+
+
+def test_self_referencing_input():
+    a = []
+    a.append(a)
+    assert any_validator(max_string_length_bytes=5).validate_python(a) is a
+
+    with pytest.raises(ValidationError) as exc_info:
+        nested_validator(max_string_length_bytes=5, max_nesting_depth=10).validate_python(a)
+    assert 'recursion_loop' in {e['type'] for e in exc_info.value.errors()}
+
+
+def test_deep_nesting():
+    deep = []
+    for _ in range(100_000):
+        deep = [deep]
+    with pytest.raises(ValidationError, match='Input exceeds the max_nesting_depth limit of 10'):
+        nested_validator(max_nesting_depth=10).validate_python(deep)
+
+
+def test_limits_not_suppressed():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.with_default_schema(
+                core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]),
+                default=None,
+                on_error='default',
+            )
+        ),
+        config=CoreConfig(max_string_length_bytes=5),
+    )
+    assert v.validate_python([1, 'a', 1.5]) == [1, 'a', None]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'x' * 6])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('resource_limit_exceeded', (1,))]
+
+
+def test_no_limits():
+    v = any_validator()
+    assert v.validate_python([[[['x' * 1000]]]] * 100) == [[[['x' * 1000]]]] * 100
@@ -1,3 +1,4 @@
+import base64
 import json
 import platform
 import re
@@ -30,7 +31,10 @@ def test_bool(input_value, output_value):
     assert v.validate_json(input_value) == output_value
 
 
-@pytest.mark.parametrize('input_value', ['[1, 2, 3]', b'[1, 2, 3]', bytearray(b'[1, 2, 3]')])
+@pytest.mark.parametrize(
+    'input_value',
+    ['[1, 2, 3]', b'[1, 2, 3]', bytearray(b'[1, 2, 3]'), memoryview(b'[1, 2, 3]'), memoryview(b'x[1, 2, 3]x')[1:-1]],
+)
 def test_input_types(input_value):
     v = SchemaValidator(core_schema.list_schema(items_schema=core_schema.int_schema()))
     assert v.validate_json(input_value) == [1, 2, 3]
@@ -39,7 +43,104 @@ def test_input_types(input_value):
 def test_input_type_invalid():
     v = SchemaValidator(core_schema.list_schema(items_schema=core_schema.int_schema()))
     with pytest.raises(ValidationError, match=r'JSON input should be string, bytes or bytearray \[type=json_type,'):
+        v.validate_json(42)
+    with pytest.raises(ValidationError, match=r'JSON input should be string, bytes or bytearray \[type=json_type,'):
+        v.validate_json([1, 2])
+
+
+def test_input_chunks():
+    v = SchemaValidator(core_schema.list_schema(items_schema=core_schema.int_schema()))
+
+    def chunks():
+        yield b'[1, '
+        yield bytearray(b'2, ')
+        yield memoryview(b'3]')
+
+    assert v.validate_json(chunks()) == [1, 2, 3]
+    assert v.validate_json(iter([b'[1', b'0]'])) == [10]
+    assert v.validate_json(iter([b'[', b'"4"', b']'])) == [4]
+    assert v.validate_json(iter([b' ', b'[', b']', b' '])) == []
+    assert v.validate_json([b'[1, ', '2', b']']) == [1, 2]
+    assert v.validate_json((chunk for chunk in (b'[3', b']'))) == [3]
+
+    with pytest.raises(ValidationError, match=r'Invalid JSON: EOF while parsing a value at line 1 column 0'):
+        v.validate_json(iter([]))
+    with pytest.raises(ValidationError, match=r'Invalid JSON: EOF while parsing a value at line 1 column 0'):
         v.validate_json([])
+    with pytest.raises(ValidationError, match=r'JSON input should be string, bytes or bytearray \[type=json_type,'):
+        v.validate_json(iter([b'[1, ', 2, b']']))
+    with pytest.raises(ValidationError, match=r'JSON input should be string, bytes or bytearray \[type=json_type,'):
+        v.validate_json({'a': b'[]'})
+
+
+def test_input_chunks_read_lazily():
+    v = SchemaValidator(core_schema.list_schema(items_schema=core_schema.int_schema()))
+    read = []
+
+    def chunks():
+        for chunk in (b'[1,', b'x', b',', b'2]'):
+            read.append(chunk)
+            yield chunk
+
+    with pytest.raises(ValidationError, match=r'Invalid JSON: expected value at line 1 column 4'):
+        v.validate_json(chunks())
+    # parsing stopped at the error, before the last chunk was read
+    assert read == [b'[1,', b'x', b',']
+
+
+@pytest.mark.parametrize(
+    'json_data',
+    [
+        '{"a": [1, {"b": "c,]}"}], "d": null}',
+        '["\\"]", "\\\\", {"]": "["}]',
+        '  "foobar"  ',
+        '123',
+        '[]',
+        '{}',
+        '[1, 2,]',
+        '[1 2]',
+        '[1, 2',
+        '{"a": 1,}',
+        '{"a" 1}',
+        '{"a": 1 "b": 2}',
+        '[1]\n\n [2]',
+        '[\n1,\n2,\nx]',
+        '{\n"a":\n[1,\n2\n}',
+        '[1, "abc',
+        '["a\\',
+        ']',
+        '',
+        '   ',
+        '[Infinity, -Infinity]',
+    ],
+)
+def test_input_chunks_match_whole(json_data):
+    v = SchemaValidator(core_schema.any_schema())
+    json_data = json_data.encode()
+    try:
+        expected = v.validate_json(json_data)
+    except ValidationError as e:
+        expected = e.errors(include_url=False)
+    for chunk_size in range(1, len(json_data) + 1):
+        chunks = (json_data[i : i + chunk_size] for i in range(0, len(json_data), chunk_size))
+        try:
+            output = v.validate_json(chunks)
+        except ValidationError as e:
+            assert e.errors(include_url=False)[0]['msg'] == expected[0]['msg']
+        else:
+            assert output == expected
+
+
+def test_input_chunks_partial():
+    v = SchemaValidator(core_schema.list_schema(items_schema=core_schema.int_schema()))
+    assert v.validate_json(iter([b'[1, 2', b', 3']), allow_partial=True) == [1, 2, 3]
+    assert v.validate_json(iter([b'[1, 2', b', ']), allow_partial=True) == [1, 2]
+
+    v = SchemaValidator(core_schema.dict_schema(values_schema=core_schema.str_schema()))
+    assert v.validate_json(iter([b'{"a": "b", ', b'"c": "d']), allow_partial='trailing-strings') == {
+        'a': 'b',
+        'c': 'd',
+    }
 
 
 def test_null():
@@ -466,3 +567,47 @@ def test_json_bytes_hex_invalid():
             'input': wrong_input,
         }
     ]
+
+
+@pytest.mark.parametrize('data', [b'', b'f', b'fo', b'foo', b'foob', b'fooba', b'foobar', bytes(range(256))])
+def test_json_bytes_base32_round_trip(data):
+    encoded = base64.b32encode(data).decode()
+    assert to_json(data, bytes_mode='base32') == json.dumps(encoded).encode()
+
+    v = SchemaValidator(core_schema.bytes_schema(), config=CoreConfig(val_json_bytes='base32'))
+    assert v.validate_json(json.dumps(encoded)) == data
+    # lowercase and unpadded input is accepted
+    assert v.validate_json(json.dumps(encoded.lower().rstrip('='))) == data
+
+
+@pytest.mark.parametrize(
+    'wrong_input,error',
+    [('MZ!W6', 'Invalid symbol 33, offset 2.'), ('MZXW6Y', 'Invalid input length or trailing bits.')],
+)
+def test_json_bytes_base32_invalid(wrong_input, error):
+    v = SchemaValidator(core_schema.bytes_schema(), config=CoreConfig(val_json_bytes='base32'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(json.dumps(wrong_input))
+    assert exc_info.value.errors(include_url=False, include_context=False) == [
+        {
+            'type': 'bytes_invalid_encoding',
+            'loc': (),
+            'msg': f'Data should be valid base32: {error}',
+            'input': wrong_input,
+        }
+    ]
+
+
+def test_json_bytes_base64url_round_trip():
+    data = b'\xfb\xff\xbf'
+    assert to_json(data, bytes_mode='base64url') == b'"-_-_"'
+    assert to_json(b'foo bar', bytes_mode='base64url') == b'"Zm9vIGJhcg"'
+
+    v = SchemaValidator(core_schema.bytes_schema(), config=CoreConfig(val_json_bytes='base64url'))
+    assert v.validate_json('"-_-_"') == data
+    assert v.validate_json('"Zm9vIGJhcg"') == b'foo bar'
+    assert v.validate_json('"Zm9vIGJhcg=="') == b'foo bar'
+
+    # unlike 'base64', the standard alphabet isn't accepted
+    with pytest.raises(ValidationError, match='Data should be valid base64url: Invalid symbol 43, offset 0.'):
+        v.validate_json('"+/+/"')
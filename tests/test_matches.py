@@ -0,0 +1,91 @@
+import pytest
+
+from pydantic_core import SchemaValidator, core_schema
+
+
+def test_smart_union():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    assert v.matches(1) == 'int'
+    assert v.matches('1') == 'str'
+    assert v.matches([]) is None
+
+
+def test_smart_union_labels():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [(core_schema.int_schema(), 'integer'), (core_schema.float_schema(strict=True), 'floating')]
+        )
+    )
+    assert v.matches(1) == 'integer'
+    assert v.matches(1.0) == 'floating'
+    assert v.matches('1') == 'integer'
+    assert v.matches('1', strict=True) is None
+
+
+def test_left_to_right_union():
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], mode='left_to_right')
+    )
+    assert v.matches('1') == 'int'
+    assert v.matches('x') == 'str'
+
+
+def test_tagged_union():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            discriminator='kind',
+            choices={
+                'cat': core_schema.typed_dict_schema({'kind': core_schema.typed_dict_field(core_schema.str_schema())}),
+                'dog': core_schema.typed_dict_schema(
+                    {
+                        'kind': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'bark': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+            },
+        )
+    )
+    assert v.matches({'kind': 'cat'}) == 'cat'
+    assert v.matches({'kind': 'dog', 'bark': '1'}) == 'dog'
+    assert v.matches({'kind': 'dog', 'bark': 'x'}) is None
+    assert v.matches({'kind': 'fish'}) is None
+    assert v.matches({}) is None
+    assert v.matches('not a dict') is None
+
+
+def test_tagged_union_function_discriminator():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            discriminator=lambda x: x.get('n') if isinstance(x, dict) else None,
+            choices={1: core_schema.dict_schema(), 2: core_schema.dict_schema()},
+        )
+    )
+    assert v.matches({'n': 2}) == '2'
+    assert v.matches({}) is None
+
+
+def test_definition_ref():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('u'),
+            [core_schema.union_schema([core_schema.int_schema(), core_schema.none_schema()], ref='u')],
+        )
+    )
+    assert v.matches(None) == 'none'
+
+
+def test_internal_error_raised():
+    def f(input_value):
+        raise RuntimeError('boom')
+
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.no_info_plain_validator_function(f), core_schema.int_schema()])
+    )
+    with pytest.raises(RuntimeError, match='boom'):
+        v.matches(1)
+
+
+def test_not_a_union():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(TypeError, match='matches is not supported for int'):
+        v.matches(1)
@@ -0,0 +1,168 @@
+import dataclasses
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+class Address:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+class User:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+address_schema = core_schema.model_schema(
+    Address,
+    core_schema.model_fields_schema(
+        {
+            'city': core_schema.model_field(core_schema.str_schema()),
+            'zip': core_schema.model_field(
+                core_schema.with_default_schema(core_schema.nullable_schema(core_schema.str_schema()), default=None)
+            ),
+        }
+    ),
+)
+
+user_v = SchemaValidator(
+    core_schema.model_schema(
+        User,
+        core_schema.model_fields_schema(
+            {
+                'name': core_schema.model_field(core_schema.str_schema()),
+                'age': core_schema.model_field(core_schema.with_default_schema(core_schema.int_schema(), default=0)),
+                'address': core_schema.model_field(address_schema),
+                'tags': core_schema.model_field(
+                    core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema())
+                ),
+            }
+        ),
+    )
+)
+
+
+@pytest.fixture
+def user():
+    return user_v.validate_python(
+        {'name': 'alice', 'age': 30, 'address': {'city': 'London', 'zip': 'N1'}, 'tags': {'a': 1, 'b': 2}}
+    )
+
+
+def test_merge_patch_model(user):
+    patched = user_v.apply_merge_patch(user, '{"age": 31, "address": {"zip": null}, "tags": {"a": null, "c": 3}}')
+    assert patched is not user
+    assert patched.name == 'alice'
+    assert patched.age == 31
+    assert patched.address.__dict__ == {'city': 'London', 'zip': None}
+    assert patched.tags == {'b': 2, 'c': 3}
+    # the original instance is left alone
+    assert user.age == 30
+    assert user.address.zip == 'N1'
+    assert user.tags == {'a': 1, 'b': 2}
+
+
+def test_merge_patch_null_uses_default(user):
+    patched = user_v.apply_merge_patch(user, b'{"age": null}')
+    assert patched.age == 0
+
+
+def test_merge_patch_validates_result(user):
+    with pytest.raises(ValidationError) as exc_info:
+        user_v.apply_merge_patch(user, '{"name": null, "age": "old"}')
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('missing', ('name',)),
+        ('int_parsing', ('age',)),
+    ]
+
+
+def test_merge_patch_replaces_non_objects(user):
+    patched = user_v.apply_merge_patch(user, '{"address": {"city": "Paris"}, "tags": {}}')
+    assert patched.address.__dict__ == {'city': 'Paris', 'zip': 'N1'}
+    assert patched.tags == {'a': 1, 'b': 2}
+
+    with pytest.raises(ValidationError, match='Input should be a valid dictionary or instance of Model'):
+        user_v.apply_merge_patch(user, '{"address": "Paris"}')
+
+
+def test_merge_patch_dict():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema()))
+    # arrays are replaced rather than merged
+    assert v.apply_merge_patch({'a': [1, 2], 'b': [3]}, '{"a": [4], "b": null, "c": []}') == {'a': [4], 'c': []}
+    # a patch which isn't an object replaces the whole value
+    with pytest.raises(ValidationError, match='Input should be a valid dictionary'):
+        v.apply_merge_patch({'a': [1]}, '[1]')
+
+
+def test_merge_patch_dataclass():
+    @dataclasses.dataclass
+    class Point:
+        x: int
+        y: int = 0
+
+    v = SchemaValidator(
+        core_schema.dataclass_schema(
+            Point,
+            core_schema.dataclass_args_schema(
+                'Point',
+                [
+                    core_schema.dataclass_field('x', core_schema.int_schema()),
+                    core_schema.dataclass_field(
+                        'y', core_schema.with_default_schema(core_schema.int_schema(), default=0)
+                    ),
+                ],
+            ),
+            ['x', 'y'],
+        )
+    )
+    assert v.apply_merge_patch(Point(1, 2), '{"x": 5, "y": null}') == Point(5, 0)
+
+
+def test_merge_patch_invalid_json(user):
+    with pytest.raises(ValueError, match='EOF while parsing an object'):
+        user_v.apply_merge_patch(user, '{"age": 1')
+
+
+def test_merge_patch_fields_set():
+    user = user_v.validate_python({'name': 'alice', 'address': {'city': 'London'}, 'tags': {}})
+    assert user.__pydantic_fields_set__ == {'name', 'address', 'tags'}
+
+    patched = user_v.apply_merge_patch(user, '{"address": {"zip": "N1"}}')
+    assert patched.__pydantic_fields_set__ == {'name', 'address', 'tags'}
+    assert patched.address.__pydantic_fields_set__ == {'city', 'zip'}
+
+    patched = user_v.apply_merge_patch(user, '{"age": 31}')
+    assert patched.__pydantic_fields_set__ == {'name', 'age', 'address', 'tags'}
+    assert patched.address.__pydantic_fields_set__ == {'city'}
+
+    patched = user_v.apply_merge_patch(patched, '{"age": null}')
+    assert patched.__pydantic_fields_set__ == {'name', 'address', 'tags'}
+
+
+def test_merge_patch_validation_alias():
+    class Item:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            Item,
+            core_schema.model_fields_schema(
+                {
+                    'name': core_schema.model_field(core_schema.str_schema(), validation_alias='itemName'),
+                    'count': core_schema.model_field(
+                        core_schema.with_default_schema(core_schema.int_schema(), default=0),
+                        validation_alias='itemCount',
+                    ),
+                }
+            ),
+        )
+    )
+    item = v.validate_python({'itemName': 'a'})
+
+    patched = v.apply_merge_patch(item, '{"name": "b"}')
+    assert patched.__dict__ == {'name': 'b', 'count': 0}
+    assert patched.__pydantic_fields_set__ == {'name'}
+
+    patched = v.apply_merge_patch(item, '{"itemCount": 2}')
+    assert patched.__dict__ == {'name': 'a', 'count': 2}
+    assert patched.__pydantic_fields_set__ == {'name', 'count'}
@@ -2,7 +2,8 @@ import dataclasses
 import re
 from datetime import date
 from enum import Enum
-from typing import Any
+from fractions import Fraction
+from typing import Any, NamedTuple
 
 import pytest
 
@@ -21,6 +22,16 @@ class MyModel:
     __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
 
 
+class MyProtocol:
+    @classmethod
+    def __validate_core__(cls, value):
+        return value
+
+
+class MyNamedTuple(NamedTuple):
+    x: int
+
+
 @dataclasses.dataclass
 class MyDataclass:
     x: int
@@ -87,7 +98,13 @@ all_schema_functions = [
         {'type': 'enum', 'cls': MyEnum, 'members': [MyEnum.a, MyEnum.b]},
     ),
     (core_schema.is_instance_schema, args(int), {'type': 'is-instance', 'cls': int}),
+    (
+        core_schema.is_instance_schema,
+        args(int, fallback_schema=core_schema.str_schema()),
+        {'type': 'is-instance', 'cls': int, 'fallback_schema': {'type': 'str'}},
+    ),
     (core_schema.callable_schema, args(), {'type': 'callable'}),
+    (core_schema.callable_schema, args(arity=2), {'type': 'callable', 'arity': 2}),
     (core_schema.list_schema, args(), {'type': 'list'}),
     (core_schema.list_schema, args({'type': 'int'}), {'type': 'list', 'items_schema': {'type': 'int'}}),
     (core_schema.tuple_schema, args([]), {'type': 'tuple', 'items_schema': []}),
@@ -102,6 +119,23 @@ all_schema_functions = [
         {'type': 'frozenset', 'items_schema': {'type': 'int'}, 'max_length': 5},
     ),
     (core_schema.generator_schema, args({'type': 'int'}), {'type': 'generator', 'items_schema': {'type': 'int'}}),
+    (core_schema.deque_schema, args(), {'type': 'deque'}),
+    (
+        core_schema.deque_schema,
+        args({'type': 'int'}, maxlen=3),
+        {'type': 'deque', 'items_schema': {'type': 'int'}, 'maxlen': 3},
+    ),
+    (core_schema.counter_schema, args({'type': 'str'}), {'type': 'counter', 'keys_schema': {'type': 'str'}}),
+    (
+        core_schema.defaultdict_schema,
+        args(default_factory=list),
+        {'type': 'defaultdict', 'default_factory': list},
+    ),
+    (
+        core_schema.namedtuple_schema,
+        args(MyNamedTuple, [{'type': 'int'}]),
+        {'type': 'namedtuple', 'cls': MyNamedTuple, 'items_schema': [{'type': 'int'}]},
+    ),
     (core_schema.dict_schema, args(), {'type': 'dict'}),
     (
         core_schema.dict_schema,
@@ -265,6 +299,8 @@ all_schema_functions = [
     (core_schema.json_schema, args({'type': 'int'}), {'type': 'json', 'schema': {'type': 'int'}}),
     (core_schema.url_schema, args(), {'type': 'url'}),
     (core_schema.multi_host_url_schema, args(), {'type': 'multi-host-url'}),
+    (core_schema.email_schema, args(), {'type': 'email'}),
+    (core_schema.email_schema, args(allow_smtputf8=False), {'type': 'email', 'allow_smtputf8': False}),
     (
         core_schema.lax_or_strict_schema,
         args({'type': 'int'}, {'type': 'int'}),
@@ -276,6 +312,7 @@ all_schema_functions = [
         {'type': 'json-or-python', 'json_schema': {'type': 'int'}, 'python_schema': {'type': 'str'}},
     ),
     (core_schema.is_subclass_schema, args(MyModel), {'type': 'is-subclass', 'cls': MyModel}),
+    (core_schema.protocol_custom_schema, args(MyProtocol), {'type': 'protocol-custom', 'cls': MyProtocol}),
     (
         core_schema.definitions_schema,
         args({'type': 'definition-ref', 'schema_ref': 'an-int'}, [{'type': 'int', 'ref': 'an-int'}]),
@@ -305,9 +342,33 @@ all_schema_functions = [
         args(MyDataclass, {'type': 'int'}, ['foobar'], slots=True),
         {'type': 'dataclass', 'schema': {'type': 'int'}, 'fields': ['foobar'], 'cls': MyDataclass, 'slots': True},
     ),
+    (
+        core_schema.context_switch_schema,
+        args('api_version', {'v1': {'type': 'int'}}, default_schema={'type': 'str'}),
+        {
+            'type': 'context-switch',
+            'context_key': 'api_version',
+            'choices': {'v1': {'type': 'int'}},
+            'default_schema': {'type': 'str'},
+        },
+    ),
     (core_schema.uuid_schema, args(), {'type': 'uuid'}),
+    (core_schema.path_schema, args(), {'type': 'path'}),
+    (
+        core_schema.path_schema,
+        args(path_type='posix', must_be_absolute=True, allowed_suffixes=['.txt']),
+        {'type': 'path', 'path_type': 'posix', 'must_be_absolute': True, 'allowed_suffixes': ['.txt']},
+    ),
+    (core_schema.ip_address_schema, args(), {'type': 'ip-address'}),
+    (core_schema.ip_address_schema, args(version=4), {'type': 'ip-address', 'version': 4}),
+    (core_schema.ip_network_schema, args(version=6), {'type': 'ip-network', 'version': 6}),
+    (core_schema.ip_interface_schema, args(strict=True), {'type': 'ip-interface', 'strict': True}),
+    (core_schema.secret_str_schema, args(min_length=3), {'type': 'secret_str', 'min_length': 3}),
+    (core_schema.secret_bytes_schema, args(strict=True), {'type': 'secret_bytes', 'strict': True}),
     (core_schema.decimal_schema, args(), {'type': 'decimal'}),
     (core_schema.decimal_schema, args(multiple_of=5, gt=1.2), {'type': 'decimal', 'multiple_of': 5, 'gt': 1.2}),
+    (core_schema.fraction_schema, args(), {'type': 'fraction'}),
+    (core_schema.fraction_schema, args(le=Fraction(3, 4)), {'type': 'fraction', 'le': Fraction(3, 4)}),
     (core_schema.complex_schema, args(), {'type': 'complex'}),
     (core_schema.invalid_schema, args(), {'type': 'invalid'}),
 ]
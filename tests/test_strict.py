@@ -5,7 +5,8 @@ from typing import Any
 
 import pytest
 
-from pydantic_core import ValidationError
+from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import core_schema as cs
 
 from .conftest import Err, PyAndJson
 
@@ -47,3 +48,100 @@ def test_int_strict_argument(
     else:
         assert v.isinstance_test(input_value, strict_to_validator) is True
         assert v.validate_test(input_value, strict_to_validator) == expected
+
+
+class MyList(list):
+    pass
+
+
+class MyDict(dict):
+    pass
+
+
+class MyStr(str):
+    pass
+
+
+@pytest.mark.parametrize(
+    'schema,config,subclass_input,exact_input,error_type',
+    [
+        (cs.list_schema(strict_exact_type=True), None, MyList([1]), [1], 'list_type'),
+        (cs.list_schema(), cs.CoreConfig(list_strict_exact_type=True), MyList([1]), [1], 'list_type'),
+        (cs.dict_schema(strict_exact_type=True), None, MyDict(a=1), {'a': 1}, 'dict_type'),
+        (cs.dict_schema(), cs.CoreConfig(dict_strict_exact_type=True), MyDict(a=1), {'a': 1}, 'dict_type'),
+        (cs.str_schema(strict_exact_type=True), None, MyStr('x'), 'x', 'string_type'),
+        (cs.str_schema(), cs.CoreConfig(str_strict_exact_type=True), MyStr('x'), 'x', 'string_type'),
+    ],
+)
+def test_strict_exact_type(schema, config, subclass_input, exact_input, error_type):
+    v = SchemaValidator(schema, config)
+    assert v.validate_python(exact_input, strict=True) == exact_input
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(subclass_input, strict=True)
+    assert exc_info.value.errors(include_url=False)[0]['type'] == error_type
+    # subclasses are still accepted in lax mode
+    assert v.validate_python(subclass_input) == subclass_input
+
+
+def test_strict_exact_type_default():
+    v = SchemaValidator(cs.list_schema(strict=True))
+    assert v.validate_python(MyList([1])) == [1]
+
+
+def test_strict_exact_type_json():
+    v = SchemaValidator(cs.dict_schema(strict=True, strict_exact_type=True))
+    assert v.validate_json('{"a": 1}') == {'a': 1}
+
+
+def strict_overrides_schema() -> cs.CoreSchema:
+    address = cs.typed_dict_schema(
+        {
+            'street': cs.typed_dict_field(cs.str_schema()),
+            'postcode': cs.typed_dict_field(cs.int_schema()),
+        }
+    )
+    return cs.typed_dict_schema(
+        {
+            'id': cs.typed_dict_field(cs.int_schema()),
+            'legacy_id': cs.typed_dict_field(cs.int_schema()),
+            'address': cs.typed_dict_field(address),
+        }
+    )
+
+
+def test_strict_overrides():
+    v = SchemaValidator(strict_overrides_schema())
+    input_value = {'id': 1, 'legacy_id': '2', 'address': {'street': 'Main', 'postcode': '3'}}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value, strict=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('legacy_id',), ('address', 'postcode')]
+
+    assert v.validate_python(
+        input_value, strict=True, strict_overrides={'legacy_id': False, ('address', 'postcode'): False}
+    ) == {'id': 1, 'legacy_id': 2, 'address': {'street': 'Main', 'postcode': 3}}
+
+    # an override applies to everything within the field
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value, strict=True, strict_overrides={'address': False})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('legacy_id',)]
+
+    # longer paths take precedence
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value, strict_overrides={'address': False, ('address', 'postcode'): True})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('address', 'postcode')]
+
+
+def test_strict_overrides_json():
+    v = SchemaValidator(cs.model_fields_schema({'a': cs.model_field(cs.int_schema())}))
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type'):
+        v.validate_json('{"a": "1"}', strict_overrides={'a': True})
+    assert v.validate_json('{"a": "1"}', strict=True, strict_overrides={'a': False})[0] == {'a': 1}
+
+
+def test_strict_overrides_invalid():
+    v = SchemaValidator(strict_overrides_schema())
+    with pytest.raises(TypeError, match='`strict_overrides` keys must be a field name or a non-empty tuple of field'):
+        v.validate_python({}, strict_overrides={1: True})
+    with pytest.raises(TypeError, match='`strict_overrides` keys must be a field name or a non-empty tuple of field'):
+        v.validate_python({}, strict_overrides={(): True})
@@ -0,0 +1,83 @@
+import pytest
+
+from pydantic_core import (
+    CoreConfig,
+    PydanticOmit,
+    SchemaError,
+    SchemaValidator,
+    ValidationError,
+    ValidationResult,
+    core_schema,
+)
+
+
+def test_ok():
+    v = SchemaValidator(core_schema.int_schema())
+    result = v.try_validate_python('123')
+    assert isinstance(result, ValidationResult)
+    assert result.ok is True
+    assert result.value == 123
+    assert result.error is None
+    assert result.errors == []
+    assert repr(result) == 'ValidationResult(ok=True, value=123)'
+
+
+def test_ok_none():
+    v = SchemaValidator(core_schema.none_schema())
+    result = v.try_validate_python(None)
+    assert result.ok is True
+    assert result.value is None
+
+
+def test_error():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    result = v.try_validate_python([1, 'a', 'b'])
+    assert result.ok is False
+    assert result.value is None
+    assert isinstance(result.error, ValidationError)
+    assert result.error.error_count() == 2
+    assert result.error.title == 'list[int]'
+    assert [(e['type'], e['loc']) for e in result.errors] == [('int_parsing', (1,)), ('int_parsing', (2,))]
+    assert repr(result) == 'ValidationResult(ok=False, error_count=2)'
+
+
+def test_error_matches_raised():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())}),
+        config=CoreConfig(title='MyTypedDict'),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x': 'a'})
+    result = v.try_validate_python({'x': 'a'})
+    assert result.errors == exc_info.value.errors()
+    assert str(result.error) == str(exc_info.value)
+
+
+def test_hide_input():
+    v = SchemaValidator(core_schema.int_schema(), config=CoreConfig(hide_input_in_errors=True))
+    result = v.try_validate_python('a')
+    assert 'input_value' not in str(result.error)
+
+
+def test_kwargs():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.try_validate_python('1', strict=True).ok is False
+    assert v.try_validate_python('1', strict=False).ok is True
+
+
+def test_internal_error_raised():
+    def f(input_value):
+        raise RuntimeError('boom')
+
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(f))
+    with pytest.raises(RuntimeError, match='boom'):
+        v.try_validate_python(1)
+
+
+def test_omit_raised():
+    def f(input_value):
+        raise PydanticOmit
+
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(f))
+    with pytest.raises(SchemaError, match='Uncaught Omit error'):
+        v.try_validate_python(1)
@@ -1,3 +1,5 @@
+import dataclasses
+
 import pytest
 
 from pydantic_core import SchemaValidator, ValidationError, core_schema
@@ -117,3 +119,107 @@ def test_validate_assignment_with_context():
     assert m2 == {'f1': "3| context: {'x': 'y', 'f1': '3'}", 'f2': "2| context: {'x': 'y', 'f1': '1', 'f2': '2'}"}
     assert model_extra is None
     assert fields_set == {'f1'}
+
+
+class MyModel:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+def test_model_context_layer():
+    def f(input_value, info):
+        if info.context is not None:
+            info.context['seen'] = input_value
+        return f'{input_value}| context: {info.context}'
+
+    inner_schema = core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {'b': core_schema.model_field(core_schema.with_info_plain_validator_function(f))}
+        ),
+        context={'inner': True},
+    )
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(inner_schema),
+                    'c': core_schema.model_field(core_schema.with_info_plain_validator_function(f)),
+                }
+            ),
+        )
+    )
+
+    context = {'x': 'y'}
+    m = v.validate_python({'a': {'b': '1'}, 'c': '2'}, context=context)
+    assert m.a.b == "1| context: {'x': 'y', 'inner': True, 'seen': '1'}"
+    # the layer is popped once the inner model is validated, changes to the outer context are kept
+    assert m.c == "2| context: {'x': 'y', 'seen': '2'}"
+    assert context == {'x': 'y', 'seen': '2'}
+
+    m = v.validate_python({'a': {'b': '1'}, 'c': '2'})
+    assert m.a.b == "1| context: {'inner': True, 'seen': '1'}"
+    assert m.c == '2| context: None'
+
+    with pytest.raises(TypeError, match="context must be a dict to add the entries of a schema's `context`, got str"):
+        v.validate_python({'a': {'b': '1'}, 'c': '2'}, context='frogspawn')
+
+
+def test_dataclass_context_layer():
+    @dataclasses.dataclass
+    class MyDataclass:
+        a: str
+
+    def f(input_value, info):
+        return f'{input_value}| context: {info.context}'
+
+    v = SchemaValidator(
+        core_schema.dataclass_schema(
+            MyDataclass,
+            core_schema.dataclass_args_schema(
+                'MyDataclass',
+                [core_schema.dataclass_field('a', core_schema.with_info_plain_validator_function(f))],
+            ),
+            ['a'],
+            context={'layer': 1},
+        )
+    )
+    assert v.validate_python({'a': 'x'}, context={'outer': 0}).a == "x| context: {'outer': 0, 'layer': 1}"
+    assert v.validate_json('{"a": "x"}').a == "x| context: {'layer': 1}"
+
+    dc = MyDataclass(a='y')
+    v.validate_assignment(dc, 'a', 'z')
+    assert dc.a == "z| context: {'layer': 1}"
+
+
+def test_field_path(py_and_json: PyAndJson):
+    paths = []
+
+    def f(input_value, info):
+        paths.append(info.field_path)
+        return input_value
+
+    leaf = core_schema.with_info_plain_validator_function(f)
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema(
+                        {'b': core_schema.typed_dict_field(core_schema.list_schema(leaf))},
+                    )
+                ),
+                'c': core_schema.typed_dict_field(leaf),
+                'd': core_schema.typed_dict_field(core_schema.tuple_schema([leaf, leaf], variadic_item_index=1)),
+            }
+        )
+    )
+    assert v.validate_test({'a': {'b': [1, 2]}, 'c': 3, 'd': [4, 5, 6]}) == {
+        'a': {'b': [1, 2]},
+        'c': 3,
+        'd': (4, 5, 6),
+    }
+    assert paths == [('a', 'b', 0), ('a', 'b', 1), ('c',), ('d', 0), ('d', 1), ('d', 2)]
+
+    paths.clear()
+    assert SchemaValidator(leaf).validate_python(1) == 1
+    assert paths == [()]
@@ -0,0 +1,265 @@
+from dataclasses import dataclass
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def counting_schema(calls: list, schema: core_schema.CoreSchema) -> core_schema.CoreSchema:
+    def f(input_value):
+        calls.append(input_value)
+        return input_value
+
+    return core_schema.no_info_before_validator_function(f, schema)
+
+
+def item_schema(calls: list) -> core_schema.TypedDictSchema:
+    return core_schema.typed_dict_schema(
+        {'x': core_schema.typed_dict_field(counting_schema(calls, core_schema.int_schema()))}
+    )
+
+
+def test_memoize_typed_dict():
+    calls = []
+    v = SchemaValidator(core_schema.list_schema(item_schema(calls)))
+    shared = {'x': '1'}
+    other = {'x': '2'}
+
+    output = v.validate_python([shared, shared, other, shared])
+    assert output == [{'x': 1}, {'x': 1}, {'x': 2}, {'x': 1}]
+    assert len(calls) == 4
+    assert output[0] is not output[1]
+
+    calls.clear()
+    output = v.validate_python([shared, shared, other, shared], memoize=True)
+    assert output == [{'x': 1}, {'x': 1}, {'x': 2}, {'x': 1}]
+    assert calls == ['1', '2']
+    # dicts are mutable, so each reuse gets a copy
+    assert output[0] is not output[1]
+    output[0]['x'] = 5
+    assert output[1] == {'x': 1}
+
+
+def test_memoize_mutable_values_not_cached():
+    calls = []
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'x': core_schema.typed_dict_field(
+                        counting_schema(calls, core_schema.list_schema(core_schema.int_schema()))
+                    )
+                }
+            )
+        )
+    )
+    shared = {'x': ['1']}
+    output = v.validate_python([shared, shared], memoize=True)
+    assert output == [{'x': [1]}, {'x': [1]}]
+    assert len(calls) == 2
+    output[0]['x'].append(2)
+    assert output[1] == {'x': [1]}
+
+
+def test_memoize_strict_overrides():
+    calls = []
+    item = item_schema(calls)
+    item['ref'] = 'item'
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.typed_dict_schema(
+                {name: core_schema.typed_dict_field(core_schema.definition_reference_schema('item')) for name in 'ab'}
+            ),
+            [item],
+        )
+    )
+    shared = {'x': '1'}
+    assert v.validate_python({'a': shared, 'b': shared}, memoize=True) == {'a': {'x': 1}, 'b': {'x': 1}}
+    assert len(calls) == 1
+
+    calls.clear()
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': shared, 'b': shared}, strict_overrides={('b', 'x'): True}, memoize=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('b', 'x')]
+    assert len(calls) == 2
+
+
+def test_memoize_per_call():
+    calls = []
+    v = SchemaValidator(core_schema.list_schema(item_schema(calls)))
+    shared = {'x': '1'}
+    assert v.validate_python([shared, shared], memoize=True) == [{'x': 1}, {'x': 1}]
+    shared['x'] = '3'
+    assert v.validate_python([shared, shared], memoize=True) == [{'x': 3}, {'x': 3}]
+    assert calls == ['1', '3']
+
+
+def test_memoize_equal_not_identical():
+    calls = []
+    v = SchemaValidator(core_schema.list_schema(item_schema(calls)))
+    assert v.validate_python([{'x': '1'}, {'x': '1'}], memoize=True) == [{'x': 1}, {'x': 1}]
+    assert len(calls) == 2
+
+
+def test_memoize_different_validators():
+    calls = []
+    v = SchemaValidator(
+        core_schema.tuple_schema(
+            [
+                item_schema(calls),
+                core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.str_schema())}),
+            ]
+        )
+    )
+    shared = {'x': '1'}
+    assert v.validate_python((shared, shared), memoize=True) == ({'x': 1}, {'x': '1'})
+    assert len(calls) == 1
+
+
+def test_memoize_errors_not_cached():
+    calls = []
+    v = SchemaValidator(core_schema.list_schema(item_schema(calls)))
+    shared = {'x': 'foo'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([shared, shared], memoize=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0, 'x'), (1, 'x')]
+
+
+class MyModel:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+def model_schema(calls: list, field_schema: core_schema.CoreSchema, frozen: bool) -> core_schema.ModelSchema:
+    return core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema({'x': core_schema.model_field(counting_schema(calls, field_schema))}),
+        frozen=frozen,
+    )
+
+
+def test_memoize_frozen_model():
+    calls = []
+    v = SchemaValidator(core_schema.list_schema(model_schema(calls, core_schema.int_schema(), frozen=True)))
+    shared = {'x': '1'}
+    output = v.validate_python([shared, shared], memoize=True)
+    assert output[0] is output[1]
+    assert output[0].x == 1
+    assert calls == ['1']
+
+
+def test_memoize_nested_frozen_model():
+    calls = []
+    inner = model_schema(calls, core_schema.int_schema(), frozen=True)
+    v = SchemaValidator(
+        core_schema.list_schema(model_schema([], core_schema.tuple_schema([inner], variadic_item_index=0), True))
+    )
+    shared = {'x': ({'x': '1'}, {'x': '2'})}
+    output = v.validate_python([shared, shared], memoize=True)
+    assert output[0] is output[1]
+    assert [m.x for m in output[0].x] == [1, 2]
+    assert calls == ['1', '2']
+
+
+@pytest.mark.parametrize(
+    'frozen,field_schema,input_value',
+    [
+        (False, core_schema.int_schema(), '1'),
+        (True, core_schema.list_schema(core_schema.int_schema()), ['1']),
+    ],
+)
+def test_memoize_mutable_model_not_shared(frozen, field_schema, input_value):
+    calls = []
+    v = SchemaValidator(core_schema.list_schema(model_schema(calls, field_schema, frozen)))
+    shared = {'x': input_value}
+    output = v.validate_python([shared, shared], memoize=True)
+    assert output[0] is not output[1]
+    assert len(calls) == 2
+
+
+def test_memoize_frozen_dataclass():
+    @dataclass(frozen=True)
+    class MyDataclass:
+        x: int
+
+    calls = []
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.dataclass_schema(
+                MyDataclass,
+                core_schema.dataclass_args_schema(
+                    'MyDataclass',
+                    [core_schema.dataclass_field('x', counting_schema(calls, core_schema.int_schema()))],
+                ),
+                ['x'],
+                frozen=True,
+            )
+        )
+    )
+    shared = {'x': '1'}
+    output = v.validate_python([shared, shared], memoize=True)
+    assert output == [MyDataclass(x=1), MyDataclass(x=1)]
+    assert output[0] is output[1]
+    assert calls == ['1']
+
+
+def test_memoize_context_dependent():
+    def f(input_value, info):
+        return f'{input_value}:{info.field_name}'
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                name: core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema(
+                        {'x': core_schema.typed_dict_field(core_schema.with_info_plain_validator_function(f))}
+                    )
+                )
+                for name in ('a', 'b')
+            }
+        )
+    )
+    shared = {'x': 'foo'}
+    output = v.validate_python({'a': shared, 'b': shared}, memoize=True)
+    assert output == {'a': {'x': 'foo:x'}, 'b': {'x': 'foo:x'}}
+    assert output['a'] is not output['b']
+
+
+def test_memoize_default_factory():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'x': core_schema.typed_dict_field(
+                        core_schema.with_default_schema(core_schema.list_schema(), default_factory=list),
+                        required=False,
+                    )
+                }
+            )
+        )
+    )
+    shared = {}
+    output = v.validate_python([shared, shared], memoize=True)
+    assert output == [{'x': []}, {'x': []}]
+    assert output[0]['x'] is not output[1]['x']
+
+
+def test_memoize_smart_union_exactness():
+    calls = []
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.union_schema(
+                [
+                    core_schema.typed_dict_schema(
+                        {'x': core_schema.typed_dict_field(counting_schema(calls, core_schema.int_schema()))}
+                    ),
+                    core_schema.typed_dict_schema(
+                        {'x': core_schema.typed_dict_field(core_schema.str_schema())}, extra_behavior='forbid'
+                    ),
+                ]
+            )
+        )
+    )
+    shared = {'x': '1'}
+    # the str choice is an exact match, so it should win both times even when the int choice is memoized
+    assert v.validate_python([shared, shared], memoize=True) == [{'x': '1'}, {'x': '1'}]
+    assert v.validate_python([shared, shared]) == [{'x': '1'}, {'x': '1'}]
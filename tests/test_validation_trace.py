@@ -0,0 +1,191 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, ValidationReport, core_schema
+
+
+def test_trace_off():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_python('1') == 1
+    assert v.validate_python('1', trace=False) == 1
+
+
+def test_trace_simple():
+    v = SchemaValidator(core_schema.int_schema())
+    value, report = v.validate_python('1', trace=True)
+    assert value == 1
+    assert isinstance(report, ValidationReport)
+    assert report.trace == [{'event': 'validate', 'validator': 'int'}]
+
+
+def test_trace_smart_union():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    value, report = v.validate_python('foo', trace=True)
+    assert value == 'foo'
+    assert report.trace == [
+        {'event': 'validate', 'validator': 'union[int,str]'},
+        {'event': 'validate', 'validator': 'int'},
+        {
+            'event': 'union_branch',
+            'union': 'union[int,str]',
+            'choice': 'int',
+            'outcome': 'rejected',
+            'errors': [
+                {
+                    'type': 'int_parsing',
+                    'loc': (),
+                    'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                }
+            ],
+        },
+        {'event': 'validate', 'validator': 'str'},
+        {
+            'event': 'union_branch',
+            'union': 'union[int,str]',
+            'choice': 'str',
+            'outcome': 'success',
+            'exactness': 'exact',
+        },
+        {'event': 'selected', 'validator': 'union[int,str]', 'choice': 'str'},
+    ]
+
+
+def test_trace_smart_union_best_match():
+    v = SchemaValidator(
+        core_schema.union_schema([(core_schema.int_schema(), 'a'), (core_schema.float_schema(strict=True), 'b')])
+    )
+    value, report = v.validate_python(1.0, trace=True)
+    assert value == 1.0
+    assert [(e['event'], e.get('choice'), e.get('exactness')) for e in report.trace] == [
+        ('validate', None, None),
+        ('validate', None, None),
+        ('union_branch', 'a', 'lax'),
+        ('validate', None, None),
+        ('union_branch', 'b', 'exact'),
+        ('selected', 'b', None),
+    ]
+
+
+def test_trace_left_to_right_union():
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], mode='left_to_right')
+    )
+    value, report = v.validate_python('1', trace=True)
+    assert value == 1
+    assert [(e['event'], e.get('choice'), e.get('outcome')) for e in report.trace] == [
+        ('validate', None, None),
+        ('validate', None, None),
+        ('union_branch', 'int', 'success'),
+        ('selected', 'int', None),
+    ]
+
+
+def test_trace_nested_union():
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.union_schema([core_schema.int_schema(), core_schema.bool_schema()]))
+    )
+    value, report = v.validate_python([1, True], trace=True)
+    assert value == [1, True]
+    assert [(e['event'], e.get('validator', e.get('choice'))) for e in report.trace] == [
+        ('validate', 'list[union[int,bool]]'),
+        ('validate', 'union[int,bool]'),
+        ('validate', 'int'),
+        ('union_branch', 'int'),
+        ('selected', 'union[int,bool]'),
+        ('validate', 'union[int,bool]'),
+        ('validate', 'int'),
+        ('union_branch', 'int'),
+        ('validate', 'bool'),
+        ('union_branch', 'bool'),
+        ('selected', 'union[int,bool]'),
+    ]
+
+
+def test_trace_tagged_union():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            discriminator='kind',
+            choices={
+                'a': core_schema.typed_dict_schema({'kind': core_schema.typed_dict_field(core_schema.str_schema())}),
+                'b': core_schema.typed_dict_schema(
+                    {
+                        'kind': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'x': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+            },
+        )
+    )
+    value, report = v.validate_python({'kind': 'b', 'x': '1'}, trace=True)
+    assert value == {'kind': 'b', 'x': 1}
+    assert report.trace[1] == {
+        'event': 'selected',
+        'validator': 'tagged-union[typed-dict,typed-dict]',
+        'key': 'b',
+        'choice': 'typed-dict',
+    }
+    assert report.trace[2:] == [
+        {'event': 'validate', 'validator': 'typed-dict'},
+        {'event': 'validate', 'validator': 'str'},
+        {'event': 'validate', 'validator': 'int'},
+    ]
+
+
+def test_trace_context_switch():
+    v = SchemaValidator(
+        core_schema.context_switch_schema(
+            'mode', {'strict': core_schema.int_schema(strict=True)}, default_schema=core_schema.str_schema()
+        )
+    )
+    value, report = v.validate_python('x', context={}, trace=True)
+    assert value == 'x'
+    assert report.trace[1] == {'event': 'selected', 'validator': 'context-switch[mode][int]', 'choice': 'str'}
+
+    value, report = v.validate_python(1, context={'mode': 'strict'}, trace=True)
+    assert value == 1
+    assert report.trace[1] == {
+        'event': 'selected',
+        'validator': 'context-switch[mode][int]',
+        'key': 'strict',
+        'choice': 'int',
+    }
+
+
+def test_trace_validation_error():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.bool_schema()]))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('foo', trace=True)
+    assert [e['type'] for e in exc_info.value.errors()] == ['int_parsing', 'bool_parsing']
+    assert [(e['event'], e.get('validator', e.get('choice'))) for e in exc_info.value.trace] == [
+        ('validate', 'union[int,bool]'),
+        ('validate', 'int'),
+        ('union_branch', 'int'),
+        ('validate', 'bool'),
+        ('union_branch', 'bool'),
+    ]
+
+
+def test_trace_validation_error_nested():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': '1', 'b': ['x', 2]}, trace=True)
+    assert exc_info.value.trace == [
+        {'event': 'validate', 'validator': 'typed-dict'},
+        {'event': 'validate', 'validator': 'int'},
+        {'event': 'validate', 'validator': 'list[str]'},
+        {'event': 'validate', 'validator': 'str'},
+        {'event': 'validate', 'validator': 'str'},
+    ]
+
+
+def test_no_trace_on_validation_error():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('foo')
+    assert exc_info.value.trace is None
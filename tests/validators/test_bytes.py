@@ -1,3 +1,4 @@
+import array
 import re
 from typing import Any
 
@@ -19,6 +20,8 @@ def test_strict_bytes_validator():
         v.validate_python('foo')
     with pytest.raises(ValidationError, match='Input should be a valid bytes'):
         v.validate_python(bytearray(b'foo'))
+    with pytest.raises(ValidationError, match='Input should be a valid bytes'):
+        v.validate_python(memoryview(b'foo'))
 
 
 def test_lax_bytes_validator():
@@ -45,6 +48,26 @@ def test_lax_bytes_validator():
     ]
 
 
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (memoryview(b'foo'), b'foo'),
+        (memoryview(b'abcdef')[::2], b'ace'),
+        (memoryview(bytearray(b'foo')), b'foo'),
+        (array.array('B', b'foo'), b'foo'),
+    ],
+)
+def test_bytes_buffer_protocol(input_value, expected):
+    v = SchemaValidator(cs.bytes_schema())
+    assert v.validate_python(input_value) == expected
+
+
+def test_bytes_buffer_protocol_not_bytes():
+    v = SchemaValidator(cs.bytes_schema())
+    with pytest.raises(ValidationError, match='Input should be a valid bytes'):
+        v.validate_python(array.array('i', [1, 2]))
+
 @pytest.mark.parametrize(
     'opts,input,expected',
     [
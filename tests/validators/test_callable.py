@@ -1,6 +1,11 @@
+import gc
+import inspect
+import weakref
+from typing import Callable
+
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 from pydantic_core import core_schema as cs
 
 
@@ -60,3 +65,283 @@ def test_repr():
 
     with pytest.raises(ValidationError, match=r'callable\s+Input should be callable'):
         v.validate_python('foo')
+
+
+def two_args(a, b):
+    pass
+
+
+def two_args_default(a, b, c=None):
+    pass
+
+
+def var_args(*args):
+    pass
+
+
+def kw_only(a, b, *, c):
+    pass
+
+
+class TwoArgsCallable:
+    def __call__(self, a, b):
+        pass
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (two_args, True),
+        (two_args_default, True),
+        (var_args, True),
+        (lambda a, b: None, True),
+        (TwoArgsCallable(), True),
+        (max, True),
+        (func, False),
+        (lambda a: None, False),
+        (lambda a, b, c: None, False),
+        (kw_only, False),
+        (Foo, False),
+    ],
+)
+def test_callable_arity(input_value, expected):
+    v = SchemaValidator(cs.callable_schema(arity=2))
+    assert v.isinstance_python(input_value) is expected
+    if not expected:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'callable_arity',
+                'loc': (),
+                'msg': 'Input should be callable with 2 positional arguments',
+                'input': input_value,
+                'ctx': {'arity': 2},
+            }
+        ]
+
+
+@pytest.mark.parametrize(
+    'callable_type,input_value,expected',
+    [
+        (Callable[[int, str], bool], two_args, True),
+        (Callable[[int, str], bool], func, False),
+        (Callable[[], int], func, True),
+        (Callable[[], int], two_args, False),
+        (Callable[..., int], two_args, True),
+        (Callable, func, True),
+    ],
+)
+def test_callable_type(callable_type, input_value, expected):
+    v = SchemaValidator(cs.callable_schema(callable_type=callable_type))
+    assert v.isinstance_python(input_value) is expected
+
+
+def test_callable_arity_and_type():
+    with pytest.raises(SchemaError, match="'arity' and 'callable_type' cannot be used together"):
+        SchemaValidator(cs.callable_schema(arity=1, callable_type=Callable[[int], int]))
+
+
+def on_event(event, *, retries, timeout=None):
+    pass
+
+
+def on_event_kwargs(event, **kwargs):
+    pass
+
+
+def positional_only(event, retries, /):
+    pass
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (on_event, True),
+        (on_event_kwargs, True),
+        (lambda event, retries: None, True),
+        (lambda retries, event: None, True),
+        (max, True),
+        (func, False),
+        (positional_only, False),
+        (lambda event: None, False),
+    ],
+)
+def test_required_keyword_params(input_value, expected):
+    v = SchemaValidator(cs.callable_schema(required_keyword_params=['retries']))
+    assert v.isinstance_python(input_value) is expected
+    if not expected:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'callable_keywords',
+                'loc': (),
+                'msg': "Input should be callable with keyword arguments 'retries'",
+                'input': input_value,
+                'ctx': {'keywords': "'retries'"},
+            }
+        ]
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (on_event, True),
+        (on_event_kwargs, True),
+        (lambda event, retries: None, True),
+        # `retries` would be passed both positionally and by keyword
+        (lambda retries, event: None, False),
+        (lambda event, retries, other: None, False),
+    ],
+)
+def test_required_keyword_params_with_arity(input_value, expected):
+    v = SchemaValidator(cs.callable_schema(arity=1, required_keyword_params=['retries']))
+    assert v.isinstance_python(input_value) is expected
+
+    v = SchemaValidator(cs.callable_schema(callable_type=Callable[[str], None], required_keyword_params=['retries']))
+    assert v.isinstance_python(input_value) is expected
+
+
+def test_required_keyword_params_arity_error_first():
+    v = SchemaValidator(cs.callable_schema(arity=2, required_keyword_params=['retries', 'timeout']))
+    with pytest.raises(ValidationError, match=r'Input should be callable with 2 positional arguments'):
+        v.validate_python(on_event)
+    with pytest.raises(ValidationError, match=r"Input should be callable with keyword arguments 'retries', 'timeout'"):
+        v.validate_python(lambda a, b, retries: None)
+    assert v.isinstance_python(lambda a, b, retries, timeout: None) is True
+
+
+async def async_func():
+    pass
+
+
+class AsyncCallable:
+    async def __call__(self):
+        pass
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (async_func, True),
+        (AsyncCallable().__call__, True),
+        (func, False),
+        (lambda: async_func(), False),
+        (AsyncCallable(), False),
+    ],
+)
+def test_must_be_coroutine(input_value, expected):
+    v = SchemaValidator(cs.callable_schema(must_be_coroutine=True))
+    assert v.isinstance_python(input_value) is expected
+    if not expected:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'callable_coroutine',
+                'loc': (),
+                'msg': 'Input should be a coroutine function',
+                'input': input_value,
+            }
+        ]
+
+
+class CountingSignature:
+    """A callable taking one argument, which counts how often its signature is inspected"""
+
+    inspected = 0
+
+    def __call__(self, a):
+        pass
+
+    @property
+    def __signature__(self):
+        self.inspected += 1
+        return inspect.signature(lambda a: None)
+
+
+def test_signature_cached():
+    v = SchemaValidator(cs.callable_schema(arity=1))
+    f = CountingSignature()
+    assert v.validate_python(f) is f
+    inspected = f.inspected
+    assert inspected > 0
+    assert v.validate_python(f) is f
+    assert v.isinstance_python(f) is True
+    assert f.inspected == inspected
+
+    v = SchemaValidator(cs.callable_schema(arity=2))
+    f = CountingSignature()
+    for _ in range(3):
+        with pytest.raises(ValidationError, match='Input should be callable with 2 positional arguments'):
+            v.validate_python(f)
+    assert f.inspected == inspected
+
+
+def test_signature_cache_weak():
+    v = SchemaValidator(cs.callable_schema(arity=1))
+    f = CountingSignature()
+    f_ref = weakref.ref(f)
+    assert v.validate_python(f) is f
+    del f
+    gc.collect()
+    assert f_ref() is None
+
+
+def test_signature_cached_unhashable():
+    class Unhashable(CountingSignature):
+        __hash__ = None
+
+    v = SchemaValidator(cs.callable_schema(arity=1))
+    f = Unhashable()
+    assert v.validate_python(f) is f
+    inspected = f.inspected
+    assert v.validate_python(f) is f
+    assert f.inspected == inspected
+
+
+def test_signature_cached_by_identity():
+    class AllEqual:
+        def __init__(self, signature):
+            self.__signature__ = inspect.signature(signature)
+
+        def __call__(self, *args):
+            pass
+
+        def __eq__(self, other):
+            return True
+
+        def __hash__(self):
+            return 0
+
+    v = SchemaValidator(cs.callable_schema(arity=1))
+    one_arg = AllEqual(lambda a: None)
+    two_args = AllEqual(lambda a, b: None)
+    assert one_arg == two_args
+    assert v.validate_python(one_arg) is one_arg
+    with pytest.raises(ValidationError, match='Input should be callable with 1 positional argument'):
+        v.validate_python(two_args)
+
+
+def test_signature_not_cached_without_weakref():
+    class NoWeakref:
+        __slots__ = ('inspected',)
+
+        def __init__(self):
+            self.inspected = 0
+
+        def __call__(self, a):
+            pass
+
+        @property
+        def __signature__(self):
+            self.inspected += 1
+            return inspect.signature(lambda a: None)
+
+    v = SchemaValidator(cs.callable_schema(arity=1))
+    f = NoWeakref()
+    assert v.validate_python(f) is f
+    inspected = f.inspected
+    assert v.validate_python(f) is f
+    assert f.inspected == 2 * inspected
\ No newline at end of file
@@ -0,0 +1,146 @@
+import re
+from collections import Counter, OrderedDict, defaultdict, deque
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import core_schema as cs
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ([1, '2', 3], deque([1, 2, 3])),
+        ([], deque()),
+        ([1, 'x'], Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+    ],
+)
+def test_deque(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(cs.deque_schema(cs.int_schema()))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert isinstance(output, deque)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (deque([1, '2']), deque([1, 2])),
+        ((1, 2), deque([1, 2])),
+        ({1, 2}, deque([1, 2])),
+        ((i for i in [1, 2]), deque([1, 2])),
+        ('abc', Err('Input should be a valid list [type=list_type,')),
+    ],
+)
+def test_deque_python(input_value, expected):
+    v = SchemaValidator(cs.deque_schema(cs.int_schema()))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_deque_maxlen():
+    v = SchemaValidator(cs.deque_schema(cs.int_schema(), maxlen=2))
+    output = v.validate_python([1, 2, 3])
+    assert output == deque([2, 3])
+    assert output.maxlen == 2
+
+
+def test_deque_strict():
+    v = SchemaValidator(cs.deque_schema(cs.int_schema(), strict=True))
+    input_value = deque([1, 2])
+    output = v.validate_python(input_value)
+    assert output == input_value
+    assert output is not input_value
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 2])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'is_instance_of',
+            'loc': (),
+            'msg': 'Input should be an instance of deque',
+            'input': [1, 2],
+            'ctx': {'class': 'deque'},
+        }
+    ]
+    assert v.validate_python(deque(['1'])) == deque([1])
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(deque(['1']), strict=True)
+    assert v.validate_json('[1, 2]') == deque([1, 2])
+
+
+def test_deque_item_errors():
+    v = SchemaValidator(cs.deque_schema(cs.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(deque([1, 'x']))
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == (1,)
+
+
+def test_counter(py_and_json: PyAndJson):
+    v = py_and_json(cs.counter_schema(cs.str_schema()))
+    output = v.validate_test({'a': 1, 'b': '2'})
+    assert output == Counter({'a': 1, 'b': 2})
+    assert isinstance(output, Counter)
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid integer [type=int_type,')):
+        v.validate_test({'a': []})
+
+
+def test_counter_python():
+    v = SchemaValidator(cs.counter_schema(cs.str_schema()))
+    assert v.validate_python(Counter('aab')) == Counter({'a': 2, 'b': 1})
+    assert v.validate_python(OrderedDict(a=1)) == Counter({'a': 1})
+
+
+def test_counter_values_schema():
+    v = SchemaValidator(cs.counter_schema(values_schema=cs.float_schema()))
+    assert v.validate_python({'a': '1.5'}) == Counter({'a': 1.5})
+
+
+def test_counter_strict():
+    v = SchemaValidator(cs.counter_schema(cs.str_schema(), strict=True))
+    assert v.validate_python(Counter(a=1)) == Counter(a=1)
+    with pytest.raises(ValidationError, match='Input should be an instance of Counter'):
+        v.validate_python({'a': 1})
+
+
+def test_defaultdict(py_and_json: PyAndJson):
+    v = py_and_json(cs.defaultdict_schema(cs.str_schema(), cs.list_schema(cs.int_schema()), default_factory=list))
+    output = v.validate_test({'a': [1, '2']})
+    assert output == {'a': [1, 2]}
+    assert isinstance(output, defaultdict)
+    assert output['b'] == []
+
+
+def test_defaultdict_keeps_input_factory():
+    v = SchemaValidator(cs.defaultdict_schema(cs.str_schema(), cs.int_schema()))
+    output = v.validate_python(defaultdict(int, a='1'))
+    assert output == {'a': 1}
+    assert output.default_factory is int
+    output = v.validate_python({'a': 1})
+    assert output.default_factory is None
+
+
+def test_defaultdict_schema_factory():
+    v = SchemaValidator(cs.defaultdict_schema(default_factory=set))
+    assert v.validate_python(defaultdict(int)).default_factory is set
+
+
+def test_defaultdict_strict():
+    v = SchemaValidator(cs.defaultdict_schema(strict=True))
+    assert v.validate_python(defaultdict(int, a=1)) == {'a': 1}
+    with pytest.raises(ValidationError, match='Input should be an instance of defaultdict'):
+        v.validate_python({'a': 1})
+
+
+def test_collections_union():
+    v = SchemaValidator(cs.union_schema([cs.deque_schema(), cs.list_schema()]))
+    assert type(v.validate_python([1])) is list
+    assert type(v.validate_python(deque([1]))) is deque
@@ -0,0 +1,95 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+from pydantic_core import core_schema as cs
+
+from ..conftest import PyAndJson
+
+
+@pytest.fixture(scope='module')
+def versioned_schema():
+    return cs.context_switch_schema(
+        'api_version',
+        {
+            'v1': cs.typed_dict_schema({'id': cs.typed_dict_field(cs.int_schema())}),
+            'v2': cs.typed_dict_schema(
+                {'id': cs.typed_dict_field(cs.str_schema()), 'name': cs.typed_dict_field(cs.str_schema())}
+            ),
+        },
+    )
+
+
+def test_context_switch(py_and_json: PyAndJson, versioned_schema):
+    v = py_and_json(versioned_schema)
+    assert v.validate_test({'id': '1'}, context={'api_version': 'v1'}) == {'id': 1}
+    assert v.validate_test({'id': '1', 'name': 'x'}, context={'api_version': 'v2'}) == {'id': '1', 'name': 'x'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'id': '1'}, context={'api_version': 'v2'})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('name',), 'msg': 'Field required', 'input': {'id': '1'}}
+    ]
+
+
+def test_context_switch_invalid_value(py_and_json: PyAndJson, versioned_schema):
+    v = py_and_json(versioned_schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'id': 1}, context={'api_version': 'v3'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'context_switch_invalid',
+            'loc': (),
+            'msg': "Context value 'v3' for 'api_version' does not match any of the expected values: 'v1', 'v2'",
+            'input': {'id': 1},
+            'ctx': {'context_key': 'api_version', 'value': "'v3'", 'expected_values': "'v1', 'v2'"},
+        }
+    ]
+
+
+@pytest.mark.parametrize('context', [None, {}, {'other': 'v1'}, 'not a mapping'])
+def test_context_switch_not_found(versioned_schema, context):
+    v = SchemaValidator(versioned_schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'id': 1}, context=context)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'context_switch_not_found',
+            'loc': (),
+            'msg': "Validation context should include 'api_version'",
+            'input': {'id': 1},
+            'ctx': {'context_key': 'api_version'},
+        }
+    ]
+
+
+def test_context_switch_default():
+    v = SchemaValidator(
+        cs.context_switch_schema('version', {1: cs.int_schema(), 2: cs.float_schema()}, default_schema=cs.str_schema())
+    )
+    assert v.validate_python('1', context={'version': 1}) == 1
+    assert v.validate_python('1', context={'version': 2}) == 1.0
+    assert v.validate_python('1', context={'version': 3}) == '1'
+    assert v.validate_python('1') == '1'
+
+
+def test_context_switch_strict():
+    v = SchemaValidator(cs.context_switch_schema('mode', {'strict': cs.int_schema(strict=True), 'lax': cs.int_schema()}))
+    assert v.validate_python('1', context={'mode': 'lax'}) == 1
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('1', context={'mode': 'strict'})
+
+
+def test_context_switch_nested():
+    v = SchemaValidator(
+        cs.list_schema(cs.context_switch_schema('v', {'int': cs.int_schema(), 'str': cs.str_schema()}))
+    )
+    assert v.validate_python(['1', '2'], context={'v': 'int'}) == [1, 2]
+    assert v.validate_python(['1', '2'], context={'v': 'str'}) == ['1', '2']
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['1', 'x'], context={'v': 'int'})
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == (1,)
+
+
+def test_context_switch_no_choices():
+    with pytest.raises(SchemaError, match='One or more context-switch choices required'):
+        SchemaValidator(cs.context_switch_schema('v', {}))
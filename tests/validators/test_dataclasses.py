@@ -455,6 +455,93 @@ def test_dataclass_post_init_args_multiple():
     assert dc_args == (True, 42)
 
 
+@pytest.mark.parametrize('slots', [False, True])
+def test_validate_after_post_init(slots):
+    @dataclasses.dataclass(slots=slots) if sys.version_info >= (3, 10) else dataclasses.dataclass
+    class Foo:
+        a: int
+        b: int = dataclasses.field(init=False)
+        scale: dataclasses.InitVar[int] = 1
+
+        def __post_init__(self, scale: int):
+            self.b = str(self.a * scale)
+
+    schema = core_schema.dataclass_schema(
+        Foo,
+        core_schema.dataclass_args_schema(
+            'Foo',
+            [
+                core_schema.dataclass_field(name='a', schema=core_schema.int_schema()),
+                core_schema.dataclass_field(name='b', schema=core_schema.int_schema(le=100), init=False),
+                core_schema.dataclass_field(
+                    name='scale',
+                    schema=core_schema.with_default_schema(core_schema.int_schema(), default=1),
+                    init_only=True,
+                ),
+            ],
+            collect_init_only=True,
+        ),
+        ['a', 'b'],
+        post_init=True,
+        validate_after_post_init=True,
+        slots=slots and sys.version_info >= (3, 10),
+    )
+
+    v = SchemaValidator(schema)
+    foo = v.validate_python({'a': 3, 'scale': 10})
+    assert foo.a == 3
+    assert foo.b == 30
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 30, 'scale': 10})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'less_than_equal',
+            'loc': ('b',),
+            'msg': 'Input should be less than or equal to 100',
+            'input': '300',
+            'ctx': {'le': 100},
+        }
+    ]
+
+    schema['validate_after_post_init'] = False
+    v = SchemaValidator(schema)
+    assert v.validate_python({'a': 30, 'scale': 10}).b == '300'
+
+
+def test_validate_after_post_init_frozen():
+    @dataclasses.dataclass(frozen=True)
+    class Foo:
+        a: str
+        b: str = ''
+
+        def __post_init__(self):
+            object.__setattr__(self, 'b', self.a.upper())
+
+    schema = core_schema.dataclass_schema(
+        Foo,
+        core_schema.dataclass_args_schema(
+            'Foo',
+            [
+                core_schema.dataclass_field(name='a', schema=core_schema.str_schema()),
+                core_schema.dataclass_field(
+                    name='b',
+                    schema=core_schema.with_default_schema(core_schema.str_schema(max_length=3), default=''),
+                ),
+            ],
+        ),
+        ['a', 'b'],
+        post_init=True,
+        validate_after_post_init=True,
+        frozen=True,
+    )
+
+    v = SchemaValidator(schema)
+    assert v.validate_python({'a': 'foo'}) == Foo(a='foo', b='FOO')
+    with pytest.raises(ValidationError, match=r'b\n +String should have at most 3 characters'):
+        v.validate_python({'a': 'spam'})
+
+
 @pytest.mark.parametrize(
     'revalidate_instances,input_value,expected',
     [
@@ -1263,6 +1350,41 @@ def test_slots() -> None:
         val.validate_assignment(m, 'x', 'abc')
 
 
+@pytest.mark.skipif(sys.version_info < (3, 10), reason='slots are only supported for dataclasses in Python >= 3.10')
+def test_slots_frozen_self_instance_subclass() -> None:
+    @dataclasses.dataclass(slots=True, frozen=True)
+    class Model:
+        x: int
+        y: str = 'y'
+
+    @dataclasses.dataclass(slots=True, frozen=True)
+    class SubModel(Model):
+        z: int = 0
+
+    schema = core_schema.dataclass_schema(
+        Model,
+        core_schema.dataclass_args_schema(
+            'Model',
+            [
+                core_schema.dataclass_field(name='x', schema=core_schema.int_schema()),
+                core_schema.dataclass_field(
+                    name='y', schema=core_schema.with_default_schema(core_schema.str_schema(), default='y')
+                ),
+            ],
+        ),
+        ['x', 'y'],
+        slots=True,
+        frozen=True,
+    )
+    val = SchemaValidator(schema)
+
+    assert val.validate_python({'x': '123'}) == Model(x=123)
+
+    sub = SubModel.__new__(SubModel)
+    val.validate_python({'x': 1, 'y': 'a'}, self_instance=sub)
+    assert (sub.x, sub.y) == (1, 'a')
+
+
 @pytest.mark.skipif(sys.version_info < (3, 10), reason='slots are only supported for dataclasses in Python >= 3.10')
 def test_dataclass_slots_field_before_validator():
     @dataclasses.dataclass(slots=True)
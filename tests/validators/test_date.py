@@ -313,3 +313,21 @@ def test_date_past_future_today():
 def test_offset_too_large():
     with pytest.raises(SchemaError, match=r'Input should be less than 86400 \[type=less_than,'):
         validate_core_schema(core_schema.date_schema(now_op='past', now_utc_offset=24 * 3600))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('25/12/2024', date(2024, 12, 25)),
+        ('Dec 25 24', date(2024, 12, 25)),
+        ('2024-12-25', date(2024, 12, 25)),
+        ('31/02/2024', Err('Input should be a valid date or datetime')),
+    ],
+)
+def test_date_formats(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.date_schema(formats=['%d/%m/%Y', '%b %d %y']))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
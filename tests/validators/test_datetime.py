@@ -5,6 +5,7 @@ import re
 import zoneinfo
 from datetime import date, datetime, time, timedelta, timezone, tzinfo
 from decimal import Decimal
+from zoneinfo import ZoneInfo
 
 import pytest
 
@@ -538,3 +539,203 @@ def test_tz_cmp() -> None:
 
     assert validated1 > validated2
     assert validated2 < validated1
+
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (datetime(2024, 1, 1, tzinfo=timezone.utc), None),
+        (datetime(2024, 1, 1, tzinfo=zoneinfo.ZoneInfo('Europe/London')), None),
+        ('2024-01-01T00:00:00Z', None),
+        ('2024-01-01T00:00:00+00:00', None),
+        ('2024-01-01T00:00:00-04:00', None),
+        ('2024-01-01T00:00:00-03:00', None),
+        ('2024-01-01T00:00:00+09:30', None),
+        # winter in Lisbon has a zero offset, but the zone name isn't allowed
+        (datetime(2024, 1, 1, tzinfo=zoneinfo.ZoneInfo('Europe/Lisbon')), "got 'Europe/Lisbon'"),
+        ('2024-01-01T00:00:00+01:00', 'got 3600'),
+        ('2024-01-01T00:00:00-05:00', 'got -18000'),
+    ],
+)
+def test_tz_allowed(input_value, expected):
+    v = SchemaValidator(cs.datetime_schema(tz_allowed=['UTC', 'Europe/London', (-4 * 3600, -3 * 3600), 34200]))
+    if expected is None:
+        assert v.validate_python(input_value) == datetime.fromisoformat(str(input_value).replace('Z', '+00:00'))
+    else:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        allowed = "'UTC', 'Europe/London', an offset between -14400 and -10800 or an offset of 34200"
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'timezone_not_allowed',
+                'loc': (),
+                'msg': f'Timezone should be {allowed}, {expected}',
+                'input': input_value,
+                'ctx': {'tz_allowed': allowed, 'tz_actual': expected[4:]},
+            }
+        ]
+
+
+def test_tz_allowed_naive():
+    v = SchemaValidator(cs.datetime_schema(tz_allowed=['UTC']))
+    with pytest.raises(ValidationError, match='Input should have timezone info'):
+        v.validate_python(datetime(2024, 1, 1))
+
+
+@pytest.mark.parametrize(
+    'tz_allowed,error',
+    [
+        ([], '`tz_allowed` should not be empty'),
+        ([(3600, 0)], 'Invalid `tz_allowed` offset range (3600, 0), min is greater than max'),
+        ([1.5], 'Invalid `tz_allowed` item 1.5, should be a zone name, an offset or a range of offsets'),
+    ],
+)
+def test_tz_allowed_invalid(tz_allowed, error):
+    with pytest.raises(SchemaError, match=re.escape(error)):
+        SchemaValidator(cs.datetime_schema(tz_allowed=tz_allowed))
+
+
+
+@pytest.mark.parametrize(
+    'epoch_unit,input_value,expected',
+    [
+        ('seconds', 1_700_000_000, datetime(2023, 11, 14, 22, 13, 20, tzinfo=timezone.utc)),
+        ('seconds', 1_700_000_000.5, datetime(2023, 11, 14, 22, 13, 20, 500_000, tzinfo=timezone.utc)),
+        ('seconds', '1700000000', datetime(2023, 11, 14, 22, 13, 20, tzinfo=timezone.utc)),
+        ('milliseconds', 1_700_000_000_123, datetime(2023, 11, 14, 22, 13, 20, 123_000, tzinfo=timezone.utc)),
+        ('milliseconds', 1_500, datetime(1970, 1, 1, 0, 0, 1, 500_000, tzinfo=timezone.utc)),
+        ('milliseconds', -1_500, datetime(1969, 12, 31, 23, 59, 58, 500_000, tzinfo=timezone.utc)),
+        ('milliseconds', 1_500.25, datetime(1970, 1, 1, 0, 0, 1, 500_250, tzinfo=timezone.utc)),
+        ('milliseconds', '1500', datetime(1970, 1, 1, 0, 0, 1, 500_000, tzinfo=timezone.utc)),
+        ('microseconds', 1_700_000_000_123_456, datetime(2023, 11, 14, 22, 13, 20, 123_456, tzinfo=timezone.utc)),
+        ('infer', 1_500, datetime(1970, 1, 1, 0, 25, tzinfo=timezone.utc)),
+        ('milliseconds', '2024-01-01T00:00:00', datetime(2024, 1, 1)),
+        (
+            'seconds',
+            1_700_000_000_123,
+            Err("Input should be a valid datetime, timestamp is out of range for epoch_unit 'seconds'"),
+        ),
+        (
+            'milliseconds',
+            1_700_000_000_123_456,
+            Err("Input should be a valid datetime, timestamp is out of range for epoch_unit 'milliseconds'"),
+        ),
+        ('milliseconds', 2**70, Err("timestamp is out of range for epoch_unit 'milliseconds'")),
+    ],
+)
+def test_epoch_unit(py_and_json: PyAndJson, epoch_unit, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(epoch_unit=epoch_unit))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_epoch_unit_strict():
+    v = SchemaValidator(core_schema.datetime_schema(epoch_unit='milliseconds'))
+    with pytest.raises(ValidationError, match='Input should be a valid datetime'):
+        v.validate_python(1_500, strict=True)
+    with pytest.raises(ValidationError, match='Input should be a valid datetime'):
+        v.validate_python(True)
+
+
+def test_epoch_unit_invalid():
+    with pytest.raises(SchemaError, match='Invalid epoch_unit "minutes"'):
+        SchemaValidator({'type': 'datetime', 'epoch_unit': 'minutes'})
+
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('2024-01-02T03:04:05+01:00[Europe/Paris]', datetime(2024, 1, 2, 3, 4, 5, tzinfo=ZoneInfo('Europe/Paris'))),
+        # the instant is kept, converted to the zone
+        ('2024-07-02T03:04:05Z[Europe/Paris]', datetime(2024, 7, 2, 5, 4, 5, tzinfo=ZoneInfo('Europe/Paris'))),
+        # a naive datetime is taken to be local time in the zone
+        ('2024-07-02T03:04:05[America/New_York]', datetime(2024, 7, 2, 3, 4, 5, tzinfo=ZoneInfo('America/New_York'))),
+        ('2024-01-02T03:04:05+01:00', datetime(2024, 1, 2, 3, 4, 5, tzinfo=timezone(timedelta(hours=1)))),
+        (
+            '2024-01-02T03:04:05[Mars/Olympus]',
+            Err('Input should be a valid datetime, unknown time zone key "Mars/Olympus"'),
+        ),
+        ('2024-13-02T03:04:05[Europe/Paris]', Err('Input should be a valid datetime, month value is outside')),
+    ],
+)
+def test_preserve_zone_key(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(preserve_zone_key=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert output.tzinfo == expected.tzinfo
+
+
+def test_preserve_zone_key_strict():
+    v = SchemaValidator(core_schema.datetime_schema(preserve_zone_key=True, strict=True))
+    assert v.validate_json('"2024-01-02T03:04:05+01:00[Europe/Paris]"').tzinfo == ZoneInfo('Europe/Paris')
+    with pytest.raises(ValidationError, match='Input should be a valid datetime'):
+        v.validate_python('2024-01-02T03:04:05+01:00[Europe/Paris]')
+
+
+def test_zone_key_not_preserved_by_default():
+    v = SchemaValidator(core_schema.datetime_schema())
+    with pytest.raises(ValidationError, match='Input should be a valid datetime or date, unexpected extra characters'):
+        v.validate_python('2024-01-02T03:04:05+01:00[Europe/Paris]')
+
+
+@pytest.mark.parametrize(
+    'formats,input_value',
+    [
+        (['%d/%m/%Y %H:%M'], '25/12/2024 18:30'),
+        (['%d/%m/%Y %H:%M'], '5/1/2024 7:05'),
+        (['%Y%m%d%H%M%S'], '20240102030405'),
+        (['%d %b %Y %I:%M %p'], '03 mar 2024 12:15 AM'),
+        (['%d %B %Y %I:%M %p'], '03 March 2024 01:15 pm'),
+        (['%a, %d %b %y %H:%M:%S %z'], 'Sun, 03 Mar 24 10:00:00 +0130'),
+        (['%Y-%m-%d %H:%M:%S.%f%z'], '2024-03-03 10:00:00.25-05:00'),
+        (['%Y-%j'], '2024-366'),
+        # the first matching format is used
+        (['%m/%d/%Y', '%d/%m/%Y'], '12/11/2024'),
+        (['%m/%d/%Y', '%d/%m/%Y'], '25/11/2024'),
+    ],
+)
+def test_datetime_formats(py_and_json: PyAndJson, formats, input_value):
+    v = py_and_json(core_schema.datetime_schema(formats=formats))
+    expected = next(_strptime_or_none(input_value, f) for f in formats if _strptime_or_none(input_value, f))
+    assert v.validate_test(input_value) == expected
+
+
+def _strptime_or_none(input_value, format):
+    try:
+        return datetime.strptime(input_value, format)
+    except ValueError:
+        return None
+
+
+def test_datetime_formats_fallback():
+    v = SchemaValidator(core_schema.datetime_schema(formats=['%d/%m/%Y %H:%M']))
+    # inputs which don't match a format are parsed as usual
+    assert v.validate_python('2024-12-25T18:30:00') == datetime(2024, 12, 25, 18, 30)
+    assert v.validate_python(datetime(2024, 12, 25)) == datetime(2024, 12, 25)
+    # an invalid date for a format which otherwise matches isn't accepted
+    with pytest.raises(ValidationError, match='Input should be a valid datetime or date'):
+        v.validate_python('31/02/2024 18:30')
+    # formats are only used in lax mode
+    with pytest.raises(ValidationError, match='Input should be a valid datetime'):
+        v.validate_python('25/12/2024 18:30', strict=True)
+
+
+@pytest.mark.parametrize(
+    'formats,error',
+    [
+        (['%Y-%m-%d %Q'], 'Invalid format "%Y-%m-%d %Q", unsupported directive \'%Q\''),
+        (['%Y-%m-%d %'], 'Invalid format "%Y-%m-%d %", stray \'%\' at the end'),
+    ],
+)
+def test_datetime_formats_invalid(formats, error):
+    with pytest.raises(SchemaError, match=re.escape(error)):
+        SchemaValidator(core_schema.datetime_schema(formats=formats))
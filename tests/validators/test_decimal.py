@@ -288,6 +288,60 @@ def test_union_decimal_simple(py_and_json: PyAndJson):
     ]
 
 
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'decimal_places': 2, 'quantize': True}, '1.005', '1.00'),
+        ({'decimal_places': 2, 'quantize': True}, '1.015', '1.02'),
+        ({'decimal_places': 2, 'quantize': True}, '1.5', '1.50'),
+        ({'decimal_places': 2, 'quantize': True}, 7, '7.00'),
+        ({'decimal_places': 2, 'quantize': True, 'rounding': 'ROUND_HALF_UP'}, '1.005', '1.01'),
+        ({'decimal_places': 2, 'quantize': True, 'rounding': 'ROUND_DOWN'}, '-1.999', '-1.99'),
+        ({'decimal_places': 0, 'quantize': True, 'rounding': 'ROUND_CEILING'}, '1.1', '2'),
+        ({'decimal_places': 2, 'quantize': True, 'max_digits': 4}, '12.345', '12.34'),
+        (
+            {'decimal_places': 2, 'quantize': True, 'max_digits': 4},
+            '123.4',
+            Err('no more than 2 digits before the decimal point'),
+        ),
+        ({'decimal_places': 2, 'quantize': True}, '1' * 40 + '.999', '1' * 39 + '2.00'),
+        ({'normalize': True}, '1.500', '1.5'),
+        ({'normalize': True}, '100.00', '100'),
+        ({'normalize': True}, '1e3', '1000'),
+        ({'normalize': True}, '0.000', '0'),
+        ({'decimal_places': 3, 'quantize': True, 'normalize': True}, '2.0004', '2'),
+    ],
+    ids=repr,
+)
+def test_decimal_quantize_normalize(py_and_json: PyAndJson, kwargs: dict[str, Any], input_value, expected):
+    v = py_and_json(cs.decimal_schema(**kwargs))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert isinstance(output, Decimal)
+        assert str(output) == expected
+
+
+def test_decimal_normalize_non_finite():
+    v = SchemaValidator(cs.decimal_schema(allow_inf_nan=True, normalize=True))
+    assert v.validate_python('inf') == Decimal('inf')
+    assert v.validate_python('nan').is_nan()
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        ({'quantize': True}, "'quantize' requires 'decimal_places' to be set"),
+        ({'rounding': 'ROUND_SIDEWAYS'}, 'Invalid rounding mode: ROUND_SIDEWAYS'),
+    ],
+)
+def test_decimal_quantize_invalid(kwargs: dict[str, Any], message: str):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(cs.decimal_schema(**kwargs))
+
+
 def test_decimal_repr():
     v = SchemaValidator(cs.decimal_schema())
     assert plain_repr(v).startswith(
@@ -521,3 +575,9 @@ def test_union_with_str_prefers_str() -> None:
 
     assert s.validate_python('1.23') == '1.23'
     assert s.validate_python(1.23) == Decimal('1.23')
+
+
+def test_coerce_decimal_hook():
+    v = SchemaValidator(cs.decimal_schema(), config=cs.CoreConfig(coerce_decimal=lambda x: x.strip('€ ')))
+    assert v.validate_python('€ 12.50') == Decimal('12.50')
+    assert v.validate_json('"12.50 €"') == Decimal('12.50')
@@ -255,3 +255,61 @@ def test_json_dict_complex_key():
     assert v.validate_json('{"1+2j": 2, "infj": 4}') == {complex(1, 2): 2, complex(0, float('inf')): 4}
     with pytest.raises(ValidationError, match='Input should be a valid complex string'):
         v.validate_json('{"1+2j": 2, "": 4}') == {complex(1, 2): 2, complex(0, float('inf')): 4}
+
+
+def test_key_pattern(py_and_json: PyAndJson):
+    v = py_and_json(cs.dict_schema(cs.str_schema(to_lower=True), cs.int_schema(), key_pattern=r'^[a-z]+$'))
+    assert v.validate_test({'aB': 1}) == {'ab': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'a': 1, 'b_2': 'x'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_pattern_mismatch',
+            'loc': ('b_2', '[key]'),
+            'msg': "String should match pattern '^[a-z]+$'",
+            'input': 'b_2',
+            'ctx': {'pattern': '^[a-z]+$'},
+        },
+        {
+            'type': 'int_parsing',
+            'loc': ('b_2',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+    ]
+
+
+def test_key_pattern_not_str():
+    v = SchemaValidator(cs.dict_schema(key_pattern='a'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({1: 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': (1, '[key]'), 'msg': 'Input should be a valid string', 'input': 1}
+    ]
+
+
+def test_required_and_forbidden_keys(py_and_json: PyAndJson):
+    v = py_and_json(
+        cs.dict_schema(cs.str_schema(), cs.int_schema(), required_keys=['a', 'b'], forbidden_keys=['secret'])
+    )
+    assert v.validate_test({'a': 1, 'b': 2, 'c': 3}) == {'a': 1, 'b': 2, 'c': 3}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'a': 'x', 'secret': 1})
+    # `a` is present, so only its value is invalid
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {'type': 'dict_key_forbidden', 'loc': ('secret', '[key]'), 'msg': 'Key is not permitted', 'input': 'secret'},
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'a': 'x', 'secret': 1}},
+    ]
+
+
+def test_required_keys_validated():
+    v = SchemaValidator(cs.dict_schema(cs.int_schema(), required_keys=[1]))
+    assert v.validate_python({'1': 'a'}) == {1: 'a'}
+    with pytest.raises(ValidationError, match=r'1\n  Field required \[type=missing,'):
+        v.validate_python({2: 'a'})
@@ -0,0 +1,154 @@
+import pickle
+import re
+from copy import deepcopy
+
+import pytest
+
+from pydantic_core import EmailAddress, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+def test_email_ok(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.email_schema())
+    email = v.validate_test('John.Doe+tag@Example.COM')
+
+    assert isinstance(email, EmailAddress)
+    assert str(email) == 'John.Doe+tag@example.com'
+    assert repr(email) == "EmailAddress('John.Doe+tag@example.com')"
+    assert email.local_part == 'John.Doe+tag'
+    assert email.domain == 'example.com'
+    assert email.ascii_domain == 'example.com'
+    assert email.ascii_email() == 'John.Doe+tag@example.com'
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('user@example.com', 'user@example.com'),
+        ("!#$%&'*+-/=?^_`{|}~@example.com", "!#$%&'*+-/=?^_`{|}~@example.com"),
+        ('first.last@sub.example.co.uk', 'first.last@sub.example.co.uk'),
+        ('user@xn--mnchen-3ya.de', 'user@münchen.de'),
+        ('user@MÜNCHEN.de', 'user@münchen.de'),
+        ('用户@例子.广告', '用户@例子.广告'),
+        ('a' * 64 + '@example.com', 'a' * 64 + '@example.com'),
+        ('a@b@example.com', Err('the part before the @-sign contains an invalid character')),
+        ('', Err('Input should be a valid email address, an email address must have an @-sign')),
+        ('user.example.com', Err('an email address must have an @-sign')),
+        ('@example.com', Err('there must be something before the @-sign')),
+        ('user@', Err('there must be something after the @-sign')),
+        ('.user@example.com', Err('the part before the @-sign cannot start with a period')),
+        ('user.@example.com', Err('the part before the @-sign cannot end with a period')),
+        ('us..er@example.com', Err('the part before the @-sign cannot contain two periods in a row')),
+        ('us er@example.com', Err("the part before the @-sign contains an invalid character: ' '")),
+        ('a' * 65 + '@example.com', Err('the part before the @-sign is too long, it should have at most 64 bytes')),
+        ('user@localhost', Err('the part after the @-sign should contain a period')),
+        ('user@[127.0.0.1]', Err('domain literals are not supported')),
+        ('user@127.0.0.1', Err('the part after the @-sign cannot have a numeric top level domain')),
+        ('user@.example.com', Err('the part after the @-sign cannot start with a period')),
+        ('user@example.com.', Err('the part after the @-sign cannot end with a period')),
+        ('user@example..com', Err('the part after the @-sign cannot contain two periods in a row')),
+        ('user@-example.com', Err('the part after the @-sign cannot have a label starting or ending with a hyphen')),
+        ('user@exa_mple.com', Err("the part after the @-sign contains an invalid character: '_'")),
+        ('user@' + 'a' * 64 + '.com', Err('the part after the @-sign has a label longer than 63 bytes')),
+        ('user@' + 'a.' * 127 + 'com', Err('the part after the @-sign is too long, it should have at most 253 bytes')),
+        (123, Err('Email address input should be a string or EmailAddress [type=email_type,')),
+    ],
+)
+def test_email_cases(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.email_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert str(v.validate_test(input_value)) == expected
+
+
+def test_email_error_details():
+    v = SchemaValidator(core_schema.email_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('user@localhost')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'email_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid email address, the part after the @-sign should contain a period',
+            'input': 'user@localhost',
+            'ctx': {'error': 'the part after the @-sign should contain a period'},
+        }
+    ]
+
+
+def test_internationalized():
+    email = SchemaValidator(core_schema.email_schema()).validate_python('josé@bücher.example')
+    assert email.local_part == 'josé'
+    assert email.domain == 'bücher.example'
+    assert email.ascii_domain == 'xn--bcher-kva.example'
+    assert email.ascii_email() is None
+
+    email = SchemaValidator(core_schema.email_schema()).validate_python('jose@bücher.example')
+    assert email.ascii_email() == 'jose@xn--bcher-kva.example'
+
+
+def test_allow_smtputf8_false():
+    v = SchemaValidator(core_schema.email_schema(allow_smtputf8=False))
+    assert str(v.validate_python('user@bücher.example')) == 'user@bücher.example'
+    with pytest.raises(ValidationError, match='internationalized characters before the @-sign are not supported'):
+        v.validate_python('josé@example.com')
+    with pytest.raises(ValidationError, match='internationalized characters before the @-sign are not supported'):
+        v.validate_python(EmailAddress('josé@example.com'))
+
+
+def test_email_instance():
+    v = SchemaValidator(core_schema.email_schema())
+    email = EmailAddress('user@example.com')
+    assert v.validate_python(email) is email
+
+
+def test_strict():
+    v = SchemaValidator(core_schema.email_schema(strict=True))
+    email = EmailAddress('user@example.com')
+    assert v.validate_python(email) is email
+    assert str(v.validate_python('user@example.com')) == 'user@example.com'
+    with pytest.raises(ValidationError, match=r'Email address input should be a string or EmailAddress \[type=email_type'):
+        v.validate_python(b'user@example.com')
+    assert str(SchemaValidator(core_schema.email_schema()).validate_python(b'user@example.com')) == 'user@example.com'
+
+
+def test_constructor():
+    assert str(EmailAddress('User@Example.com')) == 'User@example.com'
+    with pytest.raises(ValidationError, match='an email address must have an @-sign'):
+        EmailAddress('nope')
+
+
+def test_comparison_and_hash():
+    a = EmailAddress('user@Example.com')
+    b = EmailAddress('user@example.com')
+    c = EmailAddress('other@example.com')
+    assert a == b
+    assert hash(a) == hash(b)
+    assert a != c
+    assert c < a
+    assert len({a, b, c}) == 2
+
+
+def test_copy_and_pickle():
+    email = EmailAddress('josé@bücher.example')
+    assert deepcopy(email) == email
+    assert pickle.loads(pickle.dumps(email)) == email
+
+
+def test_subclass():
+    class MyEmail(EmailAddress):
+        pass
+
+    email = MyEmail('user@example.com')
+    assert isinstance(email, EmailAddress)
+    assert str(email) == 'user@example.com'
+
+
+def test_in_union():
+    v = SchemaValidator(core_schema.union_schema([core_schema.str_schema(), core_schema.email_schema()]))
+    assert v.validate_python('user@example.com') == 'user@example.com'
+    email = EmailAddress('user@example.com')
+    assert v.validate_python(email) is email
@@ -504,3 +504,125 @@ def test_strict_enum_wrap_json() -> None:
 
     assert v.validate_python(Animal.CAT) == Animal.CAT
     assert v.validate_json('"dog"') == Animal.DOG
+
+
+@pytest.mark.parametrize('start,gap', [(0, 0), (-5, 0), (10, 0), (0, 3), (2**63 - 4, 0)])
+def test_int_enum_lookup(start, gap):
+    # contiguous values use a table, values with a gap a map
+    values = [start, start + 1, start + 2 + gap, start + 3 + gap]
+    MyEnum = IntEnum('MyEnum', [(f'm{i}', value) for i, value in enumerate(values)])
+    v = SchemaValidator(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), sub_type='int'))
+
+    for member in MyEnum:
+        assert v.validate_python(member.value) is member
+        assert v.validate_json(str(member.value)) is member
+        assert v.validate_python(str(member.value)) is member
+
+    missing = [start - 1, start + 4 + gap] + ([start + 2] if gap else [])
+    for value in missing:
+        with pytest.raises(ValidationError):
+            v.validate_python(value)
+
+
+class Color(Enum):
+    RED = 'r'
+    GREEN = 'g'
+
+
+def test_enum_by_name():
+    v = SchemaValidator(core_schema.enum_schema(Color, list(Color.__members__.values()), by='name'))
+    assert v.validate_python('RED') is Color.RED
+    assert v.validate_json('"GREEN"') is Color.GREEN
+    assert v.validate_python(Color.RED) is Color.RED
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('r')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'enum',
+            'loc': (),
+            'msg': "Input should be 'RED' or 'GREEN'",
+            'input': 'r',
+            'ctx': {'expected': "'RED' or 'GREEN'"},
+        }
+    ]
+
+
+def test_enum_by_either():
+    v = SchemaValidator(core_schema.enum_schema(Color, list(Color.__members__.values()), by='either'))
+    assert v.validate_python('r') is Color.RED
+    assert v.validate_python('RED') is Color.RED
+    assert v.validate_json('"GREEN"') is Color.GREEN
+
+    with pytest.raises(ValidationError, match=r"Input should be 'r', 'g', 'RED' or 'GREEN'"):
+        v.validate_python('red')
+
+
+def test_enum_by_name_int_enum():
+    class Level(IntEnum):
+        LOW = 1
+        HIGH = 2
+
+    v = SchemaValidator(core_schema.enum_schema(Level, list(Level.__members__.values()), sub_type='int', by='either'))
+    assert v.validate_python(2) is Level.HIGH
+    assert v.validate_python('LOW') is Level.LOW
+    assert v.validate_json('"HIGH"') is Level.HIGH
+
+
+def test_enum_by_name_skips_missing():
+    class MyEnum(Enum):
+        a = 1
+
+        @classmethod
+        def _missing_(cls, value):
+            return cls.a
+
+    v = SchemaValidator(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), missing=MyEnum._missing_))
+    assert v.validate_python('x') is MyEnum.a
+
+    v = SchemaValidator(
+        core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), missing=MyEnum._missing_, by='name')
+    )
+    assert v.validate_python('a') is MyEnum.a
+    with pytest.raises(ValidationError, match=r"Input should be 'a'"):
+        v.validate_python('x')
+
+
+def test_enum_aliases():
+    v = SchemaValidator(
+        core_schema.enum_schema(Color, list(Color.__members__.values()), aliases={'crimson': Color.RED, 3: Color.GREEN})
+    )
+    assert v.validate_python('r') is Color.RED
+    assert v.validate_python('crimson') is Color.RED
+    assert v.validate_json('"crimson"') is Color.RED
+    assert v.validate_python(3) is Color.GREEN
+    assert v.validate_json('3') is Color.GREEN
+
+    # aliases aren't included in the error message
+    with pytest.raises(ValidationError, match=r"Input should be 'r' or 'g'"):
+        v.validate_python('scarlet')
+
+
+def test_enum_aliases_by_name():
+    v = SchemaValidator(
+        core_schema.enum_schema(Color, list(Color.__members__.values()), by='name', aliases={'CRIMSON': Color.RED})
+    )
+    assert v.validate_python('CRIMSON') is Color.RED
+    assert v.validate_python('RED') is Color.RED
+
+
+def test_enum_aliases_strict():
+    v = SchemaValidator(
+        core_schema.enum_schema(Color, list(Color.__members__.values()), aliases={'crimson': Color.RED}, strict=True)
+    )
+    assert v.validate_json('"crimson"') is Color.RED
+    with pytest.raises(ValidationError, match='Input should be an instance of Color'):
+        v.validate_python('crimson')
+
+
+def test_enum_invalid_by_and_aliases():
+    with pytest.raises(SchemaError, match="`by` must be one of: 'value', 'name' or 'either'"):
+        SchemaValidator(core_schema.enum_schema(Color, list(Color.__members__.values()), by='label'))
+
+    with pytest.raises(SchemaError, match=r"`aliases` values must be members of the enum, got 'r'"):
+        SchemaValidator(core_schema.enum_schema(Color, list(Color.__members__.values()), aliases={'crimson': 'r'}))
@@ -7,11 +7,16 @@ from typing import Any
 import pytest
 from dirty_equals import FunctionCheck, IsFloatNan, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 from pydantic_core import core_schema as cs
 
 from ..conftest import Err, PyAndJson, plain_repr
 
+try:
+    import numpy
+except ImportError:
+    numpy = None
+
 i64_max = (2**63) - 1
 f64_max = sys.float_info.max
 
@@ -162,6 +167,56 @@ def test_float_multiple_of(py_and_json: PyAndJson, multiple_of, input_value, err
         assert isinstance(output, float)
 
 
+@pytest.mark.parametrize(
+    'kwargs,input_value,error',
+    [
+        ({'multiple_of': 0.1}, 0.3, None),
+        ({'multiple_of': 0.1, 'multiple_of_tolerance': 0}, 0.3, Err('Input should be a multiple of 0.1')),
+        ({'multiple_of': 0.1, 'multiple_of_tolerance': 0}, 0.5, None),
+        ({'multiple_of': 0.5, 'multiple_of_tolerance': 0.01}, 1.005, None),
+        ({'multiple_of': 0.5, 'multiple_of_tolerance': 0.01}, 1.02, Err('Input should be a multiple of 0.5')),
+        # large values lose precision, so an absolute tolerance is too strict
+        ({'multiple_of': 0.1}, 12345678901234.7, Err('Input should be a multiple of 0.1')),
+        (
+            {'multiple_of': 0.1, 'multiple_of_tolerance': 1e-15, 'multiple_of_tolerance_mode': 'relative'},
+            12345678901234.7,
+            None,
+        ),
+        (
+            {'multiple_of': 0.1, 'multiple_of_tolerance': 1e-15, 'multiple_of_tolerance_mode': 'relative'},
+            12345678901234.75,
+            Err('Input should be a multiple of 0.1'),
+        ),
+        (
+            {'multiple_of': 0.1, 'multiple_of_tolerance': 1e-15, 'multiple_of_tolerance_mode': 'relative'},
+            0.3,
+            None,
+        ),
+    ],
+    ids=repr,
+)
+def test_float_multiple_of_tolerance(py_and_json: PyAndJson, kwargs: dict[str, Any], input_value, error):
+    v = py_and_json(cs.float_schema(**kwargs))
+    if error:
+        with pytest.raises(ValidationError, match=re.escape(error.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == input_value
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        ({'multiple_of_tolerance': -1}, "'multiple_of_tolerance' must be a finite, non-negative number"),
+        ({'multiple_of_tolerance': float('nan')}, "'multiple_of_tolerance' must be a finite, non-negative number"),
+        ({'multiple_of_tolerance_mode': 'fuzzy'}, 'Invalid multiple_of_tolerance_mode: fuzzy'),
+    ],
+)
+def test_float_multiple_of_tolerance_invalid(kwargs: dict[str, Any], message: str):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(cs.float_schema(multiple_of=0.1, **kwargs))
+
+
 def test_union_float(py_and_json: PyAndJson):
     v = py_and_json(
         {'type': 'union', 'choices': [{'type': 'float', 'strict': True}, {'type': 'float', 'multiple_of': 7}]}
@@ -208,12 +263,12 @@ def test_float_repr():
     v = SchemaValidator(cs.float_schema())
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true,coerce_hook:None}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator(cs.float_schema(strict=True))
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true,coerce_hook:None}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator(cs.float_schema(multiple_of=7))
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-float",validator=ConstrainedFloat(')
@@ -437,3 +492,21 @@ def test_allow_inf_nan_false_json() -> None:
     assert exc_info3.value.errors(include_url=False) == [
         {'type': 'finite_number', 'loc': (), 'msg': 'Input should be a finite number', 'input': float('-inf')}
     ]
+
+
+@pytest.mark.skipif(numpy is None, reason='numpy is not installed')
+def test_numpy_float():
+    v = SchemaValidator(cs.float_schema())
+    assert v.validate_python(numpy.float32(1.5)) == 1.5
+    assert v.validate_python(numpy.float64(1.5), strict=True) == 1.5
+    assert v.validate_python(numpy.int64(2)) == 2.0
+
+
+def test_coerce_float_hook():
+    v = SchemaValidator(cs.float_schema(), config=cs.CoreConfig(coerce_float=lambda x: x.replace(',', '')))
+    assert v.validate_python('1,000.5') == 1000.5
+    value, report = v.validate_python('1,000.5', coercions=True)
+    assert value == 1000.5
+    assert report.coercions == [{'loc': (), 'input': '1,000.5', 'input_type': 'str', 'target': 'float'}]
+    with pytest.raises(ValidationError, match=r'Input should be a valid number \[type=float_type,'):
+        v.validate_python('1,000.5', strict=True)
@@ -0,0 +1,128 @@
+import re
+from decimal import Decimal
+from fractions import Fraction
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (0, Fraction(0)),
+        (3, Fraction(3)),
+        ('3/4', Fraction(3, 4)),
+        (' -3/4 ', Fraction(-3, 4)),
+        ('0.75', Fraction(3, 4)),
+        ('1e3', Fraction(1000)),
+        (0.5, Fraction(1, 2)),
+        ('3/0', Err('Input should be a valid fraction [type=fraction_parsing,')),
+        ('foobar', Err('Input should be a valid fraction [type=fraction_parsing,')),
+        ('3/4.5', Err('Input should be a valid fraction [type=fraction_parsing,')),
+        ([1], Err('Fraction input should be an integer, float, string or Fraction object [type=fraction_type,')),
+    ],
+)
+def test_fraction(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.fraction_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert isinstance(output, Fraction)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Decimal('0.125'), Fraction(1, 8)),
+        (2**70, Fraction(2**70)),
+        (True, Err('Fraction input should be an integer, float, string or Fraction object [type=fraction_type,')),
+        (float('nan'), Err('Input should be a valid fraction [type=fraction_parsing,')),
+        (float('inf'), Err('Input should be a valid fraction [type=fraction_parsing,')),
+        (b'3/4', Err('Fraction input should be an integer, float, string or Fraction object [type=fraction_type,')),
+    ],
+)
+def test_fraction_python(input_value, expected):
+    v = SchemaValidator(core_schema.fraction_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_fraction_instance():
+    v = SchemaValidator(core_schema.fraction_schema())
+    f = Fraction(1, 3)
+    assert v.validate_python(f) is f
+
+
+def test_fraction_strict():
+    v = SchemaValidator(core_schema.fraction_schema(strict=True))
+    f = Fraction(1, 3)
+    assert v.validate_python(f) is f
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('1/3')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'is_instance_of',
+            'loc': (),
+            'msg': 'Input should be an instance of Fraction',
+            'input': '1/3',
+            'ctx': {'class': 'Fraction'},
+        }
+    ]
+    # strings from JSON are still accepted in strict mode
+    assert v.validate_json('"1/3"') == f
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'le': Fraction(3, 4)}, '3/4', Fraction(3, 4)),
+        ({'le': Fraction(3, 4)}, '4/5', Err('Input should be less than or equal to 3/4 [type=less_than_equal,')),
+        ({'lt': Fraction(3, 4)}, '3/4', Err('Input should be less than 3/4 [type=less_than,')),
+        ({'ge': Fraction(1, 3)}, '1/3', Fraction(1, 3)),
+        ({'ge': Fraction(1, 3)}, 0.3, Err('Input should be greater than or equal to 1/3 [type=greater_than_equal,')),
+        ({'gt': 0}, '0', Err('Input should be greater than 0 [type=greater_than,')),
+        ({'gt': 0, 'lt': 1}, '1/2', Fraction(1, 2)),
+    ],
+)
+def test_fraction_constraints(py_and_json: PyAndJson, kwargs, input_value, expected):
+    v = py_and_json(core_schema.fraction_schema(**kwargs))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_fraction_constraint_ctx():
+    v = SchemaValidator(core_schema.fraction_schema(le=Fraction(1, 2)))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'less_than_equal',
+            'loc': (),
+            'msg': 'Input should be less than or equal to 1/2',
+            'input': 1,
+            'ctx': {'le': Fraction(1, 2)},
+        }
+    ]
+
+
+def test_fraction_invalid_constraint():
+    with pytest.raises(SchemaError, match="'le' must be coercible to a Fraction instance"):
+        SchemaValidator(core_schema.fraction_schema(le='foobar'))
+
+
+def test_fraction_union():
+    v = SchemaValidator(core_schema.union_schema([core_schema.str_schema(), core_schema.fraction_schema()]))
+    assert v.validate_python('1/2') == '1/2'
+    assert v.validate_python(Fraction(1, 2)) == Fraction(1, 2)
@@ -1,3 +1,4 @@
+import pickle
 import re
 
 import pytest
@@ -197,3 +198,26 @@ def test_generator_too_short():
             'ctx': {'field_type': 'Generator', 'min_length': 4, 'actual_length': 3},
         }
     ]
+
+
+def test_validator_iterator_introspection():
+    v = SchemaValidator(cs.generator_schema(cs.int_schema(), min_length=1, max_length=3))
+    gen = v.validate_python(['1', 2])
+    assert gen.min_length == 1
+    assert gen.max_length == 3
+    assert gen.item_schema == 'int'
+    assert repr(gen) == "ValidatorIterator(index=0, item_schema='int', min_length=1, max_length=3)"
+    next(gen)
+    assert repr(gen) == "ValidatorIterator(index=1, item_schema='int', min_length=1, max_length=3)"
+
+    gen = SchemaValidator(cs.generator_schema()).validate_python([])
+    assert gen.min_length is None
+    assert gen.max_length is None
+    assert gen.item_schema == 'any'
+    assert repr(gen) == "ValidatorIterator(index=0, item_schema='any', min_length=None, max_length=None)"
+
+
+def test_validator_iterator_pickle():
+    gen = SchemaValidator(cs.generator_schema(cs.int_schema())).validate_python([1, 2])
+    with pytest.raises(TypeError, match='ValidatorIterator cannot be pickled'):
+        pickle.dumps(gen)
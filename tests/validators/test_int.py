@@ -4,13 +4,18 @@ from decimal import Decimal
 from typing import Any
 
 import pytest
-from dirty_equals import IsStr
+from dirty_equals import HasRepr, IsStr
 
-from pydantic_core import SchemaError, SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, register_ob_type
 from pydantic_core import core_schema as cs
 
 from ..conftest import Err, PyAndJson, plain_repr
 
+try:
+    import numpy
+except ImportError:
+    numpy = None
+
 i64_max = (2**63) - 1
 
 
@@ -402,12 +407,12 @@ def test_int_repr():
     v = SchemaValidator(cs.int_schema())
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false,lax:LaxInt{allow_underscores:true,allow_radix_prefixes:false,coerce_hook:None}}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator(cs.int_schema(strict=True))
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true,lax:LaxInt{allow_underscores:true,allow_radix_prefixes:false,coerce_hook:None}}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator(cs.int_schema(multiple_of=7))
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-int",validator=ConstrainedInt(')
@@ -470,6 +475,44 @@ def test_long_json():
         v.validate_json('nan')
 
 
+big = int('9' * 120)
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'multiple_of': 3}, 3 * big, 3 * big),
+        ({'multiple_of': 3}, 3 * big + 1, Err('Input should be a multiple of 3')),
+        ({'multiple_of': big}, 7 * big, 7 * big),
+        ({'multiple_of': big}, big + 1, Err('Input should be a multiple of')),
+        ({'multiple_of': big}, 0, 0),
+        ({'multiple_of': big}, 5, Err('Input should be a multiple of')),
+        ({'multiple_of': -1}, -(2**63), -(2**63)),
+        ({'multiple_of': 2**64}, -(2**64), -(2**64)),
+        ({'le': big}, big, big),
+        ({'le': big}, big + 1, Err('Input should be less than or equal to')),
+        ({'lt': big}, -big, -big),
+        ({'lt': -big}, 0, Err('Input should be less than')),
+        ({'ge': -big}, i64_max, i64_max),
+        ({'gt': 2**64}, 2**64, Err('Input should be greater than')),
+        ({'gt': -(2**64)}, -(2**63), -(2**63)),
+        ({'ge': 2**63 - 1}, 2**63, 2**63),
+    ],
+)
+def test_big_int_constraints(py_and_json: PyAndJson, kwargs: dict[str, Any], input_value, expected):
+    v = py_and_json(cs.int_schema(**kwargs))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_multiple_of_zero():
+    with pytest.raises(SchemaError, match="'multiple_of' must not be zero"):
+        SchemaValidator(cs.int_schema(multiple_of=0))
+
+
 def test_int_key(py_and_json: PyAndJson):
     v = py_and_json({'type': 'dict', 'keys_schema': {'type': 'int'}, 'values_schema': {'type': 'int'}})
     assert v.validate_test({'1': 1, '2': 2}) == {1: 1, 2: 2}
@@ -576,3 +619,129 @@ def test_json_big_int_key():
     assert v.validate_python({big_integer: 'x'}) == {big_integer: 'x'}
     assert v.validate_json('{"' + str(big_integer) + '": "x"}') == {big_integer: 'x'}
     assert v.validate_strings({str(big_integer): 'x'}) == {big_integer: 'x'}
+
+
+@pytest.mark.skipif(numpy is None, reason='numpy is not installed')
+def test_numpy_int():
+    v = SchemaValidator(cs.int_schema())
+    assert v.validate_python(numpy.int64(i64_max)) == i64_max
+    assert type(v.validate_python(numpy.int32(1))) is int
+    assert v.validate_python(numpy.uint64(2**64 - 1)) == 2**64 - 1
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type,'):
+        v.validate_python(numpy.int64(1), strict=True)
+
+
+def test_registered_int_type():
+    class MyInt:
+        def __init__(self, value):
+            self.value = value
+
+        def __index__(self):
+            return self.value
+
+    v = SchemaValidator(cs.int_schema())
+    # without registration `__index__` is only used via `float()`
+    with pytest.raises(ValidationError, match=r'exceeded maximum size \[type=int_parsing_size,'):
+        v.validate_python(MyInt(2**70 + 1))
+
+    register_ob_type(MyInt, 'int')
+    assert v.validate_python(MyInt(2**70 + 1)) == 2**70 + 1
+    assert type(v.validate_python(MyInt(1))) is int
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type,'):
+        v.validate_python(MyInt(1), strict=True)
+
+
+def strip_thousands(value):
+    if isinstance(value, str):
+        return value.replace(',', '').removeprefix('$')
+    raise ValueError('not a number')
+
+
+@pytest.mark.parametrize('schema', [cs.int_schema(), cs.int_schema(le=10_000)])
+def test_coerce_int_hook(schema):
+    v = SchemaValidator(schema, config=cs.CoreConfig(coerce_int=strip_thousands))
+    assert v.validate_python('$1,000') == 1000
+    assert v.validate_json('"1,000"') == 1000
+    # the hook is only called for inputs which fail validation
+    assert v.validate_python('123') == 123
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'value_error',
+            'loc': (),
+            'msg': 'Value error, not a number',
+            'input': [],
+            'ctx': {'error': HasRepr(repr(ValueError('not a number')))},
+        }
+    ]
+
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type,'):
+        v.validate_python('1,000', strict=True)
+
+    # errors validating the value returned by the hook show that value as the input
+    with pytest.raises(ValidationError, match=r"\[type=int_parsing, input_value='1000.5',"):
+        v.validate_python('1,000.5')
+
+
+def test_coerce_int_hook_constraints():
+    v = SchemaValidator(cs.int_schema(le=100), config=cs.CoreConfig(coerce_int=strip_thousands))
+    with pytest.raises(ValidationError, match=r'Input should be less than or equal to 100 \[type=less_than_equal,'):
+        v.validate_python('1,000')
+
+
+def test_coerce_int_hook_not_callable():
+    with pytest.raises(SchemaError, match="'coerce_int' must be callable, got int"):
+        SchemaValidator(cs.int_schema(), config=cs.CoreConfig(coerce_int=1))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('0x1F', 31),
+        ('0X1f', 31),
+        (' -0x1F ', -31),
+        ('+0o17', 15),
+        ('0b1010', 10),
+        ('0x_ff_ff', 0xFFFF),
+        ('0x' + 'f' * 20, 2**80 - 1),
+        ('0x', Err('int_parsing')),
+        ('0x1__F', Err('int_parsing')),
+        ('0x1F_', Err('int_parsing')),
+        ('0b102', Err('int_parsing')),
+        ('0x-1', Err('int_parsing')),
+        ('--0x1', Err('int_parsing')),
+    ],
+)
+def test_int_radix_prefixes(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(cs.int_schema(allow_radix_prefixes=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=expected.message):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_int_radix_prefixes_off():
+    v = SchemaValidator(cs.int_schema())
+    with pytest.raises(ValidationError, match=r'\[type=int_parsing,'):
+        v.validate_python('0x1F')
+
+    v = SchemaValidator(cs.int_schema(allow_radix_prefixes=True, le=10))
+    with pytest.raises(ValidationError, match=r'\[type=int_type,'):
+        v.validate_python('0x1F', strict=True)
+    with pytest.raises(ValidationError, match=r'\[type=less_than_equal,'):
+        v.validate_python('0x1F')
+
+
+def test_int_underscores():
+    v = SchemaValidator(cs.int_schema())
+    assert v.validate_python('1_000_000') == 1_000_000
+
+    v = SchemaValidator(cs.int_schema(allow_underscores=False, allow_radix_prefixes=True))
+    assert v.validate_python('1000000') == 1_000_000
+    assert v.validate_python('0xff') == 255
+    for input_value in ('1_000_000', '0x_ff', '0xf_f'):
+        with pytest.raises(ValidationError, match=r'\[type=int_parsing,'):
+            v.validate_python(input_value)
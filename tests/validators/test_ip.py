@@ -0,0 +1,185 @@
+import re
+from ipaddress import IPv4Address, IPv4Interface, IPv4Network, IPv6Address, IPv6Interface, IPv6Network
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'version,input_value,expected',
+    [
+        (None, '192.168.0.1', IPv4Address('192.168.0.1')),
+        (None, '::1', IPv6Address('::1')),
+        (None, '2001:db8::ff00:42:8329', IPv6Address('2001:db8::ff00:42:8329')),
+        (None, '::ffff:192.0.2.128', IPv6Address('::ffff:192.0.2.128')),
+        (None, 3232235521, IPv4Address('192.168.0.1')),
+        (None, 2**32, IPv6Address('::1:0:0')),
+        (4, '0.0.0.0', IPv4Address('0.0.0.0')),
+        (4, 0, IPv4Address('0.0.0.0')),
+        (4, '::1', Err('Input should be a valid IPv4 address, invalid IPv4 address syntax [type=ip_parsing,')),
+        (4, 2**32, Err('Input should be a valid IPv4 address, integer out of range [type=ip_parsing,')),
+        (6, '::1', IPv6Address('::1')),
+        (6, 1, IPv6Address('::1')),
+        (6, '192.168.0.1', Err('Input should be a valid IPv6 address, invalid IPv6 address syntax')),
+        (None, '192.168.0.01', Err('Input should be a valid IP address, invalid IP address syntax')),
+        (None, '256.0.0.1', Err('invalid IP address syntax')),
+        (None, '192.168.0.1/24', Err('invalid IP address syntax')),
+        (None, -1, Err('Input should be a valid IP address, integer out of range')),
+        (None, 2**128, Err('integer out of range')),
+        (None, 1.5, Err('IP address input should be a string, bytes or integer [type=ip_type,')),
+    ],
+)
+def test_ip_address(py_and_json: PyAndJson, version, input_value, expected):
+    v = py_and_json(core_schema.ip_address_schema(version=version))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert type(output) is type(expected)
+
+
+@pytest.mark.parametrize(
+    'version,input_value,expected',
+    [
+        (None, b'\xc0\xa8\x00\x01', IPv4Address('192.168.0.1')),
+        (None, b'\x00' * 15 + b'\x01', IPv6Address('::1')),
+        (4, b'\xc0\xa8\x00\x01', IPv4Address('192.168.0.1')),
+        (6, b'\xc0\xa8\x00\x01', Err('Input should be a valid IPv6 address, packed address should be 16 bytes')),
+        (None, b'\x00\x01', Err('Input should be a valid IP address, packed address should be 4 or 16 bytes')),
+        (None, True, Err('IP address input should be a string, bytes or integer')),
+    ],
+)
+def test_ip_address_python(version, input_value, expected):
+    v = SchemaValidator(core_schema.ip_address_schema(version=version))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'version,input_value,expected',
+    [
+        (None, '10.0.0.0/8', IPv4Network('10.0.0.0/8')),
+        (None, '10.0.0.0/255.0.0.0', IPv4Network('10.0.0.0/8')),
+        (None, '10.0.0.0/0.255.255.255', IPv4Network('10.0.0.0/8')),
+        (None, '0.0.0.0/0', IPv4Network('0.0.0.0/0')),
+        (None, '10.1.2.3', IPv4Network('10.1.2.3/32')),
+        (None, '2001:db8::/32', IPv6Network('2001:db8::/32')),
+        (None, 167772160, IPv4Network('10.0.0.0/32')),
+        (4, '2001:db8::/32', Err('Input should be a valid IPv4 network, invalid IPv4 address syntax')),
+        (6, '::/0', IPv6Network('::/0')),
+        (None, '10.0.0.1/8', Err('Input should be a valid IP network, host bits set [type=ip_parsing,')),
+        (None, '2001:db8::1/32', Err('host bits set')),
+        (None, '10.0.0.0/33', Err("invalid prefix length '33'")),
+        (None, '10.0.0.0/', Err("invalid prefix length ''")),
+        (None, '10.0.0.0/8/8', Err("invalid prefix length '8/8'")),
+        (None, '2001:db8::/ffff::', Err("invalid prefix length 'ffff::'")),
+        (None, '10.0.0.0/255.0.255.0', Err("invalid netmask '255.0.255.0'")),
+    ],
+)
+def test_ip_network(py_and_json: PyAndJson, version, input_value, expected):
+    v = py_and_json(core_schema.ip_network_schema(version=version))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert type(output) is type(expected)
+
+
+@pytest.mark.parametrize(
+    'version,input_value,expected',
+    [
+        (None, '10.0.0.1/8', IPv4Interface('10.0.0.1/8')),
+        (None, '10.0.0.1/255.255.255.0', IPv4Interface('10.0.0.1/24')),
+        (None, '10.0.0.1', IPv4Interface('10.0.0.1/32')),
+        (None, '2001:db8::1/64', IPv6Interface('2001:db8::1/64')),
+        (6, '10.0.0.1/8', Err('Input should be a valid IPv6 interface, invalid IPv6 address syntax')),
+        (None, '10.0.0.1/x', Err("Input should be a valid IP interface, invalid prefix length 'x'")),
+    ],
+)
+def test_ip_interface(py_and_json: PyAndJson, version, input_value, expected):
+    v = py_and_json(core_schema.ip_interface_schema(version=version))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert type(output) is type(expected)
+
+
+@pytest.mark.parametrize(
+    'schema,input_value',
+    [
+        (core_schema.ip_address_schema(), IPv4Address('1.2.3.4')),
+        (core_schema.ip_address_schema(version=6), IPv6Address('::1')),
+        (core_schema.ip_network_schema(), IPv6Network('::/0')),
+        (core_schema.ip_interface_schema(version=4), IPv4Interface('1.2.3.4/24')),
+    ],
+)
+def test_instances(schema, input_value):
+    for strict in (False, True):
+        assert SchemaValidator(schema).validate_python(input_value, strict=strict) is input_value
+
+
+def test_instance_wrong_version():
+    v = SchemaValidator(core_schema.ip_address_schema(version=4))
+    with pytest.raises(ValidationError, match='IPv4 address input should be a string, bytes or integer'):
+        v.validate_python(IPv6Address('::1'))
+
+
+def test_strict():
+    v = SchemaValidator(core_schema.ip_address_schema(strict=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('1.2.3.4')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'is_instance_of',
+            'loc': (),
+            'msg': 'Input should be an instance of IPv4Address | IPv6Address',
+            'input': '1.2.3.4',
+            'ctx': {'class': 'IPv4Address | IPv6Address'},
+        }
+    ]
+    assert v.validate_json('"1.2.3.4"') == IPv4Address('1.2.3.4')
+
+    v = SchemaValidator(core_schema.ip_network_schema(version=6, strict=True))
+    with pytest.raises(ValidationError, match=r'Input should be an instance of IPv6Network \[type=is_instance_of'):
+        v.validate_python('::/0')
+
+
+def test_error_details():
+    v = SchemaValidator(core_schema.ip_network_schema(version=4))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('10.0.0.1/8')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'ip_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid IPv4 network, host bits set',
+            'input': '10.0.0.1/8',
+            'ctx': {'kind': 'IPv4 network', 'error': 'host bits set'},
+        }
+    ]
+
+
+def test_union():
+    v = SchemaValidator(core_schema.union_schema([core_schema.ip_address_schema(), core_schema.ip_network_schema()]))
+    assert v.validate_python('10.0.0.0') == IPv4Address('10.0.0.0')
+    assert v.validate_python('10.0.0.0/8') == IPv4Network('10.0.0.0/8')
+    network = IPv4Network('10.0.0.0/8')
+    assert v.validate_python(network) is network
+
+
+def test_invalid_version():
+    with pytest.raises(SchemaError, match='Invalid IP version 5, expected 4 or 6'):
+        SchemaValidator({'type': 'ip-address', 'version': 5})
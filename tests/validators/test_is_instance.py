@@ -1,4 +1,5 @@
 import typing
+from decimal import Decimal
 
 import pytest
 
@@ -189,3 +190,156 @@ def test_is_instance_json_type_before_validator():
     v = SchemaValidator(schema)
 
     assert v.validate_json('null') == int
+
+
+def test_fallback_schema():
+    v = SchemaValidator(
+        cs.is_instance_schema(
+            cls=Decimal, fallback_schema=cs.no_info_after_validator_function(Decimal, cs.str_schema())
+        )
+    )
+    d = Decimal('1.5')
+    assert v.validate_python(d) is d
+    assert v.validate_python('2.5') == Decimal('2.5')
+    assert v.validate_json('"3.5"') == Decimal('3.5')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1.5)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': (), 'msg': 'Input should be a valid string', 'input': 1.5}
+    ]
+    assert repr(v).startswith('SchemaValidator(title="is-instance[Decimal,function-after[Decimal(), str]]"')
+
+
+def test_fallback_schema_strict():
+    v = SchemaValidator(cs.is_instance_schema(cls=Foo, fallback_schema=cs.int_schema()))
+    assert v.validate_python('1') == 1
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python('1', strict=True)
+
+
+def test_fallback_schema_smart_union():
+    # an instance built by the fallback is only a lax match, so other union members are preferred
+    v = SchemaValidator(
+        cs.union_schema([cs.is_instance_schema(cls=Foo, fallback_schema=cs.int_schema()), cs.str_schema()])
+    )
+    assert v.validate_python('1') == '1'
+    assert v.validate_python(1) == 1
+
+
+@typing.runtime_checkable
+class Closeable(typing.Protocol):
+    name: str
+
+    def close(self) -> None: ...
+
+
+class File:
+    name = 'file'
+
+    def close(self) -> None:
+        pass
+
+
+class NoName:
+    def close(self) -> None:
+        pass
+
+
+class CloseIsNone:
+    name = 'file'
+    close = None
+
+
+class ExplicitCloseable(Closeable):
+    pass
+
+
+class InstanceName:
+    def __init__(self):
+        self.name = 'file'
+
+    def close(self) -> None:
+        pass
+
+
+@pytest.mark.parametrize('protocol_check', [None, 'attributes', 'isinstance'])
+@pytest.mark.parametrize(
+    'input_value,valid',
+    [
+        (File(), True),
+        (ExplicitCloseable(), True),
+        (InstanceName(), True),
+        (NoName(), False),
+        (CloseIsNone(), False),
+        (1, False),
+    ],
+)
+def test_protocol(protocol_check, input_value, valid):
+    v = SchemaValidator(core_schema.is_instance_schema(Closeable, protocol_check=protocol_check))
+    assert isinstance(input_value, Closeable) is valid
+    if valid:
+        assert v.validate_python(input_value) is input_value
+    else:
+        with pytest.raises(ValidationError, match='Input should be an instance of Closeable'):
+            v.validate_python(input_value)
+
+
+def test_protocol_attributes_static():
+    class Property:
+        name = 'property'
+
+        @property
+        def close(self):
+            raise RuntimeError('boom')
+
+    # members are looked up without calling properties
+    v = SchemaValidator(core_schema.is_instance_schema(Closeable, protocol_check='attributes'))
+    p = Property()
+    assert v.validate_python(p) is p
+
+
+def test_protocol_registered():
+    @typing.runtime_checkable
+    class Sized(typing.Protocol):
+        def size(self) -> int: ...
+
+    class Empty:
+        pass
+
+    Sized.register(Empty)
+
+    assert isinstance(Empty(), Sized)
+    for protocol_check in (None, 'attributes', 'isinstance'):
+        v = SchemaValidator(core_schema.is_instance_schema(Sized, protocol_check=protocol_check))
+        assert isinstance(v.validate_python(Empty()), Empty)
+        with pytest.raises(ValidationError, match=r'Input should be an instance of .*Sized \[type=is_instance_of'):
+            v.validate_python(1)
+
+
+def test_protocol_custom_instancecheck():
+    class Meta(type(typing.Protocol)):
+        def __instancecheck__(cls, instance):
+            return instance == 'special'
+
+    @typing.runtime_checkable
+    class Special(typing.Protocol, metaclass=Meta):
+        def close(self) -> None: ...
+
+    # the metaclass's `__instancecheck__` is always used
+    v = SchemaValidator(core_schema.is_instance_schema(Special, protocol_check='attributes'))
+    assert v.validate_python('special') == 'special'
+    with pytest.raises(ValidationError):
+        v.validate_python(File())
+
+
+def test_protocol_with_fallback():
+    v = SchemaValidator(core_schema.is_instance_schema(Closeable, fallback_schema=core_schema.int_schema()))
+    f = File()
+    assert v.validate_python(f) is f
+    assert v.validate_python('1') == 1
+    assert v.validate_json('1') == 1
+
+
+def test_protocol_check_invalid():
+    with pytest.raises(SchemaError, match="`protocol_check` must be one of: 'attributes' or 'isinstance'"):
+        SchemaValidator(core_schema.is_instance_schema(Closeable, protocol_check='other'))
@@ -1,3 +1,4 @@
+import array
 import collections.abc
 import re
 from collections import deque
@@ -13,6 +14,11 @@ from pydantic_core import core_schema as cs
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
+try:
+    import numpy
+except ImportError:
+    numpy = None
+
 
 @pytest.mark.parametrize(
     'input_value,expected',
@@ -548,3 +554,98 @@ def test_list_dict_items_input(testcase: ListInputTestCase) -> None:
         output = v.validate_python(testcase.input)
         assert output == testcase.output
         assert output is not testcase.input
+
+
+def test_allow_array_like_buffer():
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), allow_array_like=True))
+    assert v.validate_python(array.array('q', [1, 2, 3])) == [1, 2, 3]
+    assert v.validate_python(memoryview(b'ab')) == [97, 98]
+    # bytes are never treated as arrays
+    with pytest.raises(ValidationError, match=r'Input should be a valid list \[type=list_type,'):
+        v.validate_python(b'ab')
+
+
+def test_allow_array_like_config():
+    v = SchemaValidator(cs.list_schema(cs.int_schema()), config=cs.CoreConfig(list_allow_array_like=True))
+    assert v.validate_python(array.array('i', [1, 2])) == [1, 2]
+
+
+def test_allow_array_like_dunder_array():
+    class ArrayLike:
+        def __array__(self):
+            return self
+
+        def tolist(self):
+            return [1, '2']
+
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), allow_array_like=True))
+    assert v.validate_python(ArrayLike()) == [1, 2]
+
+
+@pytest.mark.skipif(numpy is None, reason='numpy is not installed')
+def test_allow_array_like_numpy():
+    v = SchemaValidator(cs.list_schema(cs.list_schema(cs.float_schema()), allow_array_like=True))
+    assert v.validate_python(numpy.array([[1, 2], [3, 4]])) == [[1.0, 2.0], [3.0, 4.0]]
+
+
+def test_unique_items(py_and_json: PyAndJson):
+    v = py_and_json(cs.list_schema(cs.int_schema(), unique_items=True))
+    assert v.validate_test([1, '2', 3]) == [1, 2, 3]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 2, '1', 2])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'duplicate_item',
+            'loc': (2,),
+            'msg': 'Items should be unique, this is a duplicate of the item at index 0',
+            'input': 1,
+            'ctx': {'first_index': 0},
+        }
+    ]
+
+
+def test_unique_items_any():
+    v = SchemaValidator(cs.list_schema(unique_items=True))
+    assert v.validate_python([1, 'a', [1], {'a': 1}]) == [1, 'a', [1], {'a': 1}]
+    # unhashable items are compared with `==`
+    with pytest.raises(ValidationError, match=r'duplicate of the item at index 1 \[type=duplicate_item,'):
+        v.validate_python([[0], [1], {'a': 1}, [1]])
+    # as with sets, equal values of different types are duplicates
+    with pytest.raises(ValidationError, match=r'duplicate of the item at index 0 \[type=duplicate_item,'):
+        v.validate_python([1, 1.0])
+
+
+def test_unique_items_key():
+    v = SchemaValidator(
+        cs.list_schema(
+            cs.tuple_schema([cs.int_schema(), cs.str_schema()]),
+            unique_items=True,
+            unique_items_key=lambda item: item[0],
+        )
+    )
+    assert v.validate_python([(1, 'a'), (2, 'a')]) == [(1, 'a'), (2, 'a')]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([(1, 'a'), (2, 'b'), ('1', 'c')])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'duplicate_item',
+            'loc': (2,),
+            'msg': 'Items should be unique, this is a duplicate of the item at index 0',
+            'input': (1, 'c'),
+            'ctx': {'first_index': 0},
+        }
+    ]
+
+
+def test_unique_items_key_error():
+    def key(item):
+        if item < 0:
+            raise ValueError('negative')
+        return item
+
+    v = SchemaValidator(cs.list_schema(cs.int_schema(), unique_items=True, unique_items_key=key))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 2, -3])
+    assert exc_info.value.errors(include_url=False, include_context=False) == [
+        {'type': 'value_error', 'loc': (2,), 'msg': 'Value error, negative', 'input': -3}
+    ]
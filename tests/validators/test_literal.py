@@ -392,6 +392,39 @@ def test_big_int():
         v.validate_python(37)
 
 
+def test_big_int_mixed():
+    big_int = 2**64 + 1
+    v = SchemaValidator(core_schema.literal_schema([1, 'a', big_int]))
+    assert v.validate_python(1) == 1
+    assert v.validate_python(big_int) == big_int
+    assert v.validate_json(str(big_int)) == big_int
+    # an int too big for an i64 which isn't expected is a literal error, not a parsing error
+    with pytest.raises(ValidationError, match=r"Input should be 1, 'a' or 18446744073709551617 \[type=literal_error"):
+        v.validate_python(2**70)
+
+
+def test_bytes():
+    v = SchemaValidator(core_schema.literal_schema([b'\x00\x01', b'abc', 'abc']))
+    assert v.validate_python(b'\x00\x01') == b'\x00\x01'
+    assert v.validate_python(b'abc') == b'abc'
+    assert v.validate_python('abc') == 'abc'
+    # strings from JSON are encoded as UTF-8 by default, `str` literals take priority
+    assert v.validate_json('"abc"') == 'abc'
+    assert v.validate_json('"\\u0000\\u0001"') == b'\x00\x01'
+    with pytest.raises(ValidationError, match='literal_error'):
+        v.validate_python(bytearray(b'abc'))
+
+
+def test_bytes_base64():
+    v = SchemaValidator(core_schema.literal_schema([b'\x00\x01', b'abc']), config={'val_json_bytes': 'base64'})
+    assert v.validate_json('"AAE="') == b'\x00\x01'
+    assert v.validate_json('"YWJj"') == b'abc'
+    assert v.validate_python(b'abc') == b'abc'
+    for input_value in ('"abc"', '"not base64!"', '1'):
+        with pytest.raises(ValidationError, match=r"Input should be b'\\x00\\x01' or b'abc' \[type=literal_error"):
+            v.validate_json(input_value)
+
+
 def test_enum_for_str() -> None:
     class S(str, Enum):
         a = 'a'
@@ -7,7 +7,7 @@ from typing import Any, Callable
 import pytest
 from dirty_equals import HasRepr, IsInstance
 
-from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError, core_schema
+from pydantic_core import CoreConfig, ModelFieldGetter, SchemaError, SchemaValidator, ValidationError, core_schema
 
 
 def test_model_class():
@@ -46,6 +46,47 @@ def test_model_class():
     assert m2.__dict__ == {'field_a': 'test', 'field_b': 12}
 
 
+def test_model_class_alias_hits():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+        field_a: str
+
+    sub_schema = core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {'field_a': core_schema.model_field(core_schema.str_schema(), validation_alias='x')}
+        ),
+    )
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'field_a': core_schema.model_field(core_schema.str_schema(), validation_alias=[['a'], ['old_a']]),
+                    'sub': core_schema.model_field(core_schema.nullable_schema(sub_schema)),
+                },
+                extra_behavior='allow',
+            ),
+        )
+    )
+    m, report = v.validate_python({'old_a': 'test', 'sub': {'x': 'inner'}}, alias_hits=True)
+    assert m.__dict__ == {'field_a': 'test', 'sub': m.sub}
+    assert m.sub.__dict__ == {'field_a': 'inner'}
+    assert m.__pydantic_extra__ == {}
+    assert report.alias_hits == [
+        {'loc': ('field_a',), 'alias': 'old_a'},
+        {'loc': ('sub',), 'alias': 'sub'},
+        {'loc': ('sub', 'field_a'), 'alias': 'x'},
+    ]
+    assert report.coercions is None
+
+    m, report = v.validate_json('{"a": "test", "sub": null}', alias_hits=True)
+    assert m.__dict__ == {'field_a': 'test', 'sub': None}
+    assert report.alias_hits == [{'loc': ('field_a',), 'alias': 'a'}, {'loc': ('sub',), 'alias': 'sub'}]
+
+    assert v.validate_python({'a': 'test', 'sub': None}).__dict__ == {'field_a': 'test', 'sub': None}
+
+
 def test_model_class_extra():
     class MyModel:
         # this is not required, but it avoids `__pydantic_fields_set__` being included in `__dict__`
@@ -496,6 +537,35 @@ def test_model_class_instance_direct():
     assert m3.field_a == 'init'
 
 
+def test_model_class_slots_self_instance_subclass():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+        field_a: str
+
+        def __setattr__(self, name, value):
+            raise TypeError('frozen')
+
+    class SubModel(MyModel):
+        __slots__ = ('other',)
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel, core_schema.model_fields_schema({'field_a': core_schema.model_field(core_schema.str_schema())})
+        )
+    )
+    m = v.validate_python({'field_a': 'test'})
+    assert m.__dict__ == {'field_a': 'test'}
+    assert m.__pydantic_fields_set__ == {'field_a'}
+    assert m.__pydantic_extra__ is None
+    assert m.__pydantic_private__ is None
+
+    sub = SubModel()
+    v.validate_python({'field_a': 'sub'}, self_instance=sub)
+    assert sub.__dict__ == {'field_a': 'sub'}
+    assert sub.__pydantic_fields_set__ == {'field_a'}
+    assert sub.__pydantic_private__ is None
+
+
 def test_model_class_instance_subclass():
     post_init_calls = []
 
@@ -1216,6 +1286,135 @@ def test_validate_assignment_model_validator_function(function_schema: Any, call
     assert calls == [call1, call2]
 
 
+def test_validate_assignments():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    calls: list[Any] = []
+
+    def f(x, info):
+        calls.append(dict(info.data))
+        return x
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(
+                        core_schema.with_info_after_validator_function(f, core_schema.str_schema())
+                    ),
+                    'c': core_schema.model_field(core_schema.int_schema(), frozen=True),
+                },
+                extra_behavior='allow',
+            ),
+            extra_behavior='allow',
+        )
+    )
+    m = v.validate_python({'a': 1, 'b': 'x', 'c': 3})
+    m.__pydantic_fields_set__ = {'c'}
+    calls.clear()
+
+    assert v.validate_assignments(m, {'b': 'y', 'a': '2', 'd': 4}) is m
+    assert m.__dict__ == {'a': 2, 'b': 'y', 'c': 3}
+    assert m.__pydantic_extra__ == {'d': 4}
+    assert m.__pydantic_fields_set__ == {'a', 'b', 'c', 'd'}
+    # fields are validated in order, `b` sees the validated value of `a`
+    assert calls == [{'a': 2, 'c': 3}]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignments(m, {'a': 'x', 'b': 'z', 'c': 4})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {'type': 'frozen_field', 'loc': ('c',), 'msg': 'Field is frozen', 'input': 4},
+    ]
+    # nothing is assigned unless every value is valid
+    assert m.__dict__ == {'a': 2, 'b': 'y', 'c': 3}
+
+
+def test_validate_assignments_model_validator_function():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    calls: list[Any] = []
+
+    def f(input_value, handler, info):
+        calls.append(deepcopy(input_value))
+        return handler(input_value)
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.with_info_wrap_validator_function(
+                f,
+                core_schema.model_fields_schema(
+                    {
+                        'a': core_schema.model_field(core_schema.int_schema()),
+                        'b': core_schema.model_field(core_schema.int_schema()),
+                    },
+                    extra_behavior='forbid',
+                ),
+            ),
+        )
+    )
+    m = v.validate_python({'a': 1, 'b': 2})
+    calls.clear()
+
+    v.validate_assignments(m, {'a': '10', 'b': '20'})
+    assert m.__dict__ == {'a': 10, 'b': 20}
+    # the model validator runs once for all the assignments
+    assert calls == [{'a': '10', 'b': '20'}]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignments(m, {'c': 1})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'no_such_attribute',
+            'loc': ('c',),
+            'msg': "Object has no attribute 'c'",
+            'input': 1,
+            'ctx': {'attribute': 'c'},
+        }
+    ]
+
+
+def test_validate_assignments_frozen_and_root():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}),
+            frozen=True,
+        )
+    )
+    with pytest.raises(ValidationError, match='Instance is frozen'):
+        v.validate_assignments(v.validate_python({'a': 1}), {'a': 2})
+
+    v = SchemaValidator(core_schema.model_schema(MyModel, core_schema.int_schema(), root_model=True))
+    m = v.validate_python(1)
+    v.validate_assignments(m, {'root': '2'})
+    assert m.root == 2
+    with pytest.raises(ValidationError, match="Object has no attribute 'other'"):
+        v.validate_assignments(m, {'root': 3, 'other': 1})
+    assert m.root == 2
+
+
+def test_validate_assignments_not_supported():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(TypeError, match='^validate_assignments is not supported for int$'):
+        v.validate_assignments(1, {'a': 1})
+
+
 def test_model_error():
     class MyModel:
         # this is not required, but it avoids `__pydantic_fields_set__` being included in `__dict__`
@@ -1342,3 +1541,250 @@ def test_model_with_enum_int_field_validation_should_succeed_for_any_type_equali
     v.validate_assignment(m, 'enum_field', Decimal(1))
     v.validate_assignment(m, 'enum_field_2', Decimal(2))
     v.validate_assignment(m, 'enum_field_3', IntWrappable(3))
+
+
+def test_model_field_getter():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(core_schema.str_schema()),
+                },
+                extra_behavior='allow',
+            ),
+        )
+    )
+    m = v.validate_python({'a': '1', 'b': 'x', 'c': 'extra'})
+    assert ModelFieldGetter('a')(m) == 1
+    assert ModelFieldGetter('b')(m) == 'x'
+    assert ModelFieldGetter('c')(m) == 'extra'
+    with pytest.raises(AttributeError, match="'MyModel' object has no field 'd'"):
+        ModelFieldGetter('d')(m)
+    assert repr(ModelFieldGetter('a')) == "ModelFieldGetter('a')"
+
+    get_a = ModelFieldGetter('a')
+    m2 = v.validate_python({'a': 2, 'b': 'y'})
+    assert [get_a(m), get_a(m2)] == [1, 2]
+
+    # assignment is reflected
+    v.validate_assignment(m, 'a', '3')
+    assert get_a(m) == 3
+
+    # the model class is left untouched
+    assert not hasattr(MyModel, '__pydantic_get__')
+
+
+def test_model_field_getter_root_model():
+    class RootModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+        root: int
+
+    v = SchemaValidator(core_schema.model_schema(RootModel, core_schema.int_schema(), root_model=True))
+    assert ModelFieldGetter('root')(v.validate_python('42')) == 42
+
+
+def test_model_field_getter_slots():
+    class Slotted:
+        __slots__ = ('a',)
+
+        def __init__(self, a):
+            self.a = a
+
+    assert ModelFieldGetter('a')(Slotted(1)) == 1
+    with pytest.raises(AttributeError):
+        ModelFieldGetter('b')(Slotted(1))
+
+
+def test_generate_repr():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(core_schema.str_schema(), repr=False),
+                    'password': core_schema.model_field(core_schema.str_schema(), repr='masked'),
+                    'c': core_schema.model_field(core_schema.list_schema(), repr=True),
+                },
+                extra_behavior='allow',
+            ),
+            generate_repr=True,
+        )
+    )
+    m = v.validate_python({'a': '1', 'b': 'hidden', 'password': 'hunter2', 'c': ['x'], 'd': 'extra'})
+    assert repr(m) == "MyModel(a=1, password='**********', c=['x'], d='extra')"
+    assert str(m) == repr(m)
+    assert list(m.__rich_repr__()) == [('a', 1), ('password', '**********'), ('c', ['x']), ('d', 'extra')]
+
+    class SubModel(MyModel):
+        pass
+
+    assert repr(v.validate_python({'a': 2, 'b': '', 'password': '', 'c': []}, self_instance=SubModel())).startswith(
+        'SubModel(a=2'
+    )
+
+
+def test_generate_repr_root_model():
+    class RootModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+        root: int
+
+    v = SchemaValidator(
+        core_schema.model_schema(RootModel, core_schema.int_schema(), root_model=True, generate_repr=True)
+    )
+    assert repr(v.validate_python('42')) == 'RootModel(root=42)'
+
+
+def test_generate_repr_invalid():
+    class MyModel:
+        pass
+
+    with pytest.raises(SchemaError, match="Invalid repr value for field 'a': 'secret'"):
+        SchemaValidator(
+            core_schema.model_schema(
+                MyModel,
+                core_schema.model_fields_schema(
+                    {'a': core_schema.model_field(core_schema.int_schema(), repr='secret')}  # type: ignore
+                ),
+                generate_repr=True,
+            )
+        )
+
+
+def test_generate_eq():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    class OtherModel(MyModel):
+        pass
+
+    def schema(cls):
+        return core_schema.model_schema(
+            cls,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(core_schema.with_default_schema(core_schema.int_schema(), default=0)),
+                },
+                extra_behavior='allow',
+            ),
+            extra_behavior='allow',
+            generate_eq=True,
+        )
+
+    v = SchemaValidator(schema(MyModel))
+    m = v.validate_python({'a': 1})
+    assert m == v.validate_python({'a': 1, 'b': 0})
+    assert m != v.validate_python({'a': 2})
+    assert m != v.validate_python({'a': 1, 'c': 1})
+    assert (m == 1) is False
+    assert m.__eq__(1) is NotImplemented
+
+    # values in `__dict__` which aren't fields are ignored
+    other = v.validate_python({'a': 1})
+    other.__dict__['cached'] = 1
+    assert m == other
+
+    other.__pydantic_private__ = {'_x': 1}
+    assert m != other
+
+    # instances of a different model aren't equal
+    other_model = SchemaValidator(schema(OtherModel)).validate_python({'a': 1})
+    assert m != other_model
+
+    # not frozen, so `__hash__` isn't set
+    with pytest.raises(TypeError, match="unhashable type: 'MyModel'"):
+        hash(m)
+
+
+def test_generate_eq_generic_origin():
+    class GenericModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    class IntModel(GenericModel):
+        pass
+
+    class AnyModel(GenericModel):
+        pass
+
+    def validator(cls, schema):
+        return SchemaValidator(
+            core_schema.model_schema(
+                cls,
+                core_schema.model_fields_schema({'a': core_schema.model_field(schema)}),
+                generic_origin=GenericModel,
+                generate_eq=True,
+            )
+        )
+
+    int_model = validator(IntModel, core_schema.int_schema()).validate_python({'a': 1})
+    any_model = validator(AnyModel, core_schema.any_schema()).validate_python({'a': 1})
+    assert int_model == any_model
+
+
+def test_generate_eq_frozen_hash():
+    class FrozenModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            FrozenModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(core_schema.tuple_variable_schema(core_schema.str_schema())),
+                }
+            ),
+            frozen=True,
+            generate_eq=True,
+        )
+    )
+    m = v.validate_python({'a': 1, 'b': ['x', 'y']})
+    assert hash(m) == hash((1, ('x', 'y')))
+    assert hash(m) == hash(v.validate_python({'a': 1, 'b': ('x', 'y')}))
+    assert len({m, v.validate_python({'a': 1, 'b': ['x', 'y']}), v.validate_python({'a': 2, 'b': []})}) == 2
+
+
+def test_generate_eq_hash_without_fields():
+    class FrozenModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    fields_schema = core_schema.model_fields_schema(
+        {'a': core_schema.model_field(core_schema.int_schema()), 'b': core_schema.model_field(core_schema.int_schema())}
+    )
+    v = SchemaValidator(
+        core_schema.model_schema(
+            FrozenModel,
+            core_schema.no_info_after_validator_function(lambda x: x, fields_schema),
+            frozen=True,
+            generate_eq=True,
+        )
+    )
+    m = v.validate_python({'a': 1, 'b': 2})
+    other = v.validate_python({'a': 1, 'b': 2})
+    # the values of `__dict__` are hashed in the order of their keys, so equal instances hash the same
+    other.__dict__ = {'b': 2, 'a': 1}
+    assert m == other
+    assert hash(m) == hash(other) == hash((1, 2))
+    assert FrozenModel.__eq__.__qualname__.endswith('FrozenModel.__eq__')
+
+
+def test_generate_eq_root_model():
+    class RootModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+        root: int
+
+    v = SchemaValidator(
+        core_schema.model_schema(RootModel, core_schema.int_schema(), root_model=True, frozen=True, generate_eq=True)
+    )
+    assert v.validate_python(1) == v.validate_python('1')
+    assert v.validate_python(1) != v.validate_python(2)
+    assert hash(v.validate_python(1)) == hash((1,))
@@ -674,6 +674,130 @@ def test_aliases_path_negative_json(py_and_json: PyAndJson, input_value, expecte
         assert output == expected
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({'foo': [{'bar': 1}, {'bar': '2'}]}, ({'field_a': [1, 2]}, None, {'field_a'})),
+        ({'foo': [{'bar': 1}, {'spam': 2}, {'bar': 3}]}, ({'field_a': [1, 3]}, None, {'field_a'})),
+        ({'foo': {'x': {'bar': 1}, 'y': {'bar': 2}}}, ({'field_a': [1, 2]}, None, {'field_a'})),
+        ({'foo': []}, ({'field_a': []}, None, {'field_a'})),
+        ({'foo': [{'bar': 'x'}]}, Err(r'foo\.\*\.bar\.0\n +Input should be a valid integer')),
+        ({'foo': 'foobar'}, Err(r'foo\.\*\.bar\n +Field required \[type=missing,')),
+        ({'foo': 1}, Err(r'foo\.\*\.bar\n +Field required \[type=missing,')),
+    ],
+    ids=repr,
+)
+def test_aliases_path_wildcard(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(
+        core_schema.model_fields_schema(
+            fields={
+                'field_a': core_schema.model_field(
+                    validation_alias=['foo', ..., 'bar'], schema=core_schema.list_schema(core_schema.int_schema())
+                )
+            }
+        )
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=expected.message):
+            val = v.validate_test(input_value)
+            print(f'UNEXPECTED OUTPUT: {val!r}')
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+
+
+def test_aliases_path_wildcard_negative(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.model_fields_schema(
+            fields={
+                'field_a': core_schema.model_field(
+                    validation_alias=['foo', ..., -1, ...],
+                    schema=core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())),
+                )
+            }
+        )
+    )
+    assert v.validate_test({'foo': [[[1], [2, 3]], [], [[4]]]}) == ({'field_a': [[2, 3], [4]]}, None, {'field_a'})
+
+
+def test_aliases_path_wildcard_tuple():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            fields={
+                'field_a': core_schema.model_field(
+                    validation_alias=['foo', ..., 0], schema=core_schema.list_schema(core_schema.int_schema())
+                )
+            }
+        )
+    )
+    assert v.validate_python({'foo': ((1, 2), (3,), ())}) == ({'field_a': [1, 3]}, None, {'field_a'})
+
+
+def test_aliases_path_wildcard_from_attributes():
+    class Item:
+        def __init__(self, bar):
+            self.bar = bar
+
+    class Foo:
+        foo = [Item(1), Item('2'), object()]
+
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            fields={
+                'field_a': core_schema.model_field(
+                    validation_alias=['foo', ..., 'bar'], schema=core_schema.list_schema(core_schema.int_schema())
+                )
+            },
+            from_attributes=True,
+        )
+    )
+    assert v.validate_python(Foo()) == ({'field_a': [1, 2]}, None, {'field_a'})
+
+
+def test_aliases_path_star_key(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.model_fields_schema(
+            fields={
+                'field_a': core_schema.model_field(validation_alias=['foo', '*', 'bar'], schema=core_schema.int_schema())
+            }
+        )
+    )
+    assert v.validate_test({'foo': {'*': {'bar': 1}, 'x': {'bar': 2}}}) == ({'field_a': 1}, None, {'field_a'})
+    with pytest.raises(ValidationError, match=r'foo\.\*\.bar\n +Field required \[type=missing,'):
+        v.validate_test({'foo': [{'bar': 1}]})
+
+
+def test_alias_hits():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            fields={
+                'field_a': core_schema.model_field(
+                    validation_alias=[['foo', 'bar'], ['old_a']], schema=core_schema.int_schema()
+                ),
+                'field_b': core_schema.model_field(validation_alias='b', schema=core_schema.int_schema()),
+                'field_c': core_schema.model_field(
+                    schema=core_schema.with_default_schema(core_schema.int_schema(), default=3)
+                ),
+            }
+        ),
+        config=CoreConfig(validate_by_name=True),
+    )
+    output, report = v.validate_python({'foo': {'bar': 1}, 'b': 2}, alias_hits=True)
+    assert output == ({'field_a': 1, 'field_b': 2, 'field_c': 3}, None, {'field_a', 'field_b'})
+    assert report.alias_hits == [
+        {'loc': ('field_a',), 'alias': ['foo', 'bar']},
+        {'loc': ('field_b',), 'alias': 'b'},
+    ]
+
+    output, report = v.validate_json('{"old_a": 1, "field_b": 2, "field_c": 4}', alias_hits=True)
+    assert output == ({'field_a': 1, 'field_b': 2, 'field_c': 4}, None, {'field_a', 'field_b', 'field_c'})
+    assert report.alias_hits == [
+        {'loc': ('field_a',), 'alias': 'old_a'},
+        {'loc': ('field_b',), 'alias': 'field_b'},
+        {'loc': ('field_c',), 'alias': 'field_c'},
+    ]
+
+
 def test_aliases_debug():
     v = SchemaValidator(
         core_schema.model_fields_schema(
@@ -1783,6 +1907,64 @@ def test_extra_behavior_allow_keys_validation() -> None:
     assert exc_info.value.errors()[0]['type'] == 'string_too_long'
 
 
+def test_extra_behavior_allow_pattern_schemas() -> None:
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema())},
+            extra_behavior='allow',
+            extras_pattern_schemas={'^x-': core_schema.str_schema(), '_id$': core_schema.int_schema()},
+        )
+    )
+
+    m, model_extra, fields_set = v.validate_python({'a': 1, 'x-trace': 'abc', 'user_id': '42'})
+    assert m == {'a': 1}
+    assert model_extra == {'x-trace': 'abc', 'user_id': 42}
+    assert fields_set == {'a', 'x-trace', 'user_id'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'x-trace': 1, 'other': 2})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': ('x-trace',), 'msg': 'Input should be a valid string', 'input': 1},
+        {'type': 'extra_forbidden', 'loc': ('other',), 'msg': 'Extra inputs are not permitted', 'input': 2},
+    ]
+
+
+def test_extra_behavior_allow_pattern_schemas_fallback() -> None:
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {},
+            extra_behavior='allow',
+            extras_schema=core_schema.float_schema(),
+            extras_pattern_schemas={'^x-': core_schema.str_schema()},
+        )
+    )
+    assert v.validate_python({'x-a': 'b', 'c': '1.5'}) == ({}, {'x-a': 'b', 'c': 1.5}, {'x-a', 'c'})
+
+
+def test_extra_behavior_allow_pattern_schemas_assignment() -> None:
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema())},
+            extra_behavior='allow',
+            extras_pattern_schemas={'^x-': core_schema.int_schema()},
+        )
+    )
+    assert v.validate_assignment({'a': 1}, 'x-b', '2') == ({'a': 1}, {'x-b': 2}, {'x-b'})
+    with pytest.raises(ValidationError, match='Extra inputs are not permitted'):
+        v.validate_assignment({'a': 1}, 'c', 2)
+
+
+def test_extras_pattern_schemas_invalid() -> None:
+    with pytest.raises(SchemaError, match='extras_pattern_schemas can only be used if extra_behavior=allow'):
+        SchemaValidator(core_schema.model_fields_schema({}, extras_pattern_schemas={'^x-': core_schema.str_schema()}))
+    with pytest.raises(SchemaError, match='Invalid extras pattern "\\["'):
+        SchemaValidator(
+            core_schema.model_fields_schema(
+                {}, extra_behavior='allow', extras_pattern_schemas={'[': core_schema.str_schema()}
+            )
+        )
+
+
 @pytest.mark.parametrize('config_by_alias', [None, True, False])
 @pytest.mark.parametrize('config_by_name', [None, True, False])
 @pytest.mark.parametrize('runtime_by_alias', [None, True, False])
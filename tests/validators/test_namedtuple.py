@@ -0,0 +1,94 @@
+import re
+from collections import namedtuple
+from typing import NamedTuple
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+from pydantic_core import core_schema as cs
+
+from ..conftest import Err, PyAndJson
+
+
+class Point(NamedTuple):
+    x: int
+    y: int = 0
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ([1, '2'], Point(1, 2)),
+        ([1], Point(1, 0)),
+        ({'x': 1, 'y': '2'}, Point(1, 2)),
+        ({'x': '1'}, Point(1, 0)),
+        ([1, 2, 3], Err('Unexpected positional argument [type=unexpected_positional_argument,')),
+        ({'x': 1, 'z': 3}, Err('Unexpected keyword argument [type=unexpected_keyword_argument,')),
+        ({'y': 1}, Err('Missing required argument [type=missing_argument,')),
+        (['a'], Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+    ],
+)
+def test_namedtuple(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(cs.namedtuple_schema(Point, [cs.int_schema(), cs.int_schema()]))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert isinstance(output, Point)
+
+
+def test_namedtuple_instance():
+    v = SchemaValidator(cs.namedtuple_schema(Point, [cs.int_schema(), cs.int_schema()]))
+    assert v.validate_python(Point('1', '2')) == Point(1, 2)
+    assert v.validate_python((1, 2)) == Point(1, 2)
+
+
+def test_namedtuple_locations():
+    v = SchemaValidator(cs.namedtuple_schema(Point, [cs.int_schema(), cs.int_schema()]))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python((1, 'a', 3))
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors(include_url=False)] == [
+        ('int_parsing', (1,)),
+        ('unexpected_positional_argument', (2,)),
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'y': 'a'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors(include_url=False)] == [
+        ('missing_argument', ('x',)),
+        ('int_parsing', ('y',)),
+    ]
+
+
+def test_namedtuple_strict():
+    v = SchemaValidator(cs.namedtuple_schema(Point, [cs.int_schema(), cs.int_schema()], strict=True))
+    assert v.validate_python(Point(1, 2)) == Point(1, 2)
+    with pytest.raises(ValidationError, match=r'Input should be an instance of Point \[type=is_instance_of,'):
+        v.validate_python((1, 2))
+    assert v.validate_json('[1, 2]') == Point(1, 2)
+
+
+def test_namedtuple_no_items_schema():
+    Pair = namedtuple('Pair', ['a', 'b'], defaults=[None])
+    v = SchemaValidator(cs.namedtuple_schema(Pair))
+    assert v.validate_python(['x']) == Pair('x', None)
+    assert v.validate_python({'a': 1, 'b': 2}) == Pair(1, 2)
+
+
+def test_namedtuple_rename():
+    Renamed = namedtuple('Renamed', ['a', 'def', 'a'], rename=True)
+    assert Renamed._fields == ('a', '_1', '_2')
+    v = SchemaValidator(cs.namedtuple_schema(Renamed, [cs.int_schema(), cs.int_schema(), cs.str_schema()]))
+    assert v.validate_python({'a': '1', '_1': 2, '_2': 'c'}) == Renamed(1, 2, 'c')
+
+
+def test_namedtuple_items_schema_length():
+    with pytest.raises(SchemaError, match="'items_schema' must have one schema per field, expected 2, got 1"):
+        SchemaValidator(cs.namedtuple_schema(Point, [cs.int_schema()]))
+
+
+def test_not_namedtuple():
+    with pytest.raises(SchemaError, match="'cls' must be a NamedTuple class, got int"):
+        SchemaValidator(cs.namedtuple_schema(int))
@@ -0,0 +1,156 @@
+import os
+import re
+from pathlib import Path, PurePosixPath, PureWindowsPath
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+class MyPathLike:
+    def __init__(self, path: str):
+        self.path = path
+
+    def __fspath__(self) -> str:
+        return self.path
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('foo/bar.txt', Path('foo/bar.txt')),
+        ('/abs', Path('/abs')),
+        ('', Path('.')),
+        (123, Err('Path input should be a string, bytes or os.PathLike [type=path_type,')),
+    ],
+)
+def test_path(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.path_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert isinstance(output, Path)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (b'foo/bar', Path('foo/bar')),
+        (bytearray(b'foo'), Path('foo')),
+        (os.fsencode('caf\xe9'), Path('caf\xe9')),
+        (MyPathLike('a/b'), Path('a/b')),
+        (PurePosixPath('a/b'), Path('a/b')),
+        (object(), Err('Path input should be a string, bytes or os.PathLike [type=path_type,')),
+    ],
+)
+def test_path_python(input_value, expected):
+    v = SchemaValidator(core_schema.path_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_path_instance():
+    v = SchemaValidator(core_schema.path_schema())
+    path = Path('a/b')
+    assert v.validate_python(path) is path
+    assert v.validate_python(path, strict=True) is path
+
+
+@pytest.mark.parametrize(
+    'path_type,input_value,expected',
+    [
+        ('posix', 'a/b', PurePosixPath('a/b')),
+        ('windows', 'C:\\a\\b', PureWindowsPath('C:/a/b')),
+        ('windows', PurePosixPath('a/b'), PureWindowsPath('a\\b')),
+    ],
+)
+def test_path_type(path_type, input_value, expected):
+    v = SchemaValidator(core_schema.path_schema(path_type=path_type))
+    output = v.validate_python(input_value)
+    assert output == expected
+    assert type(output) is type(expected)
+
+
+def test_strict():
+    v = SchemaValidator(core_schema.path_schema(strict=True))
+    with pytest.raises(ValidationError, match=r'Input should be an instance of Path \[type=is_instance_of'):
+        v.validate_python('a/b')
+    assert v.validate_json('"a/b"') == Path('a/b')
+
+    v = SchemaValidator(core_schema.path_schema(path_type='windows', strict=True))
+    with pytest.raises(ValidationError, match=r'Input should be an instance of PureWindowsPath \[type=is_instance_of'):
+        v.validate_python(PurePosixPath('a'))
+
+
+def test_must_be_absolute(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.path_schema(path_type='posix', must_be_absolute=True))
+    assert v.validate_test('/a/b') == PurePosixPath('/a/b')
+    with pytest.raises(ValidationError, match=r'Path should be absolute \[type=path_not_absolute'):
+        v.validate_test('a/b')
+
+
+def test_must_be_absolute_instance():
+    v = SchemaValidator(core_schema.path_schema(path_type='posix', must_be_absolute=True))
+    with pytest.raises(ValidationError, match='Path should be absolute'):
+        v.validate_python(PurePosixPath('a/b'))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('a/b.json', PurePosixPath('a/b.json')),
+        ('a/b.tar.gz', PurePosixPath('a/b.tar.gz')),
+        ('a/b', PurePosixPath('a/b')),
+        ('a/b.JSON', Err("Path suffix should be '.json', '.gz' or '' [type=path_suffix,")),
+        ('a/b.txt', Err("Path suffix should be '.json', '.gz' or ''")),
+    ],
+)
+def test_allowed_suffixes(input_value, expected):
+    v = SchemaValidator(core_schema.path_schema(path_type='posix', allowed_suffixes=['.json', '.gz', '']))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_suffix_error_details():
+    v = SchemaValidator(core_schema.path_schema(allowed_suffixes=['.txt']))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('a.md')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'path_suffix',
+            'loc': (),
+            'msg': "Path suffix should be '.txt'",
+            'input': 'a.md',
+            'ctx': {'expected_suffixes': "'.txt'"},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'schema,message',
+    [
+        ({'type': 'path', 'path_type': 'other'}, 'Invalid path_type value: other'),
+        ({'type': 'path', 'allowed_suffixes': []}, '`allowed_suffixes` should have length > 0'),
+    ],
+)
+def test_invalid_schema(schema, message):
+    with pytest.raises(SchemaError, match=message):
+        SchemaValidator(schema)
+
+
+def test_union():
+    v = SchemaValidator(core_schema.union_schema([core_schema.str_schema(), core_schema.path_schema()]))
+    assert v.validate_python('a/b') == 'a/b'
+    path = Path('a/b')
+    assert v.validate_python(path) is path
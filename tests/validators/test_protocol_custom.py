@@ -0,0 +1,98 @@
+import pytest
+from dirty_equals import HasRepr
+
+from pydantic_core import PydanticCustomError, SchemaError, SchemaValidator, ValidationError
+from pydantic_core import core_schema as cs
+
+
+class Money:
+    def __init__(self, cents: int):
+        self.cents = cents
+
+    @classmethod
+    def __validate_core__(cls, value):
+        if isinstance(value, cls):
+            return value
+        if isinstance(value, str) and value.startswith('$'):
+            return cls(round(float(value[1:]) * 100))
+        if isinstance(value, int):
+            return cls(value)
+        raise PydanticCustomError('money', 'Invalid money value {value}', {'value': value})
+
+
+def test_protocol_custom():
+    v = SchemaValidator(cs.protocol_custom_schema(Money))
+    m = Money(1)
+    assert v.validate_python(m) is m
+    assert v.validate_python('$1.50').cents == 150
+    assert v.validate_json('250').cents == 250
+    assert v.validate_json('"$3"').cents == 300
+
+
+def test_protocol_custom_error():
+    v = SchemaValidator(cs.protocol_custom_schema(Money))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1.5)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'money', 'loc': (), 'msg': 'Invalid money value 1.5', 'input': 1.5, 'ctx': {'value': 1.5}}
+    ]
+
+
+def test_protocol_custom_value_error():
+    class Positive(int):
+        @classmethod
+        def __validate_core__(cls, value):
+            if value <= 0:
+                raise ValueError('must be positive')
+            return cls(value)
+
+    v = SchemaValidator(cs.list_schema(cs.protocol_custom_schema(Positive)))
+    assert v.validate_python([1, 2]) == [1, 2]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, -1])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'value_error',
+            'loc': (1,),
+            'msg': 'Value error, must be positive',
+            'input': -1,
+            'ctx': {'error': HasRepr(repr(ValueError('must be positive')))},
+        }
+    ]
+
+
+def test_protocol_custom_method_looked_up_once():
+    calls = []
+
+    class Tracked:
+        @classmethod
+        def __validate_core__(cls, value):
+            calls.append(value)
+            return value
+
+    v = SchemaValidator(cs.protocol_custom_schema(Tracked))
+    # replacing the method after the schema is built has no effect
+    Tracked.__validate_core__ = classmethod(lambda cls, value: None)
+    assert v.validate_python(1) == 1
+    assert calls == [1]
+
+
+def test_protocol_custom_repr():
+    v = SchemaValidator(cs.protocol_custom_schema(Money))
+    assert v.title == 'protocol-custom[Money]'
+    v = SchemaValidator(cs.protocol_custom_schema(Money, cls_repr='Cash'))
+    assert v.title == 'protocol-custom[Cash]'
+
+
+def test_protocol_custom_missing_method():
+    class Plain:
+        pass
+
+    with pytest.raises(SchemaError, match="'cls' must define a '__validate_core__' method"):
+        SchemaValidator(cs.protocol_custom_schema(Plain))
+
+    class NotCallable:
+        __validate_core__ = 1
+
+    with pytest.raises(SchemaError, match="'cls.__validate_core__' must be callable"):
+        SchemaValidator(cs.protocol_custom_schema(NotCallable))
@@ -0,0 +1,94 @@
+import copy
+import pickle
+import re
+
+import pytest
+
+from pydantic_core import Secret, SchemaValidator, ValidationError
+from pydantic_core import core_schema as cs
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('hunter2', 'hunter2'),
+        ('', ''),
+        (123, Err("Input should be a valid string [type=string_type, input_value='**********', input_type=str]")),
+    ],
+)
+def test_secret_str(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(cs.secret_str_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert isinstance(output, Secret)
+        assert output.get_secret_value() == expected
+
+
+def test_secret_str_repr():
+    v = SchemaValidator(cs.secret_str_schema())
+    secret = v.validate_python('hunter2')
+    assert repr(secret) == "Secret('**********')"
+    assert str(secret) == '**********'
+    assert len(secret) == 7
+    assert repr(v.validate_python('')) == "Secret('')"
+
+
+def test_secret_bytes():
+    v = SchemaValidator(cs.secret_bytes_schema())
+    secret = v.validate_python(b'hunter2')
+    assert secret.get_secret_value() == b'hunter2'
+    assert repr(secret) == "Secret(b'**********')"
+    assert v.validate_json('"hunter2"').get_secret_value() == b'hunter2'
+
+
+def test_secret_errors_masked():
+    v = SchemaValidator(cs.secret_str_schema(min_length=8))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('hunter2')
+    assert 'hunter2' not in str(exc_info.value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_short',
+            'loc': (),
+            'msg': 'String should have at least 8 characters',
+            'input': '**********',
+            'ctx': {'min_length': 8},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"hunter2"')
+    assert 'hunter2' not in str(exc_info.value)
+
+
+def test_secret_instance():
+    v = SchemaValidator(cs.secret_str_schema(max_length=5))
+    assert v.validate_python(Secret('abc')) == Secret('abc')
+    msg = r"String should have at most 5 characters \[type=string_too_long, input_value='\*+',"
+    with pytest.raises(ValidationError, match=msg):
+        v.validate_python(Secret('hunter2'))
+
+
+def test_secret_strict():
+    v = SchemaValidator(cs.secret_str_schema(strict=True))
+    assert v.validate_python(Secret('abc')).get_secret_value() == 'abc'
+    assert v.validate_json('"abc"').get_secret_value() == 'abc'
+    msg = r"Input should be an instance of Secret \[type=is_instance_of, input_value='\*+',"
+    with pytest.raises(ValidationError, match=msg):
+        v.validate_python('abc')
+
+
+def test_secret_class():
+    assert Secret('a') == Secret('a')
+    assert Secret('a') != Secret('b')
+    assert Secret('a') != 'a'
+    assert hash(Secret('a')) == hash('a')
+    assert copy.deepcopy(Secret('a')) == Secret('a')
+    assert pickle.loads(pickle.dumps(Secret(b'a'))) == Secret(b'a')
+    with pytest.raises(TypeError, match='Secret value must be a str or bytes'):
+        Secret(1)
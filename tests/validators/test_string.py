@@ -38,6 +38,7 @@ def test_str(py_and_json: PyAndJson, input_value, expected):
         ('🐈 Hello \ud800World', '🐈 Hello \ud800World'),
         (b'foobar', 'foobar'),
         (bytearray(b'foobar'), 'foobar'),
+        (memoryview(b'foobar'), 'foobar'),
         (
             b'\x81',
             Err('Input should be a valid string, unable to parse raw data as a unicode string [type=string_unicode'),
@@ -46,6 +47,10 @@ def test_str(py_and_json: PyAndJson, input_value, expected):
             bytearray(b'\x81'),
             Err('Input should be a valid string, unable to parse raw data as a unicode string [type=string_unicode'),
         ),
+        (
+            memoryview(b'\x81'),
+            Err('Input should be a valid string, unable to parse raw data as a unicode string [type=string_unicode'),
+        ),
         # null bytes are very annoying, but we can't really block them here
         (b'\x00', '\x00'),
         (123, Err('Input should be a valid string [type=string_type, input_value=123, input_type=int]')),
@@ -71,6 +76,13 @@ def test_str_not_json(input_value, expected):
         ({'strict': True}, 'Foobar', 'Foobar'),
         ({'to_upper': True}, 'fooBar', 'FOOBAR'),
         ({'to_lower': True}, 'fooBar', 'foobar'),
+        ({'to_title': True}, "hello wORLD they're", "Hello World They'Re"),
+        ({'to_snake': True}, 'HTTPResponseCode', 'http_response_code'),
+        ({'to_snake': True}, 'myURL-v2Name', 'my_url_v2_name'),
+        ({'to_snake': True}, 'already_snake', 'already_snake'),
+        ({'to_camel': True}, 'http_response_code', 'httpResponseCode'),
+        ({'to_camel': True}, 'HTTP Response-Code', 'httpResponseCode'),
+        ({'to_camel': True}, 'alreadyCamel', 'alreadyCamel'),
         ({'strip_whitespace': True}, ' foobar  ', 'foobar'),
         ({'strip_whitespace': True, 'to_upper': True}, ' fooBar', 'FOOBAR'),
         ({'min_length': 5}, '12345', '12345'),
@@ -419,3 +431,129 @@ def test_compiled_regex(engine) -> None:
     v = SchemaValidator(core_schema.str_schema(pattern=re.compile('abc', re.IGNORECASE), regex_engine=engine))
     assert v.validate_python('abc') == 'abc'
     assert v.validate_python('ABC') == 'ABC'
+
+
+@pytest.mark.parametrize('engine', [None, 'rust-regex', 'python-re'])
+@pytest.mark.parametrize(
+    'pattern_mode,input_value,valid',
+    [
+        (None, 'xabcx', True),
+        ('search', 'xabcx', True),
+        ('fullmatch', 'abc', True),
+        ('fullmatch', 'xabcx', False),
+        ('fullmatch', 'abcx', False),
+        # the anchors apply to every alternative
+        ('fullmatch', 'ab', True),
+        ('fullmatch', 'xab', False),
+    ],
+)
+def test_pattern_mode(engine, pattern_mode, input_value, valid):
+    v = SchemaValidator(core_schema.str_schema(pattern='abc|ab', pattern_mode=pattern_mode, regex_engine=engine))
+    if valid:
+        assert v.validate_python(input_value) == input_value
+    else:
+        with pytest.raises(ValidationError, match="String should match pattern 'abc\\|ab'"):
+            v.validate_python(input_value)
+
+
+def test_pattern_mode_fullmatch_compiled():
+    v = SchemaValidator(core_schema.str_schema(pattern=re.compile('abc', re.IGNORECASE), pattern_mode='fullmatch'))
+    assert v.validate_python('ABC') == 'ABC'
+    with pytest.raises(ValidationError):
+        v.validate_python('ABCD')
+
+
+def test_pattern_mode_invalid():
+    with pytest.raises(SchemaError, match='Invalid pattern_mode: anchored'):
+        SchemaValidator(core_schema.str_schema(pattern='a', pattern_mode='anchored'))
+
+
+def test_regex_engine_per_schema():
+    # lookahead is only supported by python's `re`, the schema's `regex_engine` overrides the config
+    v = SchemaValidator(
+        core_schema.str_schema(pattern='^a(?=b)', regex_engine='python-re'),
+        config=CoreConfig(regex_engine='rust-regex'),
+    )
+    assert v.validate_python('ab') == 'ab'
+
+
+def test_to_snake_config():
+    v = SchemaValidator(core_schema.str_schema(), config=CoreConfig(str_to_snake=True))
+    assert v.validate_python('fooBarBaz') == 'foo_bar_baz'
+    v = SchemaValidator(core_schema.str_schema(to_snake=False), config=CoreConfig(str_to_snake=True))
+    assert v.validate_python('fooBarBaz') == 'fooBarBaz'
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'normalize': 'NFC'}, 'e\u0301', '\u00e9'),
+        ({'normalize': 'NFD'}, '\u00e9', 'e\u0301'),
+        ({'normalize': 'NFKC'}, '\ufb01 \u2460', 'fi 1'),
+        ({'normalize': 'NFKD'}, '\u00e9\u2460', 'e\u03011'),
+        ({'normalize': 'NFC'}, 'plain', 'plain'),
+        ({'casefold': True}, 'Stra\u00dfe', 'strasse'),
+        ({'casefold': True, 'normalize': 'NFKC'}, '\u212b', '\u00e5'),
+        # normalization happens before length constraints
+        ({'normalize': 'NFC', 'max_length': 1}, 'e\u0301', '\u00e9'),
+        ({'normalize': 'NFD', 'max_length': 1}, '\u00e9', Err('String should have at most 1 character')),
+        ({'casefold': True, 'min_length': 7}, 'Stra\u00dfe', 'strasse'),
+        ({'casefold': True, 'pattern': '^[a-z]+$'}, 'ABC', 'abc'),
+        ({'casefold': True, 'strip_whitespace': True}, ' ABC ', 'abc'),
+    ],
+)
+def test_str_normalize_casefold(py_and_json: PyAndJson, kwargs: dict[str, Any], input_value, expected):
+    v = py_and_json(core_schema.str_schema(**kwargs))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_str_normalize_config():
+    v = SchemaValidator(core_schema.str_schema(), config=CoreConfig(str_normalize='NFC', str_casefold=True))
+    assert v.validate_python('E\u0301') == '\u00e9'
+
+
+def test_str_normalize_invalid():
+    with pytest.raises(SchemaError, match='Invalid unicode normalization form: NFX'):
+        SchemaValidator(core_schema.str_schema(normalize='NFX'))
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'max_length_bytes': 5}, 'hello', 'hello'),
+        ({'max_length_bytes': 5}, 'héllo', Err('String should have at most 5 bytes when UTF-8 encoded, not 6')),
+        ({'max_length_bytes': 4}, '\U0001f600', '\U0001f600'),
+        ({'max_length_bytes': 3}, '\U0001f600', Err('String should have at most 3 bytes when UTF-8 encoded, not 4')),
+        ({'min_length_bytes': 2}, 'é', 'é'),
+        ({'min_length_bytes': 2}, 'e', Err('String should have at least 2 bytes when UTF-8 encoded, not 1')),
+        # byte length is checked on the value after stripping and normalization
+        ({'max_length_bytes': 3, 'strip_whitespace': True}, ' abc ', 'abc'),
+        ({'max_length_bytes': 2, 'normalize': 'NFC'}, 'é', 'é'),
+    ],
+)
+def test_str_length_bytes(py_and_json: PyAndJson, kwargs: dict[str, Any], input_value, expected):
+    v = py_and_json(core_schema.str_schema(**kwargs))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_str_length_bytes_error_details():
+    v = SchemaValidator(core_schema.str_schema(max_length_bytes=3))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('éé')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_long_bytes',
+            'loc': (),
+            'msg': 'String should have at most 3 bytes when UTF-8 encoded, not 4',
+            'input': 'éé',
+            'ctx': {'max_length_bytes': 3, 'actual_length_bytes': 4},
+        }
+    ]
@@ -3,7 +3,7 @@ from enum import Enum
 import pytest
 from dirty_equals import IsAnyStr
 
-from pydantic_core import CoreConfig, SchemaValidator, ValidationError, core_schema
+from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 from .test_typed_dict import Cls
@@ -606,3 +606,103 @@ def test_custom_error_type():
             'input': {'foo': 'other', 'bar': 'Bar'},
         }
     ]
+
+
+@pytest.fixture(scope='module')
+def composite_validator():
+    def pet_schema(version_schema):
+        return core_schema.typed_dict_schema(
+            fields={
+                'type': core_schema.typed_dict_field(schema=core_schema.str_schema()),
+                'version': core_schema.typed_dict_field(schema=version_schema),
+                'name': core_schema.typed_dict_field(schema=core_schema.str_schema()),
+            }
+        )
+
+    return SchemaValidator(
+        core_schema.tagged_union_schema(
+            discriminator=('type', 'version'),
+            choices={
+                ('cat', 1): pet_schema(core_schema.literal_schema([1])),
+                ('cat', 2): pet_schema(core_schema.literal_schema([2])),
+                ('dog', 1): pet_schema(core_schema.literal_schema([1])),
+            },
+        )
+    )
+
+
+def test_composite_discriminator(composite_validator: SchemaValidator):
+    output = composite_validator.validate_python({'type': 'cat', 'version': 2, 'name': 'a'})
+    assert output == {'type': 'cat', 'version': 2, 'name': 'a'}
+
+
+def test_composite_discriminator_json(composite_validator: SchemaValidator):
+    output = composite_validator.validate_json('{"type": "dog", "version": 1, "name": "b"}')
+    assert output == {'type': 'dog', 'version': 1, 'name': 'b'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        composite_validator.validate_json('{"type": "dog", "version": 1}')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'missing',
+            'loc': ("('dog', 1)", 'name'),
+            'msg': 'Field required',
+            'input': {'type': 'dog', 'version': 1},
+        }
+    ]
+
+
+def test_composite_discriminator_errors(composite_validator: SchemaValidator):
+    with pytest.raises(ValidationError) as exc_info:
+        composite_validator.validate_python({'type': 'cat', 'version': 3})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'union_tag_invalid',
+            'loc': (),
+            'msg': "Input tag '3' found using 'version' does not match any of the expected tags: 1, 2",
+            'input': {'type': 'cat', 'version': 3},
+            'ctx': {'discriminator': "'version'", 'tag': '3', 'expected_tags': '1, 2'},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        composite_validator.validate_python({'type': 'fish', 'version': 1})
+    assert exc_info.value.errors(include_url=False)[0]['msg'] == (
+        "Input tag 'fish' found using 'type' does not match any of the expected tags: 'cat', 'dog'"
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        composite_validator.validate_python({'type': 'cat'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'union_tag_not_found',
+            'loc': (),
+            'msg': "Unable to extract tag using discriminator 'version'",
+            'input': {'type': 'cat'},
+            'ctx': {'discriminator': "'version'"},
+        }
+    ]
+
+
+def test_composite_discriminator_path():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            discriminator=('kind', ['meta', 'version']),
+            choices={
+                ('a', 1): core_schema.dict_schema(),
+                ('a', 2): core_schema.dict_schema(),
+            },
+        )
+    )
+    assert v.validate_python({'kind': 'a', 'meta': {'version': 2}}) == {'kind': 'a', 'meta': {'version': 2}}
+    with pytest.raises(ValidationError, match=r"Input tag '3' found using 'meta'\.'version' does not match"):
+        v.validate_python({'kind': 'a', 'meta': {'version': 3}})
+
+
+def test_composite_discriminator_invalid_choice():
+    msg = r"Tag 'a' should be a tuple of 2 values to match the discriminator \('x', 'y'\)"
+    with pytest.raises(SchemaError, match=msg):
+        SchemaValidator(
+            core_schema.tagged_union_schema(discriminator=('x', 'y'), choices={'a': core_schema.dict_schema()})
+        )
@@ -296,3 +296,22 @@ def test_tz_constraint_too_high():
 def test_tz_constraint_wrong():
     with pytest.raises(SchemaError, match="Input should be 'aware' or 'naive"):
         validate_core_schema(core_schema.time_schema(tz_constraint='wrong'))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('6:30 PM', time(18, 30)),
+        ('12:05 am', time(0, 5)),
+        ('1830', time(18, 30)),
+        ('18:30:15.5+0200', time(18, 30, 15, 500_000, tzinfo=timezone(timedelta(hours=2)))),
+        ('13:30 PM', Err('Input should be in a valid time format')),
+    ],
+)
+def test_time_formats(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.time_schema(formats=['%I:%M %p', '%H%M', '%H:%M:%S.%f%z']))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
@@ -5,7 +5,7 @@ from typing import Any
 import pytest
 from dirty_equals import IsNonNegative, IsTuple
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -558,3 +558,179 @@ def test_tuple_fail_fast(fail_fast, expected):
         v.validate_python(['str', 'not-num', 'again'])
 
     assert exc_info.value.errors(include_url=False) == expected
+
+
+def version_schema(**kwargs):
+    return core_schema.tuple_schema(
+        [
+            core_schema.int_schema(),
+            core_schema.with_default_schema(core_schema.int_schema(), default=0),
+            core_schema.with_default_schema(core_schema.int_schema(), default=0),
+        ],
+        **kwargs,
+    )
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ([1, 2, 3], (1, 2, 3)),
+        ([1, 2], (1, 2, 0)),
+        ([1], (1, 0, 0)),
+        ([], Err('Field required [type=missing,')),
+        ([1, 2, 3, 4], Err('Tuple should have at most 3 items after validation, not 4 [type=too_long,')),
+    ],
+)
+def test_trailing_defaults(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(version_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_trailing_defaults_min_length():
+    v = SchemaValidator(version_schema(min_length=2))
+    assert v.validate_python((1, 2)) == (1, 2, 0)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python((1,))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': (1,), 'msg': 'Field required', 'input': (1,)}
+    ]
+
+
+def test_trailing_defaults_variadic():
+    v = SchemaValidator(
+        core_schema.tuple_schema(
+            [
+                core_schema.int_schema(),
+                core_schema.with_default_schema(core_schema.int_schema(), default=0),
+                core_schema.str_schema(),
+            ],
+            variadic_item_index=2,
+        )
+    )
+    assert v.validate_python((1,)) == (1, 0)
+    assert v.validate_python((1, 2, 'a', 'b')) == (1, 2, 'a', 'b')
+
+
+def test_default_before_required():
+    schema = core_schema.tuple_schema(
+        [core_schema.with_default_schema(core_schema.int_schema(), default=0), core_schema.int_schema()]
+    )
+    with pytest.warns(UserWarning, match='Tuple item 0 has a default but is followed by required item 1'):
+        v = SchemaValidator(schema)
+    assert v.validate_python((1, 2)) == (1, 2)
+    with pytest.raises(ValidationError, match=r'1\n  Field required \[type=missing,'):
+        v.validate_python((1,))
+
+
+def test_unique_items_variadic(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.tuple_schema(
+            [core_schema.str_schema(), core_schema.int_schema()], variadic_item_index=1, unique_items=True
+        )
+    )
+    assert v.validate_test(['a', 1, 2, 3]) == ('a', 1, 2, 3)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(['a', 1, 2, '2'])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'duplicate_item',
+            'loc': (3,),
+            'msg': 'Items should be unique, this is a duplicate of the item at index 2',
+            'input': 2,
+            'ctx': {'first_index': 2},
+        }
+    ]
+
+
+def test_unique_items_key():
+    v = SchemaValidator(
+        core_schema.tuple_variable_schema(unique_items=True, unique_items_key=lambda item: item.lower())
+    )
+    assert v.validate_python(('a', 'b')) == ('a', 'b')
+    with pytest.raises(ValidationError, match=r'duplicate of the item at index 0 \[type=duplicate_item,'):
+        v.validate_python(('a', 'b', 'A'))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (('a', 1, 2, 'b'), ('a', 1, 2, 'b')),
+        (('a', 1, 2, 3, 'b'), ('a', 1, 2, 3, 'b')),
+        (
+            ('a', 1, 'b'),
+            Err('1\n  Tuple variadic section should have at least 2 items after validation, not 1 [type=too_short,'),
+        ),
+        (
+            ('a', 1, 2, 3, 4, 'b'),
+            Err('1\n  Tuple variadic section should have at most 3 items after validation, not 4 [type=too_long,'),
+        ),
+    ],
+)
+def test_variadic_length(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(
+        core_schema.tuple_schema(
+            [core_schema.str_schema(), core_schema.int_schema(), core_schema.str_schema()],
+            variadic_item_index=1,
+            variadic_min_length=2,
+            variadic_max_length=3,
+        )
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_variadic_length_no_tail():
+    v = SchemaValidator(
+        core_schema.tuple_schema(
+            [core_schema.str_schema(), core_schema.int_schema()], variadic_item_index=1, variadic_min_length=1
+        )
+    )
+    assert v.validate_python(('a', 1)) == ('a', 1)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(('a',))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'too_short',
+            'loc': (1,),
+            'msg': 'Tuple variadic section should have at least 1 item after validation, not 0',
+            'input': ('a',),
+            'ctx': {'field_type': 'Tuple variadic section', 'min_length': 1, 'actual_length': 0},
+        }
+    ]
+
+
+def test_variadic_length_requires_variadic_item():
+    with pytest.raises(SchemaError, match='`variadic_min_length` and `variadic_max_length` require'):
+        SchemaValidator(core_schema.tuple_schema([core_schema.int_schema()], variadic_max_length=1))
+
+
+def test_variadic_length_item_errors():
+    v = SchemaValidator(
+        core_schema.tuple_schema(
+            [core_schema.str_schema(), core_schema.int_schema(), core_schema.str_schema()],
+            variadic_item_index=1,
+            variadic_min_length=2,
+        )
+    )
+    # the invalid item still counts towards the section's length, so only the item error is reported
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(('a', 1, 'x', 'b'))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (2,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    # the length isn't checked once an item in the section has failed
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(('a', 'x', 'b'))
+    assert [e['type'] for e in exc_info.value.errors()] == ['int_parsing']
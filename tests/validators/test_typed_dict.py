@@ -190,6 +190,103 @@ def test_allow_extra_invalid():
         )
 
 
+def test_allow_extra_pattern_schemas(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            fields={'a': core_schema.typed_dict_field(core_schema.int_schema())},
+            extra_behavior='allow',
+            extras_pattern_schemas={'^x-': core_schema.str_schema()},
+        )
+    )
+    assert v.validate_test({'a': 1, 'x-trace': 'abc'}) == {'a': 1, 'x-trace': 'abc'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'a': 1, 'x-trace': 1, 'other': 2})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': ('x-trace',), 'msg': 'Input should be a valid string', 'input': 1},
+        {'type': 'extra_forbidden', 'loc': ('other',), 'msg': 'Extra inputs are not permitted', 'input': 2},
+    ]
+
+
+def test_allow_extra_pattern_schemas_fallback():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            fields={},
+            extra_behavior='allow',
+            extras_schema=core_schema.any_schema(),
+            extras_pattern_schemas={'^x-': core_schema.int_schema()},
+        )
+    )
+    assert v.validate_python({'x-a': '1', 'b': 'c'}) == {'x-a': 1, 'b': 'c'}
+
+
+@pytest.mark.parametrize('extra_behavior', ['allow', 'ignore'])
+def test_forbidden_extra_keys(py_and_json: PyAndJson, extra_behavior):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            fields={'a': core_schema.typed_dict_field(core_schema.int_schema())},
+            extra_behavior=extra_behavior,
+            forbidden_extra_keys=['password', 'token'],
+        )
+    )
+    expected = {'a': 1, 'b': 2} if extra_behavior == 'allow' else {'a': 1}
+    assert v.validate_test({'a': 1, 'b': 2}) == expected
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'a': 1, 'b': 2, 'password': 'x', 'token': 'y'})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'extra_forbidden', 'loc': ('password',), 'msg': 'Extra inputs are not permitted', 'input': 'x'},
+        {'type': 'extra_forbidden', 'loc': ('token',), 'msg': 'Extra inputs are not permitted', 'input': 'y'},
+    ]
+
+
+def test_forbidden_extra_keys_field():
+    with pytest.raises(SchemaError, match="Field 'a' cannot also be in forbidden_extra_keys"):
+        SchemaValidator(
+            core_schema.typed_dict_schema(
+                fields={'a': core_schema.typed_dict_field(core_schema.int_schema())}, forbidden_extra_keys=['a']
+            )
+        )
+
+
+def test_combine_extra_errors():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            fields={'a': core_schema.typed_dict_field(core_schema.int_schema())},
+            extra_behavior='forbid',
+            combine_extra_errors=True,
+        )
+    )
+    assert v.validate_python({'a': 1}) == {'a': 1}
+
+    input_value = {'a': 'x', 'b': 2, 'c': 3}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'extra_keys_forbidden',
+            'loc': (),
+            'msg': "Extra inputs are not permitted: 'b', 'c'",
+            'input': input_value,
+            'ctx': {'extra_keys': "'b', 'c'"},
+        },
+    ]
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            fields={}, extra_behavior='allow', forbidden_extra_keys=['b', 'c'], combine_extra_errors=True
+        )
+    )
+    with pytest.raises(ValidationError, match="Extra inputs are not permitted: 'c' \\[type=extra_keys_forbidden"):
+        v.validate_python({'a': 1, 'c': 3})
+
+
 def test_allow_extra_wrong():
     with pytest.raises(SchemaError, match="Input should be 'allow', 'forbid' or 'ignore'"):
         validate_core_schema({'type': 'typed-dict', 'fields': {}, 'config': {'extra_fields_behavior': 'wrong'}})
@@ -364,6 +461,46 @@ def test_alias(py_and_json: PyAndJson):
         assert v.validate_test({'field_a': '123'})
 
 
+@pytest.mark.parametrize(
+    'alias_generator,alias',
+    [('camel', 'fieldAName'), ('pascal', 'FieldAName'), ('snake', 'field_a_name'), ('kebab', 'field-a-name')],
+)
+def test_alias_generator(py_and_json: PyAndJson, alias_generator, alias):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {
+                'field_a_name': core_schema.typed_dict_field(core_schema.int_schema()),
+                'field_b': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='b'),
+            },
+            config=CoreConfig(alias_generator=alias_generator),
+        )
+    )
+    assert v.validate_test({alias: 1, 'b': 2}) == {'field_a_name': 1, 'field_b': 2}
+    if alias != 'field_a_name':
+        with pytest.raises(ValidationError, match=rf'{alias}\n +Field required \[type=missing,'):
+            v.validate_test({'field_a_name': 1, 'b': 2})
+
+
+def test_alias_generator_by_name():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'_http_server': core_schema.typed_dict_field(core_schema.int_schema())},
+            config=CoreConfig(alias_generator='camel', validate_by_name=True),
+        )
+    )
+    assert v.validate_python({'_httpServer': 1}) == {'_http_server': 1}
+    assert v.validate_python({'_http_server': 1}) == {'_http_server': 1}
+
+
+def test_alias_generator_invalid():
+    with pytest.raises(SchemaError, match='Invalid alias_generator: `upper`'):
+        SchemaValidator(
+            core_schema.typed_dict_schema(
+                {'a': core_schema.typed_dict_field(core_schema.int_schema())}, config=CoreConfig(alias_generator='upper')
+            )
+        )
+
+
 def test_empty_string_field_name(py_and_json: PyAndJson):
     v = py_and_json({'type': 'typed-dict', 'fields': {'': {'type': 'typed-dict-field', 'schema': {'type': 'int'}}}})
     assert v.validate_test({'': 123}) == {'': 123}
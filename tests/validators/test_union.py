@@ -1438,3 +1438,151 @@ def test_smart_union_wrap_validator_should_not_change_nested_model_field_counts(
     assert isinstance(m, RootModel)
     assert isinstance(m.ab, ModelA)
     assert m.ab.sub.x == 'y'
+
+
+def test_priorities_break_ties():
+    choices = [core_schema.float_schema(), core_schema.int_schema()]
+    v = SchemaValidator(core_schema.union_schema(choices))
+    assert v.validate_python('1') == IsFloat(exactly=1.0)
+
+    v = SchemaValidator(core_schema.union_schema(choices, priorities=[0, 1]))
+    assert v.validate_python('1') == IsInt(exactly=1)
+    # priorities only break ties, a better match still wins
+    assert v.validate_python(1.0) == IsFloat(exactly=1.0)
+    assert v.validate_python(1) == IsInt(exactly=1)
+
+
+def test_priorities_exact_matches():
+    def fn(label):
+        return core_schema.no_info_after_validator_function(lambda value: (label, value), core_schema.str_schema())
+
+    v = SchemaValidator(core_schema.union_schema([fn('a'), fn('b'), fn('c')], priorities=[1, 2, 0]))
+    assert v.validate_python('x') == ('b', 'x')
+
+    v = SchemaValidator(core_schema.union_schema([fn('a'), fn('b'), fn('c')], priorities=[1, 0, 0]))
+    assert v.validate_python('x') == ('a', 'x')
+
+    # an earlier exact match with a higher priority beats a later one
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [fn('a'), core_schema.int_schema(), fn('c')],
+            priorities=[1, 2, 0],
+        )
+    )
+    assert v.validate_python('x') == ('a', 'x')
+
+    # equal priorities fall back to the order of the choices
+    v = SchemaValidator(core_schema.union_schema([fn('a'), fn('b'), fn('c')], priorities=[0, 1, 1]))
+    assert v.validate_python('x') == ('b', 'x')
+
+
+def test_priorities_invalid():
+    choices = [core_schema.int_schema(), core_schema.str_schema()]
+    with pytest.raises(SchemaError, match=r"'priorities' must have one value per choice, expected 2, got 1"):
+        SchemaValidator(core_schema.union_schema(choices, priorities=[1]))
+    with pytest.raises(SchemaError, match=r"'priorities' can only be used with the `smart` union mode"):
+        SchemaValidator(core_schema.union_schema(choices, mode='left_to_right', priorities=[1, 0]))
+
+
+def error_mode_union(**kwargs):
+    return core_schema.union_schema(
+        [
+            (core_schema.int_schema(), 'int'),
+            (
+                core_schema.typed_dict_schema(
+                    {
+                        'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                        'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+                'Foo',
+            ),
+            (
+                core_schema.typed_dict_schema(
+                    {
+                        'a': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'c': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+                'Bar',
+            ),
+        ],
+        **kwargs,
+    )
+
+
+def test_error_mode_all():
+    v = SchemaValidator(error_mode_union(error_mode='all'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': 'x'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_type', ('int',)),
+        ('int_parsing', ('Foo', 'b')),
+        ('string_type', ('Bar', 'a')),
+        ('missing', ('Bar', 'c')),
+    ]
+
+
+def test_error_mode_best_match():
+    v = SchemaValidator(error_mode_union(error_mode='best-match'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': 'x'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('Foo', 'b'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+    # choices which fail at the top level are the furthest from matching
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('missing', ('Bar', 'c'))]
+
+
+def test_error_mode_summarize():
+    v = SchemaValidator(error_mode_union(error_mode='summarize'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': 'x'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'union_member_failed',
+            'loc': ('int',),
+            'msg': 'Input failed validation against this union member with 1 error',
+            'input': {'a': 1, 'b': 'x'},
+            'ctx': {'error_count': 1},
+        },
+        {
+            'type': 'int_parsing',
+            'loc': ('Foo', 'b'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'union_member_failed',
+            'loc': ('Bar',),
+            'msg': 'Input failed validation against this union member with 2 errors',
+            'input': {'a': 1, 'b': 'x'},
+            'ctx': {'error_count': 2},
+        },
+    ]
+
+
+def test_error_mode_config():
+    v = SchemaValidator(error_mode_union(), config=core_schema.CoreConfig(union_error_mode='best-match'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': 'x'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', ('Foo', 'b'))]
+
+    # the schema takes precedence over the config
+    v = SchemaValidator(error_mode_union(error_mode='all'), config=core_schema.CoreConfig(union_error_mode='summarize'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': 'x'})
+    assert exc_info.value.error_count() == 4
+
+
+def test_error_mode_invalid():
+    with pytest.raises(SchemaError, match='Invalid union error mode: `some`, expected `all`, `best-match` or'):
+        SchemaValidator(error_mode_union(), config=core_schema.CoreConfig(union_error_mode='some'))
@@ -4,7 +4,7 @@ from uuid import UUID
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -234,3 +234,64 @@ def test_uuid_wrap_json():
     assert v.validate_json('"a6cc5730-2261-11ee-9c43-2eb5a363657c"', strict=True) == UUID(
         'a6cc5730-2261-11ee-9c43-2eb5a363657c'
     )
+
+
+UUID_V4 = UUID('a6cc5730-2261-41ee-9c43-2eb5a363657c')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (UUID_V4.int, UUID_V4),
+        (0, UUID(int=0)),
+        (2**128 - 1, UUID(int=2**128 - 1)),
+        (-1, Err('Input should be a valid UUID, integer should be between 0 and 2**128 - 1')),
+        (2**128, Err('Input should be a valid UUID, integer should be between 0 and 2**128 - 1')),
+        (str(UUID_V4), UUID_V4),
+    ],
+)
+def test_uuid_allow_int(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.uuid_schema(allow_int=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert isinstance(output, UUID)
+
+
+def test_uuid_allow_int_disabled():
+    v = SchemaValidator(core_schema.uuid_schema())
+    with pytest.raises(ValidationError, match='UUID input should be a string, bytes or UUID object'):
+        v.validate_python(UUID_V4.int)
+
+    v = SchemaValidator(core_schema.uuid_schema(allow_int=True))
+    with pytest.raises(ValidationError, match='Input should be an instance of UUID'):
+        v.validate_python(UUID_V4.int, strict=True)
+    with pytest.raises(ValidationError, match='UUID input should be a string, bytes or UUID object'):
+        v.validate_json(str(UUID_V4.int), strict=True)
+
+
+def test_uuid_allow_int_version():
+    v = SchemaValidator(core_schema.uuid_schema(allow_int=True, version=4))
+    assert v.validate_python(UUID_V4.int) == UUID_V4
+    with pytest.raises(ValidationError, match='UUID version 4 expected'):
+        v.validate_python(UUID('a6cc5730-2261-11ee-9c43-2eb5a363657c').int)
+
+
+def test_uuid_bytes_order():
+    v = SchemaValidator(core_schema.uuid_schema(bytes_order='little'))
+    assert v.validate_python(UUID_V4.bytes_le) == UUID_V4
+    # strings in bytes aren't affected by the byte order
+    assert v.validate_python(str(UUID_V4).encode()) == UUID_V4
+    assert SchemaValidator(core_schema.uuid_schema()).validate_python(UUID_V4.bytes) == UUID_V4
+
+    v = SchemaValidator(core_schema.uuid_schema(bytes_order='little', version=4))
+    with pytest.raises(ValidationError, match='UUID version 4 expected'):
+        v.validate_python(UUID_V4.bytes)
+
+
+def test_uuid_bytes_order_invalid():
+    with pytest.raises(SchemaError, match="Invalid bytes_order \"middle\", expected 'big' or 'little'"):
+        SchemaValidator({'type': 'uuid', 'bytes_order': 'middle'})
@@ -264,6 +264,45 @@ def test_typed_dict_error():
         v.validate_python({'x': 'x'})
 
 
+def test_default_factory_takes_context():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            fields={
+                'tenant': core_schema.typed_dict_field(
+                    schema=core_schema.with_default_schema(
+                        schema=core_schema.str_schema(),
+                        default_factory=lambda context: context['tenant'] if context else 'default',
+                        default_factory_takes_context=True,
+                    )
+                ),
+            }
+        )
+    )
+    assert v.validate_python({}, context={'tenant': 'acme'}) == {'tenant': 'acme'}
+    assert v.validate_python({}) == {'tenant': 'default'}
+    assert v.validate_python({'tenant': 'x'}, context={'tenant': 'acme'}) == {'tenant': 'x'}
+
+
+def test_default_factory_takes_data_and_context():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            fields={
+                'x': core_schema.typed_dict_field(schema=core_schema.int_schema()),
+                'y': core_schema.typed_dict_field(
+                    schema=core_schema.with_default_schema(
+                        schema=core_schema.int_schema(),
+                        default_factory=lambda data, context: data['x'] * context['factor'],
+                        default_factory_takes_data=True,
+                        default_factory_takes_context=True,
+                    )
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'x': 2}, context={'factor': 3}) == {'x': 2, 'y': 6}
+    assert v.validate_json('{"x": 2}', context={'factor': 4}) == {'x': 2, 'y': 8}
+
+
 def test_on_error_default_not_int():
     v = SchemaValidator(
         core_schema.with_default_schema(schema=core_schema.int_schema(), default=[1, 2, 3], on_error='default')
@@ -813,3 +852,84 @@ def test_validate_default_raises_dataclass(input_value: dict, expected: Any) ->
         v.validate_python(input_value)
 
     assert exc_info.value.errors(include_url=False, include_context=False) == expected
+
+
+def test_suppressed_errors_default():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.with_default_schema(
+                core_schema.tuple_schema([core_schema.int_schema(), core_schema.int_schema()]),
+                on_error='default',
+                default=None,
+            )
+        )
+    )
+    value, report = v.validate_python([[1, 2], [3, 'x']], suppressed_errors=True)
+    assert value == [(1, 2), None]
+    assert report.suppressed_errors == [
+        {
+            'type': 'int_parsing',
+            'loc': (1, 1),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    assert report.coercions is None
+
+    value, report = v.validate_python([[1, 2]], suppressed_errors=True)
+    assert value == [(1, 2)]
+    assert report.suppressed_errors == []
+
+
+def test_suppressed_errors_omit():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.with_default_schema(core_schema.int_schema(), on_error='omit'))
+                ),
+                'b': core_schema.typed_dict_field(
+                    core_schema.dict_schema(
+                        core_schema.str_schema(),
+                        core_schema.with_default_schema(core_schema.int_schema(strict=True), on_error='omit'),
+                    )
+                ),
+            }
+        )
+    )
+    value, report = v.validate_python({'a': [1, 'x', '3'], 'b': {'c': '4', 'd': 5}}, suppressed_errors=True)
+    assert value == {'a': [1, 3], 'b': {'d': 5}}
+    assert [(e['type'], e['loc'], e['input']) for e in report.suppressed_errors] == [
+        ('int_parsing', ('a', 1), 'x'),
+        ('int_type', ('b', 'c'), '4'),
+    ]
+
+
+def test_suppressed_errors_discarded_union_choice():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.list_schema(
+                    core_schema.with_default_schema(core_schema.int_schema(), on_error='omit'), min_length=2
+                ),
+                core_schema.list_schema(core_schema.str_schema()),
+            ]
+        )
+    )
+    value, report = v.validate_python(['x', '1'], suppressed_errors=True)
+    assert value == ['x', '1']
+    assert report.suppressed_errors == []
+
+    value, report = v.validate_python([1, 2, 'x'], suppressed_errors=True)
+    assert value == [1, 2]
+    assert [(e['type'], e['loc'], e['input']) for e in report.suppressed_errors] == [('int_parsing', (2,), 'x')]
+
+
+def test_suppressed_errors_json_with_coercions():
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.with_default_schema(core_schema.int_schema(), on_error='default', default=0))
+    )
+    value, report = v.validate_json('["1", "x"]', coercions=True, suppressed_errors=True)
+    assert value == [1, 0]
+    assert report.coercions == [{'loc': (0,), 'input': '1', 'input_type': 'str', 'target': 'int'}]
+    assert [(e['type'], e['loc'], e['input']) for e in report.suppressed_errors] == [('int_parsing', (1,), 'x')]